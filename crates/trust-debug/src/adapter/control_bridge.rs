@@ -164,6 +164,9 @@ fn spawn_command_drain(rx: std::sync::mpsc::Receiver<ResourceCommand>) -> thread
                         now: trust_runtime::value::Duration::ZERO,
                     });
                 }
+                ResourceCommand::ResetInstance { respond_to, .. } => {
+                    let _ = respond_to.send(Ok(()));
+                }
                 ResourceCommand::Pause
                 | ResourceCommand::Resume
                 | ResourceCommand::UpdateWatchdog(_)
@@ -199,6 +199,7 @@ impl DebugAdapter {
                 now,
                 debug: None,
                 call_depth: 0,
+                max_call_depth: trust_runtime::eval::DEFAULT_MAX_CALL_DEPTH,
                 functions: Some(functions),
                 stdlib: Some(stdlib),
                 function_blocks: Some(function_blocks),
@@ -210,6 +211,7 @@ impl DebugAdapter {
                 loop_depth: 0,
                 pause_requested: false,
                 execution_deadline: None,
+                fault_location: None,
             };
             trust_runtime::eval::eval_expr(&mut ctx, expr)
         };
@@ -297,6 +299,7 @@ impl DebugAdapter {
                 now,
                 debug: None,
                 call_depth: 0,
+                max_call_depth: trust_runtime::eval::DEFAULT_MAX_CALL_DEPTH,
                 functions: Some(functions),
                 stdlib: Some(stdlib),
                 function_blocks: Some(function_blocks),
@@ -308,6 +311,7 @@ impl DebugAdapter {
                 loop_depth: 0,
                 pause_requested: false,
                 execution_deadline: None,
+                fault_location: None,
             };
             f(&mut ctx)
         };
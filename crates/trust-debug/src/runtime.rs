@@ -5,7 +5,7 @@ use std::sync::{Arc, Mutex};
 use trust_runtime::control::SourceFile as ControlSourceFile;
 use trust_runtime::debug::DebugControl;
 use trust_runtime::harness::CompileError;
-use trust_runtime::{Runtime, RuntimeMetadata};
+use trust_runtime::{Runtime, RuntimeMetadata, SubscriptionId, VariableChange};
 
 use crate::protocol::{Breakpoint, SetBreakpointsArguments, SetBreakpointsResponseBody, Source};
 use crate::session::{SourceFile, SourceOptionsUpdate};
@@ -24,4 +24,29 @@ pub trait DebugRuntime: Send {
     fn source_for_file_id(&self, file_id: u32) -> Option<Source>;
     fn source_text_for_file_id(&self, file_id: u32) -> Option<&str>;
     fn control_sources(&self) -> Vec<ControlSourceFile>;
+
+    /// Register for change notifications on `paths` in the debugged runtime.
+    ///
+    /// See [`Runtime::subscribe_changes`]; returns `None` if the runtime lock
+    /// is poisoned.
+    fn subscribe_changes(&self, paths: &[&str]) -> Option<SubscriptionId> {
+        let runtime = self.runtime_handle();
+        let mut runtime = runtime.lock().ok()?;
+        Some(runtime.subscribe_changes(paths.iter().copied()))
+    }
+
+    /// Drain the values that changed since the last call for `id`.
+    fn take_changes(&self, id: SubscriptionId) -> Vec<VariableChange> {
+        self.runtime_handle()
+            .lock()
+            .map(|mut runtime| runtime.take_changes(id))
+            .unwrap_or_default()
+    }
+
+    /// Remove a change subscription. Returns `false` if the id is unknown.
+    fn unsubscribe_changes(&self, id: SubscriptionId) -> bool {
+        self.runtime_handle()
+            .lock()
+            .is_ok_and(|mut runtime| runtime.unsubscribe_changes(id))
+    }
 }
@@ -17,6 +17,41 @@ mod tests {
         path
     }
 
+    #[test]
+    fn debug_runtime_forwards_change_subscriptions() {
+        let source = r#"
+PROGRAM Main
+VAR
+    count : INT;
+    idle : INT;
+END_VAR
+count := count + INT#1;
+END_PROGRAM
+"#;
+        let runtime = trust_runtime::harness::TestHarness::from_source(source)
+            .unwrap()
+            .into_runtime();
+        let session = DebugSession::new(runtime);
+        let session: &dyn DebugRuntime = &session;
+
+        let id = session
+            .subscribe_changes(&["Main.count", "Main.idle"])
+            .unwrap();
+        session
+            .runtime_handle()
+            .lock()
+            .unwrap()
+            .execute_cycle()
+            .unwrap();
+
+        let changes = session.take_changes(id);
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].path.as_str(), "Main.count");
+        assert_eq!(changes[0].value, trust_runtime::value::Value::Int(1));
+        assert!(session.unsubscribe_changes(id));
+        assert!(!session.unsubscribe_changes(id));
+    }
+
     #[test]
     fn expands_brace_globs() {
         let patterns = expand_braces("**/*.{st,ST,pou,POU}");
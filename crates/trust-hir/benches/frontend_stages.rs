@@ -0,0 +1,65 @@
+//! Per-stage frontend benchmark over the generated small/medium/large and
+//! class-hierarchy workloads.
+//!
+//! Run with `cargo bench -p trust-hir --bench frontend_stages`. Set
+//! `ST_HIR_BENCH_ITERATIONS` to change the number of samples per workload and
+//! pass a workload name (`small`, `medium`, `large`, `classes`) to run only that
+//! one.
+
+#[path = "../tests/perf_support/mod.rs"]
+mod perf_support;
+
+use perf_support::{measure, millis, workloads, StageTimings};
+use std::time::Duration;
+
+fn summarize(samples: &[Duration]) -> (Duration, Duration, Duration) {
+    let mut sorted = samples.to_vec();
+    sorted.sort_unstable();
+    let total: Duration = sorted.iter().sum();
+    let mean = total / sorted.len().max(1) as u32;
+    (sorted[0], sorted[sorted.len() / 2], mean)
+}
+
+fn main() {
+    let iterations = std::env::var("ST_HIR_BENCH_ITERATIONS")
+        .ok()
+        .and_then(|value| value.parse::<usize>().ok())
+        .unwrap_or(10)
+        .max(1);
+    let filter = std::env::args().skip(1).find(|arg| !arg.starts_with('-'));
+
+    for workload in workloads() {
+        if filter
+            .as_deref()
+            .is_some_and(|filter| !workload.name.contains(filter))
+        {
+            continue;
+        }
+        // Warm up allocator and lazily built stdlib tables before sampling.
+        let _ = measure(&workload);
+        let samples: Vec<StageTimings> = (0..iterations).map(|_| measure(&workload)).collect();
+
+        println!(
+            "{} ({} files, {} function blocks, {} bytes, {} samples)",
+            workload.name,
+            workload.files.len(),
+            workload.function_blocks,
+            workload.total_bytes(),
+            iterations
+        );
+        for (index, (stage, _)) in StageTimings::default().stages().into_iter().enumerate() {
+            let stage_samples: Vec<Duration> = samples
+                .iter()
+                .map(|timings| timings.stages()[index].1)
+                .collect();
+            let (min, median, mean) = summarize(&stage_samples);
+            println!(
+                "  {:<12} min {:>9.3} ms   median {:>9.3} ms   mean {:>9.3} ms",
+                stage,
+                millis(min),
+                millis(median),
+                millis(mean)
+            );
+        }
+    }
+}
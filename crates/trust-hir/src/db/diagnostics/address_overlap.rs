@@ -0,0 +1,112 @@
+use super::super::*;
+
+/// Located `%I`/`%Q`/`%M` address as a half-open bit range within its area.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct AddressSpan {
+    area: char,
+    start: u64,
+    end: u64,
+}
+
+impl AddressSpan {
+    fn overlaps(&self, other: &Self) -> bool {
+        self.area == other.area && self.start < other.end && other.start < self.end
+    }
+}
+
+pub(in crate::db) fn check_direct_address_overlaps(
+    symbols: &SymbolTable,
+    diagnostics: &mut DiagnosticBuilder,
+) {
+    let located: Vec<(&Symbol, &str, AddressSpan)> = symbols
+        .iter()
+        .filter(|symbol| symbol.origin.is_none() && !symbol.range.is_empty())
+        .filter_map(|symbol| {
+            let address = symbol.direct_address.as_deref()?;
+            Some((symbol, address, address_span(address)?))
+        })
+        .collect();
+
+    for (index, (symbol, address, span)) in located.iter().enumerate() {
+        let others: Vec<_> = located
+            .iter()
+            .enumerate()
+            .filter(|(other_index, (_, _, other))| *other_index != index && span.overlaps(other))
+            .map(|(_, entry)| entry)
+            .collect();
+        if others.is_empty() {
+            continue;
+        }
+
+        let relation = if others.iter().all(|(_, _, other)| other == span) {
+            "aliases"
+        } else {
+            "overlaps"
+        };
+        let listed = others
+            .iter()
+            .map(|(other, other_address, _)| format!("'{}' ({})", other.name, other_address))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let mut diagnostic = Diagnostic::warning(
+            DiagnosticCode::OverlappingDirectAddress,
+            symbol.range,
+            format!(
+                "direct address '{}' of '{}' {} {}",
+                address, symbol.name, relation, listed
+            ),
+        );
+        for (other, other_address, _) in others {
+            diagnostic = diagnostic.with_related(
+                other.range,
+                format!("'{}' is located at {}", other.name, other_address),
+            );
+        }
+        diagnostics.add(diagnostic);
+    }
+}
+
+/// Parses a fully specified direct address into the bits it occupies.
+///
+/// Follows the runtime process-image layout: byte offsets address the area
+/// directly and wider accesses cover consecutive bytes. Wildcard (`*`)
+/// addresses and malformed text yield `None`.
+fn address_span(address: &str) -> Option<AddressSpan> {
+    let text = address.trim().strip_prefix('%')?;
+    let mut chars = text.chars();
+    let area = chars.next()?.to_ascii_uppercase();
+    if !matches!(area, 'I' | 'Q' | 'M') {
+        return None;
+    }
+    let rest = chars.as_str();
+    let (size, rest) = match rest.chars().next()?.to_ascii_uppercase() {
+        'X' => (None, &rest[1..]),
+        'B' => (Some(1), &rest[1..]),
+        'W' => (Some(2), &rest[1..]),
+        'D' => (Some(4), &rest[1..]),
+        'L' => (Some(8), &rest[1..]),
+        ch if ch.is_ascii_digit() => (None, rest),
+        _ => return None,
+    };
+
+    let parts = rest
+        .split('.')
+        .map(|part| part.trim().parse::<u32>().ok().map(u64::from))
+        .collect::<Option<Vec<_>>>()?;
+    let byte = *parts.first()?;
+    let span = match size {
+        Some(bytes) => (byte * 8, (byte + bytes) * 8),
+        None => {
+            let bit = if parts.len() >= 2 { *parts.last()? } else { 0 };
+            if bit > 7 {
+                return None;
+            }
+            (byte * 8 + bit, byte * 8 + bit + 1)
+        }
+    };
+    Some(AddressSpan {
+        area,
+        start: span.0,
+        end: span.1,
+    })
+}
@@ -5,10 +5,19 @@ use super::context::is_pou_kind;
 const COMPLEXITY_WARN_THRESHOLD: usize = 15;
 const MAX_RELATED_POINTS: usize = 3;
 
+#[cfg(test)]
+thread_local! {
+    /// Number of times the complexity pass ran on this thread.
+    pub(in crate::db) static COMPLEXITY_PASS_RUNS: std::cell::Cell<usize> =
+        const { std::cell::Cell::new(0) };
+}
+
 pub(in crate::db) fn check_cyclomatic_complexity(
     root: &SyntaxNode,
     diagnostics: &mut DiagnosticBuilder,
 ) {
+    #[cfg(test)]
+    COMPLEXITY_PASS_RUNS.with(|runs| runs.set(runs.get() + 1));
     for pou in root.descendants().filter(|node| {
         matches!(
             node.kind(),
@@ -53,6 +62,7 @@ fn cyclomatic_complexity(pou: &SyntaxNode) -> (usize, Vec<TextRange>) {
             | SyntaxKind::ForStmt
             | SyntaxKind::WhileStmt
             | SyntaxKind::RepeatStmt
+            | SyntaxKind::CatchClause
             | SyntaxKind::CaseBranch => {
                 decision_points.push(node.text_range());
             }
@@ -27,19 +27,7 @@ pub(in crate::db) fn namespace_path_for_symbol(
     symbols: &SymbolTable,
     symbol_id: SymbolId,
 ) -> Vec<SmolStr> {
-    let mut parts = Vec::new();
-    let mut current = symbols.get(symbol_id).and_then(|sym| sym.parent);
-    while let Some(parent_id) = current {
-        let Some(parent) = symbols.get(parent_id) else {
-            break;
-        };
-        if matches!(parent.kind, SymbolKind::Namespace) {
-            parts.push(parent.name.clone());
-        }
-        current = parent.parent;
-    }
-    parts.reverse();
-    parts
+    symbols.namespace_path_of(symbol_id)
 }
 
 pub(in crate::db) fn normalized_name(name: &str) -> SmolStr {
@@ -69,10 +57,21 @@ pub(in crate::db) fn find_symbol_by_name_range(
     name: &str,
     range: TextRange,
 ) -> Option<SymbolId> {
-    symbols
+    // Symbols merged in from other files keep their own file's ranges, which
+    // can coincide with a range in this one, so declarations of this file win.
+    // Linked VAR_EXTERNALs are declared here but carry an origin too.
+    let mut matches = symbols
         .iter()
-        .find(|sym| sym.range == range && sym.name.eq_ignore_ascii_case(name))
-        .map(|sym| sym.id)
+        .filter(|sym| sym.range == range && sym.name.eq_ignore_ascii_case(name));
+    let first = matches.next()?;
+    if first.origin.is_none() {
+        return Some(first.id);
+    }
+    Some(
+        matches
+            .find(|sym| sym.origin.is_none())
+            .map_or(first.id, |sym| sym.id),
+    )
 }
 
 pub(in crate::db) fn property_type_for_node(
@@ -0,0 +1,120 @@
+use super::super::*;
+use super::unreachable::is_statement_kind;
+
+pub(in crate::db) fn check_empty_blocks(root: &SyntaxNode, diagnostics: &mut DiagnosticBuilder) {
+    for node in root.descendants() {
+        let (scope, opener, closers, label) = match node.kind() {
+            SyntaxKind::IfStmt => (
+                node.clone(),
+                SyntaxKind::KwThen,
+                &[
+                    SyntaxKind::ElsifBranch,
+                    SyntaxKind::ElseBranch,
+                    SyntaxKind::KwEndIf,
+                ][..],
+                "THEN",
+            ),
+            SyntaxKind::ElsifBranch => (parent_scope(&node), SyntaxKind::KwThen, &[][..], "ELSIF"),
+            SyntaxKind::ElseBranch => (parent_scope(&node), SyntaxKind::KwElse, &[][..], "ELSE"),
+            SyntaxKind::ForStmt => (
+                node.clone(),
+                SyntaxKind::KwDo,
+                &[SyntaxKind::KwEndFor][..],
+                "FOR",
+            ),
+            SyntaxKind::WhileStmt => (
+                node.clone(),
+                SyntaxKind::KwDo,
+                &[SyntaxKind::KwEndWhile][..],
+                "WHILE",
+            ),
+            SyntaxKind::RepeatStmt => (
+                node.clone(),
+                SyntaxKind::KwRepeat,
+                &[SyntaxKind::KwUntil][..],
+                "REPEAT",
+            ),
+            SyntaxKind::CaseBranch => (
+                parent_scope(&node),
+                SyntaxKind::Colon,
+                &[][..],
+                "CASE branch",
+            ),
+            _ => continue,
+        };
+        let Some(body) = block_body(&node, opener, closers) else {
+            continue;
+        };
+        if body
+            .statements
+            .iter()
+            .any(|stmt| stmt.kind() != SyntaxKind::EmptyStmt)
+        {
+            continue;
+        }
+        if has_comment_within(&scope, body.range) {
+            continue;
+        }
+        diagnostics.warning(
+            DiagnosticCode::EmptyBlock,
+            body.opener,
+            format!("empty {label} block; add a comment if this is intentional"),
+        );
+    }
+}
+
+#[derive(Debug)]
+struct BlockBody {
+    opener: TextRange,
+    range: TextRange,
+    statements: Vec<SyntaxNode>,
+}
+
+/// Statements between `opener` and the first of `closers` (or the end of `node`).
+fn block_body(node: &SyntaxNode, opener: SyntaxKind, closers: &[SyntaxKind]) -> Option<BlockBody> {
+    let mut elements = node.children_with_tokens();
+    let opener = elements
+        .by_ref()
+        .filter_map(|element| element.into_token())
+        .find(|token| token.kind() == opener)?;
+    let mut end = node.text_range().end();
+    let mut statements = Vec::new();
+    for element in elements {
+        if closers.contains(&element.kind()) {
+            // Leading trivia of a closing branch still belongs to this body.
+            end = match &element {
+                rowan::NodeOrToken::Node(closer) => closer
+                    .descendants_with_tokens()
+                    .filter_map(|element| element.into_token())
+                    .find(|token| !token.kind().is_trivia())
+                    .map_or(closer.text_range().end(), |token| {
+                        token.text_range().start()
+                    }),
+                rowan::NodeOrToken::Token(closer) => closer.text_range().start(),
+            };
+            break;
+        }
+        if let Some(child) = element.into_node() {
+            if is_statement_kind(child.kind()) {
+                statements.push(child);
+            }
+        }
+    }
+    Some(BlockBody {
+        opener: opener.text_range(),
+        range: TextRange::new(opener.text_range().end(), end),
+        statements,
+    })
+}
+
+/// Branch trivia may attach to a sibling branch, so comments are searched in the parent statement.
+fn parent_scope(branch: &SyntaxNode) -> SyntaxNode {
+    branch.parent().unwrap_or_else(|| branch.clone())
+}
+
+fn has_comment_within(scope: &SyntaxNode, range: TextRange) -> bool {
+    scope
+        .descendants_with_tokens()
+        .filter_map(|element| element.into_token())
+        .any(|token| token.kind().is_comment() && range.contains_range(token.text_range()))
+}
@@ -20,6 +20,56 @@ pub(in crate::db) fn resolve_pending_types_with_table(
             );
         }
     }
+    check_recursive_types_with_table(symbols, diagnostics);
+}
+
+/// Reports structs and unions declared in this file that contain themselves
+/// by value, directly or through other types. Such types have infinite size;
+/// `POINTER TO` and `REF_TO` break the cycle and are allowed.
+fn check_recursive_types_with_table(symbols: &SymbolTable, diagnostics: &mut DiagnosticBuilder) {
+    for symbol in symbols.iter() {
+        if !matches!(symbol.kind, SymbolKind::Type) || symbol.origin.is_some() {
+            continue;
+        }
+        if !matches!(
+            symbols.type_by_id(symbol.type_id),
+            Some(Type::Struct { .. } | Type::Union { .. })
+        ) {
+            continue;
+        }
+        let mut visited = FxHashSet::default();
+        if contains_by_value(symbols, symbol.type_id, symbol.type_id, &mut visited) {
+            diagnostics.error(
+                DiagnosticCode::CyclicDependency,
+                symbol.range,
+                format!(
+                    "type '{}' contains itself by value; use POINTER TO or REF_TO instead",
+                    symbol.name
+                ),
+            );
+        }
+    }
+}
+
+fn contains_by_value(
+    symbols: &SymbolTable,
+    type_id: TypeId,
+    target: TypeId,
+    visited: &mut FxHashSet<TypeId>,
+) -> bool {
+    let members: Vec<TypeId> = match symbols.type_by_id(type_id) {
+        Some(Type::Struct { fields, .. }) => fields.iter().map(|field| field.type_id).collect(),
+        Some(Type::Union { variants, .. }) => {
+            variants.iter().map(|variant| variant.type_id).collect()
+        }
+        Some(Type::Array { element, .. }) => vec![*element],
+        Some(Type::Alias { target, .. }) => vec![*target],
+        _ => return false,
+    };
+    members.into_iter().any(|member| {
+        member == target
+            || (visited.insert(member) && contains_by_value(symbols, member, target, visited))
+    })
 }
 
 pub(in crate::db) fn resolve_declared_var_types_with_project(
@@ -159,12 +209,44 @@ pub(in crate::db) fn is_type_defined_in_scope_with_table(
     symbols.lookup_type(name).is_some()
 }
 
-#[derive(Hash, PartialEq, Eq)]
-struct GlobalKey {
+/// Name under which a VAR_EXTERNAL links to a VAR_GLOBAL.
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub(in crate::db) struct GlobalKey {
     namespace: Vec<SmolStr>,
     name: SmolStr,
 }
 
+impl GlobalKey {
+    fn for_symbol(symbols: &SymbolTable, symbol: &Symbol) -> Self {
+        Self {
+            namespace: namespace_path_for_symbol(symbols, symbol.id),
+            name: normalized_name(symbol.name.as_str()),
+        }
+    }
+}
+
+/// The globals a file declares and the VAR_EXTERNALs it links, keyed the way
+/// `check_global_external_links_with_project` matches them.
+pub(in crate::db) fn global_link_keys(
+    symbols: &SymbolTable,
+) -> (FxHashSet<GlobalKey>, FxHashSet<GlobalKey>) {
+    let mut globals = FxHashSet::default();
+    let mut externals = FxHashSet::default();
+    for symbol in symbols.iter().filter(|symbol| symbol.origin.is_none()) {
+        if is_global_symbol(symbols, symbol) {
+            globals.insert(GlobalKey::for_symbol(symbols, symbol));
+        } else if matches!(
+            symbol.kind,
+            SymbolKind::Variable {
+                qualifier: VarQualifier::External
+            }
+        ) {
+            externals.insert(GlobalKey::for_symbol(symbols, symbol));
+        }
+    }
+    (globals, externals)
+}
+
 struct GlobalInfo {
     type_id: TypeId,
     is_constant: bool,
@@ -183,11 +265,7 @@ pub(in crate::db) fn check_global_external_links_with_project(
         if !is_global_symbol(symbols, symbol) {
             continue;
         }
-        let namespace = namespace_path_for_symbol(symbols, symbol.id);
-        let key = GlobalKey {
-            namespace,
-            name: normalized_name(symbol.name.as_str()),
-        };
+        let key = GlobalKey::for_symbol(symbols, symbol);
         let is_constant = matches!(symbol.kind, SymbolKind::Constant);
         let origin = symbol.origin.unwrap_or(SymbolOrigin {
             file_id,
@@ -213,7 +291,9 @@ pub(in crate::db) fn check_global_external_links_with_project(
         }
         let is_constant = var_block_is_constant(&block);
         for var_decl in block.children().filter(|n| n.kind() == SyntaxKind::VarDecl) {
-            let has_initializer = var_decl.children().any(|n| is_expression_kind(n.kind()));
+            let has_initializer = var_decl
+                .children()
+                .any(|n| is_expression_kind(n.kind()) || n.kind() == SyntaxKind::ArrayInitializer);
             for name_node in var_decl.children().filter(|n| n.kind() == SyntaxKind::Name) {
                 let Some((name, range)) = name_from_node(&name_node) else {
                     continue;
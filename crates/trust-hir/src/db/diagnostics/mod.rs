@@ -1,9 +1,12 @@
+mod address_overlap;
 mod configuration;
 mod context;
+mod empty_blocks;
 mod expression;
 mod globals;
 mod nondeterminism;
 mod oop;
+mod sfc_pragmas;
 mod shared_globals;
 mod type_check;
 mod unreachable;
@@ -13,22 +16,27 @@ mod using;
 #[cfg(test)]
 mod tests;
 
+pub(super) use address_overlap::check_direct_address_overlaps;
 pub(super) use complexity::check_cyclomatic_complexity;
 pub(super) use configuration::check_configuration_semantics;
 pub(super) use context::{expression_context, is_pou_kind};
+pub(super) use empty_blocks::check_empty_blocks;
 pub(super) use expression::{expression_by_id, expression_id_at_offset, is_expression_kind};
 pub(super) use globals::{
-    check_global_external_links_with_project, resolve_declared_var_types_with_project,
-    resolve_pending_types_with_table,
+    check_global_external_links_with_project, global_link_keys,
+    resolve_declared_var_types_with_project, resolve_pending_types_with_table, GlobalKey,
 };
 pub(super) use nondeterminism::check_nondeterminism;
 pub(super) use oop::{
     check_abstract_instantiations, check_class_semantics, check_extends_implements_semantics,
     check_interface_conformance, check_property_accessors,
 };
+pub(super) use sfc_pragmas::check_sfc_pragmas;
 pub(super) use shared_globals::check_shared_global_task_hazards;
 pub(super) use type_check::type_check_file;
 pub(super) use unreachable::check_unreachable_statements;
-pub(super) use unused::{add_unused_symbol_warnings, collect_used_symbols};
+pub(super) use unused::{
+    add_unused_symbol_warnings, collect_pou_references, collect_used_symbols, PouReferences,
+};
 pub(super) use using::check_using_directives;
 mod complexity;
@@ -8,6 +8,7 @@ use crate::diagnostics::Diagnostic;
 
 pub(in crate::db) fn check_interface_conformance(
     symbols: &SymbolTable,
+    children: &FxHashMap<SymbolId, Vec<SymbolId>>,
     root: &SyntaxNode,
     diagnostics: &mut DiagnosticBuilder,
 ) {
@@ -32,7 +33,7 @@ pub(in crate::db) fn check_interface_conformance(
         };
 
         let (impl_methods, impl_properties) =
-            collect_implementation_members_with_table(symbols, owner_id);
+            collect_implementation_members_with_table(symbols, children, owner_id);
 
         for (iface_parts, iface_range) in implements_clause_names(&clause) {
             let iface_name = qualified_name_string(&iface_parts);
@@ -58,7 +59,7 @@ pub(in crate::db) fn check_interface_conformance(
                 continue;
             }
 
-            let members = collect_interface_members_with_table(symbols, interface_id);
+            let members = collect_interface_members_with_table(symbols, children, interface_id);
             let context = InterfaceCheckContext {
                 owner_name: owner_name.as_str(),
                 interface_name: iface_name.as_str(),
@@ -85,6 +86,7 @@ pub(in crate::db) fn check_interface_conformance(
 
 fn collect_interface_members_with_table(
     symbols: &SymbolTable,
+    children: &FxHashMap<SymbolId, Vec<SymbolId>>,
     interface_id: SymbolId,
 ) -> InterfaceMembers {
     let mut methods = FxHashMap::default();
@@ -97,10 +99,7 @@ fn collect_interface_members_with_table(
             continue;
         }
 
-        for sym in symbols.iter() {
-            if sym.parent != Some(symbol_id) {
-                continue;
-            }
+        for sym in super::members_of(symbols, children, symbol_id) {
             match sym.kind {
                 SymbolKind::Method { .. } => {
                     let key = normalize_member_name(sym.name.as_str());
@@ -146,6 +145,7 @@ fn collect_interface_members_with_table(
 
 fn collect_implementation_members_with_table(
     symbols: &SymbolTable,
+    children: &FxHashMap<SymbolId, Vec<SymbolId>>,
     owner_id: SymbolId,
 ) -> (
     FxHashMap<SmolStr, MethodSignature>,
@@ -161,10 +161,7 @@ fn collect_implementation_members_with_table(
             break;
         }
 
-        for sym in symbols.iter() {
-            if sym.parent != Some(symbol_id) {
-                continue;
-            }
+        for sym in super::members_of(symbols, children, symbol_id) {
             match sym.kind {
                 SymbolKind::Method { .. } => {
                     let key = normalize_member_name(sym.name.as_str());
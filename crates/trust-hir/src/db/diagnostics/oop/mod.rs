@@ -20,8 +20,23 @@ pub(super) fn visibility_label(visibility: Visibility) -> &'static str {
     }
 }
 
+/// Symbols declared directly in `owner`, using an index from
+/// [`SymbolTable::children_by_parent`].
+pub(super) fn members_of<'a>(
+    symbols: &'a SymbolTable,
+    children: &'a FxHashMap<SymbolId, Vec<SymbolId>>,
+    owner: SymbolId,
+) -> impl Iterator<Item = &'a Symbol> + 'a {
+    children
+        .get(&owner)
+        .into_iter()
+        .flatten()
+        .filter_map(|id| symbols.get(*id))
+}
+
 pub(in crate::db) fn check_class_semantics(
     symbols: &SymbolTable,
+    children: &FxHashMap<SymbolId, Vec<SymbolId>>,
     root: &SyntaxNode,
     diagnostics: &mut DiagnosticBuilder,
 ) {
@@ -37,16 +52,12 @@ pub(in crate::db) fn check_class_semantics(
             continue;
         };
 
-        let declared_methods: Vec<SymbolId> = symbols
-            .iter()
-            .filter(|sym| sym.parent == Some(class_id))
+        let declared_methods: Vec<SymbolId> = members_of(symbols, children, class_id)
             .filter(|sym| matches!(sym.kind, SymbolKind::Method { .. }))
             .map(|sym| sym.id)
             .collect();
 
-        let declared_vars: Vec<SymbolId> = symbols
-            .iter()
-            .filter(|sym| sym.parent == Some(class_id))
+        let declared_vars: Vec<SymbolId> = members_of(symbols, children, class_id)
             .filter(|sym| matches!(sym.kind, SymbolKind::Variable { .. } | SymbolKind::Constant))
             .map(|sym| sym.id)
             .collect();
@@ -96,8 +107,8 @@ pub(in crate::db) fn check_class_semantics(
             }
         }
 
-        let inherited_vars = collect_inherited_variables(symbols, class_id);
-        let inherited_methods = collect_inherited_methods(symbols, class_id);
+        let inherited_vars = collect_inherited_variables(symbols, children, class_id);
+        let inherited_methods = collect_inherited_methods(symbols, children, class_id);
 
         shadowing::check_member_shadowing(
             symbols,
@@ -366,6 +377,7 @@ pub(in crate::db) fn function_block_inheritance_cycle(
 
 pub(in crate::db) fn collect_inherited_variables(
     symbols: &SymbolTable,
+    children: &FxHashMap<SymbolId, Vec<SymbolId>>,
     class_id: SymbolId,
 ) -> FxHashMap<SmolStr, SymbolId> {
     let mut vars = FxHashMap::default();
@@ -375,10 +387,7 @@ pub(in crate::db) fn collect_inherited_variables(
         if !visited.insert(base_id) {
             break;
         }
-        for sym in symbols.iter() {
-            if sym.parent != Some(base_id) {
-                continue;
-            }
+        for sym in members_of(symbols, children, base_id) {
             if !matches!(sym.kind, SymbolKind::Variable { .. } | SymbolKind::Constant) {
                 continue;
             }
@@ -395,6 +404,7 @@ pub(in crate::db) fn collect_inherited_variables(
 
 pub(in crate::db) fn collect_inherited_methods(
     symbols: &SymbolTable,
+    children: &FxHashMap<SymbolId, Vec<SymbolId>>,
     class_id: SymbolId,
 ) -> FxHashMap<SmolStr, SymbolId> {
     let mut methods = FxHashMap::default();
@@ -404,10 +414,7 @@ pub(in crate::db) fn collect_inherited_methods(
         if !visited.insert(base_id) {
             break;
         }
-        for sym in symbols.iter() {
-            if sym.parent != Some(base_id) {
-                continue;
-            }
+        for sym in members_of(symbols, children, base_id) {
             if !matches!(sym.kind, SymbolKind::Method { .. }) {
                 continue;
             }
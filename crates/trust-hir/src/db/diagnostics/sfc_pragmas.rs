@@ -0,0 +1,267 @@
+use super::super::*;
+use trust_syntax::syntax::{parse_pragma, Pragma};
+
+/// Checks SFC step/transition annotation pragmas for consistency.
+///
+/// Annotations are written as pragmas inside a PROGRAM or FUNCTION_BLOCK:
+/// `{INITIAL_STEP Start NEXT T1}`, `{STEP Fill NEXT T2, T3}` and
+/// `{TRANSITION T1 TO Fill}`. Other pragmas are ignored.
+pub(in crate::db) fn check_sfc_pragmas(root: &SyntaxNode, diagnostics: &mut DiagnosticBuilder) {
+    for pou in root
+        .descendants()
+        .filter(|node| matches!(node.kind(), SyntaxKind::Program | SyntaxKind::FunctionBlock))
+    {
+        let mut chart = SfcChart::default();
+        for pragma in pou
+            .descendants_with_tokens()
+            .filter_map(|element| element.into_token())
+            .filter_map(|token| parse_pragma(&token))
+        {
+            match parse_sfc_pragma(&pragma) {
+                Some(Ok(annotation)) => chart.add(annotation, pragma.range, diagnostics),
+                Some(Err(message)) => diagnostics.warning(
+                    DiagnosticCode::SfcPragmaInconsistency,
+                    pragma.range,
+                    message,
+                ),
+                None => {}
+            }
+        }
+        chart.check(diagnostics);
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum SfcAnnotation {
+    Step {
+        name: SmolStr,
+        initial: bool,
+        next: Vec<SmolStr>,
+    },
+    Transition {
+        name: SmolStr,
+        target: SmolStr,
+    },
+}
+
+/// Interprets a pragma as an SFC annotation; `None` means it is not one.
+fn parse_sfc_pragma(pragma: &Pragma) -> Option<Result<SfcAnnotation, String>> {
+    let keyword = pragma.name.to_ascii_uppercase();
+    if !matches!(keyword.as_str(), "INITIAL_STEP" | "STEP" | "TRANSITION") {
+        return None;
+    }
+    if let Some(arg) = pragma.args.iter().find(|arg| arg.value.is_some()) {
+        return Some(Err(format!(
+            "malformed SFC pragma; unexpected ':=' after '{}'",
+            arg.key
+        )));
+    }
+    let mut words = pragma.args.iter().map(|arg| arg.key.as_str());
+    let initial = match keyword.as_str() {
+        "INITIAL_STEP" => true,
+        "STEP" => false,
+        "TRANSITION" => {
+            return Some(
+                match (words.next(), words.next(), words.next(), words.next()) {
+                    (Some(name), Some(to), Some(target), None) if to.eq_ignore_ascii_case("TO") => {
+                        Ok(SfcAnnotation::Transition {
+                            name: SmolStr::new(name),
+                            target: SmolStr::new(target),
+                        })
+                    }
+                    _ => {
+                        Err("malformed SFC pragma; expected '{TRANSITION <name> TO <step>}'".into())
+                    }
+                },
+            );
+        }
+        _ => return None,
+    };
+    let Some(name) = words.next() else {
+        return Some(Err(format!(
+            "malformed SFC pragma; expected '{{{keyword} <name> [NEXT <transition>, ...]}}'"
+        )));
+    };
+    let next = match words.next() {
+        None => Vec::new(),
+        Some(word) if word.eq_ignore_ascii_case("NEXT") => {
+            let next: Vec<_> = words.map(SmolStr::new).collect();
+            if next.is_empty() {
+                return Some(Err(format!(
+                    "SFC step '{name}' has NEXT without any transition"
+                )));
+            }
+            next
+        }
+        Some(word) => {
+            return Some(Err(format!(
+                "malformed SFC pragma; unexpected '{word}' after step '{name}'"
+            )))
+        }
+    };
+    Some(Ok(SfcAnnotation::Step {
+        name: SmolStr::new(name),
+        initial,
+        next,
+    }))
+}
+
+#[derive(Debug)]
+struct SfcStep {
+    name: SmolStr,
+    range: TextRange,
+    next: Vec<SmolStr>,
+}
+
+#[derive(Debug)]
+struct SfcTransition {
+    name: SmolStr,
+    target: SmolStr,
+    range: TextRange,
+}
+
+/// Steps and transitions of one POU; names are indexed upper-cased.
+#[derive(Debug, Default)]
+struct SfcChart {
+    steps: Vec<SfcStep>,
+    step_index: FxHashMap<SmolStr, usize>,
+    transitions: Vec<SfcTransition>,
+    transition_index: FxHashMap<SmolStr, usize>,
+    initial: Option<usize>,
+    first_range: Option<TextRange>,
+}
+
+impl SfcChart {
+    fn add(
+        &mut self,
+        annotation: SfcAnnotation,
+        range: TextRange,
+        diagnostics: &mut DiagnosticBuilder,
+    ) {
+        self.first_range.get_or_insert(range);
+        match annotation {
+            SfcAnnotation::Step {
+                name,
+                initial,
+                next,
+            } => {
+                let key = upper(&name);
+                if self.step_index.contains_key(&key) {
+                    diagnostics.warning(
+                        DiagnosticCode::SfcPragmaInconsistency,
+                        range,
+                        format!("duplicate SFC step '{name}'"),
+                    );
+                    return;
+                }
+                if initial {
+                    if self.initial.is_some() {
+                        diagnostics.warning(
+                            DiagnosticCode::SfcPragmaInconsistency,
+                            range,
+                            format!("SFC step '{name}' is a second INITIAL_STEP"),
+                        );
+                    } else {
+                        self.initial = Some(self.steps.len());
+                    }
+                }
+                self.step_index.insert(key, self.steps.len());
+                self.steps.push(SfcStep { name, range, next });
+            }
+            SfcAnnotation::Transition { name, target } => {
+                let key = upper(&name);
+                if self.transition_index.contains_key(&key) {
+                    diagnostics.warning(
+                        DiagnosticCode::SfcPragmaInconsistency,
+                        range,
+                        format!("duplicate SFC transition '{name}'"),
+                    );
+                    return;
+                }
+                self.transition_index.insert(key, self.transitions.len());
+                self.transitions.push(SfcTransition {
+                    name,
+                    target,
+                    range,
+                });
+            }
+        }
+    }
+
+    fn check(&self, diagnostics: &mut DiagnosticBuilder) {
+        let Some(first_range) = self.first_range else {
+            return;
+        };
+        for step in &self.steps {
+            for transition in &step.next {
+                if !self.transition_index.contains_key(&upper(transition)) {
+                    diagnostics.warning(
+                        DiagnosticCode::SfcPragmaInconsistency,
+                        step.range,
+                        format!(
+                            "SFC step '{}' references undefined transition '{}'",
+                            step.name, transition
+                        ),
+                    );
+                }
+            }
+        }
+        for transition in &self.transitions {
+            if !self.step_index.contains_key(&upper(&transition.target)) {
+                diagnostics.warning(
+                    DiagnosticCode::SfcPragmaInconsistency,
+                    transition.range,
+                    format!(
+                        "SFC transition '{}' targets undefined step '{}'",
+                        transition.name, transition.target
+                    ),
+                );
+            }
+        }
+
+        let Some(initial) = self.initial else {
+            if !self.steps.is_empty() {
+                diagnostics.warning(
+                    DiagnosticCode::SfcPragmaInconsistency,
+                    first_range,
+                    "SFC steps are annotated but no INITIAL_STEP is declared",
+                );
+            }
+            return;
+        };
+        let mut reached = FxHashSet::default();
+        let mut pending = vec![initial];
+        while let Some(index) = pending.pop() {
+            if !reached.insert(index) {
+                continue;
+            }
+            pending.extend(
+                self.steps[index]
+                    .next
+                    .iter()
+                    .filter_map(|transition| self.transition_index.get(&upper(transition)))
+                    .filter_map(|&transition| {
+                        self.step_index
+                            .get(&upper(&self.transitions[transition].target))
+                    })
+                    .copied(),
+            );
+        }
+        for (index, step) in self.steps.iter().enumerate() {
+            if !reached.contains(&index) {
+                diagnostics.warning(
+                    DiagnosticCode::SfcPragmaInconsistency,
+                    step.range,
+                    format!(
+                        "SFC step '{}' is not reachable from initial step '{}'",
+                        step.name, self.steps[initial].name
+                    ),
+                );
+            }
+        }
+    }
+}
+
+fn upper(name: &str) -> SmolStr {
+    SmolStr::new(name.to_ascii_uppercase())
+}
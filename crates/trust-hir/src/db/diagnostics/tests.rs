@@ -56,3 +56,44 @@ fn test_type_of_cache_invalidates_on_change() {
     let expr_type = db.type_of(file, expr_id);
     assert_eq!(expr_type, TypeId::REAL);
 }
+
+#[test]
+fn test_disabled_complexity_pass_is_skipped() {
+    use super::complexity::COMPLEXITY_PASS_RUNS;
+
+    let mut body = String::new();
+    for idx in 0..16 {
+        body.push_str(&format!("IF x > {idx} THEN x := {idx}; END_IF;\n"));
+    }
+    let source = format!("PROGRAM Test\nVAR x : INT; unused : INT; END_VAR\n{body}END_PROGRAM\n");
+    let mut db = Database::new();
+    let file = FileId(0);
+    db.set_source_text(file, source);
+    let has_code = |diagnostics: &[Diagnostic], code: DiagnosticCode| {
+        diagnostics.iter().any(|diagnostic| diagnostic.code == code)
+    };
+
+    COMPLEXITY_PASS_RUNS.with(|runs| runs.set(0));
+    let diagnostics = db.diagnostics(file);
+    assert!(has_code(&diagnostics, DiagnosticCode::HighComplexity));
+    assert!(has_code(&diagnostics, DiagnosticCode::UnusedVariable));
+    assert_eq!(COMPLEXITY_PASS_RUNS.with(|runs| runs.get()), 1);
+
+    db.set_diagnostic_passes(DiagnosticPasses::all().without(DiagnosticPass::Complexity));
+    COMPLEXITY_PASS_RUNS.with(|runs| runs.set(0));
+    let diagnostics = db.diagnostics(file);
+    assert!(!has_code(&diagnostics, DiagnosticCode::HighComplexity));
+    assert!(
+        has_code(&diagnostics, DiagnosticCode::UnusedVariable),
+        "other passes should be unaffected"
+    );
+    assert_eq!(
+        COMPLEXITY_PASS_RUNS.with(|runs| runs.get()),
+        0,
+        "a disabled pass should not run at all"
+    );
+
+    db.set_diagnostic_passes(DiagnosticPasses::all());
+    let diagnostics = db.diagnostics(file);
+    assert!(has_code(&diagnostics, DiagnosticCode::HighComplexity));
+}
@@ -53,6 +53,7 @@ pub(in crate::db) fn type_check_pou(
     checker.set_return_type(context.return_type);
     checker.set_receiver_types(context.this_type, context.super_type);
     checker.set_current_pou(context.symbol_id);
+    checker.check_var_initializers(node);
 
     // Find and check all statements in the POU body
     for stmt_list in node
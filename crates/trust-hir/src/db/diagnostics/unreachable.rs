@@ -1,11 +1,13 @@
 use super::super::*;
+use crate::type_check::const_eval::{fold_with_symbols, ConstValue};
 
 pub(in crate::db) fn check_unreachable_statements(
+    symbols: &SymbolTable,
     root: &SyntaxNode,
     diagnostics: &mut DiagnosticBuilder,
 ) {
     check_unreachable_after_terminators(root, diagnostics);
-    check_constant_if_branches(root, diagnostics);
+    check_constant_if_branches(symbols, root, diagnostics);
 }
 
 fn check_unreachable_after_terminators(root: &SyntaxNode, diagnostics: &mut DiagnosticBuilder) {
@@ -30,12 +32,17 @@ fn check_unreachable_after_terminators(root: &SyntaxNode, diagnostics: &mut Diag
     }
 }
 
-fn check_constant_if_branches(root: &SyntaxNode, diagnostics: &mut DiagnosticBuilder) {
+fn check_constant_if_branches(
+    symbols: &SymbolTable,
+    root: &SyntaxNode,
+    diagnostics: &mut DiagnosticBuilder,
+) {
     for if_stmt in root
         .descendants()
         .filter(|node| node.kind() == SyntaxKind::IfStmt)
     {
-        let condition = first_expression_child(&if_stmt).and_then(|node| const_bool_expr(&node));
+        let condition =
+            first_expression_child(&if_stmt).and_then(|node| const_bool_expr(symbols, &node));
         let (then_stmts, branches) = collect_if_branches(&if_stmt);
         if matches!(condition, Some(false)) {
             mark_unreachable_statements(&then_stmts, diagnostics);
@@ -51,7 +58,7 @@ fn check_constant_if_branches(root: &SyntaxNode, diagnostics: &mut DiagnosticBui
             }
 
             if let Some(expr) = branch.condition.as_ref() {
-                match const_bool_expr(expr) {
+                match const_bool_expr(symbols, expr) {
                     Some(false) => {
                         mark_unreachable_statements(&branch.statements, diagnostics);
                     }
@@ -107,7 +114,7 @@ fn is_expression_kind(kind: SyntaxKind) -> bool {
     )
 }
 
-fn is_statement_kind(kind: SyntaxKind) -> bool {
+pub(super) fn is_statement_kind(kind: SyntaxKind) -> bool {
     matches!(
         kind,
         SyntaxKind::AssignStmt
@@ -115,6 +122,7 @@ fn is_statement_kind(kind: SyntaxKind) -> bool {
             | SyntaxKind::ForStmt
             | SyntaxKind::WhileStmt
             | SyntaxKind::RepeatStmt
+            | SyntaxKind::TryStmt
             | SyntaxKind::CaseStmt
             | SyntaxKind::ReturnStmt
             | SyntaxKind::ExprStmt
@@ -126,83 +134,15 @@ fn is_statement_kind(kind: SyntaxKind) -> bool {
     )
 }
 
-fn const_bool_expr(node: &SyntaxNode) -> Option<bool> {
-    match node.kind() {
-        SyntaxKind::Literal => parse_bool_literal(node),
-        SyntaxKind::ParenExpr => node
-            .children()
-            .find(|child| is_expression_kind(child.kind()))
-            .and_then(|child| const_bool_expr(&child)),
-        SyntaxKind::UnaryExpr => {
-            let is_not = node
-                .children_with_tokens()
-                .filter_map(|child| child.into_token())
-                .any(|token| token.text().eq_ignore_ascii_case("NOT"));
-            if !is_not {
-                return None;
-            }
-            let expr = node
-                .children()
-                .find(|child| is_expression_kind(child.kind()))?;
-            const_bool_expr(&expr).map(|value| !value)
-        }
-        SyntaxKind::BinaryExpr => {
-            let op = bool_binary_op(node)?;
-            let mut exprs = node
-                .children()
-                .filter(|child| is_expression_kind(child.kind()));
-            let lhs = exprs.next().and_then(|child| const_bool_expr(&child))?;
-            let rhs = exprs
-                .last()
-                .and_then(|child| const_bool_expr(&child))
-                .unwrap_or(lhs);
-            Some(match op {
-                BoolBinaryOp::And => lhs && rhs,
-                BoolBinaryOp::Or => lhs || rhs,
-                BoolBinaryOp::Xor => lhs ^ rhs,
-            })
-        }
+/// Folds an IF/ELSIF condition, including references to `CONSTANT`
+/// variables and comparisons of enumeration values.
+fn const_bool_expr(symbols: &SymbolTable, node: &SyntaxNode) -> Option<bool> {
+    match fold_with_symbols(symbols, node)? {
+        ConstValue::Bool(value) => Some(value),
         _ => None,
     }
 }
 
-fn parse_bool_literal(node: &SyntaxNode) -> Option<bool> {
-    let text = node.text().to_string();
-    if text.trim().eq_ignore_ascii_case("TRUE") {
-        Some(true)
-    } else if text.trim().eq_ignore_ascii_case("FALSE") {
-        Some(false)
-    } else {
-        None
-    }
-}
-
-fn bool_binary_op(node: &SyntaxNode) -> Option<BoolBinaryOp> {
-    for token in node
-        .children_with_tokens()
-        .filter_map(|child| child.into_token())
-    {
-        let text = token.text();
-        if text.eq_ignore_ascii_case("AND") {
-            return Some(BoolBinaryOp::And);
-        }
-        if text.eq_ignore_ascii_case("OR") {
-            return Some(BoolBinaryOp::Or);
-        }
-        if text.eq_ignore_ascii_case("XOR") {
-            return Some(BoolBinaryOp::Xor);
-        }
-    }
-    None
-}
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum BoolBinaryOp {
-    And,
-    Or,
-    Xor,
-}
-
 #[derive(Debug)]
 struct IfBranch {
     kind: IfBranchKind,
@@ -1,6 +1,9 @@
 use super::super::queries::*;
 use super::super::*;
-use super::context::{expression_context, PouContext};
+use super::context::{
+    expression_context, find_symbol_by_name_range, resolve_type_symbol_by_name_in_scope, PouContext,
+};
+use super::oop::resolve_extends_symbol;
 
 pub(in crate::db) fn add_unused_symbol_warnings(
     symbols: &SymbolTable,
@@ -31,9 +34,254 @@ pub(in crate::db) fn collect_used_symbols(
 ) -> FxHashSet<SymbolId> {
     let mut used = FxHashSet::default();
     let program_instances = collect_program_instances(symbols, root);
-    for symbol_id in program_instances.values() {
-        used.insert(*symbol_id);
+    used.extend(program_instances.values().copied());
+    visit_symbol_references(symbols, root, &program_instances, |_, symbol_id| {
+        used.insert(symbol_id);
+    });
+    used
+}
+
+/// Attribute pragma values that keep a POU alive without any reference.
+///
+/// `hide` marks vendor library internals; `export` and `linkalways` mark POUs
+/// a library exposes to projects that are not part of this workspace.
+const KEEP_ALIVE_ATTRIBUTES: &[&str] = &["hide", "export", "linkalways"];
+
+/// Reference graph contribution of a single file, keyed by the declaring file
+/// of each top-level declaration.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(in crate::db) struct PouReferences {
+    /// Declarations reachable without a reference: programs, configurations,
+    /// marked POUs and anything referenced outside a POU body.
+    pub(in crate::db) roots: Vec<(FileId, SymbolId)>,
+    /// Functions, function blocks and classes declared in the file.
+    pub(in crate::db) candidates: Vec<(FileId, SymbolId)>,
+    /// References from one top-level declaration to another.
+    pub(in crate::db) edges: Vec<((FileId, SymbolId), (FileId, SymbolId))>,
+    /// Programs, POUs, interfaces and types declared in the file.
+    pub(in crate::db) declarations: Vec<(FileId, SymbolId)>,
+    /// Compile-time dependencies of a declaration: types its layout or
+    /// signature uses by value and its EXTENDS/IMPLEMENTS bases. Self
+    /// dependencies are kept so recursive types show up as cycles.
+    pub(in crate::db) declaration_edges: Vec<((FileId, SymbolId), (FileId, SymbolId))>,
+}
+
+pub(in crate::db) fn collect_pou_references(
+    symbols: &SymbolTable,
+    root: &SyntaxNode,
+    file_id: FileId,
+) -> PouReferences {
+    let key = |symbol_id: SymbolId| {
+        let owner = top_level_owner(symbols, symbol_id);
+        let symbol = symbols.get(owner)?;
+        Some(symbol.origin.map_or((file_id, owner), |origin| {
+            (origin.file_id, origin.symbol_id)
+        }))
+    };
+
+    let mut references = PouReferences::default();
+    for symbol in symbols.iter() {
+        if symbol.origin.is_some()
+            || symbol.range.is_empty()
+            || top_level_owner(symbols, symbol.id) != symbol.id
+        {
+            continue;
+        }
+        if matches!(
+            symbol.kind,
+            SymbolKind::Program
+                | SymbolKind::Function { .. }
+                | SymbolKind::FunctionBlock
+                | SymbolKind::Class
+                | SymbolKind::Interface
+                | SymbolKind::Type
+        ) {
+            references.declarations.push((file_id, symbol.id));
+            for base in declared_bases(symbols, symbol.id) {
+                if let Some(to) = key(base) {
+                    references
+                        .declaration_edges
+                        .push(((file_id, symbol.id), to));
+                }
+            }
+        }
+        match symbol.kind {
+            SymbolKind::Program | SymbolKind::Configuration => {
+                references.roots.push((file_id, symbol.id));
+            }
+            SymbolKind::Function { .. } | SymbolKind::FunctionBlock | SymbolKind::Class => {
+                references.candidates.push((file_id, symbol.id));
+            }
+            _ => {}
+        }
+    }
+    for pou in root.descendants().filter(|node| {
+        matches!(
+            node.kind(),
+            SyntaxKind::Function | SyntaxKind::FunctionBlock | SyntaxKind::Class
+        )
+    }) {
+        if !has_keep_alive_attribute(&pou) {
+            continue;
+        }
+        if let Some(symbol_id) = name_from_node(&pou)
+            .and_then(|(name, range)| find_symbol_by_name_range(symbols, name.as_str(), range))
+        {
+            references.roots.push((file_id, symbol_id));
+        }
+    }
+
+    let program_instances = collect_program_instances(symbols, root);
+    references
+        .roots
+        .extend(program_instances.values().copied().filter_map(key));
+    visit_symbol_references(symbols, root, &program_instances, |node, symbol_id| {
+        let Some(to) = key(symbol_id) else {
+            return;
+        };
+        if let Some(from) = declaration_owner(symbols, node).and_then(key) {
+            references.declaration_edges.push((from, to));
+        }
+        match expression_context(symbols, node).symbol_id.and_then(key) {
+            Some(from) if from != to => references.edges.push((from, to)),
+            Some(_) => {}
+            None => references.roots.push(to),
+        }
+    });
+
+    for list in [
+        &mut references.roots,
+        &mut references.candidates,
+        &mut references.declarations,
+    ] {
+        list.sort_unstable_by_key(|&(file, symbol)| (file.0, symbol.0));
+        list.dedup();
     }
+    for edges in [&mut references.edges, &mut references.declaration_edges] {
+        edges.sort_unstable_by_key(|&((from_file, from), (to_file, to))| {
+            (from_file.0, from.0, to_file.0, to.0)
+        });
+        edges.dedup();
+    }
+    references
+}
+
+/// Resolves the EXTENDS base and IMPLEMENTS interfaces of a declaration.
+fn declared_bases(symbols: &SymbolTable, symbol_id: SymbolId) -> Vec<SymbolId> {
+    let scope_id = symbols
+        .scope_for_owner(symbol_id)
+        .unwrap_or(ScopeId::GLOBAL);
+    resolve_extends_symbol(symbols, symbol_id)
+        .into_iter()
+        .chain(
+            symbols
+                .implements_names(symbol_id)
+                .unwrap_or_default()
+                .iter()
+                .filter_map(|name| {
+                    resolve_type_symbol_by_name_in_scope(symbols, name.as_str(), scope_id)
+                }),
+        )
+        .collect()
+}
+
+/// Returns the top-level declaration whose layout or signature uses a type
+/// reference by value.
+///
+/// References behind `POINTER TO`/`REF_TO`, in statement bodies and in
+/// method or property declarations do not constrain the build order.
+fn declaration_owner(symbols: &SymbolTable, node: &SyntaxNode) -> Option<SymbolId> {
+    if node.kind() != SyntaxKind::TypeRef
+        || node.descendants().any(|child| {
+            matches!(
+                child.kind(),
+                SyntaxKind::PointerType | SyntaxKind::ReferenceType
+            )
+        })
+    {
+        return None;
+    }
+    for ancestor in node.ancestors().skip(1) {
+        match ancestor.kind() {
+            SyntaxKind::PointerType
+            | SyntaxKind::ReferenceType
+            | SyntaxKind::StmtList
+            | SyntaxKind::Method
+            | SyntaxKind::Property
+            | SyntaxKind::Action => return None,
+            SyntaxKind::Program
+            | SyntaxKind::Function
+            | SyntaxKind::FunctionBlock
+            | SyntaxKind::Class
+            | SyntaxKind::Interface => {
+                let (name, range) = name_from_node(&ancestor)?;
+                return find_symbol_by_name_range(symbols, name.as_str(), range);
+            }
+            SyntaxKind::TypeDecl => return type_decl_entry_symbol(symbols, &ancestor, node),
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Finds the type declared by the `TYPE` block entry containing `node`.
+fn type_decl_entry_symbol(
+    symbols: &SymbolTable,
+    type_decl: &SyntaxNode,
+    node: &SyntaxNode,
+) -> Option<SymbolId> {
+    let entry = node
+        .ancestors()
+        .find(|ancestor| ancestor.parent().as_ref() == Some(type_decl))?;
+    let mut name = None;
+    for child in type_decl.children() {
+        if child == entry {
+            break;
+        }
+        if child.kind() == SyntaxKind::Name {
+            name = Some(child);
+        }
+    }
+    let (name, range) = name_from_node(&name?)?;
+    find_symbol_by_name_range(symbols, name.as_str(), range)
+}
+
+/// Returns the declaration a symbol belongs to at file or namespace level,
+/// e.g. the function block owning a method or local variable.
+fn top_level_owner(symbols: &SymbolTable, symbol_id: SymbolId) -> SymbolId {
+    let mut current = symbol_id;
+    while let Some(parent) = symbols.get(current).and_then(|symbol| symbol.parent) {
+        if symbols
+            .get(parent)
+            .is_none_or(|symbol| matches!(symbol.kind, SymbolKind::Namespace))
+        {
+            break;
+        }
+        current = parent;
+    }
+    current
+}
+
+/// Checks for a keep-alive attribute in front of the POU or after its name.
+fn has_keep_alive_attribute(pou: &SyntaxNode) -> bool {
+    attribute_pragmas(pou)
+        .into_iter()
+        .flat_map(|pragma| pragma.args)
+        .any(|arg| {
+            KEEP_ALIVE_ATTRIBUTES
+                .iter()
+                .any(|keep| arg.key.eq_ignore_ascii_case(keep))
+        })
+}
+
+/// Calls `visit` with every name or type reference in the file that resolves
+/// to a symbol, including configuration initializer targets.
+fn visit_symbol_references(
+    symbols: &SymbolTable,
+    root: &SyntaxNode,
+    program_instances: &FxHashMap<SmolStr, SymbolId>,
+    mut visit: impl FnMut(&SyntaxNode, SymbolId),
+) {
     for node in root
         .descendants()
         .filter(|n| n.kind() == SyntaxKind::NameRef)
@@ -46,7 +294,7 @@ pub(in crate::db) fn collect_used_symbols(
             if is_self_pou_reference(symbols, symbol_id, &context) {
                 continue;
             }
-            used.insert(symbol_id);
+            visit(&node, symbol_id);
         }
     }
     for type_ref in root
@@ -61,9 +309,11 @@ pub(in crate::db) fn collect_used_symbols(
             continue;
         }
         let scope_id = expression_context(symbols, &type_ref).scope_id;
+        // A variable may share its type's name, as in `motor : Motor`.
         let symbol_id = if type_parts.len() == 1 {
             symbols
                 .resolve(type_parts[0].as_str(), scope_id)
+                .filter(|&id| symbols.get(id).is_some_and(|symbol| symbol.is_type()))
                 .or_else(|| symbols.lookup_any(type_parts[0].as_str()))
         } else {
             symbols.resolve_qualified(&type_parts)
@@ -75,7 +325,7 @@ pub(in crate::db) fn collect_used_symbols(
             .get(symbol_id)
             .is_some_and(|symbol| symbol.is_type())
         {
-            used.insert(symbol_id);
+            visit(&type_ref, symbol_id);
         }
     }
     for config_init in root
@@ -88,12 +338,10 @@ pub(in crate::db) fn collect_used_symbols(
         else {
             continue;
         };
-        if let Some(target) = resolve_access_path_target(symbols, &program_instances, &access_path)
-        {
-            used.insert(target.symbol_id);
+        if let Some(target) = resolve_access_path_target(symbols, program_instances, &access_path) {
+            visit(&config_init, target.symbol_id);
         }
     }
-    used
 }
 
 fn is_self_pou_reference(symbols: &SymbolTable, symbol_id: SymbolId, context: &PouContext) -> bool {
@@ -9,10 +9,13 @@ use smol_str::SmolStr;
 use std::sync::Arc;
 use text_size::{TextRange, TextSize};
 
-use crate::diagnostics::{Diagnostic, DiagnosticBuilder, DiagnosticCode};
+use crate::diagnostics::{
+    Diagnostic, DiagnosticBuilder, DiagnosticCode, DiagnosticPass, DiagnosticPasses,
+    DiagnosticsSummary,
+};
 use crate::ident::{is_reserved_keyword, is_valid_identifier};
 use crate::symbols::{
-    ParamDirection, ScopeId, ScopeKind, Symbol, SymbolId, SymbolKind, SymbolModifiers,
+    ParamDirection, Retention, ScopeId, ScopeKind, Symbol, SymbolId, SymbolKind, SymbolModifiers,
     SymbolOrigin, SymbolTable, VarQualifier, Visibility,
 };
 use crate::type_check::{string_literal_info, TypeChecker};
@@ -24,4 +27,7 @@ mod diagnostics;
 mod queries;
 mod symbol_import;
 
-pub use queries::{Database, FileId, SalsaEventSnapshot, SemanticDatabase, SourceDatabase};
+pub use queries::{
+    Database, DependencyCycle, FileId, PouParameter, SalsaEventSnapshot, SemanticDatabase,
+    SourceDatabase,
+};
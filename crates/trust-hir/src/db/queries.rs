@@ -2,18 +2,22 @@ use super::*;
 use parking_lot::Mutex;
 use std::sync::atomic::{AtomicU64, Ordering};
 
+mod build_order;
 mod collector;
 mod database;
 mod helpers;
+mod parameters;
 mod salsa_backend;
 
+pub use build_order::DependencyCycle;
+pub use parameters::PouParameter;
 pub use salsa_backend::SalsaEventSnapshot;
 
 pub(super) use helpers::{
-    collect_program_instances, implements_clause_names, name_from_node, normalize_member_name,
-    program_config_instance_and_type, qualified_name_parts, qualified_name_string,
-    resolve_access_path_target, type_path_from_type_ref, var_block_is_constant,
-    var_qualifier_from_block,
+    attribute_pragmas, collect_program_instances, implements_clause_names, name_from_node,
+    normalize_member_name, program_config_instance_and_type, qualified_name_parts,
+    qualified_name_string, resolve_access_path_target, type_path_from_type_ref,
+    var_block_is_constant, var_qualifier_from_block,
 };
 
 /// A file identifier.
@@ -48,6 +52,11 @@ pub trait SemanticDatabase: SourceDatabase {
 
     /// Collect symbols + typecheck diagnostics in one pass.
     fn analyze(&self, file_id: FileId) -> Arc<FileAnalysis>;
+
+    /// Count parse and semantic diagnostics for a file.
+    fn diagnostics_summary(&self, file_id: FileId) -> DiagnosticsSummary {
+        self.analyze(file_id).summary
+    }
 }
 
 /// The main database struct.
@@ -61,6 +70,7 @@ pub struct Database {
 pub struct FileAnalysis {
     pub symbols: Arc<SymbolTable>,
     pub diagnostics: Arc<Vec<Diagnostic>>,
+    pub summary: DiagnosticsSummary,
 }
 
 impl Default for Database {
@@ -73,9 +83,13 @@ impl Default for Database {
     }
 }
 
-#[cfg(test)]
 impl Database {
-    pub(crate) fn new_with_salsa_observability() -> Self {
+    /// Creates a new empty database that always collects Salsa event counters.
+    ///
+    /// See [`Database::salsa_event_snapshot`]; the default database only
+    /// collects them when `TRUST_HIR_SALSA_EVENT_METRICS` is set.
+    #[must_use]
+    pub fn new_with_salsa_observability() -> Self {
         Self {
             sources: FxHashMap::default(),
             salsa_state: Mutex::new(salsa_backend::SalsaState::with_event_observability(
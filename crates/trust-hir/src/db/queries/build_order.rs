@@ -0,0 +1,116 @@
+//! Dependency-ordered build plans over top-level declarations.
+
+use super::*;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+/// Declarations whose compile-time dependencies form a cycle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DependencyCycle {
+    /// Declarations on the cycle, in file and declaration order.
+    pub members: Vec<(FileId, SymbolId)>,
+}
+
+/// Build order of a project: every declaration after the ones it depends on,
+/// or the cycles preventing such an order.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(super) struct BuildOrder {
+    pub(super) order: Vec<(FileId, SymbolId)>,
+    pub(super) cycles: Vec<DependencyCycle>,
+}
+
+type Key = (FileId, SymbolId);
+
+fn sort_key(key: Key) -> (u32, u32) {
+    (key.0 .0, key.1 .0)
+}
+
+/// Orders `declarations` so dependencies come first, breaking ties by file and
+/// declaration order. Edges to keys outside `declarations` are ignored.
+pub(super) fn order_declarations(declarations: &[Key], edges: &[(Key, Key)]) -> BuildOrder {
+    let known: FxHashSet<Key> = declarations.iter().copied().collect();
+    let mut dependents: FxHashMap<Key, Vec<Key>> = FxHashMap::default();
+    let mut dependencies: FxHashMap<Key, Vec<Key>> = FxHashMap::default();
+    let mut pending: FxHashMap<Key, usize> = declarations.iter().map(|&key| (key, 0)).collect();
+    for &(from, to) in edges {
+        if !known.contains(&from) || !known.contains(&to) {
+            continue;
+        }
+        dependents.entry(to).or_default().push(from);
+        dependencies.entry(from).or_default().push(to);
+        *pending.entry(from).or_default() += 1;
+    }
+
+    let mut ready: BinaryHeap<Reverse<(u32, u32)>> = pending
+        .iter()
+        .filter(|(_, count)| **count == 0)
+        .map(|(key, _)| Reverse(sort_key(*key)))
+        .collect();
+    let by_sort_key: FxHashMap<(u32, u32), Key> = declarations
+        .iter()
+        .map(|&key| (sort_key(key), key))
+        .collect();
+    let mut order = Vec::with_capacity(declarations.len());
+    while let Some(Reverse(next)) = ready.pop() {
+        let key = by_sort_key[&next];
+        order.push(key);
+        for dependent in dependents.get(&key).into_iter().flatten() {
+            let count = pending.entry(*dependent).or_default();
+            *count -= 1;
+            if *count == 0 {
+                ready.push(Reverse(sort_key(*dependent)));
+            }
+        }
+    }
+
+    let placed: FxHashSet<Key> = order.iter().copied().collect();
+    let mut remaining: Vec<Key> = declarations
+        .iter()
+        .copied()
+        .filter(|key| !placed.contains(key))
+        .collect();
+    remaining.sort_unstable_by_key(|key| sort_key(*key));
+    BuildOrder {
+        order,
+        cycles: dependency_cycles(&remaining, &dependencies),
+    }
+}
+
+/// Groups the unordered declarations into cycles: strongly connected sets of
+/// two or more declarations, or a declaration depending on itself.
+/// Declarations that only depend on a cycle are not reported.
+fn dependency_cycles(
+    remaining: &[Key],
+    dependencies: &FxHashMap<Key, Vec<Key>>,
+) -> Vec<DependencyCycle> {
+    let reachable = |start: Key| {
+        let mut seen = FxHashSet::default();
+        let mut stack = vec![start];
+        while let Some(key) = stack.pop() {
+            for next in dependencies.get(&key).into_iter().flatten() {
+                if seen.insert(*next) {
+                    stack.push(*next);
+                }
+            }
+        }
+        seen
+    };
+    let reach: FxHashMap<Key, FxHashSet<Key>> =
+        remaining.iter().map(|&key| (key, reachable(key))).collect();
+
+    let mut assigned = FxHashSet::default();
+    let mut cycles = Vec::new();
+    for &key in remaining {
+        if assigned.contains(&key) || !reach[&key].contains(&key) {
+            continue;
+        }
+        let members: Vec<Key> = remaining
+            .iter()
+            .copied()
+            .filter(|other| reach[&key].contains(other) && reach[other].contains(&key))
+            .collect();
+        assigned.extend(members.iter().copied());
+        cycles.push(DependencyCycle { members });
+    }
+    cycles
+}
@@ -155,6 +155,7 @@ impl SymbolCollector {
         let mut symbol = Symbol::new(SymbolId::UNKNOWN, name.clone(), kind, symbol_type_id, range);
         symbol.visibility = self.visibility_for_pou(node, &symbol.kind);
         symbol.modifiers = modifiers_from_node(node);
+        symbol.deprecated = deprecation_from_node(node);
         symbol.parent = self.current_parent();
         if let Some(owner_parts) = owner_parts {
             let owner_id = self
@@ -499,23 +500,22 @@ impl SymbolCollector {
             && matches!(name.to_ascii_uppercase().as_str(), "EN" | "ENO");
         self.validate_identifier(&name, range, allow_reserved);
 
-        // Check for duplicate in current scope
-        if let Some(existing) = self.table.resolve_current(&name) {
-            // Only report duplicate if it's in the same scope (not from parent)
-            let current_scope = self.table.current_scope();
-            if let Some(scope) = self.table.get_scope(current_scope) {
-                if scope.lookup_local(&name).is_some() {
-                    let mut diag = Diagnostic::error(
-                        DiagnosticCode::DuplicateDeclaration,
-                        range,
-                        format!("duplicate declaration of '{}'", name),
-                    );
-                    if let Some(existing_symbol) = self.table.get(existing) {
-                        diag = diag.with_related(existing_symbol.range, "previously declared here");
-                    }
-                    self.diagnostics.add(diag);
-                }
+        // Only same-scope redeclarations are duplicates; names from parent
+        // scopes may be shadowed by nested declarations.
+        let existing = self
+            .table
+            .get_scope(self.table.current_scope())
+            .and_then(|scope| scope.lookup_local(&name));
+        if let Some(existing) = existing {
+            let mut diag = Diagnostic::error(
+                DiagnosticCode::DuplicateDeclaration,
+                range,
+                format!("duplicate declaration of '{}'", name),
+            );
+            if let Some(existing_symbol) = self.table.get(existing) {
+                diag = diag.with_related(existing_symbol.range, "previously declared here");
             }
+            self.diagnostics.add(diag);
         }
 
         self.table.add_symbol(symbol)
@@ -1,5 +1,6 @@
 use super::const_utils::*;
 use super::*;
+use crate::type_check::const_eval::fold_int_with_consts;
 
 impl SymbolCollector {
     pub(super) fn evaluate_constants(&mut self) {
@@ -19,68 +20,27 @@ impl SymbolCollector {
         self.eval_int_expr(node, scopes, &mut guard)
     }
 
+    /// Folds `node` with the shared constant folder. The constants it names
+    /// are evaluated first, since they may be declared later in the file.
     pub(super) fn eval_int_expr(
         &mut self,
         node: &SyntaxNode,
         scopes: &[Option<SmolStr>],
         guard: &mut FxHashSet<(Option<SmolStr>, SmolStr)>,
     ) -> Option<i64> {
-        match node.kind() {
-            SyntaxKind::Literal => parse_int_literal_from_node(node),
-            SyntaxKind::NameRef => {
-                let name = first_ident_token(node)?.text().to_string();
-                self.resolve_const_value(&name, scopes, guard)
-                    .or_else(|| self.table.enum_value_by_name(&name))
+        for name_ref in node
+            .descendants()
+            .filter(|child| child.kind() == SyntaxKind::NameRef)
+        {
+            if let Some(name) = first_ident_token(&name_ref) {
+                let _ = self.resolve_const_value(name.text(), scopes, guard);
             }
-            SyntaxKind::ParenExpr => node
-                .children()
-                .next()
-                .and_then(|child| self.eval_int_expr(&child, scopes, guard)),
-            SyntaxKind::UnaryExpr => {
-                let op = unary_op_from_node(node)?;
-                let expr = node.children().next()?;
-                let value = self.eval_int_expr(&expr, scopes, guard)?;
-                match op {
-                    IntUnaryOp::Plus => Some(value),
-                    IntUnaryOp::Minus => value.checked_neg(),
-                }
-            }
-            SyntaxKind::BinaryExpr => {
-                let children: Vec<_> = node.children().collect();
-                if children.len() < 2 {
-                    return None;
-                }
-                let lhs = self.eval_int_expr(&children[0], scopes, guard)?;
-                let rhs = self.eval_int_expr(&children[children.len() - 1], scopes, guard)?;
-                match binary_op_from_node(node)? {
-                    IntBinaryOp::Add => lhs.checked_add(rhs),
-                    IntBinaryOp::Sub => lhs.checked_sub(rhs),
-                    IntBinaryOp::Mul => lhs.checked_mul(rhs),
-                    IntBinaryOp::Div => {
-                        if rhs == 0 {
-                            None
-                        } else {
-                            lhs.checked_div(rhs)
-                        }
-                    }
-                    IntBinaryOp::Mod => {
-                        if rhs == 0 {
-                            None
-                        } else {
-                            lhs.checked_rem(rhs)
-                        }
-                    }
-                    IntBinaryOp::Power => {
-                        if rhs < 0 {
-                            None
-                        } else {
-                            lhs.checked_pow(rhs as u32)
-                        }
-                    }
-                }
-            }
-            _ => None,
         }
+        let const_values = &self.const_values;
+        let consts = |scope: &Option<SmolStr>, name: &str| {
+            const_values.get(&const_key(scope, name)).copied()
+        };
+        fold_int_with_consts(&self.table, &consts, node)
     }
 
     pub(super) fn resolve_const_value(
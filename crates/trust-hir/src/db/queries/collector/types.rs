@@ -2,6 +2,7 @@ use super::const_utils::*;
 use super::*;
 use crate::db::diagnostics::{is_expression_kind, resolve_pending_types_with_table};
 use crate::types::{StructField, UnionVariant};
+use trust_syntax::syntax::SubrangeNode;
 
 impl SymbolCollector {
     pub(super) fn collect_type_symbols(&mut self, node: &SyntaxNode) {
@@ -35,12 +36,18 @@ impl SymbolCollector {
         let qualified_name = self.qualify_current_name(&type_name);
 
         // Create TYPE symbol first with placeholder type_id, so that nested symbols
-        // (like enum values) can have this symbol as their parent
+        // (like enum values) can have this symbol as their parent. The id
+        // pre-registered for the name keeps self-references (`next : Node`)
+        // resolvable while the definition is collected.
+        let placeholder = self
+            .table
+            .lookup_type(&qualified_name)
+            .unwrap_or(TypeId::UNKNOWN);
         let mut symbol = Symbol::new(
             SymbolId::UNKNOWN,
             type_name,
             SymbolKind::Type,
-            TypeId::UNKNOWN, // Placeholder, will be updated below
+            placeholder, // Updated below
             name_range,
         );
         symbol.parent = self.current_parent();
@@ -251,9 +258,7 @@ impl SymbolCollector {
 
         // Collect dimensions from Subrange children
         for subrange in node.children().filter(|n| n.kind() == SyntaxKind::Subrange) {
-            if let Some((lower, upper)) = self.extract_subrange(&subrange) {
-                dimensions.push((lower, upper));
-            }
+            dimensions.push(self.extract_subrange(&subrange));
         }
 
         // Get element type from TypeRef child
@@ -263,15 +268,15 @@ impl SymbolCollector {
 
         if dimensions.is_empty() {
             // Single dimension without subrange, assume 0..MAX
-            dimensions.push((0, i64::MAX));
+            dimensions.push(Some(Type::OPEN_BOUNDS));
         }
 
         self.table.register_array_type(element_type, dimensions)
     }
 
     pub(super) fn extract_subrange(&mut self, node: &SyntaxNode) -> Option<(i64, i64)> {
-        if node.text().to_string().trim().contains('*') {
-            return Some((0, i64::MAX));
+        if SubrangeNode::cast(node.clone()).is_some_and(|subrange| subrange.is_variable_length()) {
+            return Some(Type::OPEN_BOUNDS);
         }
         let mut values = Vec::new();
         let scopes = scope_chain_for_node(node);
@@ -48,7 +48,9 @@ impl SymbolCollector {
 
             for var_decl in block.children().filter(|n| n.kind() == SyntaxKind::VarDecl) {
                 let (names, type_id, _) = self.extract_var_decl_info(&var_decl);
-                let has_initializer = var_decl.children().any(|n| is_expression_kind(n.kind()));
+                let has_initializer = var_decl.children().any(|n| {
+                    is_expression_kind(n.kind()) || n.kind() == SyntaxKind::ArrayInitializer
+                });
 
                 for (name, range) in names {
                     match qualifier {
@@ -31,18 +31,22 @@ impl SymbolCollector {
     pub(super) fn collect_var_block(&mut self, node: &SyntaxNode) {
         let qualifier = var_qualifier_from_block(node);
         let is_constant = var_block_is_constant(node);
+        let retention = var_block_retention(node);
         let visibility = match qualifier {
             VarQualifier::Input | VarQualifier::Output => Visibility::Public,
             _ => self.visibility_for_var_block(node),
         };
-        let use_global_scope = qualifier == VarQualifier::Global && self.in_configuration_scope();
+        // Configuration globals are visible everywhere; a RESOURCE keeps its
+        // own VAR_GLOBALs so two resources may declare the same name.
+        let use_global_scope = qualifier == VarQualifier::Global
+            && self.current_scope_kind() == Some(ScopeKind::Configuration);
         let previous_scope = self.table.current_scope();
         if use_global_scope {
             self.table.set_current_scope(ScopeId::GLOBAL);
         }
         for child in node.children() {
             if child.kind() == SyntaxKind::VarDecl {
-                self.collect_var_decl(&child, qualifier, is_constant, visibility);
+                self.collect_var_decl(&child, qualifier, is_constant, retention, visibility);
             }
         }
         if use_global_scope {
@@ -55,6 +59,7 @@ impl SymbolCollector {
         node: &SyntaxNode,
         qualifier: VarQualifier,
         is_constant: bool,
+        retention: Option<Retention>,
         visibility: Visibility,
     ) {
         let mut names = Vec::new();
@@ -76,11 +81,18 @@ impl SymbolCollector {
             TypeId::UNKNOWN
         };
 
-        if let Some(expr) = node.children().find(|n| is_expression_kind(n.kind())) {
-            self.check_string_initializer(type_id, &expr);
+        let initializer = node.children().find(|n| is_expression_kind(n.kind()));
+        if let Some(expr) = &initializer {
+            self.check_string_initializer(type_id, expr);
         }
 
+        // `:=` also covers initializers that are not expressions, such as
+        // `[1, 2, 3]` for arrays.
+        let has_initializer = node
+            .children_with_tokens()
+            .any(|element| element.kind() == SyntaxKind::Assign);
         let direct_address = var_decl_direct_address(node);
+        let deprecated = deprecation_from_node(node);
 
         for name_node in names {
             if let Some((name, range)) = name_from_node(&name_node) {
@@ -107,6 +119,9 @@ impl SymbolCollector {
                 symbol.direct_address = direct_address.clone();
                 symbol.parent = self.current_parent();
                 symbol.visibility = visibility;
+                symbol.modifiers.retention = retention;
+                symbol.modifiers.has_initializer = has_initializer;
+                symbol.deprecated = deprecated.clone();
                 self.declare_symbol(symbol);
             }
         }
@@ -197,10 +212,15 @@ impl SymbolCollector {
     pub(super) fn collect_var_config_block(&mut self, _node: &SyntaxNode) {}
 
     fn in_configuration_scope(&self) -> bool {
+        matches!(
+            self.current_scope_kind(),
+            Some(ScopeKind::Configuration | ScopeKind::Resource)
+        )
+    }
+
+    fn current_scope_kind(&self) -> Option<ScopeKind> {
         self.table
             .get_scope(self.table.current_scope())
-            .is_some_and(|scope| {
-                matches!(scope.kind, ScopeKind::Configuration | ScopeKind::Resource)
-            })
+            .map(|scope| scope.kind)
     }
 }
@@ -12,7 +12,10 @@ impl Database {
         Self::default()
     }
 
-    fn source_revision(&self) -> u64 {
+    /// Returns a counter that changes whenever any source text is set or
+    /// removed, so it can key caches of project-wide results.
+    #[must_use]
+    pub fn source_revision(&self) -> u64 {
         self.source_revision.load(Ordering::Relaxed)
     }
 
@@ -81,6 +84,14 @@ impl Database {
         })
     }
 
+    fn syntax_root(&self, file_id: FileId) -> Option<SyntaxNode> {
+        let (db, source) = self.source_handle_for_file(file_id)?;
+        salsa::Cancelled::catch(|| {
+            SyntaxNode::new_root(salsa_backend::parse_green(&db, source).clone())
+        })
+        .ok()
+    }
+
     fn project_symbol_tables(&self) -> FxHashMap<FileId, Arc<SymbolTable>> {
         let mut tables = FxHashMap::default();
         for &file_id in self.sources.keys() {
@@ -111,6 +122,25 @@ impl Database {
         });
     }
 
+    /// Returns the diagnostic passes that run during analysis.
+    pub fn diagnostic_passes(&self) -> DiagnosticPasses {
+        self.with_salsa_state_read(|state| state.diagnostic_passes)
+    }
+
+    /// Sets which diagnostic passes run. Changing the set re-runs analysis but
+    /// keeps parse trees and per-file symbol tables cached.
+    pub fn set_diagnostic_passes(&mut self, passes: DiagnosticPasses) {
+        self.with_salsa_state(|state| {
+            if state.diagnostic_passes == passes {
+                return;
+            }
+            state.diagnostic_passes = passes;
+            if let Some(project) = state.project_inputs {
+                project.set_diagnostic_passes(&mut state.db).to(passes);
+            }
+        });
+    }
+
     /// Remove source text and cached query inputs for a file.
     pub fn remove_source_text(&mut self, file_id: FileId) {
         if self.sources.remove(&file_id).is_none() {
@@ -159,6 +189,7 @@ impl Database {
             return Arc::new(FileAnalysis {
                 symbols: Arc::new(SymbolTable::default()),
                 diagnostics: Arc::new(Vec::new()),
+                summary: DiagnosticsSummary::default(),
             });
         };
 
@@ -167,6 +198,7 @@ impl Database {
                 Arc::new(FileAnalysis {
                     symbols: Arc::new(SymbolTable::default()),
                     diagnostics: Arc::new(Vec::new()),
+                    summary: DiagnosticsSummary::default(),
                 })
             })
     }
@@ -185,6 +217,28 @@ impl Database {
             .unwrap_or_else(|_| Arc::new(Vec::new()))
     }
 
+    /// Streams the diagnostics of `file_id` to `sink` without memoizing them;
+    /// see [`crate::diagnostics::collect_diagnostics_into`].
+    pub(crate) fn stream_diagnostics_salsa(
+        &self,
+        file_id: FileId,
+        sink: &mut dyn FnMut(Diagnostic) -> std::ops::ControlFlow<()>,
+    ) {
+        let Some((db, project)) = self.with_synced_salsa_state(|state| {
+            state
+                .sources
+                .contains_key(&file_id)
+                .then_some((state.db.clone(), salsa_backend::project_inputs(state)))
+        }) else {
+            return;
+        };
+
+        // A cancelled run simply ends the stream early.
+        let _ = salsa::Cancelled::catch(std::panic::AssertUnwindSafe(|| {
+            salsa_backend::stream_diagnostics(&db, project, file_id, sink)
+        }));
+    }
+
     fn type_of_salsa(&self, file_id: FileId, expr_id: u32) -> TypeId {
         let Some((db, project)) = self.with_synced_salsa_state(|state| {
             state
@@ -227,11 +281,85 @@ impl Database {
         }
     }
 
+    /// Returns the functions, function blocks and classes that no program or
+    /// configuration reaches, directly or through other POUs.
+    ///
+    /// POUs marked `{attribute 'hide'}`, `{attribute 'export'}` or
+    /// `{attribute 'linkalways'}` count as reachable, as does anything referenced
+    /// outside a POU body (global instances, type declarations).
+    pub fn unused_pous(&self) -> Vec<(FileId, SymbolId)> {
+        let (db, project) = self.with_synced_salsa_state(|state| {
+            (state.db.clone(), salsa_backend::project_inputs(state))
+        });
+        salsa::Cancelled::catch(|| {
+            salsa_backend::project_unused_pous_query(&db, project)
+                .as_ref()
+                .clone()
+        })
+        .unwrap_or_default()
+    }
+
+    /// Returns the programs, POUs, interfaces and types of the project ordered
+    /// by compile-time dependencies: types before the declarations using them
+    /// by value, bases before derived declarations. Ties keep file and
+    /// declaration order.
+    ///
+    /// # Errors
+    ///
+    /// Returns the dependency cycles when declarations depend on each other,
+    /// such as structs containing one another.
+    pub fn build_order(&self) -> Result<Vec<(FileId, SymbolId)>, Vec<DependencyCycle>> {
+        let (db, project) = self.with_synced_salsa_state(|state| {
+            (state.db.clone(), salsa_backend::project_inputs(state))
+        });
+        let plan = salsa::Cancelled::catch(|| {
+            salsa_backend::project_build_order_query(&db, project)
+                .as_ref()
+                .clone()
+        })
+        .unwrap_or_default();
+        if plan.cycles.is_empty() {
+            Ok(plan.order)
+        } else {
+            Err(plan.cycles)
+        }
+    }
+
+    /// Returns a fingerprint of what the diagnostics of `file_id` depend on
+    /// outside the file itself.
+    ///
+    /// The value changes when a file it references (directly or through other
+    /// files), a file referencing its declarations, or a file declaring a
+    /// clashing name is edited, added or removed, or when the diagnostic passes
+    /// change. Edits to unrelated files leave it as is, so a caller that also
+    /// tracks the file's own text can reuse earlier diagnostics.
+    pub fn dependency_fingerprint(&self, file_id: FileId) -> u64 {
+        let Some((db, project)) = self.with_synced_salsa_state(|state| {
+            state
+                .sources
+                .contains_key(&file_id)
+                .then_some((state.db.clone(), salsa_backend::project_inputs(state)))
+        }) else {
+            return 0;
+        };
+        salsa::Cancelled::catch(|| {
+            salsa_backend::dependency_fingerprint_query(&db, project, file_id)
+        })
+        .unwrap_or_default()
+    }
+
     /// Returns a symbol table augmented with project-wide symbols.
     pub fn file_symbols_with_project(&self, file_id: FileId) -> Arc<SymbolTable> {
         self.analyze(file_id).symbols.clone()
     }
 
+    /// Returns a POU's parameters in declaration order, with folded defaults and docs.
+    pub fn pou_parameters(&self, file_id: FileId, pou: SymbolId) -> Vec<PouParameter> {
+        let symbols = self.file_symbols(file_id);
+        let root = self.syntax_root(file_id);
+        parameters::pou_parameters(&symbols, root.as_ref(), pou)
+    }
+
     /// Returns a symbol table augmented with project symbols filtered to a file set.
     pub fn file_symbols_with_project_filtered(
         &self,
@@ -244,4 +372,3 @@ impl Database {
         Arc::new(symbols)
     }
 }
-
@@ -187,3 +187,56 @@
             "query boundary sequence should not panic after owned-state refactor"
         );
     }
+
+    #[test]
+    fn dependency_fingerprint_ignores_unrelated_files() {
+        let mut db = Database::new();
+        let globals = FileId(40);
+        let consumer = FileId(41);
+        let unrelated = FileId(42);
+        db.set_source_text(
+            globals,
+            "CONFIGURATION Plant\nVAR_GLOBAL\n    shared : INT;\nEND_VAR\nEND_CONFIGURATION\n"
+                .to_string(),
+        );
+        db.set_source_text(
+            consumer,
+            "PROGRAM Consumer\nVAR_EXTERNAL\n    shared : INT;\nEND_VAR\nshared := 1;\nEND_PROGRAM\n"
+                .to_string(),
+        );
+        db.set_source_text(
+            unrelated,
+            "FUNCTION Helper : INT\nHelper := 1;\nEND_FUNCTION\n".to_string(),
+        );
+        let consumer_before = db.dependency_fingerprint(consumer);
+        let globals_before = db.dependency_fingerprint(globals);
+
+        db.set_source_text(
+            unrelated,
+            "FUNCTION Helper : INT\nHelper := 2;\nEND_FUNCTION\n".to_string(),
+        );
+        assert_eq!(db.dependency_fingerprint(consumer), consumer_before);
+        assert_eq!(db.dependency_fingerprint(globals), globals_before);
+
+        db.set_source_text(
+            globals,
+            "CONFIGURATION Plant\nVAR_GLOBAL\n    shared : DINT;\nEND_VAR\nEND_CONFIGURATION\n"
+                .to_string(),
+        );
+        assert_ne!(
+            db.dependency_fingerprint(consumer),
+            consumer_before,
+            "editing a referenced global should change the consumer fingerprint"
+        );
+
+        let globals_before = db.dependency_fingerprint(globals);
+        db.set_source_text(
+            consumer,
+            "PROGRAM Consumer\nEND_PROGRAM\n".to_string(),
+        );
+        assert_ne!(
+            db.dependency_fingerprint(globals),
+            globals_before,
+            "dropping the only use of a global can change its unused warnings"
+        );
+    }
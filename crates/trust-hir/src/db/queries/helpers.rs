@@ -1,4 +1,5 @@
 use super::*;
+use trust_syntax::syntax::{leading_pragmas, parse_pragma, Pragma};
 
 pub(in crate::db) fn name_from_node(node: &SyntaxNode) -> Option<(SmolStr, TextRange)> {
     let token = node
@@ -136,6 +137,35 @@ pub(in crate::db) fn modifiers_from_node(node: &SyntaxNode) -> SymbolModifiers {
     modifiers
 }
 
+/// Returns the `{attribute ...}` pragmas in front of a declaration or between
+/// its keyword and its first non-name child (e.g. `FUNCTION_BLOCK {attribute 'hide'} FB`).
+pub(in crate::db) fn attribute_pragmas(node: &SyntaxNode) -> Vec<Pragma> {
+    let header_end = node
+        .children()
+        .find(|child| child.kind() != SyntaxKind::Name)
+        .map_or(node.text_range().end(), |child| child.text_range().start());
+    let header_pragmas = node
+        .descendants_with_tokens()
+        .filter_map(|element| element.into_token())
+        .take_while(|token| token.text_range().start() < header_end)
+        .filter_map(|token| parse_pragma(&token));
+    leading_pragmas(node)
+        .into_iter()
+        .chain(header_pragmas)
+        .filter(|pragma| pragma.is("attribute"))
+        .collect()
+}
+
+/// Returns the message of an `{attribute 'deprecated' := '...'}` pragma, or an
+/// empty message when the attribute has no value.
+pub(in crate::db) fn deprecation_from_node(node: &SyntaxNode) -> Option<SmolStr> {
+    attribute_pragmas(node)
+        .into_iter()
+        .flat_map(|pragma| pragma.args)
+        .find(|arg| arg.key.eq_ignore_ascii_case("deprecated"))
+        .map(|arg| arg.value.unwrap_or_default())
+}
+
 pub(in crate::db) fn implements_clause_names(node: &SyntaxNode) -> Vec<(Vec<SmolStr>, TextRange)> {
     let mut names = Vec::new();
     for child in node.children() {
@@ -341,6 +371,19 @@ pub(in crate::db) fn retention_modifier_range(modifiers: &VarBlockModifiers) ->
         .or(modifiers.persistent)
 }
 
+pub(in crate::db) fn var_block_retention(node: &SyntaxNode) -> Option<Retention> {
+    let modifiers = var_block_modifiers(node);
+    if modifiers.persistent.is_some() {
+        Some(Retention::Persistent)
+    } else if modifiers.retain.is_some() {
+        Some(Retention::Retain)
+    } else if modifiers.non_retain.is_some() {
+        Some(Retention::NonRetain)
+    } else {
+        None
+    }
+}
+
 pub(in crate::db) fn direct_address_has_wildcard(address: &str) -> bool {
     address.contains('*')
 }
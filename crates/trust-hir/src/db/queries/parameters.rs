@@ -0,0 +1,88 @@
+use super::*;
+use crate::db::diagnostics::is_expression_kind;
+use crate::type_check::const_eval::{fold_with_symbols, ConstValue};
+use trust_syntax::syntax::{leading_comments, trailing_comments};
+
+/// A POU parameter as structured data for tooling.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PouParameter {
+    /// Parameter name as declared.
+    pub name: SmolStr,
+    /// Declared type.
+    pub type_id: TypeId,
+    /// Parameter direction (VAR_INPUT, VAR_OUTPUT, VAR_IN_OUT).
+    pub direction: ParamDirection,
+    /// Initializer value, when it folds to a constant.
+    pub default: Option<ConstValue>,
+    /// Documentation taken from the declaration's comments.
+    pub doc: Option<SmolStr>,
+}
+
+pub(super) fn pou_parameters(
+    symbols: &SymbolTable,
+    root: Option<&SyntaxNode>,
+    pou: SymbolId,
+) -> Vec<PouParameter> {
+    if symbols.get(pou).is_none() {
+        return Vec::new();
+    }
+    symbols
+        .parameters_of(pou)
+        .into_iter()
+        .filter_map(|symbol| {
+            let SymbolKind::Parameter { direction } = symbol.kind else {
+                return None;
+            };
+            let var_decl = root.and_then(|root| var_decl_at(root, symbol.range));
+            let default = var_decl
+                .as_ref()
+                .and_then(|decl| decl.children().find(|n| is_expression_kind(n.kind())))
+                .and_then(|expr| fold_with_symbols(symbols, &expr));
+            let doc = symbol
+                .doc
+                .clone()
+                .or_else(|| var_decl.as_ref().and_then(declaration_doc));
+            Some(PouParameter {
+                name: symbol.name.clone(),
+                type_id: symbol.type_id,
+                direction,
+                default,
+                doc,
+            })
+        })
+        .collect()
+}
+
+fn var_decl_at(root: &SyntaxNode, name_range: TextRange) -> Option<SyntaxNode> {
+    root.token_at_offset(name_range.start())
+        .right_biased()?
+        .parent_ancestors()
+        .find(|node| node.kind() == SyntaxKind::VarDecl)
+}
+
+fn declaration_doc(var_decl: &SyntaxNode) -> Option<SmolStr> {
+    let mut comments = leading_comments(var_decl);
+    comments.extend(trailing_comments(var_decl));
+    let text = comments
+        .iter()
+        .map(|token| comment_text(token.text()))
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n");
+    (!text.is_empty()).then(|| SmolStr::new(text))
+}
+
+fn comment_text(text: &str) -> &str {
+    let text = text
+        .strip_prefix("//")
+        .or_else(|| {
+            text.strip_prefix("(*")
+                .map(|inner| inner.strip_suffix("*)").unwrap_or(inner))
+        })
+        .or_else(|| {
+            text.strip_prefix("/*")
+                .map(|inner| inner.strip_suffix("*/").unwrap_or(inner))
+        })
+        .unwrap_or(text);
+    text.trim()
+}
@@ -1,17 +1,22 @@
+use super::build_order::{order_declarations, BuildOrder};
 use super::collector::SymbolCollector;
 use super::diagnostics::{
     add_unused_symbol_warnings, check_abstract_instantiations, check_class_semantics,
-    check_configuration_semantics, check_cyclomatic_complexity, check_extends_implements_semantics,
+    check_configuration_semantics, check_cyclomatic_complexity, check_direct_address_overlaps,
+    check_empty_blocks, check_extends_implements_semantics,
     check_global_external_links_with_project, check_interface_conformance, check_nondeterminism,
-    check_property_accessors, check_shared_global_task_hazards, check_unreachable_statements,
-    check_using_directives, collect_used_symbols, expression_by_id, expression_context,
+    check_property_accessors, check_sfc_pragmas, check_shared_global_task_hazards,
+    check_unreachable_statements, check_using_directives, collect_pou_references,
+    collect_used_symbols, expression_by_id, expression_context, global_link_keys,
     resolve_declared_var_types_with_project, resolve_pending_types_with_table, type_check_file,
+    GlobalKey, PouReferences,
 };
 use super::symbol_import::SymbolImporter;
 use super::*;
 use rowan::GreenNode;
 use rustc_hash::{FxHashMap, FxHashSet};
 use salsa::Setter;
+use std::ops::ControlFlow;
 use std::sync::atomic::{AtomicU64, Ordering};
 
 #[salsa::db]
@@ -64,19 +69,21 @@ pub(super) struct SalsaState {
     pub(super) db: SalsaDatabase,
     pub(super) sources: FxHashMap<FileId, SourceInput>,
     pub(super) project_inputs: Option<ProjectInputs>,
+    pub(super) diagnostic_passes: DiagnosticPasses,
     pub(super) synced_revision: u64,
     #[cfg(test)]
     pub(super) project_sync_count: u64,
 }
 
 impl SalsaState {
-    #[cfg(test)]
     pub(super) fn with_event_observability(collect_events: bool, log_events: bool) -> Self {
         Self {
             db: SalsaDatabase::with_event_observability(collect_events, log_events),
             sources: FxHashMap::default(),
             project_inputs: None,
+            diagnostic_passes: DiagnosticPasses::all(),
             synced_revision: 0,
+            #[cfg(test)]
             project_sync_count: 0,
         }
     }
@@ -188,7 +195,11 @@ pub(super) fn sync_project_inputs(state: &mut SalsaState) {
     if let Some(project) = state.project_inputs {
         project.set_files(&mut state.db).to(files);
     } else {
-        state.project_inputs = Some(ProjectInputs::new(&state.db, files));
+        state.project_inputs = Some(ProjectInputs::new(
+            &state.db,
+            files,
+            state.diagnostic_passes,
+        ));
     }
 }
 
@@ -208,12 +219,28 @@ pub(super) struct SourceInput {
 pub(super) struct ProjectInputs {
     #[returns(ref)]
     pub(super) files: Vec<(FileId, SourceInput)>,
+    pub(super) diagnostic_passes: DiagnosticPasses,
+}
+
+/// The parse tree of a source and the ranges of its syntax errors.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct ParsedSource {
+    green: GreenNode,
+    error_ranges: Arc<Vec<TextRange>>,
 }
 
 #[salsa::tracked(returns(ref))]
-pub(super) fn parse_green(db: &dyn salsa::Database, input: SourceInput) -> GreenNode {
+pub(super) fn parsed_source(db: &dyn salsa::Database, input: SourceInput) -> ParsedSource {
     let parsed = parse(input.text(db));
-    parsed.syntax().green().into_owned()
+    ParsedSource {
+        green: parsed.syntax().green().into_owned(),
+        error_ranges: Arc::new(parsed.errors().iter().map(|err| err.range).collect()),
+    }
+}
+
+#[salsa::tracked(returns(ref))]
+pub(super) fn parse_green(db: &dyn salsa::Database, input: SourceInput) -> GreenNode {
+    parsed_source(db, input).green.clone()
 }
 
 #[salsa::tracked(returns(ref))]
@@ -280,6 +307,78 @@ pub(super) fn project_used_symbols_query(
     Arc::new(used)
 }
 
+#[salsa::tracked(returns(ref))]
+pub(super) fn file_pou_references_query(
+    db: &dyn salsa::Database,
+    project: ProjectInputs,
+    file_id: FileId,
+) -> Arc<PouReferences> {
+    cancellation_checkpoint(db);
+    let Some(input) = project
+        .files(db)
+        .iter()
+        .find_map(|(candidate_id, input)| (*candidate_id == file_id).then_some(*input))
+    else {
+        return Arc::new(PouReferences::default());
+    };
+    let root = SyntaxNode::new_root(parse_green(db, input).clone());
+    let symbols = merged_project_symbols_query(db, project, file_id);
+    Arc::new(collect_pou_references(symbols.as_ref(), &root, file_id))
+}
+
+/// Functions, function blocks and classes not reachable from any program,
+/// configuration or keep-alive POU, in file and declaration order.
+#[salsa::tracked(returns(ref))]
+pub(super) fn project_unused_pous_query(
+    db: &dyn salsa::Database,
+    project: ProjectInputs,
+) -> Arc<Vec<(FileId, SymbolId)>> {
+    cancellation_checkpoint(db);
+    let mut candidates = Vec::new();
+    let mut pending = Vec::new();
+    let mut edges: FxHashMap<(FileId, SymbolId), Vec<(FileId, SymbolId)>> = FxHashMap::default();
+    for (file_id, _) in project.files(db).iter().copied() {
+        let references = file_pou_references_query(db, project, file_id);
+        candidates.extend(references.candidates.iter().copied());
+        pending.extend(references.roots.iter().copied());
+        for (from, to) in references.edges.iter().copied() {
+            edges.entry(from).or_default().push(to);
+        }
+    }
+
+    let mut reachable = FxHashSet::default();
+    while let Some(key) = pending.pop() {
+        if !reachable.insert(key) {
+            continue;
+        }
+        if let Some(targets) = edges.get(&key) {
+            pending.extend(targets.iter().copied());
+        }
+    }
+
+    candidates.retain(|key| !reachable.contains(key));
+    candidates.sort_unstable_by_key(|&(file_id, symbol_id)| (file_id.0, symbol_id.0));
+    Arc::new(candidates)
+}
+
+/// Programs, POUs, interfaces and types of the project in dependency order,
+/// with the cycles that keep declarations out of the order.
+#[salsa::tracked(returns(ref))]
+pub(super) fn project_build_order_query(
+    db: &dyn salsa::Database,
+    project: ProjectInputs,
+) -> Arc<BuildOrder> {
+    cancellation_checkpoint(db);
+    let mut declarations = Vec::new();
+    let mut edges = Vec::new();
+    for (file_id, _) in project.files(db).iter().copied() {
+        let references = file_pou_references_query(db, project, file_id);
+        declarations.extend(references.declarations.iter().copied());
+        edges.extend(references.declaration_edges.iter().copied());
+    }
+    Arc::new(order_declarations(&declarations, &edges))
+}
+
 #[salsa::tracked(returns(ref))]
 pub(super) fn analyze_query(
     db: &dyn salsa::Database,
@@ -287,17 +386,71 @@ pub(super) fn analyze_query(
     file_id: FileId,
 ) -> Arc<FileAnalysis> {
     cancellation_checkpoint(db);
-    let Some(ProjectState {
-        target_input,
-        project_source_inputs,
-        project_tables,
-    }) = collect_project_state(db, project, file_id)
-    else {
+    let Some(state) = collect_project_state(db, project, file_id) else {
         return empty_analysis();
     };
 
+    let parse_errors = &parsed_source(db, state.target_input).error_ranges;
+    let mut filter = RecoveryNoiseFilter::new(parse_errors);
+    let mut diagnostics = Vec::new();
+    let symbols = check_file(db, project, file_id, state, &mut |diagnostic| {
+        if filter.keep(&diagnostic) {
+            diagnostics.push(diagnostic);
+        }
+        ControlFlow::Continue(())
+    });
+    Arc::new(FileAnalysis {
+        symbols: Arc::new(symbols),
+        diagnostics: Arc::new(diagnostics),
+        summary: filter.summary,
+    })
+}
+
+/// Passes the diagnostics of `file_id` to `sink` as each check reports them,
+/// skipping the remaining checks once `sink` returns [`ControlFlow::Break`].
+///
+/// Unlike [`diagnostics_query`] nothing is memoized, so a caller that only
+/// wants the first few diagnostics of a large file does not pay for the rest.
+pub(super) fn stream_diagnostics(
+    db: &dyn salsa::Database,
+    project: ProjectInputs,
+    file_id: FileId,
+    sink: &mut dyn FnMut(Diagnostic) -> ControlFlow<()>,
+) {
+    cancellation_checkpoint(db);
+    let Some(state) = collect_project_state(db, project, file_id) else {
+        return;
+    };
+
+    let parse_errors = &parsed_source(db, state.target_input).error_ranges;
+    let mut filter = RecoveryNoiseFilter::new(parse_errors);
+    check_file(db, project, file_id, state, &mut |diagnostic| {
+        if filter.keep(&diagnostic) {
+            sink(diagnostic)
+        } else {
+            ControlFlow::Continue(())
+        }
+    });
+}
+
+/// Builds the symbol table of `file_id` and runs the semantic checks on it,
+/// handing each diagnostic to `sink`. Checks after a
+/// [`ControlFlow::Break`] are skipped and the table is returned as far as it
+/// was resolved.
+fn check_file(
+    db: &dyn salsa::Database,
+    project: ProjectInputs,
+    file_id: FileId,
+    state: ProjectState,
+    sink: &mut dyn FnMut(Diagnostic) -> ControlFlow<()>,
+) -> SymbolTable {
+    let ProjectState {
+        target_input,
+        project_source_inputs,
+        project_tables,
+    } = state;
     let root = SyntaxNode::new_root(parse_green(db, target_input).clone());
-    let (mut symbols, mut diagnostics, pending_types) =
+    let (mut symbols, diagnostics, pending_types) =
         SymbolCollector::new().collect_for_project(&root);
     merge_project_symbols(file_id, &mut symbols, project_tables.as_ref());
 
@@ -305,41 +458,143 @@ pub(super) fn analyze_query(
     resolve_pending_types_with_table(&symbols, pending_types, &mut builder);
     resolve_declared_var_types_with_project(&mut symbols, &root);
     check_global_external_links_with_project(&mut symbols, &root, &mut builder, file_id);
-    diagnostics.extend(builder.finish());
+    let flow = diagnostics
+        .into_iter()
+        .chain(builder.finish())
+        .try_for_each(&mut *sink);
+    if flow.is_continue() {
+        let _ = run_checks(
+            db,
+            project,
+            file_id,
+            &project_source_inputs,
+            &root,
+            &mut symbols,
+            sink,
+        );
+    }
+    symbols
+}
 
-    let mut builder = DiagnosticBuilder::new();
-    check_class_semantics(&symbols, &root, &mut builder);
-    check_abstract_instantiations(&symbols, &root, &mut builder);
-    check_extends_implements_semantics(&symbols, &root, &mut builder);
-    check_interface_conformance(&symbols, &root, &mut builder);
-    check_property_accessors(&symbols, &mut builder);
-    diagnostics.extend(builder.finish());
+/// Runs the semantic passes in order, stopping at the first
+/// [`ControlFlow::Break`] from `sink`.
+fn run_checks(
+    db: &dyn salsa::Database,
+    project: ProjectInputs,
+    file_id: FileId,
+    project_source_inputs: &FxHashMap<FileId, SourceInput>,
+    root: &SyntaxNode,
+    symbols: &mut SymbolTable,
+    sink: &mut dyn FnMut(Diagnostic) -> ControlFlow<()>,
+) -> ControlFlow<()> {
+    // Disabled passes are skipped outright rather than filtered afterwards, so
+    // turning one off also saves its cost.
+    let passes = project.diagnostic_passes(db);
+
+    run_pass(passes, DiagnosticPass::Oop, sink, |builder| {
+        let children = symbols.children_by_parent();
+        check_class_semantics(symbols, &children, root, builder);
+        check_abstract_instantiations(symbols, root, builder);
+        check_extends_implements_semantics(symbols, root, builder);
+        check_interface_conformance(symbols, &children, root, builder);
+        check_property_accessors(symbols, builder);
+    })?;
+    run_pass(passes, DiagnosticPass::Using, sink, |builder| {
+        check_using_directives(symbols, builder);
+    })?;
+    run_pass(passes, DiagnosticPass::Configuration, sink, |builder| {
+        check_configuration_semantics(symbols, root, builder);
+    })?;
 
     let mut builder = DiagnosticBuilder::new();
-    check_using_directives(&symbols, &mut builder);
-    diagnostics.extend(builder.finish());
+    type_check_file(symbols, root, &mut builder);
+    builder.finish().into_iter().try_for_each(&mut *sink)?;
+
+    run_pass(passes, DiagnosticPass::Unreachable, sink, |builder| {
+        check_unreachable_statements(symbols, root, builder);
+    })?;
+    run_pass(passes, DiagnosticPass::EmptyBlocks, sink, |builder| {
+        check_empty_blocks(root, builder);
+    })?;
+    run_pass(passes, DiagnosticPass::SfcPragmas, sink, |builder| {
+        check_sfc_pragmas(root, builder);
+    })?;
+    run_pass(passes, DiagnosticPass::Complexity, sink, |builder| {
+        check_cyclomatic_complexity(root, builder);
+    })?;
+    run_pass(passes, DiagnosticPass::Nondeterminism, sink, |builder| {
+        check_nondeterminism(symbols, builder);
+    })?;
+    run_pass(passes, DiagnosticPass::AddressOverlap, sink, |builder| {
+        check_direct_address_overlaps(symbols, builder);
+    })?;
+    if has_global_variables(symbols) {
+        run_pass(passes, DiagnosticPass::SharedGlobals, sink, |builder| {
+            let project_roots = project_roots_from_inputs(db, project_source_inputs);
+            check_shared_global_task_hazards(symbols, &project_roots, file_id, builder);
+        })?;
+    }
+    run_pass(passes, DiagnosticPass::Unused, sink, |builder| {
+        let project_used = project_used_symbols_query(db, project);
+        add_unused_symbol_warnings(symbols, file_id, project_used.as_ref(), builder);
+    })
+}
 
+/// Runs `check` when `pass` is enabled and tags what it reports with the pass,
+/// so clients with their own pass settings can filter the shared results.
+fn run_pass(
+    passes: DiagnosticPasses,
+    pass: DiagnosticPass,
+    sink: &mut dyn FnMut(Diagnostic) -> ControlFlow<()>,
+    check: impl FnOnce(&mut DiagnosticBuilder),
+) -> ControlFlow<()> {
+    if !passes.is_enabled(pass) {
+        return ControlFlow::Continue(());
+    }
     let mut builder = DiagnosticBuilder::new();
-    check_configuration_semantics(&symbols, &root, &mut builder);
-    diagnostics.extend(builder.finish());
+    check(&mut builder);
+    builder
+        .finish()
+        .into_iter()
+        .try_for_each(|diagnostic| sink(diagnostic.with_pass(pass)))
+}
 
-    let project_used = project_used_symbols_query(db, project);
-    let mut builder = DiagnosticBuilder::new();
-    type_check_file(&mut symbols, &root, &mut builder);
-    check_unreachable_statements(&root, &mut builder);
-    check_cyclomatic_complexity(&root, &mut builder);
-    check_nondeterminism(&symbols, &mut builder);
-    if has_global_variables(&symbols) {
-        let project_roots = project_roots_from_inputs(db, &project_source_inputs);
-        check_shared_global_task_hazards(&symbols, &project_roots, file_id, &mut builder);
+/// Drops semantic diagnostics that parse error recovery makes unreliable:
+/// those overlapping a syntax error, and structural lints anywhere in a file
+/// with syntax errors.
+struct RecoveryNoiseFilter<'a> {
+    parse_errors: &'a [TextRange],
+    summary: DiagnosticsSummary,
+}
+
+impl<'a> RecoveryNoiseFilter<'a> {
+    fn new(parse_errors: &'a [TextRange]) -> Self {
+        Self {
+            parse_errors,
+            summary: DiagnosticsSummary {
+                parse_errors: parse_errors.len(),
+                ..DiagnosticsSummary::default()
+            },
+        }
     }
-    add_unused_symbol_warnings(&symbols, file_id, project_used.as_ref(), &mut builder);
-    diagnostics.extend(builder.finish());
 
-    Arc::new(FileAnalysis {
-        symbols: Arc::new(symbols),
-        diagnostics: Arc::new(diagnostics),
-    })
+    /// Returns whether `diagnostic` survives, counting it in the summary.
+    fn keep(&mut self, diagnostic: &Diagnostic) -> bool {
+        let noise = !self.parse_errors.is_empty()
+            && (diagnostic.code.is_structural_lint()
+                || self
+                    .parse_errors
+                    .iter()
+                    .any(|range| range.intersect(diagnostic.range).is_some()));
+        if noise {
+            self.summary.suppressed += 1;
+        } else if diagnostic.is_error() {
+            self.summary.semantic_errors += 1;
+        } else {
+            self.summary.semantic_warnings += 1;
+        }
+        !noise
+    }
 }
 
 #[salsa::tracked(returns(ref))]
@@ -351,6 +606,121 @@ pub(super) fn diagnostics_query(
     analyze_query(db, project, file_id).diagnostics.clone()
 }
 
+/// Names a file declares for use elsewhere and the identifiers it mentions,
+/// all lowercased, plus the keys its globals and VAR_EXTERNALs link by.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub(super) struct FileNames {
+    declared: FxHashSet<SmolStr>,
+    referenced: FxHashSet<SmolStr>,
+    globals: FxHashSet<GlobalKey>,
+    externals: FxHashSet<GlobalKey>,
+}
+
+impl FileNames {
+    fn references(&self, other: &FileNames) -> bool {
+        other
+            .declared
+            .iter()
+            .any(|name| self.referenced.contains(name))
+            || !self.externals.is_disjoint(&other.globals)
+    }
+}
+
+#[salsa::tracked(returns(ref))]
+pub(super) fn file_names_query(db: &dyn salsa::Database, input: SourceInput) -> Arc<FileNames> {
+    let symbols = file_symbols_query(db, input);
+    // Builtins have empty ranges; locals and parameters are not visible to
+    // other files.
+    let declared = symbols
+        .iter()
+        .filter(|symbol| !symbol.range.is_empty())
+        .filter(|symbol| {
+            !matches!(
+                symbol.kind,
+                SymbolKind::Parameter { .. }
+                    | SymbolKind::Variable {
+                        qualifier: VarQualifier::Local
+                            | VarQualifier::Input
+                            | VarQualifier::Output
+                            | VarQualifier::InOut
+                            | VarQualifier::Temp
+                            | VarQualifier::External
+                            | VarQualifier::Static
+                    }
+            )
+        })
+        .map(|symbol| SmolStr::new(symbol.name.to_ascii_lowercase()))
+        .collect();
+    let root = SyntaxNode::new_root(parse_green(db, input).clone());
+    let referenced = root
+        .descendants_with_tokens()
+        .filter_map(|element| element.into_token())
+        .filter(|token| token.kind() == SyntaxKind::Ident)
+        .map(|token| SmolStr::new(token.text().to_ascii_lowercase()))
+        .collect();
+    let (globals, externals) = global_link_keys(symbols);
+    Arc::new(FileNames {
+        declared,
+        referenced,
+        globals,
+        externals,
+    })
+}
+
+/// Hashes the sources of every file whose edits can change the diagnostics of
+/// `file_id`, plus the enabled passes.
+///
+/// Those files are the ones it references, transitively, the ones referencing
+/// its declarations (which drive its unused warnings), and the ones declaring
+/// the same names. VAR_EXTERNALs follow the same global links as diagnostics;
+/// everything else matches by name only, so the set errs on the side of
+/// including too much.
+#[salsa::tracked]
+pub(super) fn dependency_fingerprint_query(
+    db: &dyn salsa::Database,
+    project: ProjectInputs,
+    file_id: FileId,
+) -> u64 {
+    use std::hash::{Hash, Hasher};
+
+    cancellation_checkpoint(db);
+    let files = project.files(db);
+    let Some(target) = files.iter().position(|(id, _)| *id == file_id) else {
+        return 0;
+    };
+    let names: Vec<&Arc<FileNames>> = files
+        .iter()
+        .map(|(_, input)| file_names_query(db, *input))
+        .collect();
+
+    let mut dependencies = FxHashSet::default();
+    let mut stack = vec![target];
+    while let Some(current) = stack.pop() {
+        for (idx, other) in names.iter().enumerate() {
+            if idx != target && !dependencies.contains(&idx) && names[current].references(other) {
+                dependencies.insert(idx);
+                stack.push(idx);
+            }
+        }
+    }
+    let own = names[target];
+    for (idx, other) in names.iter().enumerate() {
+        if idx != target && (other.references(own) || !other.declared.is_disjoint(&own.declared)) {
+            dependencies.insert(idx);
+        }
+    }
+
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    project.diagnostic_passes(db).hash(&mut hasher);
+    for (idx, (dependency_id, input)) in files.iter().enumerate() {
+        if dependencies.contains(&idx) {
+            dependency_id.0.hash(&mut hasher);
+            input.text(db).hash(&mut hasher);
+        }
+    }
+    hasher.finish()
+}
+
 #[salsa::tracked]
 pub(super) fn type_of_query(
     db: &dyn salsa::Database,
@@ -386,6 +756,7 @@ fn empty_analysis() -> Arc<FileAnalysis> {
     Arc::new(FileAnalysis {
         symbols: Arc::new(SymbolTable::default()),
         diagnostics: Arc::new(Vec::new()),
+        summary: DiagnosticsSummary::default(),
     })
 }
 
@@ -168,6 +168,9 @@ impl<'a> SymbolImporter<'a> {
             if let Some(base) = source.extends_name(*old_id) {
                 self.target.set_extends(*new_id, base.clone());
             }
+            if let Some(interfaces) = source.implements_names(*old_id) {
+                self.target.set_implements(*new_id, interfaces.to_vec());
+            }
         }
 
         for new_id in id_map.values() {
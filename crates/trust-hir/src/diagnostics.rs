@@ -3,6 +3,8 @@
 //! This module defines the diagnostic types used to report semantic
 //! errors, warnings, and informational messages.
 
+use std::ops::ControlFlow;
+
 use text_size::TextRange;
 
 /// Severity level of a diagnostic.
@@ -58,6 +60,8 @@ pub enum DiagnosticCode {
     MissingReturn,
     /// Invalid return type.
     InvalidReturnType,
+    /// BOOL used as an integer, or an integer used as a BOOL, without conversion.
+    ImplicitBoolConversion,
 
     // Semantic errors (E300-E399)
     /// Invalid assignment target.
@@ -74,6 +78,8 @@ pub enum DiagnosticCode {
     InvalidTaskConfig,
     /// Unknown task reference in program configuration.
     UnknownTask,
+    /// CASE label overlapping an earlier label of the same CASE statement.
+    DuplicateCaseLabel,
 
     // Warnings (W001-W099)
     /// Unused variable.
@@ -100,15 +106,132 @@ pub enum DiagnosticCode {
     NondeterministicIo,
     /// Shared global accessed by multiple tasks with writes.
     SharedGlobalTaskHazard,
+    /// Integer division of constants truncates in a REAL context.
+    IntegerDivisionTruncation,
+    /// Empty control-flow body or CASE branch.
+    EmptyBlock,
+    /// Exact `=`/`<>` comparison between REAL/LREAL operands.
+    RealEqualityComparison,
+    /// Inconsistent SFC step/transition annotation pragmas.
+    SfcPragmaInconsistency,
+    /// Direct-address variables occupying overlapping `%I`/`%Q`/`%M` locations.
+    OverlappingDirectAddress,
+    /// Function block instance passed by value to a VAR_INPUT parameter.
+    FbPassedByValue,
 
     // Info/Hints (I001-I099)
     /// Suggested simplification.
     Simplification,
     /// Code style suggestion.
     StyleSuggestion,
+    /// ELSE branch of a CASE whose labels already cover every enum value.
+    UnreachableElse,
 }
 
 impl DiagnosticCode {
+    /// Every diagnostic code, in code order.
+    pub const ALL: &'static [DiagnosticCode] = &[
+        Self::UnexpectedToken,
+        Self::MissingToken,
+        Self::UnclosedBlock,
+        Self::UndefinedVariable,
+        Self::UndefinedType,
+        Self::UndefinedFunction,
+        Self::DuplicateDeclaration,
+        Self::CannotResolve,
+        Self::InvalidIdentifier,
+        Self::TypeMismatch,
+        Self::InvalidOperation,
+        Self::IncompatibleAssignment,
+        Self::WrongArgumentCount,
+        Self::InvalidArgumentType,
+        Self::MissingReturn,
+        Self::InvalidReturnType,
+        Self::ImplicitBoolConversion,
+        Self::InvalidAssignmentTarget,
+        Self::ConstantModification,
+        Self::InvalidArrayIndex,
+        Self::OutOfRange,
+        Self::CyclicDependency,
+        Self::InvalidTaskConfig,
+        Self::UnknownTask,
+        Self::DuplicateCaseLabel,
+        Self::UnusedVariable,
+        Self::UnusedParameter,
+        Self::UnreachableCode,
+        Self::MissingElse,
+        Self::ImplicitConversion,
+        Self::ShadowedVariable,
+        Self::Deprecated,
+        Self::HighComplexity,
+        Self::UnusedPou,
+        Self::NondeterministicTimeDate,
+        Self::NondeterministicIo,
+        Self::SharedGlobalTaskHazard,
+        Self::IntegerDivisionTruncation,
+        Self::EmptyBlock,
+        Self::RealEqualityComparison,
+        Self::SfcPragmaInconsistency,
+        Self::OverlappingDirectAddress,
+        Self::FbPassedByValue,
+        Self::Simplification,
+        Self::StyleSuggestion,
+        Self::UnreachableElse,
+    ];
+
+    /// Returns a one-line description of what this code reports.
+    #[must_use]
+    pub fn description(&self) -> &'static str {
+        match self {
+            Self::UnexpectedToken => "Unexpected token",
+            Self::MissingToken => "Missing token",
+            Self::UnclosedBlock => "Unclosed block",
+            Self::UndefinedVariable => "Undefined variable",
+            Self::UndefinedType => "Undefined type",
+            Self::UndefinedFunction => "Undefined function/method",
+            Self::DuplicateDeclaration => "Duplicate declaration",
+            Self::CannotResolve => "Cannot resolve name",
+            Self::InvalidIdentifier => "Invalid identifier",
+            Self::TypeMismatch => "Type mismatch",
+            Self::InvalidOperation => "Invalid operation for type",
+            Self::IncompatibleAssignment => "Incompatible assignment",
+            Self::WrongArgumentCount => "Wrong number of arguments",
+            Self::InvalidArgumentType => "Invalid argument type",
+            Self::MissingReturn => "Missing return value",
+            Self::InvalidReturnType => "Invalid return type",
+            Self::ImplicitBoolConversion => "Implicit conversion between BOOL and an integer",
+            Self::InvalidAssignmentTarget => "Invalid assignment target",
+            Self::ConstantModification => "Constant cannot be modified",
+            Self::InvalidArrayIndex => "Invalid array index",
+            Self::OutOfRange => "Out of range value",
+            Self::CyclicDependency => "Cyclic dependency",
+            Self::InvalidTaskConfig => "Invalid task configuration (missing/invalid PRIORITY)",
+            Self::UnknownTask => "Unknown task reference in program configuration",
+            Self::DuplicateCaseLabel => "Overlapping CASE label",
+            Self::UnusedVariable => "Unused variable",
+            Self::UnusedParameter => "Unused parameter",
+            Self::UnreachableCode => "Unreachable code",
+            Self::MissingElse => "Missing ELSE branch",
+            Self::ImplicitConversion => "Implicit type conversion",
+            Self::ShadowedVariable => "Shadowed variable",
+            Self::Deprecated => "Deprecated feature",
+            Self::HighComplexity => "Cyclomatic complexity exceeds threshold",
+            Self::UnusedPou => "Unused program/function/function block",
+            Self::NondeterministicTimeDate => "Non-deterministic time/date usage",
+            Self::NondeterministicIo => "Non-deterministic I/O timing usage",
+            Self::SharedGlobalTaskHazard => "Shared global accessed by multiple tasks with writes",
+            Self::IntegerDivisionTruncation => "Truncating integer division in a REAL context",
+            Self::EmptyBlock => "Empty control-flow body or CASE branch",
+            Self::RealEqualityComparison => "Exact equality comparison of REAL values",
+            Self::SfcPragmaInconsistency => "Inconsistent SFC step/transition annotation pragmas",
+            Self::OverlappingDirectAddress => "Overlapping direct address",
+            Self::FbPassedByValue => "Function block instance passed by value",
+            Self::Simplification => "Suggested simplification",
+            Self::StyleSuggestion => "Code style suggestion",
+            Self::UnreachableElse => "Unreachable ELSE in an exhaustive enum CASE",
+        }
+    }
+
     /// Returns the string code (e.g., "E101").
     #[must_use]
     pub fn code(&self) -> &'static str {
@@ -132,6 +255,7 @@ impl DiagnosticCode {
             Self::InvalidArgumentType => "E205",
             Self::MissingReturn => "E206",
             Self::InvalidReturnType => "E207",
+            Self::ImplicitBoolConversion => "E208",
             // Semantic
             Self::InvalidAssignmentTarget => "E301",
             Self::ConstantModification => "E302",
@@ -140,6 +264,7 @@ impl DiagnosticCode {
             Self::CyclicDependency => "E305",
             Self::InvalidTaskConfig => "E306",
             Self::UnknownTask => "E307",
+            Self::DuplicateCaseLabel => "E308",
             // Warnings
             Self::UnusedVariable => "W001",
             Self::UnusedParameter => "W002",
@@ -153,9 +278,16 @@ impl DiagnosticCode {
             Self::NondeterministicTimeDate => "W010",
             Self::NondeterministicIo => "W011",
             Self::SharedGlobalTaskHazard => "W012",
+            Self::IntegerDivisionTruncation => "W013",
+            Self::EmptyBlock => "W014",
+            Self::RealEqualityComparison => "W015",
+            Self::SfcPragmaInconsistency => "W016",
+            Self::OverlappingDirectAddress => "W017",
+            Self::FbPassedByValue => "W018",
             // Info
             Self::Simplification => "I001",
             Self::StyleSuggestion => "I002",
+            Self::UnreachableElse => "I003",
         }
     }
 
@@ -180,13 +312,15 @@ impl DiagnosticCode {
             | Self::InvalidArgumentType
             | Self::MissingReturn
             | Self::InvalidReturnType
+            | Self::ImplicitBoolConversion
             | Self::InvalidAssignmentTarget
             | Self::ConstantModification
             | Self::InvalidArrayIndex
             | Self::OutOfRange
             | Self::CyclicDependency
             | Self::InvalidTaskConfig
-            | Self::UnknownTask => DiagnosticSeverity::Error,
+            | Self::UnknownTask
+            | Self::DuplicateCaseLabel => DiagnosticSeverity::Error,
 
             // Warnings
             Self::UnusedVariable
@@ -200,12 +334,34 @@ impl DiagnosticCode {
             | Self::UnusedPou
             | Self::NondeterministicTimeDate
             | Self::NondeterministicIo
-            | Self::SharedGlobalTaskHazard => DiagnosticSeverity::Warning,
+            | Self::SharedGlobalTaskHazard
+            | Self::IntegerDivisionTruncation
+            | Self::EmptyBlock
+            | Self::RealEqualityComparison
+            | Self::SfcPragmaInconsistency
+            | Self::OverlappingDirectAddress
+            | Self::FbPassedByValue => DiagnosticSeverity::Warning,
 
             // Info/Hints
             Self::Simplification | Self::StyleSuggestion => DiagnosticSeverity::Hint,
+            Self::UnreachableElse => DiagnosticSeverity::Info,
         }
     }
+
+    /// Returns true for lints derived from statement structure or symbol
+    /// usage, which parse error recovery distorts.
+    #[must_use]
+    pub fn is_structural_lint(&self) -> bool {
+        matches!(
+            self,
+            Self::UnusedVariable
+                | Self::UnusedParameter
+                | Self::UnusedPou
+                | Self::UnreachableCode
+                | Self::EmptyBlock
+                | Self::HighComplexity
+        )
+    }
 }
 
 /// Related information for a diagnostic.
@@ -230,6 +386,8 @@ pub struct Diagnostic {
     pub message: String,
     /// Related information (e.g., "also declared here").
     pub related: Vec<RelatedInfo>,
+    /// The pass that reported the diagnostic; `None` for checks that always run.
+    pub pass: Option<DiagnosticPass>,
 }
 
 impl Diagnostic {
@@ -241,6 +399,7 @@ impl Diagnostic {
             range,
             message: message.into(),
             related: Vec::new(),
+            pass: None,
         }
     }
 
@@ -252,6 +411,7 @@ impl Diagnostic {
             range,
             message: message.into(),
             related: Vec::new(),
+            pass: None,
         }
     }
 
@@ -263,6 +423,7 @@ impl Diagnostic {
             range,
             message: message.into(),
             related: Vec::new(),
+            pass: None,
         }
     }
 
@@ -276,6 +437,13 @@ impl Diagnostic {
         self
     }
 
+    /// Records the pass that reported the diagnostic.
+    #[must_use]
+    pub fn with_pass(mut self, pass: DiagnosticPass) -> Self {
+        self.pass = Some(pass);
+        self
+    }
+
     /// Returns true if this is an error.
     #[must_use]
     pub fn is_error(&self) -> bool {
@@ -302,6 +470,149 @@ impl std::fmt::Display for Diagnostic {
     }
 }
 
+/// Diagnostic counts for a file, split into parse and semantic diagnostics.
+///
+/// Semantic analysis of a file with parse errors runs on a recovered tree, so
+/// clients may treat its results as incomplete.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DiagnosticsSummary {
+    /// Syntax errors reported by the parser.
+    pub parse_errors: usize,
+    /// Semantic diagnostics with error severity.
+    pub semantic_errors: usize,
+    /// Semantic diagnostics with any other severity.
+    pub semantic_warnings: usize,
+    /// Semantic diagnostics dropped as noise from parse error recovery.
+    pub suppressed: usize,
+}
+
+impl DiagnosticsSummary {
+    /// Returns true if the file has syntax errors.
+    #[must_use]
+    pub fn has_parse_errors(&self) -> bool {
+        self.parse_errors > 0
+    }
+}
+
+/// A group of semantic checks that projects can switch off.
+///
+/// Symbol resolution and type checking always run; these passes add the
+/// structural checks and lints on top of them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DiagnosticPass {
+    /// Class, interface, and property checks.
+    Oop,
+    /// `USING` directive checks.
+    Using,
+    /// Configuration and resource checks.
+    Configuration,
+    /// Unreachable code warnings.
+    Unreachable,
+    /// Empty block warnings.
+    EmptyBlocks,
+    /// SFC pragma checks.
+    SfcPragmas,
+    /// Cyclomatic complexity warnings.
+    Complexity,
+    /// Non-determinism warnings.
+    Nondeterminism,
+    /// Overlapping direct address warnings.
+    AddressOverlap,
+    /// Globals shared between tasks.
+    SharedGlobals,
+    /// Unused symbol warnings.
+    Unused,
+}
+
+impl DiagnosticPass {
+    /// Every pass, in the order the diagnostics driver runs them.
+    pub const ALL: &'static [DiagnosticPass] = &[
+        DiagnosticPass::Oop,
+        DiagnosticPass::Using,
+        DiagnosticPass::Configuration,
+        DiagnosticPass::Unreachable,
+        DiagnosticPass::EmptyBlocks,
+        DiagnosticPass::SfcPragmas,
+        DiagnosticPass::Complexity,
+        DiagnosticPass::Nondeterminism,
+        DiagnosticPass::AddressOverlap,
+        DiagnosticPass::SharedGlobals,
+        DiagnosticPass::Unused,
+    ];
+
+    /// Returns the configuration name of the pass.
+    #[must_use]
+    pub fn name(self) -> &'static str {
+        match self {
+            DiagnosticPass::Oop => "oop",
+            DiagnosticPass::Using => "using",
+            DiagnosticPass::Configuration => "configuration",
+            DiagnosticPass::Unreachable => "unreachable",
+            DiagnosticPass::EmptyBlocks => "empty_blocks",
+            DiagnosticPass::SfcPragmas => "sfc_pragmas",
+            DiagnosticPass::Complexity => "complexity",
+            DiagnosticPass::Nondeterminism => "nondeterminism",
+            DiagnosticPass::AddressOverlap => "address_overlap",
+            DiagnosticPass::SharedGlobals => "shared_globals",
+            DiagnosticPass::Unused => "unused",
+        }
+    }
+
+    /// Looks up a pass by its configuration name, ignoring case and treating
+    /// `-` like `_`.
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        let name = name.trim().to_ascii_lowercase().replace('-', "_");
+        Self::ALL.iter().copied().find(|pass| pass.name() == name)
+    }
+
+    fn bit(self) -> u32 {
+        1 << self as u32
+    }
+}
+
+/// The set of enabled diagnostic passes. All passes are enabled by default.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DiagnosticPasses {
+    mask: u32,
+}
+
+impl Default for DiagnosticPasses {
+    fn default() -> Self {
+        Self::all()
+    }
+}
+
+impl DiagnosticPasses {
+    /// Returns a set with every pass enabled.
+    #[must_use]
+    pub fn all() -> Self {
+        Self {
+            mask: DiagnosticPass::ALL
+                .iter()
+                .fold(0, |mask, pass| mask | pass.bit()),
+        }
+    }
+
+    /// Returns a copy of the set with `pass` disabled.
+    #[must_use]
+    pub fn without(mut self, pass: DiagnosticPass) -> Self {
+        self.disable(pass);
+        self
+    }
+
+    /// Disables `pass`.
+    pub fn disable(&mut self, pass: DiagnosticPass) {
+        self.mask &= !pass.bit();
+    }
+
+    /// Returns true if `pass` runs.
+    #[must_use]
+    pub fn is_enabled(&self, pass: DiagnosticPass) -> bool {
+        self.mask & pass.bit() != 0
+    }
+}
+
 /// Builder for collecting diagnostics.
 #[derive(Debug, Default)]
 pub struct DiagnosticBuilder {
@@ -343,6 +654,23 @@ impl DiagnosticBuilder {
     }
 }
 
+/// Passes the semantic diagnostics of a file to `sink` as each check reports
+/// them, in the order [`SemanticDatabase::diagnostics`] lists them.
+///
+/// Once `sink` returns [`ControlFlow::Break`] the remaining checks are not
+/// run, so callers that keep only the first few diagnostics of a large file
+/// skip the rest of the analysis. Nothing is cached; callers that want the
+/// whole list should use [`SemanticDatabase::diagnostics`].
+///
+/// [`SemanticDatabase::diagnostics`]: crate::db::SemanticDatabase::diagnostics
+pub fn collect_diagnostics_into(
+    db: &crate::Database,
+    file_id: crate::db::FileId,
+    sink: &mut dyn FnMut(Diagnostic) -> ControlFlow<()>,
+) {
+    db.stream_diagnostics_salsa(file_id, sink);
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -378,4 +706,35 @@ mod tests {
         let diagnostics = builder.finish();
         assert_eq!(diagnostics.len(), 2);
     }
+
+    #[test]
+    fn test_diagnostic_code_metadata_is_unique() {
+        let codes: rustc_hash::FxHashSet<_> = DiagnosticCode::ALL
+            .iter()
+            .map(DiagnosticCode::code)
+            .collect();
+        assert_eq!(codes.len(), DiagnosticCode::ALL.len());
+        assert!(DiagnosticCode::ALL
+            .iter()
+            .all(|code| !code.description().is_empty()));
+    }
+
+    #[test]
+    fn test_diagnostic_pass_names_round_trip() {
+        for &pass in DiagnosticPass::ALL {
+            assert_eq!(DiagnosticPass::from_name(pass.name()), Some(pass));
+        }
+        assert_eq!(
+            DiagnosticPass::from_name("Empty-Blocks"),
+            Some(DiagnosticPass::EmptyBlocks)
+        );
+        assert_eq!(DiagnosticPass::from_name("typecheck"), None);
+
+        let passes = DiagnosticPasses::all().without(DiagnosticPass::Complexity);
+        assert!(!passes.is_enabled(DiagnosticPass::Complexity));
+        assert!(DiagnosticPass::ALL
+            .iter()
+            .filter(|&&pass| pass != DiagnosticPass::Complexity)
+            .all(|&pass| passes.is_enabled(pass)));
+    }
 }
@@ -39,9 +39,10 @@ pub mod type_check;
 pub mod types;
 
 pub use db::{Database, SourceDatabase};
-pub use diagnostics::{Diagnostic, DiagnosticSeverity};
+pub use diagnostics::{Diagnostic, DiagnosticPass, DiagnosticPasses, DiagnosticSeverity};
 pub use ident::{is_reserved_keyword, is_valid_identifier};
-pub use project::{Project, SourceKey, SourceRegistry};
+pub use project::{Project, ProjectIndex, SourceKey, SourceRegistry};
 pub use symbols::{Symbol, SymbolId, SymbolKind};
+pub use type_check::const_eval;
 pub use type_check::TypeChecker;
 pub use types::{Type, TypeId};
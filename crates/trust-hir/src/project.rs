@@ -1,9 +1,17 @@
 //! Project-level source registry and database helpers.
 
+mod index;
+
+pub use index::{
+    FileIndex, IndexedReference, IndexedSymbol, IndexedSymbolKind, ProjectIndex,
+    PROJECT_INDEX_VERSION,
+};
+
 use rustc_hash::FxHashMap;
 use std::path::{Component, Path, PathBuf};
 
-use crate::db::{Database, FileId, SourceDatabase};
+use crate::db::{Database, DependencyCycle, FileId, SourceDatabase};
+use crate::symbols::SymbolId;
 
 /// Canonical key for a source file.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -117,6 +125,14 @@ impl Project {
         Self::default()
     }
 
+    /// Create a project around an existing, empty database.
+    pub fn from_database(db: Database) -> Self {
+        Self {
+            db,
+            sources: SourceRegistry::default(),
+        }
+    }
+
     /// Access the database (read-only).
     pub fn database(&self) -> &Database {
         &self.db
@@ -165,6 +181,18 @@ impl Project {
         &self.sources
     }
 
+    /// Functions, function blocks and classes unreachable from every program
+    /// and configuration in the project.
+    pub fn unused_pous(&self) -> Vec<(FileId, SymbolId)> {
+        self.db.unused_pous()
+    }
+
+    /// Programs, POUs, interfaces and types in dependency order, or the
+    /// dependency cycles that prevent one.
+    pub fn build_order(&self) -> Result<Vec<(FileId, SymbolId)>, Vec<DependencyCycle>> {
+        self.db.build_order()
+    }
+
     /// Remove a source and return its file id.
     pub fn remove_source(&mut self, key: &SourceKey) -> Option<FileId> {
         let file_id = self.sources.remove(key)?;
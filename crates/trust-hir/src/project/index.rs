@@ -0,0 +1,363 @@
+//! Portable project index.
+//!
+//! A [`ProjectIndex`] records the declarations, their types and the cross-file
+//! references of every source in a [`Project`]. It is serializable, so a host
+//! can persist it and answer symbol queries from it on the next startup before
+//! any file is reparsed. Each file entry carries the hash of the text it was
+//! built from; entries whose source changed are dropped and rebuilt.
+
+use rowan::NodeOrToken;
+use rustc_hash::{FxHashMap, FxHashSet};
+use serde::{Deserialize, Serialize};
+use std::hash::{Hash, Hasher};
+use text_size::TextRange;
+use trust_syntax::parser::parse;
+use trust_syntax::syntax::{SyntaxKind, SyntaxNode};
+
+use super::{Project, SourceKey};
+use crate::db::{FileId, SemanticDatabase, SourceDatabase};
+use crate::symbols::{Symbol, SymbolKind, SymbolTable, VarQualifier};
+
+/// Format version of [`ProjectIndex`]; bumped whenever the layout changes.
+pub const PROJECT_INDEX_VERSION: u32 = 1;
+
+/// Serializable symbol and reference index of a project.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProjectIndex {
+    version: u32,
+    files: Vec<FileIndex>,
+}
+
+/// Index entry for a single source file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FileIndex {
+    /// Display form of the file's [`SourceKey`].
+    pub key: String,
+    /// Hash of the source text the entry was built from.
+    pub content_hash: u64,
+    /// Declarations in source order.
+    pub symbols: Vec<IndexedSymbol>,
+    /// Uses of names the file does not declare itself.
+    pub references: Vec<IndexedReference>,
+}
+
+/// A declaration recorded in the index.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IndexedSymbol {
+    /// Declared name.
+    pub name: String,
+    /// Kind of declaration.
+    pub kind: IndexedSymbolKind,
+    /// Name of the enclosing declaration, if any.
+    pub container: Option<String>,
+    /// Name of the declared type, for typed declarations.
+    pub type_name: Option<String>,
+    /// Byte range of the declaration name.
+    pub start: u32,
+    /// End of the declaration name range.
+    pub end: u32,
+}
+
+/// Kind of an [`IndexedSymbol`], mirroring [`SymbolKind`] without its payload;
+/// `VAR_GLOBAL` variables get their own kind.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[allow(missing_docs)]
+pub enum IndexedSymbolKind {
+    Program,
+    Configuration,
+    Resource,
+    Task,
+    ProgramInstance,
+    Namespace,
+    Function,
+    FunctionBlock,
+    Class,
+    Method,
+    Property,
+    Interface,
+    Variable,
+    GlobalVariable,
+    Constant,
+    Type,
+    EnumValue,
+    Parameter,
+}
+
+/// A use of a name declared outside the referencing file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IndexedReference {
+    /// Referenced name as written.
+    pub name: String,
+    /// Byte range of the reference.
+    pub start: u32,
+    /// End of the reference range.
+    pub end: u32,
+}
+
+impl ProjectIndex {
+    /// Builds an index covering every source in `project`.
+    pub fn build(project: &Project) -> Self {
+        let mut sources: Vec<(String, FileId)> = project
+            .sources()
+            .iter()
+            .map(|(key, file_id)| (key.display(), file_id))
+            .collect();
+        sources.sort_by(|a, b| a.0.cmp(&b.0));
+        let mut index = Self {
+            version: PROJECT_INDEX_VERSION,
+            files: Vec::with_capacity(sources.len()),
+        };
+        for (key, file_id) in sources {
+            index.files.push(index_file(project, key, file_id));
+        }
+        index
+    }
+
+    /// Returns true if the index was written by this version of the crate.
+    pub fn is_current_version(&self) -> bool {
+        self.version == PROJECT_INDEX_VERSION
+    }
+
+    /// All file entries, ordered by key.
+    pub fn files(&self) -> &[FileIndex] {
+        &self.files
+    }
+
+    /// Returns the entry for `key`.
+    pub fn file(&self, key: &SourceKey) -> Option<&FileIndex> {
+        let key = key.display();
+        self.files.iter().find(|file| file.key == key)
+    }
+
+    /// Returns the entry for `key` if it was built from `text`.
+    pub fn fresh_file(&self, key: &SourceKey, text: &str) -> Option<&FileIndex> {
+        self.file(key)
+            .filter(|file| file.content_hash == hash_content(text))
+    }
+
+    /// Drops entries whose file is missing from `sources` or whose text changed,
+    /// and returns the keys of the sources that must be indexed again.
+    pub fn invalidate<'a>(
+        &mut self,
+        sources: impl IntoIterator<Item = (&'a SourceKey, &'a str)>,
+    ) -> Vec<SourceKey> {
+        if !self.is_current_version() {
+            self.version = PROJECT_INDEX_VERSION;
+            self.files.clear();
+        }
+        let mut current = FxHashMap::default();
+        let mut stale = Vec::new();
+        for (key, text) in sources {
+            let hash = hash_content(text);
+            let display = key.display();
+            let fresh = self
+                .files
+                .iter()
+                .any(|file| file.key == display && file.content_hash == hash);
+            if !fresh {
+                stale.push(key.clone());
+            }
+            current.insert(display, hash);
+        }
+        self.files
+            .retain(|file| current.get(&file.key) == Some(&file.content_hash));
+        stale
+    }
+
+    /// Rebuilds the entries of `keys` from `project`, typically the ones
+    /// returned by [`ProjectIndex::invalidate`].
+    pub fn update(&mut self, project: &Project, keys: &[SourceKey]) {
+        for key in keys {
+            let display = key.display();
+            self.files.retain(|file| file.key != display);
+            let Some(file_id) = project.file_id_for_key(key) else {
+                continue;
+            };
+            self.files.push(index_file(project, display, file_id));
+        }
+        self.files.sort_by(|a, b| a.key.cmp(&b.key));
+    }
+
+    /// Keys of the files declaring `name` where other files can see it.
+    pub fn declaring_files<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a str> + 'a {
+        self.files
+            .iter()
+            .filter(move |file| {
+                file.symbols
+                    .iter()
+                    .any(|symbol| symbol.is_global() && symbol.name.eq_ignore_ascii_case(name))
+            })
+            .map(|file| file.key.as_str())
+    }
+
+    /// Declarations named `name` (case-insensitive) with the key of their file.
+    pub fn find_symbols<'a>(
+        &'a self,
+        name: &'a str,
+    ) -> impl Iterator<Item = (&'a str, &'a IndexedSymbol)> + 'a {
+        self.files.iter().flat_map(move |file| {
+            file.symbols
+                .iter()
+                .filter(move |symbol| symbol.name.eq_ignore_ascii_case(name))
+                .map(move |symbol| (file.key.as_str(), symbol))
+        })
+    }
+
+    /// References to `name` (case-insensitive) from files that do not declare
+    /// it themselves, with the key of the referencing file.
+    pub fn references_to<'a>(
+        &'a self,
+        name: &'a str,
+    ) -> impl Iterator<Item = (&'a str, &'a IndexedReference)> + 'a {
+        self.files.iter().flat_map(move |file| {
+            file.references
+                .iter()
+                .filter(move |reference| reference.name.eq_ignore_ascii_case(name))
+                .map(move |reference| (file.key.as_str(), reference))
+        })
+    }
+}
+
+impl IndexedSymbol {
+    /// Source range of the declaration name.
+    pub fn range(&self) -> TextRange {
+        TextRange::new(self.start.into(), self.end.into())
+    }
+
+    /// Returns true for declarations visible to other files.
+    fn is_global(&self) -> bool {
+        self.container.is_none() || self.kind == IndexedSymbolKind::GlobalVariable
+    }
+}
+
+impl IndexedReference {
+    /// Source range of the reference.
+    pub fn range(&self) -> TextRange {
+        TextRange::new(self.start.into(), self.end.into())
+    }
+}
+
+impl From<&SymbolKind> for IndexedSymbolKind {
+    fn from(kind: &SymbolKind) -> Self {
+        match kind {
+            SymbolKind::Program => Self::Program,
+            SymbolKind::Configuration => Self::Configuration,
+            SymbolKind::Resource => Self::Resource,
+            SymbolKind::Task => Self::Task,
+            SymbolKind::ProgramInstance => Self::ProgramInstance,
+            SymbolKind::Namespace => Self::Namespace,
+            SymbolKind::Function { .. } => Self::Function,
+            SymbolKind::FunctionBlock => Self::FunctionBlock,
+            SymbolKind::Class => Self::Class,
+            SymbolKind::Method { .. } => Self::Method,
+            SymbolKind::Property { .. } => Self::Property,
+            SymbolKind::Interface => Self::Interface,
+            SymbolKind::Variable {
+                qualifier: VarQualifier::Global,
+            } => Self::GlobalVariable,
+            SymbolKind::Variable { .. } => Self::Variable,
+            SymbolKind::Constant => Self::Constant,
+            SymbolKind::Type => Self::Type,
+            SymbolKind::EnumValue { .. } => Self::EnumValue,
+            SymbolKind::Parameter { .. } => Self::Parameter,
+        }
+    }
+}
+
+fn index_file(project: &Project, key: String, file_id: FileId) -> FileIndex {
+    let text = project.database().source_text(file_id);
+    let root = parse(&text).syntax();
+    let symbols = indexed_symbols(&project.database().file_symbols(file_id), &root);
+    let references = external_references(&root, &symbols);
+    FileIndex {
+        key,
+        content_hash: hash_content(&text),
+        symbols,
+        references,
+    }
+}
+
+/// Identifier uses whose name is not declared anywhere in the file, so they
+/// resolve to another file (or to a builtin).
+fn external_references(root: &SyntaxNode, symbols: &[IndexedSymbol]) -> Vec<IndexedReference> {
+    let local: FxHashSet<String> = symbols
+        .iter()
+        .map(|symbol| symbol.name.to_ascii_lowercase())
+        .collect();
+    root.descendants_with_tokens()
+        .filter_map(|element| element.into_token())
+        .filter(|token| token.kind() == SyntaxKind::Ident)
+        .filter(|token| {
+            token
+                .parent()
+                .is_some_and(|parent| parent.kind() == SyntaxKind::NameRef)
+        })
+        .filter(|token| !local.contains(&token.text().to_ascii_lowercase()))
+        .map(|token| {
+            let range = token.text_range();
+            IndexedReference {
+                name: token.text().to_string(),
+                start: range.start().into(),
+                end: range.end().into(),
+            }
+        })
+        .collect()
+}
+
+/// Declarations of a file's own symbol table; builtins have empty ranges and
+/// are left out.
+fn indexed_symbols(symbols: &SymbolTable, root: &SyntaxNode) -> Vec<IndexedSymbol> {
+    let mut indexed: Vec<IndexedSymbol> = symbols
+        .iter()
+        .filter(|symbol| symbol.origin.is_none() && !symbol.range.is_empty())
+        .map(|symbol| IndexedSymbol {
+            name: symbol.name.to_string(),
+            kind: IndexedSymbolKind::from(&symbol.kind),
+            container: symbol
+                .parent
+                .and_then(|parent| symbols.get(parent))
+                .map(|parent| parent.name.to_string()),
+            type_name: declared_type_name(root, symbol),
+            start: symbol.range.start().into(),
+            end: symbol.range.end().into(),
+        })
+        .collect();
+    indexed.sort_by_key(|symbol| (symbol.start, symbol.end));
+    indexed
+}
+
+/// The type written in the declaration of `symbol`.
+///
+/// Taken from the syntax rather than the symbol table: a file's own table
+/// cannot resolve types declared elsewhere, and the entry must only depend on
+/// the file's text.
+fn declared_type_name(root: &SyntaxNode, symbol: &Symbol) -> Option<String> {
+    let typed = matches!(
+        symbol.kind,
+        SymbolKind::Variable { .. }
+            | SymbolKind::Parameter { .. }
+            | SymbolKind::Constant
+            | SymbolKind::Function { .. }
+            | SymbolKind::Method { .. }
+            | SymbolKind::Property { .. }
+    );
+    if !typed || !root.text_range().contains_range(symbol.range) {
+        return None;
+    }
+    let name = match root.covering_element(symbol.range) {
+        NodeOrToken::Node(node) => node,
+        NodeOrToken::Token(token) => token.parent()?,
+    };
+    let type_ref = name.ancestors().take(2).find_map(|node| {
+        node.children()
+            .find(|child| child.kind() == SyntaxKind::TypeRef)
+    })?;
+    let text = type_ref.text().to_string();
+    Some(text.split_whitespace().collect::<Vec<_>>().join(" "))
+}
+
+fn hash_content(content: &str) -> u64 {
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    content.hash(&mut hasher);
+    hasher.finish()
+}
@@ -7,6 +7,7 @@ mod builtins;
 mod defs;
 mod helpers;
 mod table;
+mod visibility;
 
 pub use defs::*;
 pub use table::SymbolTable;
@@ -145,6 +145,17 @@ pub enum VarQualifier {
     Static,
 }
 
+/// Retention qualifier on a VAR section (RETAIN, NON_RETAIN, PERSISTENT).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Retention {
+    /// RETAIN: kept across warm restarts.
+    Retain,
+    /// NON_RETAIN: always re-initialized.
+    NonRetain,
+    /// PERSISTENT: kept across warm and cold restarts.
+    Persistent,
+}
+
 /// Parameter direction.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ParamDirection {
@@ -170,7 +181,7 @@ pub enum Visibility {
     Internal,
 }
 
-/// Modifiers applied to symbols (classes, methods, function blocks).
+/// Modifiers applied to symbols (classes, methods, function blocks, variables).
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub struct SymbolModifiers {
     /// FINAL modifier (class/function block/method).
@@ -179,6 +190,10 @@ pub struct SymbolModifiers {
     pub is_abstract: bool,
     /// OVERRIDE modifier (method).
     pub is_override: bool,
+    /// Retention qualifier of the declaring VAR section (variable).
+    pub retention: Option<Retention>,
+    /// Whether the declaration has an initializer (variable/parameter).
+    pub has_initializer: bool,
 }
 
 /// A symbol in the symbol table.
@@ -206,6 +221,9 @@ pub struct Symbol {
     pub parent: Option<SymbolId>,
     /// Documentation comment, if any.
     pub doc: Option<SmolStr>,
+    /// Message of an `{attribute 'deprecated'}` pragma; empty when the
+    /// attribute carries no message.
+    pub deprecated: Option<SmolStr>,
 }
 
 impl Symbol {
@@ -229,6 +247,7 @@ impl Symbol {
             origin: None,
             parent: None,
             doc: None,
+            deprecated: None,
         }
     }
 
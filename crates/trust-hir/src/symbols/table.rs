@@ -250,6 +250,31 @@ impl SymbolTable {
         self.symbols.get_mut(&id)
     }
 
+    /// Returns the parameter symbols of a POU in declaration order.
+    #[must_use]
+    pub fn parameters_of(&self, owner: SymbolId) -> Vec<&Symbol> {
+        let mut ids = match self.get(owner).map(|symbol| &symbol.kind) {
+            Some(SymbolKind::Function { parameters, .. })
+            | Some(SymbolKind::Method { parameters, .. }) => parameters.clone(),
+            _ => Vec::new(),
+        };
+        if ids.is_empty() {
+            ids = self
+                .iter()
+                .filter(|symbol| {
+                    symbol.parent == Some(owner)
+                        && matches!(symbol.kind, SymbolKind::Parameter { .. })
+                })
+                .map(|symbol| symbol.id)
+                .collect();
+        }
+        ids.sort_by_key(|id| id.0);
+        ids.into_iter()
+            .filter_map(|id| self.get(id))
+            .filter(|symbol| matches!(symbol.kind, SymbolKind::Parameter { .. }))
+            .collect()
+    }
+
     /// Looks up a symbol by name in the global scope.
     #[must_use]
     pub fn lookup(&self, name: &str) -> Option<SymbolId> {
@@ -366,12 +391,16 @@ impl SymbolTable {
     }
 
     /// Registers an array type.
-    pub fn register_array_type(&mut self, element: TypeId, dimensions: Vec<(i64, i64)>) -> TypeId {
+    pub fn register_array_type(
+        &mut self,
+        element: TypeId,
+        dimensions: Vec<Option<(i64, i64)>>,
+    ) -> TypeId {
         // Generate a unique name for the array type
         let elem_name = self.type_name(element).unwrap_or_else(|| SmolStr::new("?"));
         let dims_str: Vec<String> = dimensions
             .iter()
-            .map(|(l, u)| format!("{}..{}", l, u))
+            .map(|bounds| Type::format_array_dimension(*bounds))
             .collect();
         let name = format!("ARRAY[{}] OF {}", dims_str.join(", "), elem_name);
         self.register_type(
@@ -464,6 +493,21 @@ impl SymbolTable {
         self.symbols.values()
     }
 
+    /// Groups symbol IDs by parent, keeping `iter()` order within each group.
+    ///
+    /// Build this once when members of many owners are needed; filtering
+    /// `iter()` by parent for every owner is quadratic in the table size.
+    #[must_use]
+    pub fn children_by_parent(&self) -> FxHashMap<SymbolId, Vec<SymbolId>> {
+        let mut children: FxHashMap<SymbolId, Vec<SymbolId>> = FxHashMap::default();
+        for symbol in self.iter() {
+            if let Some(parent) = symbol.parent {
+                children.entry(parent).or_default().push(symbol.id);
+            }
+        }
+        children
+    }
+
     /// Returns the number of symbols.
     #[must_use]
     pub fn len(&self) -> usize {
@@ -0,0 +1,147 @@
+use super::defs::*;
+use super::table::SymbolTable;
+use rustc_hash::FxHashSet;
+use smol_str::SmolStr;
+
+impl SymbolTable {
+    /// Returns true if `symbol` may be referenced from code in `from_scope`.
+    ///
+    /// PRIVATE members are visible only inside the declaring class or function
+    /// block, PROTECTED members also inside types derived from it, and INTERNAL
+    /// symbols inside the namespace that declares them.
+    #[must_use]
+    pub fn is_accessible(&self, symbol: SymbolId, from_scope: ScopeId) -> bool {
+        let Some(target) = self.get(symbol) else {
+            return false;
+        };
+        let owner = self.member_owner(symbol);
+        match target.visibility {
+            Visibility::Public => true,
+            Visibility::Private => {
+                owner.is_some_and(|owner| self.class_owner_of_scope(from_scope) == Some(owner))
+            }
+            Visibility::Protected => owner.is_some_and(|owner| {
+                self.class_owner_of_scope(from_scope)
+                    .is_some_and(|current| self.is_same_or_derived(current, owner))
+            }),
+            Visibility::Internal => {
+                self.namespace_path_of(owner.unwrap_or(symbol))
+                    == self.namespace_path_of_scope(from_scope)
+            }
+        }
+    }
+
+    /// Returns the class or function block that declares `member`, if any.
+    #[must_use]
+    pub fn member_owner(&self, member: SymbolId) -> Option<SymbolId> {
+        let parent_id = self.get(member)?.parent?;
+        match self.get(parent_id)?.kind {
+            SymbolKind::Class | SymbolKind::FunctionBlock => Some(parent_id),
+            _ => None,
+        }
+    }
+
+    /// Returns the class or function block whose body encloses `scope_id`.
+    ///
+    /// Method and property scopes resolve to the type that declares them.
+    #[must_use]
+    pub fn class_owner_of_scope(&self, scope_id: ScopeId) -> Option<SymbolId> {
+        let mut current = Some(scope_id);
+        while let Some(scope_id) = current {
+            let scope = self.get_scope(scope_id)?;
+            if let Some(symbol) = scope.owner.and_then(|owner| self.get(owner)) {
+                match symbol.kind {
+                    SymbolKind::Class | SymbolKind::FunctionBlock => return Some(symbol.id),
+                    SymbolKind::Method { .. } | SymbolKind::Property { .. } => {
+                        return Some(symbol.parent.unwrap_or(symbol.id));
+                    }
+                    _ => {}
+                }
+            }
+            current = scope.parent;
+        }
+        None
+    }
+
+    /// Returns true if `derived_id` is `base_id` or extends it, directly or indirectly.
+    #[must_use]
+    pub fn is_same_or_derived(&self, derived_id: SymbolId, base_id: SymbolId) -> bool {
+        if derived_id == base_id {
+            return true;
+        }
+        let mut visited = FxHashSet::default();
+        let mut current = self
+            .extends_name(derived_id)
+            .and_then(|name| self.resolve_by_name(name.as_str()));
+        while let Some(symbol_id) = current {
+            if !visited.insert(symbol_id) {
+                break;
+            }
+            if symbol_id == base_id {
+                return true;
+            }
+            current = self
+                .extends_name(symbol_id)
+                .and_then(|name| self.resolve_by_name(name.as_str()));
+        }
+        false
+    }
+
+    /// Returns the namespaces enclosing `symbol_id`, outermost first.
+    #[must_use]
+    pub fn namespace_path_of(&self, symbol_id: SymbolId) -> Vec<SmolStr> {
+        let mut parts = Vec::new();
+        let mut current = self.get(symbol_id).and_then(|sym| sym.parent);
+        while let Some(parent) = current.and_then(|id| self.get(id)) {
+            if matches!(parent.kind, SymbolKind::Namespace) {
+                parts.push(parent.name.clone());
+            }
+            current = parent.parent;
+        }
+        parts.reverse();
+        parts
+    }
+
+    /// Returns the dotted path of `symbol_id` through every enclosing symbol,
+    /// e.g. `Plant.Motors.FB_Pump.Init` for a method of a function block
+    /// declared in nested namespaces.
+    ///
+    /// Returns an empty string for an unknown id.
+    #[must_use]
+    pub fn qualified_name(&self, symbol_id: SymbolId) -> String {
+        self.qualified_path(symbol_id).join(".")
+    }
+
+    /// Returns the segments of [`Self::qualified_name`], outermost first:
+    /// enclosing namespaces, owning POUs or types, then the symbol itself.
+    ///
+    /// Returns an empty path for an unknown id.
+    #[must_use]
+    pub fn qualified_path(&self, symbol_id: SymbolId) -> Vec<SmolStr> {
+        let mut parts = Vec::new();
+        let mut current = Some(symbol_id);
+        while let Some(symbol) = current.and_then(|id| self.get(id)) {
+            parts.push(symbol.name.clone());
+            current = symbol.parent;
+        }
+        parts.reverse();
+        parts
+    }
+
+    /// Returns the namespaces enclosing `scope_id`, outermost first.
+    #[must_use]
+    pub fn namespace_path_of_scope(&self, scope_id: ScopeId) -> Vec<SmolStr> {
+        let mut parts = Vec::new();
+        let mut current = Some(scope_id);
+        while let Some(scope) = current.and_then(|id| self.get_scope(id)) {
+            if matches!(scope.kind, ScopeKind::Namespace) {
+                if let Some(symbol) = scope.owner.and_then(|owner| self.get(owner)) {
+                    parts.push(symbol.name.clone());
+                }
+            }
+            current = scope.parent;
+        }
+        parts.reverse();
+        parts
+    }
+}
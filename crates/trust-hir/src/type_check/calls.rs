@@ -27,6 +27,8 @@ pub(super) struct ParamInfo {
     pub(super) name: SmolStr,
     pub(super) type_id: TypeId,
     pub(super) direction: ParamDirection,
+    /// The declaration has an initializer that applies when the argument is omitted.
+    pub(super) has_default: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -88,8 +90,7 @@ impl<'a, 'b> CallChecker<'a, 'b> {
                         return TypeId::UNKNOWN;
                     };
 
-                    self.check_call_arguments(call_target.param_owner, &call_target.kind, node);
-                    return call_target.return_type;
+                    return self.check_call_target(&call_target, node);
                 }
 
                 if let Some(result) = self
@@ -100,6 +101,18 @@ impl<'a, 'b> CallChecker<'a, 'b> {
                     return result;
                 }
 
+                if let Some(owner) = self.method_owner_name(&name) {
+                    self.checker.diagnostics.error(
+                        DiagnosticCode::UndefinedFunction,
+                        callee.text_range(),
+                        format!(
+                            "'{}' is a method of '{}' and needs an instance receiver (e.g. 'instance.{}()')",
+                            name, owner, name
+                        ),
+                    );
+                    return TypeId::UNKNOWN;
+                }
+
                 self.checker.diagnostics.error(
                     DiagnosticCode::UndefinedFunction,
                     callee.text_range(),
@@ -117,8 +130,7 @@ impl<'a, 'b> CallChecker<'a, 'b> {
             {
                 if let Some(call_target) = self.checker.resolve_ref().resolve_call_target(symbol_id)
                 {
-                    self.check_call_arguments(call_target.param_owner, &call_target.kind, node);
-                    return call_target.return_type;
+                    return self.check_call_target(&call_target, node);
                 }
                 self.checker.diagnostics.error(
                     DiagnosticCode::UndefinedFunction,
@@ -152,8 +164,7 @@ impl<'a, 'b> CallChecker<'a, 'b> {
                             return TypeId::UNKNOWN;
                         };
 
-                        self.check_call_arguments(call_target.param_owner, &call_target.kind, node);
-                        return call_target.return_type;
+                        return self.check_call_target(&call_target, node);
                     }
                 }
             }
@@ -168,19 +179,48 @@ impl<'a, 'b> CallChecker<'a, 'b> {
             .resolve_ref()
             .resolve_call_target_from_type(callee_type)
         {
-            self.check_call_arguments(call_target.param_owner, &call_target.kind, node);
-            return call_target.return_type;
+            return self.check_call_target(&call_target, node);
         }
 
         TypeId::UNKNOWN
     }
 
+    /// Returns the owning type name when `name` is a method declared on some
+    /// function block, class, or interface.
+    fn method_owner_name(&self, name: &str) -> Option<SmolStr> {
+        self.checker
+            .symbols
+            .iter()
+            .filter(|sym| {
+                matches!(sym.kind, SymbolKind::Method { .. }) && sym.name.eq_ignore_ascii_case(name)
+            })
+            .filter_map(|sym| {
+                let owner = self.checker.symbols.get(sym.parent?)?;
+                Some((sym.id.0, owner.name.clone()))
+            })
+            .min_by_key(|(id, _)| *id)
+            .map(|(_, owner)| owner)
+    }
+
+    /// Checks the arguments of a call and returns the call's result type.
+    ///
+    /// Generic (`ANY_*`) return types are narrowed to the common type of the
+    /// arguments bound to parameters declared with the same generic type.
+    fn check_call_target(&mut self, target: &CallTargetInfo, node: &SyntaxNode) -> TypeId {
+        let Some((params, arg_types)) =
+            self.check_call_arguments(target.param_owner, &target.kind, node)
+        else {
+            return target.return_type;
+        };
+        self.infer_generic_return_type(target.return_type, &params, &arg_types)
+    }
+
     pub(super) fn check_call_arguments(
         &mut self,
         symbol_id: SymbolId,
         kind: &SymbolKind,
         node: &SyntaxNode,
-    ) {
+    ) -> Option<(Vec<ParamInfo>, Vec<Option<TypeId>>)> {
         if matches!(kind, SymbolKind::FunctionBlock) {
             let standard_fb = self
                 .checker
@@ -193,13 +233,52 @@ impl<'a, 'b> CallChecker<'a, 'b> {
                     .standard()
                     .check_standard_function_block_call(&name, node)
                 {
-                    return;
+                    return None;
                 }
             }
         }
 
         let params = self.callable_parameters(symbol_id, kind);
         let bound = self.bind_call_arguments(&params, node);
-        self.check_bound_call_argument_types(&params, &bound);
+        if matches!(kind, SymbolKind::Function { .. }) {
+            self.check_missing_function_inputs(&params, &bound, node);
+        }
+        let arg_types = self.check_bound_call_argument_types(&params, &bound);
+        Some((params, arg_types))
+    }
+
+    fn infer_generic_return_type(
+        &self,
+        return_type: TypeId,
+        params: &[ParamInfo],
+        arg_types: &[Option<TypeId>],
+    ) -> TypeId {
+        if !self.checker.is_generic_type(return_type) {
+            return return_type;
+        }
+
+        let mut inferred: Option<TypeId> = None;
+        for (param, arg_type) in params.iter().zip(arg_types) {
+            let Some(arg_type) = *arg_type else {
+                continue;
+            };
+            if param.type_id != return_type
+                || param.direction != ParamDirection::In
+                || arg_type == TypeId::UNKNOWN
+                || !self.checker.is_assignable(return_type, arg_type)
+            {
+                continue;
+            }
+            let arg_type = self.checker.resolve_alias_type(arg_type);
+            inferred = Some(match inferred {
+                None => arg_type,
+                Some(current) if current == arg_type => current,
+                Some(current) => match self.checker.wider_numeric(current, arg_type) {
+                    TypeId::UNKNOWN => return return_type,
+                    wider => wider,
+                },
+            });
+        }
+        inferred.unwrap_or(return_type)
     }
 }
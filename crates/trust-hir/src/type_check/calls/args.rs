@@ -183,13 +183,43 @@ impl<'a, 'b> CallChecker<'a, 'b> {
         }
     }
 
-    pub(in crate::type_check) fn check_bound_call_argument_types(
+    /// Reports function inputs left out of a formal call that have no
+    /// initializer to fall back to.
+    pub(in crate::type_check) fn check_missing_function_inputs(
         &mut self,
         params: &[ParamInfo],
         bound: &BoundArgs,
+        node: &SyntaxNode,
     ) {
-        let formal_call = bound.formal_call;
+        if !bound.formal_call {
+            return;
+        }
         for (param, arg) in params.iter().zip(bound.assigned.iter()) {
+            if arg.is_none()
+                && matches!(param.direction, ParamDirection::In)
+                && !param.has_default
+                && !is_execution_param(param)
+            {
+                self.checker.diagnostics.error(
+                    DiagnosticCode::InvalidArgumentType,
+                    node.text_range(),
+                    format!(
+                        "missing argument for input parameter '{}', which has no default value",
+                        param.name
+                    ),
+                );
+            }
+        }
+    }
+
+    pub(in crate::type_check) fn check_bound_call_argument_types(
+        &mut self,
+        params: &[ParamInfo],
+        bound: &BoundArgs,
+    ) -> Vec<Option<TypeId>> {
+        let formal_call = bound.formal_call;
+        let mut arg_types = vec![None; params.len()];
+        for (index, (param, arg)) in params.iter().zip(bound.assigned.iter()).enumerate() {
             let Some(arg) = arg else {
                 continue;
             };
@@ -220,6 +250,7 @@ impl<'a, 'b> CallChecker<'a, 'b> {
             }
 
             let arg_type = self.checker.expr().check_expression(&arg.expr);
+            arg_types[index] = Some(arg_type);
 
             match param.direction {
                 ParamDirection::In => {
@@ -235,6 +266,8 @@ impl<'a, 'b> CallChecker<'a, 'b> {
                             &arg.expr,
                             arg_type,
                         );
+                        self.checker
+                            .check_int_literal_range(param.type_id, &arg.expr);
                         if !context_literal {
                             self.checker.warn_implicit_conversion(
                                 param.type_id,
@@ -242,6 +275,12 @@ impl<'a, 'b> CallChecker<'a, 'b> {
                                 arg.range,
                             );
                         }
+                        self.checker.warn_fb_passed_by_value(
+                            &param.name,
+                            param.type_id,
+                            arg_type,
+                            arg.range,
+                        );
                     } else {
                         self.checker.diagnostics.error(
                             DiagnosticCode::InvalidArgumentType,
@@ -341,6 +380,7 @@ impl<'a, 'b> CallChecker<'a, 'b> {
                 }
             }
         }
+        arg_types
     }
 
     pub(in crate::type_check) fn check_typed_args_against_params(
@@ -368,6 +408,8 @@ impl<'a, 'b> CallChecker<'a, 'b> {
                             &arg.expr,
                             arg_type,
                         );
+                        self.checker
+                            .check_int_literal_range(param.type_id, &arg.expr);
                         if !context_literal {
                             self.checker.warn_implicit_conversion(
                                 param.type_id,
@@ -375,6 +417,12 @@ impl<'a, 'b> CallChecker<'a, 'b> {
                                 arg.range,
                             );
                         }
+                        self.checker.warn_fb_passed_by_value(
+                            &param.name,
+                            param.type_id,
+                            arg_type,
+                            arg.range,
+                        );
                     } else {
                         self.checker.diagnostics.error(
                             DiagnosticCode::InvalidArgumentType,
@@ -530,6 +578,7 @@ impl<'a, 'b> CallChecker<'a, 'b> {
                         name: sym.name.clone(),
                         type_id: sym.type_id,
                         direction,
+                        has_default: sym.modifiers.has_initializer,
                     }),
                     _ => None,
                 })
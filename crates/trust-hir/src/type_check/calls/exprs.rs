@@ -48,8 +48,11 @@ impl<'a, 'b> CallChecker<'a, 'b> {
                 );
                 return TypeId::UNKNOWN;
             }
-            for ((expr, _, idx_type), (lower, upper)) in index_exprs.iter().zip(dimensions.iter()) {
-                self.check_array_index_bounds(expr, *idx_type, *lower, *upper);
+            for ((expr, _, idx_type), bounds) in index_exprs.iter().zip(dimensions.iter()) {
+                // Non-constant bounds are only known at runtime.
+                if let Some((lower, upper)) = bounds {
+                    self.check_array_index_bounds(expr, *idx_type, *lower, *upper);
+                }
             }
             return element;
         }
@@ -191,21 +194,31 @@ impl<'a, 'b> CallChecker<'a, 'b> {
 
     pub(in crate::type_check) fn infer_deref_expr(&mut self, node: &SyntaxNode) -> TypeId {
         let operand = match node.children().next() {
+            // Dialects that model THIS as a pointer write `THIS^`; both denote the instance.
+            Some(child) if child.kind() == SyntaxKind::ThisExpr => {
+                return self.checker.infer_this_expr(&child);
+            }
             Some(child) => self.checker.expr().check_expression(&child),
             None => return TypeId::UNKNOWN,
         };
 
-        let operand = self.checker.resolve_alias_type(operand);
+        if operand == TypeId::UNKNOWN {
+            return TypeId::UNKNOWN;
+        }
+        let resolved = self.checker.resolve_alias_type(operand);
         if let Some(Type::Pointer { target } | Type::Reference { target }) =
-            self.checker.symbols.type_by_id(operand)
+            self.checker.symbols.type_by_id(resolved)
         {
             return *target;
         }
 
         self.checker.diagnostics.error(
-            DiagnosticCode::TypeMismatch,
+            DiagnosticCode::InvalidOperation,
             node.text_range(),
-            "dereference requires pointer type",
+            format!(
+                "cannot dereference '{}': '^' requires a POINTER TO or REF_TO operand",
+                self.checker.type_name(operand)
+            ),
         );
         TypeId::UNKNOWN
     }
@@ -250,6 +263,10 @@ impl<'a, 'b> CallChecker<'a, 'b> {
         lower: i64,
         upper: i64,
     ) {
+        // Open (`*`) bounds take the size of the array passed in.
+        if (lower, upper) == Type::OPEN_BOUNDS {
+            return;
+        }
         if let Some(value_int) = self.checker.eval_const_int_expr(expr) {
             if value_int < lower || value_int > upper {
                 self.checker.diagnostics.error(
@@ -309,11 +309,7 @@ impl<'a, 'b> ResolveChecker<'a, 'b> {
             return true;
         };
         let owner_id = self.checker.resolve_ref().member_owner(member_id);
-        match self
-            .checker
-            .resolve_ref()
-            .access_status(member.visibility, owner_id)
-        {
+        match self.checker.resolve_ref().access_status(member_id) {
             MemberAccessStatus::Allowed => true,
             MemberAccessStatus::Private => {
                 let mut diagnostic = Diagnostic::error(
@@ -408,13 +404,7 @@ impl<'a, 'b> ResolveChecker<'a, 'b> {
 
 impl<'a, 'b> ResolveCheckerRef<'a, 'b> {
     pub(in crate::type_check) fn member_owner(&self, member_id: SymbolId) -> Option<SymbolId> {
-        let symbol = self.checker.symbols.get(member_id)?;
-        let parent_id = symbol.parent?;
-        let parent = self.checker.symbols.get(parent_id)?;
-        match parent.kind {
-            SymbolKind::Class | SymbolKind::FunctionBlock => Some(parent_id),
-            _ => None,
-        }
+        self.checker.symbols.member_owner(member_id)
     }
 
     pub(in crate::type_check) fn current_class_owner(&self) -> Option<SymbolId> {
@@ -434,42 +424,16 @@ impl<'a, 'b> ResolveCheckerRef<'a, 'b> {
         self.class_owner_from_type(this_type)
     }
 
-    fn access_status(
-        &self,
-        visibility: Visibility,
-        owner_id: Option<SymbolId>,
-    ) -> MemberAccessStatus {
-        let current_owner = self.current_class_owner();
-        match visibility {
-            Visibility::Public => MemberAccessStatus::Allowed,
-            Visibility::Private => {
-                let allowed = owner_id.is_some_and(|owner| current_owner == Some(owner));
-                if allowed {
-                    MemberAccessStatus::Allowed
-                } else {
-                    MemberAccessStatus::Private
-                }
-            }
-            Visibility::Protected => {
-                let allowed = owner_id.is_some_and(|owner| {
-                    current_owner.is_some_and(|current| self.is_same_or_derived(current, owner))
-                });
-                if allowed {
-                    MemberAccessStatus::Allowed
-                } else {
-                    MemberAccessStatus::Protected
-                }
-            }
-            Visibility::Internal => {
-                let allowed = owner_id.is_some_and(|owner| {
-                    self.current_namespace_path() == self.namespace_path_for_symbol(owner)
-                });
-                if allowed {
-                    MemberAccessStatus::Allowed
-                } else {
-                    MemberAccessStatus::Internal
-                }
-            }
+    fn access_status(&self, member_id: SymbolId) -> MemberAccessStatus {
+        let symbols = &self.checker.symbols;
+        if symbols.is_accessible(member_id, self.checker.current_scope) {
+            return MemberAccessStatus::Allowed;
+        }
+        match symbols.get(member_id).map(|member| member.visibility) {
+            Some(Visibility::Private) => MemberAccessStatus::Private,
+            Some(Visibility::Protected) => MemberAccessStatus::Protected,
+            Some(Visibility::Internal) => MemberAccessStatus::Internal,
+            Some(Visibility::Public) | None => MemberAccessStatus::Allowed,
         }
     }
 
@@ -478,75 +442,14 @@ impl<'a, 'b> ResolveCheckerRef<'a, 'b> {
         derived_id: SymbolId,
         base_id: SymbolId,
     ) -> bool {
-        if derived_id == base_id {
-            return true;
-        }
-
-        let mut visited = FxHashSet::default();
-        let mut current = self
-            .checker
-            .symbols
-            .extends_name(derived_id)
-            .and_then(|name| self.checker.symbols.resolve_by_name(name.as_str()));
-
-        while let Some(symbol_id) = current {
-            if !visited.insert(symbol_id) {
-                break;
-            }
-            if symbol_id == base_id {
-                return true;
-            }
-            current = self
-                .checker
-                .symbols
-                .extends_name(symbol_id)
-                .and_then(|name| self.checker.symbols.resolve_by_name(name.as_str()));
-        }
-
-        false
+        self.checker.symbols.is_same_or_derived(derived_id, base_id)
     }
 
     pub(in crate::type_check) fn namespace_path_for_symbol(
         &self,
         symbol_id: SymbolId,
     ) -> Vec<SmolStr> {
-        let mut parts = Vec::new();
-        let mut current = self
-            .checker
-            .symbols
-            .get(symbol_id)
-            .and_then(|sym| sym.parent);
-        while let Some(parent_id) = current {
-            let Some(parent) = self.checker.symbols.get(parent_id) else {
-                break;
-            };
-            if matches!(parent.kind, SymbolKind::Namespace) {
-                parts.push(parent.name.clone());
-            }
-            current = parent.parent;
-        }
-        parts.reverse();
-        parts
-    }
-
-    pub(in crate::type_check) fn current_namespace_path(&self) -> Vec<SmolStr> {
-        let mut parts = Vec::new();
-        let mut current = Some(self.checker.current_scope);
-        while let Some(scope_id) = current {
-            let Some(scope) = self.checker.symbols.get_scope(scope_id) else {
-                break;
-            };
-            if matches!(scope.kind, ScopeKind::Namespace) {
-                if let Some(owner) = scope.owner {
-                    if let Some(symbol) = self.checker.symbols.get(owner) {
-                        parts.push(symbol.name.clone());
-                    }
-                }
-            }
-            current = scope.parent;
-        }
-        parts.reverse();
-        parts
+        self.checker.symbols.namespace_path_of(symbol_id)
     }
 
     pub(in crate::type_check) fn resolve_member_in_type(
@@ -12,6 +12,7 @@ impl<'a, 'b> StandardChecker<'a, 'b> {
             name: SmolStr::new(name),
             type_id,
             direction,
+            has_default: false,
         };
 
         match upper.as_str() {
@@ -75,6 +76,7 @@ impl<'a, 'b> StandardChecker<'a, 'b> {
             name: SmolStr::new(name),
             type_id,
             direction,
+            has_default: false,
         };
 
         let (mut params, pv_index, cv_index) = match name {
@@ -171,6 +173,7 @@ impl<'a, 'b> StandardChecker<'a, 'b> {
             name: SmolStr::new(name),
             type_id,
             direction,
+            has_default: false,
         };
 
         let mut params = vec![
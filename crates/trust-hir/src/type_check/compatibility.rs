@@ -68,6 +68,14 @@ impl<'a> TypeChecker<'a> {
         )
     }
 
+    /// Returns true for the numeric generics (`ANY_NUM`, `ANY_INT`, `ANY_REAL`, ...).
+    pub(super) fn is_generic_numeric_type(&self, type_id: TypeId) -> bool {
+        matches!(
+            self.symbols.type_by_id(self.resolve_alias_type(type_id)),
+            Some(Type::AnyNum | Type::AnyInt | Type::AnyUnsigned | Type::AnySigned | Type::AnyReal)
+        )
+    }
+
     pub(super) fn is_reference_like_type(&self, type_id: TypeId) -> bool {
         let resolved = self.resolve_alias_type(type_id);
         matches!(
@@ -76,6 +84,21 @@ impl<'a> TypeChecker<'a> {
         )
     }
 
+    /// True when assigning `source` to `target` keeps every guarantee of the
+    /// source: a pointer or reference to a derived type stored as one to its
+    /// base, or a `REF_TO` stored in a `POINTER TO`. `POINTER TO` into
+    /// `REF_TO` is not widening, since a pointer may hold an address no
+    /// reference could.
+    pub(super) fn is_reference_widening(&self, target: TypeId, source: TypeId) -> bool {
+        matches!(
+            (self.resolved_type(target), self.resolved_type(source)),
+            (
+                Some(Type::Pointer { .. }),
+                Some(Type::Pointer { .. } | Type::Reference { .. })
+            ) | (Some(Type::Reference { .. }), Some(Type::Reference { .. }))
+        )
+    }
+
     pub(super) fn is_assignable(&self, target: TypeId, source: TypeId) -> bool {
         let target = self.resolve_alias_type(target);
         let source = self.resolve_alias_type(source);
@@ -117,13 +140,15 @@ impl<'a> TypeChecker<'a> {
                 if td.len() != sd.len() {
                     return false;
                 }
-                for ((t_lower, t_upper), (s_lower, s_upper)) in td.iter().zip(sd.iter()) {
-                    let wildcard_target = *t_lower == 0 && *t_upper == i64::MAX;
-                    let wildcard_source = *s_lower == 0 && *s_upper == i64::MAX;
-                    if wildcard_target || wildcard_source {
+                for (target_bounds, source_bounds) in td.iter().zip(sd.iter()) {
+                    let (Some(target_bounds), Some(source_bounds)) = (target_bounds, source_bounds)
+                    else {
+                        return false;
+                    };
+                    if *target_bounds == Type::OPEN_BOUNDS || *source_bounds == Type::OPEN_BOUNDS {
                         continue;
                     }
-                    if t_lower != s_lower || t_upper != s_upper {
+                    if target_bounds != source_bounds {
                         return false;
                     }
                 }
@@ -133,7 +158,13 @@ impl<'a> TypeChecker<'a> {
                 )
             }
 
-            (Type::Reference { target: tt }, Type::Reference { target: ts }) => {
+            // Pointers and references convert into each other when the
+            // pointed-to types match or the source points to a derived type;
+            // see `is_reference_widening` for which directions also warn.
+            (Type::Reference { target: tt }, Type::Reference { target: ts })
+            | (Type::Pointer { target: tt }, Type::Pointer { target: ts })
+            | (Type::Pointer { target: tt }, Type::Reference { target: ts })
+            | (Type::Reference { target: tt }, Type::Pointer { target: ts }) => {
                 self.reference_types_compatible(*tt, *ts)
             }
 
@@ -157,6 +188,10 @@ impl<'a> TypeChecker<'a> {
                 self.is_interface_assignable(target_id, source_id)
             }
 
+            // Distinct enumeration types never convert into each other, even
+            // when their ordinals coincide, and integers do not convert to enums.
+            (Type::Enum { .. }, _) => false,
+
             // Numeric widening (safe conversions)
             (Type::Int, Type::SInt) => true,
             (Type::DInt, Type::SInt | Type::Int) => true,
@@ -197,6 +232,33 @@ impl<'a> TypeChecker<'a> {
         }
     }
 
+    /// Describes why a value of type `source` cannot be assigned to `target`.
+    pub(super) fn assignment_mismatch_message(&self, target: TypeId, source: TypeId) -> String {
+        let target_name = self.type_name(target);
+        let value_name = self.type_name(source);
+        match (self.resolved_type(target), self.resolved_type(source)) {
+            (Some(Type::Enum { .. }), Some(Type::Enum { .. })) => format!(
+                "cannot assign '{}' to '{}': values of different enumeration types are not compatible",
+                value_name, target_name
+            ),
+            (Some(Type::Enum { .. }), Some(source)) if source.is_integer() => format!(
+                "cannot assign '{}' to enumeration '{}'; use a '{}#...' value",
+                value_name, target_name, target_name
+            ),
+            (
+                Some(Type::Pointer { target } | Type::Reference { target }),
+                Some(Type::Pointer { target: source } | Type::Reference { target: source }),
+            ) => format!(
+                "cannot assign '{}' to '{}': '{}' is not compatible with pointed-to type '{}'",
+                value_name,
+                target_name,
+                self.type_name(*source),
+                self.type_name(*target)
+            ),
+            _ => format!("cannot assign '{}' to '{}'", value_name, target_name),
+        }
+    }
+
     pub(super) fn reference_types_compatible(&self, target: TypeId, source: TypeId) -> bool {
         let target = self.resolve_subrange_base(self.resolve_alias_type(target));
         let source = self.resolve_subrange_base(self.resolve_alias_type(source));
@@ -1,38 +1,136 @@
+//! Compile-time evaluation of constant expressions.
+//!
+//! One folder serves every caller: the type checker folds integer expressions
+//! for array bounds, CASE labels and range checks, diagnostics fold boolean
+//! conditions, and [`try_fold`] is the public entry point for IDE features that
+//! want the value of an arbitrary expression node.
+
 use super::literals::{
-    int_binary_op_from_node, int_unary_op_from_node, parse_int_literal_from_node, IntBinaryOp,
-    IntUnaryOp,
+    int_literal_expr_value, int_type_range, int_unary_op_from_node, parse_int_literal_from_node,
+    real_literal_value, IntUnaryOp,
 };
 use super::*;
+use crate::db::{FileId, SemanticDatabase};
+use crate::Database;
 
 impl<'a> TypeChecker<'a> {
     pub(super) fn eval_const_int_expr(&self, node: &SyntaxNode) -> Option<i64> {
+        match ConstFolder::new(self.symbols, self.current_scope).fold(node)? {
+            ConstValue::Int(value) => Some(value),
+            _ => None,
+        }
+    }
+}
+
+/// Value of a constant expression.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConstValue {
+    /// Integer, enumeration or bit-string value.
+    Int(i64),
+    /// REAL or LREAL value.
+    Real(f64),
+    /// BOOL value.
+    Bool(bool),
+    /// String literal as written, including its quotes.
+    String(SmolStr),
+}
+
+impl std::fmt::Display for ConstValue {
+    /// Renders the value as a Structured Text literal.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Int(value) => write!(f, "{value}"),
+            Self::Real(value) => write!(f, "{value:?}"),
+            Self::Bool(true) => f.write_str("TRUE"),
+            Self::Bool(false) => f.write_str("FALSE"),
+            Self::String(text) => f.write_str(text),
+        }
+    }
+}
+
+/// Folds the expression `node` of `file_id` to a constant value.
+///
+/// Literals, enumeration values and references to `CONSTANT` variables fold,
+/// as do arithmetic, bitwise and comparison operators over them. Returns
+/// `None` as soon as the expression depends on anything only known at runtime.
+pub fn try_fold(db: &Database, file_id: FileId, node: &SyntaxNode) -> Option<ConstValue> {
+    let symbols = db.file_symbols(file_id);
+    fold_with_symbols(&symbols, node)
+}
+
+/// Folds `node` against an already collected symbol table, for diagnostics
+/// that run inside file analysis.
+pub(crate) fn fold_with_symbols(symbols: &SymbolTable, node: &SyntaxNode) -> Option<ConstValue> {
+    ConstFolder::new(symbols, ScopeId::GLOBAL).fold(node)
+}
+
+/// Looks up an integer constant by declaring scope and name.
+pub(crate) type ConstLookup<'a> = dyn Fn(&Option<SmolStr>, &str) -> Option<i64> + 'a;
+
+/// Folds `node` to an integer while the symbol table is still being collected
+/// and its integer constants are not yet published; `consts` looks them up by
+/// scope and name instead.
+pub(crate) fn fold_int_with_consts(
+    symbols: &SymbolTable,
+    consts: &ConstLookup<'_>,
+    node: &SyntaxNode,
+) -> Option<i64> {
+    let mut folder = ConstFolder::new(symbols, ScopeId::GLOBAL);
+    folder.consts = Some(consts);
+    match folder.fold(node)? {
+        ConstValue::Int(value) => Some(value),
+        _ => None,
+    }
+}
+
+/// The constant folder shared by the symbol collector, the type checker,
+/// diagnostics and [`try_fold`].
+struct ConstFolder<'a> {
+    symbols: &'a SymbolTable,
+    /// Scope in which typed-literal prefixes such as `Color#Red` resolve.
+    scope: ScopeId,
+    /// Integer constants by scope and name, when not read from `symbols`.
+    consts: Option<&'a ConstLookup<'a>>,
+    /// Constant declarations being folded, to cut reference cycles.
+    visiting: FxHashSet<TextRange>,
+}
+
+impl<'a> ConstFolder<'a> {
+    fn new(symbols: &'a SymbolTable, scope: ScopeId) -> Self {
+        Self {
+            symbols,
+            scope,
+            consts: None,
+            visiting: FxHashSet::default(),
+        }
+    }
+
+    fn const_value(&self, scope: &Option<SmolStr>, name: &str) -> Option<i64> {
+        match self.consts {
+            Some(consts) => consts(scope, name),
+            None => self.symbols.const_value(scope, name),
+        }
+    }
+
+    fn fold(&mut self, node: &SyntaxNode) -> Option<ConstValue> {
         match node.kind() {
-            SyntaxKind::Literal => {
-                if let Some(value) = parse_int_literal_from_node(node) {
-                    return Some(value);
-                }
-                self.enum_value_from_typed_literal(node)
-            }
-            SyntaxKind::NameRef => {
-                let name = self.resolve_ref().get_name_from_ref(node)?;
-                for scope in self.const_scope_chain() {
-                    if let Some(value) = self.symbols.const_value(&scope, name.as_str()) {
-                        return Some(value);
-                    }
-                }
-                self.symbols.enum_value_by_name(name.as_str())
-            }
-            SyntaxKind::ParenExpr => node
-                .children()
-                .next()
-                .and_then(|child| self.eval_const_int_expr(&child)),
+            SyntaxKind::Literal => self.fold_literal(node),
+            SyntaxKind::NameRef => self.fold_name(node),
+            SyntaxKind::ParenExpr => self.fold(&node.children().next()?),
             SyntaxKind::UnaryExpr => {
-                let op = int_unary_op_from_node(node)?;
-                let expr = node.children().next()?;
-                let value = self.eval_const_int_expr(&expr)?;
-                match op {
-                    IntUnaryOp::Plus => Some(value),
-                    IntUnaryOp::Minus => value.checked_neg(),
+                let value = self.fold(&node.children().next()?)?;
+                match (UnaryOp::from_node(node), value) {
+                    (UnaryOp::Neg, ConstValue::Int(value)) => {
+                        value.checked_neg().map(ConstValue::Int)
+                    }
+                    (UnaryOp::Neg, ConstValue::Real(value)) => Some(ConstValue::Real(-value)),
+                    (UnaryOp::Not, ConstValue::Bool(value)) => Some(ConstValue::Bool(!value)),
+                    (UnaryOp::Unknown, value @ (ConstValue::Int(_) | ConstValue::Real(_)))
+                        if matches!(int_unary_op_from_node(node), Some(IntUnaryOp::Plus)) =>
+                    {
+                        Some(value)
+                    }
+                    _ => None,
                 }
             }
             SyntaxKind::BinaryExpr => {
@@ -40,110 +138,244 @@ impl<'a> TypeChecker<'a> {
                 if children.len() < 2 {
                     return None;
                 }
-                let lhs = self.eval_const_int_expr(&children[0])?;
-                let rhs = self.eval_const_int_expr(&children[children.len() - 1])?;
-                match int_binary_op_from_node(node)? {
-                    IntBinaryOp::Add => lhs.checked_add(rhs),
-                    IntBinaryOp::Sub => lhs.checked_sub(rhs),
-                    IntBinaryOp::Mul => lhs.checked_mul(rhs),
-                    IntBinaryOp::Div => {
-                        if rhs == 0 {
-                            None
-                        } else {
-                            lhs.checked_div(rhs)
-                        }
-                    }
-                    IntBinaryOp::Mod => {
-                        if rhs == 0 {
-                            None
-                        } else {
-                            lhs.checked_rem(rhs)
-                        }
-                    }
-                    IntBinaryOp::Power => {
-                        if rhs < 0 {
-                            None
-                        } else {
-                            lhs.checked_pow(rhs as u32)
-                        }
-                    }
-                }
+                let lhs = self.fold(&children[0])?;
+                let rhs = self.fold(&children[children.len() - 1])?;
+                fold_binary(BinaryOp::from_node(node), lhs, rhs)
             }
             _ => None,
         }
     }
 
-    fn enum_value_from_typed_literal(&self, node: &SyntaxNode) -> Option<i64> {
-        let mut type_name = None;
+    fn fold_literal(&self, node: &SyntaxNode) -> Option<ConstValue> {
+        let mut prefix = None;
         for token in node
             .descendants_with_tokens()
-            .filter_map(|e| e.into_token())
+            .filter_map(|element| element.into_token())
         {
-            if token.kind() != SyntaxKind::TypedLiteralPrefix {
-                continue;
+            match token.kind() {
+                SyntaxKind::KwTrue => return Some(ConstValue::Bool(true)),
+                SyntaxKind::KwFalse => return Some(ConstValue::Bool(false)),
+                SyntaxKind::StringLiteral | SyntaxKind::WideStringLiteral => {
+                    return Some(ConstValue::String(SmolStr::new(token.text())));
+                }
+                SyntaxKind::TypedLiteralPrefix => {
+                    prefix = Some(token.text().trim_end_matches('#').to_ascii_uppercase());
+                }
+                _ => {}
             }
-            type_name = Some(token.text().trim_end_matches('#').to_string());
-            break;
         }
-        let type_name = type_name?;
-        let type_id = self
-            .resolve_ref()
-            .resolve_type_by_name(type_name.as_str())?;
-        let resolved = self.resolve_alias_type(type_id);
-        let Type::Enum { values, .. } = self.symbols.type_by_id(resolved)? else {
-            return None;
-        };
-
-        let text = node.text().to_string();
-        let (_, value_text) = text.split_once('#')?;
-        let value_text = value_text.trim();
-        if value_text.is_empty() {
-            return None;
+        let negated = node
+            .descendants_with_tokens()
+            .any(|element| element.kind() == SyntaxKind::Minus);
+        match prefix.as_deref() {
+            Some("REAL" | "LREAL") => {
+                let value = real_literal_value(node)
+                    .or_else(|| parse_int_literal_from_node(node).map(|value| value as f64))?;
+                Some(ConstValue::Real(if negated { -value } else { value }))
+            }
+            Some("BOOL") => match int_literal_expr_value(node)? {
+                0 => Some(ConstValue::Bool(false)),
+                1 => Some(ConstValue::Bool(true)),
+                _ => None,
+            },
+            Some(prefix) if self.enum_values(prefix).is_some() => {
+                self.enum_literal_value(node, prefix).map(ConstValue::Int)
+            }
+            Some(prefix)
+                if TypeId::from_builtin_name(prefix)
+                    .and_then(int_type_range)
+                    .is_none() =>
+            {
+                None
+            }
+            _ => {
+                if let Some(value) = real_literal_value(node) {
+                    return Some(ConstValue::Real(if negated { -value } else { value }));
+                }
+                let value = int_literal_expr_value(node)?;
+                i64::try_from(value).ok().map(ConstValue::Int)
+            }
         }
-        let value_name = value_text
-            .split('.')
-            .next_back()
-            .map(str::trim)
-            .unwrap_or(value_text);
+    }
 
+    fn enum_literal_value(&self, node: &SyntaxNode, type_name: &str) -> Option<i64> {
+        let values = self.enum_values(type_name)?;
+        let text = node.text().to_string();
+        let (_, member) = text.split_once('#')?;
+        let member = member.trim().rsplit('.').next()?.trim();
         values
             .iter()
-            .find(|(name, _)| name.eq_ignore_ascii_case(value_name))
+            .find(|(name, _)| name.eq_ignore_ascii_case(member))
             .map(|(_, value)| *value)
     }
 
-    pub(super) fn const_scope_chain(&self) -> Vec<Option<SmolStr>> {
-        let mut scopes = Vec::new();
-        let mut current = Some(self.current_scope);
+    /// Members of the enumeration `type_name` names from the folder's scope.
+    fn enum_values(&self, type_name: &str) -> Option<&'a [(SmolStr, i64)]> {
+        let type_id = if type_name.contains('.') {
+            let parts: Vec<SmolStr> = type_name.split('.').map(SmolStr::new).collect();
+            let symbol = self.symbols.get(self.symbols.resolve_qualified(&parts)?)?;
+            symbol.is_type().then_some(symbol.type_id)?
+        } else {
+            self.symbols
+                .resolve(type_name, self.scope)
+                .and_then(|id| self.symbols.get(id))
+                .filter(|symbol| symbol.is_type())
+                .map(|symbol| symbol.type_id)
+                .or_else(|| self.symbols.lookup_type(type_name))?
+        };
+        match self
+            .symbols
+            .type_by_id(self.symbols.resolve_alias_type(type_id))?
+        {
+            Type::Enum { values, .. } => Some(values),
+            _ => None,
+        }
+    }
 
-        while let Some(scope_id) = current {
-            let Some(scope) = self.symbols.get_scope(scope_id) else {
-                break;
-            };
+    fn fold_name(&mut self, node: &SyntaxNode) -> Option<ConstValue> {
+        let name = node
+            .descendants_with_tokens()
+            .filter_map(|element| element.into_token())
+            .find(|token| token.kind() == SyntaxKind::Ident)?;
+        let name = name.text();
 
-            if matches!(
-                scope.kind,
-                ScopeKind::Program
-                    | ScopeKind::Function
-                    | ScopeKind::FunctionBlock
-                    | ScopeKind::Class
-                    | ScopeKind::Method
-                    | ScopeKind::Property
-                    | ScopeKind::Namespace
-                    | ScopeKind::Configuration
-                    | ScopeKind::Resource
-            ) {
-                if let Some(owner) = scope.owner {
-                    if let Some(symbol) = self.symbols.get(owner) {
-                        scopes.push(Some(symbol.name.clone()));
-                    }
+        let mut scopes = Vec::new();
+        for ancestor in node.ancestors() {
+            if let Some(var_decl) = var_decl_in(&ancestor, name) {
+                if !is_constant_block(&var_decl.parent()?) {
+                    return None;
+                }
+                let initializer = var_decl
+                    .children()
+                    .find(|child| is_expression_kind(child.kind()))?;
+                if !self.visiting.insert(var_decl.text_range()) {
+                    return None;
                 }
+                let value = self.fold(&initializer);
+                self.visiting.remove(&var_decl.text_range());
+                return value;
+            }
+            if let Some(owner) = scope_owner_name(&ancestor) {
+                scopes.push(Some(owner));
             }
-
-            current = scope.parent;
         }
-
         scopes.push(None);
+
         scopes
+            .iter()
+            .find_map(|scope| self.const_value(scope, name))
+            .or_else(|| self.symbols.enum_value_by_name(name))
+            .map(ConstValue::Int)
+    }
+}
+
+fn fold_binary(op: BinaryOp, lhs: ConstValue, rhs: ConstValue) -> Option<ConstValue> {
+    use ConstValue::{Bool, Int, Real};
+    let value = match (lhs, rhs) {
+        (Int(lhs), Int(rhs)) => match op {
+            BinaryOp::Add => Int(lhs.checked_add(rhs)?),
+            BinaryOp::Sub => Int(lhs.checked_sub(rhs)?),
+            BinaryOp::Mul => Int(lhs.checked_mul(rhs)?),
+            BinaryOp::Div => Int(lhs.checked_div(rhs)?),
+            BinaryOp::Mod => Int(lhs.checked_rem(rhs)?),
+            BinaryOp::Power => Int(lhs.checked_pow(u32::try_from(rhs).ok()?)?),
+            BinaryOp::And => Int(lhs & rhs),
+            BinaryOp::Or => Int(lhs | rhs),
+            BinaryOp::Xor => Int(lhs ^ rhs),
+            op => Bool(compare(op, lhs.cmp(&rhs))?),
+        },
+        (Bool(lhs), Bool(rhs)) => match op {
+            BinaryOp::And => Bool(lhs && rhs),
+            BinaryOp::Or => Bool(lhs || rhs),
+            BinaryOp::Xor => Bool(lhs ^ rhs),
+            op => Bool(compare(op, lhs.cmp(&rhs))?),
+        },
+        (lhs @ (Int(_) | Real(_)), rhs @ (Int(_) | Real(_))) => {
+            let as_real = |value| match value {
+                Int(value) => value as f64,
+                Real(value) => value,
+                _ => unreachable!(),
+            };
+            let (lhs, rhs) = (as_real(lhs), as_real(rhs));
+            let result = match op {
+                BinaryOp::Add => lhs + rhs,
+                BinaryOp::Sub => lhs - rhs,
+                BinaryOp::Mul => lhs * rhs,
+                BinaryOp::Div if rhs != 0.0 => lhs / rhs,
+                BinaryOp::Power => lhs.powf(rhs),
+                op => return Some(Bool(compare(op, lhs.partial_cmp(&rhs)?)?)),
+            };
+            if !result.is_finite() {
+                return None;
+            }
+            Real(result)
+        }
+        _ => return None,
+    };
+    Some(value)
+}
+
+fn compare(op: BinaryOp, ordering: std::cmp::Ordering) -> Option<bool> {
+    Some(match op {
+        BinaryOp::Eq => ordering.is_eq(),
+        BinaryOp::Neq => ordering.is_ne(),
+        BinaryOp::Lt => ordering.is_lt(),
+        BinaryOp::LtEq => ordering.is_le(),
+        BinaryOp::Gt => ordering.is_gt(),
+        BinaryOp::GtEq => ordering.is_ge(),
+        _ => return None,
+    })
+}
+
+/// Finds the declaration of `name` in the variable blocks directly inside `scope`.
+fn var_decl_in(scope: &SyntaxNode, name: &str) -> Option<SyntaxNode> {
+    scope
+        .children()
+        .filter(|child| child.kind() == SyntaxKind::VarBlock)
+        .flat_map(|block| block.children())
+        .filter(|child| child.kind() == SyntaxKind::VarDecl)
+        .find(|var_decl| {
+            var_decl
+                .children()
+                .filter(|child| child.kind() == SyntaxKind::Name)
+                .filter_map(|child| {
+                    child
+                        .descendants_with_tokens()
+                        .filter_map(|element| element.into_token())
+                        .find(|token| token.kind() == SyntaxKind::Ident)
+                })
+                .any(|ident| ident.text().eq_ignore_ascii_case(name))
+        })
+}
+
+fn is_constant_block(block: &SyntaxNode) -> bool {
+    block
+        .children_with_tokens()
+        .any(|element| element.kind() == SyntaxKind::KwConstant)
+}
+
+/// Name of the POU, namespace or configuration element `node` declares, the
+/// scope key under which the symbol table records its integer constants.
+fn scope_owner_name(node: &SyntaxNode) -> Option<SmolStr> {
+    if !matches!(
+        node.kind(),
+        SyntaxKind::Program
+            | SyntaxKind::Function
+            | SyntaxKind::FunctionBlock
+            | SyntaxKind::Class
+            | SyntaxKind::Method
+            | SyntaxKind::Property
+            | SyntaxKind::Interface
+            | SyntaxKind::Namespace
+            | SyntaxKind::Configuration
+            | SyntaxKind::Resource
+    ) {
+        return None;
     }
+    node.children()
+        .find(|child| child.kind() == SyntaxKind::Name)?
+        .descendants_with_tokens()
+        .filter_map(|element| element.into_token())
+        .find(|token| token.kind() == SyntaxKind::Ident)
+        .map(|token| SmolStr::new(token.text()))
 }
@@ -1,4 +1,4 @@
-use super::helpers::direct_address_type;
+use super::helpers::{compile_time_builtin_type, direct_address_type, trimmed_range};
 use super::literals::{
     int_literal_info, is_long_date_literal, is_long_dt_literal, is_long_time_literal,
     is_long_tod_literal, smallest_int_type_for_literal,
@@ -84,6 +84,7 @@ impl<'a, 'b> ExprChecker<'a, 'b> {
                 return type_id;
             }
             if let Some(type_id) = self.checker.symbols.lookup_type(type_name) {
+                self.check_enum_literal_member(node, type_name, type_id);
                 return type_id;
             }
             return TypeId::UNKNOWN;
@@ -140,6 +141,31 @@ impl<'a, 'b> ExprChecker<'a, 'b> {
         TypeId::UNKNOWN
     }
 
+    /// Validates the member of a qualified enum literal (`Color#Red`).
+    fn check_enum_literal_member(&mut self, node: &SyntaxNode, type_name: &str, type_id: TypeId) {
+        let resolved = self.checker.resolve_alias_type(type_id);
+        let Some(Type::Enum { values, .. }) = self.checker.symbols.type_by_id(resolved) else {
+            return;
+        };
+        let Some(member) = node
+            .descendants_with_tokens()
+            .filter_map(|e| e.into_token())
+            .find(|token| token.kind() == SyntaxKind::Ident)
+        else {
+            return;
+        };
+        let known = values
+            .iter()
+            .any(|(name, _)| name.eq_ignore_ascii_case(member.text()));
+        if !known {
+            self.checker.diagnostics.error(
+                DiagnosticCode::CannotResolve,
+                member.text_range(),
+                format!("enum '{}' has no value '{}'", type_name, member.text()),
+            );
+        }
+    }
+
     fn infer_name_ref(&mut self, node: &SyntaxNode) -> TypeId {
         if let Some(token) = node
             .descendants_with_tokens()
@@ -153,6 +179,9 @@ impl<'a, 'b> ExprChecker<'a, 'b> {
             Some(n) => n,
             None => return TypeId::UNKNOWN,
         };
+        if let Some(type_id) = compile_time_builtin_type(&name) {
+            return type_id;
+        }
 
         match self
             .checker
@@ -179,7 +208,7 @@ impl<'a, 'b> ExprChecker<'a, 'b> {
             None => {
                 self.checker.diagnostics.error(
                     DiagnosticCode::UndefinedVariable,
-                    node.text_range(),
+                    trimmed_range(node),
                     format!("undefined identifier '{}'", name),
                 );
                 TypeId::UNKNOWN
@@ -202,12 +231,18 @@ impl<'a, 'b> ExprChecker<'a, 'b> {
 
         if op.is_comparison() {
             self.check_comparable(lhs_type, rhs_type, node.text_range());
+            if matches!(op, BinaryOp::Eq | BinaryOp::Neq) {
+                self.checker.warn_real_equality(lhs_type, rhs_type, node);
+            }
             TypeId::BOOL
         } else if op.is_logical() {
             self.check_boolean(lhs_type, node.text_range());
             self.check_boolean(rhs_type, node.text_range());
             TypeId::BOOL
         } else if op.is_arithmetic() {
+            if matches!(op, BinaryOp::Div | BinaryOp::Mod) {
+                self.checker.check_constant_zero_divisor(op, node, rhs_node);
+            }
             if let (Some(lhs_ty), Some(rhs_ty)) = (
                 self.checker
                     .symbols
@@ -259,6 +294,17 @@ impl<'a, 'b> ExprChecker<'a, 'b> {
         }
     }
 
+    /// Checks an IF/ELSIF/WHILE/UNTIL condition; an integer condition gets a
+    /// diagnostic asking for an explicit comparison.
+    pub(super) fn check_condition(&mut self, type_id: TypeId, range: TextRange) {
+        if !self
+            .checker
+            .report_bool_integer_conversion(TypeId::BOOL, type_id, range)
+        {
+            self.check_boolean(type_id, range);
+        }
+    }
+
     pub(super) fn check_boolean(&mut self, type_id: TypeId, range: TextRange) {
         let type_id = self.checker.resolve_alias_type(type_id);
         if type_id != TypeId::BOOL && type_id != TypeId::UNKNOWN {
@@ -288,7 +334,9 @@ impl<'a, 'b> ExprChecker<'a, 'b> {
             self.checker.symbols.type_by_id(lhs),
             self.checker.symbols.type_by_id(rhs),
         ) {
-            if l.is_numeric() && r.is_numeric() {
+            let lhs_numeric = l.is_numeric() || self.checker.is_generic_numeric_type(lhs);
+            let rhs_numeric = r.is_numeric() || self.checker.is_generic_numeric_type(rhs);
+            if lhs_numeric && rhs_numeric {
                 return;
             }
         }
@@ -321,6 +369,18 @@ impl<'a, 'b> ExprChecker<'a, 'b> {
                 // Return the wider type
                 self.checker.wider_numeric(lhs, rhs)
             }
+            (Some(l), Some(r))
+                if (l.is_numeric() || self.checker.is_generic_numeric_type(lhs))
+                    && (r.is_numeric() || self.checker.is_generic_numeric_type(rhs)) =>
+            {
+                // Arithmetic on generic parameters stays generic; the call site
+                // narrows the result to the concrete argument type.
+                if self.checker.is_generic_numeric_type(lhs) {
+                    lhs
+                } else {
+                    rhs
+                }
+            }
             (None, _) | (_, None) => {
                 // Unknown types - return UNKNOWN
                 TypeId::UNKNOWN
@@ -1,5 +1,9 @@
 use super::literals::string_literal_info;
-use super::literals::{is_untyped_int_literal_expr, is_untyped_real_literal_expr};
+use super::literals::{int_binary_op_from_node, IntBinaryOp};
+use super::literals::{int_literal_expr_value, int_type_range};
+use super::literals::{
+    is_untyped_int_literal_expr, is_untyped_real_literal_expr, real_literal_value,
+};
 use super::*;
 
 impl<'a> TypeChecker<'a> {
@@ -34,11 +38,19 @@ impl<'a> TypeChecker<'a> {
         }
     }
 
+    /// Returns true if `expr` is an untyped integer literal that takes on the
+    /// `expected` type.
+    ///
+    /// A literal `0` also stands for the null pointer when `expected` is a
+    /// `POINTER TO` type, as most vendor dialects accept.
     pub(super) fn is_contextual_int_literal(&self, expected: TypeId, expr: &SyntaxNode) -> bool {
         let expected = self.resolve_alias_type(expected);
         let Some(ty) = self.symbols.type_by_id(expected) else {
             return false;
         };
+        if matches!(ty, Type::Pointer { .. }) {
+            return is_untyped_int_literal_expr(expr) && int_literal_expr_value(expr) == Some(0);
+        }
         let normalized = self.normalize_subrange(ty);
         if !normalized.is_integer() {
             return false;
@@ -76,6 +88,9 @@ impl<'a> TypeChecker<'a> {
         if self.is_generic_type(target) || self.is_generic_type(source) {
             return;
         }
+        if self.is_reference_widening(target, source) {
+            return;
+        }
 
         self.diagnostics.warning(
             DiagnosticCode::ImplicitConversion,
@@ -88,6 +103,168 @@ impl<'a> TypeChecker<'a> {
         );
     }
 
+    /// Warns when a function block instance is bound to a VAR_INPUT of a
+    /// function block type, which copies the whole instance on every call.
+    pub(super) fn warn_fb_passed_by_value(
+        &mut self,
+        param_name: &str,
+        param_type: TypeId,
+        arg_type: TypeId,
+        range: TextRange,
+    ) {
+        let is_fb = |id: TypeId| {
+            matches!(
+                self.symbols.type_by_id(self.resolve_alias_type(id)),
+                Some(Type::FunctionBlock { .. })
+            )
+        };
+        if !is_fb(param_type) || !is_fb(arg_type) {
+            return;
+        }
+        self.diagnostics.warning(
+            DiagnosticCode::FbPassedByValue,
+            range,
+            format!(
+                "function block instance of type '{}' is copied into input parameter '{}'; \
+                 declare it as VAR_IN_OUT to pass the instance by reference",
+                self.type_name(arg_type),
+                param_name
+            ),
+        );
+    }
+
+    /// Reports a BOOL used where an integer is expected, or the reverse.
+    ///
+    /// Returns false without reporting when `target` and `source` are not a
+    /// BOOL/integer pair, so callers can fall back to their generic diagnostic.
+    pub(super) fn report_bool_integer_conversion(
+        &mut self,
+        target: TypeId,
+        source: TypeId,
+        range: TextRange,
+    ) -> bool {
+        let target = self.resolve_subrange_base(target);
+        let source = self.resolve_subrange_base(source);
+        let to_bool = target == TypeId::BOOL && self.is_integer_type(source);
+        let from_bool = source == TypeId::BOOL && self.is_integer_type(target);
+        if !to_bool && !from_bool {
+            return false;
+        }
+        let source_name = self.type_name(source);
+        let target_name = self.type_name(target);
+        let comparison = if to_bool { "compare with <> 0 or " } else { "" };
+        self.diagnostics.error(
+            DiagnosticCode::ImplicitBoolConversion,
+            range,
+            format!(
+                "implicit conversion from '{}' to '{}'; {}use {}_TO_{}",
+                source_name, target_name, comparison, source_name, target_name
+            ),
+        );
+        true
+    }
+
+    fn is_integer_type(&self, type_id: TypeId) -> bool {
+        self.symbols
+            .type_by_id(type_id)
+            .is_some_and(|ty| ty.is_integer())
+    }
+
+    /// Warns when a constant integer division loses its remainder before the
+    /// result is widened into a REAL/LREAL target (e.g. `r := 5 / 2;`).
+    pub(super) fn warn_integer_division_truncation(&mut self, target: TypeId, value: &SyntaxNode) {
+        let target = self.resolve_alias_type(target);
+        let Some(ty) = self.symbols.type_by_id(target) else {
+            return;
+        };
+        if !self.normalize_subrange(ty).is_float() {
+            return;
+        }
+
+        let mut pending = vec![value.clone()];
+        while let Some(node) = pending.pop() {
+            // Arguments of calls (e.g. INT_TO_REAL(5 / 2)) are explicit conversions.
+            if node.kind() == SyntaxKind::CallExpr {
+                continue;
+            }
+            if node.kind() == SyntaxKind::BinaryExpr
+                && matches!(int_binary_op_from_node(&node), Some(IntBinaryOp::Div))
+            {
+                if let Some(quotient) = self.truncated_const_division(&node) {
+                    self.diagnostics.warning(
+                        DiagnosticCode::IntegerDivisionTruncation,
+                        node.text_range(),
+                        format!(
+                            "integer division '{}' truncates to {}.0 in '{}' context; use a REAL operand or an explicit conversion",
+                            node.text().to_string().trim(),
+                            quotient,
+                            self.type_name(target)
+                        ),
+                    );
+                }
+            }
+            pending.extend(node.children());
+        }
+    }
+
+    /// Warns on `=`/`<>` between two REAL/LREAL operands, where rounding makes
+    /// exact equality unreliable.
+    pub(super) fn warn_real_equality(&mut self, lhs: TypeId, rhs: TypeId, node: &SyntaxNode) {
+        let is_float = |id: TypeId| {
+            self.resolved_type(id)
+                .is_some_and(|ty| self.normalize_subrange(ty).is_float())
+        };
+        if !is_float(lhs) || !is_float(rhs) {
+            return;
+        }
+        self.diagnostics.warning(
+            DiagnosticCode::RealEqualityComparison,
+            node.text_range(),
+            format!(
+                "exact comparison '{}' between floating-point operands; compare ABS(a - b) against a tolerance instead",
+                node.text().to_string().trim()
+            ),
+        );
+    }
+
+    /// Rejects `/` and `MOD` whose divisor folds to a constant zero (e.g. `x / 0`).
+    pub(super) fn check_constant_zero_divisor(
+        &mut self,
+        op: BinaryOp,
+        node: &SyntaxNode,
+        divisor: &SyntaxNode,
+    ) {
+        let is_zero = self.eval_const_int_expr(divisor) == Some(0)
+            || real_literal_value(divisor) == Some(0.0);
+        if !is_zero {
+            return;
+        }
+        let message = if matches!(op, BinaryOp::Mod) {
+            "modulo by constant zero"
+        } else {
+            "division by constant zero"
+        };
+        self.diagnostics
+            .error(DiagnosticCode::InvalidOperation, node.text_range(), message);
+    }
+
+    fn truncated_const_division(&self, node: &SyntaxNode) -> Option<i64> {
+        let children: Vec<_> = node.children().collect();
+        if children.len() < 2 {
+            return None;
+        }
+        let (lhs, rhs) = (&children[0], &children[children.len() - 1]);
+        if has_real_typed_prefix(lhs) || has_real_typed_prefix(rhs) {
+            return None;
+        }
+        let lhs = self.eval_const_int_expr(lhs)?;
+        let rhs = self.eval_const_int_expr(rhs)?;
+        if rhs == 0 || lhs.checked_rem(rhs)? == 0 {
+            return None;
+        }
+        lhs.checked_div(rhs)
+    }
+
     fn is_string_family_implicit_ok(&self, target: TypeId, source: TypeId) -> bool {
         let Some(target_ty) = self.symbols.type_by_id(target) else {
             return false;
@@ -129,6 +306,30 @@ impl<'a> TypeChecker<'a> {
         }
     }
 
+    /// Rejects an integer literal whose value does not fit the integer type it
+    /// is assigned or passed to (`300` into SINT, `-1` into UINT).
+    pub(super) fn check_int_literal_range(&mut self, target_type: TypeId, value: &SyntaxNode) {
+        let target = self.resolve_alias_type(target_type);
+        let Some((min, max)) = int_type_range(target) else {
+            return;
+        };
+        let Some(literal) = int_literal_expr_value(value) else {
+            return;
+        };
+        if (min..=max).contains(&literal) {
+            return;
+        }
+        let target_name = self.type_name(target);
+        self.diagnostics.error(
+            DiagnosticCode::OutOfRange,
+            value.text_range(),
+            format!(
+                "value {} out of range for {} ({}..{})",
+                literal, target_name, min, max
+            ),
+        );
+    }
+
     pub(super) fn string_max_len(&self, type_id: TypeId) -> Option<(bool, u32)> {
         let resolved = self.resolve_alias_type(type_id);
         match self.symbols.type_by_id(resolved)? {
@@ -145,6 +346,21 @@ impl<'a> TypeChecker<'a> {
     }
 }
 
+/// Range of `node` without its leading and trailing trivia.
+pub(super) fn trimmed_range(node: &SyntaxNode) -> TextRange {
+    let mut tokens = node
+        .descendants_with_tokens()
+        .filter_map(|e| e.into_token())
+        .filter(|token| !token.kind().is_trivia());
+    match tokens.next() {
+        Some(first) => {
+            let end = tokens.last().unwrap_or_else(|| first.clone());
+            TextRange::new(first.text_range().start(), end.text_range().end())
+        }
+        None => node.text_range(),
+    }
+}
+
 pub(super) fn direct_address_type(text: &str) -> TypeId {
     let bytes = text.as_bytes();
     if bytes.len() < 2 || bytes[0] != b'%' {
@@ -166,3 +382,25 @@ pub(super) fn direct_address_type(text: &str) -> TypeId {
         None => TypeId::BOOL,
     }
 }
+
+/// Type of a compile-time builtin name. `__POUNAME` and `__LINE` are replaced
+/// by the enclosing POU name and the source line when the code is lowered.
+pub(super) fn compile_time_builtin_type(name: &str) -> Option<TypeId> {
+    if name.eq_ignore_ascii_case("__POUNAME") {
+        Some(TypeId::STRING)
+    } else if name.eq_ignore_ascii_case("__LINE") {
+        Some(TypeId::INT)
+    } else {
+        None
+    }
+}
+
+fn has_real_typed_prefix(node: &SyntaxNode) -> bool {
+    node.descendants_with_tokens()
+        .filter_map(|e| e.into_token())
+        .filter(|token| token.kind() == SyntaxKind::TypedLiteralPrefix)
+        .any(|token| {
+            let prefix = token.text().trim_end_matches('#');
+            prefix.eq_ignore_ascii_case("REAL") || prefix.eq_ignore_ascii_case("LREAL")
+        })
+}
@@ -0,0 +1,89 @@
+use super::helpers::trimmed_range;
+use super::*;
+
+impl<'a> TypeChecker<'a> {
+    /// Checks `[...]` initializers of the variables declared directly in `pou`.
+    pub fn check_var_initializers(&mut self, pou: &SyntaxNode) {
+        for var_decl in pou
+            .children()
+            .filter(|child| child.kind() == SyntaxKind::VarBlock)
+            .flat_map(|block| block.children())
+            .filter(|child| child.kind() == SyntaxKind::VarDecl)
+        {
+            let Some(initializer) = var_decl
+                .children()
+                .find(|child| child.kind() == SyntaxKind::ArrayInitializer)
+            else {
+                continue;
+            };
+            let Some(type_id) = self.declared_var_type(&var_decl) else {
+                continue;
+            };
+            let Some(Type::Array { element, .. }) = self.resolved_type(type_id).cloned() else {
+                continue;
+            };
+            self.check_array_initializer(&initializer, element);
+        }
+    }
+
+    fn declared_var_type(&self, var_decl: &SyntaxNode) -> Option<TypeId> {
+        let name = var_decl
+            .children()
+            .find(|child| child.kind() == SyntaxKind::Name)?;
+        let ident = name
+            .descendants_with_tokens()
+            .filter_map(|e| e.into_token())
+            .find(|token| token.kind() == SyntaxKind::Ident)?;
+        let id = self
+            .symbols
+            .lookup_in_scope(self.current_scope, ident.text())?;
+        self.symbols.get(id).map(|symbol| symbol.type_id)
+    }
+
+    fn check_array_initializer(&mut self, initializer: &SyntaxNode, element: TypeId) {
+        for item in initializer.children() {
+            match item.kind() {
+                SyntaxKind::ArrayInitializer => self.check_array_initializer(&item, element),
+                // Repetition: `n(value, ...)`.
+                SyntaxKind::CallExpr if is_repetition(&item) => {
+                    for value in item
+                        .children()
+                        .filter(|child| child.kind() == SyntaxKind::ArgList)
+                        .flat_map(|args| args.children())
+                        .filter_map(|arg| first_expression_child(&arg))
+                    {
+                        self.check_array_element(&value, element);
+                    }
+                }
+                kind if is_expression_kind(kind) => self.check_array_element(&item, element),
+                _ => {}
+            }
+        }
+    }
+
+    fn check_array_element(&mut self, value: &SyntaxNode, element: TypeId) {
+        let value_type = self.expr().check_expression(value);
+        if self.is_assignable(element, value_type)
+            || self.is_contextual_int_literal(element, value)
+            || self.is_contextual_real_literal(element, value)
+        {
+            return;
+        }
+        let value_name = self.type_name(value_type);
+        let element_name = self.type_name(element);
+        self.diagnostics.error(
+            DiagnosticCode::IncompatibleAssignment,
+            trimmed_range(value),
+            format!(
+                "array element of type '{}' does not match element type '{}'",
+                value_name, element_name
+            ),
+        );
+    }
+}
+
+fn is_repetition(call: &SyntaxNode) -> bool {
+    call.children()
+        .next()
+        .is_some_and(|callee| callee.kind() == SyntaxKind::Literal)
+}
@@ -88,6 +88,72 @@ fn parse_int_literal(text: &str) -> Option<IntLiteralInfo> {
     })
 }
 
+/// Returns the value of an integer literal expression: an untyped or
+/// integer-typed literal, optionally signed or parenthesized (`-300`,
+/// `INT#16#FFFF`, `UINT#-1`, `(+7)`).
+///
+/// Values are widened to `i128` so that ULINT literals and negated values
+/// survive range checks against any IEC integer type.
+pub(super) fn int_literal_expr_value(node: &SyntaxNode) -> Option<i128> {
+    match node.kind() {
+        SyntaxKind::Literal => {
+            let mut negated = false;
+            let mut value = None;
+            for token in node
+                .descendants_with_tokens()
+                .filter_map(|e| e.into_token())
+                .filter(|token| !token.kind().is_trivia())
+            {
+                match token.kind() {
+                    SyntaxKind::TypedLiteralPrefix => {
+                        let prefix = token.text().trim_end_matches('#');
+                        int_type_range(TypeId::from_builtin_name(prefix)?)?;
+                    }
+                    // Typed literals carry their sign inside the literal (`INT#-5`).
+                    SyntaxKind::Minus => negated = !negated,
+                    SyntaxKind::Plus => {}
+                    SyntaxKind::IntLiteral => value = Some(parse_wide_int_literal(token.text())?),
+                    _ => return None,
+                }
+            }
+            value.map(|value| if negated { -value } else { value })
+        }
+        SyntaxKind::ParenExpr => int_literal_expr_value(&node.children().next()?),
+        SyntaxKind::UnaryExpr => {
+            let value = int_literal_expr_value(&node.children().next()?)?;
+            match int_unary_op_from_node(node)? {
+                IntUnaryOp::Plus => Some(value),
+                IntUnaryOp::Minus => Some(-value),
+            }
+        }
+        _ => None,
+    }
+}
+
+fn parse_wide_int_literal(text: &str) -> Option<i128> {
+    let cleaned: String = text.chars().filter(|c| *c != '_').collect();
+    match cleaned.split_once('#') {
+        Some((base, digits)) => i128::from_str_radix(digits, base.parse().ok()?).ok(),
+        None => cleaned.parse().ok(),
+    }
+}
+
+/// Returns the representable range of an elementary integer or bit string type.
+pub(super) fn int_type_range(type_id: TypeId) -> Option<(i128, i128)> {
+    let range = match type_id {
+        TypeId::SINT => (i8::MIN.into(), i8::MAX.into()),
+        TypeId::INT => (i16::MIN.into(), i16::MAX.into()),
+        TypeId::DINT => (i32::MIN.into(), i32::MAX.into()),
+        TypeId::LINT => (i64::MIN.into(), i64::MAX.into()),
+        TypeId::USINT | TypeId::BYTE => (0, u8::MAX.into()),
+        TypeId::UINT | TypeId::WORD => (0, u16::MAX.into()),
+        TypeId::UDINT | TypeId::DWORD => (0, u32::MAX.into()),
+        TypeId::ULINT | TypeId::LWORD => (0, u64::MAX.into()),
+        _ => return None,
+    };
+    Some(range)
+}
+
 #[derive(Clone, Copy)]
 pub(super) enum IntUnaryOp {
     Plus,
@@ -235,6 +301,22 @@ pub(super) fn is_untyped_real_literal_expr(node: &SyntaxNode) -> bool {
     }
 }
 
+/// Returns the value of an untyped or REAL/LREAL-typed real literal.
+pub(super) fn real_literal_value(node: &SyntaxNode) -> Option<f64> {
+    match node.kind() {
+        SyntaxKind::Literal => node
+            .descendants_with_tokens()
+            .filter_map(|e| e.into_token())
+            .find(|token| token.kind() == SyntaxKind::RealLiteral)
+            .and_then(|token| token.text().replace('_', "").parse::<f64>().ok()),
+        SyntaxKind::ParenExpr => node
+            .children()
+            .next()
+            .and_then(|child| real_literal_value(&child)),
+        _ => None,
+    }
+}
+
 #[derive(Clone, Copy)]
 pub(super) enum IntBinaryOp {
     Add,
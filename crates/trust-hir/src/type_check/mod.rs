@@ -16,9 +16,10 @@ use trust_syntax::syntax::{SyntaxKind, SyntaxNode};
 
 mod calls;
 mod compatibility;
-mod const_eval;
+pub mod const_eval;
 mod expr;
 mod helpers;
+mod initializers;
 mod literals;
 mod ops;
 mod standard;
@@ -120,20 +121,70 @@ struct LabelScope {
     pending_jumps: Vec<(SmolStr, SmolStr, TextRange)>,
 }
 
-#[derive(Debug, Default)]
-struct CaseLabelTracker {
+/// Integer values covered by the labels of a CASE statement.
+///
+/// Enum labels are tracked by their ordinal value, so coverage of an enum
+/// selector can be checked member by member.
+#[derive(Debug, Default, Clone)]
+pub struct CaseLabelTracker {
     ints: FxHashMap<i64, TextRange>,
-    ranges: Vec<(i64, i64)>,
+    ranges: Vec<(i64, i64, TextRange)>,
 }
 
 impl CaseLabelTracker {
-    fn covers(&self, value: i64) -> bool {
-        if self.ints.contains_key(&value) {
-            return true;
+    /// Records a single label value.
+    ///
+    /// If the value is already covered, leaves the tracker unchanged and
+    /// returns the range of the earlier label that covers it.
+    pub fn record_value(&mut self, value: i64, range: TextRange) -> Result<(), TextRange> {
+        if let Some(previous) = self.overlapping_label(value, value) {
+            return Err(previous);
         }
-        self.ranges
+        self.ints.insert(value, range);
+        Ok(())
+    }
+
+    /// Records a subrange label; the bounds may be given in either order.
+    ///
+    /// If the range overlaps a recorded label, leaves the tracker unchanged and
+    /// returns the range of the earlier label.
+    pub fn record_range(
+        &mut self,
+        start: i64,
+        end: i64,
+        range: TextRange,
+    ) -> Result<(), TextRange> {
+        let (lower, upper) = if start <= end {
+            (start, end)
+        } else {
+            (end, start)
+        };
+        if let Some(previous) = self.overlapping_label(lower, upper) {
+            return Err(previous);
+        }
+        self.ranges.push((lower, upper, range));
+        Ok(())
+    }
+
+    /// Returns true if a recorded label covers `value`.
+    #[must_use]
+    pub fn covers(&self, value: i64) -> bool {
+        self.overlapping_label(value, value).is_some()
+    }
+
+    /// Returns the range of the earliest recorded label overlapping `lower..=upper`.
+    fn overlapping_label(&self, lower: i64, upper: i64) -> Option<TextRange> {
+        let values = self
+            .ints
+            .iter()
+            .filter(|(value, _)| **value >= lower && **value <= upper)
+            .map(|(_, range)| *range);
+        let ranges = self
+            .ranges
             .iter()
-            .any(|(lower, upper)| value >= *lower && value <= *upper)
+            .filter(|(r_lower, r_upper, _)| !(upper < *r_lower || lower > *r_upper))
+            .map(|(_, _, range)| *range);
+        values.chain(ranges).min_by_key(|range| range.start())
     }
 }
 
@@ -164,6 +215,7 @@ fn is_statement_kind(kind: SyntaxKind) -> bool {
             | SyntaxKind::ForStmt
             | SyntaxKind::WhileStmt
             | SyntaxKind::RepeatStmt
+            | SyntaxKind::TryStmt
             | SyntaxKind::CaseStmt
             | SyntaxKind::ReturnStmt
             | SyntaxKind::ExprStmt
@@ -38,10 +38,10 @@ impl<'a, 'b> StandardChecker<'a, 'b> {
                 "EXPT" => self.infer_expt_call(node),
                 "MOVE" => self.infer_move_call(node),
                 "SHL" | "SHR" | "ROL" | "ROR" => self.infer_bit_shift_call(node, &upper),
-                "AND" | "OR" | "XOR" => self.infer_variadic_bitwise_call(node),
+                "AND" | "OR" | "XOR" => self.infer_variadic_bitwise_call(node, &upper),
                 "NOT" => self.infer_not_call(node),
                 "SEL" => self.infer_sel_call(node),
-                "MAX" | "MIN" => self.infer_min_max_call(node),
+                "MAX" | "MIN" => self.infer_min_max_call(node, &upper),
                 "LIMIT" => self.infer_limit_call(node),
                 "MUX" => self.infer_mux_call(node),
                 "GT" | "GE" | "EQ" | "LE" | "LT" | "NE" => self.infer_comparison_call(node, &upper),
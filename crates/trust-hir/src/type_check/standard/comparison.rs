@@ -7,15 +7,9 @@ impl<'a, 'b> StandardChecker<'a, 'b> {
         node: &SyntaxNode,
         name: &str,
     ) -> TypeId {
-        let arg_count = self.checker.calls().collect_call_args(node).len();
-        if arg_count < 2 {
-            self.checker.diagnostics.error(
-                DiagnosticCode::WrongArgumentCount,
-                node.text_range(),
-                format!("expected at least 2 arguments, found {}", arg_count),
-            );
+        let Some(arg_count) = self.variadic_arg_count(node, name, 2) else {
             return TypeId::UNKNOWN;
-        }
+        };
         if name.eq_ignore_ascii_case("NE") && arg_count != 2 {
             self.checker.diagnostics.error(
                 DiagnosticCode::WrongArgumentCount,
@@ -68,129 +68,9 @@ impl<'a, 'b> StandardChecker<'a, 'b> {
 
 
     pub(in crate::type_check) fn is_conversion_allowed(&self, src: TypeId, dst: TypeId) -> bool {
-        let src = self.base_type_id(src);
-        let dst = self.base_type_id(dst);
-
-        if src == dst {
-            return true;
-        }
-
-        if self.is_numeric_type(src) && self.is_numeric_type(dst) {
-            return true;
-        }
-
-        if matches!(
-            src,
-            TypeId::BYTE | TypeId::WORD | TypeId::DWORD | TypeId::LWORD
-        ) && matches!(
-            dst,
-            TypeId::BYTE | TypeId::WORD | TypeId::DWORD | TypeId::LWORD
-        ) {
-            return true;
-        }
-
-        if matches!(
-            src,
-            TypeId::BOOL | TypeId::BYTE | TypeId::WORD | TypeId::DWORD | TypeId::LWORD
-        ) && matches!(
-            dst,
-            TypeId::SINT
-                | TypeId::INT
-                | TypeId::DINT
-                | TypeId::LINT
-                | TypeId::USINT
-                | TypeId::UINT
-                | TypeId::UDINT
-                | TypeId::ULINT
-        ) {
-            return true;
-        }
-
-        if matches!(src, TypeId::DWORD) && dst == TypeId::REAL {
-            return true;
-        }
-        if matches!(src, TypeId::LWORD) && dst == TypeId::LREAL {
-            return true;
-        }
-
-        if matches!(
-            dst,
-            TypeId::BYTE | TypeId::WORD | TypeId::DWORD | TypeId::LWORD
-        ) && matches!(
-            src,
-            TypeId::SINT
-                | TypeId::INT
-                | TypeId::DINT
-                | TypeId::LINT
-                | TypeId::USINT
-                | TypeId::UINT
-                | TypeId::UDINT
-                | TypeId::ULINT
-        ) {
-            return true;
-        }
-
-        if src == TypeId::REAL && dst == TypeId::DWORD {
-            return true;
-        }
-        if src == TypeId::LREAL && dst == TypeId::LWORD {
-            return true;
-        }
-
-        if matches!(src, TypeId::LTIME) && dst == TypeId::TIME {
-            return true;
-        }
-        if matches!(src, TypeId::TIME) && dst == TypeId::LTIME {
-            return true;
-        }
-        if matches!(src, TypeId::LDT) && dst == TypeId::DT {
-            return true;
-        }
-        if matches!(src, TypeId::LDT) && dst == TypeId::DATE {
-            return true;
-        }
-        if matches!(src, TypeId::LDT) && dst == TypeId::LTOD {
-            return true;
-        }
-        if matches!(src, TypeId::LDT) && dst == TypeId::TOD {
-            return true;
-        }
-        if matches!(src, TypeId::DT) && dst == TypeId::LDT {
-            return true;
-        }
-        if matches!(src, TypeId::DT) && dst == TypeId::DATE {
-            return true;
-        }
-        if matches!(src, TypeId::DT) && dst == TypeId::LTOD {
-            return true;
-        }
-        if matches!(src, TypeId::DT) && dst == TypeId::TOD {
-            return true;
-        }
-        if matches!(src, TypeId::LTOD) && dst == TypeId::TOD {
-            return true;
-        }
-        if matches!(src, TypeId::TOD) && dst == TypeId::LTOD {
-            return true;
-        }
-
-        let src = self.normalize_string_type_id(src);
-        let dst = self.normalize_string_type_id(dst);
-
-        if matches!(src, TypeId::WSTRING) && matches!(dst, TypeId::STRING | TypeId::WCHAR) {
-            return true;
-        }
-        if matches!(src, TypeId::STRING) && matches!(dst, TypeId::WSTRING | TypeId::CHAR) {
-            return true;
-        }
-        if matches!(src, TypeId::WCHAR) && matches!(dst, TypeId::WSTRING | TypeId::CHAR) {
-            return true;
-        }
-        if matches!(src, TypeId::CHAR) && matches!(dst, TypeId::STRING | TypeId::WCHAR) {
-            return true;
-        }
-
-        false
+        let src = self.normalize_string_type_id(self.base_type_id(src));
+        let dst = self.normalize_string_type_id(self.base_type_id(dst));
+        crate::types::conversions::is_conversion_allowed(src, dst)
     }
 
 }
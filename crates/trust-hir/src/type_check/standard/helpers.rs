@@ -6,6 +6,7 @@ pub(in crate::type_check) fn builtin_param(name: &str, direction: ParamDirection
         name: SmolStr::new(name),
         type_id: TypeId::ANY,
         direction,
+        has_default: false,
     }
 }
 
@@ -43,6 +44,11 @@ impl BuiltinCall {
         checker.check_formal_arg_count(&self.bound, node, self.arg_count, expected);
     }
 
+    /// Returns true if an argument was bound to the parameter at `index`.
+    pub(in crate::type_check) fn is_bound(&self, index: usize) -> bool {
+        self.bound.assigned.get(index).is_some_and(Option::is_some)
+    }
+
     pub(in crate::type_check) fn arg(&self, index: usize) -> Option<(CallArg, TypeId)> {
         self.typed.get(index).and_then(|arg| arg.clone())
     }
@@ -53,6 +59,27 @@ impl BuiltinCall {
 }
 
 impl<'a, 'b> StandardChecker<'a, 'b> {
+    /// Returns the argument count of a call to the variadic function `name`,
+    /// or reports the call and returns `None` if it has fewer than `min`
+    /// arguments.
+    pub(in crate::type_check) fn variadic_arg_count(
+        &mut self,
+        node: &SyntaxNode,
+        name: &str,
+        min: usize,
+    ) -> Option<usize> {
+        let arg_count = self.checker.calls().collect_call_args(node).len();
+        if arg_count < min {
+            self.checker.diagnostics.error(
+                DiagnosticCode::WrongArgumentCount,
+                node.text_range(),
+                format!("{name} expects at least {min} arguments, found {arg_count}"),
+            );
+            return None;
+        }
+        Some(arg_count)
+    }
+
     pub(in crate::type_check) fn builtin_call(
         &mut self,
         node: &SyntaxNode,
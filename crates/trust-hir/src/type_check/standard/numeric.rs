@@ -64,15 +64,9 @@ impl<'a, 'b> StandardChecker<'a, 'b> {
     }
 
     pub(in crate::type_check) fn infer_add_call(&mut self, node: &SyntaxNode) -> TypeId {
-        let arg_count = self.checker.calls().collect_call_args(node).len();
-        if arg_count < 2 {
-            self.checker.diagnostics.error(
-                DiagnosticCode::WrongArgumentCount,
-                node.text_range(),
-                format!("expected at least 2 arguments, found {}", arg_count),
-            );
+        let Some(arg_count) = self.variadic_arg_count(node, "ADD", 2) else {
             return TypeId::UNKNOWN;
-        }
+        };
         let params = builtin_in_params("IN", 1, arg_count);
         let call = self.builtin_call(node, params);
         let inputs = call.args_from(0);
@@ -137,15 +131,9 @@ impl<'a, 'b> StandardChecker<'a, 'b> {
     }
 
     pub(in crate::type_check) fn infer_mul_call(&mut self, node: &SyntaxNode) -> TypeId {
-        let arg_count = self.checker.calls().collect_call_args(node).len();
-        if arg_count < 2 {
-            self.checker.diagnostics.error(
-                DiagnosticCode::WrongArgumentCount,
-                node.text_range(),
-                format!("expected at least 2 arguments, found {}", arg_count),
-            );
+        let Some(arg_count) = self.variadic_arg_count(node, "MUL", 2) else {
             return TypeId::UNKNOWN;
-        }
+        };
         let params = builtin_in_params("IN", 1, arg_count);
         let call = self.builtin_call(node, params);
         let inputs = call.args_from(0);
@@ -261,15 +249,61 @@ impl<'a, 'b> StandardChecker<'a, 'b> {
     }
 
     pub(in crate::type_check) fn infer_move_call(&mut self, node: &SyntaxNode) -> TypeId {
-        let params = vec![builtin_param("IN", ParamDirection::In)];
+        let params = vec![
+            builtin_param("EN", ParamDirection::In),
+            builtin_param("IN", ParamDirection::In),
+            builtin_param("ENO", ParamDirection::Out),
+        ];
         let call = self.builtin_call(node, params);
-        call.check_formal_arg_count(self, node, 1);
-        if call.arg_count() != 1 {
+        let expected = 1 + [0, 2]
+            .into_iter()
+            .filter(|&index| call.is_bound(index))
+            .count();
+        call.check_formal_arg_count(self, node, expected);
+        if call.arg_count() != expected {
             return TypeId::UNKNOWN;
         }
-        let Some((_, arg_type)) = call.arg(0) else {
+        if expected > 1 && !is_assignment_value(node) {
+            self.checker.diagnostics.error(
+                DiagnosticCode::InvalidOperation,
+                node.text_range(),
+                "MOVE with EN/ENO must be the value of an assignment (x := MOVE(...))",
+            );
+        }
+        if let Some((arg, arg_type)) = call.arg(0) {
+            if self.base_type_id(arg_type) != TypeId::BOOL {
+                self.checker.diagnostics.error(
+                    DiagnosticCode::InvalidArgumentType,
+                    arg.range,
+                    "expected BOOL for EN",
+                );
+            }
+        }
+        if let Some((arg, arg_type)) = call.arg(2) {
+            if !self.checker.is_assignable(arg_type, TypeId::BOOL) {
+                self.checker.diagnostics.error(
+                    DiagnosticCode::InvalidArgumentType,
+                    arg.range,
+                    "expected BOOL target for ENO",
+                );
+            }
+        }
+        let Some((_, arg_type)) = call.arg(1) else {
             return TypeId::UNKNOWN;
         };
         self.base_type_id(arg_type)
     }
 }
+
+/// Returns true for the `MOVE(...)` in `x := MOVE(...)`, the only place the
+/// runtime can honour EN/ENO by skipping the assignment.
+fn is_assignment_value(node: &SyntaxNode) -> bool {
+    node.parent().is_some_and(|parent| {
+        parent.kind() == SyntaxKind::AssignStmt
+            && parent.first_child().as_ref() != Some(node)
+            && !parent
+                .children_with_tokens()
+                .filter_map(|child| child.into_token())
+                .any(|token| token.kind() == SyntaxKind::RefAssign)
+    })
+}
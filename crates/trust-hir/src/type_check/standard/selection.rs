@@ -29,16 +29,14 @@ impl<'a, 'b> StandardChecker<'a, 'b> {
             .unwrap_or(TypeId::UNKNOWN)
     }
 
-    pub(in crate::type_check) fn infer_min_max_call(&mut self, node: &SyntaxNode) -> TypeId {
-        let arg_count = self.checker.calls().collect_call_args(node).len();
-        if arg_count < 2 {
-            self.checker.diagnostics.error(
-                DiagnosticCode::WrongArgumentCount,
-                node.text_range(),
-                format!("expected at least 2 arguments, found {}", arg_count),
-            );
+    pub(in crate::type_check) fn infer_min_max_call(
+        &mut self,
+        node: &SyntaxNode,
+        name: &str,
+    ) -> TypeId {
+        let Some(arg_count) = self.variadic_arg_count(node, name, 2) else {
             return TypeId::UNKNOWN;
-        }
+        };
         let params = builtin_in_params("IN", 1, arg_count);
         let call = self.builtin_call(node, params);
         let inputs = call.args_from(0);
@@ -63,15 +61,9 @@ impl<'a, 'b> StandardChecker<'a, 'b> {
     }
 
     pub(in crate::type_check) fn infer_mux_call(&mut self, node: &SyntaxNode) -> TypeId {
-        let arg_count = self.checker.calls().collect_call_args(node).len();
-        if arg_count < 3 {
-            self.checker.diagnostics.error(
-                DiagnosticCode::WrongArgumentCount,
-                node.text_range(),
-                format!("expected at least 3 arguments, found {}", arg_count),
-            );
+        let Some(arg_count) = self.variadic_arg_count(node, "MUX", 3) else {
             return TypeId::UNKNOWN;
-        }
+        };
         let mut params = vec![builtin_param("K", ParamDirection::In)];
         params.extend(builtin_in_params("IN", 0, arg_count - 1));
         let call = self.builtin_call(node, params);
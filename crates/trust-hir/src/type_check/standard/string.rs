@@ -110,15 +110,9 @@ impl<'a, 'b> StandardChecker<'a, 'b> {
     }
 
     pub(in crate::type_check) fn infer_concat_call(&mut self, node: &SyntaxNode) -> TypeId {
-        let arg_count = self.checker.calls().collect_call_args(node).len();
-        if arg_count < 2 {
-            self.checker.diagnostics.error(
-                DiagnosticCode::WrongArgumentCount,
-                node.text_range(),
-                format!("expected at least 2 arguments, found {}", arg_count),
-            );
+        let Some(arg_count) = self.variadic_arg_count(node, "CONCAT", 2) else {
             return TypeId::UNKNOWN;
-        }
+        };
         let params = builtin_in_params("IN", 1, arg_count);
         let call = self.builtin_call(node, params);
         let inputs = call.args_from(0);
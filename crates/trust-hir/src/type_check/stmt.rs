@@ -1,4 +1,6 @@
+use super::helpers::trimmed_range;
 use super::*;
+use crate::diagnostics::Diagnostic;
 
 include!("stmt_impl_part_01.rs");
 include!("stmt_impl_part_02.rs");
@@ -9,6 +9,7 @@ impl<'a, 'b> StmtChecker<'a, 'b> {
             SyntaxKind::ForStmt => self.check_for_stmt(node),
             SyntaxKind::WhileStmt => self.check_while_stmt(node),
             SyntaxKind::RepeatStmt => self.check_repeat_stmt(node),
+            SyntaxKind::TryStmt => self.check_try_stmt(node),
             SyntaxKind::CaseStmt => self.check_case_stmt(node),
             SyntaxKind::ReturnStmt => self.check_return_stmt(node),
             SyntaxKind::ExprStmt => self.check_expr_stmt(node),
@@ -107,19 +108,27 @@ impl<'a, 'b> StmtChecker<'a, 'b> {
                 value_type
             };
             self.check_subrange_assignment(target_type, value, checked_type);
+            self.checker.check_int_literal_range(target_type, value);
             self.checker
                 .check_string_literal_assignment(target_type, value, checked_type);
+            self.checker
+                .warn_integer_division_truncation(target_type, value);
             if !is_context_int && !is_context_real {
                 self.checker
                     .warn_implicit_conversion(target_type, value_type, node.text_range());
             }
-        } else {
-            let target_name = self.checker.type_name(target_type);
-            let value_name = self.checker.type_name(value_type);
+        } else if !self.checker.report_bool_integer_conversion(
+            target_type,
+            value_type,
+            node.text_range(),
+        ) {
+            let message = self
+                .checker
+                .assignment_mismatch_message(target_type, value_type);
             self.checker.diagnostics.error(
                 DiagnosticCode::IncompatibleAssignment,
                 node.text_range(),
-                format!("cannot assign '{}' to '{}'", value_name, target_name),
+                message,
             );
         }
     }
@@ -131,7 +140,7 @@ impl<'a, 'b> StmtChecker<'a, 'b> {
             let cond_type = self.check_expression(&expr);
             self.checker
                 .expr()
-                .check_boolean(cond_type, expr.text_range());
+                .check_condition(cond_type, expr.text_range());
         }
 
         // Check nested statements
@@ -143,7 +152,7 @@ impl<'a, 'b> StmtChecker<'a, 'b> {
                             let cond_type = self.check_expression(&expr);
                             self.checker
                                 .expr()
-                                .check_boolean(cond_type, expr.text_range());
+                                .check_condition(cond_type, expr.text_range());
                         }
                     }
                     self.check_statement_children(&child);
@@ -99,7 +99,7 @@ impl<'a, 'b> StmtChecker<'a, 'b> {
             let cond_type = self.check_expression(&expr);
             self.checker
                 .expr()
-                .check_boolean(cond_type, expr.text_range());
+                .check_condition(cond_type, expr.text_range());
         }
 
         self.checker.loop_stack.push(LoopContext {
@@ -116,7 +116,7 @@ impl<'a, 'b> StmtChecker<'a, 'b> {
             let cond_type = self.check_expression(&expr);
             self.checker
                 .expr()
-                .check_boolean(cond_type, expr.text_range());
+                .check_condition(cond_type, expr.text_range());
         }
 
         self.checker.loop_stack.push(LoopContext {
@@ -127,6 +127,57 @@ impl<'a, 'b> StmtChecker<'a, 'b> {
     }
 
 
+    fn check_try_stmt(&mut self, node: &SyntaxNode) {
+        self.check_statement_children(node);
+        for clause in node.children() {
+            match clause.kind() {
+                SyntaxKind::CatchClause => {
+                    if let Some(target) = first_expression_child(&clause) {
+                        self.check_catch_target(&target);
+                    }
+                    self.check_statement_children(&clause);
+                }
+                SyntaxKind::FinallyClause => self.check_statement_children(&clause),
+                _ => {}
+            }
+        }
+    }
+
+
+    fn check_catch_target(&mut self, target: &SyntaxNode) {
+        if !self.checker.is_valid_lvalue(target) {
+            self.checker.diagnostics.error(
+                DiagnosticCode::InvalidAssignmentTarget,
+                target.text_range(),
+                "__CATCH target must be a variable",
+            );
+            return;
+        }
+
+        let target_type = self.check_expression(target);
+        let target_type = self.checker.resolve_alias_type(target_type);
+        if let Some(ty) = self.checker.symbols.type_by_id(target_type) {
+            if !ty.is_integer() {
+                self.checker.diagnostics.error(
+                    DiagnosticCode::TypeMismatch,
+                    target.text_range(),
+                    "__CATCH target must be integer type",
+                );
+            } else if matches!(
+                self.checker.normalize_subrange(ty),
+                Type::SInt | Type::USInt
+            ) {
+                // Exception codes go beyond 255, so 8-bit targets could not hold them.
+                self.checker.diagnostics.error(
+                    DiagnosticCode::TypeMismatch,
+                    target.text_range(),
+                    "__CATCH target must be at least 16 bits wide to hold exception codes",
+                );
+            }
+        }
+    }
+
+
     fn check_case_stmt(&mut self, node: &SyntaxNode) {
         // Get selector type
         let mut selector_type = TypeId::UNKNOWN;
@@ -150,15 +201,26 @@ impl<'a, 'b> StmtChecker<'a, 'b> {
             }
         }
 
-        let has_else = node
+        let else_branch = node
             .children()
-            .any(|child| child.kind() == SyntaxKind::ElseBranch);
-        if !has_else && !self.case_labels_cover_enum(selector_type, &tracker) {
-            self.checker.diagnostics.warning(
-                DiagnosticCode::MissingElse,
-                node.text_range(),
-                "CASE statement has no ELSE branch",
-            );
+            .find(|child| child.kind() == SyntaxKind::ElseBranch);
+        let covers_enum = self.case_labels_cover_enum(selector_type, &tracker);
+        match else_branch {
+            None if !covers_enum => {
+                self.checker.diagnostics.warning(
+                    DiagnosticCode::MissingElse,
+                    node.text_range(),
+                    "CASE statement has no ELSE branch",
+                );
+            }
+            Some(else_branch) if covers_enum => {
+                self.checker.diagnostics.add(Diagnostic::new(
+                    DiagnosticCode::UnreachableElse,
+                    else_branch.text_range(),
+                    "ELSE is unreachable: the CASE labels cover every enum value",
+                ));
+            }
+            _ => {}
         }
     }
 
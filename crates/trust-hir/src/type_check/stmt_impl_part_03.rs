@@ -77,8 +77,8 @@ impl<'a, 'b> StmtChecker<'a, 'b> {
         }
 
         match bounds.len() {
-            1 => self.record_case_label_value(tracker, bounds[0], node.text_range()),
-            2 => self.record_case_label_range(tracker, bounds[0], bounds[1], node.text_range()),
+            1 => self.record_case_label_value(tracker, bounds[0], trimmed_range(node)),
+            2 => self.record_case_label_range(tracker, bounds[0], bounds[1], trimmed_range(node)),
             _ => {}
         }
 
@@ -120,7 +120,7 @@ impl<'a, 'b> StmtChecker<'a, 'b> {
         }
 
         if let Some(value) = self.checker.eval_const_int_expr(expr) {
-            self.record_case_label_value(tracker, value, expr.text_range());
+            self.record_case_label_value(tracker, value, trimmed_range(expr));
         }
     }
 
@@ -94,21 +94,9 @@ impl<'a, 'b> StmtChecker<'a, 'b> {
         value: i64,
         range: TextRange,
     ) {
-        if tracker.ints.contains_key(&value)
-            || tracker
-                .ranges
-                .iter()
-                .any(|(lower, upper)| value >= *lower && value <= *upper)
-        {
-            self.checker.diagnostics.error(
-                DiagnosticCode::InvalidOperation,
-                range,
-                "duplicate CASE label",
-            );
-            return;
+        if let Err(previous) = tracker.record_value(value, range) {
+            self.report_duplicate_case_label(range, previous);
         }
-
-        tracker.ints.insert(value, range);
     }
 
 
@@ -119,30 +107,21 @@ impl<'a, 'b> StmtChecker<'a, 'b> {
         end: i64,
         range: TextRange,
     ) {
-        let (lower, upper) = if start <= end {
-            (start, end)
-        } else {
-            (end, start)
-        };
-        let overlaps_value = tracker
-            .ints
-            .keys()
-            .any(|value| *value >= lower && *value <= upper);
-        let overlaps_range = tracker
-            .ranges
-            .iter()
-            .any(|(r_lower, r_upper)| !(upper < *r_lower || lower > *r_upper));
-
-        if overlaps_value || overlaps_range {
-            self.checker.diagnostics.error(
-                DiagnosticCode::InvalidOperation,
-                range,
-                "duplicate CASE label",
-            );
-            return;
+        if let Err(previous) = tracker.record_range(start, end, range) {
+            self.report_duplicate_case_label(range, previous);
         }
+    }
+
 
-        tracker.ranges.push((lower, upper));
+    fn report_duplicate_case_label(&mut self, range: TextRange, previous: TextRange) {
+        self.checker.diagnostics.add(
+            Diagnostic::error(
+                DiagnosticCode::DuplicateCaseLabel,
+                range,
+                "CASE label overlaps an earlier label",
+            )
+            .with_related(previous, "earlier label here"),
+        );
     }
 
 
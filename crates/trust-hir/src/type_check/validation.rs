@@ -1,4 +1,5 @@
 use super::calls::ResolvedSymbol;
+use super::helpers::compile_time_builtin_type;
 use super::*;
 
 impl<'a> TypeChecker<'a> {
@@ -9,7 +10,10 @@ impl<'a> TypeChecker<'a> {
             }
         }
         match node.kind() {
-            SyntaxKind::NameRef | SyntaxKind::DerefExpr => true,
+            SyntaxKind::NameRef => {
+                compile_time_builtin_type(node.text().to_string().trim()).is_none()
+            }
+            SyntaxKind::DerefExpr => true,
             SyntaxKind::ThisExpr | SyntaxKind::SuperExpr => false,
             SyntaxKind::FieldExpr | SyntaxKind::IndexExpr => node
                 .children()
@@ -120,6 +124,25 @@ impl<'a> TypeChecker<'a> {
 
         match symbol.kind {
             SymbolKind::Variable { .. } => true,
+            SymbolKind::Parameter {
+                direction: ParamDirection::Out,
+            } if self.is_external_member_write(node, resolved.id) => {
+                let owner = self
+                    .resolve_ref()
+                    .member_owner(resolved.id)
+                    .and_then(|owner| self.symbols.get(owner))
+                    .map(|owner| owner.name.clone())
+                    .unwrap_or_default();
+                self.diagnostics.error(
+                    DiagnosticCode::InvalidAssignmentTarget,
+                    node.text_range(),
+                    format!(
+                        "cannot assign to output '{}' from outside '{}'",
+                        symbol.name, owner
+                    ),
+                );
+                false
+            }
             SymbolKind::Parameter {
                 direction: ParamDirection::Out | ParamDirection::InOut,
             } => true,
@@ -164,6 +187,28 @@ impl<'a> TypeChecker<'a> {
         }
     }
 
+    /// Returns true if `node` writes `member` through an instance (`inst.out`)
+    /// from code outside the member's owner and its derived types.
+    fn is_external_member_write(&self, node: &SyntaxNode, member: SymbolId) -> bool {
+        let target = match node.kind() {
+            SyntaxKind::ParenExpr | SyntaxKind::IndexExpr => node.children().next(),
+            _ => Some(node.clone()),
+        };
+        if target.is_none_or(|target| target.kind() != SyntaxKind::FieldExpr) {
+            return false;
+        }
+        let resolve = self.resolve_ref();
+        let Some(owner) = resolve.member_owner(member) else {
+            return false;
+        };
+        if self.current_pou_symbol == Some(owner) {
+            return false;
+        }
+        !resolve
+            .current_class_owner()
+            .is_some_and(|current| resolve.is_same_or_derived(current, owner))
+    }
+
     pub(super) fn assignment_target_symbol(&mut self, node: &SyntaxNode) -> Option<ResolvedSymbol> {
         if node.kind() == SyntaxKind::ParenExpr {
             return node
@@ -5,6 +5,7 @@
 
 mod builtins;
 mod compat;
+pub mod conversions;
 mod defs;
 mod registry;
 
@@ -39,13 +39,15 @@ impl TypeRegistry {
                 if td.len() != sd.len() {
                     return false;
                 }
-                for ((t_lower, t_upper), (s_lower, s_upper)) in td.iter().zip(sd.iter()) {
-                    let wildcard_target = *t_lower == 0 && *t_upper == i64::MAX;
-                    let wildcard_source = *s_lower == 0 && *s_upper == i64::MAX;
-                    if wildcard_target || wildcard_source {
+                for (target_bounds, source_bounds) in td.iter().zip(sd.iter()) {
+                    let (Some(target_bounds), Some(source_bounds)) = (target_bounds, source_bounds)
+                    else {
+                        return false;
+                    };
+                    if *target_bounds == Type::OPEN_BOUNDS || *source_bounds == Type::OPEN_BOUNDS {
                         continue;
                     }
-                    if t_lower != s_lower || t_upper != s_upper {
+                    if target_bounds != source_bounds {
                         return false;
                     }
                 }
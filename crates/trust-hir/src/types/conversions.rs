@@ -0,0 +1,120 @@
+//! Standard conversion rules (IEC 61131-3 Ed.3, Tables 22-27).
+//!
+//! The same table backs `<src>_TO_<dst>` / `TO_<dst>` type checking, runtime
+//! conversion dispatch and conversion-name completion.
+
+use super::defs::TypeId;
+
+/// Elementary types that can appear in `<src>_TO_<dst>` conversion names.
+pub const CONVERSION_TYPES: &[TypeId] = &[
+    TypeId::BOOL,
+    TypeId::SINT,
+    TypeId::INT,
+    TypeId::DINT,
+    TypeId::LINT,
+    TypeId::USINT,
+    TypeId::UINT,
+    TypeId::UDINT,
+    TypeId::ULINT,
+    TypeId::REAL,
+    TypeId::LREAL,
+    TypeId::BYTE,
+    TypeId::WORD,
+    TypeId::DWORD,
+    TypeId::LWORD,
+    TypeId::TIME,
+    TypeId::LTIME,
+    TypeId::DATE,
+    TypeId::LDATE,
+    TypeId::TOD,
+    TypeId::LTOD,
+    TypeId::DT,
+    TypeId::LDT,
+    TypeId::STRING,
+    TypeId::WSTRING,
+    TypeId::CHAR,
+    TypeId::WCHAR,
+];
+
+/// Returns true if a value of elementary type `src` can be converted to `dst`.
+///
+/// Both ids must already be resolved to their elementary base type; aliases,
+/// subranges and sized strings are not looked through.
+#[must_use]
+pub fn is_conversion_allowed(src: TypeId, dst: TypeId) -> bool {
+    if src == dst {
+        return true;
+    }
+    match (src, dst) {
+        // Table 23: numeric conversions.
+        (src, dst) if is_numeric(src) && is_numeric(dst) => true,
+        // Table 24: bit string conversions.
+        (src, dst) if is_bit_string(src) && is_bit_string(dst) => true,
+        // Table 25: bit string and BOOL to integer, integer to bit string and
+        // BOOL (non-zero is TRUE), and the bit-preserving REAL <-> DWORD and
+        // LREAL <-> LWORD pairs.
+        (src, dst) if (is_bit_string(src) || src == TypeId::BOOL) && is_integer(dst) => true,
+        (src, dst) if is_integer(src) && (is_bit_string(dst) || dst == TypeId::BOOL) => true,
+        (TypeId::BYTE | TypeId::WORD | TypeId::DWORD | TypeId::LWORD, TypeId::BOOL) => true,
+        (TypeId::DWORD, TypeId::REAL)
+        | (TypeId::LWORD, TypeId::LREAL)
+        | (TypeId::REAL, TypeId::DWORD)
+        | (TypeId::LREAL, TypeId::LWORD) => true,
+        // Table 26: duration and date/time conversions.
+        (TypeId::LTIME, TypeId::TIME) | (TypeId::TIME, TypeId::LTIME) => true,
+        (TypeId::LDT | TypeId::DT, TypeId::LDT | TypeId::DT | TypeId::DATE)
+        | (TypeId::LDT | TypeId::DT, TypeId::LTOD | TypeId::TOD) => true,
+        (TypeId::LTOD, TypeId::TOD) | (TypeId::TOD, TypeId::LTOD) => true,
+        // Table 27: character conversions.
+        (TypeId::WSTRING, TypeId::STRING | TypeId::WCHAR)
+        | (TypeId::STRING, TypeId::WSTRING | TypeId::CHAR)
+        | (TypeId::WCHAR, TypeId::WSTRING | TypeId::CHAR)
+        | (TypeId::CHAR, TypeId::STRING | TypeId::WCHAR) => true,
+        _ => false,
+    }
+}
+
+/// Returns the types `src` can be converted to, excluding `src` itself.
+#[must_use]
+pub fn conversion_targets(src: TypeId) -> Vec<TypeId> {
+    CONVERSION_TYPES
+        .iter()
+        .copied()
+        .filter(|&dst| dst != src && is_conversion_allowed(src, dst))
+        .collect()
+}
+
+/// Returns the types that can be converted to `dst`, excluding `dst` itself.
+#[must_use]
+pub fn conversion_sources(dst: TypeId) -> Vec<TypeId> {
+    CONVERSION_TYPES
+        .iter()
+        .copied()
+        .filter(|&src| src != dst && is_conversion_allowed(src, dst))
+        .collect()
+}
+
+fn is_integer(ty: TypeId) -> bool {
+    matches!(
+        ty,
+        TypeId::SINT
+            | TypeId::INT
+            | TypeId::DINT
+            | TypeId::LINT
+            | TypeId::USINT
+            | TypeId::UINT
+            | TypeId::UDINT
+            | TypeId::ULINT
+    )
+}
+
+fn is_numeric(ty: TypeId) -> bool {
+    is_integer(ty) || matches!(ty, TypeId::REAL | TypeId::LREAL)
+}
+
+fn is_bit_string(ty: TypeId) -> bool {
+    matches!(
+        ty,
+        TypeId::BYTE | TypeId::WORD | TypeId::DWORD | TypeId::LWORD
+    )
+}
@@ -289,8 +289,10 @@ pub enum Type {
     Array {
         /// Element type.
         element: TypeId,
-        /// Array dimensions (lower..upper for each dimension).
-        dimensions: Vec<(i64, i64)>,
+        /// Array dimensions (lower..upper for each dimension), or
+        /// [`Type::OPEN_BOUNDS`] for `ARRAY[*]`. `None` marks a dimension
+        /// whose bounds do not fold to constants.
+        dimensions: Vec<Option<(i64, i64)>>,
     },
     /// Struct type.
     Struct {
@@ -394,6 +396,20 @@ pub enum Type {
 }
 
 impl Type {
+    /// Bounds of an `ARRAY[*]` dimension, which takes the size of whatever
+    /// array is passed in.
+    pub const OPEN_BOUNDS: (i64, i64) = (0, i64::MAX);
+
+    /// Formats one array dimension as `lower..upper`, or `?` when its bounds
+    /// do not fold to constants.
+    #[must_use]
+    pub fn format_array_dimension(bounds: Option<(i64, i64)>) -> String {
+        match bounds {
+            Some((lower, upper)) => format!("{lower}..{upper}"),
+            None => "?".to_string(),
+        }
+    }
+
     /// Returns true if this is a numeric type.
     #[must_use]
     pub fn is_numeric(&self) -> bool {
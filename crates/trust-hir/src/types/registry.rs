@@ -94,7 +94,7 @@ impl TypeRegistry {
             name,
             Type::Array {
                 element,
-                dimensions,
+                dimensions: dimensions.into_iter().map(Some).collect(),
             },
         )
     }
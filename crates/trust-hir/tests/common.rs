@@ -3,7 +3,7 @@
 
 pub use trust_hir::db::{Database, FileId, SemanticDatabase, SourceDatabase};
 pub use trust_hir::diagnostics::{DiagnosticCode, DiagnosticSeverity};
-pub use trust_hir::symbols::{ParamDirection, SymbolKind, Visibility};
+pub use trust_hir::symbols::{ParamDirection, Retention, SymbolKind, Visibility};
 pub use trust_hir::Type;
 
 /// Helper to check diagnostics for a source file.
@@ -46,3 +46,15 @@ pub fn check_warnings(source: &str) -> Vec<DiagnosticCode> {
         .map(|d| d.code)
         .collect()
 }
+
+/// Helper to check info diagnostics for a source file.
+pub fn check_infos(source: &str) -> Vec<DiagnosticCode> {
+    let mut db = Database::new();
+    let file = FileId(0);
+    db.set_source_text(file, source.to_string());
+    db.diagnostics(file)
+        .iter()
+        .filter(|d| d.severity == DiagnosticSeverity::Info)
+        .map(|d| d.code)
+        .collect()
+}
@@ -0,0 +1,170 @@
+//! Tests for the public constant-folding query.
+
+mod common;
+use common::*;
+use trust_hir::const_eval::{try_fold, ConstValue};
+use trust_syntax::parser::parse;
+use trust_syntax::SyntaxKind;
+
+/// Folds the outermost expression in `source` whose text is `expr`.
+fn fold(source: &str, expr: &str) -> Option<ConstValue> {
+    let mut db = Database::new();
+    let file = FileId(0);
+    db.set_source_text(file, source.to_string());
+    let root = parse(source).syntax();
+    let node = root
+        .descendants()
+        .filter(|node| {
+            matches!(
+                node.kind(),
+                SyntaxKind::Literal
+                    | SyntaxKind::NameRef
+                    | SyntaxKind::ParenExpr
+                    | SyntaxKind::UnaryExpr
+                    | SyntaxKind::BinaryExpr
+            )
+        })
+        .find(|node| node.text().to_string().trim() == expr)
+        .unwrap_or_else(|| panic!("no expression `{expr}` in source"));
+    try_fold(&db, file, &node)
+}
+
+#[test]
+fn folds_literals_and_operators() {
+    let source = r#"
+PROGRAM Main
+VAR
+    x : LREAL;
+    b : BOOL;
+END_VAR
+x := (2 + 3) * 4 - 10 / 3;
+b := 7 MOD 4 = 3;
+x := 16#FF AND 2#1010;
+END_PROGRAM
+"#;
+    assert_eq!(
+        fold(source, "(2 + 3) * 4 - 10 / 3"),
+        Some(ConstValue::Int(17))
+    );
+    assert_eq!(fold(source, "7 MOD 4 = 3"), Some(ConstValue::Bool(true)));
+    assert_eq!(fold(source, "16#FF AND 2#1010"), Some(ConstValue::Int(10)));
+}
+
+#[test]
+fn folds_real_bool_and_string_values() {
+    let source = r#"
+PROGRAM Main
+VAR
+    x : LREAL;
+    b : BOOL;
+    s : STRING;
+END_VAR
+x := 2.5 * 2;
+b := NOT (TRUE XOR FALSE);
+s := 'text';
+END_PROGRAM
+"#;
+    assert_eq!(fold(source, "2.5 * 2"), Some(ConstValue::Real(5.0)));
+    assert_eq!(
+        fold(source, "NOT (TRUE XOR FALSE)"),
+        Some(ConstValue::Bool(false))
+    );
+    assert_eq!(
+        fold(source, "'text'"),
+        Some(ConstValue::String("'text'".into()))
+    );
+}
+
+#[test]
+fn folds_constant_variable_references() {
+    let source = r#"
+PROGRAM Main
+VAR CONSTANT
+    Size : INT := 4;
+    Limit : DINT := Size * 10 + 2;
+    Gain : REAL := 1.5;
+    Enabled : BOOL := TRUE;
+    Name : STRING := 'pump';
+END_VAR
+VAR
+    x : LREAL;
+    b : BOOL;
+    s : STRING;
+END_VAR
+x := Limit - 2;
+x := Gain * 2.0;
+b := Enabled AND Size > 3;
+s := Name;
+END_PROGRAM
+"#;
+    assert_eq!(fold(source, "Limit - 2"), Some(ConstValue::Int(40)));
+    assert_eq!(fold(source, "Gain * 2.0"), Some(ConstValue::Real(3.0)));
+    assert_eq!(
+        fold(source, "Enabled AND Size > 3"),
+        Some(ConstValue::Bool(true))
+    );
+    assert_eq!(
+        fold(source, "Name"),
+        Some(ConstValue::String("'pump'".into()))
+    );
+}
+
+#[test]
+fn folds_enum_values() {
+    let source = r#"
+TYPE Color : (Red, Green := 5, Blue); END_TYPE
+
+PROGRAM Main
+VAR
+    x : LREAL;
+END_VAR
+x := Color#Green + 1;
+END_PROGRAM
+"#;
+    assert_eq!(fold(source, "Color#Green + 1"), Some(ConstValue::Int(6)));
+}
+
+#[test]
+fn folds_boolean_constants_and_enum_comparisons() {
+    let source = r#"
+TYPE Color : (Red, Green := 5, Blue); END_TYPE
+
+PROGRAM Main
+VAR CONSTANT
+    Ready : BOOL := TRUE OR FALSE;
+END_VAR
+VAR
+    x : LREAL;
+    b : BOOL;
+END_VAR
+b := Ready;
+b := Color#Blue > Color#Green;
+x := Color#Blue;
+END_PROGRAM
+"#;
+    assert_eq!(fold(source, "Ready"), Some(ConstValue::Bool(true)));
+    assert_eq!(
+        fold(source, "Color#Blue > Color#Green"),
+        Some(ConstValue::Bool(true))
+    );
+    assert_eq!(fold(source, "Color#Blue"), Some(ConstValue::Int(6)));
+}
+
+#[test]
+fn runtime_dependent_expressions_do_not_fold() {
+    let source = r#"
+PROGRAM Main
+VAR
+    counter : INT := 3;
+    x : LREAL;
+    b : BOOL;
+END_VAR
+x := counter + 1;
+x := 10 / 0;
+b := x > 1.0;
+END_PROGRAM
+"#;
+    assert_eq!(fold(source, "counter + 1"), None);
+    assert_eq!(fold(source, "10 / 0"), None);
+    assert_eq!(fold(source, "x > 1.0"), None);
+}
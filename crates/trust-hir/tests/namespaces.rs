@@ -1,6 +1,7 @@
 mod common;
 
 use common::*;
+use trust_hir::SymbolId;
 
 #[test]
 fn iec_table64() {
@@ -48,3 +49,77 @@ END_PROGRAM
 "#,
     );
 }
+
+#[test]
+fn qualified_name_follows_namespaces_and_owners() {
+    let mut db = Database::new();
+    let file = FileId(0);
+    db.set_source_text(
+        file,
+        r#"
+NAMESPACE Plant.Motors
+FUNCTION_BLOCK FB_Pump
+VAR
+    speed : INT;
+END_VAR
+METHOD Init
+END_METHOD
+END_FUNCTION_BLOCK
+END_NAMESPACE
+
+PROGRAM Main
+END_PROGRAM
+"#
+        .to_string(),
+    );
+
+    let symbols = db.file_symbols(file);
+    let qualified = |name: &str| {
+        let symbol = symbols.iter().find(|s| s.name == name).unwrap();
+        symbols.qualified_name(symbol.id)
+    };
+    assert_eq!(qualified("Init"), "Plant.Motors.FB_Pump.Init");
+    assert_eq!(qualified("speed"), "Plant.Motors.FB_Pump.speed");
+    assert_eq!(qualified("Motors"), "Plant.Motors");
+    assert_eq!(qualified("Main"), "Main");
+}
+
+#[test]
+fn qualified_path_covers_functions_and_enum_members() {
+    let mut db = Database::new();
+    let file = FileId(0);
+    db.set_source_text(
+        file,
+        r#"
+NAMESPACE MyNs
+TYPE Color : (Red, Green); END_TYPE
+FUNCTION_BLOCK FB_Motor
+METHOD PUBLIC Start
+END_METHOD
+END_FUNCTION_BLOCK
+END_NAMESPACE
+
+FUNCTION Scale : INT
+VAR_INPUT
+    x : INT;
+END_VAR
+Scale := x;
+END_FUNCTION
+"#
+        .to_string(),
+    );
+
+    let symbols = db.file_symbols(file);
+    let path = |name: &str| {
+        let symbol = symbols.iter().find(|s| s.name == name).unwrap();
+        symbols.qualified_path(symbol.id)
+    };
+    assert_eq!(path("Start"), ["MyNs", "FB_Motor", "Start"]);
+    assert_eq!(path("Scale"), ["Scale"]);
+    assert_eq!(path("Green"), ["MyNs", "Color", "Green"]);
+    assert!(symbols.qualified_path(SymbolId(u32::MAX)).is_empty());
+    assert_eq!(
+        symbols.namespace_path_of(symbols.iter().find(|s| s.name == "Start").unwrap().id),
+        ["MyNs"]
+    );
+}
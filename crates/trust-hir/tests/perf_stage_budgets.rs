@@ -0,0 +1,170 @@
+//! Time budgets for lexing, parsing, symbol collection, and diagnostics.
+//!
+//! Budgets are generous so the test only trips on real regressions such as an
+//! accidental quadratic scan. Override them with `ST_HIR_PERF_<STAGE>_MS`; run
+//! with `--nocapture` to see per-stage timings.
+//!
+//! The growth-ratio tests compare timings between two workload sizes and are
+//! too sensitive to machine load for every run, so they are `#[ignore]`d. Run
+//! them with `--ignored`, tuning `ST_HIR_PERF_MAX_GROWTH` and
+//! `ST_HIR_PERF_GROWTH_FACTOR` (how many times larger the large workload is).
+
+mod perf_support;
+
+use perf_support::{
+    generate_class_workload, generate_workload, measure, millis, workloads, StageTimings, Workload,
+};
+use std::time::Duration;
+use trust_hir::db::SemanticDatabase;
+use trust_hir::{DiagnosticSeverity, Project, SourceKey};
+
+const DEFAULT_BUDGETS_MS: [(&str, u64); 4] = [
+    ("lex", 2_000),
+    ("parse", 5_000),
+    ("symbols", 15_000),
+    ("diagnostics", 30_000),
+];
+
+/// Stages below this time on the medium workload are too noisy to compare.
+const MIN_GROWTH_SAMPLE: Duration = Duration::from_millis(5);
+
+fn env_u64(name: &str, default: u64) -> u64 {
+    std::env::var(name)
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(default)
+}
+
+/// Best of three runs, per stage, to damp scheduler noise.
+fn best_of_three(workload: &Workload) -> StageTimings {
+    let mut best = measure(workload);
+    for _ in 0..2 {
+        let next = measure(workload);
+        best.lex = best.lex.min(next.lex);
+        best.parse = best.parse.min(next.parse);
+        best.symbols = best.symbols.min(next.symbols);
+        best.diagnostics = best.diagnostics.min(next.diagnostics);
+    }
+    best
+}
+
+#[test]
+fn frontend_stages_stay_within_budget() {
+    let mut measured = Vec::new();
+    for workload in workloads() {
+        let timings = best_of_three(&workload);
+        for (stage, elapsed) in timings.stages() {
+            println!(
+                "perf_stage workload={} files={} function_blocks={} bytes={} stage={} ms={:.2}",
+                workload.name,
+                workload.files.len(),
+                workload.function_blocks,
+                workload.total_bytes(),
+                stage,
+                millis(elapsed)
+            );
+        }
+        measured.push((workload, timings));
+    }
+
+    for (workload, timings) in &measured {
+        for ((stage, elapsed), (_, default_ms)) in
+            timings.stages().into_iter().zip(DEFAULT_BUDGETS_MS)
+        {
+            let budget_ms = env_u64(
+                &format!("ST_HIR_PERF_{}_MS", stage.to_ascii_uppercase()),
+                default_ms,
+            );
+            assert!(
+                elapsed.as_millis() <= budget_ms as u128,
+                "{} stage on {} workload took {:?}, exceeding budget {}ms",
+                stage,
+                workload.name,
+                elapsed,
+                budget_ms
+            );
+        }
+    }
+}
+
+/// Fails when a stage's time per generated POU grows by more than the allowed
+/// factor between a workload and a larger one.
+fn assert_linear_growth(medium: &Workload, large: &Workload) {
+    let medium_timings = best_of_three(medium);
+    let large_timings = best_of_three(large);
+    let max_growth = env_u64("ST_HIR_PERF_MAX_GROWTH", 4) as f64;
+
+    for ((stage, medium_elapsed), (_, large_elapsed)) in medium_timings
+        .stages()
+        .into_iter()
+        .zip(large_timings.stages())
+    {
+        if medium_elapsed < MIN_GROWTH_SAMPLE {
+            continue;
+        }
+        let medium_per_fb = medium_elapsed.as_secs_f64() / medium.function_blocks as f64;
+        let large_per_fb = large_elapsed.as_secs_f64() / large.function_blocks as f64;
+        let growth = large_per_fb / medium_per_fb;
+        println!("perf_growth stage={stage} per_fb_growth={growth:.2}");
+        assert!(
+            growth <= max_growth,
+            "{stage} time per function block grew {growth:.2}x from {} to {} FBs (limit {max_growth}x); \
+             look for a scan that became quadratic",
+            medium.function_blocks,
+            large.function_blocks
+        );
+    }
+}
+
+fn growth_factor() -> usize {
+    env_u64("ST_HIR_PERF_GROWTH_FACTOR", 10).max(2) as usize
+}
+
+#[test]
+#[ignore]
+fn frontend_stages_scale_linearly_with_function_blocks() {
+    let factor = growth_factor();
+    assert_linear_growth(
+        &generate_workload("medium", 4, 48),
+        &generate_workload("large", 12, 48 * factor),
+    );
+}
+
+#[test]
+#[ignore]
+fn oop_diagnostics_scale_linearly_with_classes() {
+    assert_linear_growth(
+        &generate_class_workload("classes_medium", 64),
+        &generate_class_workload("classes_large", 64 * growth_factor()),
+    );
+}
+
+#[test]
+fn generated_workloads_are_free_of_errors() {
+    for workload in [
+        generate_workload("check", 3, 6),
+        generate_class_workload("check_classes", 16),
+    ] {
+        let mut project = Project::new();
+        let file_ids: Vec<_> = workload
+            .files
+            .iter()
+            .map(|(name, text)| {
+                project.set_source_text(SourceKey::from_virtual(name), text.clone())
+            })
+            .collect();
+        let db = project.database();
+        for file_id in file_ids {
+            let diagnostics = db.diagnostics(file_id);
+            let errors: Vec<_> = diagnostics
+                .iter()
+                .filter(|diagnostic| diagnostic.severity == DiagnosticSeverity::Error)
+                .collect();
+            assert!(
+                errors.is_empty(),
+                "unexpected errors in {} workload: {errors:?}",
+                workload.name
+            );
+        }
+    }
+}
@@ -0,0 +1,235 @@
+//! Generated workloads and stage timings shared by the frontend perf test and
+//! the `frontend_stages` benchmark.
+
+#![allow(dead_code)]
+
+use std::fmt::Write as _;
+use std::time::{Duration, Instant};
+
+use trust_hir::db::{FileId, SemanticDatabase};
+use trust_hir::{Project, SourceKey};
+
+/// A set of generated source files measured as one project.
+pub struct Workload {
+    pub name: &'static str,
+    pub files: Vec<(String, String)>,
+    /// Number of generated FBs, or classes for the class workload.
+    pub function_blocks: usize,
+}
+
+impl Workload {
+    pub fn total_bytes(&self) -> usize {
+        self.files.iter().map(|(_, text)| text.len()).sum()
+    }
+}
+
+/// Wall-clock time spent in each frontend stage over all files of a workload.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct StageTimings {
+    pub lex: Duration,
+    pub parse: Duration,
+    pub symbols: Duration,
+    pub diagnostics: Duration,
+}
+
+impl StageTimings {
+    pub fn stages(&self) -> [(&'static str, Duration); 4] {
+        [
+            ("lex", self.lex),
+            ("parse", self.parse),
+            ("symbols", self.symbols),
+            ("diagnostics", self.diagnostics),
+        ]
+    }
+}
+
+/// Small, medium, and large workloads, the large one spreading hundreds of
+/// function blocks over many files so cross-file scans dominate.
+pub fn workloads() -> Vec<Workload> {
+    vec![
+        generate_workload("small", 1, 4),
+        generate_workload("medium", 4, 48),
+        generate_workload("large", 12, 480),
+        generate_class_workload("classes", 400),
+    ]
+}
+
+/// Generates `function_blocks` FBs extending a shared base that implements an
+/// interface, spread round-robin over `files` files, plus a program that
+/// instantiates and calls every FB.
+pub fn generate_workload(name: &'static str, files: usize, function_blocks: usize) -> Workload {
+    let files = files.max(1);
+    let mut texts = vec![String::new(); files];
+    texts[0].push_str(
+        r#"INTERFACE IDevice
+METHOD Start : BOOL
+END_METHOD
+METHOD Stop : BOOL
+END_METHOD
+END_INTERFACE
+
+FUNCTION_BLOCK DeviceBase IMPLEMENTS IDevice
+VAR
+    running : BOOL;
+END_VAR
+METHOD PUBLIC Start : BOOL
+running := TRUE;
+Start := running;
+END_METHOD
+METHOD PUBLIC Stop : BOOL
+running := FALSE;
+Stop := NOT running;
+END_METHOD
+END_FUNCTION_BLOCK
+
+"#,
+    );
+
+    for idx in 0..function_blocks {
+        let text = &mut texts[idx % files];
+        let _ = write!(
+            text,
+            r#"FUNCTION_BLOCK Device{idx} EXTENDS DeviceBase
+VAR_INPUT
+    enable : BOOL;
+    setpoint : INT;
+END_VAR
+VAR_OUTPUT
+    value : INT;
+END_VAR
+VAR
+    ticks : DINT;
+END_VAR
+METHOD PUBLIC OVERRIDE Start : BOOL
+ticks := 0;
+Start := enable;
+END_METHOD
+IF enable THEN
+    ticks := ticks + 1;
+    value := setpoint;
+ELSE
+    value := 0;
+END_IF;
+END_FUNCTION_BLOCK
+
+"#
+        );
+    }
+
+    let program = &mut texts[files - 1];
+    program.push_str("PROGRAM Main\nVAR\n    device : IDevice;\n");
+    for idx in 0..function_blocks {
+        let _ = writeln!(program, "    dev{idx} : Device{idx};");
+    }
+    program.push_str("END_VAR\n");
+    for idx in 0..function_blocks {
+        let _ = writeln!(
+            program,
+            "dev{idx}(enable := TRUE, setpoint := INT#{});",
+            idx % 100
+        );
+    }
+    if function_blocks > 0 {
+        program.push_str("device := dev0;\ndevice.Start();\n");
+    }
+    program.push_str("END_PROGRAM\n");
+
+    Workload {
+        name,
+        files: texts
+            .into_iter()
+            .enumerate()
+            .map(|(idx, text)| (format!("{name}_{idx}.st"), text))
+            .collect(),
+        function_blocks,
+    }
+}
+
+/// Generates a single file of `classes` classes in inheritance chains of eight,
+/// each declaring members and overriding a base method, so the OOP checks walk
+/// many hierarchies within one symbol table.
+pub fn generate_class_workload(name: &'static str, classes: usize) -> Workload {
+    let mut text = String::new();
+    for idx in 0..classes {
+        let extends = if idx % 8 == 0 {
+            String::new()
+        } else {
+            format!(" EXTENDS Shape{}", idx - 1)
+        };
+        let overrides = if idx % 8 == 0 { "" } else { " OVERRIDE" };
+        let _ = write!(
+            text,
+            r#"CLASS Shape{idx}{extends}
+VAR PUBLIC
+    width{idx} : REAL;
+    height{idx} : REAL;
+END_VAR
+METHOD PUBLIC{overrides} Area : REAL
+Area := width{idx} * height{idx};
+END_METHOD
+METHOD PUBLIC Scale{idx}
+VAR_INPUT
+    factor : REAL;
+END_VAR
+width{idx} := width{idx} * factor;
+END_METHOD
+END_CLASS
+
+"#
+        );
+    }
+    Workload {
+        name,
+        files: vec![(format!("{name}_0.st"), text)],
+        function_blocks: classes,
+    }
+}
+
+/// Runs every stage once over a fresh project and returns the timings.
+///
+/// Symbol collection and diagnostics run against a new database each time, so
+/// no stage reuses results memoized by an earlier measurement.
+pub fn measure(workload: &Workload) -> StageTimings {
+    let start = Instant::now();
+    for (_, text) in &workload.files {
+        std::hint::black_box(trust_syntax::lex(text));
+    }
+    let lex = start.elapsed();
+
+    let start = Instant::now();
+    for (_, text) in &workload.files {
+        std::hint::black_box(trust_syntax::parser::parse(text));
+    }
+    let parse = start.elapsed();
+
+    let mut project = Project::new();
+    let file_ids: Vec<FileId> = workload
+        .files
+        .iter()
+        .map(|(name, text)| project.set_source_text(SourceKey::from_virtual(name), text.clone()))
+        .collect();
+    let db = project.database();
+
+    let start = Instant::now();
+    for &file_id in &file_ids {
+        std::hint::black_box(db.file_symbols(file_id));
+    }
+    let symbols = start.elapsed();
+
+    let start = Instant::now();
+    for &file_id in &file_ids {
+        std::hint::black_box(db.diagnostics(file_id));
+    }
+    let diagnostics = start.elapsed();
+
+    StageTimings {
+        lex,
+        parse,
+        symbols,
+        diagnostics,
+    }
+}
+
+pub fn millis(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
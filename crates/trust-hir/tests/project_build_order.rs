@@ -0,0 +1,100 @@
+use trust_hir::db::{FileId, SemanticDatabase};
+use trust_hir::{Project, SourceKey, SymbolId};
+
+fn name_of(project: &Project, (file_id, symbol_id): (FileId, SymbolId)) -> String {
+    project
+        .database()
+        .file_symbols(file_id)
+        .get(symbol_id)
+        .expect("declaration symbol")
+        .name
+        .to_string()
+}
+
+#[test]
+fn build_order_places_types_and_bases_before_users() {
+    let mut project = Project::new();
+    project.set_source_text(
+        SourceKey::from_virtual("main.st"),
+        r#"
+PROGRAM Main
+VAR
+    motor : Motor;
+END_VAR
+motor();
+END_PROGRAM
+"#
+        .to_string(),
+    );
+    project.set_source_text(
+        SourceKey::from_virtual("lib.st"),
+        r#"
+FUNCTION_BLOCK Motor EXTENDS Base
+VAR
+    settings : Config;
+END_VAR
+END_FUNCTION_BLOCK
+
+FUNCTION_BLOCK Base
+END_FUNCTION_BLOCK
+
+TYPE
+    Config : STRUCT
+        speed : Speed;
+        next : REF_TO Config;
+    END_STRUCT;
+    Speed : INT;
+END_TYPE
+"#
+        .to_string(),
+    );
+
+    let order: Vec<String> = project
+        .build_order()
+        .expect("acyclic project")
+        .into_iter()
+        .map(|key| name_of(&project, key))
+        .collect();
+    let position = |name: &str| {
+        order
+            .iter()
+            .position(|candidate| candidate == name)
+            .unwrap_or_else(|| panic!("{name} missing from {order:?}"))
+    };
+    assert_eq!(order.len(), 5, "{order:?}");
+    assert!(position("Speed") < position("Config"), "{order:?}");
+    assert!(position("Config") < position("Motor"), "{order:?}");
+    assert!(position("Base") < position("Motor"), "{order:?}");
+    assert!(position("Motor") < position("Main"), "{order:?}");
+}
+
+#[test]
+fn build_order_reports_type_dependency_cycle() {
+    let mut project = Project::new();
+    project.set_source_text(
+        SourceKey::from_virtual("types.st"),
+        r#"
+TYPE
+    Outer : STRUCT
+        inner : Inner;
+    END_STRUCT;
+    Inner : STRUCT
+        outer : Outer;
+    END_STRUCT;
+    Holder : STRUCT
+        value : Outer;
+    END_STRUCT;
+END_TYPE
+"#
+        .to_string(),
+    );
+
+    let cycles = project.build_order().expect_err("cyclic types");
+    assert_eq!(cycles.len(), 1, "{cycles:?}");
+    let members: Vec<String> = cycles[0]
+        .members
+        .iter()
+        .map(|key| name_of(&project, *key))
+        .collect();
+    assert_eq!(members, vec!["Outer", "Inner"]);
+}
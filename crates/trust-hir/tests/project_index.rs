@@ -0,0 +1,173 @@
+use trust_hir::db::SemanticDatabase;
+use trust_hir::project::IndexedSymbolKind;
+use trust_hir::{Project, ProjectIndex, SourceKey};
+
+fn sample_project(lib: &str, main: &str) -> Project {
+    let mut project = Project::new();
+    project.set_source_text(SourceKey::from_virtual("lib.st"), lib.to_string());
+    project.set_source_text(SourceKey::from_virtual("main.st"), main.to_string());
+    project
+}
+
+fn reload(index: &ProjectIndex) -> ProjectIndex {
+    let payload = serde_json::to_string(index).expect("serialize index");
+    serde_json::from_str(&payload).expect("deserialize index")
+}
+
+#[test]
+fn reloaded_index_matches_a_fresh_parse() {
+    let lib = r#"
+TYPE Mode : (Idle, Running); END_TYPE
+
+FUNCTION_BLOCK Pump
+VAR_INPUT
+    speed : INT;
+END_VAR
+END_FUNCTION_BLOCK
+
+CONFIGURATION Plant
+VAR_GLOBAL
+    Setpoint : INT;
+END_VAR
+END_CONFIGURATION
+"#;
+    let main = r#"
+PROGRAM Main
+VAR
+    pump : Pump;
+    mode : Mode;
+END_VAR
+pump(speed := Setpoint);
+END_PROGRAM
+"#;
+    let index = reload(&ProjectIndex::build(&sample_project(lib, main)));
+    assert!(index.is_current_version());
+
+    let fresh = sample_project(lib, main);
+    for (key, file_id) in fresh.sources().iter() {
+        let symbols = fresh.database().file_symbols(file_id);
+        let mut expected = 0;
+        for symbol in symbols
+            .iter()
+            .filter(|symbol| symbol.origin.is_none() && !symbol.range.is_empty())
+        {
+            expected += 1;
+            let found = index.find_symbols(&symbol.name).any(|(file, indexed)| {
+                file == key.display()
+                    && indexed.range() == symbol.range
+                    && IndexedSymbolKind::from(&symbol.kind) == indexed.kind
+            });
+            assert!(
+                found,
+                "{} missing from index for {}",
+                symbol.name,
+                key.display()
+            );
+        }
+        let indexed = index.file(key).expect("file entry").symbols.len();
+        assert_eq!(indexed, expected, "symbol count for {}", key.display());
+    }
+
+    let (_, pump) = index
+        .find_symbols("PUMP")
+        .find(|(file, _)| *file == "main.st")
+        .expect("pump instance");
+    assert_eq!(pump.type_name.as_deref(), Some("Pump"));
+    assert_eq!(pump.container.as_deref(), Some("Main"));
+}
+
+#[test]
+fn index_records_cross_file_references() {
+    let lib = r#"
+TYPE Mode : (Idle, Running); END_TYPE
+
+FUNCTION_BLOCK Pump
+VAR_INPUT
+    speed : INT;
+END_VAR
+END_FUNCTION_BLOCK
+
+CONFIGURATION Plant
+VAR_GLOBAL
+    Setpoint : INT;
+END_VAR
+END_CONFIGURATION
+"#;
+    let main = r#"
+PROGRAM Main
+VAR
+    pump : Pump;
+    mode : Mode;
+END_VAR
+pump(speed := Setpoint);
+END_PROGRAM
+"#;
+    let index = reload(&ProjectIndex::build(&sample_project(lib, main)));
+
+    assert_eq!(
+        index.declaring_files("pump").collect::<Vec<_>>(),
+        ["lib.st"]
+    );
+    assert_eq!(
+        index.declaring_files("setpoint").collect::<Vec<_>>(),
+        ["lib.st"]
+    );
+
+    let setpoint_uses: Vec<_> = index
+        .references_to("Setpoint")
+        .map(|(file, reference)| (file, &main[reference.range()]))
+        .collect();
+    assert_eq!(setpoint_uses, [("main.st", "Setpoint")]);
+}
+
+#[test]
+fn changed_files_are_invalidated_and_rebuilt() {
+    let lib = r#"
+TYPE Mode : (Idle, Running); END_TYPE
+
+FUNCTION_BLOCK Pump
+VAR_INPUT
+    speed : INT;
+END_VAR
+END_FUNCTION_BLOCK
+
+CONFIGURATION Plant
+VAR_GLOBAL
+    Setpoint : INT;
+END_VAR
+END_CONFIGURATION
+"#;
+    let main = r#"
+PROGRAM Main
+VAR
+    pump : Pump;
+    mode : Mode;
+END_VAR
+pump(speed := Setpoint);
+END_PROGRAM
+"#;
+    let mut index = reload(&ProjectIndex::build(&sample_project(lib, main)));
+    let lib_key = SourceKey::from_virtual("lib.st");
+    let main_key = SourceKey::from_virtual("main.st");
+    let edited = main.replace("mode : Mode;", "mode : Mode;\n    count : DINT;");
+
+    assert!(index.fresh_file(&main_key, main).is_some());
+    assert!(index.fresh_file(&main_key, &edited).is_none());
+
+    let stale = index.invalidate([(&lib_key, lib), (&main_key, edited.as_str())]);
+    assert_eq!(stale, vec![main_key.clone()]);
+    assert!(
+        index.file(&main_key).is_none(),
+        "stale entry should be dropped"
+    );
+    assert!(index.fresh_file(&lib_key, lib).is_some());
+
+    let mut project = sample_project(lib, main);
+    project.set_source_text(main_key.clone(), edited.clone());
+    index.update(&project, &stale);
+    assert!(index.fresh_file(&main_key, &edited).is_some());
+    assert!(index
+        .find_symbols("count")
+        .any(|(file, symbol)| file == "main.st" && symbol.type_name.as_deref() == Some("DINT")));
+    assert_eq!(index, ProjectIndex::build(&project));
+}
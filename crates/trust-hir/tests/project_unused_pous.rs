@@ -0,0 +1,148 @@
+use trust_hir::db::SemanticDatabase;
+use trust_hir::{Project, SourceKey};
+
+fn unused_names(project: &Project) -> Vec<String> {
+    let mut names: Vec<String> = project
+        .unused_pous()
+        .into_iter()
+        .map(|(file_id, symbol_id)| {
+            let symbols = project.database().file_symbols(file_id);
+            symbols
+                .get(symbol_id)
+                .expect("unused symbol")
+                .name
+                .to_string()
+        })
+        .collect();
+    names.sort();
+    names
+}
+
+#[test]
+fn unused_pous_follow_references_from_programs_across_files() {
+    let mut project = Project::new();
+    project.set_source_text(
+        SourceKey::from_virtual("lib.st"),
+        r#"
+FUNCTION Helper : INT
+Helper := 1;
+END_FUNCTION
+
+FUNCTION_BLOCK Used
+VAR
+    x : INT;
+END_VAR
+x := Helper();
+END_FUNCTION_BLOCK
+
+FUNCTION_BLOCK Orphan
+VAR
+    inner : OnlyFromOrphan;
+END_VAR
+END_FUNCTION_BLOCK
+
+FUNCTION_BLOCK OnlyFromOrphan
+END_FUNCTION_BLOCK
+
+CLASS Unreferenced
+END_CLASS
+"#
+        .to_string(),
+    );
+    project.set_source_text(
+        SourceKey::from_virtual("main.st"),
+        r#"
+PROGRAM Main
+VAR
+    fb : Used;
+END_VAR
+fb();
+END_PROGRAM
+"#
+        .to_string(),
+    );
+
+    assert_eq!(
+        unused_names(&project),
+        vec!["OnlyFromOrphan", "Orphan", "Unreferenced"]
+    );
+}
+
+#[test]
+fn unused_pous_treat_configuration_references_as_roots() {
+    let mut project = Project::new();
+    project.set_source_text(
+        SourceKey::from_virtual("config.st"),
+        r#"
+FUNCTION_BLOCK Motor
+END_FUNCTION_BLOCK
+
+CONFIGURATION Plant
+VAR_GLOBAL
+    pump : Motor;
+END_VAR
+RESOURCE Cpu ON PLC
+    TASK Fast (INTERVAL := T#10ms, PRIORITY := 1);
+    PROGRAM Instance WITH Fast : Idle;
+END_RESOURCE
+END_CONFIGURATION
+
+PROGRAM Idle
+END_PROGRAM
+"#
+        .to_string(),
+    );
+
+    assert!(unused_names(&project).is_empty());
+}
+
+#[test]
+fn unused_pous_skip_hidden_and_exported_library_pous() {
+    let mut project = Project::new();
+    project.set_source_text(
+        SourceKey::from_virtual("vendor.st"),
+        r#"
+{attribute 'hide'}
+FUNCTION_BLOCK HiddenInternal
+VAR
+    helper : HiddenHelper;
+END_VAR
+END_FUNCTION_BLOCK
+
+FUNCTION_BLOCK HiddenHelper
+END_FUNCTION_BLOCK
+
+{attribute 'export'}
+FUNCTION ExportedApi : INT
+ExportedApi := 0;
+END_FUNCTION
+
+FUNCTION_BLOCK HeaderMarked {attribute 'linkalways'}
+END_FUNCTION_BLOCK
+
+FUNCTION_BLOCK PlainVendor
+END_FUNCTION_BLOCK
+"#
+        .to_string(),
+    );
+
+    assert_eq!(unused_names(&project), vec!["PlainVendor"]);
+}
+
+#[test]
+fn unused_pous_update_after_edit() {
+    let mut project = Project::new();
+    project.set_source_text(
+        SourceKey::from_virtual("lib.st"),
+        "FUNCTION_BLOCK Valve\nEND_FUNCTION_BLOCK\n".to_string(),
+    );
+    let main = SourceKey::from_virtual("main.st");
+    project.set_source_text(main.clone(), "PROGRAM Main\nEND_PROGRAM\n".to_string());
+    assert_eq!(unused_names(&project), vec!["Valve"]);
+
+    project.set_source_text(
+        main,
+        "PROGRAM Main\nVAR\n    v : Valve;\nEND_VAR\nEND_PROGRAM\n".to_string(),
+    );
+    assert!(unused_names(&project).is_empty());
+}
@@ -76,6 +76,60 @@ END_INTERFACE
     assert!(errors.is_empty(), "Expected no errors, got: {:?}", errors);
 }
 
+#[test]
+fn test_interface_assignment_from_inherited_implementation_cross_file() {
+    let mut db = Database::new();
+    db.set_source_text(
+        FileId(0),
+        r#"
+INTERFACE IDevice
+    METHOD Start : BOOL
+    END_METHOD
+END_INTERFACE
+
+FUNCTION_BLOCK DeviceBase IMPLEMENTS IDevice
+    METHOD PUBLIC Start : BOOL
+        Start := TRUE;
+    END_METHOD
+END_FUNCTION_BLOCK
+
+FUNCTION_BLOCK Device EXTENDS DeviceBase
+    METHOD PUBLIC OVERRIDE Start : BOOL
+        Start := FALSE;
+    END_METHOD
+END_FUNCTION_BLOCK
+"#
+        .to_string(),
+    );
+    db.set_source_text(
+        FileId(1),
+        r#"
+FUNCTION_BLOCK OtherDevice EXTENDS DeviceBase
+    METHOD PUBLIC OVERRIDE Start : BOOL
+        Start := FALSE;
+    END_METHOD
+END_FUNCTION_BLOCK
+
+PROGRAM Main
+VAR
+    device : IDevice;
+    dev : Device;
+END_VAR
+device := dev;
+END_PROGRAM
+"#
+        .to_string(),
+    );
+
+    let errors: Vec<_> = db
+        .diagnostics(FileId(1))
+        .iter()
+        .filter(|d| d.severity == DiagnosticSeverity::Error)
+        .map(|d| d.code)
+        .collect();
+    assert!(errors.is_empty(), "Expected no errors, got: {:?}", errors);
+}
+
 #[test]
 fn test_interface_extends_non_interface_error() {
     check_has_error(
@@ -69,6 +69,129 @@ END_PROGRAM
     );
 }
 
+#[test]
+fn test_duplicate_declaration_across_var_blocks_reports_related() {
+    let source = r#"
+FUNCTION_BLOCK FB_Dup
+    VAR_INPUT
+        x : INT;
+    END_VAR
+    VAR
+        x : INT;
+    END_VAR
+END_FUNCTION_BLOCK
+"#;
+    let mut db = Database::new();
+    let file = FileId(0);
+    db.set_source_text(file, source.to_string());
+    let diagnostics = db.diagnostics(file);
+    let duplicates: Vec<_> = diagnostics
+        .iter()
+        .filter(|d| d.code == DiagnosticCode::DuplicateDeclaration)
+        .collect();
+    assert_eq!(duplicates.len(), 1, "{duplicates:?}");
+
+    let first = source.find("x : INT").expect("first declaration");
+    let second = source.rfind("x : INT").expect("second declaration");
+    let diag = duplicates[0];
+    assert_eq!(usize::from(diag.range.start()), second);
+    assert_eq!(diag.related.len(), 1);
+    assert_eq!(usize::from(diag.related[0].range.start()), first);
+    assert_eq!(diag.related[0].message, "previously declared here");
+}
+
+#[test]
+fn test_same_variable_name_in_different_function_blocks_ok() {
+    check_no_errors(
+        r#"
+FUNCTION_BLOCK FB_A
+    VAR
+        x : INT;
+    END_VAR
+    x := 1;
+END_FUNCTION_BLOCK
+
+FUNCTION_BLOCK FB_B
+    VAR
+        x : INT;
+    END_VAR
+    x := 2;
+END_FUNCTION_BLOCK
+"#,
+    );
+}
+
+#[test]
+fn test_same_global_name_in_different_resources_ok() {
+    check_no_errors(
+        r#"
+PROGRAM Counter
+VAR_EXTERNAL
+    count : INT;
+END_VAR
+count := count + 1;
+END_PROGRAM
+
+CONFIGURATION Plant
+RESOURCE R1 ON CPU
+VAR_GLOBAL
+    count : INT;
+END_VAR
+PROGRAM P1 : Counter;
+END_RESOURCE
+RESOURCE R2 ON CPU
+VAR_GLOBAL
+    count : INT;
+END_VAR
+PROGRAM P2 : Counter;
+END_RESOURCE
+END_CONFIGURATION
+"#,
+    );
+}
+
+#[test]
+fn test_resource_global_duplicate_within_resource() {
+    check_has_error(
+        r#"
+CONFIGURATION Plant
+RESOURCE R1 ON CPU
+VAR_GLOBAL
+    count : INT;
+END_VAR
+VAR_GLOBAL
+    count : BOOL;
+END_VAR
+END_RESOURCE
+END_CONFIGURATION
+"#,
+        DiagnosticCode::DuplicateDeclaration,
+    );
+}
+
+#[test]
+fn test_method_local_shadowing_is_not_duplicate() {
+    let errors = check_errors(
+        r#"
+FUNCTION_BLOCK FB_Shadow
+    VAR
+        x : INT;
+    END_VAR
+    METHOD Run : INT
+        VAR
+            x : INT;
+        END_VAR
+        Run := x;
+    END_METHOD
+END_FUNCTION_BLOCK
+"#,
+    );
+    assert!(
+        !errors.contains(&DiagnosticCode::DuplicateDeclaration),
+        "{errors:?}"
+    );
+}
+
 #[test]
 fn test_invalid_identifier() {
     check_has_error(
@@ -135,3 +258,30 @@ END_PROGRAM
 "#,
     );
 }
+
+#[test]
+fn test_compile_time_builtins_resolve_without_declaration() {
+    check_no_errors(
+        r#"
+FUNCTION_BLOCK Logger
+    VAR source : STRING; line : INT; END_VAR
+    source := __POUNAME;
+    IF line = 0 THEN
+        line := __LINE;
+    END_IF;
+END_FUNCTION_BLOCK
+"#,
+    );
+}
+
+#[test]
+fn test_compile_time_builtins_are_not_assignable() {
+    check_has_error(
+        r#"
+PROGRAM Test
+    __LINE := 3;
+END_PROGRAM
+"#,
+        DiagnosticCode::InvalidAssignmentTarget,
+    );
+}
@@ -1,5 +1,8 @@
 mod common;
 use common::*;
+use trust_hir::const_eval::ConstValue;
+use trust_hir::db::PouParameter;
+use trust_hir::TypeId;
 
 // Parameter Tests
 #[test]
@@ -105,3 +108,86 @@ END_FUNCTION_BLOCK
     let local_found = symbols.iter().any(|s| s.name == "localVar");
     assert!(local_found, "Should have 'localVar' symbol");
 }
+
+fn pou_parameters(source: &str, pou: &str) -> Vec<PouParameter> {
+    let mut db = Database::new();
+    let file = FileId(0);
+    db.set_source_text(file, source.to_string());
+    let pou_id = db.resolve_name(file, pou).expect("POU symbol");
+    db.pou_parameters(file, pou_id)
+}
+
+#[test]
+fn test_pou_parameters_report_mixed_directions() {
+    let params = pou_parameters(
+        r#"
+FUNCTION Scale : BOOL
+VAR_INPUT
+    // Raw sensor value.
+    raw : INT;
+END_VAR
+VAR_OUTPUT
+    scaled : REAL; (* Engineering units *)
+END_VAR
+VAR_IN_OUT
+    total : INT;
+END_VAR
+Scale := TRUE;
+END_FUNCTION
+"#,
+        "Scale",
+    );
+
+    let summary: Vec<_> = params
+        .iter()
+        .map(|param| (param.name.as_str(), param.type_id, param.direction))
+        .collect();
+    assert_eq!(
+        summary,
+        vec![
+            ("raw", TypeId::INT, ParamDirection::In),
+            ("scaled", TypeId::REAL, ParamDirection::Out),
+            ("total", TypeId::INT, ParamDirection::InOut),
+        ]
+    );
+    assert!(params.iter().all(|param| param.default.is_none()));
+    assert_eq!(params[0].doc.as_deref(), Some("Raw sensor value."));
+    assert_eq!(params[1].doc.as_deref(), Some("Engineering units"));
+    assert_eq!(params[2].doc, None);
+}
+
+#[test]
+fn test_pou_parameters_resolve_constant_defaults() {
+    let params = pou_parameters(
+        r#"
+FUNCTION_BLOCK Ramp
+VAR CONSTANT
+    LIMIT : INT := 40;
+END_VAR
+VAR_INPUT
+    target : INT := LIMIT * 2 + 1;
+    rate : REAL := -0.5;
+    enable : BOOL := TRUE;
+    label : STRING := 'ramp';
+    source : INT;
+END_VAR
+END_FUNCTION_BLOCK
+"#,
+        "Ramp",
+    );
+
+    let defaults: Vec<_> = params
+        .iter()
+        .map(|param| (param.name.as_str(), param.default.clone()))
+        .collect();
+    assert_eq!(
+        defaults,
+        vec![
+            ("target", Some(ConstValue::Int(81))),
+            ("rate", Some(ConstValue::Real(-0.5))),
+            ("enable", Some(ConstValue::Bool(true))),
+            ("label", Some(ConstValue::String("'ramp'".into()))),
+            ("source", None),
+        ]
+    );
+}
@@ -106,6 +106,76 @@ END_FUNCTION_BLOCK
     );
 }
 
+#[test]
+fn test_bare_method_call_requires_instance_receiver() {
+    let source = r#"
+FUNCTION_BLOCK FB_Motor
+    VAR
+        running : BOOL;
+    END_VAR
+
+    METHOD PUBLIC Start
+        running := TRUE;
+    END_METHOD
+
+    METHOD PUBLIC Restart
+        Start();
+        THIS.Start();
+    END_METHOD
+END_FUNCTION_BLOCK
+
+PROGRAM Main
+    VAR
+        motor : FB_Motor;
+    END_VAR
+    Start();
+END_PROGRAM
+"#;
+    let mut db = Database::new();
+    let file = FileId(0);
+    db.set_source_text(file, source.to_string());
+    let diagnostics = db.diagnostics(file);
+    let diag = diagnostics
+        .iter()
+        .find(|d| d.code == DiagnosticCode::UndefinedFunction)
+        .expect("missing receiver diagnostic");
+    assert!(
+        diag.message.contains("method of 'FB_Motor'") && diag.message.contains("receiver"),
+        "unexpected message: {}",
+        diag.message
+    );
+}
+
+#[test]
+fn test_method_call_through_instance_and_sibling_ok() {
+    check_no_errors(
+        r#"
+FUNCTION_BLOCK FB_Motor
+    VAR
+        running : BOOL;
+    END_VAR
+
+    METHOD PUBLIC Start
+        running := TRUE;
+    END_METHOD
+
+    METHOD PUBLIC Restart
+        Start();
+        THIS.Start();
+    END_METHOD
+END_FUNCTION_BLOCK
+
+PROGRAM Main
+    VAR
+        motor : FB_Motor;
+    END_VAR
+    motor.Start();
+    motor.Restart();
+END_PROGRAM
+"#,
+    );
+}
+
 #[test]
 fn test_super_field_access() {
     check_no_errors(
@@ -266,3 +336,48 @@ END_FUNCTION_BLOCK
         DiagnosticCode::IncompatibleAssignment,
     );
 }
+
+#[test]
+fn test_member_accessibility_by_location() {
+    let mut db = Database::new();
+    let file = FileId(0);
+    db.set_source_text(
+        file,
+        r#"
+CLASS Base
+    METHOD PUBLIC PubM
+    END_METHOD
+    METHOD PROTECTED ProtM
+    END_METHOD
+    METHOD PRIVATE PrivM
+    END_METHOD
+    METHOD INTERNAL IntM
+    END_METHOD
+END_CLASS
+
+CLASS Derived EXTENDS Base
+END_CLASS
+
+CLASS Sibling
+END_CLASS
+
+NAMESPACE Other
+    CLASS Stranger
+    END_CLASS
+END_NAMESPACE
+"#
+        .to_string(),
+    );
+
+    let symbols = db.file_symbols(file);
+    let id = |name: &str| symbols.iter().find(|s| s.name == name).unwrap().id;
+    let scope = |name: &str| symbols.scope_for_owner(id(name)).unwrap();
+    let members = ["PubM", "ProtM", "PrivM", "IntM"].map(id);
+    let accessible_from =
+        |owner: &str| members.map(|member| symbols.is_accessible(member, scope(owner)));
+
+    assert_eq!(accessible_from("Base"), [true, true, true, true]);
+    assert_eq!(accessible_from("Derived"), [true, true, false, true]);
+    assert_eq!(accessible_from("Sibling"), [true, false, false, true]);
+    assert_eq!(accessible_from("Stranger"), [true, false, false, false]);
+}
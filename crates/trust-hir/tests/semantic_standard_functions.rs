@@ -136,6 +136,112 @@ END_PROGRAM
     );
 }
 
+#[test]
+fn test_variadic_standard_functions_below_minimum() {
+    for call in ["ADD()", "MAX(x)", "MIN(x)", "MUL(x)", "CONCAT(s)"] {
+        let source = format!(
+            r#"
+PROGRAM Test
+VAR
+    x: DINT;
+    b: BOOL;
+    s: STRING;
+END_VAR
+x := {call};
+END_PROGRAM
+"#
+        );
+        let errors = check_errors(&source);
+        assert!(
+            errors.contains(&DiagnosticCode::WrongArgumentCount),
+            "{call}: {errors:?}"
+        );
+    }
+}
+
+#[test]
+fn test_variadic_minimum_error_names_function_and_minimum() {
+    let mut db = Database::new();
+    let file = FileId(0);
+    db.set_source_text(
+        file,
+        r#"
+PROGRAM Test
+VAR
+    x: DINT;
+END_VAR
+x := MAX(x);
+END_PROGRAM
+"#
+        .to_string(),
+    );
+    let diagnostics = db.diagnostics(file);
+    let diag = diagnostics
+        .iter()
+        .find(|d| d.code == DiagnosticCode::WrongArgumentCount)
+        .expect("missing argument count diagnostic");
+    assert_eq!(diag.message, "MAX expects at least 2 arguments, found 1");
+}
+
+#[test]
+fn test_variadic_standard_functions_accept_more_than_minimum() {
+    check_no_errors(
+        r#"
+PROGRAM Test
+VAR
+    a, b, c, x: DINT;
+END_VAR
+x := ADD(a, b, c);
+x := MAX(a, b, c);
+END_PROGRAM
+"#,
+    );
+}
+
+#[test]
+fn test_math_functions_infer_real_results() {
+    check_no_errors(
+        r#"
+PROGRAM Test
+VAR
+    r: REAL;
+    lr: LREAL;
+    i: SINT;
+END_VAR
+r := SQRT(r);
+r := LN(REAL#2.0);
+lr := SQRT(lr);
+lr := LOG(lr);
+i := ABS(i);
+END_PROGRAM
+"#,
+    );
+    check_has_error(
+        r#"
+PROGRAM Test
+VAR
+    r: REAL;
+    i: INT;
+END_VAR
+i := SQRT(r);
+END_PROGRAM
+"#,
+        DiagnosticCode::IncompatibleAssignment,
+    );
+    check_has_error(
+        r#"
+PROGRAM Test
+VAR
+    i: INT;
+    r: REAL;
+END_VAR
+r := LN(i);
+END_PROGRAM
+"#,
+        DiagnosticCode::InvalidArgumentType,
+    );
+}
+
 #[test]
 fn test_standard_function_type_mismatch() {
     check_has_error(
@@ -151,6 +257,160 @@ END_PROGRAM
     );
 }
 
+#[test]
+// IEC 61131-3 Ed.3 Table 29 (extensible comparison functions)
+fn test_comparison_chains_return_bool_for_ordered_operands() {
+    check_no_errors(
+        r#"
+PROGRAM Test
+VAR
+    x: INT;
+    r: REAL;
+    t: TIME;
+    s: STRING;
+    ok: BOOL;
+END_VAR
+ok := GT(3, 2, 1);
+ok := EQ(x, x, x);
+ok := LE(x, r, 10.0);
+ok := GE(t, T#1s, T#0s);
+ok := LT(s, 'b', 'c');
+ok := NE(x, r);
+END_PROGRAM
+"#,
+    );
+}
+
+#[test]
+fn test_comparison_chain_rejects_incompatible_operands() {
+    check_has_error(
+        r#"
+PROGRAM Test
+VAR
+    x: INT;
+    ok: BOOL;
+END_VAR
+ok := GT(x, 'abc', 1);
+END_PROGRAM
+"#,
+        DiagnosticCode::InvalidArgumentType,
+    );
+    check_has_error(
+        r#"
+PROGRAM Test
+VAR
+    x: INT;
+    ok: BOOL;
+END_VAR
+ok := NE(x, x, x);
+END_PROGRAM
+"#,
+        DiagnosticCode::WrongArgumentCount,
+    );
+}
+
+#[test]
+fn test_move_accepts_en_eno_and_composite_types() {
+    check_no_errors(
+        r#"
+TYPE Point : STRUCT
+    x : INT;
+    y : INT;
+END_STRUCT
+END_TYPE
+
+PROGRAM Test
+VAR
+    a, b: Point;
+    arr1, arr2: ARRAY[1..3] OF INT;
+    i, j: INT;
+    go, ok: BOOL;
+END_VAR
+b := MOVE(a);
+arr2 := MOVE(IN := arr1);
+i := MOVE(EN := go, IN := j, ENO => ok);
+END_PROGRAM
+"#,
+    );
+}
+
+#[test]
+fn test_move_rejects_non_bool_en() {
+    check_has_error(
+        r#"
+PROGRAM Test
+VAR
+    i: INT;
+END_VAR
+i := MOVE(EN := 1, IN := 5);
+END_PROGRAM
+"#,
+        DiagnosticCode::InvalidArgumentType,
+    );
+}
+
+#[test]
+fn test_move_with_en_outside_assignment_is_rejected() {
+    check_has_error(
+        r#"
+PROGRAM Test
+VAR
+    i, j: INT;
+    go: BOOL;
+END_VAR
+i := MOVE(EN := go, IN := j) + 1;
+END_PROGRAM
+"#,
+        DiagnosticCode::InvalidOperation,
+    );
+}
+
+#[test]
+fn test_int_to_bool_conversion_returns_bool() {
+    check_no_errors(
+        r#"
+PROGRAM Test
+VAR
+    i: INT;
+    w: WORD;
+    flag: BOOL;
+END_VAR
+flag := INT_TO_BOOL(i);
+flag := WORD_TO_BOOL(w);
+flag := TO_BOOL(i);
+END_PROGRAM
+"#,
+    );
+    check_has_error(
+        r#"
+PROGRAM Test
+VAR
+    i: INT;
+    x: INT;
+END_VAR
+x := INT_TO_BOOL(i);
+END_PROGRAM
+"#,
+        DiagnosticCode::ImplicitBoolConversion,
+    );
+}
+
+#[test]
+fn test_invalid_conversion_target_error() {
+    check_has_error(
+        r#"
+PROGRAM Test
+VAR
+    r: REAL;
+    flag: BOOL;
+END_VAR
+flag := REAL_TO_BOOL(r);
+END_PROGRAM
+"#,
+        DiagnosticCode::InvalidArgumentType,
+    );
+}
+
 #[test]
 fn test_using_directive_resolves_type() {
     check_no_errors(
@@ -304,10 +564,75 @@ CASE Mode OF
 END_CASE;
 END_PROGRAM
 "#,
-        DiagnosticCode::InvalidOperation,
+        DiagnosticCode::DuplicateCaseLabel,
     );
 }
 
+#[test]
+fn test_overlapping_case_labels_error() {
+    for labels in [
+        "1..5: Mode := 2;\n    4..8: Mode := 3;",
+        "1..5: Mode := 2;\n    3: Mode := 3;",
+        "3: Mode := 2;\n    1..5: Mode := 3;",
+        "1, 2: Mode := 2;\n    9..7: Mode := 3;\n    8: Mode := 4;",
+    ] {
+        let errors = check_errors(&format!(
+            "PROGRAM Test\nVAR\n    Mode : DINT;\nEND_VAR\nCASE Mode OF\n    {labels}\nEND_CASE;\nEND_PROGRAM\n"
+        ));
+        assert_eq!(errors, vec![DiagnosticCode::DuplicateCaseLabel], "{labels}");
+    }
+}
+
+#[test]
+fn test_duplicate_enum_case_label_error() {
+    check_has_error(
+        r#"
+TYPE Color : (Red, Green, Blue); END_TYPE
+
+PROGRAM Test
+VAR
+    c : Color;
+    x : INT;
+END_VAR
+CASE c OF
+    Color#Red, Color#Green: x := 1;
+    Color#Green: x := 2;
+    Color#Blue: x := 3;
+END_CASE;
+END_PROGRAM
+"#,
+        DiagnosticCode::DuplicateCaseLabel,
+    );
+}
+
+#[test]
+fn test_duplicate_case_label_points_at_earlier_label() {
+    let source = r#"
+PROGRAM Test
+VAR
+    Mode : DINT;
+END_VAR
+CASE Mode OF
+    1..5: Mode := 2;
+    4..8: Mode := 3;
+END_CASE;
+END_PROGRAM
+"#;
+    let mut db = Database::new();
+    let file = FileId(0);
+    db.set_source_text(file, source.to_string());
+    let diagnostics = db.diagnostics(file);
+    let duplicate = diagnostics
+        .iter()
+        .find(|d| d.code == DiagnosticCode::DuplicateCaseLabel)
+        .expect("duplicate CASE label diagnostic");
+
+    let text = |range: text_size::TextRange| &source[range];
+    assert_eq!(text(duplicate.range), "4..8");
+    assert_eq!(duplicate.related.len(), 1);
+    assert_eq!(text(duplicate.related[0].range), "1..5");
+}
+
 #[test]
 fn test_duplicate_label_declaration_error() {
     check_has_error(
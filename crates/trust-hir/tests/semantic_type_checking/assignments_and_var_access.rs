@@ -111,6 +111,49 @@ END_CLASS
     );
 }
 
+#[test]
+fn test_this_deref_matches_this_member_access() {
+    let source = |receiver: &str| {
+        format!(
+            r#"
+FUNCTION_BLOCK FB_Counter
+    VAR
+        count : INT;
+        total : INT;
+        flag : BOOL;
+    END_VAR
+    METHOD Update
+        total := {receiver}.count + 1;
+        flag := {receiver}.count;
+    END_METHOD
+END_FUNCTION_BLOCK
+"#
+        )
+    };
+    let plain = check_errors(&source("THIS"));
+    let deref = check_errors(&source("THIS^"));
+    assert_eq!(plain, deref);
+    assert!(
+        deref.contains(&DiagnosticCode::ImplicitBoolConversion),
+        "{deref:?}"
+    );
+}
+
+#[test]
+fn test_this_deref_outside_instance_context_error() {
+    check_has_error(
+        r#"
+PROGRAM Main
+    VAR
+        x : INT;
+    END_VAR
+    x := THIS^.x;
+END_PROGRAM
+"#,
+        DiagnosticCode::CannotResolve,
+    );
+}
+
 #[test]
 fn test_property_without_setter_assignment_error() {
     check_has_error(
@@ -230,7 +273,7 @@ END_FUNCTION_BLOCK
     let Type::Array { dimensions, .. } = symbols.type_by_id(type_id).unwrap() else {
         panic!("expected array type");
     };
-    assert_eq!(dimensions, &vec![(0, 6)]);
+    assert_eq!(dimensions, &vec![Some((0, 6))]);
 }
 
 #[test]
@@ -258,7 +301,65 @@ END_PROGRAM
     let Type::Array { dimensions, .. } = symbols.type_by_id(type_id).unwrap() else {
         panic!("expected array type");
     };
-    assert_eq!(dimensions, &vec![(1, 3)]);
+    assert_eq!(dimensions, &vec![Some((1, 3))]);
+}
+
+#[test]
+fn test_array_bounds_typed_enum_literal() {
+    let mut db = Database::new();
+    let file = FileId(0);
+    db.set_source_text(
+        file,
+        r#"
+TYPE Color : (Red, Green, Blue)
+END_TYPE
+
+PROGRAM Test
+    VAR
+        arr : ARRAY[0..Color#Blue] OF INT;
+    END_VAR
+END_PROGRAM
+"#
+        .to_string(),
+    );
+
+    let symbols = db.file_symbols(file);
+    let arr = symbols.iter().find(|s| s.name == "arr").unwrap();
+    let type_id = symbols.resolve_alias_type(arr.type_id);
+    let Type::Array { dimensions, .. } = symbols.type_by_id(type_id).unwrap() else {
+        panic!("expected array type");
+    };
+    assert_eq!(dimensions, &vec![Some((0, 2))]);
+}
+
+#[test]
+fn test_array_bounds_non_constant_are_unresolved() {
+    let mut db = Database::new();
+    let file = FileId(0);
+    db.set_source_text(
+        file,
+        r#"
+PROGRAM Test
+    VAR
+        n : DINT := 4;
+        arr : ARRAY[0..n] OF INT;
+    END_VAR
+END_PROGRAM
+"#
+        .to_string(),
+    );
+
+    let symbols = db.file_symbols(file);
+    let arr = symbols.iter().find(|s| s.name == "arr").unwrap();
+    let type_id = symbols.resolve_alias_type(arr.type_id);
+    let Type::Array { dimensions, .. } = symbols.type_by_id(type_id).unwrap() else {
+        panic!("expected array type");
+    };
+    assert_eq!(dimensions, &vec![None]);
+    assert_eq!(
+        symbols.type_name(type_id).as_deref(),
+        Some("ARRAY[?] OF INT")
+    );
 }
 
 #[test]
@@ -274,6 +375,88 @@ END_PROGRAM
     );
 }
 
+#[test]
+fn test_array_index_constant_below_lower_bound() {
+    check_has_error(
+        r#"
+PROGRAM Test
+    VAR CONSTANT LO : INT := 1; END_VAR
+    VAR arr : ARRAY[1..10] OF INT; END_VAR
+    arr[LO - 1] := 1;
+END_PROGRAM
+"#,
+        DiagnosticCode::OutOfRange,
+    );
+}
+
+#[test]
+fn test_array_index_out_of_bounds_per_dimension() {
+    let errors = check_errors(
+        r#"
+PROGRAM Test
+    VAR grid : ARRAY[0..9, 1..5] OF INT; END_VAR
+    grid[9, 5] := 1;
+    grid[9, 0] := 2;
+    grid[10, 1] := 3;
+END_PROGRAM
+"#,
+    );
+    assert_eq!(errors, vec![DiagnosticCode::OutOfRange; 2]);
+}
+
+#[test]
+fn test_array_index_out_of_bounds_nested_array() {
+    check_has_error(
+        r#"
+TYPE Row : ARRAY[0..3] OF INT; END_TYPE
+
+PROGRAM Test
+    VAR rows : ARRAY[0..1] OF Row; END_VAR
+    rows[1][4] := 1;
+END_PROGRAM
+"#,
+        DiagnosticCode::OutOfRange,
+    );
+}
+
+#[test]
+fn test_array_index_bounds_skip_non_constant_index_and_open_bounds() {
+    check_no_errors(
+        r#"
+FUNCTION Sum : INT
+    VAR_IN_OUT values : ARRAY[*] OF INT; END_VAR
+    Sum := values[-1] + values[100];
+END_FUNCTION
+
+PROGRAM Test
+    VAR arr : ARRAY[0..3] OF INT; i : INT; scaled : ARRAY[0..2 * 3] OF INT; END_VAR
+    arr[i] := 1;
+    arr[i + 10] := 2;
+    scaled[6] := 3;
+END_PROGRAM
+"#,
+    );
+}
+
+#[test]
+fn test_array_with_non_constant_bounds_is_not_open() {
+    let errors = check_errors(
+        r#"
+PROGRAM Test
+    VAR
+        n : INT;
+        sized : ARRAY[1..n] OF INT;
+        fixed : ARRAY[1..10] OF INT;
+    END_VAR
+    sized[99] := 1;
+    fixed := sized;
+    sized := fixed;
+END_PROGRAM
+"#,
+    );
+    assert_eq!(errors, vec![DiagnosticCode::IncompatibleAssignment; 2]);
+}
+
 #[test]
 // IEC 61131-3 Ed.3 Tables 11, 15-16 (array bounds and indexing)
 fn test_array_index_subrange_out_of_bounds() {
@@ -702,3 +885,218 @@ END_PROGRAM
 "#,
     );
 }
+
+#[test]
+fn test_cross_enum_assignment_error() {
+    let source = r#"
+TYPE
+    ColorA : (Red, Green, Blue);
+    ColorB : (Cyan, Magenta, Yellow);
+END_TYPE
+
+PROGRAM Test
+    VAR b : ColorB; END_VAR
+    b := ColorA#Red;
+END_PROGRAM
+"#;
+    check_has_error(source, DiagnosticCode::IncompatibleAssignment);
+
+    let mut db = Database::new();
+    let file = FileId(0);
+    db.set_source_text(file, source.to_string());
+    let diagnostics = db.diagnostics(file);
+    let diagnostic = diagnostics
+        .iter()
+        .find(|d| d.code == DiagnosticCode::IncompatibleAssignment)
+        .expect("cross-enum diagnostic");
+    assert!(
+        diagnostic.message.contains("different enumeration types"),
+        "{}",
+        diagnostic.message
+    );
+}
+
+#[test]
+fn test_same_enum_assignment_ok() {
+    check_no_errors(
+        r#"
+TYPE
+    ColorA : (Red, Green, Blue);
+    ColorB : (Cyan, Magenta, Yellow);
+END_TYPE
+
+PROGRAM Test
+    VAR b : ColorB; END_VAR
+    b := ColorB#Magenta;
+END_PROGRAM
+"#,
+    );
+}
+
+#[test]
+fn test_integer_to_enum_assignment_error() {
+    check_has_error(
+        r#"
+TYPE
+    ColorB : (Cyan, Magenta, Yellow);
+END_TYPE
+
+PROGRAM Test
+    VAR
+        b : ColorB;
+        i : INT;
+    END_VAR
+    b := 1;
+    b := i;
+END_PROGRAM
+"#,
+        DiagnosticCode::IncompatibleAssignment,
+    );
+}
+
+#[test]
+fn test_integer_literal_overflow_on_assignment() {
+    let errors = check_errors(
+        r#"
+PROGRAM Test
+    VAR
+        s : SINT;
+        i : INT;
+        u : UINT;
+        b : BYTE;
+    END_VAR
+    s := 300;
+    i := 70000;
+    i := INT#16#FFFF;
+    u := -1;
+    b := BYTE#2#1_0000_0000;
+END_PROGRAM
+"#,
+    );
+    assert_eq!(errors, vec![DiagnosticCode::OutOfRange; 5]);
+}
+
+#[test]
+fn test_integer_literal_at_type_bounds_ok() {
+    check_no_errors(
+        r#"
+PROGRAM Test
+    VAR
+        s : SINT;
+        i : INT;
+        ul : ULINT;
+        w : WORD;
+    END_VAR
+    s := -128;
+    s := SINT#-128;
+    i := -(32768);
+    ul := 16#FFFF_FFFF_FFFF_FFFF;
+    w := WORD#16#FFFF;
+    w := WORD#8#177777;
+END_PROGRAM
+"#,
+    );
+}
+
+#[test]
+fn test_integer_literal_overflow_on_input_argument() {
+    let errors = check_errors(
+        r#"
+FUNCTION Scale : USINT
+    VAR_INPUT
+        factor : USINT;
+    END_VAR
+    Scale := factor;
+END_FUNCTION
+
+PROGRAM Test
+    VAR
+        r : USINT;
+    END_VAR
+    r := Scale(factor := 256);
+    r := Scale(-1);
+    r := Scale(255);
+END_PROGRAM
+"#,
+    );
+    assert_eq!(errors, vec![DiagnosticCode::OutOfRange; 2]);
+}
+
+#[test]
+fn test_external_write_to_fb_output_error() {
+    let source = r#"
+FUNCTION_BLOCK FB_Out
+    VAR_OUTPUT
+        Done : BOOL;
+        Counts : ARRAY[0..1] OF INT;
+    END_VAR
+    Done := TRUE;
+    Counts[0] := 1;
+    THIS^.Done := FALSE;
+END_FUNCTION_BLOCK
+
+PROGRAM Main
+    VAR
+        inst : FB_Out;
+    END_VAR
+    inst.Done := TRUE;
+    inst.Counts[1] := 2;
+END_PROGRAM
+"#;
+    let errors = check_errors(source);
+    assert_eq!(
+        errors,
+        vec![DiagnosticCode::InvalidAssignmentTarget; 2],
+        "{errors:?}"
+    );
+}
+
+#[test]
+fn test_external_read_of_fb_output_ok() {
+    check_no_errors(
+        r#"
+FUNCTION_BLOCK FB_Out
+    VAR_OUTPUT
+        Done : BOOL;
+        Counts : ARRAY[0..1] OF INT;
+    END_VAR
+    Done := TRUE;
+    Counts[0] := 1;
+    THIS^.Done := FALSE;
+END_FUNCTION_BLOCK
+
+PROGRAM Main
+    VAR
+        inst : FB_Out;
+        flag : BOOL;
+        count : INT;
+    END_VAR
+    inst();
+    flag := inst.Done;
+    count := inst.Counts[0];
+END_PROGRAM
+"#,
+    );
+}
+
+#[test]
+fn test_derived_fb_writes_inherited_output_ok() {
+    check_no_errors(
+        r#"
+FUNCTION_BLOCK FB_Out
+    VAR_OUTPUT
+        Done : BOOL;
+        Counts : ARRAY[0..1] OF INT;
+    END_VAR
+    Done := TRUE;
+    Counts[0] := 1;
+    THIS^.Done := FALSE;
+END_FUNCTION_BLOCK
+
+FUNCTION_BLOCK FB_Derived EXTENDS FB_Out
+    Done := TRUE;
+    THIS^.Done := FALSE;
+END_FUNCTION_BLOCK
+"#,
+    );
+}
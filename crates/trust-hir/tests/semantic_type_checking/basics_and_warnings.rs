@@ -47,7 +47,7 @@ PROGRAM Test
     END_IF;
 END_PROGRAM
 "#,
-        DiagnosticCode::TypeMismatch,
+        DiagnosticCode::ImplicitBoolConversion,
     );
 }
 
@@ -65,6 +65,82 @@ END_PROGRAM
     );
 }
 
+#[test]
+fn test_integer_conditions_require_explicit_comparison() {
+    let errors = check_errors(
+        r#"
+PROGRAM Test
+    VAR count : INT; level : REAL; END_VAR
+    WHILE count DO
+        count := count - 1;
+    END_WHILE;
+    REPEAT
+        count := count + 1;
+    UNTIL count
+    END_REPEAT;
+    IF level THEN
+        count := 0;
+    END_IF;
+END_PROGRAM
+"#,
+    );
+    assert_eq!(
+        errors
+            .iter()
+            .filter(|code| **code == DiagnosticCode::ImplicitBoolConversion)
+            .count(),
+        2,
+        "{errors:?}"
+    );
+    assert!(errors.contains(&DiagnosticCode::TypeMismatch), "{errors:?}");
+
+    check_no_errors(
+        r#"
+PROGRAM Test
+    VAR count : INT; END_VAR
+    IF count <> 0 THEN
+        count := 0;
+    ELSIF count > 5 THEN
+        count := 5;
+    END_IF;
+END_PROGRAM
+"#,
+    );
+}
+
+#[test]
+fn test_bool_integer_assignment_requires_conversion() {
+    let mut db = Database::new();
+    let file = FileId(0);
+    db.set_source_text(
+        file,
+        r#"
+PROGRAM Test
+    VAR flag : BOOL; count : INT; END_VAR
+    count := flag;
+    flag := count;
+    count := BOOL_TO_INT(flag);
+    flag := count <> 0;
+END_PROGRAM
+"#
+        .to_string(),
+    );
+    let messages: Vec<_> = db
+        .diagnostics(file)
+        .iter()
+        .filter(|d| d.code == DiagnosticCode::ImplicitBoolConversion)
+        .map(|d| d.message.clone())
+        .collect();
+    assert_eq!(
+        messages,
+        vec![
+            "implicit conversion from 'BOOL' to 'INT'; use BOOL_TO_INT".to_string(),
+            "implicit conversion from 'INT' to 'BOOL'; compare with <> 0 or use INT_TO_BOOL"
+                .to_string(),
+        ]
+    );
+}
+
 #[test]
 fn test_contextual_int_literal_assignment() {
     check_no_errors(
@@ -206,6 +282,82 @@ END_PROGRAM
     assert!(warnings.contains(&DiagnosticCode::UnusedVariable));
 }
 
+#[test]
+fn test_diagnostics_summary_separates_parse_errors() {
+    let mut db = Database::new();
+    let file = FileId(0);
+    db.set_source_text(
+        file,
+        r#"
+PROGRAM Test
+    VAR unused : INT; x : INT; END_VAR
+    x := 1;
+END_PROGRAM
+"#
+        .to_string(),
+    );
+    let summary = db.diagnostics_summary(file);
+    assert!(!summary.has_parse_errors());
+    assert_eq!(summary.suppressed, 0);
+    assert!(summary.semantic_warnings > 0);
+
+    db.set_source_text(
+        file,
+        r#"
+PROGRAM Test
+    VAR unused : INT; x : INT; END_VAR
+    x := ;
+END_PROGRAM
+"#
+        .to_string(),
+    );
+    let summary = db.diagnostics_summary(file);
+    assert!(summary.parse_errors > 0, "{summary:?}");
+    assert!(summary.suppressed > 0, "{summary:?}");
+    assert!(db
+        .diagnostics(file)
+        .iter()
+        .all(|diag| diag.code != DiagnosticCode::UnusedVariable));
+}
+
+#[test]
+fn test_collect_diagnostics_into_matches_list_and_stops_at_break() {
+    let mut db = Database::new();
+    let file = FileId(0);
+    db.set_source_text(
+        file,
+        r#"
+PROGRAM Test
+    VAR unused : INT; x : INT; END_VAR
+    x := a;
+    x := b;
+    IF x > 0 THEN
+    END_IF;
+END_PROGRAM
+"#
+        .to_string(),
+    );
+
+    let mut streamed = Vec::new();
+    trust_hir::diagnostics::collect_diagnostics_into(&db, file, &mut |diagnostic| {
+        streamed.push(diagnostic);
+        std::ops::ControlFlow::Continue(())
+    });
+    assert_eq!(streamed, *db.diagnostics(file));
+    assert!(streamed.len() > 2, "{streamed:?}");
+
+    let mut calls = 0;
+    trust_hir::diagnostics::collect_diagnostics_into(&db, file, &mut |_| {
+        calls += 1;
+        if calls == 2 {
+            std::ops::ControlFlow::Break(())
+        } else {
+            std::ops::ControlFlow::Continue(())
+        }
+    });
+    assert_eq!(calls, 2);
+}
+
 #[test]
 fn test_var_config_marks_symbol_used_across_files() {
     let mut db = Database::new();
@@ -366,6 +518,39 @@ END_PROGRAM
     assert!(warnings.contains(&DiagnosticCode::UnreachableCode));
 }
 
+#[test]
+fn test_unreachable_branches_of_folded_constant_conditions() {
+    let warnings = check_warnings(
+        r#"
+TYPE Color : (Red, Green, Blue)
+END_TYPE
+
+PROGRAM Test
+VAR CONSTANT
+    Debug : BOOL := TRUE OR FALSE;
+END_VAR
+VAR
+    x : INT;
+END_VAR
+    IF NOT Debug THEN
+        x := 1;
+    END_IF;
+    IF Color#Blue < Color#Red THEN
+        x := 2;
+    END_IF;
+    IF x > 0 THEN
+        x := 3;
+    END_IF;
+END_PROGRAM
+"#,
+    );
+    let unreachable = warnings
+        .iter()
+        .filter(|code| **code == DiagnosticCode::UnreachableCode)
+        .count();
+    assert_eq!(unreachable, 2, "{warnings:?}");
+}
+
 #[test]
 fn test_nondeterministic_time_date_warning() {
     let warnings = check_warnings(
@@ -511,3 +696,323 @@ END_PROGRAM
         "Unexpected unused POU warning: {warnings:?}"
     );
 }
+
+#[test]
+fn test_integer_division_truncation_in_real_context_warns() {
+    let warnings = check_warnings(
+        r#"
+PROGRAM Test
+    VAR
+        aReal : REAL;
+    END_VAR
+    aReal := 5 / 2;
+END_PROGRAM
+"#,
+    );
+    assert!(warnings.contains(&DiagnosticCode::IntegerDivisionTruncation));
+}
+
+#[test]
+fn test_integer_division_truncation_real_operand_no_warning() {
+    let warnings = check_warnings(
+        r#"
+PROGRAM Test
+    VAR
+        aReal : REAL;
+    END_VAR
+    aReal := 5.0 / 2;
+END_PROGRAM
+"#,
+    );
+    assert!(!warnings.contains(&DiagnosticCode::IntegerDivisionTruncation));
+}
+
+#[test]
+fn test_integer_division_truncation_integer_target_no_warning() {
+    let warnings = check_warnings(
+        r#"
+PROGRAM Test
+    VAR
+        anInt : INT;
+    END_VAR
+    anInt := 5 / 2;
+END_PROGRAM
+"#,
+    );
+    assert!(!warnings.contains(&DiagnosticCode::IntegerDivisionTruncation));
+}
+
+#[test]
+fn test_empty_if_body_warns() {
+    let warnings = check_warnings(
+        r#"
+PROGRAM Test
+    VAR
+        x : BOOL;
+    END_VAR
+    IF x THEN
+        ;
+    END_IF;
+END_PROGRAM
+"#,
+    );
+    assert!(warnings.contains(&DiagnosticCode::EmptyBlock));
+}
+
+#[test]
+fn test_empty_body_with_comment_no_warning() {
+    let warnings = check_warnings(
+        r#"
+PROGRAM Test
+    VAR
+        x : BOOL;
+        i : INT;
+    END_VAR
+    IF x THEN
+        // intentionally empty
+    ELSE
+        (* nothing to do yet *)
+    END_IF;
+    CASE i OF
+        1: // handled by hardware interlock
+        2: x := TRUE;
+    END_CASE;
+END_PROGRAM
+"#,
+    );
+    assert!(
+        !warnings.contains(&DiagnosticCode::EmptyBlock),
+        "Unexpected empty block warning: {warnings:?}"
+    );
+}
+
+#[test]
+fn test_non_empty_bodies_no_empty_block_warning() {
+    let warnings = check_warnings(
+        r#"
+PROGRAM Test
+    VAR
+        x : BOOL;
+        i : INT;
+    END_VAR
+    IF x THEN
+        i := 1;
+    ELSIF i > 2 THEN
+        i := 2;
+    END_IF;
+    FOR i := 1 TO 3 DO
+        x := NOT x;
+    END_FOR;
+    WHILE i < 10 DO
+        i := i + 1;
+    END_WHILE;
+    CASE i OF
+        1: x := TRUE;
+    ELSE
+        x := FALSE;
+    END_CASE;
+END_PROGRAM
+"#,
+    );
+    assert!(
+        !warnings.contains(&DiagnosticCode::EmptyBlock),
+        "Unexpected empty block warning: {warnings:?}"
+    );
+}
+
+#[test]
+fn test_real_equality_comparison_warns() {
+    let warnings = check_warnings(
+        r#"
+PROGRAM Test
+    VAR
+        aReal : REAL;
+        bReal : REAL;
+        same : BOOL;
+    END_VAR
+    same := aReal = bReal;
+END_PROGRAM
+"#,
+    );
+    assert!(warnings.contains(&DiagnosticCode::RealEqualityComparison));
+}
+
+#[test]
+fn test_real_ordering_comparison_no_warning() {
+    let warnings = check_warnings(
+        r#"
+PROGRAM Test
+    VAR
+        aReal : REAL;
+        bReal : REAL;
+        greater : BOOL;
+    END_VAR
+    greater := aReal > bReal;
+END_PROGRAM
+"#,
+    );
+    assert!(!warnings.contains(&DiagnosticCode::RealEqualityComparison));
+}
+
+#[test]
+fn test_real_comparison_with_epsilon_helper_no_warning() {
+    let warnings = check_warnings(
+        r#"
+FUNCTION NearlyEqual : BOOL
+    VAR_INPUT
+        a : REAL;
+        b : REAL;
+        eps : REAL;
+    END_VAR
+    NearlyEqual := ABS(a - b) < eps;
+END_FUNCTION
+
+PROGRAM Test
+    VAR
+        aReal : REAL;
+        bReal : REAL;
+        same : BOOL;
+    END_VAR
+    same := NearlyEqual(aReal, bReal, 0.001);
+END_PROGRAM
+"#,
+    );
+    assert!(!warnings.contains(&DiagnosticCode::RealEqualityComparison));
+}
+
+#[test]
+fn test_sfc_step_referencing_undefined_transition_warns() {
+    let warnings = check_warnings(
+        r#"
+PROGRAM Test
+    VAR
+        level : INT;
+    END_VAR
+    {INITIAL_STEP Idle NEXT StartFill}
+    {STEP Fill NEXT Drain}
+    {TRANSITION StartFill TO Fill}
+    level := level + 1;
+END_PROGRAM
+"#,
+    );
+    assert!(warnings.contains(&DiagnosticCode::SfcPragmaInconsistency));
+}
+
+#[test]
+fn test_consistent_sfc_pragmas_no_warning() {
+    let warnings = check_warnings(
+        r#"
+PROGRAM Test
+    VAR
+        level : INT;
+    END_VAR
+    {INITIAL_STEP Idle NEXT StartFill}
+    {STEP Fill NEXT FillDone}
+    {step Drain next DrainDone}
+    {TRANSITION StartFill TO Fill}
+    {TRANSITION FillDone TO Drain}
+    {transition DrainDone to Idle}
+    {attribute 'hide'}
+    level := level + 1;
+END_PROGRAM
+"#,
+    );
+    assert!(
+        !warnings.contains(&DiagnosticCode::SfcPragmaInconsistency),
+        "unexpected SFC warnings: {warnings:?}"
+    );
+}
+
+#[test]
+fn test_sfc_duplicate_and_unreachable_steps_warn() {
+    let warnings = check_warnings(
+        r#"
+PROGRAM Test
+    VAR
+        level : INT;
+    END_VAR
+    {INITIAL_STEP Idle}
+    {STEP Fill}
+    {STEP Fill}
+    level := level + 1;
+END_PROGRAM
+"#,
+    );
+    assert_eq!(
+        warnings
+            .iter()
+            .filter(|code| **code == DiagnosticCode::SfcPragmaInconsistency)
+            .count(),
+        2
+    );
+}
+
+fn overlap_messages(source: &str) -> Vec<String> {
+    let mut db = Database::new();
+    let file = FileId(0);
+    db.set_source_text(file, source.to_string());
+    db.diagnostics(file)
+        .iter()
+        .filter(|d| d.code == DiagnosticCode::OverlappingDirectAddress)
+        .map(|d| d.message.clone())
+        .collect()
+}
+
+#[test]
+fn test_direct_address_aliases_warn() {
+    let messages = overlap_messages(
+        r#"
+PROGRAM Test
+    VAR
+        start AT %IX0.0 : BOOL;
+        start_copy AT %IX0.0 : BOOL;
+    END_VAR
+END_PROGRAM
+"#,
+    );
+    assert_eq!(messages.len(), 2, "{messages:?}");
+    assert!(messages
+        .iter()
+        .any(|message| message.contains("aliases 'start_copy' (%IX0.0)")));
+}
+
+#[test]
+fn test_direct_address_bit_inside_byte_warns() {
+    let messages = overlap_messages(
+        r#"
+PROGRAM Test
+    VAR
+        status AT %QB2 : BYTE;
+        lamp AT %QX2.3 : BOOL;
+    END_VAR
+END_PROGRAM
+"#,
+    );
+    assert_eq!(messages.len(), 2, "{messages:?}");
+    assert!(messages
+        .iter()
+        .any(|message| message.contains("overlaps 'lamp' (%QX2.3)")));
+}
+
+#[test]
+fn test_disjoint_direct_addresses_no_overlap_warning() {
+    let warnings = check_warnings(
+        r#"
+PROGRAM Test
+    VAR
+        a AT %IX0.0 : BOOL;
+        b AT %IX0.1 : BOOL;
+        c AT %QX0.0 : BOOL;
+        d AT %IB1 : BYTE;
+        e AT %MW2 : WORD;
+        f AT %MB4 : BYTE;
+        g AT %I* : BOOL;
+    END_VAR
+END_PROGRAM
+"#,
+    );
+    assert!(
+        !warnings.contains(&DiagnosticCode::OverlappingDirectAddress),
+        "unexpected overlap warnings: {warnings:?}"
+    );
+}
@@ -14,6 +14,38 @@ END_PROGRAM
     );
 }
 
+#[test]
+fn test_for_loop_control_variable_assignment_in_body() {
+    check_has_error(
+        r#"
+PROGRAM Test
+    VAR i : INT; END_VAR
+    FOR i := 1 TO 10 DO
+        IF i = 5 THEN
+            i := 8;
+        END_IF;
+    END_FOR;
+END_PROGRAM
+"#,
+        DiagnosticCode::InvalidOperation,
+    );
+}
+
+#[test]
+fn test_for_loop_control_variable_read_and_other_writes_ok() {
+    check_no_errors(
+        r#"
+PROGRAM Test
+    VAR i : INT; total : INT; END_VAR
+    FOR i := 1 TO 10 DO
+        total := total + i;
+    END_FOR;
+    i := 0;
+END_PROGRAM
+"#,
+    );
+}
+
 #[test]
 fn test_case_selector_requires_elementary() {
     check_has_error(
@@ -127,6 +159,70 @@ END_PROGRAM
     );
 }
 
+#[test]
+fn test_case_enum_exhaustive_else_is_unreachable() {
+    let infos = check_infos(
+        r#"
+TYPE Mode : (Off, Manual, Auto)
+END_TYPE
+
+PROGRAM Test
+    VAR m : Mode; END_VAR
+    CASE m OF
+        Mode#Off, Mode#Manual: m := Mode#Auto;
+        Mode#Auto: m := Mode#Off;
+    ELSE
+        m := Mode#Off;
+    END_CASE;
+END_PROGRAM
+"#,
+    );
+    assert_eq!(infos, vec![DiagnosticCode::UnreachableElse]);
+}
+
+#[test]
+fn test_case_enum_partial_else_is_reachable() {
+    let infos = check_infos(
+        r#"
+TYPE Mode : (Off, Manual, Auto)
+END_TYPE
+
+PROGRAM Test
+    VAR m : Mode; END_VAR
+    CASE m OF
+        Mode#Off: m := Mode#Auto;
+    ELSE
+        m := Mode#Off;
+    END_CASE;
+END_PROGRAM
+"#,
+    );
+    assert!(
+        infos.is_empty(),
+        "Expected no info diagnostics, got: {infos:?}"
+    );
+}
+
+#[test]
+fn test_case_integer_else_is_reachable() {
+    let infos = check_infos(
+        r#"
+PROGRAM Test
+    VAR x : INT; END_VAR
+    CASE x OF
+        0..100: x := 1;
+    ELSE
+        x := 0;
+    END_CASE;
+END_PROGRAM
+"#,
+    );
+    assert!(
+        infos.is_empty(),
+        "Expected no info diagnostics, got: {infos:?}"
+    );
+}
+
 #[test]
 fn test_named_argument_order() {
     check_has_error(
@@ -240,9 +336,29 @@ END_PROGRAM
 }
 
 #[test]
-fn test_formal_call_allows_missing_arguments() {
+fn test_formal_call_allows_missing_defaulted_arguments() {
     check_no_errors(
         r#"
+FUNCTION Add : DINT
+    VAR_INPUT
+        a : DINT;
+        b : DINT := 1;
+    END_VAR
+    Add := a + b;
+END_FUNCTION
+
+PROGRAM Test
+    VAR a : DINT; res : DINT; END_VAR
+    res := Add(a := a);
+END_PROGRAM
+"#,
+    );
+}
+
+#[test]
+fn test_formal_call_requires_inputs_without_default() {
+    check_has_error(
+        r#"
 FUNCTION Add : DINT
     VAR_INPUT
         a : DINT;
@@ -255,6 +371,50 @@ PROGRAM Test
     VAR a : DINT; res : DINT; END_VAR
     res := Add(a := a);
 END_PROGRAM
+"#,
+        DiagnosticCode::InvalidArgumentType,
+    );
+}
+
+#[test]
+fn test_formal_call_allows_missing_array_input_with_initializer() {
+    check_no_errors(
+        r#"
+FUNCTION Sum : INT
+    VAR_INPUT
+        arr : ARRAY[1..3] OF INT := [1, 2, 3];
+        offset : INT;
+    END_VAR
+    Sum := arr[1] + arr[2] + arr[3] + offset;
+END_FUNCTION
+
+PROGRAM Test
+    VAR res : INT; END_VAR
+    res := Sum(offset := 1);
+END_PROGRAM
+"#,
+    );
+}
+
+#[test]
+fn test_formal_call_missing_fb_input_is_allowed() {
+    check_no_errors(
+        r#"
+FUNCTION_BLOCK Scale
+    VAR_INPUT
+        value : DINT;
+        factor : DINT;
+    END_VAR
+    VAR_OUTPUT
+        result : DINT;
+    END_VAR
+    result := value * factor;
+END_FUNCTION_BLOCK
+
+PROGRAM Test
+    VAR fb : Scale; END_VAR
+    fb(value := 2);
+END_PROGRAM
 "#,
     );
 }
@@ -627,3 +787,201 @@ END_PROGRAM
 "#,
     );
 }
+
+#[test]
+fn test_try_catch_checks_protected_and_handler_bodies() {
+    check_no_errors(
+        r#"
+PROGRAM Test
+    VAR x : INT; y : INT; err : DINT; END_VAR
+    __TRY
+        x := 10 / y;
+    __CATCH(err)
+        x := 0;
+    __FINALLY
+        y := 1;
+    __ENDTRY
+END_PROGRAM
+"#,
+    );
+    check_has_error(
+        r#"
+PROGRAM Test
+    VAR x : INT; END_VAR
+    __TRY
+        x := 'text';
+    __ENDTRY
+END_PROGRAM
+"#,
+        DiagnosticCode::IncompatibleAssignment,
+    );
+}
+
+#[test]
+fn test_try_catch_target_requires_integer() {
+    check_has_error(
+        r#"
+PROGRAM Test
+    VAR x : INT; err : STRING; END_VAR
+    __TRY
+        x := 1;
+    __CATCH(err)
+        x := 0;
+    __ENDTRY
+END_PROGRAM
+"#,
+        DiagnosticCode::TypeMismatch,
+    );
+}
+
+#[test]
+fn test_try_catch_target_must_hold_exception_codes() {
+    check_has_error(
+        r#"
+PROGRAM Test
+    VAR x : INT; err : SINT; END_VAR
+    __TRY
+        x := 1;
+    __CATCH(err)
+        x := 0;
+    __ENDTRY
+END_PROGRAM
+"#,
+        DiagnosticCode::TypeMismatch,
+    );
+}
+
+#[test]
+fn test_generic_any_num_parameter_accepts_int_and_real() {
+    check_no_errors(
+        r#"
+FUNCTION Twice : ANY_NUM
+    VAR_INPUT
+        x : ANY_NUM;
+    END_VAR
+    IF x > 0 THEN
+        Twice := x + x;
+    ELSE
+        Twice := x;
+    END_IF
+END_FUNCTION
+
+PROGRAM Test
+    VAR i : INT; r : REAL; END_VAR
+    i := Twice(i);
+    r := Twice(r);
+    r := Twice(x := r);
+END_PROGRAM
+"#,
+    );
+}
+
+#[test]
+fn test_generic_any_num_parameter_rejects_string() {
+    check_has_error(
+        r#"
+FUNCTION Twice : ANY_NUM
+    VAR_INPUT
+        x : ANY_NUM;
+    END_VAR
+    IF x > 0 THEN
+        Twice := x + x;
+    ELSE
+        Twice := x;
+    END_IF
+END_FUNCTION
+
+PROGRAM Test
+    VAR s : STRING; r : REAL; END_VAR
+    r := Twice(s);
+END_PROGRAM
+"#,
+        DiagnosticCode::InvalidArgumentType,
+    );
+}
+
+#[test]
+fn test_generic_any_num_return_type_follows_argument() {
+    check_has_error(
+        r#"
+FUNCTION Twice : ANY_NUM
+    VAR_INPUT
+        x : ANY_NUM;
+    END_VAR
+    IF x > 0 THEN
+        Twice := x + x;
+    ELSE
+        Twice := x;
+    END_IF
+END_FUNCTION
+
+PROGRAM Test
+    VAR i : INT; r : REAL; END_VAR
+    i := Twice(r);
+END_PROGRAM
+"#,
+        DiagnosticCode::IncompatibleAssignment,
+    );
+}
+
+#[test]
+fn test_fb_instance_to_var_input_warns() {
+    let warnings = check_warnings(
+        r#"
+FUNCTION_BLOCK Motor
+    VAR_INPUT speed : INT; END_VAR
+END_FUNCTION_BLOCK
+
+FUNCTION ByValue : INT
+    VAR_INPUT m : Motor; END_VAR
+    ByValue := m.speed;
+END_FUNCTION
+
+PROGRAM Test
+    VAR motor : Motor; r : INT; END_VAR
+    r := ByValue(m := motor);
+END_PROGRAM
+"#,
+    );
+    assert!(warnings.contains(&DiagnosticCode::FbPassedByValue));
+}
+
+#[test]
+fn test_fb_instance_to_var_in_out_does_not_warn() {
+    let warnings = check_warnings(
+        r#"
+FUNCTION_BLOCK Motor
+    VAR_INPUT speed : INT; END_VAR
+END_FUNCTION_BLOCK
+
+FUNCTION ByRef : INT
+    VAR_IN_OUT m : Motor; END_VAR
+    ByRef := m.speed;
+END_FUNCTION
+
+PROGRAM Test
+    VAR motor : Motor; r : INT; END_VAR
+    r := ByRef(m := motor);
+END_PROGRAM
+"#,
+    );
+    assert!(!warnings.contains(&DiagnosticCode::FbPassedByValue));
+}
+
+#[test]
+fn test_scalar_to_var_input_does_not_warn_fb_by_value() {
+    let warnings = check_warnings(
+        r#"
+FUNCTION Scalar : INT
+    VAR_INPUT v : INT; END_VAR
+    Scalar := v;
+END_FUNCTION
+
+PROGRAM Test
+    VAR speed : INT; r : INT; END_VAR
+    r := Scalar(v := speed);
+END_PROGRAM
+"#,
+    );
+    assert!(!warnings.contains(&DiagnosticCode::FbPassedByValue));
+}
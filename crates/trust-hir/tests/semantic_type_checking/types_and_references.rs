@@ -130,6 +130,197 @@ END_PROGRAM
     );
 }
 
+#[test]
+fn test_deref_requires_pointer_operand() {
+    check_has_error(
+        r#"
+PROGRAM Test
+    VAR x : DINT; y : DINT; END_VAR
+    y := x^;
+END_PROGRAM
+"#,
+        DiagnosticCode::InvalidOperation,
+    );
+}
+
+#[test]
+fn test_deref_pointer_resolves_struct_fields() {
+    let point = r#"
+TYPE Point : STRUCT
+    x : INT;
+    y : INT;
+END_STRUCT
+END_TYPE
+"#;
+    check_no_errors(&format!(
+        r#"{point}
+PROGRAM Test
+    VAR pt : Point; p : POINTER TO Point; v : INT; END_VAR
+    p := ADR(pt);
+    v := p^.x;
+    p^.y := v;
+END_PROGRAM
+"#
+    ));
+    check_has_error(
+        &format!(
+            r#"{point}
+PROGRAM Test
+    VAR p : POINTER TO Point; v : INT; END_VAR
+    v := p^.z;
+END_PROGRAM
+"#
+        ),
+        DiagnosticCode::CannotResolve,
+    );
+}
+
+#[test]
+fn test_adr_assigned_to_incompatible_pointer() {
+    check_has_error(
+        r#"
+PROGRAM Test
+    VAR x : INT; p : POINTER TO DINT; END_VAR
+    p := ADR(x);
+END_PROGRAM
+"#,
+        DiagnosticCode::IncompatibleAssignment,
+    );
+    check_no_errors(
+        r#"
+PROGRAM Test
+    VAR x : DINT; p : POINTER TO DINT; END_VAR
+    p := ADR(x);
+END_PROGRAM
+"#,
+    );
+}
+
+#[test]
+fn test_null_pointer_literals_assign_to_pointers() {
+    check_no_errors(
+        r#"
+PROGRAM Test
+    VAR p : POINTER TO INT; END_VAR
+    p := 0;
+    p := NULL;
+END_PROGRAM
+"#,
+    );
+    check_has_error(
+        r#"
+PROGRAM Test
+    VAR p : POINTER TO INT; END_VAR
+    p := 1;
+END_PROGRAM
+"#,
+        DiagnosticCode::IncompatibleAssignment,
+    );
+}
+
+const POINTER_HIERARCHY: &str = r#"
+FUNCTION_BLOCK Base
+END_FUNCTION_BLOCK
+
+FUNCTION_BLOCK Derived EXTENDS Base
+END_FUNCTION_BLOCK
+
+FUNCTION_BLOCK Other
+END_FUNCTION_BLOCK
+"#;
+
+#[test]
+fn test_pointer_to_derived_assigns_to_pointer_to_base() {
+    let source = format!(
+        r#"{POINTER_HIERARCHY}
+PROGRAM Test
+    VAR
+        d : Derived;
+        pd : POINTER TO Derived;
+        pb : POINTER TO Base;
+        rd : REF_TO Derived;
+        rb : REF_TO Base;
+    END_VAR
+    pd := ADR(d);
+    pb := pd;
+    rd := REF(d);
+    rb := rd;
+    pb := rd;
+END_PROGRAM
+"#
+    );
+    check_no_errors(&source);
+    let warnings = check_warnings(&source);
+    assert!(
+        !warnings.contains(&DiagnosticCode::ImplicitConversion),
+        "{warnings:?}"
+    );
+}
+
+#[test]
+fn test_unrelated_pointer_types_are_incompatible() {
+    for assignment in ["pb := po;", "pd := pb;", "rb := ro;", "pi := pr;"] {
+        let source = format!(
+            r#"{POINTER_HIERARCHY}
+PROGRAM Test
+    VAR
+        pb : POINTER TO Base;
+        pd : POINTER TO Derived;
+        po : POINTER TO Other;
+        rb : REF_TO Base;
+        ro : REF_TO Other;
+        pi : POINTER TO INT;
+        pr : POINTER TO REAL;
+    END_VAR
+    {assignment}
+END_PROGRAM
+"#
+        );
+        let errors = check_errors(&source);
+        assert!(
+            errors.contains(&DiagnosticCode::IncompatibleAssignment),
+            "{assignment}: {errors:?}"
+        );
+    }
+}
+
+#[test]
+fn test_null_assigns_to_any_pointer_or_reference() {
+    check_no_errors(&format!(
+        r#"{POINTER_HIERARCHY}
+PROGRAM Test
+    VAR
+        pb : POINTER TO Base;
+        pa : POINTER TO ARRAY[1..3] OF INT;
+        rb : REF_TO Base;
+        rs : REF_TO STRING;
+    END_VAR
+    pb := NULL;
+    pa := NULL;
+    rb := NULL;
+    rs := NULL;
+END_PROGRAM
+"#
+    ));
+}
+
+#[test]
+fn test_pointer_to_reference_assignment_warns() {
+    let source = r#"
+PROGRAM Test
+    VAR
+        x : INT;
+        p : POINTER TO INT;
+        r : REF_TO INT;
+    END_VAR
+    p := ADR(x);
+    r := p;
+END_PROGRAM
+"#;
+    check_no_errors(source);
+    assert!(check_warnings(source).contains(&DiagnosticCode::ImplicitConversion));
+}
+
 #[test]
 // IEC 61131-3 Ed.3 Table 12 (reference operators)
 fn test_ref_returns_reference() {
@@ -270,3 +461,100 @@ END_PROGRAM
 "#,
     );
 }
+
+#[test]
+fn test_division_by_constant_zero() {
+    check_has_error(
+        r#"
+PROGRAM Test
+    VAR x : INT; END_VAR
+    x := 5 / 0;
+END_PROGRAM
+"#,
+        DiagnosticCode::InvalidOperation,
+    );
+}
+
+#[test]
+fn test_modulo_by_constant_zero() {
+    check_has_error(
+        r#"
+PROGRAM Test
+    VAR
+        x : INT;
+    END_VAR
+    VAR CONSTANT
+        NONE : INT := 0;
+    END_VAR
+    x := 5 MOD NONE;
+END_PROGRAM
+"#,
+        DiagnosticCode::InvalidOperation,
+    );
+}
+
+#[test]
+fn test_division_by_variable_allowed() {
+    check_no_errors(
+        r#"
+PROGRAM Test
+    VAR x : INT; zero : INT := 0; r : REAL; END_VAR
+    x := 5 / zero;
+    x := 5 MOD zero;
+    r := r / 2.0;
+END_PROGRAM
+"#,
+    );
+}
+
+#[test]
+fn test_array_initializer_element_type_mismatch() {
+    let source = r#"
+PROGRAM Test
+    VAR
+        values : ARRAY[1..3] OF INT := [1, 2.5, 3];
+    END_VAR
+END_PROGRAM
+"#;
+    let mut db = Database::new();
+    let file = FileId(0);
+    db.set_source_text(file, source.to_string());
+    let diagnostics = db.diagnostics(file);
+    let mismatches: Vec<_> = diagnostics
+        .iter()
+        .filter(|d| d.code == DiagnosticCode::IncompatibleAssignment)
+        .collect();
+    assert_eq!(mismatches.len(), 1, "{mismatches:?}");
+    let element = source.find("2.5").expect("REAL element");
+    assert_eq!(usize::from(mismatches[0].range.start()), element);
+}
+
+#[test]
+fn test_array_initializer_enum_elements() {
+    check_no_errors(
+        r#"
+TYPE Color : (Red, Green, Blue);
+END_TYPE
+
+PROGRAM Test
+    VAR
+        palette : ARRAY[0..1] OF Color := [Red, Color#Green];
+    END_VAR
+END_PROGRAM
+"#,
+    );
+}
+
+#[test]
+fn test_array_initializer_string_in_numeric_array() {
+    check_has_error(
+        r#"
+PROGRAM Test
+    VAR
+        values : ARRAY[0..1] OF INT := [1, 'x'];
+    END_VAR
+END_PROGRAM
+"#,
+        DiagnosticCode::IncompatibleAssignment,
+    );
+}
@@ -70,6 +70,47 @@ END_TYPE
     assert!(type_id.is_some(), "Color type should be registered");
 }
 
+#[test]
+fn test_enum_qualified_literal_resolves_to_enum_type() {
+    let source = r#"
+TYPE Color : (Red, Green, Blue)
+END_TYPE
+
+PROGRAM Test
+    VAR c : Color; END_VAR
+    c := Color#Red;
+END_PROGRAM
+"#;
+    check_no_errors(source);
+
+    let mut db = Database::new();
+    let file = FileId(0);
+    db.set_source_text(file, source.to_string());
+    let offset = source.find("Color#Red").expect("literal") as u32 + 7;
+    let expr_id = db.expr_id_at_offset(file, offset).expect("expr id");
+    let color = db
+        .file_symbols(file)
+        .lookup_type("Color")
+        .expect("Color type");
+    assert_eq!(db.type_of(file, expr_id), color);
+}
+
+#[test]
+fn test_enum_qualified_literal_unknown_member_error() {
+    check_has_error(
+        r#"
+TYPE Color : (Red, Green, Blue)
+END_TYPE
+
+PROGRAM Test
+    VAR c : Color; END_VAR
+    c := Color#Purple;
+END_PROGRAM
+"#,
+        DiagnosticCode::CannotResolve,
+    );
+}
+
 #[test]
 fn test_class_type_registered() {
     let mut db = Database::new();
@@ -377,3 +418,110 @@ END_CLASS
         DiagnosticCode::InvalidOperation,
     );
 }
+
+#[test]
+fn test_children_by_parent_matches_parent_scan() {
+    let mut db = Database::new();
+    let file = FileId(0);
+    db.set_source_text(
+        file,
+        r#"
+INTERFACE IShape
+METHOD Area : REAL
+END_METHOD
+END_INTERFACE
+
+CLASS Base IMPLEMENTS IShape
+VAR PUBLIC
+    id : INT;
+END_VAR
+METHOD PUBLIC Area : REAL
+Area := 0.0;
+END_METHOD
+END_CLASS
+
+CLASS Square EXTENDS Base
+VAR
+    side : REAL;
+END_VAR
+METHOD PUBLIC OVERRIDE Area : REAL
+VAR_INPUT
+    scale : REAL;
+END_VAR
+Area := side * side * scale;
+END_METHOD
+END_CLASS
+"#
+        .to_string(),
+    );
+    let symbols = db.file_symbols(file);
+    let children = symbols.children_by_parent();
+
+    let mut parents = 0;
+    for owner in symbols.iter() {
+        let naive: Vec<_> = symbols
+            .iter()
+            .filter(|sym| sym.parent == Some(owner.id))
+            .map(|sym| sym.id)
+            .collect();
+        let indexed = children.get(&owner.id).cloned().unwrap_or_default();
+        assert_eq!(indexed, naive, "children of '{}'", owner.name);
+        if !naive.is_empty() {
+            parents += 1;
+        }
+    }
+    let with_parent = symbols.iter().filter(|sym| sym.parent.is_some()).count();
+    assert_eq!(children.values().map(Vec::len).sum::<usize>(), with_parent);
+    assert!(parents >= 4, "expected classes, methods, and interface");
+}
+
+#[test]
+fn test_struct_containing_itself_by_value_is_rejected() {
+    check_has_error(
+        r#"
+TYPE Node : STRUCT
+    value : INT;
+    next : Node;
+END_STRUCT
+END_TYPE
+"#,
+        DiagnosticCode::CyclicDependency,
+    );
+}
+
+#[test]
+fn test_struct_with_pointer_to_itself_is_allowed() {
+    check_no_errors(
+        r#"
+TYPE Node : STRUCT
+    value : INT;
+    next : POINTER TO Node;
+    prev : REF_TO Node;
+END_STRUCT
+END_TYPE
+"#,
+    );
+}
+
+#[test]
+fn test_mutually_recursive_structs_are_rejected() {
+    let source = r#"
+TYPE
+    Outer : STRUCT
+        inner : Inner;
+    END_STRUCT;
+    Inner : STRUCT
+        items : ARRAY[1..2] OF Outer;
+    END_STRUCT;
+END_TYPE
+"#;
+    let errors = check_errors(source);
+    assert_eq!(
+        errors
+            .iter()
+            .filter(|code| **code == DiagnosticCode::CyclicDependency)
+            .count(),
+        2,
+        "{errors:?}"
+    );
+}
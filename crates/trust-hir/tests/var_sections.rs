@@ -38,3 +38,37 @@ END_CONFIGURATION
 "#,
     );
 }
+
+#[test]
+fn global_retention_qualifiers_are_recorded() {
+    let source = r#"
+CONFIGURATION Conf
+VAR_GLOBAL RETAIN
+    kept : INT;
+END_VAR
+VAR_GLOBAL PERSISTENT
+    stored : INT;
+END_VAR
+VAR_GLOBAL
+    plain : INT;
+END_VAR
+END_CONFIGURATION
+"#;
+    check_no_errors(source);
+
+    let mut db = Database::new();
+    let file = FileId(0);
+    db.set_source_text(file, source.to_string());
+    let symbols = db.file_symbols(file);
+    let retention = |name: &str| {
+        symbols
+            .iter()
+            .find(|symbol| symbol.name == name)
+            .unwrap()
+            .modifiers
+            .retention
+    };
+    assert_eq!(retention("kept"), Some(Retention::Retain));
+    assert_eq!(retention("stored"), Some(Retention::Persistent));
+    assert_eq!(retention("plain"), None);
+}
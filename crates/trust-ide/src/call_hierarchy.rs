@@ -141,6 +141,9 @@ pub fn incoming_calls(db: &Database, item: &CallHierarchyItem) -> Vec<CallHierar
 }
 
 /// Resolves incoming calls for the given call hierarchy item, scoped to a file set.
+///
+/// For a method, calls to the methods it overrides and to its overrides in
+/// derived types count too, since any of them may dispatch to it.
 pub fn incoming_calls_in_files(
     db: &Database,
     item: &CallHierarchyItem,
@@ -151,8 +154,12 @@ pub fn incoming_calls_in_files(
         file_id: item.file_id,
         symbol_id: item.symbol_id,
     };
+    let targets = dispatch_family(db, key, allowed_files);
     let mut grouped: FxHashMap<SymbolKey, Vec<TextRange>> = FxHashMap::default();
-    for edge in edges.into_iter().filter(|edge| edge.callee == key) {
+    for edge in edges
+        .into_iter()
+        .filter(|edge| targets.contains(&edge.callee))
+    {
         grouped.entry(edge.caller).or_default().push(edge.range);
     }
 
@@ -230,6 +237,47 @@ fn symbol_key(symbols: &SymbolTable, symbol_id: SymbolId, file_id: FileId) -> Op
     }
 }
 
+/// Returns `key` plus, when it is a method, every same-named method of a base
+/// or derived class/function block along its inheritance chain.
+fn dispatch_family(
+    db: &Database,
+    key: SymbolKey,
+    allowed_files: Option<&FxHashSet<FileId>>,
+) -> FxHashSet<SymbolKey> {
+    let mut family = FxHashSet::default();
+    family.insert(key);
+
+    let symbols = symbols_for_call_hierarchy(db, key.file_id, allowed_files);
+    let Some(method) = symbols.get(key.symbol_id) else {
+        return family;
+    };
+    if !matches!(method.kind, SymbolKind::Method { .. }) {
+        return family;
+    }
+    let Some(owner) = symbols.member_owner(method.id) else {
+        return family;
+    };
+
+    for candidate in symbols.iter() {
+        if !matches!(candidate.kind, SymbolKind::Method { .. })
+            || !candidate.name.eq_ignore_ascii_case(&method.name)
+        {
+            continue;
+        }
+        let Some(candidate_owner) = symbols.member_owner(candidate.id) else {
+            continue;
+        };
+        if symbols.is_same_or_derived(candidate_owner, owner)
+            || symbols.is_same_or_derived(owner, candidate_owner)
+        {
+            if let Some(candidate_key) = symbol_key(&symbols, candidate.id, key.file_id) {
+                family.insert(candidate_key);
+            }
+        }
+    }
+    family
+}
+
 fn symbols_for_call_hierarchy(
     db: &Database,
     file_id: FileId,
@@ -271,14 +319,18 @@ fn collect_call_edges_in_files(
                 continue;
             };
 
-            let target = resolve_target_at_position_with_context(
-                db,
-                file_id,
-                callee_offset,
-                &source,
-                &root,
-                &symbols,
-            );
+            let target = super_call_target(&symbols, &root, &call_expr)
+                .map(crate::util::ResolvedTarget::Symbol)
+                .or_else(|| {
+                    resolve_target_at_position_with_context(
+                        db,
+                        file_id,
+                        callee_offset,
+                        &source,
+                        &root,
+                        &symbols,
+                    )
+                });
 
             let mut callee_key = None;
             if let Some(crate::util::ResolvedTarget::Symbol(symbol_id)) = target {
@@ -358,6 +410,43 @@ fn caller_symbol_key(
     symbol_key(symbols, owner, file_id)
 }
 
+/// Resolves `SUPER.Method()` (or `SUPER^.Method()`) to the method inherited
+/// from the base of the class or function block containing the call.
+fn super_call_target(
+    symbols: &SymbolTable,
+    root: &SyntaxNode,
+    call_expr: &SyntaxNode,
+) -> Option<SymbolId> {
+    let callee = call_expr
+        .children()
+        .find(|child| child.kind() != SyntaxKind::ArgList)?;
+    if callee.kind() != SyntaxKind::FieldExpr {
+        return None;
+    }
+    let mut parts = callee.children();
+    let receiver = parts.next()?;
+    let is_super = match receiver.kind() {
+        SyntaxKind::SuperExpr => true,
+        SyntaxKind::DerefExpr => receiver
+            .children()
+            .any(|child| child.kind() == SyntaxKind::SuperExpr),
+        _ => false,
+    };
+    if !is_super {
+        return None;
+    }
+    let name = parts
+        .next()?
+        .descendants_with_tokens()
+        .filter_map(|element| element.into_token())
+        .find(|token| token.kind() == SyntaxKind::Ident)?;
+
+    let scope_id = scope_at_position(symbols, root, call_expr.text_range().start());
+    let owner = symbols.class_owner_of_scope(scope_id)?;
+    let base_id = symbols.resolve_by_name(symbols.extends_name(owner)?.as_str())?;
+    symbols.resolve_member_symbol_in_hierarchy(base_id, name.text())
+}
+
 fn callee_name_offset(call_expr: &SyntaxNode) -> Option<TextSize> {
     let callee = call_expr
         .children()
@@ -369,9 +458,11 @@ fn callee_name_offset(call_expr: &SyntaxNode) -> Option<TextSize> {
             .filter_map(|element| element.into_token())
             .find(|token| token.kind() == SyntaxKind::Ident)
             .map(|token| token.text_range().start()),
+        // The member of `receiver.Method` is a `Name`, the receiver may be a
+        // `NameRef`; take whichever comes last.
         SyntaxKind::FieldExpr => callee
-            .descendants()
-            .filter(|child| child.kind() == SyntaxKind::NameRef)
+            .children()
+            .filter(|child| matches!(child.kind(), SyntaxKind::NameRef | SyntaxKind::Name))
             .last()
             .and_then(|child| {
                 child
@@ -434,6 +525,95 @@ END_PROGRAM
         assert!(outgoing[0].to.name.eq_ignore_ascii_case("Add"));
     }
 
+    #[test]
+    fn call_hierarchy_incoming_includes_calls_through_base_type() {
+        let source = r#"
+CLASS Base
+METHOD PUBLIC Run : INT
+    Run := 1;
+END_METHOD
+END_CLASS
+
+CLASS Mid EXTENDS Base
+END_CLASS
+
+CLASS Leaf EXTENDS Mid
+METHOD PUBLIC OVERRIDE Run : INT
+    Run := SUPER.Run() + 1;
+END_METHOD
+END_CLASS
+
+PROGRAM Main
+VAR
+    item : Base;
+    result : INT;
+END_VAR
+    result := item.Run();
+END_PROGRAM
+"#;
+        let mut db = Database::new();
+        let file_id = FileId(0);
+        db.set_source_text(file_id, source.to_string());
+
+        let position = TextSize::from(
+            source
+                .find("OVERRIDE Run")
+                .map(|idx| idx + "OVERRIDE ".len())
+                .expect("override") as u32,
+        );
+        let item = prepare_call_hierarchy(&db, file_id, position).expect("prepare");
+        let incoming = incoming_calls(&db, &item);
+        assert!(
+            incoming
+                .iter()
+                .any(|call| call.from.name.eq_ignore_ascii_case("Main")),
+            "call through a Base-typed variable should reach the override"
+        );
+    }
+
+    #[test]
+    fn call_hierarchy_outgoing_resolves_super_call_to_base_method() {
+        let source = r#"
+CLASS Base
+METHOD PUBLIC Run : INT
+    Run := 1;
+END_METHOD
+END_CLASS
+
+CLASS Mid EXTENDS Base
+END_CLASS
+
+CLASS Leaf EXTENDS Mid
+METHOD PUBLIC OVERRIDE Run : INT
+    Run := SUPER.Run() + 1;
+END_METHOD
+END_CLASS
+
+PROGRAM Main
+VAR
+    item : Base;
+    result : INT;
+END_VAR
+    result := item.Run();
+END_PROGRAM
+"#;
+        let mut db = Database::new();
+        let file_id = FileId(0);
+        db.set_source_text(file_id, source.to_string());
+
+        let position = TextSize::from(
+            source
+                .find("OVERRIDE Run")
+                .map(|idx| idx + "OVERRIDE ".len())
+                .expect("override") as u32,
+        );
+        let item = prepare_call_hierarchy(&db, file_id, position).expect("prepare");
+        let outgoing = outgoing_calls(&db, &item);
+        assert_eq!(outgoing.len(), 1);
+        let base_run = source.find("Run : INT").expect("base method");
+        assert_eq!(u32::from(outgoing[0].to.range.start()), base_run as u32);
+    }
+
     #[test]
     fn call_hierarchy_respects_allowed_files() {
         let source_main = r#"
@@ -7,7 +7,10 @@ use smol_str::SmolStr;
 use text_size::{TextRange, TextSize};
 
 use trust_hir::db::SemanticDatabase;
-use trust_hir::symbols::{ParamDirection, ScopeId, SymbolId, SymbolTable, Visibility};
+use trust_hir::project::IndexedSymbolKind;
+use trust_hir::symbols::{ParamDirection, ScopeId, Symbol, SymbolTable};
+use trust_hir::type_check::CaseLabelTracker;
+use trust_hir::types::conversions::{conversion_targets, CONVERSION_TYPES};
 use trust_hir::{Database, SymbolKind, Type, TypeId};
 use trust_syntax::syntax::{SyntaxKind, SyntaxNode, SyntaxToken};
 
@@ -24,4 +27,6 @@ include!("completion/engine.rs");
 include!("completion/keywords.rs");
 include!("completion/symbols.rs");
 include!("completion/typed_literals.rs");
+include!("completion/conversions.rs");
+include!("completion/case_labels.rs");
 include!("completion/tests.rs");
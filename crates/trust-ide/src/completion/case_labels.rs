@@ -0,0 +1,191 @@
+/// A label slot in a CASE statement.
+#[derive(Debug, Clone)]
+struct CaseLabelContext {
+    case_stmt: SyntaxNode,
+    /// Range of the label typed so far, excluded from coverage.
+    typed: TextRange,
+    /// True at the start of a branch, where `ELSE` may follow; false after a
+    /// comma in a label list.
+    new_branch: bool,
+}
+
+/// Detects a CASE label position: right after `OF`, after a comma in a label
+/// list, or on a new line after a branch's last statement, indented like the
+/// labels of that branch.
+fn case_label_context(context: &IdeContext<'_>, position: TextSize) -> Option<CaseLabelContext> {
+    let token = context.root.token_at_offset(position).left_biased()?;
+    let mut typed = TextRange::empty(position);
+    let prev = if token.kind() == SyntaxKind::Ident && token.text_range().end() == position {
+        typed = token.text_range();
+        previous_non_trivia_token(&token)?
+    } else if is_trivia(token.kind()) {
+        previous_non_trivia_token(&token)?
+    } else {
+        token
+    };
+
+    let (case_stmt, new_branch) = match prev.kind() {
+        SyntaxKind::KwOf => (prev.parent()?, true),
+        SyntaxKind::Comma => (prev.parent()?.parent()?, false),
+        _ => {
+            let stmt = prev.parent_ancestors().find(|node| {
+                node.parent().map(|parent| parent.kind()) == Some(SyntaxKind::CaseBranch)
+            })?;
+            if stmt.kind() == SyntaxKind::CaseLabel || last_non_trivia_token(&stmt)? != prev {
+                return None;
+            }
+            let branch = stmt.parent()?;
+            let label_start = first_non_trivia_token(&branch)?.text_range().start();
+            let label_indent = line_indent(&context.source, label_start);
+            let typed_indent = line_indent(&context.source, typed.start())?;
+            if Some(typed_indent) != label_indent {
+                return None;
+            }
+            (branch.parent()?, true)
+        }
+    };
+    if case_stmt.kind() != SyntaxKind::CaseStmt {
+        return None;
+    }
+    Some(CaseLabelContext {
+        case_stmt,
+        typed,
+        new_branch,
+    })
+}
+
+/// Offers the enum members not yet covered by a label of the CASE statement,
+/// plus an `ELSE` branch at the start of a branch when there is none yet.
+///
+/// Returns an empty list unless the selector has an enum type.
+fn case_label_completions(
+    context: &IdeContext<'_>,
+    label: &CaseLabelContext,
+) -> Vec<CompletionItem> {
+    let symbols = &context.symbols;
+    let Some(selector) = label
+        .case_stmt
+        .children()
+        .next()
+        .filter(|node| !matches!(node.kind(), SyntaxKind::CaseBranch | SyntaxKind::ElseBranch))
+    else {
+        return Vec::new();
+    };
+    let Some(selector_type) = base_type_from_expr_node(
+        context.db,
+        context.file_id,
+        symbols,
+        &context.root,
+        &selector,
+    ) else {
+        return Vec::new();
+    };
+    let Some(Type::Enum { values, .. }) =
+        symbols.type_by_id(symbols.resolve_alias_type(selector_type))
+    else {
+        return Vec::new();
+    };
+
+    let mut tracker = CaseLabelTracker::default();
+    for case_label in label
+        .case_stmt
+        .children()
+        .filter(|node| node.kind() == SyntaxKind::CaseBranch)
+        .flat_map(|branch| branch.children())
+        .filter(|node| node.kind() == SyntaxKind::CaseLabel)
+    {
+        if !label.typed.is_empty() && case_label.text_range().contains_range(label.typed) {
+            continue;
+        }
+        let bounds: Vec<i64> = match case_label
+            .children()
+            .find(|node| node.kind() == SyntaxKind::Subrange)
+        {
+            Some(subrange) => subrange
+                .children()
+                .filter_map(|bound| case_label_value(&bound, values))
+                .collect(),
+            None => case_label_value(&case_label, values).into_iter().collect(),
+        };
+        match bounds.as_slice() {
+            [value] => {
+                let _ = tracker.record_value(*value, case_label.text_range());
+            }
+            [start, end] => {
+                let _ = tracker.record_range(*start, *end, case_label.text_range());
+            }
+            _ => {}
+        }
+    }
+
+    let mut items: Vec<_> = values
+        .iter()
+        .filter(|(_, value)| !tracker.covers(*value))
+        .map(|(name, value)| {
+            CompletionItem::new(name.clone(), CompletionKind::EnumValue)
+                .with_insert_text(format!("{name}:$0"))
+                .with_detail(format!("{name} = {value}"))
+                .with_priority(5)
+        })
+        .collect();
+    let has_else = label
+        .case_stmt
+        .children()
+        .any(|node| node.kind() == SyntaxKind::ElseBranch);
+    if label.new_branch && !has_else {
+        items.push(
+            CompletionItem::new("ELSE", CompletionKind::Keyword)
+                .with_insert_text("ELSE\n\t$0")
+                .with_detail("Default branch")
+                .with_priority(10),
+        );
+    }
+    items
+}
+
+/// Evaluates a CASE label to an enum ordinal: a member name, optionally
+/// qualified (`Color#Red`, `Color.Red`), or an integer literal.
+fn case_label_value(node: &SyntaxNode, values: &[(SmolStr, i64)]) -> Option<i64> {
+    let tokens: Vec<_> = node
+        .descendants_with_tokens()
+        .filter_map(|element| element.into_token())
+        .filter(|token| !is_trivia(token.kind()))
+        .collect();
+    if let Some(ident) = tokens
+        .iter()
+        .rev()
+        .find(|token| token.kind() == SyntaxKind::Ident)
+    {
+        return values
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(ident.text()))
+            .map(|(_, value)| *value);
+    }
+    match tokens.as_slice() {
+        [literal] if literal.kind() == SyntaxKind::IntLiteral => {
+            literal.text().replace('_', "").parse().ok()
+        }
+        _ => None,
+    }
+}
+
+fn first_non_trivia_token(node: &SyntaxNode) -> Option<SyntaxToken> {
+    node.descendants_with_tokens()
+        .filter_map(|element| element.into_token())
+        .find(|token| !is_trivia(token.kind()))
+}
+
+fn last_non_trivia_token(node: &SyntaxNode) -> Option<SyntaxToken> {
+    node.descendants_with_tokens()
+        .filter_map(|element| element.into_token())
+        .filter(|token| !is_trivia(token.kind()))
+        .last()
+}
+
+/// Returns the indentation before `offset` if only whitespace precedes it on
+/// its line.
+fn line_indent(source: &str, offset: TextSize) -> Option<usize> {
+    let before = source.get(..usize::from(offset))?;
+    let line = &before[before.rfind('\n').map_or(0, |idx| idx + 1)..];
+    line.trim_start().is_empty().then_some(line.len())
+}
@@ -7,6 +7,10 @@ fn detect_context(root: &SyntaxNode, position: TextSize) -> CompletionContext {
         return CompletionContext::General;
     };
 
+    if let Some(context) = configuration_context(&token, position) {
+        return context;
+    }
+
     // Check for trigger characters by looking at the previous non-trivia token
     if let Some(prev) = previous_non_trivia_token(&token) {
         match prev.kind() {
@@ -93,6 +97,25 @@ fn detect_context(root: &SyntaxNode, position: TextSize) -> CompletionContext {
     CompletionContext::General
 }
 
+/// Detects the task and type slots of `PROGRAM name WITH task : Type`.
+fn configuration_context(token: &SyntaxToken, position: TextSize) -> Option<CompletionContext> {
+    let mut prev = previous_non_trivia_token(token)?;
+    if prev.kind() == SyntaxKind::Ident && prev.text_range().end() == position {
+        prev = previous_non_trivia_token(&prev)?;
+    }
+    if !prev
+        .parent_ancestors()
+        .any(|node| node.kind() == SyntaxKind::ProgramConfig)
+    {
+        return None;
+    }
+    match prev.kind() {
+        SyntaxKind::KwWith => Some(CompletionContext::TaskName),
+        SyntaxKind::Colon => Some(CompletionContext::ProgramType),
+        _ => None,
+    }
+}
+
 fn is_recovered_statement_position(node: &SyntaxNode, position: TextSize) -> bool {
     node.ancestors().any(|ancestor| {
         matches!(
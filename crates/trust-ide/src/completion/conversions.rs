@@ -0,0 +1,102 @@
+/// A conversion function name typed up to `TO_` or `<src>_TO_`.
+#[derive(Debug, Clone)]
+struct ConversionPrefixContext {
+    /// Source type of `<src>_TO_`; `None` for the generic `TO_` form.
+    source: Option<TypeId>,
+    /// Range of the typed prefix, replaced by the completed name.
+    range: TextRange,
+    /// The upper-cased prefix, e.g. `INT_TO_`.
+    prefix: SmolStr,
+}
+
+fn conversion_prefix_context(
+    context: &IdeContext<'_>,
+    position: TextSize,
+) -> Option<ConversionPrefixContext> {
+    let token = context.root.token_at_offset(position).left_biased()?;
+    if token.kind() != SyntaxKind::Ident || token.text_range().end() != position {
+        return None;
+    }
+    let upper = token.text().to_ascii_uppercase();
+    let source = if upper == "TO_" {
+        None
+    } else {
+        let source = TypeId::from_builtin_name(upper.strip_suffix("_TO_")?)?;
+        if !CONVERSION_TYPES.contains(&source) {
+            return None;
+        }
+        Some(source)
+    };
+    Some(ConversionPrefixContext {
+        source,
+        range: token.text_range(),
+        prefix: SmolStr::new(upper),
+    })
+}
+
+/// Offers the conversion targets valid for the typed prefix.
+///
+/// `<src>_TO_` lists the types `src` converts to; `TO_` lists every target.
+/// When the prefix is the right-hand side of `variable := ...`, the variable's
+/// type is ranked first.
+fn conversion_completions(
+    context: &IdeContext<'_>,
+    prefix: &ConversionPrefixContext,
+    scope_id: ScopeId,
+    stdlib_filter: &StdlibFilter,
+) -> Vec<CompletionItem> {
+    let targets = match prefix.source {
+        Some(source) => conversion_targets(source),
+        None => CONVERSION_TYPES.to_vec(),
+    };
+    let expected = assignment_target_type(context, prefix.range.start(), scope_id);
+    let mut items: Vec<_> = targets
+        .into_iter()
+        .filter_map(|target| {
+            let target_name = target.builtin_name()?;
+            let name = format!("{}{}", prefix.prefix, target_name);
+            if !stdlib_filter.allows_function(&name) {
+                return None;
+            }
+            let detail = match prefix.source.and_then(TypeId::builtin_name) {
+                Some(source_name) => format!("{source_name} -> {target_name}"),
+                None => format!("ANY -> {target_name}"),
+            };
+            let priority = if Some(target) == expected { 5 } else { 20 };
+            let mut item = CompletionItem::new(name.clone(), CompletionKind::Function)
+                .with_text_edit(CompletionTextEdit {
+                    range: prefix.range,
+                    new_text: SmolStr::new(format!("{name}($0)")),
+                })
+                .with_detail(detail)
+                .with_priority(priority);
+            if let Some(doc) = stdlib_docs::standard_function_doc(&name) {
+                item.documentation = Some(SmolStr::new(doc));
+            }
+            Some(item)
+        })
+        .collect();
+    items.sort_by_key(|item| item.sort_priority);
+    items
+}
+
+/// Returns the elementary type of `name` in `name := <offset>`, if any.
+fn assignment_target_type(
+    context: &IdeContext<'_>,
+    offset: TextSize,
+    scope_id: ScopeId,
+) -> Option<TypeId> {
+    let token = context.root.token_at_offset(offset).right_biased()?;
+    let assign = previous_non_trivia_token(&token)?;
+    if assign.kind() != SyntaxKind::Assign {
+        return None;
+    }
+    let target = previous_non_trivia_token(&assign)?;
+    if target.kind() != SyntaxKind::Ident {
+        return None;
+    }
+    let symbols = &context.symbols;
+    let symbol = symbols.get(symbols.resolve(target.text(), scope_id)?)?;
+    let type_id = symbols.resolve_alias_type(symbol.type_id);
+    CONVERSION_TYPES.contains(&type_id).then_some(type_id)
+}
@@ -7,6 +7,40 @@ pub fn complete(
     complete_with_filter(db, file_id, position, &StdlibFilter::allow_all())
 }
 
+/// Rebuilds the detail, signature and documentation of a symbol completion.
+///
+/// Large completion lists leave these fields empty; clients fetch them for a
+/// single item through `completionItem/resolve`.
+pub fn resolve_completion_symbol(
+    db: &Database,
+    file_id: trust_hir::db::FileId,
+    key: &CompletionSymbolKey,
+    stdlib_filter: &StdlibFilter,
+) -> Option<CompletionItem> {
+    let context = IdeContext::new(db, file_id);
+    let symbols = &context.symbols;
+    let name = key.qualified_name.rsplit('.').next()?;
+    let symbol = symbols.iter().find(|symbol| {
+        symbol.name.eq_ignore_ascii_case(name) && key.matches(symbols, symbol)
+    })?;
+    let mut item = CompletionItem::new(
+        symbol.name.clone(),
+        completion_kind_for_symbol(&symbol.kind),
+    )
+    .with_symbol(symbols, symbol);
+    if let Some(signature) = crate::signature_help::symbol_signature_label(symbols, symbol) {
+        item = item.with_detail(signature);
+    } else if let Some(detail) = type_detail(symbols, symbol.type_id) {
+        item = item.with_detail(detail);
+    }
+    Some(attach_symbol_docs_simple(
+        item,
+        symbol,
+        symbols,
+        stdlib_filter,
+    ))
+}
+
 /// Computes completions with stdlib filtering.
 pub fn complete_with_filter(
     db: &Database,
@@ -24,6 +58,30 @@ pub fn complete_with_filter(
     let typed_literal_context = typed_literal_completion_context(&context, position);
     let scope_id = context.scope_at_position(position);
 
+    // `EnumType#` narrows completion to that enum's members.
+    if let Some(members) = typed_literal_context
+        .as_ref()
+        .and_then(|literal| enum_literal_completions(symbols, literal))
+    {
+        return members;
+    }
+
+    // `TO_` and `<src>_TO_` narrow completion to the valid conversion targets.
+    if let Some(prefix) = conversion_prefix_context(&context, position) {
+        let items = conversion_completions(&context, &prefix, scope_id, stdlib_filter);
+        if !items.is_empty() {
+            return items;
+        }
+    }
+
+    // A CASE label slot with an enum selector lists the uncovered members.
+    if let Some(label) = case_label_context(&context, position) {
+        let items = case_label_completions(&context, &label);
+        if !items.is_empty() {
+            return items;
+        }
+    }
+
     match detect {
         CompletionContext::TopLevel => {
             items.extend(keyword_snippets());
@@ -51,6 +109,12 @@ pub fn complete_with_filter(
             items.extend(type_keywords());
             items.extend(type_symbols(&filter));
         }
+        CompletionContext::TaskName => {
+            items.extend(task_completions(symbols));
+        }
+        CompletionContext::ProgramType => {
+            items.extend(program_type_completions(symbols));
+        }
         CompletionContext::VarBlock => {
             items.extend(keyword_snippets());
             items.extend(var_block_keywords());
@@ -136,4 +200,3 @@ fn parameter_name_completions(
 
     items
 }
-
@@ -12,28 +12,11 @@ fn symbols_in_scope(
         {
             continue;
         }
-        let kind = match symbol.kind {
-            SymbolKind::Variable { .. } => CompletionKind::Variable,
-            SymbolKind::Constant => CompletionKind::Constant,
-            SymbolKind::Function { .. } => CompletionKind::Function,
-            SymbolKind::FunctionBlock => CompletionKind::FunctionBlock,
-            SymbolKind::Class => CompletionKind::Type,
-            SymbolKind::Method { .. } => CompletionKind::Method,
-            SymbolKind::Property { .. } => CompletionKind::Property,
-            SymbolKind::Interface | SymbolKind::Type => CompletionKind::Type,
-            SymbolKind::EnumValue { .. } => CompletionKind::EnumValue,
-            SymbolKind::Program
-            | SymbolKind::ProgramInstance
-            | SymbolKind::Parameter { .. }
-            | SymbolKind::Namespace
-            | SymbolKind::Configuration
-            | SymbolKind::Resource
-            | SymbolKind::Task => CompletionKind::Variable,
-        };
+        let kind = completion_kind_for_symbol(&symbol.kind);
 
-        let mut item = CompletionItem::new(symbol.name.clone(), kind);
-        if let Some(type_name) = TypeId::builtin_name(symbol.type_id) {
-            item = item.with_detail(type_name);
+        let mut item = CompletionItem::new(symbol.name.clone(), kind).with_symbol(filter.symbols(), symbol);
+        if let Some(detail) = symbol_detail(symbol, TypeId::builtin_name(symbol.type_id)) {
+            item = item.with_detail(detail);
         }
         item = attach_symbol_docs(item, symbol, filter, Some(scope_id), stdlib_filter);
         seen.insert(symbol.name.to_ascii_uppercase());
@@ -49,10 +32,69 @@ fn symbols_in_scope(
     items
 }
 
+fn completion_kind_for_symbol(kind: &SymbolKind) -> CompletionKind {
+    match kind {
+        SymbolKind::Variable { .. } => CompletionKind::Variable,
+        SymbolKind::Constant => CompletionKind::Constant,
+        SymbolKind::Function { .. } => CompletionKind::Function,
+        SymbolKind::FunctionBlock => CompletionKind::FunctionBlock,
+        SymbolKind::Class => CompletionKind::Type,
+        SymbolKind::Method { .. } => CompletionKind::Method,
+        SymbolKind::Property { .. } => CompletionKind::Property,
+        SymbolKind::Interface | SymbolKind::Type => CompletionKind::Type,
+        SymbolKind::EnumValue { .. } => CompletionKind::EnumValue,
+        SymbolKind::Task => CompletionKind::Task,
+        SymbolKind::Resource => CompletionKind::Resource,
+        SymbolKind::Program
+        | SymbolKind::ProgramInstance
+        | SymbolKind::Parameter { .. }
+        | SymbolKind::Namespace
+        | SymbolKind::Configuration => CompletionKind::Variable,
+    }
+}
+
 fn type_symbols(filter: &SymbolFilter<'_>) -> Vec<CompletionItem> {
     filter
         .type_symbols()
-        .map(|symbol| CompletionItem::new(symbol.name.clone(), CompletionKind::Type))
+        .map(|symbol| {
+            CompletionItem::new(symbol.name.clone(), CompletionKind::Type)
+                .with_symbol(filter.symbols(), symbol)
+        })
+        .collect()
+}
+
+fn task_completions(symbols: &SymbolTable) -> Vec<CompletionItem> {
+    let mut tasks: Vec<_> = symbols
+        .iter()
+        .filter(|symbol| matches!(symbol.kind, SymbolKind::Task))
+        .collect();
+    // Offer tasks in declaration order.
+    tasks.sort_by_key(|symbol| symbol.range.start());
+    tasks
+        .into_iter()
+        .map(|symbol| {
+            CompletionItem::new(symbol.name.clone(), CompletionKind::Task)
+                .with_detail("TASK")
+                .with_priority(10)
+        })
+        .collect()
+}
+
+fn program_type_completions(symbols: &SymbolTable) -> Vec<CompletionItem> {
+    symbols
+        .iter()
+        .filter_map(|symbol| {
+            let (kind, detail, priority) = match symbol.kind {
+                SymbolKind::Program => (CompletionKind::Type, "PROGRAM", 10),
+                SymbolKind::FunctionBlock => (CompletionKind::FunctionBlock, "FUNCTION_BLOCK", 20),
+                _ => return None,
+            };
+            Some(
+                CompletionItem::new(symbol.name.clone(), kind)
+                    .with_detail(detail)
+                    .with_priority(priority),
+            )
+        })
         .collect()
 }
 
@@ -174,105 +216,14 @@ fn member_symbols_for_type(
     let Some(owner_id) = filter.owner_for_type(type_id) else {
         return Vec::new();
     };
-    let current_owner = current_owner_for_scope(symbols, scope_id);
-    let current_namespace = namespace_path_for_scope(symbols, scope_id);
-
     filter
         .members_in_hierarchy(owner_id, |symbol| is_member_symbol_kind(&symbol.kind))
         .into_iter()
-        .filter(|symbol| {
-            is_member_visible(symbols, symbol, owner_id, current_owner, &current_namespace)
-        })
+        .filter(|symbol| symbols.is_accessible(symbol.id, scope_id))
         .filter_map(|symbol| completion_item_for_symbol(symbol, symbols, stdlib_filter))
         .collect()
 }
 
-fn current_owner_for_scope(symbols: &SymbolTable, scope_id: ScopeId) -> Option<SymbolId> {
-    let mut current = Some(scope_id);
-    while let Some(scope_id) = current {
-        let Some(scope) = symbols.get_scope(scope_id) else {
-            break;
-        };
-        if let Some(owner_id) = scope.owner {
-            if let Some(symbol) = symbols.get(owner_id) {
-                match symbol.kind {
-                    SymbolKind::Class | SymbolKind::FunctionBlock => return Some(owner_id),
-                    SymbolKind::Method { .. } | SymbolKind::Property { .. } => {
-                        if let Some(parent) = symbol.parent {
-                            return Some(parent);
-                        }
-                        return Some(owner_id);
-                    }
-                    _ => {}
-                }
-            }
-        }
-        current = scope.parent;
-    }
-    None
-}
-
-fn namespace_path_for_scope(symbols: &SymbolTable, scope_id: ScopeId) -> Vec<SmolStr> {
-    let mut current = Some(scope_id);
-    while let Some(scope_id) = current {
-        let Some(scope) = symbols.get_scope(scope_id) else {
-            break;
-        };
-        if let Some(owner_id) = scope.owner {
-            if let Some(symbol) = symbols.get(owner_id) {
-                return namespace_path_for_symbol(symbols, symbol);
-            }
-        }
-        current = scope.parent;
-    }
-    Vec::new()
-}
-
-fn is_member_visible(
-    symbols: &SymbolTable,
-    member: &trust_hir::symbols::Symbol,
-    owner_id: SymbolId,
-    current_owner: Option<SymbolId>,
-    current_namespace: &[SmolStr],
-) -> bool {
-    match member.visibility {
-        Visibility::Public => true,
-        Visibility::Private => current_owner == Some(owner_id),
-        Visibility::Protected => {
-            current_owner.is_some_and(|current| is_same_or_derived(symbols, current, owner_id))
-        }
-        Visibility::Internal => {
-            let owner_namespace = symbols
-                .get(owner_id)
-                .map(|symbol| namespace_path_for_symbol(symbols, symbol))
-                .unwrap_or_default();
-            owner_namespace == current_namespace
-        }
-    }
-}
-
-fn is_same_or_derived(symbols: &SymbolTable, derived_id: SymbolId, base_id: SymbolId) -> bool {
-    if derived_id == base_id {
-        return true;
-    }
-    let mut visited: FxHashSet<SymbolId> = FxHashSet::default();
-    let mut current = symbols
-        .extends_name(derived_id)
-        .and_then(|name| symbols.resolve_by_name(name.as_str()));
-    while let Some(symbol_id) = current {
-        if !visited.insert(symbol_id) {
-            break;
-        }
-        if symbol_id == base_id {
-            return true;
-        }
-        current = symbols
-            .extends_name(symbol_id)
-            .and_then(|name| symbols.resolve_by_name(name.as_str()));
-    }
-    false
-}
-
 fn completion_item_for_symbol(
     symbol: &trust_hir::symbols::Symbol,
     symbols: &SymbolTable,
@@ -292,14 +243,27 @@ fn completion_item_for_symbol(
         SymbolKind::Property { .. } => CompletionKind::Property,
         _ => return None,
     };
-    let mut item = CompletionItem::new(symbol.name.clone(), kind).with_priority(10);
-    if let Some(detail) = type_detail(symbols, symbol.type_id) {
+    let mut item = CompletionItem::new(symbol.name.clone(), kind)
+        .with_priority(10)
+        .with_symbol(symbols, symbol);
+    let type_name = type_detail(symbols, symbol.type_id);
+    if let Some(detail) = symbol_detail(symbol, type_name.as_deref()) {
         item = item.with_detail(detail);
     }
     item = attach_symbol_docs_simple(item, symbol, symbols, stdlib_filter);
     Some(item)
 }
 
+/// Completion detail for a symbol: its type, prefixed with the `AT` address
+/// of directly represented variables (`AT %QX0.1 : BOOL`).
+fn symbol_detail(symbol: &trust_hir::symbols::Symbol, type_name: Option<&str>) -> Option<String> {
+    match (symbol.direct_address.as_deref(), type_name) {
+        (Some(address), Some(type_name)) => Some(format!("AT {address} : {type_name}")),
+        (Some(address), None) => Some(format!("AT {address}")),
+        (None, type_name) => type_name.map(str::to_string),
+    }
+}
+
 fn attach_symbol_docs_simple(
     mut item: CompletionItem,
     symbol: &trust_hir::symbols::Symbol,
@@ -450,26 +414,10 @@ fn using_scope_symbol_completions(
                     continue;
                 }
 
-                let kind = match symbol.kind {
-                    SymbolKind::Variable { .. } => CompletionKind::Variable,
-                    SymbolKind::Constant => CompletionKind::Constant,
-                    SymbolKind::Function { .. } => CompletionKind::Function,
-                    SymbolKind::FunctionBlock => CompletionKind::FunctionBlock,
-                    SymbolKind::Class => CompletionKind::Type,
-                    SymbolKind::Method { .. } => CompletionKind::Method,
-                    SymbolKind::Property { .. } => CompletionKind::Property,
-                    SymbolKind::Interface | SymbolKind::Type => CompletionKind::Type,
-                    SymbolKind::EnumValue { .. } => CompletionKind::EnumValue,
-                    SymbolKind::Program
-                    | SymbolKind::ProgramInstance
-                    | SymbolKind::Parameter { .. }
-                    | SymbolKind::Namespace
-                    | SymbolKind::Configuration
-                    | SymbolKind::Resource
-                    | SymbolKind::Task => CompletionKind::Variable,
-                };
+                let kind = completion_kind_for_symbol(&symbol.kind);
 
-                let mut item = CompletionItem::new(symbol.name.clone(), kind);
+                let mut item =
+                    CompletionItem::new(symbol.name.clone(), kind).with_symbol(symbols, symbol);
                 if let Some(type_name) = TypeId::builtin_name(symbol.type_id) {
                     item = item.with_detail(type_name);
                 }
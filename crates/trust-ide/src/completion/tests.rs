@@ -147,6 +147,98 @@ END_PROGRAM
         assert!(items.iter().any(|item| item.label == "DATE#2024-01-15"));
     }
 
+    #[test]
+    fn test_enum_literal_completion_after_prefix() {
+        let source = r#"
+TYPE Color : (Red, Green, Blue)
+END_TYPE
+
+TYPE Mode : (Off, Auto)
+END_TYPE
+
+PROGRAM Main
+VAR
+    c : Color;
+END_VAR
+    c := Color#|;
+END_PROGRAM
+"#;
+        let cursor = source.find('|').expect("cursor");
+        let mut cleaned = source.to_string();
+        cleaned.remove(cursor);
+
+        let mut db = Database::new();
+        let file_id = FileId(0);
+        db.set_source_text(file_id, cleaned);
+
+        let items = complete(&db, file_id, TextSize::from(cursor as u32));
+        let labels: Vec<_> = items.iter().map(|item| item.label.as_str()).collect();
+        assert_eq!(labels, vec!["Color#Red", "Color#Green", "Color#Blue"]);
+        assert!(items
+            .iter()
+            .all(|item| item.kind == CompletionKind::EnumValue));
+        let edit = items[0].text_edit.as_ref().expect("text edit");
+        assert_eq!(edit.new_text.as_str(), "Red");
+        assert_eq!(edit.range.start(), TextSize::from(cursor as u32));
+    }
+
+    #[test]
+    fn test_case_label_completion_offers_uncovered_enum_members() {
+        let complete_at = |source: &str| {
+            let cursor = source.find('|').expect("cursor");
+            let mut cleaned = source.to_string();
+            cleaned.remove(cursor);
+
+            let mut db = Database::new();
+            let file_id = FileId(0);
+            db.set_source_text(file_id, cleaned);
+            complete(&db, file_id, TextSize::from(cursor as u32))
+        };
+
+        let items = complete_at(
+            r#"
+TYPE Color : (Red, Green, Blue)
+END_TYPE
+
+PROGRAM Main
+VAR
+    c : Color;
+    x : INT;
+END_VAR
+    CASE c OF
+        |
+    END_CASE;
+END_PROGRAM
+"#,
+        );
+        let labels: Vec<_> = items.iter().map(|item| item.label.as_str()).collect();
+        assert_eq!(labels, vec!["Red", "Green", "Blue", "ELSE"]);
+        assert_eq!(items[0].kind, CompletionKind::EnumValue);
+        assert_eq!(items[0].insert_text.as_deref(), Some("Red:$0"));
+        assert_eq!(items[3].kind, CompletionKind::Keyword);
+
+        let items = complete_at(
+            r#"
+TYPE Color : (Red, Green, Blue)
+END_TYPE
+
+PROGRAM Main
+VAR
+    c : Color;
+    x : INT;
+END_VAR
+    CASE c OF
+        Red: x := 1;
+        Color#Green: x := 2;
+        |
+    END_CASE;
+END_PROGRAM
+"#,
+        );
+        let labels: Vec<_> = items.iter().map(|item| item.label.as_str()).collect();
+        assert_eq!(labels, vec!["Blue", "ELSE"]);
+    }
+
     #[test]
     fn test_typed_literal_completion_after_prefix() {
         let source = r#"
@@ -313,4 +405,89 @@ END_PROGRAM
             .map(|doc| doc.contains("USING Lib"))
             .unwrap_or(false));
     }
+
+    #[test]
+    fn test_program_config_completion_offers_tasks_and_program_types() {
+        let source = r#"
+FUNCTION_BLOCK Motor
+END_FUNCTION_BLOCK
+
+PROGRAM Main
+END_PROGRAM
+
+CONFIGURATION Plant
+RESOURCE Cpu ON PLC
+    TASK Fast (INTERVAL := T#10ms, PRIORITY := 1);
+    TASK Slow (INTERVAL := T#100ms, PRIORITY := 2);
+    PROGRAM P1 WITH $task : $type;
+END_RESOURCE
+END_CONFIGURATION
+"#;
+        let complete_at = |marker: &str, filler: &str| {
+            let other = if marker == "$task" { "$type" } else { "$task" };
+            let text = source.replace(other, filler);
+            let cursor = text.find(marker).expect("cursor");
+            let cleaned = text.replace(marker, "");
+            let mut db = Database::new();
+            let file_id = FileId(0);
+            db.set_source_text(file_id, cleaned);
+            complete(&db, file_id, TextSize::from(cursor as u32))
+        };
+
+        let tasks = complete_at("$task", "Main");
+        let labels: Vec<_> = tasks.iter().map(|item| item.label.as_str()).collect();
+        assert_eq!(labels, vec!["Fast", "Slow"]);
+        assert!(tasks.iter().all(|item| item.kind == CompletionKind::Task));
+
+        let types = complete_at("$type", "Fast");
+        let main = types
+            .iter()
+            .find(|item| item.label == "Main")
+            .expect("program type");
+        assert_eq!(main.kind, CompletionKind::Type);
+        assert_eq!(main.detail.as_deref(), Some("PROGRAM"));
+        assert!(types.iter().any(|item| item.label == "Motor"));
+        assert!(types.iter().all(|item| item.kind != CompletionKind::Task));
+        assert_eq!(types[0].label, "Main");
+    }
+
+    #[test]
+    fn test_conversion_prefix_completion_offers_valid_targets() {
+        let source = r#"
+PROGRAM Main
+VAR
+    count : INT;
+    flag : BOOL;
+END_VAR
+    flag := $prefix
+END_PROGRAM
+"#;
+        let complete_prefix = |prefix: &str| {
+            let cursor = source.find("$prefix").expect("cursor") + prefix.len();
+            let cleaned = source.replace("$prefix", prefix);
+            let mut db = Database::new();
+            let file_id = FileId(0);
+            db.set_source_text(file_id, cleaned);
+            complete(&db, file_id, TextSize::from(cursor as u32))
+        };
+
+        let items = complete_prefix("INT_TO_");
+        let labels: Vec<_> = items.iter().map(|item| item.label.as_str()).collect();
+        assert_eq!(labels.first(), Some(&"INT_TO_BOOL"));
+        for expected in ["INT_TO_DINT", "INT_TO_REAL", "INT_TO_WORD"] {
+            assert!(labels.contains(&expected), "missing {expected}");
+        }
+        for unexpected in ["INT_TO_INT", "INT_TO_TIME", "INT_TO_STRING", "INT_TO_CHAR"] {
+            assert!(!labels.contains(&unexpected), "unexpected {unexpected}");
+        }
+        assert!(items
+            .iter()
+            .all(|item| item.kind == CompletionKind::Function));
+        let edit = items[0].text_edit.as_ref().expect("text edit");
+        assert_eq!(edit.new_text, "INT_TO_BOOL($0)");
+
+        let items = complete_prefix("to_");
+        assert_eq!(items[0].label, "TO_BOOL");
+        assert!(items.iter().any(|item| item.label == "TO_LTIME"));
+    }
 }
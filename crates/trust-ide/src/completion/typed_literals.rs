@@ -241,6 +241,34 @@ fn typed_literal_completions_for_context(context: &TypedLiteralContext) -> Vec<C
     items
 }
 
+fn enum_literal_completions(
+    symbols: &SymbolTable,
+    context: &TypedLiteralContext,
+) -> Option<Vec<CompletionItem>> {
+    let type_id = symbols.lookup_type(context.prefix.as_str())?;
+    let Some(Type::Enum { values, .. }) =
+        symbols.type_by_id(symbols.resolve_alias_type(type_id))
+    else {
+        return None;
+    };
+    let items = values
+        .iter()
+        .map(|(name, value)| {
+            CompletionItem::new(
+                format!("{}#{}", context.prefix_text, name),
+                CompletionKind::EnumValue,
+            )
+            .with_text_edit(CompletionTextEdit {
+                range: context.value_range,
+                new_text: name.clone(),
+            })
+            .with_detail(format!("{} = {}", name, value))
+            .with_priority(10)
+        })
+        .collect();
+    Some(items)
+}
+
 fn is_typed_literal_value_token(kind: SyntaxKind) -> bool {
     matches!(
         kind,
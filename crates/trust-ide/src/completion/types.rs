@@ -19,6 +19,10 @@ pub enum CompletionKind {
     Type,
     /// An enum value.
     EnumValue,
+    /// A configuration task.
+    Task,
+    /// A configuration resource.
+    Resource,
     /// A snippet.
     Snippet,
 }
@@ -40,6 +44,8 @@ pub struct CompletionItem {
     pub text_edit: Option<CompletionTextEdit>,
     /// Sort priority (lower = higher priority).
     pub sort_priority: u32,
+    /// Symbol the item was built from, used to resolve details on demand.
+    pub symbol: Option<CompletionSymbolKey>,
 }
 
 impl CompletionItem {
@@ -53,6 +59,7 @@ impl CompletionItem {
             insert_text: None,
             text_edit: None,
             sort_priority: 100,
+            symbol: None,
         }
     }
 
@@ -90,6 +97,40 @@ impl CompletionItem {
         self.sort_priority = priority;
         self
     }
+
+    /// Sets the symbol the item was built from.
+    #[must_use]
+    pub fn with_symbol(mut self, symbols: &SymbolTable, symbol: &Symbol) -> Self {
+        self.symbol = Some(CompletionSymbolKey::for_symbol(symbols, symbol));
+        self
+    }
+}
+
+/// Identifies the symbol behind a completion item independently of the
+/// revision it was computed in, unlike a [`trust_hir::symbols::SymbolId`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompletionSymbolKey {
+    /// Dotted qualified name, e.g. `Main.counter`.
+    pub qualified_name: SmolStr,
+    /// Kind of the symbol, to tell apart declarations sharing a path.
+    pub kind: IndexedSymbolKind,
+}
+
+impl CompletionSymbolKey {
+    /// Creates the key for a symbol of `symbols`.
+    pub fn for_symbol(symbols: &SymbolTable, symbol: &Symbol) -> Self {
+        Self {
+            qualified_name: symbols.qualified_name(symbol.id).into(),
+            kind: IndexedSymbolKind::from(&symbol.kind),
+        }
+    }
+
+    fn matches(&self, symbols: &SymbolTable, symbol: &Symbol) -> bool {
+        IndexedSymbolKind::from(&symbol.kind) == self.kind
+            && symbols
+                .qualified_name(symbol.id)
+                .eq_ignore_ascii_case(&self.qualified_name)
+    }
 }
 
 /// Text edit for completion items.
@@ -116,6 +157,10 @@ pub enum CompletionContext {
     TopLevel,
     /// Inside a VAR block.
     VarBlock,
+    /// After `WITH` in a program configuration (task name).
+    TaskName,
+    /// After `:` in a program configuration (program type).
+    ProgramType,
     /// Unknown/general context.
     General,
 }
@@ -2,14 +2,18 @@
 //!
 //! This module provides functionality to collect and format diagnostics.
 
-use trust_hir::db::SemanticDatabase;
+use std::ops::ControlFlow;
+
 use trust_hir::{Database, Diagnostic, DiagnosticSeverity};
 
 /// Collects all diagnostics for a file.
 pub fn collect_diagnostics(db: &Database, file_id: trust_hir::db::FileId) -> Vec<Diagnostic> {
-    // Get diagnostics from the database
-    let diagnostics = db.diagnostics(file_id);
-    diagnostics.as_ref().clone()
+    let mut diagnostics = Vec::new();
+    trust_hir::diagnostics::collect_diagnostics_into(db, file_id, &mut |diagnostic| {
+        diagnostics.push(diagnostic);
+        ControlFlow::Continue(())
+    });
+    diagnostics
 }
 
 /// Filters diagnostics by severity.
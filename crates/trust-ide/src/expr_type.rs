@@ -0,0 +1,172 @@
+//! Type of a selected expression.
+//!
+//! Backs "show type of selection" editor commands: the selection is mapped to
+//! the smallest expression covering it, and the type checker's inferred type
+//! for that expression is reported.
+
+use smol_str::SmolStr;
+use text_size::{TextRange, TextSize};
+
+use trust_hir::db::{FileId, SemanticDatabase, SourceDatabase};
+use trust_hir::{Database, Type};
+use trust_syntax::parser::parse;
+use trust_syntax::syntax::{SyntaxKind, SyntaxNode};
+
+use crate::hover::format_type;
+
+/// The inferred type of the expression covering a selection.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExpressionType {
+    /// Range of the expression that was typed, without surrounding trivia.
+    pub range: TextRange,
+    /// Display name of the inferred type.
+    pub type_name: SmolStr,
+}
+
+/// Returns the inferred type of the expression covering `range`.
+///
+/// Returns `None` when the selection is not inside an expression or the type
+/// checker could not infer a type for it.
+pub fn expression_type(db: &Database, file_id: FileId, range: TextRange) -> Option<ExpressionType> {
+    let source = db.source_text(file_id);
+    let selection = trim_selection(&source, range)?;
+    let root = parse(&source).syntax();
+    let expr = covering_expression(&root, selection)?;
+    let expr_id = expression_id(&root, &expr)?;
+
+    let symbols = db.file_symbols_with_project(file_id);
+    let type_id = db.type_of(file_id, expr_id);
+    let type_name = match symbols.type_by_id(type_id) {
+        None | Some(Type::Unknown) => return None,
+        Some(ty) => symbols
+            .type_name(type_id)
+            .unwrap_or_else(|| SmolStr::new(format_type(ty))),
+    };
+
+    Some(ExpressionType {
+        range: token_range(&expr),
+        type_name,
+    })
+}
+
+fn trim_selection(source: &str, range: TextRange) -> Option<TextRange> {
+    let text = source.get(usize::from(range.start())..usize::from(range.end()))?;
+    let trimmed = text.trim();
+    if trimmed.is_empty() {
+        return None;
+    }
+    let leading = text.len() - text.trim_start().len();
+    let start = range.start() + TextSize::from(leading as u32);
+    Some(TextRange::at(start, TextSize::from(trimmed.len() as u32)))
+}
+
+fn covering_expression(root: &SyntaxNode, selection: TextRange) -> Option<SyntaxNode> {
+    let element = root.covering_element(selection);
+    let node = match element.clone().into_node() {
+        Some(node) => node,
+        None => element.into_token()?.parent()?,
+    };
+    node.ancestors()
+        .find(|node| is_expression_kind(node.kind()))
+}
+
+/// Expression ids are the pre-order index among expression nodes, matching
+/// the numbering used by `type_of`.
+fn expression_id(root: &SyntaxNode, expr: &SyntaxNode) -> Option<u32> {
+    root.descendants()
+        .filter(|node| is_expression_kind(node.kind()))
+        .position(|node| &node == expr)
+        .and_then(|index| u32::try_from(index).ok())
+}
+
+fn token_range(node: &SyntaxNode) -> TextRange {
+    let mut tokens = node
+        .descendants_with_tokens()
+        .filter_map(|element| element.into_token())
+        .filter(|token| !token.kind().is_trivia());
+    let Some(first) = tokens.next() else {
+        return node.text_range();
+    };
+    let end = tokens
+        .last()
+        .unwrap_or_else(|| first.clone())
+        .text_range()
+        .end();
+    TextRange::new(first.text_range().start(), end)
+}
+
+fn is_expression_kind(kind: SyntaxKind) -> bool {
+    matches!(
+        kind,
+        SyntaxKind::Literal
+            | SyntaxKind::NameRef
+            | SyntaxKind::BinaryExpr
+            | SyntaxKind::UnaryExpr
+            | SyntaxKind::CallExpr
+            | SyntaxKind::IndexExpr
+            | SyntaxKind::FieldExpr
+            | SyntaxKind::DerefExpr
+            | SyntaxKind::AddrExpr
+            | SyntaxKind::ParenExpr
+            | SyntaxKind::ThisExpr
+            | SyntaxKind::SuperExpr
+            | SyntaxKind::SizeOfExpr
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SOURCE: &str = r#"
+FUNCTION Scale : REAL
+VAR_INPUT
+    raw : DINT;
+END_VAR
+Scale := DINT_TO_REAL(raw) / 10.0;
+END_FUNCTION
+
+PROGRAM Main
+VAR
+    a : DINT;
+    b : DINT;
+    total : DINT;
+    level : REAL;
+END_VAR
+total := a + b * 2;
+level := Scale(raw := total);
+END_PROGRAM
+"#;
+
+    fn type_of_selection(text: &str) -> Option<ExpressionType> {
+        let mut db = Database::new();
+        let file_id = FileId(0);
+        db.set_source_text(file_id, SOURCE.to_string());
+        let start = SOURCE.find(text).expect("selection text");
+        let range = TextRange::at(
+            TextSize::from(start as u32),
+            TextSize::from(text.len() as u32),
+        );
+        expression_type(&db, file_id, range)
+    }
+
+    #[test]
+    fn binary_expression_reports_result_type() {
+        let result = type_of_selection("a + b * 2").expect("expression type");
+        assert_eq!(result.type_name, "DINT");
+        assert_eq!(&SOURCE[result.range], "a + b * 2");
+    }
+
+    #[test]
+    fn call_reports_return_type() {
+        let result = type_of_selection(" Scale(raw := total)").expect("expression type");
+        assert_eq!(result.type_name, "REAL");
+        assert_eq!(&SOURCE[result.range], "Scale(raw := total)");
+    }
+
+    #[test]
+    fn non_expression_selection_reports_nothing() {
+        assert_eq!(type_of_selection("total := a + b * 2;"), None);
+        assert_eq!(type_of_selection("VAR_INPUT"), None);
+    }
+}
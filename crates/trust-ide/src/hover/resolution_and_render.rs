@@ -294,7 +294,11 @@ fn format_symbol(
                 qual.push(' ');
                 qual.push_str(retention);
             }
-            result.push_str(&format!("({}) {} : {}", qual, symbol.name, resolved_type));
+            result.push_str(&format!("({}) {}", qual, symbol.name));
+            if let Some(address) = &symbol.direct_address {
+                result.push_str(&format!(" AT {}", address));
+            }
+            result.push_str(&format!(" : {}", resolved_type));
             if let Some(initializer) = info.initializer {
                 result.push_str(&format!(" := {}", initializer));
             }
@@ -451,6 +455,13 @@ fn format_symbol(
     result.push_str("\n```");
 
     let mut sections = Vec::new();
+    if let Some(message) = &symbol.deprecated {
+        if message.is_empty() {
+            sections.push("Deprecated".to_string());
+        } else {
+            sections.push(format!("Deprecated: {message}"));
+        }
+    }
     if let Some(doc) = &symbol.doc {
         sections.push(doc.to_string());
     } else if stdlib_filter.allows_function_block(symbol.name.as_str()) {
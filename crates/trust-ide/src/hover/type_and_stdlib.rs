@@ -74,7 +74,11 @@ fn hover_standard_function(
     }
     let doc = stdlib_docs::standard_function_doc(name)?;
     let signature = signature_help(context.db, context.file_id, position)
-        .and_then(|help| help.signatures.first().map(|sig| sig.label.clone()))
+        .and_then(|help| {
+            help.signatures
+                .get(help.active_signature)
+                .map(|sig| sig.label.clone())
+        })
         .unwrap_or_else(|| name.to_string());
     let contents = format!("```st\n{signature}\n```\n\n{doc}");
     Some(HoverResult::new(contents).with_range(range))
@@ -125,7 +129,7 @@ pub fn format_type(ty: &Type) -> String {
         Type::Array { dimensions, .. } => {
             let dims: Vec<String> = dimensions
                 .iter()
-                .map(|(l, u)| format!("{}..{}", l, u))
+                .map(|bounds| Type::format_array_dimension(*bounds))
                 .collect();
             format!("ARRAY[{}] OF ...", dims.join(", "))
         }
@@ -2,18 +2,15 @@
 //!
 //! Finds classes and function blocks that implement a given interface.
 
+use rustc_hash::FxHashSet;
 use smol_str::SmolStr;
 use text_size::{TextRange, TextSize};
 
 use trust_hir::db::FileId;
-use trust_hir::{Database, SourceDatabase, Symbol, SymbolKind, Type};
-use trust_syntax::parser::parse;
-use trust_syntax::syntax::{SyntaxKind, SyntaxNode};
+use trust_hir::symbols::{ScopeId, SymbolTable};
+use trust_hir::{Database, Symbol, SymbolId, SymbolKind, Type};
 
-use crate::util::{
-    name_range_from_node, qualified_name_parts_from_node, resolve_target_at_position,
-    resolve_type_symbol, scope_at_position, ResolvedTarget, SymbolFilter,
-};
+use crate::util::{resolve_target_at_position, resolve_type_symbol, ResolvedTarget, SymbolFilter};
 
 /// Result of a go-to-implementation request.
 #[derive(Debug, Clone)]
@@ -22,6 +19,9 @@ pub struct ImplementationResult {
     pub file_id: FileId,
     /// The range of the implementing symbol.
     pub range: TextRange,
+    /// True when the type implements the interface only through a base type
+    /// it extends.
+    pub indirect: bool,
 }
 
 /// Finds implementations of the interface at the given position.
@@ -47,6 +47,32 @@ pub fn goto_implementation(
     Vec::new()
 }
 
+/// Finds the classes and function blocks implementing `interface`, a symbol
+/// of `file_id`'s symbol table, in every file of the workspace.
+///
+/// Types implementing an interface that extends `interface` are included, as
+/// are types deriving from an implementor, which are marked
+/// [`ImplementationResult::indirect`].
+pub fn implementors_of(
+    db: &Database,
+    file_id: FileId,
+    interface: SymbolId,
+) -> Vec<ImplementationResult> {
+    let symbols = db.file_symbols_with_project(file_id);
+    let Some(target_parts) = interface_parts_for_symbol(&symbols, interface) else {
+        return Vec::new();
+    };
+    find_interface_implementations(db, &target_parts)
+}
+
+/// Finds the implementors of the interface with the given qualified name
+/// (e.g. `Plant.IPump`) in every file of the workspace.
+///
+/// See [`implementors_of`].
+pub fn implementors_of_name(db: &Database, interface_name: &str) -> Vec<ImplementationResult> {
+    find_interface_implementations(db, &split_qualified_name(interface_name))
+}
+
 fn find_interface_implementations(
     db: &Database,
     target_parts: &[SmolStr],
@@ -54,30 +80,28 @@ fn find_interface_implementations(
     let mut results: Vec<ImplementationResult> = Vec::new();
 
     for candidate_file in db.file_ids() {
-        let source = db.source_text(candidate_file);
-        let parsed = parse(&source);
-        let root = parsed.syntax();
         let symbols = db.file_symbols_with_project(candidate_file);
-
-        for node in root.descendants() {
-            if !matches!(node.kind(), SyntaxKind::Class | SyntaxKind::FunctionBlock) {
+        for symbol in symbols.iter() {
+            if symbol.origin.is_some()
+                || !matches!(symbol.kind, SymbolKind::Class | SymbolKind::FunctionBlock)
+            {
                 continue;
             }
-
-            if !implements_interface(&node, &root, &symbols, target_parts) {
+            let mut visited = FxHashSet::default();
+            let Some(indirect) =
+                implementation_kind(&symbols, symbol.id, target_parts, &mut visited)
+            else {
                 continue;
-            }
-
-            if let Some(range) = symbol_range_for_node(&node, &symbols) {
-                if !results
-                    .iter()
-                    .any(|res| res.file_id == candidate_file && res.range == range)
-                {
-                    results.push(ImplementationResult {
-                        file_id: candidate_file,
-                        range,
-                    });
-                }
+            };
+            if !results
+                .iter()
+                .any(|res| res.file_id == candidate_file && res.range == symbol.range)
+            {
+                results.push(ImplementationResult {
+                    file_id: candidate_file,
+                    range: symbol.range,
+                    indirect,
+                });
             }
         }
     }
@@ -85,43 +109,79 @@ fn find_interface_implementations(
     results
 }
 
-fn implements_interface(
-    node: &SyntaxNode,
-    root: &SyntaxNode,
-    symbols: &trust_hir::symbols::SymbolTable,
+/// Returns whether the class or function block `symbol_id` implements the
+/// target interface: `Some(false)` when one of its own IMPLEMENTS entries
+/// names the interface or an interface extending it, `Some(true)` when it
+/// inherits the implementation from a base type.
+fn implementation_kind(
+    symbols: &SymbolTable,
+    symbol_id: SymbolId,
+    target_parts: &[SmolStr],
+    visited: &mut FxHashSet<SymbolId>,
+) -> Option<bool> {
+    if !visited.insert(symbol_id) {
+        return None;
+    }
+    let scope_id = declaration_scope(symbols, symbol_id);
+    let direct = symbols
+        .implements_names(symbol_id)
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|name| resolve_type_symbol(symbols, &split_qualified_name(name), scope_id))
+        .any(|interface_id| interface_extends_target(symbols, interface_id, target_parts));
+    if direct {
+        return Some(false);
+    }
+
+    let base_name = symbols.extends_name(symbol_id)?;
+    let base_id = resolve_type_symbol(symbols, &split_qualified_name(base_name), scope_id)?;
+    implementation_kind(symbols, base_id, target_parts, visited).map(|_| true)
+}
+
+/// Returns true if `interface_id` is the target interface or reaches it
+/// through its EXTENDS chain.
+fn interface_extends_target(
+    symbols: &SymbolTable,
+    interface_id: SymbolId,
     target_parts: &[SmolStr],
 ) -> bool {
-    for clause in node
-        .children()
-        .filter(|child| child.kind() == SyntaxKind::ImplementsClause)
-    {
-        let scope_id = scope_at_position(symbols, root, clause.text_range().start());
-        for name_node in clause
-            .children()
-            .filter(|child| matches!(child.kind(), SyntaxKind::Name | SyntaxKind::QualifiedName))
-        {
-            let Some(parts) = qualified_name_parts_from_node(&name_node) else {
-                continue;
-            };
-            let Some(symbol_id) = resolve_type_symbol(symbols, &parts, scope_id) else {
-                continue;
-            };
-            let Some(found_parts) = interface_parts_for_symbol(symbols, symbol_id) else {
-                continue;
-            };
-            if parts_equal_case_insensitive(&found_parts, target_parts) {
-                return true;
-            }
+    let mut visited = FxHashSet::default();
+    let mut current = Some(interface_id);
+    while let Some(symbol_id) = current {
+        if !visited.insert(symbol_id) {
+            break;
+        }
+        let Some(symbol) = symbols.get(symbol_id) else {
+            break;
+        };
+        if !matches!(symbol.kind, SymbolKind::Interface) {
+            break;
         }
+        if parts_equal_case_insensitive(&qualified_symbol_parts(symbols, symbol_id), target_parts) {
+            return true;
+        }
+        let scope_id = declaration_scope(symbols, symbol_id);
+        current = symbols
+            .extends_name(symbol_id)
+            .and_then(|name| resolve_type_symbol(symbols, &split_qualified_name(name), scope_id));
     }
-
     false
 }
 
-fn interface_parts_for_symbol(
-    symbols: &trust_hir::symbols::SymbolTable,
-    symbol_id: trust_hir::SymbolId,
-) -> Option<Vec<SmolStr>> {
+/// Scope in which the names of a type's EXTENDS/IMPLEMENTS clauses resolve.
+fn declaration_scope(symbols: &SymbolTable, symbol_id: SymbolId) -> ScopeId {
+    symbols
+        .scope_for_owner(symbol_id)
+        .or_else(|| {
+            symbols
+                .get(symbol_id)
+                .and_then(|symbol| symbol.parent)
+                .and_then(|parent| symbols.scope_for_owner(parent))
+        })
+        .unwrap_or(ScopeId::GLOBAL)
+}
+
+fn interface_parts_for_symbol(symbols: &SymbolTable, symbol_id: SymbolId) -> Option<Vec<SmolStr>> {
     let symbol = symbols.get(symbol_id)?;
     if matches!(symbol.kind, SymbolKind::Interface) {
         return Some(qualified_symbol_parts(symbols, symbol_id));
@@ -135,8 +195,8 @@ fn interface_parts_for_symbol(
 }
 
 fn implementation_for_symbol(
-    symbols: &trust_hir::symbols::SymbolTable,
-    symbol_id: trust_hir::SymbolId,
+    symbols: &SymbolTable,
+    symbol_id: SymbolId,
     fallback_file_id: FileId,
 ) -> Option<ImplementationResult> {
     let filter = SymbolFilter::new(symbols);
@@ -171,13 +231,11 @@ fn implementation_result_for_symbol(
     ImplementationResult {
         file_id,
         range: symbol.range,
+        indirect: false,
     }
 }
 
-fn qualified_symbol_parts(
-    symbols: &trust_hir::symbols::SymbolTable,
-    symbol_id: trust_hir::SymbolId,
-) -> Vec<SmolStr> {
+fn qualified_symbol_parts(symbols: &SymbolTable, symbol_id: SymbolId) -> Vec<SmolStr> {
     let mut parts = Vec::new();
     let mut current = symbols.get(symbol_id).and_then(|sym| sym.parent);
     while let Some(parent_id) = current {
@@ -196,21 +254,6 @@ fn qualified_symbol_parts(
     parts
 }
 
-fn symbol_range_for_node(
-    node: &SyntaxNode,
-    symbols: &trust_hir::symbols::SymbolTable,
-) -> Option<TextRange> {
-    let name_node = node
-        .children()
-        .find(|child| child.kind() == SyntaxKind::Name)?;
-    let range = name_range_from_node(&name_node)?;
-    let filter = SymbolFilter::new(symbols);
-    filter
-        .symbol_at_range(range)
-        .filter(|sym| matches!(sym.kind, SymbolKind::Class | SymbolKind::FunctionBlock))
-        .map(|sym| sym.range)
-}
-
 fn split_qualified_name(name: &str) -> Vec<SmolStr> {
     name.split('.').map(SmolStr::new).collect()
 }
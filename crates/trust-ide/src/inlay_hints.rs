@@ -1,24 +1,52 @@
 //! Inlay hints for Structured Text.
 //!
-//! Provides parameter name hints for positional call arguments.
+//! Provides parameter name hints for positional call arguments, the
+//! function block or class type of instance receivers in member accesses,
+//! and (opt-in) the defaults of inputs a function call omits.
 
 use smol_str::SmolStr;
 use text_size::{TextRange, TextSize};
 
-use trust_hir::db::{FileId, SourceDatabase};
-use trust_hir::symbols::ParamDirection;
-use trust_hir::Database;
+use trust_hir::db::{FileId, SemanticDatabase, SourceDatabase};
+use trust_hir::symbols::{ParamDirection, SymbolKind};
+use trust_hir::{Database, Type};
 use trust_syntax::parser::parse;
 use trust_syntax::syntax::{SyntaxKind, SyntaxNode};
 
-use crate::signature_help::signature_for_call_expr;
+use crate::signature_help::{signature_for_call_expr, ParamData};
 use crate::util::name_from_name_node;
+use crate::var_decl::{find_var_decl_for_range, initializer_from_var_decl};
 
 /// Kinds of inlay hints produced by trust-ide.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum InlayHintKind {
     /// Parameter name hint.
     Parameter,
+    /// Type of the function block or class instance a member is accessed on.
+    ReceiverType,
+    /// Default value of an input the call omits.
+    DefaultValue,
+}
+
+/// Options controlling which inlay hints are produced.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InlayHintOptions {
+    /// Show parameter name hints (`speed :=`) before positional arguments.
+    pub parameter_names: bool,
+    /// Show receiver type hints (`inst: FB_Motor`) before member accesses.
+    pub receiver_types: bool,
+    /// Show the declared default of inputs a function or method call omits.
+    pub default_values: bool,
+}
+
+impl Default for InlayHintOptions {
+    fn default() -> Self {
+        Self {
+            parameter_names: true,
+            receiver_types: false,
+            default_values: false,
+        }
+    }
 }
 
 /// A single inlay hint in ST source.
@@ -34,11 +62,41 @@ pub struct InlayHint {
 
 /// Computes inlay hints within a source range.
 pub fn inlay_hints(db: &Database, file_id: FileId, range: TextRange) -> Vec<InlayHint> {
+    inlay_hints_with_options(db, file_id, range, InlayHintOptions::default())
+}
+
+/// Computes inlay hints within a source range using the given options.
+pub fn inlay_hints_with_options(
+    db: &Database,
+    file_id: FileId,
+    range: TextRange,
+    options: InlayHintOptions,
+) -> Vec<InlayHint> {
     let source = db.source_text(file_id);
     let parsed = parse(&source);
     let root = parsed.syntax();
 
     let mut hints = Vec::new();
+    if options.parameter_names || options.default_values {
+        call_hints(db, file_id, &source, &root, range, options, &mut hints);
+    }
+
+    if options.receiver_types {
+        receiver_type_hints(db, file_id, &root, range, &mut hints);
+    }
+
+    hints
+}
+
+fn call_hints(
+    db: &Database,
+    file_id: FileId,
+    source: &str,
+    root: &SyntaxNode,
+    range: TextRange,
+    options: InlayHintOptions,
+    hints: &mut Vec<InlayHint>,
+) {
     for call_expr in root
         .descendants()
         .filter(|n| n.kind() == SyntaxKind::CallExpr)
@@ -47,34 +105,35 @@ pub fn inlay_hints(db: &Database, file_id: FileId, range: TextRange) -> Vec<Inla
             continue;
         }
 
-        let arg_list = call_expr
+        let Some(arg_list) = call_expr
             .children()
-            .find(|child| child.kind() == SyntaxKind::ArgList);
-        let Some(arg_list) = arg_list else {
+            .find(|child| child.kind() == SyntaxKind::ArgList)
+        else {
             continue;
         };
 
         let args = collect_call_args(&arg_list);
-        if args.is_empty() {
+        if args.is_empty() && !options.default_values {
             continue;
         }
 
-        let Some(signature) = signature_for_call_expr(db, file_id, &source, &root, &call_expr)
-        else {
+        let Some(signature) = signature_for_call_expr(db, file_id, source, root, &call_expr) else {
             continue;
         };
 
-        for (index, arg) in args.iter().enumerate() {
-            if arg.name.is_some() {
-                continue;
-            }
-            let Some(param) = signature.params.get(index) else {
+        // Positional arguments bind to the non-EN/ENO parameters in order.
+        let positional_params: Vec<_> = signature
+            .params
+            .iter()
+            .filter(|param| !is_execution_param(&param.name))
+            .collect();
+        let mut bound = vec![false; positional_params.len()];
+        for (index, arg) in args.iter().filter(|arg| arg.name.is_none()).enumerate() {
+            let Some(param) = positional_params.get(index) else {
                 break;
             };
-            if is_execution_param(&param.name) {
-                continue;
-            }
-            if !range.contains(arg.range.start()) {
+            bound[index] = true;
+            if !options.parameter_names || !range.contains(arg.range.start()) {
                 continue;
             }
 
@@ -82,16 +141,151 @@ pub fn inlay_hints(db: &Database, file_id: FileId, range: TextRange) -> Vec<Inla
                 ParamDirection::Out => "=>",
                 ParamDirection::In | ParamDirection::InOut => ":=",
             };
-            let label = SmolStr::new(format!("{} {}", param.name, op));
             hints.push(InlayHint {
                 position: arg.range.start(),
-                label,
+                label: SmolStr::new(format!("{} {}", param.name, op)),
                 kind: InlayHintKind::Parameter,
             });
         }
+
+        if options.default_values {
+            for arg_name in args.iter().filter_map(|arg| arg.name.as_ref()) {
+                if let Some(index) = positional_params
+                    .iter()
+                    .position(|param| param.name.eq_ignore_ascii_case(arg_name))
+                {
+                    bound[index] = true;
+                }
+            }
+            let omitted = positional_params
+                .iter()
+                .zip(&bound)
+                .filter(|(param, bound)| !**bound && param.direction == ParamDirection::In)
+                .map(|(param, _)| *param);
+            default_value_hints(
+                db,
+                file_id,
+                &arg_list,
+                !args.is_empty(),
+                omitted,
+                range,
+                hints,
+            );
+        }
     }
+}
 
-    hints
+fn default_value_hints<'a>(
+    db: &Database,
+    file_id: FileId,
+    arg_list: &SyntaxNode,
+    has_args: bool,
+    omitted: impl Iterator<Item = &'a ParamData>,
+    range: TextRange,
+    hints: &mut Vec<InlayHint>,
+) {
+    let Some(position) = arg_list
+        .children_with_tokens()
+        .filter_map(|element| element.into_token())
+        .find(|token| token.kind() == SyntaxKind::RParen)
+        .map(|token| token.text_range().start())
+    else {
+        return;
+    };
+    if !range.contains_inclusive(position) {
+        return;
+    }
+
+    let symbols = db.file_symbols_with_project(file_id);
+    let mut separate = has_args;
+    for param in omitted {
+        let Some(symbol) = param.symbol.and_then(|id| symbols.get(id)) else {
+            continue;
+        };
+        // Omitted function block inputs keep their previous value, so only
+        // function and method calls fall back to the declared default.
+        let owner_kind = symbol
+            .parent
+            .and_then(|parent| symbols.get(parent))
+            .map(|owner| &owner.kind);
+        if !matches!(
+            owner_kind,
+            Some(SymbolKind::Function { .. } | SymbolKind::Method { .. })
+        ) {
+            continue;
+        }
+        let (decl_file, decl_range) = match symbol.origin {
+            Some(origin) => {
+                let origin_symbols = db.file_symbols(origin.file_id);
+                let Some(origin_symbol) = origin_symbols.get(origin.symbol_id) else {
+                    continue;
+                };
+                (origin.file_id, origin_symbol.range)
+            }
+            None => (file_id, symbol.range),
+        };
+        let Some(default) = declared_default(db, decl_file, decl_range) else {
+            continue;
+        };
+
+        let separator = if separate { ", " } else { "" };
+        separate = true;
+        hints.push(InlayHint {
+            position,
+            label: SmolStr::new(format!("{separator}{} := {default}", param.name)),
+            kind: InlayHintKind::DefaultValue,
+        });
+    }
+}
+
+fn declared_default(db: &Database, file_id: FileId, name_range: TextRange) -> Option<String> {
+    let source = db.source_text(file_id);
+    let root = parse(&source).syntax();
+    let var_decl = find_var_decl_for_range(&root, name_range)?;
+    initializer_from_var_decl(&source, &var_decl)
+}
+
+fn receiver_type_hints(
+    db: &Database,
+    file_id: FileId,
+    root: &SyntaxNode,
+    range: TextRange,
+    hints: &mut Vec<InlayHint>,
+) {
+    let symbols = db.file_symbols(file_id);
+    for field_expr in root
+        .descendants()
+        .filter(|n| n.kind() == SyntaxKind::FieldExpr)
+    {
+        let Some(receiver) = field_expr.children().next() else {
+            continue;
+        };
+        if matches!(
+            receiver.kind(),
+            SyntaxKind::ThisExpr | SyntaxKind::SuperExpr
+        ) {
+            continue;
+        }
+        let position = receiver.text_range().end();
+        if !range.contains_inclusive(position) {
+            continue;
+        }
+        // The receiver is the smallest expression covering its last character.
+        let Some(expr_id) = db.expr_id_at_offset(file_id, u32::from(position).saturating_sub(1))
+        else {
+            continue;
+        };
+        let type_id = symbols.resolve_alias_type(db.type_of(file_id, expr_id));
+        let name = match symbols.type_by_id(type_id) {
+            Some(Type::FunctionBlock { name } | Type::Class { name }) => name,
+            _ => continue,
+        };
+        hints.push(InlayHint {
+            position,
+            label: SmolStr::new(format!(": {name}")),
+            kind: InlayHintKind::ReceiverType,
+        });
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -194,4 +388,154 @@ END_PROGRAM
         assert_eq!(hints.len(), 1);
         assert!(hints[0].label.as_str().starts_with("A"));
     }
+
+    #[test]
+    fn inlay_hints_show_receiver_types_along_member_chains() {
+        let source = r#"
+FUNCTION_BLOCK FB_Motor
+VAR_OUTPUT
+    speed : INT;
+END_VAR
+END_FUNCTION_BLOCK
+
+FUNCTION_BLOCK FB_Axis
+VAR_OUTPUT
+    motor : FB_Motor;
+END_VAR
+END_FUNCTION_BLOCK
+
+PROGRAM Main
+VAR
+    axis : FB_Axis;
+    result : INT;
+END_VAR
+    result := axis.motor.speed;
+END_PROGRAM
+"#;
+        let start = source.find("axis.motor").expect("chain");
+        let end = source.find(".speed").expect("chain end") + ".speed".len();
+        let range = TextRange::new(TextSize::from(start as u32), TextSize::from(end as u32));
+
+        let mut db = Database::new();
+        let file_id = FileId(0);
+        db.set_source_text(file_id, source.to_string());
+
+        let hints = inlay_hints(&db, file_id, range);
+        assert!(hints
+            .iter()
+            .all(|hint| hint.kind != InlayHintKind::ReceiverType));
+
+        let options = InlayHintOptions {
+            receiver_types: true,
+            ..InlayHintOptions::default()
+        };
+        let mut hints = inlay_hints_with_options(&db, file_id, range, options);
+        hints.sort_by_key(|hint| hint.position);
+        let labels: Vec<_> = hints
+            .iter()
+            .filter(|hint| hint.kind == InlayHintKind::ReceiverType)
+            .map(|hint| (hint.position, hint.label.as_str()))
+            .collect();
+        let axis_end = TextSize::from((start + "axis".len()) as u32);
+        let motor_end = TextSize::from((start + "axis.motor".len()) as u32);
+        assert_eq!(
+            labels,
+            vec![(axis_end, ": FB_Axis"), (motor_end, ": FB_Motor")]
+        );
+    }
+
+    #[test]
+    fn inlay_hints_show_omitted_function_defaults_when_enabled() {
+        let source = r#"
+FUNCTION Scale : INT
+VAR_INPUT
+    x : INT;
+    gain : INT := 10;
+    offset : INT := -1;
+END_VAR
+    Scale := x * gain + offset;
+END_FUNCTION
+
+FUNCTION_BLOCK FB_Ramp
+VAR_INPUT
+    step : INT := 2;
+END_VAR
+END_FUNCTION_BLOCK
+
+PROGRAM Main
+VAR
+    a : INT;
+    b : INT;
+    ramp : FB_Ramp;
+END_VAR
+    a := Scale(5);
+    b := Scale(x := 5, offset := 3);
+    ramp();
+END_PROGRAM
+"#;
+        let mut db = Database::new();
+        let file_id = FileId(0);
+        db.set_source_text(file_id, source.to_string());
+        let range = TextRange::new(TextSize::from(0), TextSize::from(source.len() as u32));
+
+        let hints = inlay_hints(&db, file_id, range);
+        assert!(hints
+            .iter()
+            .all(|hint| hint.kind != InlayHintKind::DefaultValue));
+
+        let options = InlayHintOptions {
+            default_values: true,
+            ..InlayHintOptions::default()
+        };
+        let hints = inlay_hints_with_options(&db, file_id, range, options);
+        let defaults: Vec<_> = hints
+            .iter()
+            .filter(|hint| hint.kind == InlayHintKind::DefaultValue)
+            .map(|hint| (usize::from(hint.position), hint.label.as_str()))
+            .collect();
+        let first_close = source.find("Scale(5)").unwrap() + "Scale(5".len();
+        let second_close = source.find("offset := 3)").unwrap() + "offset := 3".len();
+        assert_eq!(
+            defaults,
+            vec![
+                (first_close, ", gain := 10"),
+                (first_close, ", offset := -1"),
+                (second_close, ", gain := 10"),
+            ]
+        );
+    }
+
+    #[test]
+    fn inlay_hints_parameter_names_can_be_disabled() {
+        let source = r#"
+FUNCTION Add : INT
+VAR_INPUT
+    A : INT;
+    B : INT := 1;
+END_VAR
+    Add := A + B;
+END_FUNCTION
+
+PROGRAM Main
+VAR
+    result : INT;
+END_VAR
+    result := Add(1);
+END_PROGRAM
+"#;
+        let mut db = Database::new();
+        let file_id = FileId(0);
+        db.set_source_text(file_id, source.to_string());
+        let range = TextRange::new(TextSize::from(0), TextSize::from(source.len() as u32));
+
+        let options = InlayHintOptions {
+            parameter_names: false,
+            receiver_types: false,
+            default_values: true,
+        };
+        let hints = inlay_hints_with_options(&db, file_id, range, options);
+        assert_eq!(hints.len(), 1);
+        assert_eq!(hints[0].kind, InlayHintKind::DefaultValue);
+        assert_eq!(hints[0].label.as_str(), ", B := 1");
+    }
 }
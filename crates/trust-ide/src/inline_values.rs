@@ -1,6 +1,7 @@
 use rustc_hash::FxHashSet;
 use text_size::TextRange;
 
+use trust_hir::const_eval;
 use trust_hir::db::{FileId, SemanticDatabase};
 use trust_hir::symbols::{Symbol, VarQualifier};
 use trust_hir::{Database, SourceDatabase, SymbolKind};
@@ -9,7 +10,8 @@ use trust_syntax::syntax::SyntaxKind;
 
 use crate::util::{resolve_target_at_position_with_context, ResolvedTarget};
 use crate::var_decl::{
-    find_var_decl_for_range, initializer_from_var_decl, var_decl_info_for_symbol,
+    find_var_decl_for_range, initializer_expr_from_var_decl, initializer_from_var_decl,
+    var_decl_info_for_symbol,
 };
 
 /// Inline value hint for a symbol reference.
@@ -147,6 +149,11 @@ fn inline_text_for_constant(db: &Database, file_id: FileId, symbol: &Symbol) ->
     let decl_source = db.source_text(decl_file_id);
     let decl_root = parse(&decl_source).syntax();
     let var_decl = find_var_decl_for_range(&decl_root, decl_range)?;
+    if let Some(value) = initializer_expr_from_var_decl(&var_decl)
+        .and_then(|expr| const_eval::try_fold(db, decl_file_id, &expr))
+    {
+        return Some(format!(" = {value}"));
+    }
     let initializer = initializer_from_var_decl(&decl_source, &var_decl)?;
     Some(format!(" = {initializer}"))
 }
@@ -248,4 +255,29 @@ END_PROGRAM
 
         assert!(hints.iter().any(|hint| hint.text == " = 42"));
     }
+
+    #[test]
+    fn inline_value_hints_show_folded_constant_values() {
+        let program = r#"
+PROGRAM Test
+VAR CONSTANT
+    BASE : INT := 4;
+    LIMIT : INT := BASE * 10 + 2;
+END_VAR
+VAR
+    x : INT;
+END_VAR
+    x := LIMIT;
+END_PROGRAM
+"#;
+        let mut db = Database::new();
+        let file_id = FileId(0);
+        db.set_source_text(file_id, program.to_string());
+
+        let start = TextSize::from(0u32);
+        let end = TextSize::from(program.len() as u32);
+        let hints = inline_value_hints(&db, file_id, TextRange::new(start, end));
+
+        assert!(hints.iter().any(|hint| hint.text == " = 42"));
+    }
 }
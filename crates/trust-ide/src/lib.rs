@@ -22,6 +22,7 @@
 pub mod call_hierarchy;
 pub mod completion;
 pub mod diagnostics;
+pub mod expr_type;
 pub mod goto_def;
 pub mod hover;
 pub mod implementation;
@@ -48,11 +49,19 @@ pub use call_hierarchy::{
     prepare_call_hierarchy, prepare_call_hierarchy_in_files, CallHierarchyIncomingCall,
     CallHierarchyItem, CallHierarchyOutgoingCall,
 };
-pub use completion::{complete, complete_with_filter, CompletionItem, CompletionKind};
+pub use completion::{
+    complete, complete_with_filter, resolve_completion_symbol, CompletionItem, CompletionKind,
+    CompletionSymbolKey,
+};
+pub use expr_type::{expression_type, ExpressionType};
 pub use goto_def::{goto_declaration, goto_definition, goto_type_definition, DefinitionResult};
 pub use hover::{hover, hover_with_filter, HoverResult};
-pub use implementation::{goto_implementation, ImplementationResult};
-pub use inlay_hints::{inlay_hints, InlayHint, InlayHintKind};
+pub use implementation::{
+    goto_implementation, implementors_of, implementors_of_name, ImplementationResult,
+};
+pub use inlay_hints::{
+    inlay_hints, inlay_hints_with_options, InlayHint, InlayHintKind, InlayHintOptions,
+};
 pub use inline_values::{
     inline_value_data, inline_value_hints, InlineValueData, InlineValueHint, InlineValueScope,
     InlineValueTarget,
@@ -60,11 +69,13 @@ pub use inline_values::{
 pub use linked_editing::linked_editing_ranges;
 pub use refactor::{
     convert_function_block_to_function, convert_function_to_function_block, extract_method,
-    extract_pou, extract_property, generate_interface_stubs, inline_symbol, move_namespace_path,
-    ExtractResult, ExtractTargetKind, InlineResult, InlineTargetKind,
+    extract_pou, extract_property, extract_variable, generate_interface_stubs, inline_symbol,
+    move_namespace_path, ExtractResult, ExtractTargetKind, InlineResult, InlineTargetKind,
+};
+pub use references::{
+    find_references, find_references_cancellable, FindReferencesOptions, Reference,
 };
-pub use references::{find_references, FindReferencesOptions, Reference};
-pub use rename::rename;
+pub use rename::{rename, rename_cancellable};
 pub use selection_range::{selection_ranges, SelectionRange};
 pub use semantic_tokens::{semantic_tokens, SemanticToken, SemanticTokenType};
 pub use signature_help::{
@@ -73,4 +84,4 @@ pub use signature_help::{
 };
 pub use stdlib_docs::StdlibFilter;
 pub use type_hierarchy::{prepare_type_hierarchy, subtypes, supertypes, TypeHierarchyItem};
-pub use util::symbol_name_at_position;
+pub use util::{symbol_container_path, symbol_name_at_position};
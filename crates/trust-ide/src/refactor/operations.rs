@@ -8,7 +8,9 @@ use text_size::{TextRange, TextSize};
 
 use trust_hir::db::{FileId, SemanticDatabase};
 use trust_hir::symbols::{SymbolKind, SymbolTable};
-use trust_hir::{is_reserved_keyword, is_valid_identifier, Database, SourceDatabase, SymbolId};
+use trust_hir::{
+    is_reserved_keyword, is_valid_identifier, Database, SourceDatabase, SymbolId, Type, TypeId,
+};
 use trust_syntax::parser::parse;
 use trust_syntax::syntax::{SyntaxKind, SyntaxNode, SyntaxToken};
 
@@ -69,6 +71,8 @@ pub enum ExtractTargetKind {
     Property,
     /// Extract a FUNCTION (POU).
     Function,
+    /// Extract a local variable.
+    Variable,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
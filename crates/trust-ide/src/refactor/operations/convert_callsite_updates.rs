@@ -218,6 +218,7 @@ fn is_statement_kind(kind: SyntaxKind) -> bool {
             | SyntaxKind::ForStmt
             | SyntaxKind::WhileStmt
             | SyntaxKind::RepeatStmt
+            | SyntaxKind::TryStmt
             | SyntaxKind::ReturnStmt
             | SyntaxKind::ExitStmt
             | SyntaxKind::ContinueStmt
@@ -488,6 +488,89 @@ pub fn extract_pou(db: &Database, file_id: FileId, range: TextRange) -> Option<E
     })
 }
 
+/// Extracts a selected expression into a new local variable.
+///
+/// The variable is declared in the nearest `VAR`/`VAR_TEMP` block of the
+/// enclosing POU (a `VAR` block is created when there is none) and assigned
+/// just before the statement holding the selection. Identical call-free
+/// occurrences later in the same statement list are replaced as well, up to
+/// the first statement that may change a value the expression reads.
+pub fn extract_variable(
+    db: &Database,
+    file_id: FileId,
+    range: TextRange,
+    name: &str,
+) -> Option<ExtractResult> {
+    if !is_valid_identifier(name) || is_reserved_keyword(name) {
+        return None;
+    }
+    let source = db.source_text(file_id);
+    let parsed = parse(&source);
+    let root = parsed.syntax();
+    let selection = trim_range_to_non_whitespace(&source, range)?;
+    let expr_node = expression_node_for_selection(&root, selection)?;
+    if is_write_context(&expr_node) || is_call_name(&expr_node) || is_output_arg(&expr_node) {
+        return None;
+    }
+    let statement = extraction_anchor_statement(&expr_node)?;
+
+    let owner_node = find_enclosing_owner_node(
+        &root,
+        selection.start(),
+        &[
+            SyntaxKind::Method,
+            SyntaxKind::Program,
+            SyntaxKind::Function,
+            SyntaxKind::FunctionBlock,
+        ],
+    )?;
+    let symbols = db.file_symbols_with_project(file_id);
+    let owner_id = owner_symbol_id(&symbols, &owner_node)?;
+    let expr_id = expression_id_for_node(&root, &expr_node)?;
+    let type_id = db.type_of(file_id, expr_id);
+    if !is_declarable_type(&symbols, type_id) {
+        return None;
+    }
+    let type_name = symbols.type_name(type_id)?;
+    let expr_text = text_for_range(&source, expr_node.text_range());
+    if expr_text.is_empty() {
+        return None;
+    }
+    let name = unique_member_name(&symbols, owner_id, name);
+
+    let mut edits = RenameResult::new();
+    edits.add_edit(
+        file_id,
+        local_declaration_edit(&source, &owner_node, name.as_str(), type_name.as_str())?,
+    );
+
+    let stmt_start = node_token_range(&statement).start();
+    let indent = line_indent_at_offset(&source, stmt_start);
+    edits.add_edit(
+        file_id,
+        TextEdit {
+            range: TextRange::new(stmt_start, stmt_start),
+            new_text: format!("{name} := {expr_text};\n{indent}"),
+        },
+    );
+
+    for occurrence in extract_variable_occurrences(&expr_node, &statement) {
+        edits.add_edit(
+            file_id,
+            TextEdit {
+                range: occurrence,
+                new_text: name.to_string(),
+            },
+        );
+    }
+
+    Some(ExtractResult {
+        edits,
+        name,
+        kind: ExtractTargetKind::Variable,
+    })
+}
+
 /// Converts a FUNCTION to a FUNCTION_BLOCK.
 pub fn convert_function_to_function_block(
     db: &Database,
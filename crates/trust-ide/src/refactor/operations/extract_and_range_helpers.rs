@@ -442,3 +442,226 @@ fn build_prefix_insert_text(source: &str, insert_offset: usize, line: &str) -> S
     insert
 }
 
+fn is_output_arg(expr: &SyntaxNode) -> bool {
+    expr.parent().is_some_and(|parent| {
+        parent.kind() == SyntaxKind::Arg
+            && parent
+                .children_with_tokens()
+                .filter_map(|element| element.into_token())
+                .any(|token| token.kind() == SyntaxKind::Arrow)
+    })
+}
+
+/// Returns the statement an extracted expression is evaluated by, provided the
+/// expression is evaluated once, before the statement body runs.
+fn extraction_anchor_statement(expr: &SyntaxNode) -> Option<SyntaxNode> {
+    let mut current = expr.parent()?;
+    while is_expression_kind(current.kind())
+        || matches!(current.kind(), SyntaxKind::Arg | SyntaxKind::ArgList)
+    {
+        current = current.parent()?;
+    }
+    if !is_statement_kind(current.kind())
+        || matches!(
+            current.kind(),
+            SyntaxKind::WhileStmt | SyntaxKind::RepeatStmt
+        )
+    {
+        return None;
+    }
+    Some(current)
+}
+
+/// Mirrors the semantic database's expression numbering for `type_of`.
+fn expression_id_for_node(root: &SyntaxNode, expr: &SyntaxNode) -> Option<u32> {
+    root.descendants()
+        .filter(|node| is_expression_kind(node.kind()))
+        .position(|node| &node == expr)
+        .and_then(|index| u32::try_from(index).ok())
+}
+
+fn is_declarable_type(symbols: &SymbolTable, type_id: TypeId) -> bool {
+    !matches!(
+        symbols.type_by_id(type_id),
+        None | Some(
+            Type::Unknown
+                | Type::Void
+                | Type::Null
+                | Type::Any
+                | Type::AnyDerived
+                | Type::AnyElementary
+                | Type::AnyMagnitude
+                | Type::AnyInt
+                | Type::AnyUnsigned
+                | Type::AnySigned
+                | Type::AnyReal
+                | Type::AnyNum
+                | Type::AnyDuration
+                | Type::AnyBit
+                | Type::AnyChars
+                | Type::AnyString
+                | Type::AnyChar
+                | Type::AnyDate
+        )
+    )
+}
+
+/// Builds the edit declaring `name` in the last plain `VAR`/`VAR_TEMP` block of
+/// `owner`, or inserting a new `VAR` block when there is none.
+fn local_declaration_edit(
+    source: &str,
+    owner: &SyntaxNode,
+    name: &str,
+    type_name: &str,
+) -> Option<TextEdit> {
+    let block = owner
+        .children()
+        .filter(|child| child.kind() == SyntaxKind::VarBlock)
+        .filter(|block| {
+            matches!(
+                var_block_kind(block),
+                Some(SyntaxKind::KwVar | SyntaxKind::KwVarTemp)
+            )
+        })
+        .filter(|block| {
+            !block
+                .children_with_tokens()
+                .filter_map(|element| element.into_token())
+                .any(|token| {
+                    matches!(
+                        token.kind(),
+                        SyntaxKind::KwConstant
+                            | SyntaxKind::KwRetain
+                            | SyntaxKind::KwNonRetain
+                            | SyntaxKind::KwPersistent
+                    )
+                })
+        })
+        .last();
+
+    let Some(block) = block else {
+        let insert_offset = var_block_insert_offset(owner)?;
+        let indent = line_indent_at_offset(source, TextSize::from(insert_offset as u32));
+        let indent_unit = indent_unit_for(&indent);
+        let var_block = build_var_block(&indent, indent_unit, name, type_name);
+        let offset = TextSize::from(insert_offset as u32);
+        return Some(TextEdit {
+            range: TextRange::new(offset, offset),
+            new_text: build_insert_text(source, insert_offset, &var_block),
+        });
+    };
+
+    let end_var = keyword_token(&block, SyntaxKind::KwEndVar)?;
+    let end_offset = end_var.text_range().start();
+    let line_start = source[..usize::from(end_offset)]
+        .rfind('\n')
+        .map_or(0, |index| index + 1);
+    if !source[line_start..usize::from(end_offset)]
+        .trim()
+        .is_empty()
+    {
+        return Some(TextEdit {
+            range: TextRange::new(end_offset, end_offset),
+            new_text: format!("{name} : {type_name}; "),
+        });
+    }
+    let decl_indent = block
+        .children()
+        .filter(|child| child.kind() == SyntaxKind::VarDecl)
+        .last()
+        .map(|decl| line_indent_at_offset(source, node_token_range(&decl).start()))
+        .unwrap_or_else(|| {
+            let end_indent = line_indent_at_offset(source, end_offset);
+            format!("{end_indent}{}", indent_unit_for(&end_indent))
+        });
+    let offset = TextSize::from(line_start as u32);
+    Some(TextEdit {
+        range: TextRange::new(offset, offset),
+        new_text: format!("{decl_indent}{name} : {type_name};\n"),
+    })
+}
+
+/// Collects the ranges to replace with the extracted variable: the selection
+/// itself plus identical occurrences in the same statement list whose value
+/// cannot have changed since the anchor statement started.
+fn extract_variable_occurrences(expr: &SyntaxNode, statement: &SyntaxNode) -> Vec<TextRange> {
+    let selected = node_token_range(expr);
+    let reads = expression_reads(expr);
+    let has_call = expr
+        .descendants()
+        .any(|node| node.kind() == SyntaxKind::CallExpr);
+    if has_call || statement_may_write(statement, &reads) {
+        return vec![selected];
+    }
+
+    let signature = expression_signature(expr);
+    let mut ranges = Vec::new();
+    let siblings = statement
+        .parent()
+        .into_iter()
+        .flat_map(|parent| parent.children())
+        .filter(|node| is_statement_kind(node.kind()))
+        .skip_while(|node| node != statement);
+    for (index, sibling) in siblings.enumerate() {
+        if index > 0 && statement_may_write(&sibling, &reads) {
+            break;
+        }
+        let mut last_end = None;
+        for candidate in sibling
+            .descendants()
+            .filter(|node| is_expression_kind(node.kind()))
+        {
+            let range = node_token_range(&candidate);
+            if last_end.is_some_and(|end| range.start() < end) {
+                continue;
+            }
+            if expression_signature(&candidate) != signature
+                || is_write_context(&candidate)
+                || is_call_name(&candidate)
+                || is_output_arg(&candidate)
+            {
+                continue;
+            }
+            last_end = Some(range.end());
+            ranges.push(range);
+        }
+    }
+    if !ranges.contains(&selected) {
+        ranges.push(selected);
+    }
+    ranges
+}
+
+fn expression_reads(expr: &SyntaxNode) -> FxHashSet<SmolStr> {
+    expr.descendants()
+        .filter(|node| node.kind() == SyntaxKind::NameRef)
+        .map(|node| SmolStr::new(node.text().to_string().trim().to_ascii_uppercase()))
+        .collect()
+}
+
+fn expression_signature(expr: &SyntaxNode) -> Vec<String> {
+    expr.descendants_with_tokens()
+        .filter_map(|element| element.into_token())
+        .filter(|token| !token.kind().is_trivia())
+        .map(|token| token.text().to_string())
+        .collect()
+}
+
+/// Returns true when `statement` calls anything or assigns one of `reads`.
+fn statement_may_write(statement: &SyntaxNode, reads: &FxHashSet<SmolStr>) -> bool {
+    statement.descendants().any(|node| match node.kind() {
+        SyntaxKind::CallExpr => true,
+        SyntaxKind::NameRef => {
+            let name = SmolStr::new(node.text().to_string().trim().to_ascii_uppercase());
+            reads.contains(&name) && is_write_context(&node)
+        }
+        SyntaxKind::Name => {
+            node.parent()
+                .is_some_and(|parent| parent.kind() == SyntaxKind::ForStmt)
+                && reads.contains(&SmolStr::new(
+                    node.text().to_string().trim().to_ascii_uppercase(),
+                ))
+        }
+        _ => false,
+    })
+}
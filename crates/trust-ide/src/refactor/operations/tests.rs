@@ -420,4 +420,102 @@ END_PROGRAM
         let result = convert_function_block_to_function(&db, fb_id, TextSize::from(offset as u32));
         assert!(result.is_none(), "expected conversion to be unavailable");
     }
+
+    #[test]
+    fn extract_variable_declares_local_and_replaces_occurrences() {
+        let source = r#"
+PROGRAM Main
+    VAR
+        x : INT;
+        y : INT;
+        z : INT;
+    END_VAR
+    y := x * 2 + 1;
+    z := x * 2 - 1;
+    x := 0;
+    y := x * 2;
+END_PROGRAM
+"#;
+        let mut db = Database::new();
+        let file_id = FileId(0);
+        db.set_source_text(file_id, source.to_string());
+
+        let start = source.find("x * 2").expect("start");
+        let range = TextRange::new(
+            TextSize::from(start as u32),
+            TextSize::from((start + "x * 2".len()) as u32),
+        );
+        let result = extract_variable(&db, file_id, range, "scaled").expect("extract variable");
+        assert_eq!(result.kind, ExtractTargetKind::Variable);
+        let edits = result.edits.edits.get(&file_id).expect("file edits");
+        assert!(edits
+            .iter()
+            .any(|edit| edit.new_text == "        scaled : INT;\n"));
+        assert!(edits
+            .iter()
+            .any(|edit| edit.new_text == "scaled := x * 2;\n    "));
+        // The write to `x` stops replacement before the last occurrence.
+        assert_eq!(
+            edits
+                .iter()
+                .filter(|edit| edit.new_text == "scaled")
+                .count(),
+            2
+        );
+    }
+
+    #[test]
+    fn extract_variable_creates_var_block_when_missing() {
+        let source = r#"
+FUNCTION Check : BOOL
+    VAR_INPUT
+        a : INT;
+    END_VAR
+    Check := a + 1 > 3;
+END_FUNCTION
+"#;
+        let mut db = Database::new();
+        let file_id = FileId(0);
+        db.set_source_text(file_id, source.to_string());
+
+        let start = source.find("a + 1").expect("start");
+        let range = TextRange::new(
+            TextSize::from(start as u32),
+            TextSize::from((start + "a + 1".len()) as u32),
+        );
+        let result = extract_variable(&db, file_id, range, "next").expect("extract variable");
+        let edits = result.edits.edits.get(&file_id).expect("file edits");
+        assert!(edits.iter().any(|edit| edit
+            .new_text
+            .contains("VAR\n        next : INT;\n    END_VAR")));
+        assert!(edits.iter().any(|edit| edit.new_text == "next"));
+    }
+
+    #[test]
+    fn extract_variable_rejects_statements_and_assignment_targets() {
+        let source = r#"
+PROGRAM Main
+    VAR
+        x : INT;
+        y : INT;
+    END_VAR
+    y := x + 1;
+    x := y;
+END_PROGRAM
+"#;
+        let mut db = Database::new();
+        let file_id = FileId(0);
+        db.set_source_text(file_id, source.to_string());
+
+        let start = source.find("y := x + 1").expect("statement");
+        let end = source.find("x := y;").expect("next statement") + "x := y;".len();
+        let spanning = TextRange::new(TextSize::from(start as u32), TextSize::from(end as u32));
+        assert!(extract_variable(&db, file_id, spanning, "tmp").is_none());
+
+        let target = TextRange::new(
+            TextSize::from(start as u32),
+            TextSize::from((start + 1) as u32),
+        );
+        assert!(extract_variable(&db, file_id, target, "tmp").is_none());
+    }
 }
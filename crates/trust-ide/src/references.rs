@@ -12,8 +12,9 @@ use trust_syntax::syntax::{SyntaxKind, SyntaxNode};
 
 use crate::util::{
     field_declaration_ranges, ident_token_in_name, is_type_name_node, is_type_symbol_kind,
-    qualified_name_from_field_expr, resolve_target_at_position_with_context,
-    resolve_type_symbol_at_node, scope_at_position, FieldTarget, IdeContext, ResolvedTarget,
+    qualified_name_from_field_expr, qualified_name_parts_from_node,
+    resolve_target_at_position_with_context, resolve_type_symbol_at_node, scope_at_position,
+    FieldTarget, IdeContext, ResolvedTarget,
 };
 
 /// A reference to a symbol.
@@ -47,6 +48,21 @@ pub fn find_references(
     position: TextSize,
     options: FindReferencesOptions,
 ) -> Vec<Reference> {
+    find_references_cancellable(db, file_id, position, options, &|| false).unwrap_or_default()
+}
+
+/// Finds all references to the symbol at the given position, polling
+/// `is_cancelled` between files.
+///
+/// Returns `None` once cancellation is observed, so callers never see a
+/// result that covers only part of the project.
+pub fn find_references_cancellable(
+    db: &Database,
+    file_id: FileId,
+    position: TextSize,
+    options: FindReferencesOptions,
+    is_cancelled: &dyn Fn() -> bool,
+) -> Option<Vec<Reference>> {
     let context = IdeContext::new(db, file_id);
 
     if let Some(target) = resolve_target_at_position_with_context(
@@ -61,27 +77,30 @@ pub fn find_references(
             ResolvedTarget::Symbol(symbol_id) => {
                 let symbols = &context.symbols;
                 let Some(target_symbol) = symbols.get(symbol_id) else {
-                    return Vec::new();
+                    return Some(Vec::new());
                 };
                 let Some(identity) = symbol_identity(symbols, symbol_id, file_id) else {
-                    return Vec::new();
+                    return Some(Vec::new());
                 };
                 if is_type_symbol_kind(&target_symbol.kind) {
-                    find_type_references_across_project(db, identity, options)
+                    find_type_references_across_project(db, identity, options, is_cancelled)
                 } else {
                     find_references_to_symbol_across_project(
                         db,
                         identity,
                         target_symbol.name.clone(),
                         options,
+                        is_cancelled,
                     )
                 }
             }
-            ResolvedTarget::Field(field) => find_references_to_field(db, file_id, &field, options),
+            ResolvedTarget::Field(field) => {
+                find_references_to_field(db, file_id, &field, options, is_cancelled)
+            }
         };
     }
 
-    Vec::new()
+    Some(Vec::new())
 }
 
 fn find_references_to_symbol_across_project(
@@ -89,7 +108,8 @@ fn find_references_to_symbol_across_project(
     identity: SymbolIdentity,
     target_name: SmolStr,
     options: FindReferencesOptions,
-) -> Vec<Reference> {
+    is_cancelled: &dyn Fn() -> bool,
+) -> Option<Vec<Reference>> {
     let mut references = Vec::new();
     if options.include_declaration {
         if let Some(reference) = declaration_reference(db, identity) {
@@ -98,6 +118,9 @@ fn find_references_to_symbol_across_project(
     }
 
     for other_file_id in db.file_ids() {
+        if is_cancelled() {
+            return None;
+        }
         references.extend(find_references_to_symbol_in_file_by_identity(
             db,
             other_file_id,
@@ -106,7 +129,7 @@ fn find_references_to_symbol_across_project(
         ));
     }
 
-    references
+    Some(references)
 }
 
 fn find_references_to_symbol_in_file_by_identity(
@@ -172,6 +195,21 @@ fn find_references_to_symbol_in_file_by_identity(
         }
     }
 
+    for node in root
+        .descendants()
+        .filter(|n| n.kind() == SyntaxKind::AccessPath)
+    {
+        for (segment_id, range) in resolve_access_path_segments(&symbols, &root, &node) {
+            if symbol_identity(&symbols, segment_id, file_id) == Some(identity) {
+                references.push(Reference {
+                    file_id,
+                    range,
+                    is_write: false,
+                });
+            }
+        }
+    }
+
     references
 }
 
@@ -179,7 +217,8 @@ fn find_type_references_across_project(
     db: &Database,
     identity: SymbolIdentity,
     options: FindReferencesOptions,
-) -> Vec<Reference> {
+    is_cancelled: &dyn Fn() -> bool,
+) -> Option<Vec<Reference>> {
     let mut references = Vec::new();
     if options.include_declaration {
         if let Some(reference) = declaration_reference(db, identity) {
@@ -188,6 +227,9 @@ fn find_type_references_across_project(
     }
 
     for other_file_id in db.file_ids() {
+        if is_cancelled() {
+            return None;
+        }
         references.extend(find_type_references_in_file_by_identity(
             db,
             other_file_id,
@@ -195,7 +237,7 @@ fn find_type_references_across_project(
         ));
     }
 
-    references
+    Some(references)
 }
 
 fn find_type_references_in_file_by_identity(
@@ -363,6 +405,21 @@ fn find_references_to_symbol_in_context(
         }
     }
 
+    for node in root
+        .descendants()
+        .filter(|n| n.kind() == SyntaxKind::AccessPath)
+    {
+        for (segment_id, range) in resolve_access_path_segments(symbols, root, &node) {
+            if segment_id == symbol_id {
+                references.push(Reference {
+                    file_id,
+                    range,
+                    is_write: false,
+                });
+            }
+        }
+    }
+
     references
 }
 
@@ -371,11 +428,15 @@ pub(crate) fn find_references_to_field(
     file_id: FileId,
     target: &FieldTarget,
     options: FindReferencesOptions,
-) -> Vec<Reference> {
+    is_cancelled: &dyn Fn() -> bool,
+) -> Option<Vec<Reference>> {
     let _ = file_id;
     let mut references = Vec::new();
 
     for other_file_id in db.file_ids() {
+        if is_cancelled() {
+            return None;
+        }
         let source = db.source_text(other_file_id);
         let parsed = parse(&source);
         let root = parsed.syntax();
@@ -392,7 +453,7 @@ pub(crate) fn find_references_to_field(
         ));
     }
 
-    references
+    Some(references)
 }
 
 fn find_references_to_field_in_context(
@@ -648,6 +709,129 @@ fn resolve_field_expr_qualified_symbol(
     Some((symbol_id, range))
 }
 
+/// Resolves the segments of a `VAR_ACCESS`/`VAR_CONFIG` access path such as
+/// `Station.Motor.Speed` to their declaring symbols, one entry per segment.
+///
+/// Resolution stops at the first segment that is not backed by a symbol
+/// (struct fields, bit offsets, direct addresses).
+fn resolve_access_path_segments(
+    symbols: &trust_hir::symbols::SymbolTable,
+    root: &SyntaxNode,
+    node: &SyntaxNode,
+) -> Vec<(SymbolId, TextRange)> {
+    let mut segments = Vec::new();
+    let mut owner: Option<SymbolId> = None;
+    let mut owner_type = TypeId::UNKNOWN;
+
+    for element in node.children_with_tokens() {
+        if let Some(token) = element.as_token() {
+            match token.kind() {
+                SyntaxKind::DirectAddress | SyntaxKind::IntLiteral => break,
+                SyntaxKind::RBracket => {
+                    let Some(Type::Array {
+                        element: element_type,
+                        ..
+                    }) = symbols.type_by_id(symbols.resolve_alias_type(owner_type))
+                    else {
+                        break;
+                    };
+                    owner = None;
+                    owner_type = *element_type;
+                }
+                _ => {}
+            }
+            continue;
+        }
+
+        let Some(child) = element.as_node() else {
+            continue;
+        };
+        if child.kind() != SyntaxKind::Name {
+            continue;
+        }
+        let Some(ident) = ident_token_in_name(child) else {
+            break;
+        };
+        let resolved = if segments.is_empty() {
+            symbols.lookup_any(ident.text())
+        } else {
+            access_path_member(symbols, root, owner, owner_type, ident.text())
+        };
+        let Some(symbol_id) = resolved else {
+            break;
+        };
+        segments.push((symbol_id, ident.text_range()));
+        owner = Some(symbol_id);
+        owner_type = symbols.get(symbol_id).map_or(TypeId::UNKNOWN, |symbol| {
+            symbols.resolve_alias_type(symbol.type_id)
+        });
+    }
+
+    segments
+}
+
+fn access_path_member(
+    symbols: &trust_hir::symbols::SymbolTable,
+    root: &SyntaxNode,
+    owner: Option<SymbolId>,
+    owner_type: TypeId,
+    name: &str,
+) -> Option<SymbolId> {
+    if let Some(owner) = owner.and_then(|id| symbols.get(id)) {
+        match owner.kind {
+            trust_hir::symbols::SymbolKind::Namespace | trust_hir::symbols::SymbolKind::Program => {
+                return symbols.resolve_member_symbol_in_hierarchy(owner.id, name);
+            }
+            trust_hir::symbols::SymbolKind::ProgramInstance => {
+                let program = program_config_target(symbols, root, owner.name.as_str())?;
+                return symbols.resolve_member_symbol_in_hierarchy(program, name);
+            }
+            _ => {}
+        }
+    }
+    symbols.resolve_member_symbol_in_type(owner_type, name)
+}
+
+/// Resolves the program type instantiated by `PROGRAM <instance> : <type>`.
+fn program_config_target(
+    symbols: &trust_hir::symbols::SymbolTable,
+    root: &SyntaxNode,
+    instance: &str,
+) -> Option<SymbolId> {
+    let config = root
+        .descendants()
+        .filter(|n| n.kind() == SyntaxKind::ProgramConfig)
+        .find(|n| {
+            n.children()
+                .find(|child| child.kind() == SyntaxKind::Name)
+                .and_then(|name| ident_token_in_name(&name))
+                .is_some_and(|ident| ident.text().eq_ignore_ascii_case(instance))
+        })?;
+    let type_node = config
+        .children_with_tokens()
+        .skip_while(|element| element.kind() != SyntaxKind::Colon)
+        .find_map(|element| element.into_node())?;
+    let name_node = if type_node.kind() == SyntaxKind::TypeRef {
+        type_node
+            .children()
+            .find(|n| matches!(n.kind(), SyntaxKind::Name | SyntaxKind::QualifiedName))?
+    } else {
+        type_node
+    };
+    let parts = qualified_name_parts_from_node(&name_node)?;
+    let program = symbols
+        .resolve_qualified(&parts)
+        .or_else(|| match parts.as_slice() {
+            [name] => symbols.lookup_any(name.as_str()),
+            _ => None,
+        })?;
+    matches!(
+        symbols.get(program)?.kind,
+        trust_hir::symbols::SymbolKind::Program
+    )
+    .then_some(program)
+}
+
 fn is_terminal_type_name(name_node: &SyntaxNode) -> bool {
     let Some(parent) = name_node.parent() else {
         return true;
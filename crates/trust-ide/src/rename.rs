@@ -6,7 +6,9 @@ use std::collections::HashMap;
 use text_size::{TextRange, TextSize};
 
 use crate::refactor::{move_namespace_path, namespace_full_path, parse_namespace_path};
-use crate::references::{find_references, find_references_to_field, FindReferencesOptions};
+use crate::references::{
+    find_references_cancellable, find_references_to_field, FindReferencesOptions,
+};
 use crate::util::{ident_at_offset, resolve_target_at_position, FieldTarget, ResolvedTarget};
 use trust_hir::db::{FileId, SemanticDatabase};
 use trust_hir::symbols::{ScopeId, SymbolTable};
@@ -70,6 +72,21 @@ pub fn rename(
     file_id: FileId,
     position: TextSize,
     new_name: &str,
+) -> Option<RenameResult> {
+    rename_cancellable(db, file_id, position, new_name, &|| false)
+}
+
+/// Performs a rename operation, polling `is_cancelled` while references are
+/// collected across the project.
+///
+/// Returns `None` when cancelled; a cancelled rename never yields edits for
+/// only some of the occurrences.
+pub fn rename_cancellable(
+    db: &Database,
+    file_id: FileId,
+    position: TextSize,
+    new_name: &str,
+    is_cancelled: &dyn Fn() -> bool,
 ) -> Option<RenameResult> {
     let target = resolve_target_at_position(db, file_id, position)?;
 
@@ -100,9 +117,9 @@ pub fn rename(
             if has_conflict(&symbols, symbol_id, new_name) {
                 return None;
             }
-            rename_symbol(db, file_id, symbol_id, new_name)
+            rename_symbol_cancellable(db, file_id, symbol_id, new_name, is_cancelled)
         }
-        ResolvedTarget::Field(field) => rename_field(db, file_id, &field, new_name),
+        ResolvedTarget::Field(field) => rename_field(db, file_id, &field, new_name, is_cancelled),
     }
 }
 
@@ -112,6 +129,16 @@ pub fn rename_symbol(
     file_id: FileId,
     symbol_id: SymbolId,
     new_name: &str,
+) -> Option<RenameResult> {
+    rename_symbol_cancellable(db, file_id, symbol_id, new_name, &|| false)
+}
+
+fn rename_symbol_cancellable(
+    db: &Database,
+    file_id: FileId,
+    symbol_id: SymbolId,
+    new_name: &str,
+    is_cancelled: &dyn Fn() -> bool,
 ) -> Option<RenameResult> {
     if !is_valid_identifier(new_name) {
         return None;
@@ -134,14 +161,15 @@ pub fn rename_symbol(
     let mut result = RenameResult::new();
 
     // Find all references across project (including declaration)
-    let references = find_references(
+    let references = find_references_cancellable(
         db,
         target_file_id,
         target_position,
         FindReferencesOptions {
             include_declaration: true,
         },
-    );
+        is_cancelled,
+    )?;
 
     // Create edits for all references
     for reference in references {
@@ -162,6 +190,7 @@ fn rename_field(
     file_id: FileId,
     field: &FieldTarget,
     new_name: &str,
+    is_cancelled: &dyn Fn() -> bool,
 ) -> Option<RenameResult> {
     if !is_valid_identifier(new_name) || is_reserved_keyword(new_name) {
         return None;
@@ -179,7 +208,8 @@ fn rename_field(
         FindReferencesOptions {
             include_declaration: true,
         },
-    );
+        is_cancelled,
+    )?;
 
     if references.is_empty() {
         return None;
@@ -5,7 +5,7 @@
 use text_size::{TextRange, TextSize};
 
 use trust_hir::db::{FileId, SemanticDatabase};
-use trust_hir::symbols::{SymbolKind, SymbolTable};
+use trust_hir::symbols::{Symbol, SymbolKind, SymbolTable};
 use trust_hir::{Database, SourceDatabase};
 use trust_syntax::parser::parse;
 use trust_syntax::syntax::{SyntaxKind, SyntaxNode};
@@ -59,6 +59,8 @@ pub struct SemanticTokenModifiers {
     pub is_static: bool,
     /// This is a modification (write).
     pub modification: bool,
+    /// The symbol is marked `{attribute 'deprecated'}`.
+    pub deprecated: bool,
 }
 
 /// A semantic token.
@@ -120,6 +122,17 @@ fn symbol_kind_to_token_type(kind: &SymbolKind) -> SemanticTokenType {
     }
 }
 
+/// Sets the modifiers that follow from the symbol itself rather than from
+/// where it is referenced.
+fn apply_symbol_modifiers(symbol: &Symbol, modifiers: &mut SemanticTokenModifiers) {
+    if matches!(symbol.kind, SymbolKind::Constant) {
+        modifiers.readonly = true;
+    }
+    if symbol.deprecated.is_some() {
+        modifiers.deprecated = true;
+    }
+}
+
 /// Classifies an identifier token based on semantic analysis.
 fn classify_identifier(
     db: &Database,
@@ -138,9 +151,7 @@ fn classify_identifier(
     // Check if this is a declaration site (range matches a symbol's range)
     if let Some(symbol) = filter.symbol_at_range(range) {
         modifiers.declaration = true;
-        if matches!(symbol.kind, SymbolKind::Constant) {
-            modifiers.readonly = true;
-        }
+        apply_symbol_modifiers(symbol, &mut modifiers);
         return (symbol_kind_to_token_type(&symbol.kind), modifiers);
     }
 
@@ -153,9 +164,7 @@ fn classify_identifier(
             match target {
                 ResolvedTarget::Symbol(symbol_id) => {
                     if let Some(symbol) = symbols.get(symbol_id) {
-                        if matches!(symbol.kind, SymbolKind::Constant) {
-                            modifiers.readonly = true;
-                        }
+                        apply_symbol_modifiers(symbol, &mut modifiers);
                         if symbol.range == range {
                             modifiers.declaration = true;
                         }
@@ -178,17 +187,13 @@ fn classify_identifier(
     // Find the scope at this position and resolve the name
     let scope_id = scope_at_position(symbols, root, offset);
     if let Some(symbol) = filter.resolve_in_scope(name, scope_id) {
-        if matches!(symbol.kind, SymbolKind::Constant) {
-            modifiers.readonly = true;
-        }
+        apply_symbol_modifiers(symbol, &mut modifiers);
         return (symbol_kind_to_token_type(&symbol.kind), modifiers);
     }
 
     // Fallback: try global lookup
     if let Some(symbol) = filter.lookup_any(name) {
-        if matches!(symbol.kind, SymbolKind::Constant) {
-            modifiers.readonly = true;
-        }
+        apply_symbol_modifiers(symbol, &mut modifiers);
         return (symbol_kind_to_token_type(&symbol.kind), modifiers);
     }
 
@@ -326,6 +331,10 @@ pub fn semantic_tokens(db: &Database, file_id: FileId) -> Vec<SemanticToken> {
             | TokenKind::KwExit
             | TokenKind::KwContinue
             | TokenKind::KwJmp
+            | TokenKind::KwTryDunder
+            | TokenKind::KwCatchDunder
+            | TokenKind::KwFinallyDunder
+            | TokenKind::KwEndTryDunder
             | TokenKind::KwStep
             | TokenKind::KwEndStep
             | TokenKind::KwInitialStep
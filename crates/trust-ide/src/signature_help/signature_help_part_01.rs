@@ -3,7 +3,7 @@ use smol_str::SmolStr;
 use text_size::{TextRange, TextSize};
 
 use trust_hir::db::{FileId, SemanticDatabase};
-use trust_hir::symbols::{ParamDirection, Symbol, SymbolKind, SymbolTable};
+use trust_hir::symbols::{ParamDirection, Symbol, SymbolId, SymbolKind, SymbolTable};
 use trust_hir::{Database, SourceDatabase, TypeId};
 use trust_syntax::parser::parse;
 use trust_syntax::syntax::{SyntaxKind, SyntaxNode, SyntaxToken};
@@ -16,7 +16,8 @@ use crate::util::{
 /// Signature help result for a call site.
 #[derive(Debug, Clone)]
 pub struct SignatureHelpResult {
-    /// All available signatures (usually a single entry).
+    /// All available signatures; overloaded standard functions list one
+    /// entry per parameter shape.
     pub signatures: Vec<Signature>,
     /// The active signature index.
     pub active_signature: usize,
@@ -63,6 +64,8 @@ pub(crate) struct ParamData {
     pub(crate) name: SmolStr,
     pub(crate) type_id: TypeId,
     pub(crate) direction: ParamDirection,
+    /// Declaring parameter symbol; `None` for standard library signatures.
+    pub(crate) symbol: Option<SymbolId>,
 }
 
 #[derive(Debug, Clone)]
@@ -215,14 +218,32 @@ pub fn signature_help(
 
     let args = collect_call_args(&arg_list);
     let arg_types = arg_types_for_args(db, file_id, &args);
-    let signature = apply_arg_types(&signature, &arg_types);
     let formal_call = args.iter().any(|arg| arg.name.is_some());
-    let signature = if formal_call {
-        signature
-    } else {
-        strip_execution_params(&signature)
-    };
     let active_arg = active_arg_index(&args, &arg_list, position);
+    let candidates: Vec<SignatureInfo> =
+        match standard_overloads(&signature, args.len().max(active_arg + 1)) {
+            Some(overloads) => overloads
+                .into_iter()
+                .flat_map(|overload| {
+                    let instantiated =
+                        instantiate_overload(&symbols, &overload, &args, &arg_types);
+                    std::iter::once(overload).chain(instantiated)
+                })
+                .collect(),
+            None => vec![apply_arg_types(&signature, &arg_types)],
+        };
+    let candidates: Vec<SignatureInfo> = candidates
+        .into_iter()
+        .map(|signature| {
+            if formal_call {
+                signature
+            } else {
+                strip_execution_params(&signature)
+            }
+        })
+        .collect();
+    let active_signature = best_overload(&symbols, &candidates, &args, &arg_types, active_arg);
+    let signature = &candidates[active_signature];
     let mut active_param = active_param_index(&args, active_arg, &signature.params);
     if signature.params.is_empty() {
         active_param = 0;
@@ -230,19 +251,23 @@ pub fn signature_help(
         active_param = signature.params.len() - 1;
     }
 
-    let label = format_signature_label(&symbols, &signature);
-    let parameters = signature
-        .params
+    let signatures = candidates
         .iter()
-        .map(|param| SignatureParameter {
-            label: format_param_label(&symbols, param),
+        .map(|signature| Signature {
+            label: format_signature_label(&symbols, signature),
+            parameters: signature
+                .params
+                .iter()
+                .map(|param| SignatureParameter {
+                    label: format_param_label(&symbols, param),
+                })
+                .collect(),
         })
         .collect();
 
     Some(SignatureHelpResult {
-        signatures: vec![Signature { label, parameters }],
-        active_signature: 0,
+        signatures,
+        active_signature,
         active_parameter: active_param,
     })
 }
-
@@ -184,6 +184,22 @@ fn signature_from_symbol(symbols: &SymbolTable, symbol: &Symbol) -> Option<Signa
     })
 }
 
+/// Formats the call signature of a callable symbol, e.g. `Scale(x: REAL) : REAL`.
+pub(crate) fn symbol_signature_label(symbols: &SymbolTable, symbol: &Symbol) -> Option<String> {
+    let signature = match symbol.kind {
+        SymbolKind::Function { .. } | SymbolKind::Method { .. } => {
+            signature_from_symbol(symbols, symbol)?
+        }
+        SymbolKind::FunctionBlock => SignatureInfo {
+            name: symbol.name.clone(),
+            params: callable_params(symbols, symbol),
+            return_type: None,
+        },
+        _ => return None,
+    };
+    Some(format_signature_label(symbols, &signature))
+}
+
 fn signature_from_type(symbols: &SymbolTable, type_id: TypeId) -> Option<SignatureInfo> {
     let symbol = symbols.iter().find(|sym| {
         sym.type_id == type_id
@@ -202,35 +218,17 @@ fn signature_from_type(symbols: &SymbolTable, type_id: TypeId) -> Option<Signatu
 }
 
 fn callable_params(symbols: &SymbolTable, symbol: &Symbol) -> Vec<ParamData> {
-    let mut ids: Vec<_> = match &symbol.kind {
-        SymbolKind::Function { parameters, .. } | SymbolKind::Method { parameters, .. } => {
-            parameters.clone()
-        }
-        _ => Vec::new(),
-    };
-
-    if ids.is_empty() {
-        ids = symbols
-            .iter()
-            .filter(|sym| {
-                sym.parent == Some(symbol.id) && matches!(sym.kind, SymbolKind::Parameter { .. })
-            })
-            .map(|sym| sym.id)
-            .collect();
-    }
-
-    ids.sort_by_key(|id| id.0);
-    ids.into_iter()
-        .filter_map(|id| {
-            let sym = symbols.get(id)?;
-            match sym.kind {
-                SymbolKind::Parameter { direction } => Some(ParamData {
-                    name: sym.name.clone(),
-                    type_id: sym.type_id,
-                    direction,
-                }),
-                _ => None,
-            }
+    symbols
+        .parameters_of(symbol.id)
+        .into_iter()
+        .filter_map(|sym| match sym.kind {
+            SymbolKind::Parameter { direction } => Some(ParamData {
+                name: sym.name.clone(),
+                type_id: sym.type_id,
+                direction,
+                symbol: Some(sym.id),
+            }),
+            _ => None,
         })
         .collect()
 }
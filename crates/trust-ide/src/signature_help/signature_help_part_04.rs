@@ -89,6 +89,7 @@ fn param(name: &str, type_id: TypeId) -> ParamData {
         name: SmolStr::new(name),
         type_id,
         direction: ParamDirection::In,
+        symbol: None,
     }
 }
 
@@ -97,6 +98,7 @@ fn out_param(name: &str, type_id: TypeId) -> ParamData {
         name: SmolStr::new(name),
         type_id,
         direction: ParamDirection::Out,
+        symbol: None,
     }
 }
 
@@ -123,3 +125,225 @@ fn mux_params(arg_count: usize) -> Vec<ParamData> {
 fn time_binary(lhs: TypeId, rhs: TypeId) -> Vec<ParamData> {
     vec![param("IN1", lhs), param("IN2", rhs)]
 }
+
+/// Parameter layout of a standard function whose signature depends on the
+/// argument count or argument types.
+///
+/// Mirrors the runtime registry's `StdParams::Variadic`: fixed parameters
+/// followed by `prefix{start}`, `prefix{start + 1}`, ... with at least `min`
+/// and at most `max` variadic inputs.
+struct StandardShape {
+    fixed: Vec<ParamData>,
+    prefix: &'static str,
+    start: usize,
+    min: usize,
+    max: Option<usize>,
+    value_type: TypeId,
+    return_type: Option<TypeId>,
+}
+
+fn standard_shape(name: &str) -> Option<StandardShape> {
+    let variadic = |prefix, start, min, value_type, return_type| StandardShape {
+        fixed: Vec::new(),
+        prefix,
+        start,
+        min,
+        max: None,
+        value_type,
+        return_type,
+    };
+    let shape = match name.to_ascii_uppercase().as_str() {
+        "SEL" => StandardShape {
+            fixed: vec![param("G", TypeId::BOOL)],
+            max: Some(2),
+            ..variadic("IN", 0, 2, TypeId::ANY, None)
+        },
+        "MUX" => StandardShape {
+            fixed: vec![param("K", TypeId::ANY_INT)],
+            ..variadic("IN", 0, 2, TypeId::ANY, None)
+        },
+        "LIMIT" => StandardShape {
+            fixed: vec![
+                param("MN", TypeId::ANY_ELEMENTARY),
+                param("IN", TypeId::ANY_ELEMENTARY),
+                param("MX", TypeId::ANY_ELEMENTARY),
+            ],
+            max: Some(0),
+            ..variadic("IN", 0, 0, TypeId::ANY_ELEMENTARY, None)
+        },
+        "MIN" | "MAX" => variadic("IN", 1, 2, TypeId::ANY_ELEMENTARY, None),
+        "ADD" | "MUL" => variadic("IN", 1, 2, TypeId::ANY, None),
+        "AND" | "OR" | "XOR" => variadic("IN", 1, 2, TypeId::ANY_BIT, None),
+        "GT" | "GE" | "EQ" | "LE" | "LT" => {
+            variadic("IN", 1, 2, TypeId::ANY_ELEMENTARY, Some(TypeId::BOOL))
+        }
+        "CONCAT" => variadic("IN", 1, 2, TypeId::ANY_STRING, None),
+        _ => return None,
+    };
+    Some(shape)
+}
+
+/// Lists the parameter shapes of an overloaded standard function, one per
+/// variadic input count up to one more than `needed` arguments.
+///
+/// Returns `None` unless `signature` is a standard library signature with a
+/// known shape, so user-defined callables keep their single signature.
+fn standard_overloads(signature: &SignatureInfo, needed: usize) -> Option<Vec<SignatureInfo>> {
+    if signature.params.iter().any(|param| param.symbol.is_some()) {
+        return None;
+    }
+    let shape = standard_shape(signature.name.as_str())?;
+    let wanted = needed.saturating_sub(shape.fixed.len()).max(shape.min) + 1;
+    let largest = shape.max.map_or(wanted, |max| wanted.min(max));
+    let overloads = (shape.min..=largest)
+        .map(|count| {
+            let mut params = shape.fixed.clone();
+            params.extend((shape.start..shape.start + count).map(|index| {
+                param(&format!("{}{}", shape.prefix, index), shape.value_type)
+            }));
+            SignatureInfo {
+                name: signature.name.clone(),
+                params,
+                return_type: shape.return_type,
+            }
+        })
+        .collect();
+    Some(overloads)
+}
+
+/// Binds each generic parameter family of `overload` to the first typed
+/// argument passed for it. The return type follows the family of the last
+/// parameter, which holds the selected or combined values.
+///
+/// Returns `None` when no argument narrows a family.
+fn instantiate_overload(
+    symbols: &SymbolTable,
+    overload: &SignatureInfo,
+    args: &[ArgInfo],
+    arg_types: &[Option<TypeId>],
+) -> Option<SignatureInfo> {
+    let mut bindings: Vec<(TypeId, TypeId)> = Vec::new();
+    for (index, arg_type) in arg_types.iter().enumerate() {
+        let Some(arg_type) = *arg_type else {
+            continue;
+        };
+        let Some(param) = bound_param(args, index, &overload.params) else {
+            continue;
+        };
+        let family = overload.params[param].type_id;
+        if !is_generic_type(family)
+            || is_generic_type(arg_type)
+            || arg_type == TypeId::UNKNOWN
+            || bindings.iter().any(|(bound, _)| *bound == family)
+            || !arg_fits_param(symbols, family, arg_type)
+        {
+            continue;
+        }
+        bindings.push((family, arg_type));
+    }
+    if bindings.is_empty() {
+        return None;
+    }
+
+    let bound_type = |type_id: TypeId| {
+        bindings
+            .iter()
+            .find(|(family, _)| *family == type_id)
+            .map(|(_, bound)| *bound)
+    };
+    let mut instantiated = overload.clone();
+    for param in &mut instantiated.params {
+        if let Some(bound) = bound_type(param.type_id) {
+            param.type_id = bound;
+        }
+    }
+    if overload.return_type.is_none_or(is_generic_type) {
+        let value_family = overload.params.last().map(|param| param.type_id);
+        if let Some(bound) = value_family.and_then(bound_type) {
+            instantiated.return_type = Some(bound);
+        }
+    }
+    Some(instantiated)
+}
+
+/// Picks the candidate whose arity admits every argument up to the active
+/// one and whose parameter types accept the typed arguments, preferring the
+/// fewest parameters and then the most specific parameter types.
+fn best_overload(
+    symbols: &SymbolTable,
+    candidates: &[SignatureInfo],
+    args: &[ArgInfo],
+    arg_types: &[Option<TypeId>],
+    active_arg: usize,
+) -> usize {
+    let needed = args.len().max(active_arg + 1);
+    let score = |signature: &SignatureInfo| {
+        let named_fit = args.iter().all(|arg| {
+            arg.name.as_ref().is_none_or(|name| {
+                signature
+                    .params
+                    .iter()
+                    .any(|param| param.name.eq_ignore_ascii_case(name))
+            })
+        });
+        let fits = named_fit && signature.params.len() >= needed;
+        let mismatches = arg_types
+            .iter()
+            .enumerate()
+            .filter(|(index, arg_type)| {
+                let Some(arg_type) = arg_type else {
+                    return false;
+                };
+                bound_param(args, *index, &signature.params).is_none_or(|param| {
+                    !arg_fits_param(symbols, signature.params[param].type_id, *arg_type)
+                })
+            })
+            .count();
+        let generic = signature
+            .params
+            .iter()
+            .filter(|param| is_generic_type(param.type_id))
+            .count();
+        (
+            std::cmp::Reverse(fits),
+            mismatches,
+            signature.params.len(),
+            generic,
+        )
+    };
+    candidates
+        .iter()
+        .enumerate()
+        .min_by_key(|(_, signature)| score(signature))
+        .map_or(0, |(index, _)| index)
+}
+
+fn bound_param(args: &[ArgInfo], index: usize, params: &[ParamData]) -> Option<usize> {
+    match args.get(index).and_then(|arg| arg.name.as_ref()) {
+        Some(name) => params
+            .iter()
+            .position(|param| param.name.eq_ignore_ascii_case(name)),
+        None => (index < params.len()).then_some(index),
+    }
+}
+
+/// Returns true when an argument of `arg_type` can bind to a parameter of
+/// `param_type`. Unresolved types are accepted.
+fn arg_fits_param(symbols: &SymbolTable, param_type: TypeId, arg_type: TypeId) -> bool {
+    let arg_type = symbols.resolve_alias_type(arg_type);
+    if arg_type == TypeId::UNKNOWN || arg_type == param_type {
+        return true;
+    }
+    let Some(arg) = symbols.type_by_id(arg_type) else {
+        return true;
+    };
+    match param_type {
+        TypeId::ANY_INT => arg.is_integer(),
+        TypeId::ANY_NUM => arg.is_numeric(),
+        TypeId::ANY_REAL => arg.is_float(),
+        TypeId::ANY_BIT => arg.is_bit_string(),
+        TypeId::ANY_STRING => arg.is_string(),
+        TypeId::ANY_ELEMENTARY => arg.is_elementary(),
+        _ => is_generic_type(param_type),
+    }
+}
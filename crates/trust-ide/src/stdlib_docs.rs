@@ -259,6 +259,9 @@ static STANDARD_FUNCTIONS: Lazy<Vec<StdlibFunctionEntry>> = Lazy::new(|| {
     for dst in NUMERIC_TYPES {
         push(format!("BOOL_TO_{dst}"), DOC_CONVERSIONS);
     }
+    for src in INTEGER_TYPES.iter().chain(BIT_TYPES.iter()) {
+        push(format!("{src}_TO_BOOL"), DOC_CONVERSIONS);
+    }
 
     // Table 26: Time/date conversions
     for (src, dst) in [
@@ -73,19 +73,16 @@ pub(crate) fn name_from_name_node(node: &SyntaxNode) -> Option<SmolStr> {
 }
 
 pub(crate) fn namespace_path_for_symbol(symbols: &SymbolTable, symbol: &Symbol) -> Vec<SmolStr> {
-    let mut parts = Vec::new();
-    let mut current = symbol.parent;
-    while let Some(parent_id) = current {
-        let Some(parent) = symbols.get(parent_id) else {
-            break;
-        };
-        if matches!(parent.kind, SymbolKind::Namespace) {
-            parts.push(parent.name.clone());
-        }
-        current = parent.parent;
-    }
-    parts.reverse();
-    parts
+    symbols.namespace_path_of(symbol.id)
+}
+
+/// Returns the dotted path of the symbol's parent, e.g. `Plant.Motors.FB_Pump`
+/// for a method of `FB_Pump` in namespace `Plant.Motors`.
+pub fn symbol_container_path(symbols: &SymbolTable, symbol: &Symbol) -> Option<String> {
+    symbol
+        .parent
+        .map(|parent_id| symbols.qualified_name(parent_id))
+        .filter(|path| !path.is_empty())
 }
 
 pub(crate) fn using_path_for_symbol(
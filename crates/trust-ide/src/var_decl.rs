@@ -85,10 +85,14 @@ fn find_var_decl_for_name(root: &SyntaxNode, name: &str) -> Option<SyntaxNode> {
         })
 }
 
-pub(crate) fn initializer_from_var_decl(source: &str, var_decl: &SyntaxNode) -> Option<String> {
-    let expr = var_decl
+pub(crate) fn initializer_expr_from_var_decl(var_decl: &SyntaxNode) -> Option<SyntaxNode> {
+    var_decl
         .children()
-        .find(|node| is_expression_kind(node.kind()))?;
+        .find(|node| is_expression_kind(node.kind()))
+}
+
+pub(crate) fn initializer_from_var_decl(source: &str, var_decl: &SyntaxNode) -> Option<String> {
+    let expr = initializer_expr_from_var_decl(var_decl)?;
     let text = text_for_range(source, expr.text_range());
     (!text.is_empty()).then_some(text)
 }
@@ -7,9 +7,9 @@ use trust_hir::Database;
 use trust_ide::completion::complete;
 use trust_ide::hover;
 use trust_ide::references::{find_references, FindReferencesOptions};
-use trust_ide::rename::rename;
+use trust_ide::rename::{rename, RenameResult};
 use trust_ide::semantic_tokens::{semantic_tokens, SemanticTokenType};
-use trust_ide::{goto_definition, goto_implementation};
+use trust_ide::{goto_definition, goto_implementation, implementors_of_name};
 
 fn setup(source: &str) -> (Database, FileId) {
     let mut db = Database::new();
@@ -197,6 +197,67 @@ END_FUNCTION_BLOCK
         .any(|res| res.range.start() == TextSize::from(impl_start)));
 }
 
+#[test]
+fn test_goto_implementation_follows_extends_chains() {
+    let source = r#"
+INTERFACE IBase
+END_INTERFACE
+
+INTERFACE IDerived EXTENDS IBase
+END_INTERFACE
+
+FUNCTION_BLOCK Direct IMPLEMENTS IBase
+END_FUNCTION_BLOCK
+
+FUNCTION_BLOCK ViaInterface IMPLEMENTS IDerived
+END_FUNCTION_BLOCK
+
+FUNCTION_BLOCK Derived EXTENDS Direct
+END_FUNCTION_BLOCK
+
+FUNCTION_BLOCK Unrelated
+END_FUNCTION_BLOCK
+"#;
+    let (db, file) = setup(source);
+    let pos = TextSize::from(source.find("IBase").unwrap() as u32);
+    let mut found: Vec<(&str, bool)> = goto_implementation(&db, file, pos)
+        .iter()
+        .map(|res| (&source[res.range], res.indirect))
+        .collect();
+    found.sort();
+    assert_eq!(
+        found,
+        vec![
+            ("Derived", true),
+            ("Direct", false),
+            ("ViaInterface", false)
+        ]
+    );
+}
+
+#[test]
+fn test_implementors_of_name_scans_all_files() {
+    let interfaces = r#"
+NAMESPACE Plant
+INTERFACE IPump
+END_INTERFACE
+END_NAMESPACE
+"#;
+    let pumps = r#"
+FUNCTION_BLOCK Pump IMPLEMENTS Plant.IPump
+END_FUNCTION_BLOCK
+"#;
+    let mut db = Database::new();
+    db.set_source_text(FileId(0), interfaces.to_string());
+    db.set_source_text(FileId(1), pumps.to_string());
+
+    let results = implementors_of_name(&db, "Plant.IPump");
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].file_id, FileId(1));
+    assert_eq!(&pumps[results[0].range], "Pump");
+    assert!(!results[0].indirect);
+}
+
 // =============================================================================
 // References Tests
 // =============================================================================
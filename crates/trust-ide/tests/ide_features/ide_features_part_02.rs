@@ -233,3 +233,118 @@ END_PROGRAM
         "Should rename declaration and usage"
     );
 }
+
+fn edit_starts(result: &RenameResult, file: FileId) -> Vec<usize> {
+    let mut starts: Vec<_> = result.edits[&file]
+        .iter()
+        .map(|edit| u32::from(edit.range.start()) as usize)
+        .collect();
+    starts.sort_unstable();
+    starts
+}
+
+#[test]
+fn test_rename_updates_middle_access_path_segment() {
+    let source = r#"
+FUNCTION_BLOCK MotorFb
+VAR_OUTPUT
+    Speed : INT;
+END_VAR
+Speed := 1;
+END_FUNCTION_BLOCK
+
+PROGRAM Main
+VAR
+    Motor : MotorFb;
+END_VAR
+Motor();
+END_PROGRAM
+
+CONFIGURATION Plant
+RESOURCE Cpu ON PLC
+    TASK Fast (INTERVAL := T#10ms, PRIORITY := 1);
+    PROGRAM Station WITH Fast : Main;
+END_RESOURCE
+VAR_ACCESS
+    MotorSpeed : Station.Motor.Speed : INT READ_ONLY;
+END_VAR
+VAR_CONFIG
+    Station.Motor.Speed : INT := 5;
+END_VAR
+END_CONFIGURATION
+"#;
+    let (db, file) = setup(source);
+    let pos = TextSize::from(source.find("Motor : MotorFb").unwrap() as u32);
+
+    let result = rename(&db, file, pos, "Drive").expect("rename should succeed");
+    for edit in &result.edits[&file] {
+        let start = u32::from(edit.range.start()) as usize;
+        let end = u32::from(edit.range.end()) as usize;
+        assert_eq!(&source[start..end], "Motor");
+    }
+
+    let starts = edit_starts(&result, file);
+    let path_segments: Vec<_> = source
+        .match_indices("Station.Motor.Speed")
+        .map(|(start, _)| start + "Station.".len())
+        .collect();
+    assert_eq!(path_segments.len(), 2);
+    for segment in path_segments {
+        assert!(
+            starts.contains(&segment),
+            "expected access path segment at {segment} in {starts:?}"
+        );
+    }
+    assert_eq!(starts.len(), 4, "declaration, call, VAR_ACCESS, VAR_CONFIG");
+}
+
+#[test]
+fn test_rename_updates_trailing_access_path_segment() {
+    let source = r#"
+FUNCTION_BLOCK MotorFb
+VAR_OUTPUT
+    Speed : INT;
+END_VAR
+Speed := 1;
+END_FUNCTION_BLOCK
+
+PROGRAM Main
+VAR
+    Motor : MotorFb;
+END_VAR
+Motor();
+END_PROGRAM
+
+CONFIGURATION Plant
+RESOURCE Cpu ON PLC
+    TASK Fast (INTERVAL := T#10ms, PRIORITY := 1);
+    PROGRAM Station WITH Fast : Main;
+END_RESOURCE
+VAR_ACCESS
+    MotorSpeed : Station.Motor.Speed : INT READ_ONLY;
+END_VAR
+VAR_CONFIG
+    Station.Motor.Speed : INT := 5;
+END_VAR
+END_CONFIGURATION
+"#;
+    let (db, file) = setup(source);
+    let pos = TextSize::from(source.find("Speed : INT").unwrap() as u32);
+
+    let result = rename(&db, file, pos, "Rpm").expect("rename should succeed");
+    let starts = edit_starts(&result, file);
+    for (start, _) in source.match_indices("Station.Motor.Speed") {
+        let segment = start + "Station.Motor.".len();
+        assert!(
+            starts.contains(&segment),
+            "expected access path segment at {segment} in {starts:?}"
+        );
+    }
+    assert!(
+        !starts
+            .iter()
+            .any(|start| source[*start..].starts_with("Station")
+                || source[*start..].starts_with("Motor")),
+        "only the renamed segment should be edited"
+    );
+}
@@ -246,6 +246,46 @@ END_PROGRAM
     }
 }
 
+#[test]
+fn test_semantic_tokens_deprecated_symbol() {
+    let source = r#"
+{attribute 'deprecated' := 'use FB_NewPump'}
+FUNCTION_BLOCK FB_OldPump
+END_FUNCTION_BLOCK
+
+FUNCTION_BLOCK FB_NewPump
+END_FUNCTION_BLOCK
+
+PROGRAM Test
+    VAR
+        oldPump : FB_OldPump;
+        newPump : FB_NewPump;
+    END_VAR
+END_PROGRAM
+"#;
+    let (db, file) = setup(source);
+    let tokens = semantic_tokens(&db, file);
+    let token_at = |needle: &str| {
+        let offset = source.find(needle).unwrap() as u32;
+        tokens
+            .iter()
+            .find(|t| u32::from(t.range.start()) == offset)
+            .expect("token")
+    };
+
+    assert!(token_at("FB_OldPump;").modifiers.deprecated);
+    assert!(!token_at("FB_NewPump;").modifiers.deprecated);
+    assert!(!token_at("oldPump").modifiers.deprecated);
+
+    let offset = TextSize::from(source.find("FB_OldPump;").unwrap() as u32);
+    let info = hover(&db, file, offset).expect("hover");
+    assert!(
+        info.contents.contains("Deprecated: use FB_NewPump"),
+        "{}",
+        info.contents
+    );
+}
+
 // =============================================================================
 // Hover & Go-to-definition Tests
 // =============================================================================
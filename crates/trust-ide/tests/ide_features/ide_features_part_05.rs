@@ -59,6 +59,42 @@ END_PROGRAM
     );
 }
 
+#[test]
+fn test_hover_and_completion_show_direct_address() {
+    let source = r#"
+PROGRAM Test
+    VAR
+        valve AT %QX0.1 : BOOL;
+        level : INT;
+    END_VAR
+    valve := TRUE;
+
+END_PROGRAM
+"#;
+    let (db, file) = setup(source);
+
+    let use_offset = TextSize::from(source.find("valve := TRUE").unwrap() as u32);
+    let valve_hover = hover(&db, file, use_offset).expect("hover");
+    assert!(
+        valve_hover.contents.contains("valve AT %QX0.1 : BOOL"),
+        "Hover should include the direct address, got: {}",
+        valve_hover.contents
+    );
+
+    let pos = TextSize::from(source.find("\n\nEND_PROGRAM").unwrap() as u32 + 1);
+    let completions = complete(&db, file, pos);
+    let valve = completions
+        .iter()
+        .find(|item| item.label == "valve")
+        .expect("valve completion");
+    assert_eq!(valve.detail.as_deref(), Some("AT %QX0.1 : BOOL"));
+    let level = completions
+        .iter()
+        .find(|item| item.label == "level")
+        .expect("level completion");
+    assert_eq!(level.detail.as_deref(), Some("INT"));
+}
+
 #[test]
 fn test_hover_task_priority() {
     let source = r#"
@@ -191,3 +227,166 @@ END_FUNCTION_BLOCK
         hover_result.contents
     );
 }
+
+#[test]
+fn test_collect_diagnostics_into_stops_at_break() {
+    let source = r#"
+PROGRAM Test
+    VAR x : INT; END_VAR
+    x := a;
+    x := b;
+    x := c;
+END_PROGRAM
+"#;
+    let (db, file) = setup(source);
+    let all = trust_ide::diagnostics::collect_diagnostics(&db, file);
+    let errors = all
+        .iter()
+        .filter(|diagnostic| diagnostic.is_error())
+        .count();
+    assert!(errors >= 3, "expected an error per unresolved name");
+
+    let mut first_two = Vec::new();
+    trust_hir::diagnostics::collect_diagnostics_into(&db, file, &mut |diagnostic| {
+        if diagnostic.is_error() {
+            first_two.push(diagnostic);
+        }
+        if first_two.len() == 2 {
+            std::ops::ControlFlow::Break(())
+        } else {
+            std::ops::ControlFlow::Continue(())
+        }
+    });
+    let expected: Vec<_> = all
+        .into_iter()
+        .filter(|diagnostic| diagnostic.is_error())
+        .take(2)
+        .collect();
+    assert_eq!(first_two, expected);
+}
+
+fn signature_labels(help: &trust_ide::SignatureHelpResult) -> Vec<&str> {
+    help.signatures
+        .iter()
+        .map(|signature| signature.label.as_str())
+        .collect()
+}
+
+#[test]
+fn test_signature_help_lists_variadic_max_forms() {
+    let source = r#"
+PROGRAM Test
+    VAR a : INT; b : INT; c : INT; m : INT; END_VAR
+    m := MAX(a, b, c);
+END_PROGRAM
+"#;
+    let (db, file) = setup(source);
+    let position = TextSize::from(source.find("c);").unwrap() as u32);
+    let help = trust_ide::signature_help(&db, file, position).expect("signature help");
+    let labels = signature_labels(&help);
+
+    assert!(
+        labels.contains(&"MAX(IN1: ANY_ELEMENTARY, IN2: ANY_ELEMENTARY)"),
+        "{labels:?}"
+    );
+    assert!(
+        labels.iter().any(|label| label.contains("IN4")),
+        "next variadic form should be offered: {labels:?}"
+    );
+    assert_eq!(
+        labels[help.active_signature],
+        "MAX(IN1: INT, IN2: INT, IN3: INT) : INT"
+    );
+    assert_eq!(help.active_parameter, 2);
+}
+
+#[test]
+fn test_signature_help_sel_binds_value_family_not_selector() {
+    let source = r#"
+PROGRAM Test
+    VAR flag : BOOL; a : REAL; b : REAL; r : REAL; END_VAR
+    r := SEL(flag, a, b);
+END_PROGRAM
+"#;
+    let (db, file) = setup(source);
+    let position = TextSize::from(source.find("b);").unwrap() as u32);
+    let help = trust_ide::signature_help(&db, file, position).expect("signature help");
+    let labels = signature_labels(&help);
+
+    assert_eq!(
+        labels,
+        vec![
+            "SEL(G: BOOL, IN0: ANY, IN1: ANY)",
+            "SEL(G: BOOL, IN0: REAL, IN1: REAL) : REAL",
+        ]
+    );
+    assert_eq!(help.active_signature, 1);
+    assert_eq!(help.active_parameter, 2);
+}
+
+#[test]
+fn test_signature_help_mux_and_limit_shapes() {
+    let source = r#"
+PROGRAM Test
+    VAR k : INT; a : DINT; b : DINT; c : DINT; r : DINT; END_VAR
+    r := MUX(k, a, b, c);
+    r := LIMIT(a, b, c);
+END_PROGRAM
+"#;
+    let (db, file) = setup(source);
+    let position = TextSize::from(source.find("c);").unwrap() as u32);
+    let help = trust_ide::signature_help(&db, file, position).expect("signature help");
+    let labels = signature_labels(&help);
+    assert!(
+        labels.contains(&"MUX(K: ANY_INT, IN0: ANY, IN1: ANY)"),
+        "{labels:?}"
+    );
+    assert_eq!(
+        labels[help.active_signature],
+        "MUX(K: INT, IN0: DINT, IN1: DINT, IN2: DINT) : DINT"
+    );
+    assert_eq!(help.active_parameter, 3);
+
+    let position = TextSize::from(source.rfind("b, c").unwrap() as u32);
+    let help = trust_ide::signature_help(&db, file, position).expect("signature help");
+    assert_eq!(
+        signature_labels(&help),
+        vec![
+            "LIMIT(MN: ANY_ELEMENTARY, IN: ANY_ELEMENTARY, MX: ANY_ELEMENTARY)",
+            "LIMIT(MN: DINT, IN: DINT, MX: DINT) : DINT",
+        ]
+    );
+    assert_eq!(help.active_signature, 1);
+    assert_eq!(help.active_parameter, 1);
+}
+
+#[test]
+fn test_rename_cancelled_mid_search_returns_no_edits() {
+    let fb_source = "FUNCTION_BLOCK Pump\nEND_FUNCTION_BLOCK\n";
+    let mut db = Database::new();
+    let fb_file = FileId(0);
+    db.set_source_text(fb_file, fb_source.to_string());
+    for idx in 1..4 {
+        db.set_source_text(
+            FileId(idx),
+            format!("PROGRAM P{idx}\nVAR\n    pump : Pump;\nEND_VAR\nEND_PROGRAM\n"),
+        );
+    }
+    let pos = TextSize::from(fb_source.find("Pump").unwrap() as u32);
+
+    let polls = std::cell::Cell::new(0usize);
+    let cancel_after_first_file = || {
+        polls.set(polls.get() + 1);
+        polls.get() > 1
+    };
+    assert!(
+        trust_ide::rename_cancellable(&db, fb_file, pos, "Motor", &cancel_after_first_file)
+            .is_none(),
+        "a cancelled rename must not return a partial edit"
+    );
+    assert!(polls.get() > 1, "cancellation should be polled per file");
+
+    let result = trust_ide::rename_cancellable(&db, fb_file, pos, "Motor", &|| false)
+        .expect("uncancelled rename succeeds");
+    assert_eq!(result.edits.len(), 4, "every file should be edited");
+}
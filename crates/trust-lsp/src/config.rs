@@ -4,6 +4,8 @@ use serde::{Deserialize, Serialize};
 use std::collections::{BTreeMap, HashMap};
 use std::path::{Path, PathBuf};
 use tower_lsp::lsp_types::DiagnosticSeverity;
+use tracing::warn;
+use trust_hir::DiagnosticPass;
 
 mod deps;
 mod git;
@@ -87,8 +89,20 @@ impl DiagnosticSettings {
         if let Some(value) = section.warn_nondeterminism {
             settings.warn_nondeterminism = value;
         }
+        if let Some(value) = section.warn_integer_division {
+            settings.warn_integer_division = value;
+        }
+        if let Some(value) = section.warn_real_equality {
+            settings.warn_real_equality = value;
+        }
 
         apply_severity_overrides(&mut settings, section.severity_overrides);
+        for name in &section.disabled_passes {
+            match DiagnosticPass::from_name(name) {
+                Some(pass) => settings.passes.disable(pass),
+                None => warn!("Unknown diagnostic pass '{name}' in disabled_passes"),
+            }
+        }
         settings
     }
 }
@@ -160,6 +174,15 @@ impl From<WorkspaceSection> for WorkspaceSettings {
     }
 }
 
+impl From<FormattingSection> for FormattingSettings {
+    fn from(section: FormattingSection) -> Self {
+        FormattingSettings {
+            align_var_declarations: section.align_var_declarations,
+            align_var_initializers: section.align_var_initializers,
+        }
+    }
+}
+
 impl TelemetryConfig {
     fn from_section(root: &Path, section: TelemetrySection) -> Self {
         let enabled = section.enabled.unwrap_or(false);
@@ -200,6 +223,8 @@ struct ConfigFile {
     runtime: RuntimeSection,
     #[serde(default)]
     telemetry: TelemetrySection,
+    #[serde(default)]
+    formatting: FormattingSection,
 }
 
 #[derive(Debug, Default, Deserialize)]
@@ -244,10 +269,14 @@ struct DiagnosticSection {
     warn_deprecated: Option<bool>,
     warn_complexity: Option<bool>,
     warn_nondeterminism: Option<bool>,
+    warn_integer_division: Option<bool>,
+    warn_real_equality: Option<bool>,
     #[serde(default)]
     external_paths: Vec<String>,
     #[serde(default)]
     severity_overrides: HashMap<String, String>,
+    #[serde(default)]
+    disabled_passes: Vec<String>,
 }
 
 #[derive(Debug, Default, Deserialize)]
@@ -263,6 +292,12 @@ struct TelemetrySection {
     flush_every: Option<usize>,
 }
 
+#[derive(Debug, Default, Deserialize)]
+struct FormattingSection {
+    align_var_declarations: Option<bool>,
+    align_var_initializers: Option<bool>,
+}
+
 #[derive(Debug, Default, Deserialize)]
 struct DependencyPolicySection {
     #[serde(default)]
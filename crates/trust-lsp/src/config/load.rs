@@ -52,6 +52,7 @@ impl super::ProjectConfig {
         config.runtime = parsed.runtime.into();
         config.workspace = super::WorkspaceSettings::from(parsed.workspace);
         config.telemetry = super::TelemetryConfig::from_section(root, parsed.telemetry);
+        config.formatting = parsed.formatting.into();
 
         let mut include_paths = super::resolve_paths(root, &parsed.project.include_paths);
         config.include_paths.append(&mut include_paths);
@@ -128,6 +129,7 @@ impl super::ProjectConfig {
             runtime: super::RuntimeConfig::default(),
             workspace: super::WorkspaceSettings::default(),
             telemetry: super::TelemetryConfig::default(),
+            formatting: super::FormattingSettings::default(),
         }
     }
 }
@@ -3,6 +3,7 @@
 use std::collections::HashMap;
 use std::path::PathBuf;
 use tower_lsp::lsp_types::DiagnosticSeverity;
+use trust_hir::DiagnosticPasses;
 
 /// Project configuration loaded from `trust-lsp.toml`.
 #[derive(Debug, Clone)]
@@ -39,6 +40,8 @@ pub struct ProjectConfig {
     pub workspace: WorkspaceSettings,
     /// Telemetry configuration (opt-in).
     pub telemetry: TelemetryConfig,
+    /// Formatter settings from `[formatting]`.
+    pub formatting: FormattingSettings,
 }
 impl ProjectConfig {
     pub fn indexing_roots(&self) -> Vec<PathBuf> {
@@ -136,8 +139,14 @@ pub struct DiagnosticSettings {
     pub warn_complexity: bool,
     /// Toggle non-determinism warnings (W010/W011).
     pub warn_nondeterminism: bool,
+    /// Toggle integer division truncation warnings (W013, opt-in).
+    pub warn_integer_division: bool,
+    /// Toggle exact REAL/LREAL equality comparison warnings (W015).
+    pub warn_real_equality: bool,
     /// Per-code severity overrides (e.g., W010 -> error).
     pub severity_overrides: HashMap<String, DiagnosticSeverity>,
+    /// Analysis passes to run; disabled passes are skipped, not filtered.
+    pub passes: DiagnosticPasses,
 }
 
 impl Default for DiagnosticSettings {
@@ -151,7 +160,10 @@ impl Default for DiagnosticSettings {
             warn_deprecated: true,
             warn_complexity: true,
             warn_nondeterminism: true,
+            warn_integer_division: false,
+            warn_real_equality: true,
             severity_overrides: HashMap::new(),
+            passes: DiagnosticPasses::all(),
         }
     }
 }
@@ -165,6 +177,8 @@ impl DiagnosticSettings {
         self.warn_deprecated = true;
         self.warn_complexity = true;
         self.warn_nondeterminism = true;
+        self.warn_integer_division = true;
+        self.warn_real_equality = true;
     }
 }
 /// Runtime control settings for inline values/debug integration.
@@ -287,6 +301,16 @@ impl Default for TelemetryConfig {
         }
     }
 }
+
+/// Formatter settings; unset values keep the profile and client defaults.
+#[derive(Debug, Clone, Default)]
+pub struct FormattingSettings {
+    /// Align the `:` of declarations within each group of a VAR block.
+    pub align_var_declarations: Option<bool>,
+    /// Also align the `:=` of initializers within those groups.
+    pub align_var_initializers: Option<bool>,
+}
+
 #[derive(Debug, Clone)]
 pub struct LibraryDependency {
     pub name: String,
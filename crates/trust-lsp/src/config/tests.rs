@@ -238,6 +238,55 @@ vendor_profile = "mitsubishi"
         fs::remove_dir_all(root).ok();
     }
 
+    #[test]
+    fn integer_division_warning_is_opt_in() {
+        let root = temp_dir("trustlsp-config-diagnostics-int-div");
+        let config_path = root.join("trust-lsp.toml");
+        fs::write(&config_path, "[diagnostics]\nwarn_unused = true\n").expect("write config");
+        let config = ProjectConfig::load(&root);
+        assert!(!config.diagnostics.warn_integer_division);
+
+        fs::write(&config_path, "[diagnostics]\nwarn_integer_division = true\n")
+            .expect("write config");
+        let config = ProjectConfig::load(&root);
+        assert!(config.diagnostics.warn_integer_division);
+
+        fs::remove_dir_all(root).ok();
+    }
+
+    #[test]
+    fn real_equality_warning_can_be_disabled() {
+        let root = temp_dir("trustlsp-config-diagnostics-real-eq");
+        let config_path = root.join("trust-lsp.toml");
+        fs::write(&config_path, "[diagnostics]\nwarn_unused = true\n").expect("write config");
+        let config = ProjectConfig::load(&root);
+        assert!(config.diagnostics.warn_real_equality);
+
+        fs::write(&config_path, "[diagnostics]\nwarn_real_equality = false\n")
+            .expect("write config");
+        let config = ProjectConfig::load(&root);
+        assert!(!config.diagnostics.warn_real_equality);
+
+        fs::remove_dir_all(root).ok();
+    }
+
+    #[test]
+    fn diagnostic_passes_can_be_disabled() {
+        let root = temp_dir("trustlsp-config-diagnostic-passes");
+        fs::write(
+            root.join("trust-lsp.toml"),
+            "[diagnostics]\ndisabled_passes = [\"complexity\", \"Empty-Blocks\", \"bogus\"]\n",
+        )
+        .expect("write config");
+        let config = ProjectConfig::load(&root);
+        let passes = config.diagnostics.passes;
+        assert!(!passes.is_enabled(trust_hir::DiagnosticPass::Complexity));
+        assert!(!passes.is_enabled(trust_hir::DiagnosticPass::EmptyBlocks));
+        assert!(passes.is_enabled(trust_hir::DiagnosticPass::Unused));
+
+        fs::remove_dir_all(root).ok();
+    }
+
     #[test]
     fn resolves_local_dependencies_transitively() {
         let root = temp_dir("trustlsp-config-dependencies");
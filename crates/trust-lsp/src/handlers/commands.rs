@@ -30,6 +30,7 @@ pub const MOVE_NAMESPACE_COMMAND: &str = "trust-lsp.moveNamespace";
 pub const PROJECT_INFO_COMMAND: &str = "trust-lsp.projectInfo";
 pub const HMI_INIT_COMMAND: &str = "trust-lsp.hmiInit";
 pub const HMI_BINDINGS_COMMAND: &str = "trust-lsp.hmiBindings";
+pub const EXPRESSION_TYPE_COMMAND: &str = "trust-lsp.expressionType";
 
 #[derive(Debug, Deserialize)]
 pub struct MoveNamespaceCommandArgs {
@@ -48,6 +49,12 @@ struct ProjectInfoCommandArgs {
     text_document: Option<TextDocumentIdentifier>,
 }
 
+#[derive(Debug, Deserialize)]
+struct ExpressionTypeCommandArgs {
+    text_document: TextDocumentIdentifier,
+    range: Range,
+}
+
 #[derive(Debug, Deserialize, Default)]
 struct HmiInitCommandArgs {
     #[serde(default)]
@@ -81,6 +88,7 @@ pub async fn execute_command(
         PROJECT_INFO_COMMAND => project_info_value(state, params.arguments),
         HMI_INIT_COMMAND => hmi_init_value(state, params.arguments),
         HMI_BINDINGS_COMMAND => hmi_bindings_value(state, params.arguments),
+        EXPRESSION_TYPE_COMMAND => expression_type_value(state, params.arguments),
         _ => None,
     }
 }
@@ -92,6 +100,31 @@ fn parse_move_namespace_args(args: Vec<Value>) -> Option<MoveNamespaceCommandArg
     serde_json::from_value(args.into_iter().next()?).ok()
 }
 
+/// Returns `{ "type", "range" }` for the expression covering the selected
+/// range, or `None` when the selection is not an expression.
+pub(crate) fn expression_type_value(state: &ServerState, args: Vec<Value>) -> Option<Value> {
+    if args.len() != 1 {
+        return None;
+    }
+    let args: ExpressionTypeCommandArgs = serde_json::from_value(args.into_iter().next()?).ok()?;
+    let doc = state.get_document(&args.text_document.uri)?;
+    let start = position_to_offset(&doc.content, args.range.start)?;
+    let end = position_to_offset(&doc.content, args.range.end)?;
+    if end < start {
+        return None;
+    }
+    let range = TextRange::new(TextSize::from(start), TextSize::from(end));
+    let result = state.with_database(|db| trust_ide::expression_type(db, doc.file_id, range))?;
+    let range = Range {
+        start: offset_to_position(&doc.content, u32::from(result.range.start())),
+        end: offset_to_position(&doc.content, u32::from(result.range.end())),
+    };
+    Some(json!({
+        "type": result.type_name.as_str(),
+        "range": range,
+    }))
+}
+
 pub(crate) fn project_info_value(state: &ServerState, args: Vec<Value>) -> Option<Value> {
     project_info_value_with_context(state, args)
 }
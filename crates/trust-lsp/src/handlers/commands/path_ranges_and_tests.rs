@@ -2,8 +2,8 @@
 mod tests {
     use super::*;
     use crate::config::{
-        BuildConfig, DiagnosticSettings, IndexingConfig, ProjectConfig, RuntimeConfig,
-        StdlibSettings, TelemetryConfig, WorkspaceSettings,
+        BuildConfig, DiagnosticSettings, FormattingSettings, IndexingConfig, ProjectConfig,
+        RuntimeConfig, StdlibSettings, TelemetryConfig, WorkspaceSettings,
     };
     use crate::state::Document;
     use serde_json::json;
@@ -82,6 +82,7 @@ mod tests {
             runtime: RuntimeConfig::default(),
             workspace: WorkspaceSettings::default(),
             telemetry: TelemetryConfig::default(),
+            formatting: FormattingSettings::default(),
         }
     }
 
@@ -15,7 +15,7 @@ use tower_lsp::lsp_types::{
 };
 use tower_lsp::Client;
 
-use trust_hir::db::FileId;
+use trust_hir::db::{FileId, SemanticDatabase};
 use trust_hir::symbols::SymbolKind;
 use trust_hir::DiagnosticSeverity as HirSeverity;
 use trust_runtime::bundle_builder::resolve_sources_root;
@@ -110,6 +110,9 @@ pub(crate) fn workspace_diagnostic(
     params: WorkspaceDiagnosticParams,
 ) -> WorkspaceDiagnosticReportResult {
     let request_ticket = state.begin_semantic_request();
+    // Loading diagnostics for one file may index others and enforce the memory
+    // budget; keep the documents being iterated from being evicted meanwhile.
+    let _pinned = state.pin_all_documents();
     let mut previous = std::collections::HashMap::new();
     for entry in params.previous_result_ids {
         previous.insert(entry.uri, entry.value);
@@ -122,16 +125,41 @@ pub(crate) fn workspace_diagnostic(
             break;
         }
         seen.insert(doc.uri.clone());
-        let diagnostics = collect_diagnostics_with_ticket(
-            state,
-            &doc.uri,
-            &doc.content,
-            doc.file_id,
-            Some(request_ticket),
-        );
         let content_hash = hash_content(&doc.content);
-        let diagnostic_hash = hash_diagnostics(&diagnostics);
-        let result_id = state.store_diagnostics(doc.uri.clone(), content_hash, diagnostic_hash);
+        let dependency_hash = diagnostic_dependency_hash(state, &doc.uri, doc.file_id);
+        // Skip recomputing files whose text and dependencies are unchanged
+        // since the report the client already holds.
+        let reused = dependency_hash
+            .and_then(|dependency_hash| {
+                state.reusable_diagnostics(&doc.uri, content_hash, dependency_hash)
+            })
+            .filter(|result_id| previous.get(&doc.uri) == Some(result_id));
+        let (result_id, diagnostics) = match reused {
+            Some(result_id) => (result_id, Vec::new()),
+            None => {
+                let diagnostics = collect_diagnostics_with_ticket(
+                    state,
+                    &doc.uri,
+                    &doc.content,
+                    doc.file_id,
+                    Some(request_ticket),
+                );
+                if state.semantic_request_cancelled(request_ticket) {
+                    break;
+                }
+                let diagnostic_hash = hash_diagnostics(&diagnostics);
+                let result_id = match dependency_hash {
+                    Some(dependency_hash) => state.store_diagnostics_with_dependencies(
+                        doc.uri.clone(),
+                        content_hash,
+                        dependency_hash,
+                        diagnostic_hash,
+                    ),
+                    None => state.store_diagnostics(doc.uri.clone(), content_hash, diagnostic_hash),
+                };
+                (result_id, diagnostics)
+            }
+        };
 
         if previous
             .get(&doc.uri)
@@ -204,6 +232,22 @@ pub(crate) fn workspace_diagnostic(
     WorkspaceDiagnosticReportResult::Report(WorkspaceDiagnosticReport { items })
 }
 
+/// Returns the fingerprint of what a document's diagnostics depend on besides
+/// its own text, or `None` when they also depend on inputs the project does not
+/// track, such as TOML files or external linter payloads.
+fn diagnostic_dependency_hash(state: &ServerState, uri: &Url, file_id: FileId) -> Option<u64> {
+    if is_config_uri(uri) || is_hmi_toml_uri(uri) {
+        return None;
+    }
+    if state
+        .workspace_config_for_uri(uri)
+        .is_some_and(|config| !config.diagnostic_external_paths.is_empty())
+    {
+        return None;
+    }
+    Some(state.with_database(|db| db.dependency_fingerprint(file_id)))
+}
+
 pub(crate) fn collect_diagnostics_with_ticket(
     state: &ServerState,
     uri: &Url,
@@ -244,11 +288,12 @@ pub(crate) fn collect_diagnostics_with_ticket(
                 start: offset_to_position(content, err.range.start().into()),
                 end: offset_to_position(content, err.range.end().into()),
             };
-            let code = if err.message.starts_with("expected ") {
-                "E002"
-            } else {
-                "E001"
-            };
+            let code =
+                if err.message.starts_with("expected ") || err.message.starts_with("missing ") {
+                    "E002"
+                } else {
+                    "E001"
+                };
 
             Diagnostic {
                 range,
@@ -261,13 +306,19 @@ pub(crate) fn collect_diagnostics_with_ticket(
         })
         .collect();
 
-    let semantic = state.with_database(|db| {
+    let mut semantic = state.with_database(|db| {
         if request_ticket.is_some_and(|ticket| state.semantic_request_cancelled(ticket)) {
             Vec::new()
         } else {
-            trust_ide::diagnostics::collect_diagnostics(db, file_id)
+            db.diagnostics(file_id).as_ref().clone()
         }
     });
+    // The shared database only skips passes every root disables; drop the
+    // rest of what this document's root switched off.
+    if let Some(config) = state.workspace_config_for_uri(uri) {
+        let passes = config.diagnostics.passes;
+        semantic.retain(|diag| diag.pass.is_none_or(|pass| passes.is_enabled(pass)));
+    }
 
     if request_ticket.is_some_and(|ticket| state.semantic_request_cancelled(ticket)) {
         return diagnostics;
@@ -30,13 +30,15 @@ fn diagnostic_allowed(settings: &DiagnosticSettings, diagnostic: &Diagnostic) ->
     };
     match code.as_str() {
         "W001" | "W002" | "W009" => settings.warn_unused,
-        "W003" => settings.warn_unreachable,
+        "W003" | "I003" => settings.warn_unreachable,
         "W004" => settings.warn_missing_else,
         "W005" => settings.warn_implicit_conversion,
         "W006" => settings.warn_shadowed,
         "W007" => settings.warn_deprecated,
         "W008" => settings.warn_complexity,
         "W010" | "W011" => settings.warn_nondeterminism,
+        "W013" => settings.warn_integer_division,
+        "W015" => settings.warn_real_equality,
         _ => true,
     }
 }
@@ -408,7 +410,7 @@ fn contains_plain_equal(text: &str) -> bool {
 }
 
 fn conversion_guidance_hint(code: &str, message: &str) -> Option<String> {
-    if !matches!(code, "E201" | "E203" | "E207" | "W005") {
+    if !matches!(code, "E201" | "E203" | "E207" | "E208" | "W005") {
         return None;
     }
     let quoted = collect_quoted_segments(message);
@@ -547,7 +549,7 @@ fn diagnostic_explainer(code: &str, message: &str) -> Option<DiagnosticExplainer
             iec_ref: "IEC 61131-3 Ed.3 §6.1.2",
             spec_path: "docs/specs/01-lexical-elements.md",
         }),
-        "E201" | "E202" | "E203" => Some(DiagnosticExplainer {
+        "E201" | "E202" | "E203" | "E208" => Some(DiagnosticExplainer {
             iec_ref: "IEC 61131-3 Ed.3 §7.3.2",
             spec_path: "docs/specs/05-expressions.md",
         }),
@@ -563,6 +565,10 @@ fn diagnostic_explainer(code: &str, message: &str) -> Option<DiagnosticExplainer
             iec_ref: "IEC 61131-3 Ed.3 §6.2.6",
             spec_path: "docs/specs/02-data-types.md",
         }),
+        "E308" => Some(DiagnosticExplainer {
+            iec_ref: "IEC 61131-3 Ed.3 §7.3.3.3.3",
+            spec_path: "docs/specs/06-statements.md",
+        }),
         "W004" => Some(DiagnosticExplainer {
             iec_ref: "IEC 61131-3 Ed.3 §7.3.3.3.3",
             spec_path: "docs/specs/06-statements.md",
@@ -595,6 +601,30 @@ fn diagnostic_explainer(code: &str, message: &str) -> Option<DiagnosticExplainer
             iec_ref: "Tooling quality lint (non-IEC); shared globals across tasks (IEC 61131-3 Ed.3 §6.5.2.2 Tables 13-16; §6.2/§6.8.2 Table 62)",
             spec_path: "docs/specs/09-semantic-rules.md",
         }),
+        "W013" => Some(DiagnosticExplainer {
+            iec_ref: "Tooling quality lint (non-IEC); integer division per IEC 61131-3 Ed.3 §7.3.2 (Table 71)",
+            spec_path: "docs/specs/09-semantic-rules.md",
+        }),
+        "W014" => Some(DiagnosticExplainer {
+            iec_ref: "Tooling quality lint (non-IEC); statement bodies per IEC 61131-3 Ed.3 §7.3.3 (Table 72)",
+            spec_path: "docs/specs/09-semantic-rules.md",
+        }),
+        "W015" => Some(DiagnosticExplainer {
+            iec_ref: "Tooling quality lint (non-IEC); comparison operators per IEC 61131-3 Ed.3 §7.3.2 (Table 71)",
+            spec_path: "docs/specs/09-semantic-rules.md",
+        }),
+        "W016" => Some(DiagnosticExplainer {
+            iec_ref: "Tooling quality lint (non-IEC); SFC steps and transitions per IEC 61131-3 Ed.3 §6.7 (Tables 54–57)",
+            spec_path: "docs/specs/09-semantic-rules.md",
+        }),
+        "W017" => Some(DiagnosticExplainer {
+            iec_ref: "Tooling quality lint (non-IEC); Direct variables per IEC 61131-3 Ed.3 §6.5.5 (Table 16)",
+            spec_path: "docs/specs/09-semantic-rules.md",
+        }),
+        "W018" => Some(DiagnosticExplainer {
+            iec_ref: "Tooling quality lint (non-IEC); FB instances as parameters per IEC 61131-3 Ed.3 §6.6.1.2 (Table 14)",
+            spec_path: "docs/specs/09-semantic-rules.md",
+        }),
         "L001" | "L002" | "L003" | "L005" | "L006" | "L007" => Some(DiagnosticExplainer {
             iec_ref: "Tooling config lint (non-IEC)",
             spec_path: "docs/specs/10-runtime.md",
@@ -18,6 +18,7 @@ use trust_hir::TypeId;
 use trust_syntax::parser::parse;
 use trust_syntax::syntax::{SyntaxKind, SyntaxNode, SyntaxToken};
 
+use super::super::config::{bool_with_aliases, lsp_section, value_with_aliases};
 use super::super::lsp_utils::{
     display_symbol_name, is_primary_pou_symbol_kind, lsp_symbol_kind, offset_to_line_col,
     offset_to_position, position_to_offset, rename_result_to_changes, semantic_tokens_to_lsp,
@@ -41,6 +42,8 @@ use trust_ide::{
 };
 
 const PARTIAL_CHUNK_SIZE: usize = 200;
+/// Completion lists above this size omit symbol detail/documentation until resolved.
+const LAZY_COMPLETION_THRESHOLD: usize = 100;
 
 mod action_requests;
 mod completion_requests;
@@ -133,13 +133,9 @@ fn code_action_with_ticket(
             }
             Some("E002") | Some("E003") => {
                 if let Some(edit) = missing_end_text_edit(&doc, &root, diagnostic) {
-                    push_quickfix_action(
-                        &mut actions,
-                        "Insert missing END_*",
-                        diagnostic,
-                        uri,
-                        edit,
-                    );
+                    let keyword = expected_end_keyword(&diagnostic.message).unwrap_or("END_*");
+                    let title = format!("Insert missing {keyword}");
+                    push_quickfix_action(&mut actions, &title, diagnostic, uri, edit);
                 }
             }
             Some("E205") => {
@@ -199,7 +195,7 @@ fn code_action_with_ticket(
 
                 actions.push(CodeActionOrCommand::CodeAction(action));
             }
-            Some("W005") | Some("E203") => {
+            Some("W005") | Some("E203") | Some("E208") => {
                 if let Some(edit) = implicit_conversion_text_edit(&doc, &root, diagnostic) {
                     push_quickfix_action(
                         &mut actions,
@@ -254,6 +250,13 @@ fn code_action_with_ticket(
         actions.push(action);
     }
 
+    if state.semantic_request_cancelled(request_ticket) {
+        return None;
+    }
+    if let Some(action) = named_arguments_action(state, &doc, &root, &params) {
+        actions.push(action);
+    }
+
     Some(actions)
 }
 
@@ -282,8 +285,16 @@ fn rename_with_ticket(
         return None;
     }
 
-    let result = state
-        .with_database(|db| trust_ide::rename(db, doc.file_id, TextSize::from(offset), new_name))?;
+    let is_cancelled = || state.semantic_request_cancelled(request_ticket);
+    let result = state.with_database(|db| {
+        trust_ide::rename_cancellable(
+            db,
+            doc.file_id,
+            TextSize::from(offset),
+            new_name,
+            &is_cancelled,
+        )
+    })?;
 
     if state.semantic_request_cancelled(request_ticket) {
         return None;
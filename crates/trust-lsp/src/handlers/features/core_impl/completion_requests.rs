@@ -72,6 +72,9 @@ fn completion_with_ticket(
         return None;
     }
 
+    // Large lists ship label/kind only for symbols; details come from completion_resolve.
+    let lazy = items.len() > LAZY_COMPLETION_THRESHOLD;
+
     // Convert to LSP completion items
     let mut lsp_items: Vec<CompletionItem> = items
         .into_iter()
@@ -86,6 +89,8 @@ fn completion_with_ticket(
                 trust_ide::CompletionKind::Constant => CompletionItemKind::CONSTANT,
                 trust_ide::CompletionKind::Type => CompletionItemKind::CLASS,
                 trust_ide::CompletionKind::EnumValue => CompletionItemKind::ENUM_MEMBER,
+                trust_ide::CompletionKind::Task => CompletionItemKind::EVENT,
+                trust_ide::CompletionKind::Resource => CompletionItemKind::MODULE,
                 trust_ide::CompletionKind::Snippet => CompletionItemKind::SNIPPET,
             };
 
@@ -106,11 +111,24 @@ fn completion_with_ticket(
                 item.insert_text.as_ref().map(|s| s.to_string())
             };
 
+            let data = item.symbol.as_ref().map(|symbol| {
+                json!({
+                    "fileId": doc.file_id.0,
+                    "symbol": symbol.qualified_name.as_str(),
+                    "symbolKind": symbol.kind,
+                })
+            });
+            let (detail, documentation) = if lazy && data.is_some() {
+                (None, None)
+            } else {
+                (item.detail, item.documentation)
+            };
+
             CompletionItem {
                 label: item.label.to_string(),
                 kind: Some(kind),
-                detail: item.detail.map(|s| s.to_string()),
-                documentation: item.documentation.map(|s| {
+                detail: detail.map(|s| s.to_string()),
+                documentation: documentation.map(|s| {
                     Documentation::MarkupContent(MarkupContent {
                         kind: MarkupKind::Markdown,
                         value: s.to_string(),
@@ -127,6 +145,7 @@ fn completion_with_ticket(
                 },
                 sort_text: Some(format!("{:05}", item.sort_priority)),
                 text_edit,
+                data,
                 ..Default::default()
             }
         })
@@ -135,6 +154,9 @@ fn completion_with_ticket(
     if let Some(docs) = state.library_docs_for_uri(uri) {
         if !docs.is_empty() {
             for item in &mut lsp_items {
+                if lazy && item.data.is_some() {
+                    continue;
+                }
                 if let Some(extra) = doc_for_name(docs.as_ref(), &item.label) {
                     append_completion_doc(item, extra);
                 }
@@ -154,7 +176,8 @@ pub(crate) fn completion_with_ticket_for_tests(
     completion_with_ticket(state, params, request_ticket)
 }
 
-pub fn completion_resolve(_state: &ServerState, mut item: CompletionItem) -> CompletionItem {
+pub fn completion_resolve(state: &ServerState, mut item: CompletionItem) -> CompletionItem {
+    resolve_symbol_completion(state, &mut item);
     if item.detail.is_none() {
         if item.insert_text_format == Some(InsertTextFormat::SNIPPET) {
             item.detail = Some("snippet".to_string());
@@ -186,6 +209,54 @@ pub fn completion_resolve(_state: &ServerState, mut item: CompletionItem) -> Com
     item
 }
 
+/// Fill detail/documentation for an item carrying `{ fileId, symbol, symbolKind }` data.
+fn resolve_symbol_completion(state: &ServerState, item: &mut CompletionItem) {
+    let Some(serde_json::Value::Object(map)) = &item.data else {
+        return;
+    };
+    let file_id = map
+        .get("fileId")
+        .and_then(|value| value.as_u64())
+        .map(|value| trust_hir::db::FileId(value as u32));
+    let qualified_name = map.get("symbol").and_then(|value| value.as_str());
+    let kind = map
+        .get("symbolKind")
+        .and_then(|value| serde_json::from_value(value.clone()).ok());
+    let (Some(file_id), Some(qualified_name), Some(kind)) = (file_id, qualified_name, kind) else {
+        return;
+    };
+    let key = trust_ide::CompletionSymbolKey {
+        qualified_name: qualified_name.into(),
+        kind,
+    };
+    let uri = state.uri_for_file_id(file_id);
+    let stdlib_filter = uri
+        .as_ref()
+        .map(|uri| stdlib_filter_for_uri(state, uri))
+        .unwrap_or_else(StdlibFilter::allow_all);
+    let Some(resolved) = state.with_database(|db| {
+        trust_ide::resolve_completion_symbol(db, file_id, &key, &stdlib_filter)
+    }) else {
+        return;
+    };
+    if item.detail.is_none() {
+        item.detail = resolved.detail.map(|detail| detail.to_string());
+    }
+    if item.documentation.is_none() {
+        item.documentation = resolved.documentation.map(|doc| {
+            Documentation::MarkupContent(MarkupContent {
+                kind: MarkupKind::Markdown,
+                value: doc.to_string(),
+            })
+        });
+        if let Some(docs) = uri.as_ref().and_then(|uri| state.library_docs_for_uri(uri)) {
+            if let Some(extra) = doc_for_name(docs.as_ref(), &item.label) {
+                append_completion_doc(item, extra);
+            }
+        }
+    }
+}
+
 pub fn signature_help(state: &ServerState, params: SignatureHelpParams) -> Option<SignatureHelp> {
     let uri = &params.text_document_position_params.text_document.uri;
     let position = params.text_document_position_params.position;
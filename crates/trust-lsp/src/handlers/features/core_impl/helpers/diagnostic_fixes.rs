@@ -293,22 +293,37 @@ pub(in super::super) fn missing_end_text_edit(
     diagnostic: &Diagnostic,
 ) -> Option<TextEdit> {
     let expected = expected_end_keyword(&diagnostic.message)?;
-    let start = position_to_offset(&doc.content, diagnostic.range.start)?;
-    let diag_range = TextRange::new(TextSize::from(start), TextSize::from(start));
+    let start = position_to_offset(&doc.content, diagnostic.range.start)? as usize;
+    let at_eof = start >= doc.content.len();
+    let block = node_kind_for_end_keyword(expected)
+        .and_then(|kind| unterminated_block(root, start, kind, at_eof));
+    let indent = indent_at_offset(
+        &doc.content,
+        block
+            .as_ref()
+            .map_or(start, |node| usize::from(node.text_range().start())),
+    );
 
-    let indent = if let Some(kind) = node_kind_for_end_keyword(expected) {
-        if let Some(node) = find_enclosing_node_of_kind(root, diag_range, kind) {
-            indent_at_offset(&doc.content, usize::from(node.text_range().start()))
-        } else {
-            indent_at_offset(&doc.content, start as usize)
+    let newline = newline_for_source(&doc.content);
+    let mut insert_text = format!("{indent}{expected}{newline}");
+    let insert_offset = if diagnostic.message.starts_with("missing ") || at_eof {
+        // Reported at the opener of an unterminated block, or at the end of
+        // the source: close it after the last token the parser kept inside it.
+        let node = block?;
+        let last_end = node
+            .descendants_with_tokens()
+            .filter_map(|element| element.into_token())
+            .filter(|token| !token.kind().is_trivia())
+            .last()
+            .map_or(node.text_range().end(), |token| token.text_range().end());
+        let offset = line_end_offset(&doc.content, usize::from(last_end));
+        if offset == doc.content.len() && !doc.content.ends_with('\n') {
+            insert_text.insert_str(0, newline);
         }
+        offset
     } else {
-        indent_at_offset(&doc.content, start as usize)
+        line_start_offset(&doc.content, start)
     };
-
-    let newline = newline_for_source(&doc.content);
-    let insert_text = format!("{indent}{expected}{newline}");
-    let insert_offset = line_start_offset(&doc.content, start as usize);
     let insert_pos = offset_to_position(&doc.content, insert_offset as u32);
     Some(TextEdit {
         range: Range {
@@ -319,6 +334,22 @@ pub(in super::super) fn missing_end_text_edit(
     })
 }
 
+/// Finds the block a missing terminator belongs to: the innermost `kind` node
+/// around `offset`, or the last one when the error sits at the end of the
+/// source, where trailing trivia may lie outside the node.
+fn unterminated_block(
+    root: &SyntaxNode,
+    offset: usize,
+    kind: SyntaxKind,
+    at_eof: bool,
+) -> Option<SyntaxNode> {
+    if at_eof {
+        return root.descendants().filter(|node| node.kind() == kind).last();
+    }
+    let offset = TextSize::from(offset as u32);
+    find_enclosing_node_of_kind(root, TextRange::empty(offset), kind)
+}
+
 pub(in super::super) fn missing_return_text_edit(
     state: &ServerState,
     doc: &crate::state::Document,
@@ -340,6 +340,56 @@ pub(in super::super) fn convert_function_block_action(
     Some(CodeActionOrCommand::CodeAction(action))
 }
 
+pub(in super::super) fn named_arguments_action(
+    state: &ServerState,
+    doc: &crate::state::Document,
+    root: &SyntaxNode,
+    params: &CodeActionParams,
+) -> Option<CodeActionOrCommand> {
+    if !allows_refactor_action(&params.context.only) {
+        return None;
+    }
+    let start = position_to_offset(&doc.content, params.range.start)?;
+    let end = position_to_offset(&doc.content, params.range.end).unwrap_or(start);
+    let range = TextRange::new(TextSize::from(start), TextSize::from(end));
+
+    let call_expr = find_enclosing_node_of_kind(root, range, SyntaxKind::CallExpr)?;
+    let arg_list = call_expr
+        .children()
+        .find(|child| child.kind() == SyntaxKind::ArgList)?;
+    let args = parse_call_args(&arg_list, &doc.content);
+    if args.iter().all(|arg| arg.name.is_some()) {
+        return None;
+    }
+
+    let call_params = state.with_database(|db| {
+        call_signature_info(db, doc.file_id, arg_list.text_range().start()).map(|info| info.params)
+    })?;
+    let call_params = call_params
+        .into_iter()
+        .filter(|param| !is_execution_param(param.name.as_str()))
+        .collect::<Vec<_>>();
+    let text = build_named_call(&args, &call_params)?;
+
+    let mut changes: std::collections::HashMap<Url, Vec<TextEdit>> =
+        std::collections::HashMap::new();
+    changes.insert(
+        doc.uri.clone(),
+        vec![replace_arg_list_edit(&doc.content, &arg_list, text)],
+    );
+    let action = CodeAction {
+        title: "Use named arguments".to_string(),
+        kind: Some(CodeActionKind::REFACTOR_REWRITE),
+        edit: Some(WorkspaceEdit {
+            changes: Some(changes),
+            document_changes: None,
+            change_annotations: None,
+        }),
+        ..Default::default()
+    };
+    Some(CodeActionOrCommand::CodeAction(action))
+}
+
 pub(in super::super) fn allows_refactor_action(only: &Option<Vec<CodeActionKind>>) -> bool {
     let Some(only) = only else {
         return true;
@@ -456,6 +506,38 @@ fn build_formal_call(
     Some(format!("({})", out.join(", ")))
 }
 
+/// Names the leading positional arguments after the parameters they bind to
+/// and keeps arguments that are already named.
+fn build_named_call(
+    args: &[ParsedArg],
+    params: &[trust_ide::CallSignatureParam],
+) -> Option<String> {
+    let mut out = Vec::new();
+    let mut saw_named = false;
+    for (idx, arg) in args.iter().enumerate() {
+        let (name, param) = match &arg.name {
+            Some(name) => {
+                saw_named = true;
+                let param = params
+                    .iter()
+                    .find(|param| param.name.eq_ignore_ascii_case(name))?;
+                (name.as_str(), param)
+            }
+            None if saw_named => return None,
+            None => {
+                let param = params.get(idx)?;
+                (param.name.as_str(), param)
+            }
+        };
+        let op = match param.direction {
+            ParamDirection::Out => "=>",
+            ParamDirection::In | ParamDirection::InOut => ":=",
+        };
+        out.push(format!("{name} {op} {}", arg.expr_text));
+    }
+    Some(format!("({})", out.join(", ")))
+}
+
 fn build_positional_call(
     args: &[ParsedArg],
     params: &[trust_ide::CallSignatureParam],
@@ -60,6 +60,7 @@ pub(in super::super) fn is_foldable_kind(kind: SyntaxKind) -> bool {
             | SyntaxKind::ForStmt
             | SyntaxKind::WhileStmt
             | SyntaxKind::RepeatStmt
+            | SyntaxKind::TryStmt
     )
 }
 
@@ -121,7 +122,9 @@ pub(in super::super) fn ranges_intersect(a: Range, b: Range) -> bool {
 }
 
 pub(in super::super) fn expected_end_keyword(message: &str) -> Option<&str> {
-    let rest = message.strip_prefix("expected ")?;
+    let rest = message
+        .strip_prefix("expected ")
+        .or_else(|| message.strip_prefix("missing "))?;
     let token = rest.split_whitespace().next()?;
     if token.starts_with("END_") {
         Some(token)
@@ -137,9 +140,9 @@ pub(in super::super) fn node_kind_for_end_keyword(keyword: &str) -> Option<Synta
         "END_FOR" => Some(SyntaxKind::ForStmt),
         "END_WHILE" => Some(SyntaxKind::WhileStmt),
         "END_REPEAT" => Some(SyntaxKind::RepeatStmt),
-        "END_PROGRAM" => Some(SyntaxKind::Program),
+        "END_PROGRAM" | "END_TEST_PROGRAM" => Some(SyntaxKind::Program),
         "END_FUNCTION" => Some(SyntaxKind::Function),
-        "END_FUNCTION_BLOCK" => Some(SyntaxKind::FunctionBlock),
+        "END_FUNCTION_BLOCK" | "END_TEST_FUNCTION_BLOCK" => Some(SyntaxKind::FunctionBlock),
         "END_CLASS" => Some(SyntaxKind::Class),
         "END_INTERFACE" => Some(SyntaxKind::Interface),
         "END_NAMESPACE" => Some(SyntaxKind::Namespace),
@@ -147,6 +150,8 @@ pub(in super::super) fn node_kind_for_end_keyword(keyword: &str) -> Option<Synta
         "END_VAR" => Some(SyntaxKind::VarBlock),
         "END_METHOD" => Some(SyntaxKind::Method),
         "END_PROPERTY" => Some(SyntaxKind::Property),
+        "END_GET" => Some(SyntaxKind::PropertyGet),
+        "END_SET" => Some(SyntaxKind::PropertySet),
         "END_ACTION" => Some(SyntaxKind::Action),
         "END_CONFIGURATION" => Some(SyntaxKind::Configuration),
         "END_RESOURCE" => Some(SyntaxKind::Resource),
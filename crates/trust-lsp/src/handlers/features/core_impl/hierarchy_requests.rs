@@ -1,5 +1,9 @@
 use super::*;
 
+/// Reference-count lenses for the POUs, methods and properties of a document.
+///
+/// Counting runs `find_references` per declaration, so results are cached
+/// until the source revision of the project changes.
 pub fn code_lens(state: &ServerState, params: CodeLensParams) -> Option<Vec<CodeLens>> {
     let uri = &params.text_document.uri;
     let doc = state.get_document(uri)?;
@@ -9,9 +13,14 @@ pub fn code_lens(state: &ServerState, params: CodeLensParams) -> Option<Vec<Code
         references: Vec<trust_ide::Reference>,
     }
 
-    let entries = state.with_database(|db| {
+    let revision = state.with_database(|db| db.source_revision());
+    if let Some(lenses) = state.cached_code_lenses(uri, revision) {
+        return Some(lenses);
+    }
+
+    let (revision, entries) = state.with_database(|db| {
         let symbols = db.file_symbols(doc.file_id);
-        symbols
+        let entries = symbols
             .iter()
             .filter(|symbol| {
                 is_code_lens_symbol(&symbol.kind)
@@ -32,7 +41,8 @@ pub fn code_lens(state: &ServerState, params: CodeLensParams) -> Option<Vec<Code
                     references,
                 }
             })
-            .collect::<Vec<_>>()
+            .collect::<Vec<_>>();
+        (db.source_revision(), entries)
     });
 
     let mut lenses = Vec::new();
@@ -55,7 +65,10 @@ pub fn code_lens(state: &ServerState, params: CodeLensParams) -> Option<Vec<Code
             });
         }
 
-        let title = format!("References: {}", locations.len());
+        let title = match locations.len() {
+            1 => "1 reference".to_string(),
+            count => format!("{count} references"),
+        };
         let position = offset_to_position(&doc.content, entry.range.start().into());
 
         let command = Command {
@@ -71,6 +84,7 @@ pub fn code_lens(state: &ServerState, params: CodeLensParams) -> Option<Vec<Code
         });
     }
 
+    state.store_code_lenses(uri.clone(), revision, lenses.clone());
     Some(lenses)
 }
 
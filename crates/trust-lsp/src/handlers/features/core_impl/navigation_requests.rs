@@ -153,9 +153,16 @@ fn references_with_ticket(
     let options = trust_ide::references::FindReferencesOptions {
         include_declaration: params.context.include_declaration,
     };
+    let is_cancelled = || state.semantic_request_cancelled(request_ticket);
     let refs = state.with_database(|db| {
-        trust_ide::find_references(db, doc.file_id, TextSize::from(offset), options)
-    });
+        trust_ide::find_references_cancellable(
+            db,
+            doc.file_id,
+            TextSize::from(offset),
+            options,
+            &is_cancelled,
+        )
+    })?;
 
     if state.semantic_request_cancelled(request_ticket) {
         return None;
@@ -190,7 +197,10 @@ pub async fn references_with_progress(
     let work_done_token = params.work_done_progress_params.work_done_token.clone();
     let partial_token = params.partial_result_params.partial_result_token.clone();
     send_work_done_begin(client, &work_done_token, "Finding references", None).await;
-    let result = references(state, params);
+    let result = {
+        let _pinned = state.pin_all_documents();
+        references(state, params)
+    };
 
     if let Some(locations) = result.as_ref() {
         if partial_token.is_some() {
@@ -202,6 +202,29 @@ pub fn linked_editing_range(
     })
 }
 
+fn inlay_hint_options(state: &ServerState) -> trust_ide::InlayHintOptions {
+    let mut options = trust_ide::InlayHintOptions::default();
+    let value = state.config();
+    let inlay = lsp_section(&value)
+        .and_then(|section| value_with_aliases(section, &["inlayHints", "inlay_hints"]));
+    if let Some(enabled) =
+        inlay.and_then(|inlay| bool_with_aliases(inlay, &["parameterNames", "parameter_names"]))
+    {
+        options.parameter_names = enabled;
+    }
+    if let Some(enabled) =
+        inlay.and_then(|inlay| bool_with_aliases(inlay, &["receiverTypes", "receiver_types"]))
+    {
+        options.receiver_types = enabled;
+    }
+    if let Some(enabled) =
+        inlay.and_then(|inlay| bool_with_aliases(inlay, &["defaultValues", "default_values"]))
+    {
+        options.default_values = enabled;
+    }
+    options
+}
+
 pub fn inlay_hint(state: &ServerState, params: InlayHintParams) -> Option<Vec<InlayHint>> {
     let uri = &params.text_document.uri;
     let doc = state.get_document(uri)?;
@@ -212,11 +235,13 @@ pub fn inlay_hint(state: &ServerState, params: InlayHintParams) -> Option<Vec<In
         return Some(Vec::new());
     }
 
+    let options = inlay_hint_options(state);
     let hints = state.with_database(|db| {
-        trust_ide::inlay_hints(
+        trust_ide::inlay_hints_with_options(
             db,
             doc.file_id,
             TextRange::new(TextSize::from(start_offset), TextSize::from(end_offset)),
+            options,
         )
     });
 
@@ -225,7 +250,10 @@ pub fn inlay_hint(state: &ServerState, params: InlayHintParams) -> Option<Vec<In
         .map(|hint| {
             let position = offset_to_position(&doc.content, u32::from(hint.position));
             let kind = match hint.kind {
-                trust_ide::InlayHintKind::Parameter => InlayHintKind::PARAMETER,
+                trust_ide::InlayHintKind::Parameter | trust_ide::InlayHintKind::DefaultValue => {
+                    InlayHintKind::PARAMETER
+                }
+                trust_ide::InlayHintKind::ReceiverType => InlayHintKind::TYPE,
             };
             InlayHint {
                 position,
@@ -234,7 +262,7 @@ pub fn inlay_hint(state: &ServerState, params: InlayHintParams) -> Option<Vec<In
                 text_edits: None,
                 tooltip: None,
                 padding_left: None,
-                padding_right: Some(true),
+                padding_right: Some(hint.kind == trust_ide::InlayHintKind::Parameter),
                 data: None,
             }
         })
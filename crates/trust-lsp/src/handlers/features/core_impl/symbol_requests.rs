@@ -115,7 +115,7 @@ fn workspace_symbol_with_ticket(
                 start: offset_to_position(&doc.content, symbol.range.start().into()),
                 end: offset_to_position(&doc.content, symbol.range.end().into()),
             };
-            let container_name = symbol_container_name(&symbols, symbol);
+            let container_name = trust_ide::symbol_container_path(&symbols, symbol);
 
             #[allow(deprecated)]
             result.push((
@@ -199,7 +199,10 @@ pub async fn workspace_symbol_with_progress(
     )
     .await;
 
-    let result = workspace_symbol(state, params);
+    let result = {
+        let _pinned = state.pin_all_documents();
+        workspace_symbol(state, params)
+    };
 
     if let Some(symbols) = result.as_ref() {
         if partial_token.is_some() {
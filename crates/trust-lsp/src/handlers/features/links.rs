@@ -2,6 +2,8 @@ use std::path::{Path, PathBuf};
 
 use text_size::TextRange;
 use tower_lsp::lsp_types::{DocumentLink, DocumentLinkParams, Range, Url};
+use trust_hir::db::SemanticDatabase;
+use trust_hir::symbols::SymbolKind;
 
 use crate::config::{find_config_file, CONFIG_FILES};
 use crate::state::{path_to_uri, uri_to_path, ServerState};
@@ -39,6 +41,13 @@ fn document_links_for_using(
     state: &ServerState,
     doc: &crate::state::Document,
 ) -> Vec<DocumentLink> {
+    let mut documents: Vec<_> = state
+        .documents()
+        .into_iter()
+        .filter(|candidate| uri_to_path(&candidate.uri).is_some_and(|path| is_st_file(&path)))
+        .collect();
+    documents.sort_by(|a, b| a.uri.as_str().cmp(b.uri.as_str()));
+
     let entries = state.with_database(|db| {
         let symbols = db.file_symbols_with_project(doc.file_id);
         let mut entries = Vec::new();
@@ -47,37 +56,75 @@ fn document_links_for_using(
                 if using.range.is_empty() || using.path.is_empty() {
                     continue;
                 }
-                let Some(symbol_id) = symbols.resolve_qualified(&using.path) else {
-                    continue;
-                };
-                let Some(symbol) = symbols.get(symbol_id) else {
-                    continue;
-                };
-                let file_id = symbol
-                    .origin
-                    .map(|origin| origin.file_id)
-                    .unwrap_or(doc.file_id);
-                entries.push((using.range, file_id));
+                let declarations: Vec<_> = documents
+                    .iter()
+                    .filter_map(|candidate| {
+                        let local = db.file_symbols(candidate.file_id);
+                        let declaration = local
+                            .iter()
+                            .filter(|symbol| {
+                                matches!(symbol.kind, SymbolKind::Namespace)
+                                    && symbol.origin.is_none()
+                            })
+                            .find(|symbol| {
+                                let path = local.qualified_path(symbol.id);
+                                path.len() == using.path.len()
+                                    && path
+                                        .iter()
+                                        .zip(&using.path)
+                                        .all(|(a, b)| a.eq_ignore_ascii_case(b))
+                            })
+                            .map(|symbol| (candidate, symbol.range));
+                        declaration
+                    })
+                    .collect();
+                if !declarations.is_empty() {
+                    entries.push((using.range, declarations));
+                }
             }
         }
         entries
     });
 
     let mut links = Vec::new();
-    for (range, file_id) in entries {
-        let Some(target_doc) = state.document_for_file_id(file_id) else {
-            continue;
+    for (range, declarations) in entries {
+        let (target_doc, target_range) = declarations[0];
+        let mut target = target_doc.uri.clone();
+        let start = offset_to_position(&target_doc.content, target_range.start().into());
+        target.set_fragment(Some(&format!(
+            "L{},{}",
+            start.line + 1,
+            start.character + 1
+        )));
+        let others: Vec<_> = declarations[1..]
+            .iter()
+            .map(|(other, _)| file_label(&other.uri))
+            .collect();
+        let tooltip = if others.is_empty() {
+            "Open namespace definition".to_string()
+        } else {
+            format!(
+                "Open namespace definition (also declared in {})",
+                others.join(", ")
+            )
         };
         links.push(DocumentLink {
             range: text_range_to_lsp(&doc.content, range),
-            target: Some(target_doc.uri),
-            tooltip: Some("Open namespace definition".to_string()),
+            target: Some(target),
+            tooltip: Some(tooltip),
             data: None,
         });
     }
     links
 }
 
+fn file_label(uri: &Url) -> String {
+    uri.path_segments()
+        .and_then(|mut segments| segments.next_back())
+        .filter(|name| !name.is_empty())
+        .map_or_else(|| uri.to_string(), str::to_string)
+}
+
 fn document_links_for_config_paths(source: &str, root: &Path) -> Vec<DocumentLink> {
     let mut links = Vec::new();
     let mut in_library_block = false;
@@ -6,6 +6,9 @@ use tower_lsp::lsp_types::{
 };
 
 use serde_json::Value;
+use text_size::{TextRange, TextSize};
+use trust_syntax::parser::parse;
+use trust_syntax::syntax::{SyntaxKind, SyntaxNode, SyntaxToken};
 use trust_syntax::{lex, Token, TokenKind};
 
 use crate::state::ServerState;
@@ -38,12 +41,29 @@ struct FormatConfig {
     insert_spaces: bool,
     keyword_case: KeywordCase,
     align_var_decl_colons: bool,
+    align_var_initializers: bool,
     align_assignments: bool,
     max_line_length: Option<usize>,
     spacing_style: SpacingStyle,
     end_keyword_style: EndKeywordStyle,
 }
 
+impl Default for FormatConfig {
+    fn default() -> Self {
+        Self {
+            indent_width: 4,
+            insert_spaces: true,
+            keyword_case: KeywordCase::Preserve,
+            align_var_decl_colons: true,
+            align_var_initializers: false,
+            align_assignments: true,
+            max_line_length: None,
+            spacing_style: SpacingStyle::Spaced,
+            end_keyword_style: EndKeywordStyle::Aligned,
+        }
+    }
+}
+
 fn format_config(
     state: &ServerState,
     uri: &Url,
@@ -52,15 +72,11 @@ fn format_config(
     let mut config = FormatConfig {
         indent_width: options.tab_size as usize,
         insert_spaces: options.insert_spaces,
-        keyword_case: KeywordCase::Preserve,
-        align_var_decl_colons: true,
-        align_assignments: true,
-        max_line_length: None,
-        spacing_style: SpacingStyle::Spaced,
-        end_keyword_style: EndKeywordStyle::Aligned,
+        ..FormatConfig::default()
     };
 
-    if let Some(workspace_config) = state.workspace_config_for_uri(uri) {
+    let workspace_config = state.workspace_config_for_uri(uri);
+    if let Some(workspace_config) = workspace_config.as_ref() {
         let overrides = format_profile_overrides(workspace_config.vendor_profile.as_deref());
         apply_format_overrides(&mut config, overrides);
     }
@@ -85,9 +101,22 @@ fn format_config(
                 _ => KeywordCase::Preserve,
             };
         }
-        if let Some(align) = bool_with_aliases(format, &["alignVarDecls", "align_var_decls"]) {
+        if let Some(align) = bool_with_aliases(
+            format,
+            &[
+                "alignVarDecls",
+                "align_var_decls",
+                "alignVarDeclarations",
+                "align_var_declarations",
+            ],
+        ) {
             config.align_var_decl_colons = align;
         }
+        if let Some(align) =
+            bool_with_aliases(format, &["alignVarInitializers", "align_var_initializers"])
+        {
+            config.align_var_initializers = align;
+        }
         if let Some(align) = bool_with_aliases(format, &["alignAssignments", "align_assignments"]) {
             config.align_assignments = align;
         }
@@ -113,6 +142,18 @@ fn format_config(
         }
     }
 
+    if let Some(workspace_config) = workspace_config.as_ref() {
+        // The project file is shared by the whole team, so it wins over
+        // per-user client settings.
+        let formatting = &workspace_config.formatting;
+        if let Some(align) = formatting.align_var_declarations {
+            config.align_var_decl_colons = align;
+        }
+        if let Some(align) = formatting.align_var_initializers {
+            config.align_var_initializers = align;
+        }
+    }
+
     config
 }
 
@@ -191,19 +232,17 @@ pub fn formatting(state: &ServerState, params: DocumentFormattingParams) -> Opti
     let uri = &params.text_document.uri;
     let doc = state.get_document(uri)?;
     let config = format_config(state, uri, &params.options);
-    let formatted = format_document(&doc.content, &config);
-    if formatted == doc.content {
-        return Some(Vec::new());
-    }
+    Some(document_format_edits(&doc.content, &config))
+}
 
-    let end = offset_to_position(&doc.content, doc.content.len() as u32);
-    Some(vec![TextEdit {
-        range: Range {
-            start: Position::new(0, 0),
-            end,
-        },
-        new_text: formatted,
-    }])
+/// Returns the edits that bring `source` into the form `config` describes;
+/// an empty result means the source is already formatted.
+fn document_format_edits(source: &str, config: &FormatConfig) -> Vec<TextEdit> {
+    let formatted = format_document(source, config);
+    if formatted == source {
+        return Vec::new();
+    }
+    minimal_line_edits(source, &formatted)
 }
 
 pub fn range_formatting(
@@ -241,6 +280,12 @@ pub fn on_type_formatting(
     let doc = state.get_document(uri)?;
 
     let config = format_config(state, uri, &params.options);
+    if params.ch == "\n" {
+        let line = params.text_document_position.position.line as usize;
+        if let Some(edits) = enter_edits(&doc.content, line, &config) {
+            return Some(edits);
+        }
+    }
     let formatted = format_document(&doc.content, &config);
     if formatted == doc.content {
         return Some(Vec::new());
@@ -322,6 +367,9 @@ fn format_document(source: &str, config: &FormatConfig) -> String {
     let mut line_in_var_block = vec![false; line_count];
     let mut line_colon_index: Vec<Option<usize>> = vec![None; line_count];
     let mut in_var_block = false;
+    // Indent of each open CASE line; branch labels sit one level below it and
+    // branch bodies two.
+    let mut case_indents: Vec<i32> = Vec::new();
 
     for i in 0..line_count {
         let line_start = line_starts[i];
@@ -376,6 +424,21 @@ fn format_document(source: &str, config: &FormatConfig) -> String {
                 }
             }
         }
+        let case_line = case_indents
+            .last()
+            .and_then(|case_indent| case_line_kind(tokens, *case_indent, indent_level))
+            .map(|kind| (kind, case_indents[case_indents.len() - 1]));
+        match case_line {
+            Some((CaseLine::End, case_indent)) => {
+                current_indent = match config.end_keyword_style {
+                    EndKeywordStyle::Aligned => case_indent,
+                    EndKeywordStyle::Indented => case_indent + 1,
+                };
+                dedent_after = false;
+            }
+            Some((CaseLine::Label, case_indent)) => current_indent = case_indent + 1,
+            None => {}
+        }
 
         let indent_prefix = indent_unit.repeat(current_indent as usize);
         let formatted_line = if line_has_line_comment[i] || line_has_pragma[i] {
@@ -399,6 +462,18 @@ fn format_document(source: &str, config: &FormatConfig) -> String {
         if dedent_after {
             indent_level = indent_level.saturating_sub(1);
         }
+        match case_line {
+            Some((CaseLine::End, case_indent)) => {
+                case_indents.pop();
+                indent_level = case_indent;
+            }
+            // `ELSE` already opened the body through `line_has_indent_start`.
+            Some((CaseLine::Label, _)) if tokens[0].kind != TokenKind::KwElse => indent_level += 1,
+            _ => {}
+        }
+        if tokens.iter().any(|token| token.kind == TokenKind::KwCase) {
+            case_indents.push(current_indent);
+        }
         if has_var_start {
             in_var_block = true;
         }
@@ -408,7 +483,12 @@ fn format_document(source: &str, config: &FormatConfig) -> String {
     }
 
     if config.align_var_decl_colons {
-        align_var_block_colons(&mut output_lines, &line_in_var_block, &line_colon_index);
+        align_var_block_colons(
+            &mut output_lines,
+            &line_in_var_block,
+            &line_colon_index,
+            config.align_var_initializers,
+        );
     }
     let line_masks = LineFormatMasks {
         in_var_block: &line_in_var_block,
@@ -441,4 +521,5 @@ fn format_document(source: &str, config: &FormatConfig) -> String {
 
 include!("formatting/block_and_line_formatting.rs");
 include!("formatting/alignment_wrap_and_tests.rs");
+include!("formatting/on_type_enter.rs");
 include!("formatting/tests.rs");
@@ -42,6 +42,56 @@ fn format_lines_edit(
     })
 }
 
+/// Returns one edit per changed line, trimmed to the characters that differ,
+/// so clients see only the padding and spacing the formatter changed.
+fn minimal_line_edits(source: &str, formatted: &str) -> Vec<TextEdit> {
+    let source_lines: Vec<&str> = source.split('\n').collect();
+    let formatted_lines: Vec<&str> = formatted.split('\n').collect();
+    if source_lines.len() != formatted_lines.len() {
+        // Wrapping shifts later lines, so cover everything between the first
+        // and the last difference with a single edit instead.
+        return vec![trimmed_edit(0, source, formatted)];
+    }
+    source_lines
+        .iter()
+        .zip(&formatted_lines)
+        .enumerate()
+        .filter(|(_, (old, new))| old != new)
+        .map(|(line, (old, new))| trimmed_edit(line as u32, old, new))
+        .collect()
+}
+
+/// Replaces `old` (starting at `first_line`) with `new`, skipping the common
+/// prefix and suffix.
+fn trimmed_edit(first_line: u32, old: &str, new: &str) -> TextEdit {
+    let prefix: usize = old
+        .chars()
+        .zip(new.chars())
+        .take_while(|(a, b)| a == b)
+        .map(|(ch, _)| ch.len_utf8())
+        .sum();
+    let suffix: usize = old[prefix..]
+        .chars()
+        .rev()
+        .zip(new[prefix..].chars().rev())
+        .take_while(|(a, b)| a == b)
+        .map(|(ch, _)| ch.len_utf8())
+        .sum();
+    TextEdit {
+        range: Range {
+            start: end_position(first_line, &old[..prefix]),
+            end: end_position(first_line, &old[..old.len() - suffix]),
+        },
+        new_text: new[prefix..new.len() - suffix].to_string(),
+    }
+}
+
+fn end_position(first_line: u32, text: &str) -> Position {
+    let line = first_line + text.matches('\n').count() as u32;
+    let column = text.rsplit('\n').next().unwrap_or_default().chars().count();
+    Position::new(line, column as u32)
+}
+
 fn line_starts(source: &str) -> Vec<usize> {
     let mut starts = vec![0];
     for (idx, ch) in source.char_indices() {
@@ -79,6 +129,7 @@ enum BlockKind {
     For,
     While,
     Repeat,
+    Try,
     Get,
     Set,
     Step,
@@ -173,6 +224,7 @@ fn block_start_kind(kind: TokenKind) -> Option<BlockKind> {
         TokenKind::KwFor => Some(BlockKind::For),
         TokenKind::KwWhile => Some(BlockKind::While),
         TokenKind::KwRepeat => Some(BlockKind::Repeat),
+        TokenKind::KwTryDunder => Some(BlockKind::Try),
         TokenKind::KwGet => Some(BlockKind::Get),
         TokenKind::KwSet => Some(BlockKind::Set),
         TokenKind::KwStep => Some(BlockKind::Step),
@@ -203,6 +255,7 @@ fn block_end_kind(kind: TokenKind) -> Option<BlockKind> {
         TokenKind::KwEndFor => Some(BlockKind::For),
         TokenKind::KwEndWhile => Some(BlockKind::While),
         TokenKind::KwEndRepeat => Some(BlockKind::Repeat),
+        TokenKind::KwEndTryDunder => Some(BlockKind::Try),
         TokenKind::KwEndGet => Some(BlockKind::Get),
         TokenKind::KwEndSet => Some(BlockKind::Set),
         TokenKind::KwEndStep => Some(BlockKind::Step),
@@ -233,11 +286,14 @@ fn is_dedent_token(kind: TokenKind) -> bool {
             | TokenKind::KwEndFor
             | TokenKind::KwEndWhile
             | TokenKind::KwEndRepeat
+            | TokenKind::KwEndTryDunder
             | TokenKind::KwEndGet
             | TokenKind::KwEndSet
             | TokenKind::KwElse
             | TokenKind::KwElsif
             | TokenKind::KwUntil
+            | TokenKind::KwCatchDunder
+            | TokenKind::KwFinallyDunder
     )
 }
 
@@ -261,11 +317,36 @@ fn is_end_keyword(kind: TokenKind) -> bool {
             | TokenKind::KwEndFor
             | TokenKind::KwEndWhile
             | TokenKind::KwEndRepeat
+            | TokenKind::KwEndTryDunder
             | TokenKind::KwEndGet
             | TokenKind::KwEndSet
     )
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum CaseLine {
+    /// A branch label (`1, 2:`) or the `ELSE` of the CASE itself.
+    Label,
+    End,
+}
+
+/// Classifies a line directly inside a CASE statement whose line sits at
+/// `case_indent`. Lines nested deeper than a branch body (e.g. the `ELSE` of
+/// an inner IF) are not CASE lines.
+fn case_line_kind(tokens: &[Token], case_indent: i32, indent_level: i32) -> Option<CaseLine> {
+    let first = tokens.first()?.kind;
+    if first == TokenKind::KwEndCase {
+        return Some(CaseLine::End);
+    }
+    if indent_level > case_indent + 2 {
+        return None;
+    }
+    let is_label = first == TokenKind::KwElse
+        || (first != TokenKind::KwCase
+            && tokens.iter().any(|token| token.kind == TokenKind::Colon));
+    is_label.then_some(CaseLine::Label)
+}
+
 fn line_has_indent_start(tokens: &[Token]) -> bool {
     tokens.iter().any(|token| {
         let kind = token.kind;
@@ -297,10 +378,13 @@ fn line_has_indent_start(tokens: &[Token]) -> bool {
                 | TokenKind::KwFor
                 | TokenKind::KwWhile
                 | TokenKind::KwRepeat
+                | TokenKind::KwTryDunder
                 | TokenKind::KwGet
                 | TokenKind::KwSet
                 | TokenKind::KwElse
                 | TokenKind::KwElsif
+                | TokenKind::KwCatchDunder
+                | TokenKind::KwFinallyDunder
         )
     })
 }
@@ -432,6 +516,7 @@ fn align_var_block_colons(
     lines: &mut [String],
     line_in_var_block: &[bool],
     line_colon_index: &[Option<usize>],
+    align_initializers: bool,
 ) {
     let mut i = 0usize;
     while i < lines.len() {
@@ -479,6 +564,28 @@ fn align_var_block_colons(
             updated.push_str(&line[colon_idx..]);
             lines[idx] = updated;
         }
+
+        if align_initializers {
+            align_var_initializers(&mut lines[start..i], max_colon);
+        }
+    }
+}
+
+/// Lines up the `:=` of the declarations in one alignment group; lines
+/// without an initializer are left alone.
+fn align_var_initializers(lines: &mut [String], colon_idx: usize) {
+    let initializer = |line: &str| {
+        line.get(colon_idx..)
+            .and_then(|rest| rest.find(":="))
+            .map(|offset| colon_idx + offset)
+    };
+    let Some(max_op) = lines.iter().filter_map(|line| initializer(line)).max() else {
+        return;
+    };
+    for line in lines.iter_mut() {
+        if let Some(op_idx) = initializer(line) {
+            line.insert_str(op_idx, &" ".repeat(max_op - op_idx));
+        }
     }
 }
 
@@ -0,0 +1,135 @@
+/// Edits for the line created by pressing Enter, derived from the CST around
+/// the previous code line.
+///
+/// Returns `None` when no rule applies so the caller falls back to plain line
+/// formatting. Indents are taken from anchor lines (the CASE or label line)
+/// rather than the current whitespace, so repeated Enter presses converge on
+/// the same indentation instead of accumulating it.
+fn enter_edits(source: &str, line: usize, config: &FormatConfig) -> Option<Vec<TextEdit>> {
+    let lines: Vec<&str> = source
+        .split('\n')
+        .map(|text| text.strip_suffix('\r').unwrap_or(text))
+        .collect();
+    let current = *lines.get(line)?;
+    let prev_line = (0..line).rev().find(|idx| !lines[*idx].trim().is_empty())?;
+    let line_starts = line_starts(source);
+    let prev_start = line_starts[prev_line];
+    let prev_end = prev_start + lines[prev_line].len();
+
+    let root = parse(source).syntax();
+    let first = code_token_on_line(&root, prev_start, prev_end, true)?;
+    let last = code_token_on_line(&root, prev_start, prev_end, false)?;
+    let unit = if config.insert_spaces {
+        " ".repeat(config.indent_width.max(1))
+    } else {
+        "\t".to_string()
+    };
+    let prev_indent = leading_whitespace(lines[prev_line]);
+
+    let mut edits = Vec::new();
+    let indent = match (first.kind(), last.kind()) {
+        (SyntaxKind::KwEndCase, _) if parent_is(&first, SyntaxKind::CaseStmt) => {
+            let case_indent = keyword_line_indent(source, first.parent()?, SyntaxKind::KwCase)?;
+            edits.extend(indent_edit(prev_line, lines[prev_line], case_indent));
+            case_indent.to_string()
+        }
+        (_, SyntaxKind::KwOf) if parent_is(&last, SyntaxKind::CaseStmt) => {
+            let case_indent = keyword_line_indent(source, last.parent()?, SyntaxKind::KwCase)?;
+            format!("{case_indent}{unit}")
+        }
+        (_, SyntaxKind::Colon) if parent_is(&last, SyntaxKind::CaseBranch) => {
+            format!("{prev_indent}{unit}")
+        }
+        (_, SyntaxKind::KwThen | SyntaxKind::KwElse) => format!("{prev_indent}{unit}"),
+        (SyntaxKind::KwIf | SyntaxKind::KwElsif, _) if condition_awaits_then(&first, &last) => {
+            let insert_at = offset_to_position(source, u32::from(last.text_range().end()));
+            edits.push(TextEdit {
+                range: Range::new(insert_at, insert_at),
+                new_text: format!(" {}", keyword_like(first.text(), "THEN")),
+            });
+            format!("{prev_indent}{unit}")
+        }
+        _ => return None,
+    };
+    edits.extend(indent_edit(line, current, &indent));
+    Some(edits)
+}
+
+/// First (or last) non-trivia token starting on the line `[start, end)`.
+fn code_token_on_line(
+    root: &SyntaxNode,
+    start: usize,
+    end: usize,
+    first: bool,
+) -> Option<SyntaxToken> {
+    let range = TextRange::new(TextSize::from(start as u32), TextSize::from(end as u32));
+    let mut tokens = root
+        .descendants_with_tokens()
+        .filter_map(|element| element.into_token())
+        .skip_while(|token| token.text_range().end() <= range.start())
+        .take_while(|token| token.text_range().start() < range.end())
+        .filter(|token| !token.kind().is_trivia() && range.contains(token.text_range().start()));
+    if first {
+        tokens.next()
+    } else {
+        tokens.last()
+    }
+}
+
+fn parent_is(token: &SyntaxToken, kind: SyntaxKind) -> bool {
+    token.parent().is_some_and(|parent| parent.kind() == kind)
+}
+
+/// Indentation of the line holding `node`'s `keyword` token.
+fn keyword_line_indent(source: &str, node: SyntaxNode, keyword: SyntaxKind) -> Option<&str> {
+    let token = node
+        .children_with_tokens()
+        .filter_map(|element| element.into_token())
+        .find(|token| token.kind() == keyword)?;
+    let offset = usize::from(token.text_range().start());
+    let line_start = source[..offset].rfind('\n').map_or(0, |idx| idx + 1);
+    Some(leading_whitespace(&source[line_start..offset]))
+}
+
+/// True when `first` opens an IF/ELSIF whose condition ends with `last` and
+/// no THEN follows it yet.
+fn condition_awaits_then(first: &SyntaxToken, last: &SyntaxToken) -> bool {
+    let Some(node) = first.parent() else {
+        return false;
+    };
+    if !matches!(node.kind(), SyntaxKind::IfStmt | SyntaxKind::ElsifBranch) {
+        return false;
+    }
+    let has_then = node
+        .children_with_tokens()
+        .any(|element| element.kind() == SyntaxKind::KwThen);
+    let condition = node.children().next();
+    !has_then
+        && condition.is_some_and(|condition| {
+            condition.text_range().start() >= first.text_range().end()
+                && condition.text_range().contains_range(last.text_range())
+        })
+}
+
+/// Spells `keyword` in the case of `sample` (`if` -> `then`).
+fn keyword_like(sample: &str, keyword: &str) -> String {
+    if sample.chars().any(|ch| ch.is_ascii_uppercase()) {
+        keyword.to_string()
+    } else {
+        keyword.to_ascii_lowercase()
+    }
+}
+
+fn indent_edit(line: usize, text: &str, indent: &str) -> Option<TextEdit> {
+    let current = leading_whitespace(text);
+    if current == indent {
+        return None;
+    }
+    Some(TextEdit {
+        range: Range::new(
+            Position::new(line as u32, 0),
+            Position::new(line as u32, current.chars().count() as u32),
+        ),
+        new_text: indent.to_string(),
+    })
+}
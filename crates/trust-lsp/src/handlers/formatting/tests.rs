@@ -1,6 +1,10 @@
 #[cfg(test)]
 mod tests {
-    use super::{format_document, EndKeywordStyle, FormatConfig, KeywordCase, SpacingStyle};
+    use super::{
+        document_format_edits, format_document, EndKeywordStyle, FormatConfig, KeywordCase,
+        SpacingStyle,
+    };
+    use crate::test_support::apply_text_edits;
 
     #[test]
     fn format_document_normalizes_spacing() {
@@ -10,6 +14,7 @@ mod tests {
             insert_spaces: true,
             keyword_case: KeywordCase::Preserve,
             align_var_decl_colons: true,
+            align_var_initializers: false,
             align_assignments: true,
             max_line_length: None,
             spacing_style: SpacingStyle::Spaced,
@@ -30,6 +35,7 @@ mod tests {
             insert_spaces: true,
             keyword_case: KeywordCase::Preserve,
             align_var_decl_colons: true,
+            align_var_initializers: false,
             align_assignments: true,
             max_line_length: None,
             spacing_style: SpacingStyle::Spaced,
@@ -56,6 +62,7 @@ mod tests {
             insert_spaces: true,
             keyword_case: KeywordCase::Preserve,
             align_var_decl_colons: true,
+            align_var_initializers: false,
             align_assignments: true,
             max_line_length: None,
             spacing_style: SpacingStyle::Spaced,
@@ -82,6 +89,7 @@ mod tests {
             insert_spaces: true,
             keyword_case: KeywordCase::Preserve,
             align_var_decl_colons: true,
+            align_var_initializers: false,
             align_assignments: true,
             max_line_length: None,
             spacing_style: SpacingStyle::Compact,
@@ -100,6 +108,7 @@ mod tests {
             insert_spaces: true,
             keyword_case: KeywordCase::Preserve,
             align_var_decl_colons: true,
+            align_var_initializers: false,
             align_assignments: true,
             max_line_length: None,
             spacing_style: SpacingStyle::Spaced,
@@ -125,6 +134,7 @@ mod tests {
             insert_spaces: true,
             keyword_case: KeywordCase::Preserve,
             align_var_decl_colons: true,
+            align_var_initializers: false,
             align_assignments: true,
             max_line_length: None,
             spacing_style: SpacingStyle::Spaced,
@@ -142,6 +152,7 @@ mod tests {
             insert_spaces: true,
             keyword_case: KeywordCase::Preserve,
             align_var_decl_colons: true,
+            align_var_initializers: false,
             align_assignments: true,
             max_line_length: Some(20),
             spacing_style: SpacingStyle::Spaced,
@@ -163,4 +174,68 @@ mod tests {
             longer_line.find(":=").unwrap()
         );
     }
+
+    #[test]
+    fn format_edits_are_empty_for_canonical_source() {
+        let source =
+            "PROGRAM Main\n    VAR\n        x: INT;\n    END_VAR\n    x := x + 1;\nEND_PROGRAM\n";
+        assert!(document_format_edits(source, &FormatConfig::default()).is_empty());
+    }
+
+    #[test]
+    fn format_edits_apply_to_canonical_form() {
+        let source = "PROGRAM Main\nVAR\nx:INT;\nEND_VAR\nx:=x+1;\nEND_PROGRAM\n";
+        let config = FormatConfig::default();
+        let edits = document_format_edits(source, &config);
+        let lines: Vec<u32> = edits.iter().map(|edit| edit.range.start.line).collect();
+        assert_eq!(lines, [1, 2, 3, 4]);
+        let formatted = apply_text_edits(source, &edits);
+        assert_eq!(formatted, format_document(source, &config));
+        assert!(formatted.contains("    x := x + 1;"));
+        assert!(document_format_edits(&formatted, &config).is_empty());
+    }
+
+    #[test]
+    fn format_document_aligns_var_initializers_within_groups() {
+        let source = "PROGRAM Test\nVAR\n    a : INT := 1;\n    flag : BOOL;\n    speed : REAL := 2.5;\n\n    other_name : DINT := 3;\n    b : LREAL := 4.0;\nEND_VAR\nEND_PROGRAM\n";
+        let config = FormatConfig {
+            align_var_initializers: true,
+            ..FormatConfig::default()
+        };
+        let formatted = format_document(source, &config);
+        let lines: Vec<&str> = formatted.lines().collect();
+        assert_eq!(
+            &lines[2..8],
+            [
+                "        a    : INT  := 1;",
+                "        flag : BOOL;",
+                "        speed: REAL := 2.5;",
+                "",
+                "        other_name: DINT  := 3;",
+                "        b         : LREAL := 4.0;",
+            ]
+        );
+    }
+
+    #[test]
+    fn format_document_leaves_initializers_unaligned_by_default() {
+        let source = "PROGRAM Test\nVAR\n    a : INT := 1;\n    flag : BOOL;\n    speed : REAL := 2.5;\nEND_VAR\nEND_PROGRAM\n";
+        let formatted = format_document(source, &FormatConfig::default());
+        assert!(formatted.contains("    a    : INT := 1;"));
+        assert!(formatted.contains("    speed: REAL := 2.5;"));
+    }
+
+    #[test]
+    fn format_document_indents_case_branch_bodies_below_labels() {
+        let source = "PROGRAM Main\nCASE mode OF\n1:\nx := 1;\n2, 3: x := 2;\nELSE\nIF x > 0 THEN\nx := 0;\nELSE\nx := 1;\nEND_IF;\nEND_CASE;\nEND_PROGRAM\n";
+        let formatted = format_document(source, &FormatConfig::default());
+        assert_eq!(
+            formatted,
+            "PROGRAM Main\n    CASE mode OF\n        1:\n            x := 1;\n        2, 3: x := 2;\n        ELSE\n            IF x > 0 THEN\n                x := 0;\n            ELSE\n                x := 1;\n            END_IF;\n    END_CASE;\nEND_PROGRAM\n"
+        );
+        assert_eq!(
+            format_document(&formatted, &FormatConfig::default()),
+            formatted
+        );
+    }
 }
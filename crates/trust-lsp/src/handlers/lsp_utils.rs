@@ -117,6 +117,9 @@ pub(crate) fn semantic_tokens_to_lsp(
         if token.modifiers.modification {
             modifiers |= 1 << 4;
         }
+        if token.modifiers.deprecated {
+            modifiers |= 1 << 5;
+        }
 
         let delta_line = line - prev_line;
         let delta_start = if delta_line == 0 {
@@ -203,6 +203,7 @@ fn lsp_project_info_exposes_build_and_targets() {
             runtime: RuntimeConfig::default(),
             workspace: WorkspaceSettings::default(),
             telemetry: TelemetryConfig::default(),
+            formatting: FormattingSettings::default(),
         },
     );
 
@@ -236,3 +237,38 @@ fn lsp_project_info_exposes_build_and_targets() {
             && lib.get("version").and_then(|v| v.as_str()) == Some("1.0")
     }));
 }
+
+#[test]
+fn lsp_expression_type_command_reports_selection_type() {
+    let source =
+        "PROGRAM Main\nVAR\n    a : DINT;\n    b : DINT;\nEND_VAR\na := a + b * 2;\nEND_PROGRAM\n";
+    let state = ServerState::new();
+    let uri = tower_lsp::lsp_types::Url::parse("file:///workspace/main.st").unwrap();
+    state.open_document(uri.clone(), 1, source.to_string());
+    let expression_type = |start: u32, end: u32| {
+        super::commands::expression_type_value(
+            &state,
+            vec![json!({
+                "text_document": { "uri": uri },
+                "range": {
+                    "start": { "line": 5, "character": start },
+                    "end": { "line": 5, "character": end },
+                },
+            })],
+        )
+    };
+
+    let result = expression_type(5, 14).expect("expression type");
+    assert_eq!(result.get("type").and_then(Value::as_str), Some("DINT"));
+    assert_eq!(
+        result.get("range"),
+        Some(&json!({
+            "start": { "line": 5, "character": 5 },
+            "end": { "line": 5, "character": 14 },
+        }))
+    );
+    assert!(
+        expression_type(0, 15).is_none(),
+        "a whole statement is not an expression"
+    );
+}
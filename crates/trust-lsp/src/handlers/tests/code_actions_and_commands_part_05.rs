@@ -153,3 +153,179 @@ END_PROGRAM
         "expected LibB qualification quick fix"
     );
 }
+
+fn named_arguments_action_at(
+    source: &str,
+    needle: &str,
+) -> Option<tower_lsp::lsp_types::CodeAction> {
+    let state = ServerState::new();
+    let uri = tower_lsp::lsp_types::Url::parse("file:///test.st").unwrap();
+    state.open_document(uri.clone(), 1, source.to_string());
+
+    let position = position_at(source, needle);
+    let params = tower_lsp::lsp_types::CodeActionParams {
+        text_document: tower_lsp::lsp_types::TextDocumentIdentifier { uri },
+        range: tower_lsp::lsp_types::Range {
+            start: position,
+            end: position,
+        },
+        context: tower_lsp::lsp_types::CodeActionContext {
+            diagnostics: Vec::new(),
+            only: Some(vec![tower_lsp::lsp_types::CodeActionKind::REFACTOR_REWRITE]),
+            trigger_kind: None,
+        },
+        work_done_progress_params: Default::default(),
+        partial_result_params: Default::default(),
+    };
+    code_action(&state, params)
+        .unwrap_or_default()
+        .into_iter()
+        .find_map(|action| match action {
+            tower_lsp::lsp_types::CodeActionOrCommand::CodeAction(code_action)
+                if code_action.title == "Use named arguments" =>
+            {
+                Some(code_action)
+            }
+            _ => None,
+        })
+}
+
+#[test]
+fn lsp_code_action_converts_positional_call_to_named_arguments() {
+    let source = r#"
+FUNCTION Scale : REAL
+VAR_INPUT
+    value : REAL;
+    gain : REAL;
+    offset : REAL;
+END_VAR
+Scale := value * gain + offset;
+END_FUNCTION
+
+PROGRAM Main
+VAR
+    y : REAL;
+END_VAR
+y := Scale(1.0, 2.0, 3.0);
+END_PROGRAM
+"#;
+    let action = named_arguments_action_at(source, "Scale(1.0").expect("named arguments action");
+    let uri = tower_lsp::lsp_types::Url::parse("file:///test.st").unwrap();
+    let edits = action
+        .edit
+        .as_ref()
+        .and_then(|edit| edit.changes.as_ref())
+        .and_then(|changes| changes.get(&uri))
+        .expect("named arguments edits");
+    assert_eq!(edits.len(), 1);
+    assert_eq!(
+        edits[0].new_text,
+        "(value := 1.0, gain := 2.0, offset := 3.0)"
+    );
+}
+
+#[test]
+fn lsp_code_action_named_arguments_not_offered_for_formal_call() {
+    let source = r#"
+FUNCTION Scale : REAL
+VAR_INPUT
+    value : REAL;
+    gain : REAL;
+END_VAR
+Scale := value * gain;
+END_FUNCTION
+
+PROGRAM Main
+VAR
+    y : REAL;
+END_VAR
+y := Scale(value := 1.0, gain := 2.0);
+END_PROGRAM
+"#;
+    assert!(named_arguments_action_at(source, "Scale(value").is_none());
+}
+
+fn missing_end_edit_at(
+    source: &str,
+    offset: usize,
+) -> Option<(String, tower_lsp::lsp_types::TextEdit)> {
+    let state = ServerState::new();
+    let uri = tower_lsp::lsp_types::Url::parse("file:///test.st").unwrap();
+    state.open_document(uri.clone(), 1, source.to_string());
+
+    let position = super::lsp_utils::offset_to_position(source, offset as u32);
+    let params = tower_lsp::lsp_types::CodeActionParams {
+        text_document: tower_lsp::lsp_types::TextDocumentIdentifier { uri: uri.clone() },
+        range: tower_lsp::lsp_types::Range {
+            start: position,
+            end: position,
+        },
+        context: tower_lsp::lsp_types::CodeActionContext {
+            diagnostics: Vec::new(),
+            only: None,
+            trigger_kind: None,
+        },
+        work_done_progress_params: Default::default(),
+        partial_result_params: Default::default(),
+    };
+    code_action(&state, params)
+        .unwrap_or_default()
+        .into_iter()
+        .find_map(|action| match action {
+            tower_lsp::lsp_types::CodeActionOrCommand::CodeAction(code_action)
+                if code_action.title.starts_with("Insert missing END_") =>
+            {
+                let edit = code_action
+                    .edit
+                    .as_ref()
+                    .and_then(|edit| edit.changes.as_ref())
+                    .and_then(|changes| changes.get(&uri))
+                    .and_then(|edits| edits.first())
+                    .cloned()?;
+                Some((code_action.title, edit))
+            }
+            _ => None,
+        })
+}
+
+#[test]
+fn lsp_code_action_inserts_missing_end_if_with_block_indent() {
+    let source = "PROGRAM Test
+VAR
+    x : INT;
+    y : INT;
+END_VAR
+    IF x > 0 THEN
+        y := 1;
+    x := 2;
+END_PROGRAM
+";
+    let (title, edit) =
+        missing_end_edit_at(source, source.find("IF x").unwrap()).expect("END_IF action");
+    assert_eq!(title, "Insert missing END_IF");
+    assert_eq!(edit.new_text, "    END_IF\n");
+    assert_eq!(edit.range.start, position_at(source, "    x := 2;"));
+    assert_eq!(edit.range.start, edit.range.end);
+
+    let body = source.find("y := 1").unwrap();
+    assert!(
+        missing_end_edit_at(source, body).is_none(),
+        "END_IF action offered away from the diagnostic"
+    );
+}
+
+#[test]
+fn lsp_code_action_inserts_missing_pou_end_at_eof() {
+    let source = "PROGRAM Test
+VAR
+    x : INT;
+END_VAR
+    x := 1;";
+    let (title, edit) = missing_end_edit_at(source, source.len()).expect("END_PROGRAM action");
+    assert_eq!(title, "Insert missing END_PROGRAM");
+    assert_eq!(edit.new_text, "\nEND_PROGRAM\n");
+    assert_eq!(
+        edit.range.start,
+        super::lsp_utils::offset_to_position(source, source.len() as u32)
+    );
+}
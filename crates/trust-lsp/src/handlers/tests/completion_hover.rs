@@ -69,6 +69,7 @@ END_PROGRAM
             runtime: RuntimeConfig::default(),
             workspace: WorkspaceSettings::default(),
             telemetry: TelemetryConfig::default(),
+            formatting: FormattingSettings::default(),
         },
     );
 
@@ -133,6 +134,7 @@ END_PROGRAM
             runtime: RuntimeConfig::default(),
             workspace: WorkspaceSettings::default(),
             telemetry: TelemetryConfig::default(),
+            formatting: FormattingSettings::default(),
         },
     );
 
@@ -281,6 +283,7 @@ END_PROGRAM
             runtime: RuntimeConfig::default(),
             workspace: WorkspaceSettings::default(),
             telemetry: TelemetryConfig::default(),
+            formatting: FormattingSettings::default(),
         },
     );
 
@@ -298,3 +301,101 @@ END_PROGRAM
     let hover = hover(&state, params);
     assert!(hover.is_none(), "expected stdlib hover to be filtered");
 }
+
+#[test]
+fn lsp_completion_defers_symbol_docs_to_resolve() {
+    let source = r#"
+PROGRAM Test
+VAR
+    x : INT;
+END_VAR
+    x := A
+END_PROGRAM
+"#;
+    let state = ServerState::new();
+    let uri = tower_lsp::lsp_types::Url::parse("file:///workspace/test.st").unwrap();
+    state.open_document(uri.clone(), 1, source.to_string());
+
+    let params = tower_lsp::lsp_types::CompletionParams {
+        text_document_position: tower_lsp::lsp_types::TextDocumentPositionParams {
+            text_document: tower_lsp::lsp_types::TextDocumentIdentifier { uri: uri.clone() },
+            position: position_at(source, "A\nEND_PROGRAM"),
+        },
+        work_done_progress_params: Default::default(),
+        partial_result_params: Default::default(),
+        context: None,
+    };
+
+    let response = completion(&state, params).expect("completion response");
+    let items = match response {
+        tower_lsp::lsp_types::CompletionResponse::Array(items) => items,
+        tower_lsp::lsp_types::CompletionResponse::List(list) => list.items,
+    };
+
+    let ton = items
+        .iter()
+        .find(|item| item.label.eq_ignore_ascii_case("TON") && item.data.is_some())
+        .cloned()
+        .expect("TON completion with resolve data");
+    assert!(ton.documentation.is_none());
+    assert!(ton.detail.is_none());
+
+    let resolved = completion_resolve(&state, ton);
+    let detail = resolved.detail.expect("resolved detail");
+    assert!(detail.starts_with("TON("), "unexpected detail: {detail}");
+    let documentation = match resolved.documentation {
+        Some(tower_lsp::lsp_types::Documentation::MarkupContent(content)) => content.value,
+        other => panic!("expected markdown documentation, got {other:?}"),
+    };
+    assert!(documentation.contains("Standard timer function block"));
+}
+
+#[test]
+fn lsp_completion_resolve_survives_edits_that_renumber_symbols() {
+    let source = r#"
+FUNCTION Scale : INT
+VAR_INPUT
+    value : INT;
+END_VAR
+Scale := value * 2;
+END_FUNCTION
+
+PROGRAM Test
+VAR
+    x : INT;
+END_VAR
+    x := S
+END_PROGRAM
+"#;
+    let state = ServerState::new();
+    let uri = tower_lsp::lsp_types::Url::parse("file:///workspace/test.st").unwrap();
+    state.open_document(uri.clone(), 1, source.to_string());
+
+    let params = tower_lsp::lsp_types::CompletionParams {
+        text_document_position: tower_lsp::lsp_types::TextDocumentPositionParams {
+            text_document: tower_lsp::lsp_types::TextDocumentIdentifier { uri: uri.clone() },
+            position: position_at(source, "S\nEND_PROGRAM"),
+        },
+        work_done_progress_params: Default::default(),
+        partial_result_params: Default::default(),
+        context: None,
+    };
+    let items = match completion(&state, params).expect("completion response") {
+        tower_lsp::lsp_types::CompletionResponse::Array(items) => items,
+        tower_lsp::lsp_types::CompletionResponse::List(list) => list.items,
+    };
+    let scale = items
+        .iter()
+        .find(|item| item.label == "Scale" && item.data.is_some())
+        .cloned()
+        .expect("Scale completion with resolve data");
+    assert!(scale.detail.is_none());
+
+    // Declaring a function in front of `Scale` shifts every symbol id after it.
+    let edited = format!("FUNCTION Offset : INT\nOffset := 1;\nEND_FUNCTION\n{source}");
+    state.update_document(&uri, 2, edited);
+
+    let resolved = completion_resolve(&state, scale);
+    let detail = resolved.detail.expect("resolved detail");
+    assert!(detail.starts_with("Scale("), "unexpected detail: {detail}");
+}
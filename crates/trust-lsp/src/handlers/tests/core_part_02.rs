@@ -76,11 +76,15 @@ END_PROGRAM
                 warn_deprecated: true,
                 warn_complexity: false,
                 warn_nondeterminism: true,
+                warn_integer_division: false,
+                warn_real_equality: false,
                 severity_overrides: Default::default(),
+                passes: Default::default(),
             },
             runtime: RuntimeConfig::default(),
             workspace: WorkspaceSettings::default(),
             telemetry: TelemetryConfig::default(),
+            formatting: FormattingSettings::default(),
         },
     );
 
@@ -210,16 +214,16 @@ END_PROGRAM
         "expected did-you-mean hint for E102, got {type_hints:?}"
     );
 
-    let incompatible_assignment = diagnostics
+    let bool_conversion = diagnostics
         .iter()
         .find(|diag| {
             matches!(
                 diag.code.as_ref(),
-                Some(tower_lsp::lsp_types::NumberOrString::String(code)) if code == "E203"
+                Some(tower_lsp::lsp_types::NumberOrString::String(code)) if code == "E208"
             )
         })
-        .expect("expected E203 diagnostic");
-    let conversion_hints: Vec<&str> = incompatible_assignment
+        .expect("expected E208 diagnostic");
+    let conversion_hints: Vec<&str> = bool_conversion
         .related_information
         .as_ref()
         .map(|items| items.iter().map(|item| item.message.as_str()).collect())
@@ -147,6 +147,7 @@ pub(super) fn lsp_external_diagnostics_provide_quick_fixes() {
             runtime: RuntimeConfig::default(),
             workspace: WorkspaceSettings::default(),
             telemetry: TelemetryConfig::default(),
+            formatting: FormattingSettings::default(),
         },
     );
 
@@ -75,6 +75,35 @@ END_PROGRAM
     );
 }
 
+#[test]
+pub(super) fn lsp_workspace_symbols_use_qualified_container_names() {
+    let source = r#"
+NAMESPACE Plant.Motors
+FUNCTION_BLOCK FB_Pump
+METHOD Init
+END_METHOD
+END_FUNCTION_BLOCK
+END_NAMESPACE
+"#;
+
+    let state = ServerState::new();
+    let uri = tower_lsp::lsp_types::Url::parse("file:///plant.st").unwrap();
+    state.open_document(uri, 1, source.to_string());
+
+    let params = tower_lsp::lsp_types::WorkspaceSymbolParams {
+        query: "init".to_string(),
+        work_done_progress_params: Default::default(),
+        partial_result_params: Default::default(),
+    };
+
+    let items = workspace_symbol(&state, params).expect("workspace symbols");
+    let container = items
+        .iter()
+        .find(|item| item.name.starts_with("Init"))
+        .and_then(|item| item.container_name.clone());
+    assert_eq!(container.as_deref(), Some("Plant.Motors.FB_Pump"));
+}
+
 #[test]
 pub(super) fn lsp_workspace_symbols_respect_root_visibility_and_priority() {
     let source = r#"
@@ -110,6 +139,7 @@ END_FUNCTION_BLOCK
                 visibility: crate::config::WorkspaceVisibility::Public,
             },
             telemetry: TelemetryConfig::default(),
+            formatting: FormattingSettings::default(),
         },
     );
     state.set_workspace_config(
@@ -134,6 +164,7 @@ END_FUNCTION_BLOCK
                 visibility: crate::config::WorkspaceVisibility::Private,
             },
             telemetry: TelemetryConfig::default(),
+            formatting: FormattingSettings::default(),
         },
     );
 
@@ -67,6 +67,51 @@ END_PROGRAM
         .any(|hint| inlay_label_contains(&hint.label, "B")));
 }
 
+#[test]
+pub(super) fn lsp_inlay_hints_receiver_types_follow_client_setting() {
+    let source = r#"
+FUNCTION_BLOCK FB_Motor
+VAR_OUTPUT
+    speed : INT;
+END_VAR
+END_FUNCTION_BLOCK
+
+PROGRAM Main
+VAR
+    motor : FB_Motor;
+    result : INT;
+END_VAR
+    result := motor.speed;
+END_PROGRAM
+"#;
+    let state = ServerState::new();
+    let uri = tower_lsp::lsp_types::Url::parse("file:///test.st").unwrap();
+    state.open_document(uri.clone(), 1, source.to_string());
+
+    let params = || tower_lsp::lsp_types::InlayHintParams {
+        text_document: tower_lsp::lsp_types::TextDocumentIdentifier { uri: uri.clone() },
+        range: tower_lsp::lsp_types::Range {
+            start: position_at(source, "result := motor"),
+            end: position_at(source, ";\nEND_PROGRAM"),
+        },
+        work_done_progress_params: Default::default(),
+    };
+
+    let hints = inlay_hint(&state, params()).expect("inlay hints");
+    assert!(hints.is_empty());
+
+    state.set_config(json!({
+        "trust-lsp": { "inlayHints": { "receiverTypes": true } }
+    }));
+    let hints = inlay_hint(&state, params()).expect("inlay hints");
+    assert_eq!(hints.len(), 1);
+    assert_eq!(
+        hints[0].kind,
+        Some(tower_lsp::lsp_types::InlayHintKind::TYPE)
+    );
+    assert!(inlay_label_contains(&hints[0].label, "FB_Motor"));
+}
+
 #[test]
 pub(super) fn lsp_inline_values_constants() {
     let constants = r#"
@@ -190,6 +235,7 @@ pub(super) fn lsp_inline_values_fetch_runtime_values_from_control_stub() {
             },
             workspace: WorkspaceSettings::default(),
             telemetry: TelemetryConfig::default(),
+            formatting: FormattingSettings::default(),
         },
     );
 
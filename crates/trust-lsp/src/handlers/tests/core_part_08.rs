@@ -26,6 +26,7 @@ pub(super) fn lsp_inline_values_runtime_override_accepts_camel_case_client_setti
             runtime: RuntimeConfig::default(),
             workspace: WorkspaceSettings::default(),
             telemetry: TelemetryConfig::default(),
+            formatting: FormattingSettings::default(),
         },
     );
     state.set_config(json!({
@@ -81,6 +82,7 @@ pub(super) fn lsp_inline_values_runtime_override_accepts_snake_case_client_setti
             runtime: RuntimeConfig::default(),
             workspace: WorkspaceSettings::default(),
             telemetry: TelemetryConfig::default(),
+            formatting: FormattingSettings::default(),
         },
     );
     state.set_config(json!({
@@ -140,6 +142,7 @@ pub(super) fn lsp_inline_values_runtime_override_prefers_camel_case_when_aliases
             runtime: RuntimeConfig::default(),
             workspace: WorkspaceSettings::default(),
             telemetry: TelemetryConfig::default(),
+            formatting: FormattingSettings::default(),
         },
     );
     state.set_config(json!({
@@ -216,6 +219,7 @@ END_PROGRAM
             },
             workspace: WorkspaceSettings::default(),
             telemetry: TelemetryConfig::default(),
+            formatting: FormattingSettings::default(),
         },
     );
 
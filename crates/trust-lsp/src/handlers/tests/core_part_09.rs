@@ -38,6 +38,7 @@ END_NAMESPACE
             },
             workspace: WorkspaceSettings::default(),
             telemetry: TelemetryConfig::default(),
+            formatting: FormattingSettings::default(),
         },
     );
 
@@ -142,11 +143,15 @@ pub(super) fn lsp_tutorial_examples_no_unexpected_diagnostics_snapshot() {
                 warn_deprecated: false,
                 warn_complexity: false,
                 warn_nondeterminism: false,
+                warn_integer_division: false,
+                warn_real_equality: false,
                 severity_overrides: Default::default(),
+                passes: Default::default(),
             },
             runtime: RuntimeConfig::default(),
             workspace: WorkspaceSettings::default(),
             telemetry: TelemetryConfig::default(),
+            formatting: FormattingSettings::default(),
         },
     );
     let mut output = serde_json::Map::new();
@@ -32,11 +32,15 @@ pub(super) fn lsp_siemens_hash_prefixed_example_has_no_unexpected_diagnostics()
                 warn_deprecated: false,
                 warn_complexity: false,
                 warn_nondeterminism: false,
+                warn_integer_division: false,
+                warn_real_equality: false,
                 severity_overrides: Default::default(),
+                passes: Default::default(),
             },
             runtime: RuntimeConfig::default(),
             workspace: WorkspaceSettings::default(),
             telemetry: TelemetryConfig::default(),
+            formatting: FormattingSettings::default(),
         },
     );
 
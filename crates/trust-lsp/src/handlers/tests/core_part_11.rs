@@ -33,11 +33,15 @@ pub(super) fn lsp_mitsubishi_gxworks3_example_has_no_unexpected_diagnostics() {
                 warn_deprecated: false,
                 warn_complexity: false,
                 warn_nondeterminism: false,
+                warn_integer_division: false,
+                warn_real_equality: false,
                 severity_overrides: Default::default(),
+                passes: Default::default(),
             },
             runtime: RuntimeConfig::default(),
             workspace: WorkspaceSettings::default(),
             telemetry: TelemetryConfig::default(),
+            formatting: FormattingSettings::default(),
         },
     );
 
@@ -0,0 +1,371 @@
+use super::*;
+
+fn watched_event(
+    path: &std::path::Path,
+    typ: tower_lsp::lsp_types::FileChangeType,
+) -> tower_lsp::lsp_types::FileEvent {
+    tower_lsp::lsp_types::FileEvent {
+        uri: tower_lsp::lsp_types::Url::from_file_path(path).expect("file uri"),
+        typ,
+    }
+}
+
+#[test]
+pub(super) fn lsp_watched_delete_and_create_during_background_index_settle_on_final_files() {
+    let root = temp_dir("trustlsp-reindex-race");
+    let lib = root.join("lib");
+    std::fs::create_dir_all(&lib).expect("create lib dir");
+    std::fs::write(
+        root.join("trust-lsp.toml"),
+        r#"
+[indexing]
+cache = false
+throttle_idle_ms = 20
+throttle_active_ms = 20
+"#,
+    )
+    .expect("write config");
+    std::fs::write(root.join("main.st"), "PROGRAM Main\nEND_PROGRAM\n").expect("write main");
+    let lib_files: Vec<_> = (0..8).map(|idx| lib.join(format!("f{idx}.st"))).collect();
+    for (idx, path) in lib_files.iter().enumerate() {
+        std::fs::write(path, format!("FUNCTION Old{idx} : INT\nEND_FUNCTION\n"))
+            .expect("write lib file");
+    }
+
+    let state = Arc::new(ServerState::new());
+    let root_uri = tower_lsp::lsp_types::Url::from_file_path(&root).expect("root uri");
+    state.set_workspace_folders(vec![root_uri]);
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .expect("tokio runtime");
+    runtime.block_on(async {
+        let client = test_client();
+        index_workspace_background_with_refresh(client.clone(), Arc::clone(&state));
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+        assert!(
+            state.workspace_index_pending(),
+            "initial index should still be running"
+        );
+
+        std::fs::remove_dir_all(&lib).expect("delete lib dir");
+        did_change_watched_files(
+            &client,
+            &state,
+            tower_lsp::lsp_types::DidChangeWatchedFilesParams {
+                changes: lib_files
+                    .iter()
+                    .map(|path| watched_event(path, tower_lsp::lsp_types::FileChangeType::DELETED))
+                    .collect(),
+            },
+        )
+        .await;
+
+        std::fs::create_dir_all(&lib).expect("recreate lib dir");
+        std::fs::write(&lib_files[0], "FUNCTION Fresh : INT\nEND_FUNCTION\n")
+            .expect("write fresh file");
+        did_change_watched_files(
+            &client,
+            &state,
+            tower_lsp::lsp_types::DidChangeWatchedFilesParams {
+                changes: vec![watched_event(
+                    &lib_files[0],
+                    tower_lsp::lsp_types::FileChangeType::CREATED,
+                )],
+            },
+        )
+        .await;
+
+        for _ in 0..200 {
+            if !state.workspace_index_pending() {
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        }
+        assert!(
+            !state.workspace_index_pending(),
+            "reindex should finish after the watched-file events"
+        );
+    });
+
+    let mut documents: Vec<(String, String)> = state
+        .documents()
+        .into_iter()
+        .filter_map(|doc| {
+            let path = doc.uri.to_file_path().ok()?;
+            let name = path.file_name()?.to_string_lossy().into_owned();
+            Some((name, doc.content))
+        })
+        .collect();
+    documents.sort();
+    assert_eq!(
+        documents,
+        vec![
+            (
+                "f0.st".to_string(),
+                "FUNCTION Fresh : INT\nEND_FUNCTION\n".to_string()
+            ),
+            (
+                "main.st".to_string(),
+                "PROGRAM Main\nEND_PROGRAM\n".to_string()
+            ),
+        ]
+    );
+    std::fs::remove_dir_all(root).ok();
+}
+
+#[test]
+pub(super) fn lsp_watched_config_change_reloads_only_owning_workspace_root() {
+    let root_a = temp_dir("trustlsp-config-reload-a");
+    let root_b = temp_dir("trustlsp-config-reload-b");
+    let config_a = root_a.join("trust-lsp.toml");
+    std::fs::write(&config_a, "[project]\nvendor_profile = \"codesys\"\n").expect("write config a");
+    std::fs::write(
+        root_b.join("trust-lsp.toml"),
+        "[project]\nvendor_profile = \"codesys\"\n",
+    )
+    .expect("write config b");
+
+    let state = Arc::new(ServerState::new());
+    let uri_a = tower_lsp::lsp_types::Url::from_file_path(&root_a).expect("root a uri");
+    let uri_b = tower_lsp::lsp_types::Url::from_file_path(&root_b).expect("root b uri");
+    state.set_workspace_folders(vec![uri_a.clone(), uri_b.clone()]);
+    state.set_workspace_config(uri_a.clone(), ProjectConfig::load(&root_a));
+    state.set_workspace_config(uri_b.clone(), ProjectConfig::load(&root_b));
+
+    std::fs::write(&config_a, "[project]\nvendor_profile = \"siemens\"\n")
+        .expect("rewrite config a");
+    std::fs::write(
+        root_b.join("trust-lsp.toml"),
+        "[project]\nvendor_profile = \"twincat\"\n",
+    )
+    .expect("rewrite config b");
+
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .expect("tokio runtime");
+    runtime.block_on(async {
+        let client = test_client();
+        did_change_watched_files(
+            &client,
+            &state,
+            tower_lsp::lsp_types::DidChangeWatchedFilesParams {
+                changes: vec![watched_event(
+                    &config_a,
+                    tower_lsp::lsp_types::FileChangeType::CHANGED,
+                )],
+            },
+        )
+        .await;
+    });
+
+    let vendor_for = |root: &tower_lsp::lsp_types::Url| {
+        state
+            .workspace_configs()
+            .into_iter()
+            .find(|(folder, _)| folder == root)
+            .and_then(|(_, config)| config.vendor_profile)
+    };
+    assert_eq!(vendor_for(&uri_a).as_deref(), Some("siemens"));
+    assert_eq!(
+        vendor_for(&uri_b).as_deref(),
+        Some("codesys"),
+        "configs of other roots should not be reloaded"
+    );
+    assert!(
+        !state.workspace_index_pending(),
+        "config edits that keep the indexing roots should not reindex"
+    );
+
+    std::fs::remove_dir_all(root_a).ok();
+    std::fs::remove_dir_all(root_b).ok();
+}
+
+fn workspace_reports(
+    state: &ServerState,
+    previous: &[(tower_lsp::lsp_types::Url, String)],
+) -> Vec<tower_lsp::lsp_types::WorkspaceDocumentDiagnosticReport> {
+    let params = tower_lsp::lsp_types::WorkspaceDiagnosticParams {
+        identifier: None,
+        previous_result_ids: previous
+            .iter()
+            .map(|(uri, value)| tower_lsp::lsp_types::PreviousResultId {
+                uri: uri.clone(),
+                value: value.clone(),
+            })
+            .collect(),
+        work_done_progress_params: Default::default(),
+        partial_result_params: Default::default(),
+    };
+    match workspace_diagnostic(state, params) {
+        tower_lsp::lsp_types::WorkspaceDiagnosticReportResult::Report(report) => report.items,
+        _ => panic!("expected workspace diagnostic report"),
+    }
+}
+
+/// Returns the result id per URI and whether the report was a full one.
+fn report_ids(
+    reports: &[tower_lsp::lsp_types::WorkspaceDocumentDiagnosticReport],
+) -> Vec<(tower_lsp::lsp_types::Url, String, bool)> {
+    let mut ids: Vec<_> = reports
+        .iter()
+        .map(|report| match report {
+            tower_lsp::lsp_types::WorkspaceDocumentDiagnosticReport::Full(full) => (
+                full.uri.clone(),
+                full.full_document_diagnostic_report
+                    .result_id
+                    .clone()
+                    .expect("result id"),
+                true,
+            ),
+            tower_lsp::lsp_types::WorkspaceDocumentDiagnosticReport::Unchanged(unchanged) => (
+                unchanged.uri.clone(),
+                unchanged
+                    .unchanged_document_diagnostic_report
+                    .result_id
+                    .clone(),
+                false,
+            ),
+        })
+        .collect();
+    ids.sort_by(|a, b| a.0.as_str().cmp(b.0.as_str()));
+    ids
+}
+
+#[test]
+pub(super) fn lsp_workspace_diagnostics_reuse_reports_of_files_unaffected_by_an_edit() {
+    let state = ServerState::new();
+    let globals = tower_lsp::lsp_types::Url::parse("file:///deps/a_globals.st").unwrap();
+    let consumer = tower_lsp::lsp_types::Url::parse("file:///deps/b_consumer.st").unwrap();
+    let other = tower_lsp::lsp_types::Url::parse("file:///deps/c_other.st").unwrap();
+    state.open_document(
+        globals.clone(),
+        1,
+        "CONFIGURATION Plant\nVAR_GLOBAL\n    shared : INT;\nEND_VAR\nEND_CONFIGURATION\n"
+            .to_string(),
+    );
+    state.open_document(
+        consumer.clone(),
+        1,
+        "PROGRAM Consumer\nVAR_EXTERNAL\n    shared : INT;\nEND_VAR\nshared := 1;\nEND_PROGRAM\n"
+            .to_string(),
+    );
+    state.open_document(
+        other.clone(),
+        1,
+        "FUNCTION Helper : INT\nHelper := 1;\nEND_FUNCTION\n".to_string(),
+    );
+
+    let first = report_ids(&workspace_reports(&state, &[]));
+    assert!(first.iter().all(|(_, _, full)| *full));
+    let previous: Vec<_> = first
+        .iter()
+        .map(|(uri, id, _)| (uri.clone(), id.clone()))
+        .collect();
+
+    state.update_document(
+        &other,
+        2,
+        "FUNCTION Helper : INT\nHelper := 2;\nEND_FUNCTION\n".to_string(),
+    );
+    let second = report_ids(&workspace_reports(&state, &previous));
+    assert_eq!(
+        second
+            .iter()
+            .map(|(uri, _, full)| (uri.clone(), *full))
+            .collect::<Vec<_>>(),
+        vec![
+            (globals.clone(), false),
+            (consumer.clone(), false),
+            (other.clone(), true),
+        ],
+        "only the edited file should get a full report"
+    );
+    let previous: Vec<_> = second
+        .iter()
+        .map(|(uri, id, _)| (uri.clone(), id.clone()))
+        .collect();
+
+    state.update_document(
+        &globals,
+        2,
+        "CONFIGURATION Plant\nVAR_GLOBAL\n    renamed : INT;\nEND_VAR\nEND_CONFIGURATION\n"
+            .to_string(),
+    );
+    let reports = workspace_reports(&state, &previous);
+    let consumer_report = reports
+        .iter()
+        .find_map(|report| match report {
+            tower_lsp::lsp_types::WorkspaceDocumentDiagnosticReport::Full(full)
+                if full.uri == consumer =>
+            {
+                Some(&full.full_document_diagnostic_report.items)
+            }
+            _ => None,
+        })
+        .expect("consumer should be recomputed after its global changed");
+    assert!(
+        consumer_report
+            .iter()
+            .any(|diagnostic| diagnostic.message.contains("shared")),
+        "consumer should report the now missing global: {consumer_report:?}"
+    );
+    assert!(
+        report_ids(&reports)
+            .iter()
+            .any(|(uri, _, full)| uri == &other && !*full),
+        "unrelated file should stay unchanged"
+    );
+}
+
+#[test]
+pub(super) fn lsp_disabled_passes_apply_per_workspace_root() {
+    let root_a = temp_dir("trustlsp-root-passes-a");
+    let root_b = temp_dir("trustlsp-root-passes-b");
+    std::fs::write(
+        root_a.join("trust-lsp.toml"),
+        "[diagnostics]\ndisabled_passes = [\"empty_blocks\"]\n",
+    )
+    .expect("write config a");
+
+    let state = ServerState::new();
+    let mut codes = Vec::new();
+    for (root, name) in [(&root_a, "A"), (&root_b, "B")] {
+        let root_uri = tower_lsp::lsp_types::Url::from_file_path(root).expect("root uri");
+        state.set_workspace_config(root_uri, ProjectConfig::load(root));
+        let uri =
+            tower_lsp::lsp_types::Url::from_file_path(root.join("main.st")).expect("file uri");
+        let source = format!(
+            "PROGRAM Main{name}\nVAR\n    x : BOOL;\nEND_VAR\nIF x THEN\nEND_IF;\nEND_PROGRAM\n"
+        );
+        let file_id = state.open_document(uri.clone(), 1, source.clone());
+        let ticket = state.begin_semantic_request();
+        let diagnostics = super::diagnostics::collect_diagnostics_with_ticket_for_tests(
+            &state, &uri, &source, file_id, ticket,
+        );
+        codes.push(
+            diagnostics
+                .into_iter()
+                .filter_map(|diagnostic| match diagnostic.code {
+                    Some(tower_lsp::lsp_types::NumberOrString::String(code)) => Some(code),
+                    _ => None,
+                })
+                .collect::<Vec<_>>(),
+        );
+    }
+
+    assert!(
+        !codes[0].iter().any(|code| code == "W014"),
+        "root a disables empty_blocks: {:?}",
+        codes[0]
+    );
+    assert!(
+        codes[1].iter().any(|code| code == "W014"),
+        "root b keeps empty_blocks: {:?}",
+        codes[1]
+    );
+
+    std::fs::remove_dir_all(root_a).ok();
+    std::fs::remove_dir_all(root_b).ok();
+}
@@ -77,10 +77,7 @@ fn lsp_formatting_snapshot() {
     };
 
     let edits = formatting(&state, params).expect("formatting edits");
-    let formatted = edits
-        .first()
-        .map(|edit| edit.new_text.as_str())
-        .unwrap_or("");
+    let formatted = apply_text_edits(source, &edits);
     expect![[r#"
 PROGRAM Test
     VAR
@@ -89,7 +86,7 @@ PROGRAM Test
     x := 1;
 END_PROGRAM
 "#]]
-    .assert_eq(formatted);
+    .assert_eq(&formatted);
 }
 
 #[test]
@@ -116,6 +113,7 @@ fn lsp_formatting_vendor_profile_applies_keyword_case() {
             runtime: RuntimeConfig::default(),
             workspace: WorkspaceSettings::default(),
             telemetry: TelemetryConfig::default(),
+            formatting: FormattingSettings::default(),
         },
     );
 
@@ -134,7 +132,7 @@ fn lsp_formatting_vendor_profile_applies_keyword_case() {
 
     let edits = formatting(&state, params).expect("formatting edits");
     assert!(!edits.is_empty());
-    let formatted = edits[0].new_text.as_str();
+    let formatted = apply_text_edits(source, &edits);
     let expected = "PROGRAM Test\n  VAR\n    x:INT;\n  END_VAR\n  x:=1+2;\nEND_PROGRAM\n";
     assert_eq!(formatted, expected);
 }
@@ -163,6 +161,7 @@ fn lsp_formatting_siemens_profile_preserves_hash_prefixed_references() {
             runtime: RuntimeConfig::default(),
             workspace: WorkspaceSettings::default(),
             telemetry: TelemetryConfig::default(),
+            formatting: FormattingSettings::default(),
         },
     );
 
@@ -181,7 +180,7 @@ fn lsp_formatting_siemens_profile_preserves_hash_prefixed_references() {
 
     let edits = formatting(&state, params).expect("formatting edits");
     assert!(!edits.is_empty());
-    let formatted = edits[0].new_text.as_str();
+    let formatted = apply_text_edits(source, &edits);
     let expected = "PROGRAM Test\n  VAR\n    sum:INT;\n  END_VAR\n  #sum:=#sum+1;\nEND_PROGRAM\n";
     assert_eq!(formatted, expected);
 }
@@ -210,6 +209,7 @@ fn lsp_formatting_mitsubishi_profile_keeps_spaced_style() {
             runtime: RuntimeConfig::default(),
             workspace: WorkspaceSettings::default(),
             telemetry: TelemetryConfig::default(),
+            formatting: FormattingSettings::default(),
         },
     );
 
@@ -228,7 +228,7 @@ fn lsp_formatting_mitsubishi_profile_keeps_spaced_style() {
 
     let edits = formatting(&state, params).expect("formatting edits");
     assert!(!edits.is_empty());
-    let formatted = edits[0].new_text.as_str();
+    let formatted = apply_text_edits(source, &edits);
     let expected = "PROGRAM Test\n    VAR\n        pulse : BOOL;\n        rise  : difu;\n        q_rise: BOOL;\n    END_VAR\n    rise(clk := pulse, q => q_rise);\nEND_PROGRAM\n";
     assert_eq!(formatted, expected);
 }
@@ -261,7 +261,7 @@ fn lsp_formatting_accepts_snake_case_client_keys() {
 
     let edits = formatting(&state, params).expect("formatting edits");
     assert!(!edits.is_empty());
-    let formatted = edits[0].new_text.as_str();
+    let formatted = apply_text_edits(source, &edits);
     assert!(formatted.contains("program Test"));
     assert!(formatted.contains("\n  var\n"));
     assert!(formatted.contains("\n  x := 1;\n"));
@@ -296,12 +296,65 @@ fn lsp_formatting_prefers_camel_case_when_both_aliases_present() {
 
     let edits = formatting(&state, params).expect("formatting edits");
     assert!(!edits.is_empty());
-    let formatted = edits[0].new_text.as_str();
+    let formatted = apply_text_edits(source, &edits);
     assert!(formatted.contains("PROGRAM Test"));
     assert!(formatted.contains("\n    VAR\n"));
     assert!(formatted.contains("\n    x := 1;\n"));
 }
 
+#[test]
+fn lsp_formatting_aligns_var_declarations_from_project_config_with_minimal_edits() {
+    let root = temp_dir("trustlsp-format-align");
+    std::fs::write(
+        root.join("trust-lsp.toml"),
+        "[formatting]\nalign_var_declarations = true\nalign_var_initializers = true\n",
+    )
+    .expect("write config");
+    let source = "PROGRAM Main\n    VAR\n        a: INT := 1;\n        flag: BOOL;\n        speed: REAL := 2.5;\n\n        gain_factor: LREAL;\n    END_VAR\n    a := a + 1;\nEND_PROGRAM\n";
+    let state = ServerState::new();
+    state.set_config(serde_json::json!({
+        "trust_lsp": { "format": { "alignVarDecls": false } }
+    }));
+    let root_uri = tower_lsp::lsp_types::Url::from_file_path(&root).expect("root uri");
+    state.set_workspace_config(root_uri, ProjectConfig::load(&root));
+    let uri = tower_lsp::lsp_types::Url::from_file_path(root.join("main.st")).expect("file uri");
+    state.open_document(uri.clone(), 1, source.to_string());
+
+    let params = tower_lsp::lsp_types::DocumentFormattingParams {
+        text_document: tower_lsp::lsp_types::TextDocumentIdentifier { uri },
+        options: tower_lsp::lsp_types::FormattingOptions {
+            tab_size: 4,
+            insert_spaces: true,
+            ..Default::default()
+        },
+        work_done_progress_params: Default::default(),
+    };
+
+    let edits = formatting(&state, params).expect("formatting edits");
+    let edited_lines: Vec<u32> = edits.iter().map(|edit| edit.range.start.line).collect();
+    assert_eq!(
+        edited_lines,
+        [2, 3],
+        "only misaligned lines should be edited"
+    );
+    assert!(edits
+        .iter()
+        .all(|edit| edit.range.start.line == edit.range.end.line));
+    assert_eq!(
+        edits[1].range,
+        tower_lsp::lsp_types::Range::new(
+            tower_lsp::lsp_types::Position::new(3, 12),
+            tower_lsp::lsp_types::Position::new(3, 12),
+        )
+    );
+    assert_eq!(edits[1].new_text, " ");
+    assert_eq!(
+        apply_text_edits(source, &edits),
+        "PROGRAM Main\n    VAR\n        a    : INT  := 1;\n        flag : BOOL;\n        speed: REAL := 2.5;\n\n        gain_factor: LREAL;\n    END_VAR\n    a := a + 1;\nEND_PROGRAM\n"
+    );
+    std::fs::remove_dir_all(root).ok();
+}
+
 #[test]
 fn lsp_code_lens_references() {
     let source = r#"
@@ -335,7 +388,7 @@ END_PROGRAM
     let mut found = false;
     for lens in lenses {
         if let Some(cmd) = &lens.command {
-            if let Some(count_str) = cmd.title.strip_prefix("References: ") {
+            if let Some(count_str) = cmd.title.strip_suffix(" references") {
                 if let Ok(count) = count_str.trim().parse::<usize>() {
                     if count >= 2 {
                         found = true;
@@ -347,6 +400,63 @@ END_PROGRAM
     assert!(found, "expected references code lens");
 }
 
+fn code_lens_titles(state: &ServerState, uri: &tower_lsp::lsp_types::Url) -> Vec<(u32, String)> {
+    let params = tower_lsp::lsp_types::CodeLensParams {
+        text_document: tower_lsp::lsp_types::TextDocumentIdentifier { uri: uri.clone() },
+        work_done_progress_params: Default::default(),
+        partial_result_params: Default::default(),
+    };
+    let mut titles: Vec<_> = code_lens(state, params)
+        .expect("code lenses")
+        .into_iter()
+        .map(|lens| {
+            let command = lens.command.expect("resolved lens command");
+            assert_eq!(command.command, "editor.action.showReferences");
+            (lens.range.start.line, command.title)
+        })
+        .collect();
+    titles.sort();
+    titles
+}
+
+#[test]
+fn lsp_code_lens_counts_pou_and_method_references_across_edits() {
+    let source = r#"FUNCTION_BLOCK Motor
+METHOD Start
+END_METHOD
+
+METHOD Stop
+END_METHOD
+END_FUNCTION_BLOCK
+"#;
+    let user_v1 = "PROGRAM Main\nVAR\n    m : Motor;\nEND_VAR\n    m.Start();\nEND_PROGRAM\n";
+    let state = ServerState::new();
+    let uri = tower_lsp::lsp_types::Url::parse("file:///motor.st").unwrap();
+    let user_uri = tower_lsp::lsp_types::Url::parse("file:///main.st").unwrap();
+    state.open_document(uri.clone(), 1, source.to_string());
+    state.open_document(user_uri.clone(), 1, user_v1.to_string());
+
+    let expected = vec![
+        (0, "1 reference".to_string()),
+        (1, "1 reference".to_string()),
+        (4, "0 references".to_string()),
+    ];
+    assert_eq!(code_lens_titles(&state, &uri), expected);
+    assert_eq!(code_lens_titles(&state, &uri), expected, "cached lenses");
+
+    let user_v2 = user_v1.replace("m.Start();", "m.Start();\n    m.Stop();");
+    state.update_document(&user_uri, 2, user_v2);
+    assert_eq!(
+        code_lens_titles(&state, &uri),
+        vec![
+            (0, "1 reference".to_string()),
+            (1, "1 reference".to_string()),
+            (4, "1 reference".to_string()),
+        ],
+        "edits in other files should invalidate cached counts"
+    );
+}
+
 #[test]
 fn lsp_document_link_using_directive() {
     let lib_source = r#"
@@ -383,7 +493,10 @@ END_FUNCTION
     let start_offset = main_source.find("Lib").expect("Lib offset") as u32;
     let end_offset = start_offset + "Lib".len() as u32;
     assert!(links.iter().any(|link| {
-        link.target.as_ref() == Some(&lib_uri)
+        link.target
+            .as_ref()
+            .map(|target| target.as_str().split('#').next() == Some(lib_uri.as_str()))
+            .unwrap_or(false)
             && super::lsp_utils::position_to_offset(main_source, link.range.start)
                 .map(|start| start <= start_offset)
                 .unwrap_or(false)
@@ -393,6 +506,43 @@ END_FUNCTION
     }));
 }
 
+#[test]
+fn lsp_document_link_using_targets_first_namespace_declaration() {
+    let motors_a = "\nNAMESPACE Plant.Motors\nFUNCTION Start : BOOL\n    Start := TRUE;\nEND_FUNCTION\nEND_NAMESPACE\n";
+    let motors_b = "\nNAMESPACE Plant.Motors\nFUNCTION Stop : BOOL\n    Stop := TRUE;\nEND_FUNCTION\nEND_NAMESPACE\n";
+    let main_source = "USING Plant.Motors;\nUSING Plant.Missing;\nPROGRAM Main\nEND_PROGRAM\n";
+    let state = ServerState::new();
+    let a_uri = tower_lsp::lsp_types::Url::parse("file:///a_motors.st").unwrap();
+    let b_uri = tower_lsp::lsp_types::Url::parse("file:///b_motors.st").unwrap();
+    let main_uri = tower_lsp::lsp_types::Url::parse("file:///main.st").unwrap();
+    state.open_document(b_uri, 1, motors_b.to_string());
+    state.open_document(a_uri.clone(), 1, motors_a.to_string());
+    state.open_document(main_uri.clone(), 1, main_source.to_string());
+
+    let params = tower_lsp::lsp_types::DocumentLinkParams {
+        text_document: tower_lsp::lsp_types::TextDocumentIdentifier { uri: main_uri },
+        work_done_progress_params: Default::default(),
+        partial_result_params: Default::default(),
+    };
+    let links = document_link(&state, params).expect("document links");
+    assert_eq!(links.len(), 1, "unresolved USING should not be linked");
+
+    let link = &links[0];
+    assert_eq!(link.range.start.line, 0);
+    let target = link.target.as_ref().expect("link target");
+    assert_eq!(target.path(), a_uri.path());
+    assert!(
+        target
+            .fragment()
+            .is_some_and(|fragment| fragment.starts_with("L2,")),
+        "target should point at the declaring line: {target}"
+    );
+    assert!(link
+        .tooltip
+        .as_deref()
+        .is_some_and(|tooltip| tooltip.contains("b_motors.st")));
+}
+
 #[test]
 fn lsp_document_link_config_paths() {
     let source = r#"
@@ -752,3 +902,71 @@ fn lsp_on_type_formatting_formats_line() {
     assert_eq!(edits.len(), 1);
     assert!(edits[0].new_text.contains("x := 1 + 2;"));
 }
+
+fn enter_edits_at(source: &str, line: u32) -> Vec<tower_lsp::lsp_types::TextEdit> {
+    let state = ServerState::new();
+    let uri = tower_lsp::lsp_types::Url::parse("file:///test.st").unwrap();
+    state.open_document(uri.clone(), 1, source.to_string());
+    let params = tower_lsp::lsp_types::DocumentOnTypeFormattingParams {
+        text_document_position: tower_lsp::lsp_types::TextDocumentPositionParams {
+            text_document: tower_lsp::lsp_types::TextDocumentIdentifier { uri },
+            position: tower_lsp::lsp_types::Position::new(line, 0),
+        },
+        ch: "\n".to_string(),
+        options: tower_lsp::lsp_types::FormattingOptions {
+            tab_size: 4,
+            insert_spaces: true,
+            ..Default::default()
+        },
+    };
+    on_type_formatting(&state, params).expect("on type formatting")
+}
+
+#[test]
+fn lsp_on_type_enter_indents_case_branches() {
+    let source = "PROGRAM Main\n    CASE mode OF\n    \nEND_PROGRAM\n";
+    let after_of = apply_text_edits(source, &enter_edits_at(source, 2));
+    assert_eq!(
+        after_of,
+        "PROGRAM Main\n    CASE mode OF\n        \nEND_PROGRAM\n"
+    );
+    assert!(
+        enter_edits_at(&after_of, 2).is_empty(),
+        "repeated Enter should not add indentation"
+    );
+
+    let source = "PROGRAM Main\n    CASE mode OF\n        1:\n\n    END_CASE\nEND_PROGRAM\n";
+    assert_eq!(
+        apply_text_edits(source, &enter_edits_at(source, 3)),
+        "PROGRAM Main\n    CASE mode OF\n        1:\n            \n    END_CASE\nEND_PROGRAM\n"
+    );
+}
+
+#[test]
+fn lsp_on_type_enter_realigns_end_case_with_case() {
+    let source =
+        "PROGRAM Main\n    CASE mode OF\n        1:\n            x := 1;\n            END_CASE\n\nEND_PROGRAM\n";
+    let closed = apply_text_edits(source, &enter_edits_at(source, 5));
+    assert_eq!(
+        closed,
+        "PROGRAM Main\n    CASE mode OF\n        1:\n            x := 1;\n    END_CASE\n    \nEND_PROGRAM\n"
+    );
+    assert!(enter_edits_at(&closed, 5).is_empty());
+}
+
+#[test]
+fn lsp_on_type_enter_completes_then_after_if_condition() {
+    let source = "PROGRAM Main\n    IF mode > 1\n\nEND_PROGRAM\n";
+    let completed = apply_text_edits(source, &enter_edits_at(source, 2));
+    assert_eq!(
+        completed,
+        "PROGRAM Main\n    IF mode > 1 THEN\n        \nEND_PROGRAM\n"
+    );
+    assert!(enter_edits_at(&completed, 2).is_empty());
+
+    let source = "PROGRAM Main\n    if mode > 1\n\nEND_PROGRAM\n";
+    assert_eq!(
+        apply_text_edits(source, &enter_edits_at(source, 2)),
+        "PROGRAM Main\n    if mode > 1 then\n        \nEND_PROGRAM\n"
+    );
+}
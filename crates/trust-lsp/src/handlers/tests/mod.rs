@@ -2,11 +2,12 @@ use super::features::{references, workspace_symbol};
 use super::namespace_move_workspace_edit;
 use super::*;
 use crate::config::{
-    BuildConfig, DiagnosticSettings, IndexingConfig, LibraryDependency, LibrarySpec, ProjectConfig,
-    RuntimeConfig, StdlibSettings, TargetProfile, TelemetryConfig, WorkspaceSettings,
+    BuildConfig, DiagnosticSettings, FormattingSettings, IndexingConfig, LibraryDependency,
+    LibrarySpec, ProjectConfig, RuntimeConfig, StdlibSettings, TargetProfile, TelemetryConfig,
+    WorkspaceSettings,
 };
 use crate::state::ServerState;
-use crate::test_support::test_client;
+use crate::test_support::{apply_text_edits, test_client};
 use expect_test::expect;
 use insta::assert_snapshot;
 use serde_json::{json, Value};
@@ -99,6 +99,7 @@ path = "vendor"
                 visibility: crate::config::WorkspaceVisibility::Public,
             },
             telemetry: TelemetryConfig::default(),
+            formatting: FormattingSettings::default(),
         },
     );
     state.set_workspace_config(
@@ -123,6 +124,7 @@ path = "vendor"
                 visibility: crate::config::WorkspaceVisibility::Private,
             },
             telemetry: TelemetryConfig::default(),
+            formatting: FormattingSettings::default(),
         },
     );
 
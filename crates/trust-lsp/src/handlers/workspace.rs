@@ -34,6 +34,8 @@ pub async fn register_file_watchers(client: &Client) {
     for name in CONFIG_FILES {
         watchers.push(json!({ "globPattern": format!("**/{name}") }));
     }
+    watchers.push(json!({ "globPattern": "**/hmi.toml" }));
+    watchers.push(json!({ "globPattern": "**/hmi/**" }));
 
     let registration = Registration {
         id: "trustlsp-watchers".to_string(),
@@ -68,10 +70,18 @@ pub async fn register_type_hierarchy(client: &Client) {
     }
 }
 
+#[cfg(test)]
 pub async fn index_workspace(client: &Client, state: &ServerState) {
+    index_workspace_generation(client, state, state.workspace_index_generation()).await;
+}
+
+/// Indexes the workspace folders until `generation` is superseded.
+///
+/// Returns false when a newer generation cancelled the run part-way.
+async fn index_workspace_generation(client: &Client, state: &ServerState, generation: u64) -> bool {
     let folders = state.workspace_folders();
     if folders.is_empty() {
-        return;
+        return true;
     }
 
     let mut indexed_total = 0usize;
@@ -85,7 +95,12 @@ pub async fn index_workspace(client: &Client, state: &ServerState) {
         };
         let config = ProjectConfig::load(&root);
         state.set_workspace_config(folder.clone(), config.clone());
-        let summary = index_workspace_root(client, state, &config, &folder, &mut seen).await;
+        let summary =
+            index_workspace_root(client, state, &config, &folder, &mut seen, generation).await;
+        if summary.cancelled {
+            info!("Workspace indexing superseded by a newer generation");
+            return false;
+        }
         indexed_total += summary.indexed;
         skipped_total += summary.skipped;
         if summary.truncated {
@@ -107,11 +122,23 @@ pub async fn index_workspace(client: &Client, state: &ServerState) {
         }
         client.log_message(MessageType::INFO, message).await;
     }
+    true
 }
 
+/// Indexes the workspace in the background and refreshes the client afterwards.
+///
+/// Starting a new index supersedes the one in flight; the superseded run stops
+/// at its next file and leaves the refresh to the newer run.
 pub fn index_workspace_background_with_refresh(client: Client, state: Arc<ServerState>) {
+    let generation = state.begin_workspace_index();
     tokio::spawn(async move {
-        state.run_background(index_workspace(&client, &state)).await;
+        let completed = state
+            .run_background(index_workspace_generation(&client, &state, generation))
+            .await;
+        state.finish_workspace_index(generation);
+        if !completed {
+            return;
+        }
         refresh_diagnostics(&client, &state).await;
         refresh_semantic_tokens(&client, &state).await;
     });
@@ -272,6 +299,7 @@ struct IndexSummary {
     indexed: usize,
     skipped: usize,
     truncated: bool,
+    cancelled: bool,
 }
 
 struct IndexThrottle {
@@ -330,6 +358,7 @@ async fn index_workspace_root(
     config: &ProjectConfig,
     root_uri: &Url,
     seen: &mut FxHashSet<PathBuf>,
+    generation: u64,
 ) -> IndexSummary {
     let mut files = Vec::new();
     collect_workspace_files(config, &mut files);
@@ -338,10 +367,15 @@ async fn index_workspace_root(
 
     let total = files.len();
     if total == 0 {
+        let cancelled = state.workspace_index_cancelled(generation);
+        if !cancelled {
+            remove_missing_documents(state, config);
+        }
         return IndexSummary {
             indexed: 0,
             skipped: 0,
             truncated: false,
+            cancelled,
         };
     }
 
@@ -360,6 +394,7 @@ async fn index_workspace_root(
     let mut indexed = 0usize;
     let mut skipped = 0usize;
     let mut truncated = false;
+    let mut cancelled = false;
     let mut indexed_since_budget_check = 0usize;
     const BUDGET_CHECK_INTERVAL: usize = 256;
     let max_files = config.indexing.max_files;
@@ -368,6 +403,10 @@ async fn index_workspace_root(
     let mut throttle = IndexThrottle::new(config);
 
     for (idx, path) in files.iter().enumerate() {
+        if state.workspace_index_cancelled(generation) {
+            cancelled = true;
+            break;
+        }
         if let Some(max) = max_files {
             if indexed >= max {
                 truncated = true;
@@ -448,9 +487,14 @@ async fn index_workspace_root(
 
     state.apply_memory_budget();
 
-    if let (Some(cache), Some(dir)) = (cache.as_mut(), cache_dir.as_ref()) {
-        cache.retain_paths(&files);
-        let _ = cache.save(dir);
+    // A superseded run may hold a stale file list and cache snapshot; the newer
+    // run owns the cache and the cleanup.
+    if !cancelled {
+        if let (Some(cache), Some(dir)) = (cache.as_mut(), cache_dir.as_ref()) {
+            cache.retain_paths(&files);
+            let _ = cache.save(dir);
+        }
+        remove_missing_documents(state, config);
     }
 
     end_progress(client, &progress, indexed, truncated).await;
@@ -459,6 +503,7 @@ async fn index_workspace_root(
         indexed,
         skipped,
         truncated,
+        cancelled,
     }
 }
 
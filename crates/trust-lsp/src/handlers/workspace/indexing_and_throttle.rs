@@ -1,3 +1,21 @@
+/// Drops closed documents under the indexing roots whose files no longer exist,
+/// such as files an earlier, superseded index run picked up just before their
+/// deletion.
+fn remove_missing_documents(state: &ServerState, config: &ProjectConfig) {
+    let roots = config.indexing_roots();
+    for doc in state.documents() {
+        if doc.is_open {
+            continue;
+        }
+        let Some(path) = uri_to_path(&doc.uri) else {
+            continue;
+        };
+        if roots.iter().any(|root| path.starts_with(root)) && !path.exists() {
+            state.remove_document(&doc.uri);
+        }
+    }
+}
+
 fn collect_workspace_files(config: &ProjectConfig, out: &mut Vec<PathBuf>) {
     for root in config.indexing_roots() {
         collect_st_files(&root, out);
@@ -254,17 +272,22 @@ pub async fn did_change_watched_files(
     state: &Arc<ServerState>,
     params: DidChangeWatchedFilesParams,
 ) {
+    // An index still in flight may have listed files before this batch; stop it
+    // now and rerun it afterwards so the project ends on the final file set.
+    let index_interrupted = state.cancel_workspace_index();
     let mut indexed = 0usize;
     let mut removed = 0usize;
-    let mut config_changed = false;
+    let mut changed_config_roots: HashSet<Url> = HashSet::new();
     let mut cache_by_dir: HashMap<PathBuf, IndexCache> = HashMap::new();
     let mut dirty_cache_dirs: HashSet<PathBuf> = HashSet::new();
     for change in params.changes {
         let Some(path) = uri_to_path(&change.uri) else {
             continue;
         };
-        if is_config_file(&path) {
-            config_changed = true;
+        if is_config_file(&path) || (is_hmi_path(&path) && !is_st_file(&path)) {
+            if let Some(root) = workspace_root_for_path(state, &path) {
+                changed_config_roots.insert(root);
+            }
             continue;
         }
         if !is_st_file(&path) {
@@ -312,7 +335,7 @@ pub async fn did_change_watched_files(
         }
     }
 
-    let mut diagnostics_refresh = indexed > 0 || removed > 0;
+    let diagnostics_refresh = indexed > 0 || removed > 0;
     if diagnostics_refresh {
         client
             .log_message(
@@ -322,19 +345,68 @@ pub async fn did_change_watched_files(
             .await;
     }
 
-    if config_changed {
+    let mut roots_changed = false;
+    for root in &changed_config_roots {
+        roots_changed |= reload_workspace_config(state, root);
         client
             .log_message(
                 MessageType::INFO,
-                "Workspace config changed; reindexing".to_string(),
+                format!("Workspace config reloaded for {root}"),
             )
             .await;
+    }
+
+    if roots_changed || index_interrupted {
         index_workspace_background_with_refresh(client.clone(), Arc::clone(state));
-        diagnostics_refresh = false;
+        return;
     }
 
-    if diagnostics_refresh {
+    if !changed_config_roots.is_empty() {
+        refresh_diagnostics(client, state).await;
+        refresh_semantic_tokens(client, state).await;
+    } else if diagnostics_refresh {
         refresh_diagnostics(client, state).await;
     }
 }
 
+/// Returns true for `hmi.toml` and for files inside an `hmi/` directory.
+fn is_hmi_path(path: &Path) -> bool {
+    path.file_name().is_some_and(|name| name == "hmi.toml")
+        || path
+            .parent()
+            .is_some_and(|dir| dir.components().any(|part| part.as_os_str() == "hmi"))
+}
+
+/// Returns the innermost workspace folder containing `path`.
+fn workspace_root_for_path(state: &ServerState, path: &Path) -> Option<Url> {
+    state
+        .workspace_folders()
+        .into_iter()
+        .filter_map(|folder| {
+            let root = uri_to_path(&folder)?;
+            path.starts_with(&root)
+                .then(|| (root.components().count(), folder))
+        })
+        .max_by_key(|(depth, _)| *depth)
+        .map(|(_, folder)| folder)
+}
+
+/// Reloads the project config of one workspace root in place.
+///
+/// Returns true when the indexing roots changed, in which case the caller has
+/// to reindex to pick up added or dropped include and library paths.
+fn reload_workspace_config(state: &ServerState, root: &Url) -> bool {
+    let Some(root_path) = uri_to_path(root) else {
+        return false;
+    };
+    let previous_roots = state
+        .workspace_configs()
+        .into_iter()
+        .find(|(folder, _)| folder == root)
+        .map(|(_, config)| config.indexing_roots());
+    let config = ProjectConfig::load(&root_path);
+    let roots_changed = previous_roots.as_ref() != Some(&config.indexing_roots());
+    state.set_workspace_config(root.clone(), config);
+    roots_changed
+}
+
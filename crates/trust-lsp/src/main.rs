@@ -28,7 +28,8 @@ use tower_lsp::{Client, LanguageServer, LspService, Server};
 use tracing::info;
 
 use crate::handlers::{
-    HMI_BINDINGS_COMMAND, HMI_INIT_COMMAND, MOVE_NAMESPACE_COMMAND, PROJECT_INFO_COMMAND,
+    EXPRESSION_TYPE_COMMAND, HMI_BINDINGS_COMMAND, HMI_INIT_COMMAND, MOVE_NAMESPACE_COMMAND,
+    PROJECT_INFO_COMMAND,
 };
 use crate::state::ServerState;
 use crate::telemetry::TelemetryEvent;
@@ -137,6 +138,7 @@ impl LanguageServer for StLanguageServer {
                         PROJECT_INFO_COMMAND.to_string(),
                         HMI_INIT_COMMAND.to_string(),
                         HMI_BINDINGS_COMMAND.to_string(),
+                        EXPRESSION_TYPE_COMMAND.to_string(),
                     ],
                     ..Default::default()
                 }),
@@ -225,6 +227,7 @@ impl LanguageServer for StLanguageServer {
                                     SemanticTokenModifier::READONLY,
                                     SemanticTokenModifier::STATIC,
                                     SemanticTokenModifier::MODIFICATION,
+                                    SemanticTokenModifier::DEPRECATED,
                                 ],
                             },
                             full: Some(SemanticTokensFullOptions::Delta { delta: Some(true) }),
@@ -602,7 +605,14 @@ impl LanguageServer for StLanguageServer {
     }
 
     async fn code_lens(&self, params: CodeLensParams) -> Result<Option<Vec<CodeLens>>> {
-        Ok(handlers::code_lens(&self.state, params))
+        let result = self
+            .state
+            .run_background(async {
+                let _pinned = self.state.pin_all_documents();
+                handlers::code_lens(&self.state, params)
+            })
+            .await;
+        Ok(result)
     }
 
     async fn prepare_call_hierarchy(
@@ -650,7 +660,13 @@ impl LanguageServer for StLanguageServer {
     async fn rename(&self, params: RenameParams) -> Result<Option<WorkspaceEdit>> {
         let uri = params.text_document_position.text_document.uri.clone();
         let start = Instant::now();
-        let result = handlers::rename(&self.state, params);
+        let result = self
+            .state
+            .run_background(async {
+                let _pinned = self.state.pin_all_documents();
+                handlers::rename(&self.state, params)
+            })
+            .await;
         self.state
             .record_telemetry(TelemetryEvent::Rename, start.elapsed(), Some(&uri));
         Ok(result)
@@ -1,6 +1,6 @@
-use tower_lsp::lsp_types::{SemanticToken, Url};
+use tower_lsp::lsp_types::{CodeLens, SemanticToken, Url};
 
-use super::{DiagnosticCache, SemanticTokensCache, ServerState};
+use super::{CodeLensCache, DiagnosticCache, SemanticTokensCache, ServerState};
 
 pub(super) fn semantic_tokens_cache(state: &ServerState, uri: &Url) -> Option<SemanticTokensCache> {
     state.semantic_tokens.read().get(uri).cloned()
@@ -20,15 +20,39 @@ pub(super) fn store_semantic_tokens(
     result_id
 }
 
+pub(super) fn cached_code_lenses(
+    state: &ServerState,
+    uri: &Url,
+    revision: u64,
+) -> Option<Vec<CodeLens>> {
+    let cache = state.code_lenses.read();
+    let existing = cache.get(uri)?;
+    (existing.revision == revision).then(|| existing.lenses.clone())
+}
+
+pub(super) fn store_code_lenses(
+    state: &ServerState,
+    uri: Url,
+    revision: u64,
+    lenses: Vec<CodeLens>,
+) {
+    state
+        .code_lenses
+        .write()
+        .insert(uri, CodeLensCache { revision, lenses });
+}
+
 pub(super) fn store_diagnostics(
     state: &ServerState,
     uri: Url,
     content_hash: u64,
+    dependency_hash: Option<u64>,
     diagnostic_hash: u64,
 ) -> String {
     let mut cache = state.diagnostics.write();
-    if let Some(existing) = cache.get(&uri) {
+    if let Some(existing) = cache.get_mut(&uri) {
         if existing.content_hash == content_hash && existing.diagnostic_hash == diagnostic_hash {
+            existing.dependency_hash = dependency_hash;
             return existing.result_id.clone();
         }
     }
@@ -39,11 +63,32 @@ pub(super) fn store_diagnostics(
             result_id: result_id.clone(),
             content_hash,
             diagnostic_hash,
+            dependency_hash,
         },
     );
     result_id
 }
 
+pub(super) fn reusable_diagnostics(
+    state: &ServerState,
+    uri: &Url,
+    content_hash: u64,
+    dependency_hash: u64,
+) -> Option<String> {
+    let cache = state.diagnostics.read();
+    let existing = cache.get(uri)?;
+    (existing.content_hash == content_hash && existing.dependency_hash == Some(dependency_hash))
+        .then(|| existing.result_id.clone())
+}
+
+/// Settings changes can alter any report, so no cached diagnostics may be
+/// reused until they are recomputed.
+pub(super) fn forget_diagnostic_dependencies(state: &ServerState) {
+    for entry in state.diagnostics.write().values_mut() {
+        entry.dependency_hash = None;
+    }
+}
+
 fn next_semantic_tokens_id(state: &ServerState) -> String {
     state
         .semantic_tokens_id
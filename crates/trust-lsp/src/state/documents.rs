@@ -128,6 +128,7 @@ pub(super) fn remove_document(state: &ServerState, uri: &Url) -> Option<FileId>
     let doc = state.documents.write().remove(uri)?;
     state.semantic_tokens.write().remove(uri);
     state.diagnostics.write().remove(uri);
+    state.code_lenses.write().remove(uri);
     let mut project = state.project.write();
     project.remove_source(&key);
     Some(doc.file_id)
@@ -138,6 +139,7 @@ pub(super) fn rename_document(state: &ServerState, old_uri: &Url, new_uri: &Url)
     let mut doc = docs.remove(old_uri)?;
     state.semantic_tokens.write().remove(old_uri);
     state.diagnostics.write().remove(old_uri);
+    state.code_lenses.write().remove(old_uri);
 
     let old_key = source_key_for_uri(old_uri);
     let new_key = source_key_for_uri(new_uri);
@@ -248,12 +250,15 @@ fn enforce_memory_budget(state: &ServerState) {
     let mut candidates = Vec::new();
     {
         let docs = state.documents.read();
+        let pinned = state.pinned_files.read();
         for (uri, doc) in docs.iter() {
             if doc.is_open {
                 continue;
             }
             total_bytes = total_bytes.saturating_add(doc.content_bytes);
-            candidates.push((doc.last_access, uri.clone(), doc.content_bytes));
+            if !pinned.contains_key(&doc.file_id) {
+                candidates.push((doc.last_access, uri.clone(), doc.content_bytes));
+            }
         }
     }
     if total_bytes <= budget_bytes {
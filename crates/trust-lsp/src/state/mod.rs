@@ -12,12 +12,12 @@ use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::sync::Semaphore;
-use tower_lsp::lsp_types::{SemanticToken, Url};
+use tower_lsp::lsp_types::{CodeLens, SemanticToken, Url};
 
 use crate::config::ProjectConfig;
 use crate::library_docs::library_doc_map;
 use crate::telemetry::{TelemetryCollector, TelemetryEvent};
-use trust_hir::{db::FileId, Database, Project};
+use trust_hir::{db::FileId, Database, DiagnosticPass, DiagnosticPasses, Project};
 
 const BACKGROUND_REQUEST_LIMIT: usize = 1;
 
@@ -75,11 +75,41 @@ pub struct SemanticTokensCache {
     pub tokens: Vec<SemanticToken>,
 }
 
+#[derive(Debug, Clone)]
+pub struct CodeLensCache {
+    /// Database source revision the lenses were computed at.
+    pub revision: u64,
+    pub lenses: Vec<CodeLens>,
+}
+
 #[derive(Debug, Clone)]
 pub struct DiagnosticCache {
     pub result_id: String,
     pub content_hash: u64,
     pub diagnostic_hash: u64,
+    /// Fingerprint of the other files the diagnostics depend on, when known.
+    pub dependency_hash: Option<u64>,
+}
+
+/// Keeps documents safe from memory-budget eviction until dropped.
+#[must_use = "documents are unpinned as soon as the guard is dropped"]
+pub struct PinnedDocuments<'a> {
+    state: &'a ServerState,
+    file_ids: Vec<FileId>,
+}
+
+impl Drop for PinnedDocuments<'_> {
+    fn drop(&mut self) {
+        let mut pinned = self.state.pinned_files.write();
+        for file_id in &self.file_ids {
+            if let Some(count) = pinned.get_mut(file_id) {
+                *count -= 1;
+                if *count == 0 {
+                    pinned.remove(file_id);
+                }
+            }
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -111,6 +141,10 @@ pub struct ServerState {
     semantic_tokens: RwLock<FxHashMap<Url, SemanticTokensCache>>,
     /// Cached diagnostics for pull requests.
     diagnostics: RwLock<FxHashMap<Url, DiagnosticCache>>,
+    /// Cached reference-count code lenses.
+    code_lenses: RwLock<FxHashMap<Url, CodeLensCache>>,
+    /// Pin counts of files protected from memory-budget eviction.
+    pinned_files: RwLock<FxHashMap<FileId, usize>>,
     /// Monotonic ID for semantic token result IDs.
     semantic_tokens_id: AtomicU64,
     /// Monotonic ID for diagnostic result IDs.
@@ -119,6 +153,10 @@ pub struct ServerState {
     doc_access_counter: AtomicU64,
     /// Monotonic generation used for cooperative semantic request cancellation.
     semantic_request_generation: AtomicU64,
+    /// Monotonic generation of background workspace indexing; older runs stop early.
+    workspace_index_generation: AtomicU64,
+    /// Generation of the queued or running background index, or 0 when idle.
+    workspace_index_pending: AtomicU64,
     /// Last activity time (epoch ms) for adaptive throttling.
     last_activity_ms: AtomicU64,
     /// Whether work-done progress is supported by the client.
@@ -152,10 +190,14 @@ impl ServerState {
             documents: RwLock::new(FxHashMap::default()),
             semantic_tokens: RwLock::new(FxHashMap::default()),
             diagnostics: RwLock::new(FxHashMap::default()),
+            code_lenses: RwLock::new(FxHashMap::default()),
+            pinned_files: RwLock::new(FxHashMap::default()),
             semantic_tokens_id: AtomicU64::new(1),
             diagnostic_id: AtomicU64::new(1),
             doc_access_counter: AtomicU64::new(1),
             semantic_request_generation: AtomicU64::new(1),
+            workspace_index_generation: AtomicU64::new(1),
+            workspace_index_pending: AtomicU64::new(0),
             last_activity_ms: AtomicU64::new(0),
             work_done_progress: AtomicBool::new(false),
             diagnostic_refresh_supported: AtomicBool::new(false),
@@ -234,6 +276,34 @@ impl ServerState {
     pub fn set_workspace_config(&self, root: Url, config: ProjectConfig) {
         self.workspace_configs.write().insert(root.clone(), config);
         self.library_docs.write().remove(&root);
+        self.sync_diagnostic_passes();
+        cache::forget_diagnostic_dependencies(self);
+    }
+
+    /// Applies the configured diagnostic passes to the shared database.
+    ///
+    /// All roots share one database, so a pass is skipped only when every
+    /// workspace root disables it; diagnostics collection drops the results
+    /// of passes a document's own root disables.
+    fn sync_diagnostic_passes(&self) {
+        let mut passes = DiagnosticPasses::all();
+        {
+            let configs = self.workspace_configs.read();
+            if !configs.is_empty() {
+                for &pass in DiagnosticPass::ALL {
+                    if configs
+                        .values()
+                        .all(|config| !config.diagnostics.passes.is_enabled(pass))
+                    {
+                        passes.disable(pass);
+                    }
+                }
+            }
+        }
+        let mut project = self.project.write();
+        if project.database().diagnostic_passes() != passes {
+            project.database_mut().set_diagnostic_passes(passes);
+        }
     }
 
     /// Returns all workspace configurations with their roots.
@@ -370,6 +440,7 @@ impl ServerState {
     /// Stores updated client configuration settings.
     pub fn set_config(&self, config: Value) {
         *self.config.write() = config;
+        cache::forget_diagnostic_dependencies(self);
     }
 
     /// Returns the current configuration snapshot.
@@ -393,9 +464,49 @@ impl ServerState {
         cache::store_semantic_tokens(self, uri, tokens)
     }
 
+    /// Returns the code lenses cached for `uri` if they were computed at the
+    /// given source revision.
+    pub fn cached_code_lenses(&self, uri: &Url, revision: u64) -> Option<Vec<CodeLens>> {
+        cache::cached_code_lenses(self, uri, revision)
+    }
+
+    pub fn store_code_lenses(&self, uri: Url, revision: u64, lenses: Vec<CodeLens>) {
+        cache::store_code_lenses(self, uri, revision, lenses);
+    }
+
     /// Stores diagnostics in the cache and returns the result ID.
     pub fn store_diagnostics(&self, uri: Url, content_hash: u64, diagnostic_hash: u64) -> String {
-        cache::store_diagnostics(self, uri, content_hash, diagnostic_hash)
+        cache::store_diagnostics(self, uri, content_hash, None, diagnostic_hash)
+    }
+
+    /// Stores diagnostics together with the dependency fingerprint they were
+    /// computed against and returns the result ID.
+    pub fn store_diagnostics_with_dependencies(
+        &self,
+        uri: Url,
+        content_hash: u64,
+        dependency_hash: u64,
+        diagnostic_hash: u64,
+    ) -> String {
+        cache::store_diagnostics(
+            self,
+            uri,
+            content_hash,
+            Some(dependency_hash),
+            diagnostic_hash,
+        )
+    }
+
+    /// Returns the cached result ID if the diagnostics were computed for the
+    /// same content and dependency fingerprint, so they can be reported as
+    /// unchanged without recomputing them.
+    pub fn reusable_diagnostics(
+        &self,
+        uri: &Url,
+        content_hash: u64,
+        dependency_hash: u64,
+    ) -> Option<String> {
+        cache::reusable_diagnostics(self, uri, content_hash, dependency_hash)
     }
 
     /// Enforces the configured memory budget for closed documents.
@@ -403,6 +514,25 @@ impl ServerState {
         documents::apply_memory_budget(self);
     }
 
+    /// Protects the given files from memory-budget eviction while the returned
+    /// guard is alive. Pins nest, so overlapping requests may pin the same file.
+    pub fn pin_documents(&self, file_ids: impl IntoIterator<Item = FileId>) -> PinnedDocuments<'_> {
+        let file_ids: Vec<FileId> = file_ids.into_iter().collect();
+        let mut pinned = self.pinned_files.write();
+        for file_id in &file_ids {
+            *pinned.entry(*file_id).or_default() += 1;
+        }
+        PinnedDocuments {
+            state: self,
+            file_ids,
+        }
+    }
+
+    /// Pins every known document for the duration of a workspace-wide request.
+    pub fn pin_all_documents(&self) -> PinnedDocuments<'_> {
+        self.pin_documents(self.documents().into_iter().map(|doc| doc.file_id))
+    }
+
     /// Executes a function with a read lock on the database.
     pub fn with_database<F, R>(&self, f: F) -> R
     where
@@ -423,6 +553,57 @@ impl ServerState {
     pub fn semantic_request_cancelled(&self, ticket: u64) -> bool {
         self.semantic_request_generation.load(Ordering::Relaxed) != ticket
     }
+
+    /// Starts a background workspace index generation, superseding any index in flight.
+    pub fn begin_workspace_index(&self) -> u64 {
+        let generation = self
+            .workspace_index_generation
+            .fetch_add(1, Ordering::Relaxed)
+            + 1;
+        self.workspace_index_pending
+            .store(generation, Ordering::Relaxed);
+        generation
+    }
+
+    /// Returns the current workspace index generation.
+    #[cfg(test)]
+    pub fn workspace_index_generation(&self) -> u64 {
+        self.workspace_index_generation.load(Ordering::Relaxed)
+    }
+
+    /// Returns true if a workspace index generation has been superseded.
+    pub fn workspace_index_cancelled(&self, generation: u64) -> bool {
+        self.workspace_index_generation.load(Ordering::Relaxed) != generation
+    }
+
+    /// Marks a background workspace index generation as finished.
+    pub fn finish_workspace_index(&self, generation: u64) {
+        let _ = self.workspace_index_pending.compare_exchange(
+            generation,
+            0,
+            Ordering::Relaxed,
+            Ordering::Relaxed,
+        );
+    }
+
+    /// Returns true while a background workspace index is queued or running.
+    #[cfg(test)]
+    pub fn workspace_index_pending(&self) -> bool {
+        self.workspace_index_pending.load(Ordering::Relaxed) != 0
+    }
+
+    /// Cancels the background workspace index in flight, if any.
+    ///
+    /// Returns true when an index was cancelled, so the caller can schedule a
+    /// fresh one that observes the current files.
+    pub fn cancel_workspace_index(&self) -> bool {
+        if self.workspace_index_pending.swap(0, Ordering::Relaxed) == 0 {
+            return false;
+        }
+        self.workspace_index_generation
+            .fetch_add(1, Ordering::Relaxed);
+        true
+    }
 }
 
 impl Default for ServerState {
@@ -565,6 +746,69 @@ evict_to_percent = 75
         fs::remove_dir_all(root).ok();
     }
 
+    #[test]
+    fn pinned_documents_survive_concurrent_eviction() {
+        let root = temp_dir("trustlsp-budget-pinned");
+        fs::write(
+            root.join("trust-lsp.toml"),
+            r#"
+[indexing]
+memory_budget_mb = 1
+evict_to_percent = 75
+"#,
+        )
+        .expect("write config");
+        let config = ProjectConfig::load(&root);
+
+        let state = ServerState::new();
+        let root_uri = Url::from_file_path(&root).expect("root uri");
+        state.set_workspace_config(root_uri, config);
+
+        let payload = "A".repeat(400_000);
+        let uri_a = Url::from_file_path(root.join("a.st")).expect("uri a");
+        let uri_b = Url::from_file_path(root.join("b.st")).expect("uri b");
+        let uri_c = Url::from_file_path(root.join("c.st")).expect("uri c");
+        let file_a = state
+            .index_document(uri_a.clone(), payload.clone())
+            .expect("index a");
+        state.index_document(uri_b.clone(), payload.clone());
+
+        let (pinned_tx, pinned_rx) = std::sync::mpsc::channel();
+        let (evicted_tx, evicted_rx) = std::sync::mpsc::channel();
+        let state_ref = &state;
+        std::thread::scope(|scope| {
+            scope.spawn(move || {
+                let _pinned = state_ref.pin_documents([file_a]);
+                pinned_tx.send(()).expect("signal pinned");
+                evicted_rx.recv().expect("wait for eviction");
+                assert!(
+                    state_ref.document_for_file_id(file_a).is_some(),
+                    "pinned document was evicted mid-iteration"
+                );
+            });
+            scope.spawn(move || {
+                pinned_rx.recv().expect("wait for pin");
+                state_ref.index_document(uri_c, payload);
+                evicted_tx.send(()).expect("signal eviction");
+            });
+        });
+
+        assert!(
+            state.get_document(&uri_a).is_some(),
+            "expected pinned least-recent document to remain"
+        );
+        assert!(
+            state.get_document(&uri_b).is_none(),
+            "expected the oldest unpinned document to be evicted instead"
+        );
+        assert!(
+            state.pinned_files.read().is_empty(),
+            "expected the guard to unpin on drop"
+        );
+
+        fs::remove_dir_all(root).ok();
+    }
+
     #[test]
     fn document_lifecycle_open_update_close_rename_remove() {
         let root = temp_dir("trustlsp-doc-lifecycle");
@@ -628,6 +872,65 @@ evict_to_percent = 75
         );
     }
 
+    #[test]
+    fn diagnostic_cache_reuse_requires_matching_dependencies() {
+        let state = ServerState::new();
+        let uri = Url::parse("file:///tmp/trust-lsp-deps.st").expect("uri");
+
+        assert_eq!(state.reusable_diagnostics(&uri, 11, 33), None);
+        state.store_diagnostics(uri.clone(), 11, 22);
+        assert_eq!(
+            state.reusable_diagnostics(&uri, 11, 33),
+            None,
+            "entries stored without a fingerprint are never reused"
+        );
+
+        let result_id = state.store_diagnostics_with_dependencies(uri.clone(), 11, 33, 22);
+        assert_eq!(state.reusable_diagnostics(&uri, 11, 33), Some(result_id));
+        assert_eq!(state.reusable_diagnostics(&uri, 12, 33), None);
+        assert_eq!(state.reusable_diagnostics(&uri, 11, 34), None);
+
+        state.set_config(Value::Null);
+        assert_eq!(
+            state.reusable_diagnostics(&uri, 11, 33),
+            None,
+            "settings changes should invalidate reusable reports"
+        );
+    }
+
+    #[test]
+    fn diagnostic_passes_are_skipped_only_when_every_root_disables_them() {
+        let root_a = temp_dir("trustlsp-passes-a");
+        let root_b = temp_dir("trustlsp-passes-b");
+        let disable_complexity = "[diagnostics]\ndisabled_passes = [\"complexity\"]\n";
+        fs::write(root_a.join("trust-lsp.toml"), disable_complexity).expect("write config a");
+
+        let state = ServerState::new();
+        let uri_a = Url::from_file_path(&root_a).expect("root a uri");
+        let uri_b = Url::from_file_path(&root_b).expect("root b uri");
+        state.set_workspace_config(uri_a, ProjectConfig::load(&root_a));
+        let passes = state.with_database(|db| db.diagnostic_passes());
+        assert!(!passes.is_enabled(DiagnosticPass::Complexity));
+
+        state.set_workspace_config(uri_b.clone(), ProjectConfig::load(&root_b));
+        let passes = state.with_database(|db| db.diagnostic_passes());
+        assert!(
+            passes.is_enabled(DiagnosticPass::Complexity),
+            "a root that keeps the pass should keep it running"
+        );
+
+        fs::write(root_b.join("trust-lsp.toml"), disable_complexity).expect("write config b");
+        state.set_workspace_config(uri_b, ProjectConfig::load(&root_b));
+        let passes = state.with_database(|db| db.diagnostic_passes());
+        assert_eq!(
+            passes,
+            DiagnosticPasses::all().without(DiagnosticPass::Complexity)
+        );
+
+        fs::remove_dir_all(root_a).ok();
+        fs::remove_dir_all(root_b).ok();
+    }
+
     #[test]
     fn library_docs_cache_reuses_entries_until_workspace_config_changes() {
         let root = temp_dir("trustlsp-library-doc-cache");
@@ -35,3 +35,36 @@ pub(crate) fn test_client() -> Client {
         .expect("test client");
     client
 }
+
+/// Applies LSP text edits (character-based positions) to `source`.
+pub(crate) fn apply_text_edits(source: &str, edits: &[tower_lsp::lsp_types::TextEdit]) -> String {
+    let offset = |position: tower_lsp::lsp_types::Position| {
+        let line_start: usize = source
+            .split_inclusive('\n')
+            .take(position.line as usize)
+            .map(str::len)
+            .sum();
+        let column: usize = source[line_start..]
+            .chars()
+            .take(position.character as usize)
+            .map(char::len_utf8)
+            .sum();
+        line_start + column
+    };
+    let mut edits: Vec<_> = edits
+        .iter()
+        .map(|edit| {
+            (
+                offset(edit.range.start),
+                offset(edit.range.end),
+                &edit.new_text,
+            )
+        })
+        .collect();
+    edits.sort_by_key(|(start, _, _)| std::cmp::Reverse(*start));
+    let mut result = source.to_string();
+    for (start, end, new_text) in edits {
+        result.replace_range(start..end, new_text);
+    }
+    result
+}
@@ -105,6 +105,17 @@ impl<'a> BytecodeEncoder<'a> {
                     false
                 }
             }
+            // Bytecode has no exception opcodes, so the protected body and
+            // `__FINALLY` run inline and the `__CATCH` handler is dropped.
+            crate::eval::stmt::Stmt::Try {
+                body,
+                finally_block,
+                ..
+            } => {
+                self.emit_block(ctx, pou_id, body, code, debug_entries)?;
+                self.emit_block(ctx, pou_id, finally_block, code, debug_entries)?;
+                true
+            }
             _ => false,
         };
 
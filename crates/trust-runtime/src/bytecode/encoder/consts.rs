@@ -45,6 +45,7 @@ impl<'a> BytecodeEncoder<'a> {
             now: crate::value::Duration::ZERO,
             debug: None,
             call_depth: 0,
+            max_call_depth: crate::eval::DEFAULT_MAX_CALL_DEPTH,
             functions: None,
             stdlib: None,
             function_blocks: None,
@@ -56,6 +57,7 @@ impl<'a> BytecodeEncoder<'a> {
             loop_depth: 0,
             pause_requested: false,
             execution_deadline: None,
+            fault_location: None,
         };
         crate::eval::expr::eval_expr(&mut ctx, expr)
             .map_err(|_| BytecodeError::InvalidSection("unsupported const expression".into()))
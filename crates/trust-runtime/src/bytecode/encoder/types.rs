@@ -319,13 +319,14 @@ impl<'a> BytecodeEncoder<'a> {
                 dimensions,
             } => {
                 let elem_type_id = self.type_index(*element)?;
-                (
-                    TypeKind::Array,
-                    TypeData::Array {
-                        elem_type_id,
-                        dims: dimensions.clone(),
-                    },
-                )
+                let dims = dimensions
+                    .iter()
+                    .copied()
+                    .collect::<Option<_>>()
+                    .ok_or_else(|| {
+                        BytecodeError::InvalidSection("non-constant array bounds".into())
+                    })?;
+                (TypeKind::Array, TypeData::Array { elem_type_id, dims })
             }
             Type::Struct { fields, .. } => {
                 let mut out_fields = Vec::with_capacity(fields.len());
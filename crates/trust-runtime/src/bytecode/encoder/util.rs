@@ -43,6 +43,18 @@ pub(super) fn count_for_loops(stmts: &[crate::eval::stmt::Stmt]) -> usize {
             | crate::eval::stmt::Stmt::Repeat { body, .. } => {
                 count = count.saturating_add(count_for_loops(body));
             }
+            crate::eval::stmt::Stmt::Try {
+                body,
+                catch,
+                finally_block,
+                ..
+            } => {
+                count = count.saturating_add(count_for_loops(body));
+                if let Some((_, handler)) = catch {
+                    count = count.saturating_add(count_for_loops(handler));
+                }
+                count = count.saturating_add(count_for_loops(finally_block));
+            }
             crate::eval::stmt::Stmt::Label { stmt, .. } => {
                 if let Some(stmt) = stmt.as_deref() {
                     count = count.saturating_add(count_for_loops(std::slice::from_ref(stmt)));
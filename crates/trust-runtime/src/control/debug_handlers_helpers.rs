@@ -104,6 +104,7 @@ fn evaluate_with_snapshot(
             now,
             debug: None,
             call_depth: 0,
+            max_call_depth: crate::eval::DEFAULT_MAX_CALL_DEPTH,
             functions: Some(functions),
             stdlib: Some(stdlib),
             function_blocks: Some(function_blocks),
@@ -115,6 +116,7 @@ fn evaluate_with_snapshot(
             loop_depth: 0,
             pause_requested: false,
             execution_deadline: None,
+            fault_location: None,
         };
         crate::eval::eval_expr(&mut ctx, expr)
     };
@@ -8,6 +8,11 @@ pub(super) fn dispatch(request: &ControlRequest, state: &ControlState) -> Option
             request.params.clone(),
             state,
         ),
+        "instance.reset" => super::super::program_handlers::handle_instance_reset(
+            request.id,
+            request.params.clone(),
+            state,
+        ),
         "bytecode.reload" => super::super::program_handlers::handle_bytecode_reload(
             request.id,
             request.params.clone(),
@@ -57,7 +57,9 @@ pub(crate) fn required_role_for_control_request(
         | "debug.breakpoint_locations"
         | "breakpoints.list"
         | "var.forced" => AccessRole::Viewer,
-        "pause" | "resume" | "restart" | "hmi.alarm.ack" | "pair.claim" => AccessRole::Operator,
+        "pause" | "resume" | "restart" | "instance.reset" | "hmi.alarm.ack" | "pair.claim" => {
+            AccessRole::Operator
+        }
         "step_in"
         | "step_over"
         | "step_out"
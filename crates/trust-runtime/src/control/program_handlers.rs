@@ -6,7 +6,9 @@ use base64::engine::general_purpose::STANDARD as BASE64_STANDARD;
 use base64::Engine;
 use serde_json::json;
 
-use super::types::{BytecodeReloadParams, PairClaimParams, PairRevokeParams, RestartParams};
+use super::types::{
+    BytecodeReloadParams, InstanceResetParams, PairClaimParams, PairRevokeParams, RestartParams,
+};
 use super::{ControlResponse, ControlState};
 
 #[derive(Debug, Clone, Copy)]
@@ -86,6 +88,46 @@ pub(super) fn handle_restart(
     ControlResponse::ok(id, json!({"status": "restart queued"}))
 }
 
+/// Resets one FB instance in place. `mode` picks which members survive, as for
+/// `restart`; it defaults to `warm`, keeping RETAIN members.
+pub(super) fn handle_instance_reset(
+    id: u64,
+    params: Option<serde_json::Value>,
+    state: &ControlState,
+) -> ControlResponse {
+    let params: InstanceResetParams = match params {
+        Some(value) => match serde_json::from_value(value) {
+            Ok(parsed) => parsed,
+            Err(err) => return ControlResponse::error(id, format!("invalid params: {err}")),
+        },
+        None => return ControlResponse::error(id, "missing params".into()),
+    };
+    let mode = match params
+        .mode
+        .as_deref()
+        .unwrap_or("warm")
+        .to_ascii_lowercase()
+        .as_str()
+    {
+        "warm" => RestartMode::Warm,
+        "cold" => RestartMode::Cold,
+        _ => return ControlResponse::error(id, "invalid restart mode".into()),
+    };
+    let (tx, rx) = std::sync::mpsc::channel();
+    if let Err(err) = state.resource.send_command(ResourceCommand::ResetInstance {
+        path: params.path.as_str().into(),
+        mode,
+        respond_to: tx,
+    }) {
+        return ControlResponse::error(id, err.to_string());
+    }
+    match rx.recv_timeout(std::time::Duration::from_secs(5)) {
+        Ok(Ok(())) => ControlResponse::ok(id, json!({ "status": "reset", "path": params.path })),
+        Ok(Err(err)) => ControlResponse::error(id, err.to_string()),
+        Err(_) => ControlResponse::error(id, "reset timeout".into()),
+    }
+}
+
 pub(super) fn handle_bytecode_reload(
     id: u64,
     params: Option<serde_json::Value>,
@@ -115,6 +115,10 @@ fn hmi_test_state(source: &str) -> ControlState {
                 ResourceCommand::Snapshot { respond_to } => {
                     let _ = respond_to.send(snapshot.clone());
                 }
+                ResourceCommand::ResetInstance { respond_to, .. } => {
+                    let _ = respond_to
+                        .send(Err(RuntimeError::ControlError(SmolStr::new("unsupported"))));
+                }
                 ResourceCommand::MeshApply { .. }
                 | ResourceCommand::Pause
                 | ResourceCommand::Resume
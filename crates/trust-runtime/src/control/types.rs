@@ -158,6 +158,12 @@ pub(super) struct RestartParams {
     pub(super) mode: String,
 }
 
+#[derive(Debug, Deserialize)]
+pub(super) struct InstanceResetParams {
+    pub(super) path: String,
+    pub(super) mode: Option<String>,
+}
+
 #[derive(Debug, Deserialize)]
 pub(super) struct BytecodeReloadParams {
     pub(super) bytes: String,
@@ -298,5 +304,16 @@ pub(super) fn runtime_event_to_json(event: RuntimeEvent) -> serde_json::Value {
             "error": error,
             "time_ns": time.as_nanos(),
         }),
+        RuntimeEvent::UninitializedRead {
+            name,
+            location,
+            time,
+        } => json!({
+            "type": "uninitialized_read",
+            "name": name.as_str(),
+            "file_id": location.map(|location| location.file_id),
+            "start": location.map(|location| location.start),
+            "time_ns": time.as_nanos(),
+        }),
     }
 }
@@ -12,7 +12,7 @@ use crate::eval::expr::{Expr, LValue};
 use crate::eval::{eval_expr, EvalContext};
 use crate::io::{IoAddress, IoSnapshot};
 use crate::memory::{FrameId, InstanceId};
-use crate::value::Value;
+use crate::value::{Duration, Value};
 
 use super::breakpoints::matches_breakpoint;
 use super::hook::DebugHook;
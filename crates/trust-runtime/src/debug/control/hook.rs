@@ -17,6 +17,19 @@ impl DebugHook for DebugControl {
     ) {
         self.on_statement_inner(location, call_depth, Some(ctx));
     }
+
+    fn on_uninitialized_read(&mut self, name: &str, now: Duration) {
+        let location = {
+            let (lock, _) = &*self.state;
+            let state = lock.lock().expect("debug state poisoned");
+            state.last_location
+        };
+        self.push_runtime_event(RuntimeEvent::UninitializedRead {
+            name: SmolStr::new(name),
+            location,
+            time: now,
+        });
+    }
 }
 
 impl DebugControl {
@@ -3,6 +3,7 @@
 #![allow(missing_docs)]
 
 use crate::eval::EvalContext;
+use crate::value::Duration;
 
 use super::SourceLocation;
 
@@ -20,6 +21,11 @@ pub trait DebugHook {
     ) {
         self.on_statement(location, call_depth);
     }
+
+    /// Called when a local is read before it was ever written.
+    ///
+    /// Only reported while uninitialized-read tracking is enabled on the storage.
+    fn on_uninitialized_read(&mut self, _name: &str, _now: Duration) {}
 }
 
 /// No-op debug hook.
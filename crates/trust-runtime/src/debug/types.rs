@@ -164,6 +164,15 @@ pub enum RuntimeEvent {
         /// Time when the fault was recorded.
         time: Duration,
     },
+    /// A local was read before it was ever written.
+    UninitializedRead {
+        /// Variable name.
+        name: SmolStr,
+        /// Statement being executed when the read happened (if known).
+        location: Option<SourceLocation>,
+        /// Time when the read was detected.
+        time: Duration,
+    },
 }
 
 /// Stop reason for debugger events.
@@ -75,6 +75,10 @@ pub enum RuntimeError {
     #[error("arithmetic overflow")]
     Overflow,
 
+    /// Math function argument outside the function's domain.
+    #[error("argument outside the domain of {0}")]
+    MathDomain(SmolStr),
+
     /// Index out of bounds.
     #[error("array index {index} out of bounds [{lower}..{upper}]")]
     IndexOutOfBounds { index: i64, lower: i64, upper: i64 },
@@ -139,6 +143,10 @@ pub enum RuntimeError {
     #[error("execution timed out")]
     ExecutionTimeout,
 
+    /// Nested POU calls exceeded the configured maximum depth.
+    #[error("call depth limit {limit} exceeded: {}", .chain.join(" -> "))]
+    CallDepthExceeded { limit: u32, chain: Vec<SmolStr> },
+
     /// Scripted simulation fault injection.
     #[error("simulation fault '{0}'")]
     SimulationFault(SmolStr),
@@ -160,6 +168,24 @@ pub enum RuntimeError {
     ControlError(SmolStr),
 }
 
+impl RuntimeError {
+    /// Exception code delivered to a `__CATCH` handler, or `None` when the
+    /// error cannot be trapped and must fault the resource.
+    ///
+    /// Codes follow the CODESYS `RTSEXCPT_*` numbering.
+    #[must_use]
+    pub fn exception_code(&self) -> Option<i64> {
+        match self {
+            Self::NullReference => Some(0x51),
+            Self::IndexOutOfBounds { .. } => Some(0x100),
+            Self::DivisionByZero | Self::ModuloByZero => Some(0x101),
+            Self::Overflow => Some(0x102),
+            Self::MathDomain(_) => Some(0x154),
+            _ => None,
+        }
+    }
+}
+
 impl From<DateTimeError> for RuntimeError {
     fn from(value: DateTimeError) -> Self {
         Self::DateTimeRange(value)
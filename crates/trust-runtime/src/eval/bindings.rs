@@ -5,42 +5,35 @@ fn prepare_bindings(
     mode: BindingMode,
 ) -> Result<PreparedBindings, RuntimeError> {
     let positional = args.iter().all(|arg| arg.name.is_none());
-    let mut positional_iter = if positional { Some(args.iter()) } else { None };
-    if positional {
-        let expected = params.iter().filter(|param| !is_en_eno(param)).count();
-        if args.len() != expected {
-            return Err(RuntimeError::InvalidArgumentCount {
-                expected,
-                got: args.len(),
-            });
-        }
-    }
+    let assigned = assign_args(params, args)?;
 
     let mut param_values = Vec::new();
     let mut out_targets = Vec::new();
 
-    for param in params {
+    for (param, arg) in params.iter().zip(assigned.iter().copied()) {
         if param.name.eq_ignore_ascii_case("EN") && matches!(param.direction, ParamDirection::In) {
-            let en_value = if positional {
-                Value::Bool(true)
-            } else {
-                find_arg_value(args, &param.name)
-                    .map(|arg| eval_arg_expr(ctx, arg))
-                    .transpose()?
-                    .unwrap_or(Value::Bool(true))
-            };
+            let en_value = arg
+                .map(|arg| eval_arg_expr(ctx, arg))
+                .transpose()?
+                .unwrap_or(Value::Bool(true));
             param_values.push((param.name.clone(), en_value.clone()));
             if let Value::Bool(false) = en_value {
-                let eno_param = params.iter().find(|p| {
-                    p.name.eq_ignore_ascii_case("ENO") && matches!(p.direction, ParamDirection::Out)
-                });
-                if let Some(eno_param) = eno_param {
-                    if let Some(arg) = find_arg_target(args, &eno_param.name) {
-                        out_targets.push(OutputBinding::Value {
-                            target: arg.clone(),
-                            value: Value::Bool(false),
-                        });
-                    }
+                let eno_target = params
+                    .iter()
+                    .zip(assigned.iter().copied())
+                    .find(|(p, _)| {
+                        p.name.eq_ignore_ascii_case("ENO")
+                            && matches!(p.direction, ParamDirection::Out)
+                    })
+                    .and_then(|(_, arg)| match arg.map(|arg| &arg.value) {
+                        Some(ArgValue::Target(target)) => Some(target),
+                        _ => None,
+                    });
+                if let Some(target) = eno_target {
+                    out_targets.push(OutputBinding::Value {
+                        target: target.clone(),
+                        value: Value::Bool(false),
+                    });
                 }
                 return Ok(PreparedBindings {
                     should_execute: false,
@@ -61,13 +54,12 @@ fn prepare_bindings(
             continue;
         }
 
-        let arg = if positional {
-            positional_iter.as_mut().and_then(|iter| iter.next())
-        } else {
-            find_arg_value(args, &param.name)
-        };
         match param.direction {
             ParamDirection::In => {
+                if arg.is_none() && matches!(mode, BindingMode::FunctionBlock) {
+                    // Omitted FB inputs keep the value from the previous call.
+                    continue;
+                }
                 let value = if let Some(arg) = arg {
                     eval_arg_expr(ctx, arg)?
                 } else if let Some(default) = &param.default {
@@ -77,13 +69,19 @@ fn prepare_bindings(
                         .unwrap_or(Value::Null)
                 };
                 let value = coerce_input_value_to_param_type(value, param.type_id)?;
+                let value = fit_to_declared_length(value, param.type_id, ctx.registry);
                 param_values.push((param.name.clone(), value));
             }
             ParamDirection::Out => {
                 if matches!(mode, BindingMode::Function) {
-                    let value =
+                    // Function and method outputs start fresh on every call.
+                    let value = if let Some(default) = &param.default {
+                        let value = expr::eval_expr(ctx, default)?;
+                        coerce_input_value_to_param_type(value, param.type_id)?
+                    } else {
                         default_value_for_type_id(param.type_id, ctx.registry, &ctx.profile)
-                            .unwrap_or(Value::Null);
+                            .unwrap_or(Value::Null)
+                    };
                     param_values.push((param.name.clone(), value));
                 }
                 if let Some(arg) = arg {
@@ -92,6 +90,7 @@ fn prepare_bindings(
                     };
                     out_targets.push(OutputBinding::Param {
                         param: param.name.clone(),
+                        type_id: param.type_id,
                         target: target.clone(),
                     });
                 }
@@ -105,6 +104,7 @@ fn prepare_bindings(
                     param_values.push((param.name.clone(), value.clone()));
                     out_targets.push(OutputBinding::Param {
                         param: param.name.clone(),
+                        type_id: param.type_id,
                         target: target.clone(),
                     });
                 }
@@ -118,6 +118,51 @@ fn prepare_bindings(
     })
 }
 
+/// Matches call arguments to parameters: leading positional arguments fill the
+/// non-EN/ENO parameters in declaration order, named arguments bind by name in
+/// any order.
+fn assign_args<'a>(
+    params: &[Param],
+    args: &'a [CallArg],
+) -> Result<Vec<Option<&'a CallArg>>, RuntimeError> {
+    let mut assigned = vec![None; params.len()];
+    let mut positional_slots = (0..params.len()).filter(|&index| !is_en_eno(&params[index]));
+    let expected = positional_slots.clone().count();
+    if args.iter().all(|arg| arg.name.is_none()) && args.len() != expected {
+        return Err(RuntimeError::InvalidArgumentCount {
+            expected,
+            got: args.len(),
+        });
+    }
+
+    let mut saw_named = false;
+    for arg in args {
+        let Some(name) = arg.name.as_ref() else {
+            if saw_named {
+                return Err(RuntimeError::InvalidArgumentName("<unnamed>".into()));
+            }
+            let index = positional_slots
+                .next()
+                .ok_or(RuntimeError::InvalidArgumentCount {
+                    expected,
+                    got: args.len(),
+                })?;
+            assigned[index] = Some(arg);
+            continue;
+        };
+        saw_named = true;
+        let index = params
+            .iter()
+            .position(|param| param.name.eq_ignore_ascii_case(name))
+            .ok_or_else(|| RuntimeError::InvalidArgumentName(name.clone()))?;
+        if assigned[index].is_some() {
+            return Err(RuntimeError::InvalidArgumentName(name.clone()));
+        }
+        assigned[index] = Some(arg);
+    }
+    Ok(assigned)
+}
+
 fn coerce_input_value_to_param_type(value: Value, type_id: TypeId) -> Result<Value, RuntimeError> {
     if matches!(
         type_id,
@@ -155,3 +200,21 @@ fn coerce_input_value_to_param_type(value: Value, type_id: TypeId) -> Result<Val
     };
     result
 }
+
+/// Applies a bounded STRING[n]/WSTRING[n] parameter length to a bound value.
+fn fit_to_declared_length(value: Value, type_id: TypeId, registry: &TypeRegistry) -> Value {
+    match registry.get(type_id) {
+        Some(
+            trust_hir::Type::String {
+                max_len: Some(max_len),
+            }
+            | trust_hir::Type::WString {
+                max_len: Some(max_len),
+            },
+        ) => crate::stdlib::string::truncate_to_length(value, *max_len),
+        Some(trust_hir::Type::Alias { target, .. }) => {
+            fit_to_declared_length(value, *target, registry)
+        }
+        _ => value,
+    }
+}
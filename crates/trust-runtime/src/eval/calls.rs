@@ -18,12 +18,33 @@ pub fn exec_block(
     stmt::exec_block(ctx, stmts)
 }
 
+/// Fails with [`RuntimeError::CallDepthExceeded`] when calling `callee` would
+/// nest deeper than the configured limit. The reported chain lists the active
+/// frames, outermost first, followed by `callee`.
+fn check_call_depth(ctx: &EvalContext<'_>, callee: &SmolStr) -> Result<(), RuntimeError> {
+    if ctx.call_depth < ctx.max_call_depth {
+        return Ok(());
+    }
+    let chain = ctx
+        .storage
+        .frames()
+        .iter()
+        .map(|frame| frame.owner.clone())
+        .chain(std::iter::once(callee.clone()))
+        .collect();
+    Err(RuntimeError::CallDepthExceeded {
+        limit: ctx.max_call_depth,
+        chain,
+    })
+}
+
 /// Call a function definition.
 pub fn call_function<'a>(
     ctx: &mut EvalContext<'a>,
     func: &'a FunctionDef,
     args: &[CallArg],
 ) -> Result<Value, RuntimeError> {
+    check_call_depth(ctx, &func.name)?;
     let saved_using = ctx.using;
     let saved_return = ctx.return_name.clone();
     let PreparedBindings {
@@ -123,6 +144,7 @@ pub fn call_method(
     instance_id: InstanceId,
     args: &[CallArg],
 ) -> Result<Value, RuntimeError> {
+    check_call_depth(ctx, &method.name)?;
     let saved_using = ctx.using;
     let saved_instance = ctx.current_instance;
     let saved_return = ctx.return_name.clone();
@@ -234,6 +256,7 @@ pub fn call_function_block<'a>(
     instance_id: InstanceId,
     args: &[CallArg],
 ) -> Result<(), RuntimeError> {
+    check_call_depth(ctx, &fb.name)?;
     let saved_using = ctx.using;
     let saved_instance = ctx.current_instance;
     let PreparedBindings {
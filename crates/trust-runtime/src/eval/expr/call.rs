@@ -16,6 +16,7 @@ include!("call/arg_read.rs");
 include!("call/stdlib_args.rs");
 include!("call/split_call.rs");
 include!("call/reference.rs");
+include!("call/selection.rs");
 
 #[cfg(test)]
 mod tests {
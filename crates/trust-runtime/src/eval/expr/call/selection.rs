@@ -0,0 +1,134 @@
+/// Returns true for the selection functions that evaluate their operands lazily.
+pub(super) fn is_lazy_selection_name(key: &str) -> bool {
+    matches!(key, "SEL" | "MUX")
+}
+
+/// Evaluates `SEL`/`MUX`, evaluating the selector and then only the chosen operand.
+///
+/// Operands that are not selected are never evaluated, so calls inside them have
+/// no side effects. The selected value is converted to the common type of all
+/// operands, as the eager `SEL`/`MUX` standard functions do; unselected operands
+/// contribute their type through [`operand_type_sample`].
+pub(super) fn eval_selection_call(
+    ctx: &mut EvalContext<'_>,
+    key: &str,
+    args: &[CallArg],
+) -> Result<Value, RuntimeError> {
+    let is_sel = key == "SEL";
+    let (selector, inputs) = bind_selection_args(if is_sel { "G" } else { "K" }, args)?;
+    if (is_sel && inputs.len() != 2) || inputs.len() < 2 {
+        return Err(RuntimeError::InvalidArgumentCount {
+            expected: 3,
+            got: args.len(),
+        });
+    }
+
+    let selector = read_arg_value(ctx, selector)?;
+    let index = if is_sel {
+        match selector {
+            Value::Bool(value) => usize::from(value),
+            _ => return Err(RuntimeError::TypeMismatch),
+        }
+    } else {
+        let index = crate::stdlib::helpers::to_i64(&selector)?;
+        usize::try_from(index)
+            .ok()
+            .filter(|index| *index < inputs.len())
+            .ok_or(RuntimeError::IndexOutOfBounds {
+                index,
+                lower: 0,
+                upper: inputs.len() as i64 - 1,
+            })?
+    };
+    let selected = read_arg_value(ctx, inputs[index])?;
+    let mut samples = vec![selected];
+    samples.extend(
+        inputs
+            .iter()
+            .enumerate()
+            .filter(|(position, _)| *position != index)
+            .filter_map(|(_, arg)| operand_type_sample(ctx, arg)),
+    );
+    let kind = crate::stdlib::helpers::common_kind(&samples)?;
+    crate::stdlib::helpers::coerce_to_common(&samples[0], &kind)
+}
+
+/// Returns a value with the type an unselected operand would produce, without
+/// running any of its side effects.
+///
+/// Variables and literals are read as-is and user function calls stand in as
+/// the default value of their return type. Other operands are left out of the
+/// common type.
+fn operand_type_sample(ctx: &EvalContext<'_>, arg: &CallArg) -> Option<Value> {
+    match &arg.value {
+        ArgValue::Target(LValue::Name(name)) | ArgValue::Expr(Expr::Name(name)) => {
+            super::access::read_name(ctx, name).ok()
+        }
+        ArgValue::Expr(Expr::Literal(value)) => Some(value.clone()),
+        ArgValue::Expr(Expr::Call { target, .. }) => {
+            let name = call_target_name(target)?;
+            let func = ctx
+                .functions?
+                .get(&SmolStr::new(name.to_ascii_uppercase()))?;
+            crate::value::default_value_for_type_id(func.return_type, ctx.registry, &ctx.profile)
+                .ok()
+        }
+        _ => None,
+    }
+}
+
+/// Splits selection arguments into the selector and the `IN0..INn` operands.
+///
+/// Arguments are either all positional or all named, as for other standard
+/// functions.
+fn bind_selection_args<'a>(
+    selector_name: &str,
+    args: &'a [CallArg],
+) -> Result<(&'a CallArg, Vec<&'a CallArg>), RuntimeError> {
+    if args.iter().all(|arg| arg.name.is_none()) {
+        let Some((selector, inputs)) = args.split_first() else {
+            return Err(RuntimeError::InvalidArgumentCount {
+                expected: 3,
+                got: 0,
+            });
+        };
+        return Ok((selector, inputs.iter().collect()));
+    }
+
+    let mut selector = None;
+    let mut inputs: Vec<Option<&CallArg>> = Vec::new();
+    for arg in args {
+        let Some(name) = arg.name.as_ref() else {
+            return Err(RuntimeError::InvalidArgumentName("<unnamed>".into()));
+        };
+        let key = name.to_ascii_uppercase();
+        let slot = if key == selector_name {
+            &mut selector
+        } else {
+            let index = key
+                .strip_prefix("IN")
+                .and_then(|suffix| suffix.parse::<usize>().ok())
+                .ok_or_else(|| RuntimeError::InvalidArgumentName(name.clone()))?;
+            if inputs.len() <= index {
+                inputs.resize(index + 1, None);
+            }
+            &mut inputs[index]
+        };
+        if slot.replace(arg).is_some() {
+            return Err(RuntimeError::InvalidArgumentName(name.clone()));
+        }
+    }
+
+    let missing = RuntimeError::InvalidArgumentCount {
+        expected: inputs.len() + 1,
+        got: args.len(),
+    };
+    let Some(selector) = selector else {
+        return Err(missing);
+    };
+    let inputs = inputs
+        .into_iter()
+        .collect::<Option<Vec<_>>>()
+        .ok_or(missing)?;
+    Ok((selector, inputs))
+}
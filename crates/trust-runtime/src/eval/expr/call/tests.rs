@@ -13,6 +13,7 @@ fn make_context<'a>(storage: &'a mut VariableStorage, registry: &'a TypeRegistry
         now: Duration::ZERO,
         debug: None,
         call_depth: 0,
+        max_call_depth: crate::eval::DEFAULT_MAX_CALL_DEPTH,
         functions: None,
         stdlib: None,
         function_blocks: None,
@@ -24,6 +25,7 @@ fn make_context<'a>(storage: &'a mut VariableStorage, registry: &'a TypeRegistry
         loop_depth: 0,
         pause_requested: false,
         execution_deadline: None,
+        fault_location: None,
     }
 }
 
@@ -9,8 +9,9 @@ use crate::value::{size_of_type, size_of_value, SizeOfError, Value};
 use super::access::{eval_indices, read_field, read_indices, read_name};
 use super::ast::{Expr, SizeOfTarget};
 use super::call::{
-    bind_stdlib_named_args, call_target_name, eval_positional_args, eval_ref_call, eval_split_call,
-    resolve_instance_method, resolve_using_function,
+    bind_stdlib_named_args, call_target_name, eval_positional_args, eval_ref_call,
+    eval_selection_call, eval_split_call, is_lazy_selection_name, resolve_instance_method,
+    resolve_using_function,
 };
 use super::lvalue::resolve_reference_for_lvalue;
 
@@ -34,7 +35,15 @@ pub fn eval_expr(ctx: &mut EvalContext<'_>, expr: &Expr) -> Result<Value, Runtim
                 .ok_or(RuntimeError::TypeMismatch)
         }
         Expr::SizeOf(target) => eval_size_of(ctx, target),
-        Expr::Name(name) => read_name(ctx, name),
+        Expr::Name(name) => {
+            if ctx.storage.tracks_uninitialized() && ctx.storage.take_uninitialized_local(name) {
+                let now = ctx.now;
+                if let Some(debug) = ctx.debug.as_mut() {
+                    debug.on_uninitialized_read(name, now);
+                }
+            }
+            read_name(ctx, name)
+        }
         Expr::Call { target, args } => {
             if let Some(name) = call_target_name(target) {
                 let key = SmolStr::new(name.to_ascii_uppercase());
@@ -59,6 +68,9 @@ pub fn eval_expr(ctx: &mut EvalContext<'_>, expr: &Expr) -> Result<Value, Runtim
                     }
                 }
                 if let Some(stdlib) = ctx.stdlib {
+                    if is_lazy_selection_name(key.as_str()) && stdlib.get(&key).is_some() {
+                        return eval_selection_call(ctx, key.as_str(), args);
+                    }
                     let has_named = args.iter().any(|arg| arg.name.is_some());
                     if let Some(entry) = stdlib.get(&key) {
                         let values = if has_named {
@@ -163,7 +175,7 @@ pub fn eval_expr(ctx: &mut EvalContext<'_>, expr: &Expr) -> Result<Value, Runtim
                     .read_by_ref(reference)
                     .cloned()
                     .ok_or(RuntimeError::NullReference),
-                Value::Reference(None) => Err(RuntimeError::NullReference),
+                Value::Reference(None) | Value::Null => Err(RuntimeError::NullReference),
                 _ => Err(RuntimeError::TypeMismatch),
             }
         }
@@ -57,7 +57,7 @@ pub(super) fn resolve_reference_for_lvalue(
             let value = super::eval::eval_expr(ctx, expr)?;
             match value {
                 Value::Reference(Some(reference)) => Ok(reference),
-                Value::Reference(None) => Err(RuntimeError::NullReference),
+                Value::Reference(None) | Value::Null => Err(RuntimeError::NullReference),
                 _ => Err(RuntimeError::TypeMismatch),
             }
         }
@@ -85,7 +85,7 @@ pub fn read_lvalue(ctx: &mut EvalContext<'_>, target: &LValue) -> Result<Value,
                     .read_by_ref(reference)
                     .cloned()
                     .ok_or(RuntimeError::NullReference),
-                Value::Reference(None) => Err(RuntimeError::NullReference),
+                Value::Reference(None) | Value::Null => Err(RuntimeError::NullReference),
                 _ => Err(RuntimeError::TypeMismatch),
             }
         }
@@ -133,7 +133,7 @@ pub fn write_lvalue(
                         Err(RuntimeError::NullReference)
                     }
                 }
-                Value::Reference(None) => Err(RuntimeError::NullReference),
+                Value::Reference(None) | Value::Null => Err(RuntimeError::NullReference),
                 _ => Err(RuntimeError::TypeMismatch),
             }
         }
@@ -57,6 +57,9 @@ pub(crate) fn init_locals(
                 .unwrap_or(Value::Null)
         };
         ctx.storage.set_local(local.name.clone(), value);
+        if local.initializer.is_none() {
+            ctx.storage.mark_local_uninitialized(&local.name);
+        }
     }
     Ok(())
 }
@@ -120,6 +123,9 @@ pub(crate) fn init_locals_in_frame(
                 .unwrap_or(Value::Null)
         };
         ctx.storage.set_local(local.name.clone(), value);
+        if local.initializer.is_none() {
+            ctx.storage.mark_local_uninitialized(&local.name);
+        }
     }
     Ok(())
 }
@@ -5,8 +5,14 @@ fn collect_outputs(
     let mut values = Vec::new();
     for binding in out_targets {
         match binding {
-            OutputBinding::Param { param, target } => {
+            OutputBinding::Param {
+                param,
+                type_id,
+                target,
+            } => {
+                // Outputs and in-outs write back at the parameter's declared length.
                 let value = expr::read_lvalue(ctx, &expr::LValue::Name(param.clone()))?;
+                let value = fit_to_declared_length(value, *type_id, ctx.registry);
                 values.push((target.clone(), value));
             }
             OutputBinding::Value { target, value } => {
@@ -31,17 +37,6 @@ fn eval_arg_expr(ctx: &mut EvalContext<'_>, arg: &CallArg) -> Result<Value, Runt
     expr::read_arg_value(ctx, arg)
 }
 
-fn find_arg_value<'a>(args: &'a [CallArg], name: &SmolStr) -> Option<&'a CallArg> {
-    args.iter().find(|arg| arg.name.as_ref() == Some(name))
-}
-
-fn find_arg_target<'a>(args: &'a [CallArg], name: &SmolStr) -> Option<&'a expr::LValue> {
-    args.iter().find_map(|arg| match &arg.value {
-        ArgValue::Target(target) if arg.name.as_ref() == Some(name) => Some(target),
-        _ => None,
-    })
-}
-
 fn is_en_eno(param: &Param) -> bool {
     matches!(param.direction, ParamDirection::In | ParamDirection::Out)
         && (param.name.eq_ignore_ascii_case("EN") || param.name.eq_ignore_ascii_case("ENO"))
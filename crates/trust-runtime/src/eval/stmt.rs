@@ -75,6 +75,12 @@ pub enum Stmt {
         until: Expr,
         location: Option<SourceLocation>,
     },
+    Try {
+        body: Vec<Stmt>,
+        catch: Option<(LValue, Vec<Stmt>)>,
+        finally_block: Vec<Stmt>,
+        location: Option<SourceLocation>,
+    },
     Label {
         name: SmolStr,
         stmt: Option<Box<Stmt>>,
@@ -108,6 +114,7 @@ impl Stmt {
             | Stmt::For { location, .. }
             | Stmt::While { location, .. }
             | Stmt::Repeat { location, .. }
+            | Stmt::Try { location, .. }
             | Stmt::Label { location, .. }
             | Stmt::Jmp { location, .. }
             | Stmt::Return { location, .. }
@@ -118,7 +125,18 @@ impl Stmt {
 }
 
 /// Execute a statement.
+///
+/// On error, the location of the innermost failing statement is recorded in
+/// `ctx.fault_location`.
 pub fn exec_stmt(ctx: &mut EvalContext<'_>, stmt: &Stmt) -> Result<StmtResult, RuntimeError> {
+    let result = exec_stmt_inner(ctx, stmt);
+    if result.is_err() && ctx.fault_location.is_none() {
+        ctx.fault_location = stmt.location().copied();
+    }
+    result
+}
+
+fn exec_stmt_inner(ctx: &mut EvalContext<'_>, stmt: &Stmt) -> Result<StmtResult, RuntimeError> {
     check_execution_budget(ctx)?;
     #[cfg(feature = "debug")]
     if let Some(hook) = ctx.debug.take() {
@@ -228,7 +246,7 @@ pub fn exec_stmt(ctx: &mut EvalContext<'_>, stmt: &Stmt) -> Result<StmtResult, R
             write_lvalue(
                 ctx,
                 &LValue::Name(control.clone()),
-                coerce_loop_value(&control_template, current)?,
+                int_value_like(&control_template, current)?,
             )?;
             loop {
                 check_execution_budget(ctx)?;
@@ -249,7 +267,7 @@ pub fn exec_stmt(ctx: &mut EvalContext<'_>, stmt: &Stmt) -> Result<StmtResult, R
                 write_lvalue(
                     ctx,
                     &LValue::Name(control.clone()),
-                    coerce_loop_value(&control_template, current)?,
+                    int_value_like(&control_template, current)?,
                 )?;
             }
             Ok(StmtResult::Continue)
@@ -291,6 +309,31 @@ pub fn exec_stmt(ctx: &mut EvalContext<'_>, stmt: &Stmt) -> Result<StmtResult, R
                 return Ok(StmtResult::Continue);
             }
         },
+        Stmt::Try {
+            body,
+            catch,
+            finally_block,
+            ..
+        } => {
+            let result = match exec_block(ctx, body) {
+                Err(err) => match (err.exception_code(), catch) {
+                    (Some(code), Some((target, handler))) => {
+                        ctx.fault_location = None;
+                        // A failure to bind the code still runs `__FINALLY` below.
+                        read_lvalue(ctx, target)
+                            .and_then(|template| int_value_like(&template, code))
+                            .and_then(|value| write_lvalue(ctx, target, value))
+                            .and_then(|()| exec_block(ctx, handler))
+                    }
+                    _ => Err(err),
+                },
+                result => result,
+            };
+            match exec_block(ctx, finally_block)? {
+                StmtResult::Continue => result,
+                finally_result => Ok(finally_result),
+            }
+        }
         Stmt::Label { stmt, .. } => {
             if let Some(inner) = stmt {
                 exec_stmt(ctx, inner)
@@ -386,7 +429,9 @@ fn is_unsigned_int(value: &Value) -> bool {
     )
 }
 
-fn coerce_loop_value(template: &Value, value: i64) -> Result<Value, RuntimeError> {
+/// Converts `value` to the integer type of `template` (FOR control variables,
+/// `__CATCH` exception codes).
+fn int_value_like(template: &Value, value: i64) -> Result<Value, RuntimeError> {
     match template {
         Value::SInt(_) => i8::try_from(value)
             .map(Value::SInt)
@@ -1,3 +1,6 @@
+/// Default limit on nested function, method and function block calls.
+pub const DEFAULT_MAX_CALL_DEPTH: u32 = 256;
+
 /// Evaluation context shared across expression and statement execution.
 pub struct EvalContext<'a> {
     pub storage: &'a mut VariableStorage,
@@ -6,6 +9,7 @@ pub struct EvalContext<'a> {
     pub now: Duration,
     pub debug: Option<&'a mut dyn crate::debug::DebugHook>,
     pub call_depth: u32,
+    pub max_call_depth: u32,
     pub functions: Option<&'a IndexMap<SmolStr, FunctionDef>>,
     pub stdlib: Option<&'a StandardLibrary>,
     pub function_blocks: Option<&'a IndexMap<SmolStr, FunctionBlockDef>>,
@@ -17,6 +21,7 @@ pub struct EvalContext<'a> {
     pub loop_depth: u32,
     pub pause_requested: bool,
     pub execution_deadline: Option<std::time::Instant>,
+    pub fault_location: Option<crate::debug::SourceLocation>,
 }
 
 /// Parameter declaration for POUs.
@@ -120,6 +125,7 @@ pub struct CallArg {
 enum OutputBinding {
     Param {
         param: SmolStr,
+        type_id: TypeId,
         target: expr::LValue,
     },
     Value {
@@ -4,6 +4,7 @@
 
 use super::build;
 use super::types::{CompileError, SourceFile};
+use crate::value::BoolArrayLayout;
 use crate::Runtime;
 
 /// Compile helper for runtime + bytecode builds.
@@ -11,6 +12,7 @@ use crate::Runtime;
 pub struct CompileSession {
     sources: Vec<SourceFile>,
     label_errors: bool,
+    bool_array_layout: BoolArrayLayout,
 }
 
 impl CompileSession {
@@ -19,6 +21,7 @@ impl CompileSession {
         Self {
             sources: vec![SourceFile::new(source)],
             label_errors: false,
+            bool_array_layout: BoolArrayLayout::default(),
         }
     }
 
@@ -28,6 +31,7 @@ impl CompileSession {
         Self {
             sources,
             label_errors,
+            bool_array_layout: BoolArrayLayout::default(),
         }
     }
 
@@ -37,6 +41,13 @@ impl CompileSession {
         self
     }
 
+    /// Select how `ARRAY OF BOOL` variables at bit addresses map onto the
+    /// process image. Defaults to one byte per element.
+    pub fn bool_array_layout(mut self, layout: BoolArrayLayout) -> Self {
+        self.bool_array_layout = layout;
+        self
+    }
+
     /// Access the registered sources.
     pub fn sources(&self) -> &[SourceFile] {
         &self.sources
@@ -44,12 +55,20 @@ impl CompileSession {
 
     /// Compile sources into a runtime.
     pub fn build_runtime(&self) -> Result<Runtime, CompileError> {
-        build::build_runtime_from_source_files(&self.sources, self.label_errors)
+        build::build_runtime_from_source_files(
+            &self.sources,
+            self.label_errors,
+            self.bool_array_layout,
+        )
     }
 
     /// Compile sources into a bytecode module.
     pub fn build_bytecode_module(&self) -> Result<crate::bytecode::BytecodeModule, CompileError> {
-        build::build_bytecode_module_from_source_files(&self.sources, self.label_errors)
+        build::build_bytecode_module_from_source_files(
+            &self.sources,
+            self.label_errors,
+            self.bool_array_layout,
+        )
     }
 
     /// Compile sources into bytecode bytes.
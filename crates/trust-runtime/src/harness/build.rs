@@ -12,13 +12,14 @@ use trust_syntax::parser;
 use super::config::{
     apply_config_inits, apply_globals, apply_program_retain_overrides,
     attach_fb_instances_to_tasks, attach_programs_to_tasks, ensure_wildcards_resolved,
-    register_access_bindings, register_program_instances,
+    register_access_bindings, register_program_instances, register_resource_scopes,
 };
 use super::types::{CompileError, SourceFile};
 
 pub(super) fn build_runtime_from_source_files(
     sources: &[SourceFile],
     label_errors: bool,
+    bool_array_layout: crate::value::BoolArrayLayout,
 ) -> Result<Runtime, CompileError> {
     let mut parses = Vec::with_capacity(sources.len());
     let mut parse_errors = Vec::new();
@@ -66,6 +67,7 @@ pub(super) fn build_runtime_from_source_files(
     }
 
     let mut runtime = Runtime::new();
+    runtime.io_mut().set_bool_array_layout(bool_array_layout);
     let profile = runtime.profile();
     let mut statement_locations: Vec<Vec<SourceLocation>> = vec![Vec::new(); sources.len()];
 
@@ -236,7 +238,8 @@ pub(super) fn build_runtime_from_source_files(
             &mut wildcards,
         )?;
         ensure_wildcards_resolved(&wildcards)?;
-        register_access_bindings(&mut runtime, &config.access)?;
+        register_access_bindings(&mut runtime, &config.access, &config.resources)?;
+        register_resource_scopes(&mut runtime, &config.resources)?;
         let mut tasks = config.tasks;
         attach_programs_to_tasks(&mut tasks, &config.programs)?;
         attach_fb_instances_to_tasks(&runtime, &mut tasks, &config.programs)?;
@@ -280,8 +283,9 @@ pub(super) fn build_runtime_from_source_files(
 pub(super) fn build_bytecode_module_from_source_files(
     sources: &[SourceFile],
     label_errors: bool,
+    bool_array_layout: crate::value::BoolArrayLayout,
 ) -> Result<crate::bytecode::BytecodeModule, CompileError> {
-    let runtime = build_runtime_from_source_files(sources, label_errors)?;
+    let runtime = build_runtime_from_source_files(sources, label_errors, bool_array_layout)?;
     let source_refs = sources
         .iter()
         .map(|source| source.text.as_str())
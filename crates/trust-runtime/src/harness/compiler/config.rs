@@ -9,12 +9,13 @@ use trust_syntax::syntax::{SyntaxKind, SyntaxNode};
 use super::super::lower::{const_duration_from_node, const_int_from_node, lower_expr};
 use super::super::types::CompileError;
 use super::super::util::{
-    collect_using_directives, extract_name_from_expr, is_expression_kind, node_text,
+    collect_using_directives, extract_name_from_expr, is_expression_kind, is_initializer_kind,
+    node_text,
 };
 use super::lower_type_ref;
 use super::model::{
     AccessDecl, AccessPart, AccessPath, ConfigInit, ConfigModel, FbTaskBinding, GlobalInit,
-    LoweringContext, ProgramInstanceConfig,
+    LoweringContext, ProgramInstanceConfig, ResourceModel,
 };
 use super::vars::{parse_var_decl, var_block_kind, var_block_qualifiers, VarBlockKind};
 
@@ -31,6 +31,7 @@ pub(crate) fn lower_configuration(
     let mut programs = Vec::new();
     let mut access = Vec::new();
     let mut config_inits = Vec::new();
+    let mut resources = Vec::new();
 
     for child in config.children() {
         match child.kind() {
@@ -45,35 +46,69 @@ pub(crate) fn lower_configuration(
             SyntaxKind::VarConfigBlock => {
                 config_inits.extend(lower_var_config_block(&child, &mut ctx)?);
             }
-            SyntaxKind::Resource => {
-                let resource = child;
-                for res_child in resource.children() {
-                    match res_child.kind() {
-                        SyntaxKind::VarBlock => {
-                            globals.extend(lower_global_var_block(&res_child, &mut ctx)?)
-                        }
-                        SyntaxKind::TaskConfig => {
-                            tasks.push(lower_task_config(&res_child, &mut ctx)?)
-                        }
-                        SyntaxKind::ProgramConfig => {
-                            programs.push(lower_program_config(&res_child, &mut ctx)?)
-                        }
-                        SyntaxKind::VarAccessBlock => {
-                            let result = lower_var_access_block(&res_child, &mut ctx)?;
-                            globals.extend(result.globals);
-                            access.extend(result.access);
-                        }
-                        SyntaxKind::VarConfigBlock => {
-                            config_inits.extend(lower_var_config_block(&res_child, &mut ctx)?);
-                        }
-                        _ => {}
-                    }
-                }
-            }
+            SyntaxKind::Resource => resources.push(lower_resource(&child, &mut ctx)?),
             _ => {}
         }
     }
 
+    let mut resource_models = Vec::new();
+    let scoped = resources.len() > 1;
+    // Only names declared more than once need a `RESOURCE.` prefix to stay apart.
+    let mut declared = std::collections::HashMap::<String, usize>::new();
+    for global in globals
+        .iter()
+        .chain(resources.iter().flat_map(|resource| &resource.globals))
+    {
+        *declared.entry(global.name.to_ascii_uppercase()).or_default() += 1;
+    }
+    for mut resource in resources {
+        config_inits.extend(resource.config_inits);
+        if !scoped {
+            globals.extend(resource.globals);
+            tasks.extend(resource.tasks);
+            programs.extend(resource.programs);
+            access.extend(resource.access);
+            continue;
+        }
+        let mut model = ResourceModel {
+            name: resource.name.clone(),
+            globals: Vec::new(),
+            programs: resource
+                .programs
+                .iter()
+                .map(|program| program.name.clone())
+                .collect(),
+            access: resource.access,
+        };
+        for mut global in resource.globals {
+            let storage_name = if declared[&global.name.to_ascii_uppercase()] > 1 {
+                SmolStr::new(format!("{}.{}", resource.name, global.name))
+            } else {
+                global.name.clone()
+            };
+            model
+                .globals
+                .push((global.name.clone(), storage_name.clone()));
+            global.name = storage_name;
+            globals.push(global);
+        }
+        for task in &mut resource.tasks {
+            let Some(single) = task.single.as_mut() else {
+                continue;
+            };
+            if let Some((_, storage_name)) = model
+                .globals
+                .iter()
+                .find(|(name, _)| name.eq_ignore_ascii_case(single))
+            {
+                *single = storage_name.clone();
+            }
+        }
+        tasks.extend(resource.tasks);
+        programs.extend(resource.programs);
+        resource_models.push(model);
+    }
+
     Ok(Some(ConfigModel {
         globals,
         tasks,
@@ -81,5 +116,57 @@ pub(crate) fn lower_configuration(
         using: ctx.using.clone(),
         access,
         config_inits,
+        resources: resource_models,
     }))
 }
+
+struct LoweredResource {
+    name: SmolStr,
+    globals: Vec<GlobalInit>,
+    tasks: Vec<crate::task::TaskConfig>,
+    programs: Vec<ProgramInstanceConfig>,
+    access: Vec<AccessDecl>,
+    config_inits: Vec<ConfigInit>,
+}
+
+fn lower_resource(
+    resource: &SyntaxNode,
+    ctx: &mut LoweringContext<'_>,
+) -> Result<LoweredResource, CompileError> {
+    let name = resource
+        .children()
+        .find(|child| child.kind() == SyntaxKind::Name)
+        .map(|child| SmolStr::new(node_text(&child)))
+        .ok_or_else(|| CompileError::new("missing RESOURCE name"))?;
+    let mut lowered = LoweredResource {
+        name,
+        globals: Vec::new(),
+        tasks: Vec::new(),
+        programs: Vec::new(),
+        access: Vec::new(),
+        config_inits: Vec::new(),
+    };
+    for res_child in resource.children() {
+        match res_child.kind() {
+            SyntaxKind::VarBlock => lowered
+                .globals
+                .extend(lower_global_var_block(&res_child, ctx)?),
+            SyntaxKind::TaskConfig => lowered.tasks.push(lower_task_config(&res_child, ctx)?),
+            SyntaxKind::ProgramConfig => lowered
+                .programs
+                .push(lower_program_config(&res_child, ctx)?),
+            SyntaxKind::VarAccessBlock => {
+                let result = lower_var_access_block(&res_child, ctx)?;
+                lowered.globals.extend(result.globals);
+                lowered.access.extend(result.access);
+            }
+            SyntaxKind::VarConfigBlock => {
+                lowered
+                    .config_inits
+                    .extend(lower_var_config_block(&res_child, ctx)?);
+            }
+            _ => {}
+        }
+    }
+    Ok(lowered)
+}
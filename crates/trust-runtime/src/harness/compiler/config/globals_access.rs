@@ -113,7 +113,7 @@ fn lower_var_config_block(
         let type_id = lower_type_ref(&type_ref, ctx)?;
         let initializer = config_init
             .children()
-            .find(|child| is_expression_kind(child.kind()))
+            .find(|child| is_initializer_kind(child.kind()))
             .map(|expr| lower_expr(&expr, ctx))
             .transpose()?;
         let address = config_init_address(&config_init)?;
@@ -11,7 +11,7 @@ mod vars;
 pub(super) use config::{lower_configuration, resolve_program_type_name};
 pub(super) use model::{
     AccessDecl, AccessPart, AccessPath, ConfigInit, GlobalInit, LoweringContext,
-    ProgramInstanceConfig, ResolvedAccess, WildcardRequirement,
+    ProgramInstanceConfig, ResolvedAccess, ResourceModel, WildcardRequirement,
 };
 pub(super) use pou::{
     lower_classes, lower_function_blocks, lower_functions, lower_interfaces, lower_programs,
@@ -27,6 +27,16 @@ pub(crate) struct ConfigModel {
     pub(crate) using: Vec<SmolStr>,
     pub(crate) access: Vec<AccessDecl>,
     pub(crate) config_inits: Vec<ConfigInit>,
+    pub(crate) resources: Vec<ResourceModel>,
+}
+
+/// Per-resource scope, only populated when a CONFIGURATION has several RESOURCEs.
+pub(crate) struct ResourceModel {
+    pub(crate) name: SmolStr,
+    /// Declared name and storage name (`RESOURCE.name`) of each resource-local global.
+    pub(crate) globals: Vec<(SmolStr, SmolStr)>,
+    pub(crate) programs: Vec<SmolStr>,
+    pub(crate) access: Vec<AccessDecl>,
 }
 
 pub(crate) struct ProgramInstanceConfig {
@@ -53,7 +53,7 @@ fn lower_function_var_blocks(
                             type_id,
                             direction: ParamDirection::Out,
                             address: address_info.clone(),
-                            default: None,
+                            default: init_expr.clone(),
                         });
                     }
                 }
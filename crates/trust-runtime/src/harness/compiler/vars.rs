@@ -2,7 +2,7 @@ use smol_str::SmolStr;
 use trust_syntax::syntax::{SyntaxKind, SyntaxNode};
 
 use super::super::types::CompileError;
-use super::super::util::{is_expression_kind, node_text};
+use super::super::util::{is_initializer_kind, node_text};
 
 #[derive(Debug, Clone, Copy)]
 pub(super) enum VarBlockKind {
@@ -99,7 +99,7 @@ pub(super) fn parse_var_decl(
 
     let initializer = var_decl
         .children()
-        .find(|child| is_expression_kind(child.kind()));
+        .find(|child| is_initializer_kind(child.kind()));
 
     let mut address = None;
     let mut seen_at = false;
@@ -13,7 +13,7 @@ use super::io::{
 };
 use super::{
     AccessDecl, AccessPart, AccessPath, CompileError, ConfigInit, GlobalInit,
-    ProgramInstanceConfig, ResolvedAccess, WildcardRequirement,
+    ProgramInstanceConfig, ResolvedAccess, ResourceModel, WildcardRequirement,
 };
 
 include!("config/access_paths.rs");
@@ -16,9 +16,11 @@ pub(super) fn ensure_wildcards_resolved(
 pub(super) fn register_access_bindings(
     runtime: &mut Runtime,
     access_decls: &[AccessDecl],
+    resources: &[ResourceModel],
 ) -> Result<(), CompileError> {
     for decl in access_decls {
-        let resolved = resolve_access_path(runtime, &decl.path)?;
+        let path = scope_access_path(runtime, &decl.path, None, resources)?;
+        let resolved = resolve_access_path(runtime, &path)?;
         match resolved {
             ResolvedAccess::Variable { reference, partial } => {
                 runtime
@@ -34,3 +36,163 @@ pub(super) fn register_access_bindings(
     }
     Ok(())
 }
+
+/// Build one access map per RESOURCE and bind it to the resource's programs.
+///
+/// Each map starts from the configuration-level VAR_ACCESS bindings, maps the
+/// declared names of resource-local globals onto their `RESOURCE.name` storage
+/// slots, and adds the resource's own VAR_ACCESS paths.
+pub(super) fn register_resource_scopes(
+    runtime: &mut Runtime,
+    resources: &[ResourceModel],
+) -> Result<(), CompileError> {
+    for resource in resources {
+        let mut access = runtime.access_map().clone();
+        for (name, storage_name) in &resource.globals {
+            let reference = runtime
+                .storage()
+                .ref_for_global(storage_name.as_ref())
+                .ok_or_else(|| {
+                    CompileError::new(format!(
+                        "failed to resolve RESOURCE global '{storage_name}'"
+                    ))
+                })?;
+            access.bind(name.clone(), reference, None);
+        }
+        for decl in &resource.access {
+            let path = scope_access_path(runtime, &decl.path, Some(resource), resources)?;
+            match resolve_access_path(runtime, &path)? {
+                ResolvedAccess::Variable { reference, partial } => {
+                    access.bind(decl.name.clone(), reference, partial);
+                }
+                ResolvedAccess::Direct(_) => {
+                    return Err(CompileError::new(
+                        "VAR_ACCESS direct addresses must be declared as globals",
+                    ));
+                }
+            }
+        }
+        for program in &resource.programs {
+            runtime.set_program_access_map(program.clone(), access.clone());
+        }
+    }
+    Ok(())
+}
+
+/// Rewrite an access path so it only reaches variables visible from `current`.
+///
+/// A leading `RESOURCE.` segment selects that resource's globals and programs.
+/// Paths declared inside a resource may not name another resource, its
+/// globals, or its program instances.
+fn scope_access_path(
+    runtime: &Runtime,
+    path: &AccessPath,
+    current: Option<&ResourceModel>,
+    resources: &[ResourceModel],
+) -> Result<AccessPath, CompileError> {
+    let AccessPath::Parts(parts) = path else {
+        return Ok(path.clone());
+    };
+    if resources.is_empty() {
+        return Ok(path.clone());
+    }
+    let Some(AccessPart::Name(first)) = parts.first() else {
+        return Ok(path.clone());
+    };
+    let display = access_path_display(path);
+    let mut parts = parts.clone();
+    let mut owner = current;
+    if let Some(target) = resources
+        .iter()
+        .find(|resource| resource.name.eq_ignore_ascii_case(first))
+    {
+        if current.is_some_and(|current| !std::ptr::eq(current, target)) {
+            return Err(CompileError::new(format!(
+                "VAR_ACCESS path '{display}' crosses into RESOURCE '{}'",
+                target.name
+            )));
+        }
+        if !matches!(parts.get(1), Some(AccessPart::Name(_))) {
+            return Err(CompileError::new(format!(
+                "VAR_ACCESS path '{display}' must name a variable of RESOURCE '{}'",
+                target.name
+            )));
+        }
+        parts.remove(0);
+        owner = Some(target);
+    }
+    let Some(AccessPart::Name(head)) = parts.first().cloned() else {
+        return Ok(AccessPath::Parts(parts));
+    };
+    let Some(owner) = owner else {
+        return Ok(AccessPath::Parts(parts));
+    };
+    if let Some((_, storage_name)) = owner
+        .globals
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case(&head))
+    {
+        parts[0] = AccessPart::Name(storage_name.clone());
+        return Ok(AccessPath::Parts(parts));
+    }
+    if owner
+        .programs
+        .iter()
+        .any(|program| program.eq_ignore_ascii_case(&head))
+    {
+        return Ok(AccessPath::Parts(parts));
+    }
+    if let Some(other) = resources.iter().find(|resource| {
+        resource
+            .programs
+            .iter()
+            .any(|program| program.eq_ignore_ascii_case(&head))
+    }) {
+        return Err(CompileError::new(format!(
+            "VAR_ACCESS path '{display}' crosses into RESOURCE '{}'",
+            other.name
+        )));
+    }
+    if let Some(other) = resources.iter().find(|resource| {
+        resource
+            .globals
+            .iter()
+            .any(|(name, _)| name.eq_ignore_ascii_case(&head))
+    }) {
+        return Err(CompileError::new(format!(
+            "VAR_ACCESS path '{display}' crosses into RESOURCE '{}'",
+            other.name
+        )));
+    }
+    if runtime.storage().ref_for_global(head.as_ref()).is_some() {
+        return Ok(AccessPath::Parts(parts));
+    }
+    // A bare program variable only resolves against the owning resource's programs.
+    let mut matched = None;
+    for program in &owner.programs {
+        let Some(Value::Instance(id)) = runtime.storage().get_global(program.as_ref()) else {
+            continue;
+        };
+        if runtime
+            .storage()
+            .ref_for_instance(*id, head.as_ref())
+            .is_none()
+        {
+            continue;
+        }
+        if matched.is_some() {
+            return Err(CompileError::new(format!(
+                "ambiguous VAR_ACCESS path '{display}'"
+            )));
+        }
+        matched = Some(program.clone());
+    }
+    let program = matched.ok_or_else(|| {
+        CompileError::new(format!(
+            "VAR_ACCESS path '{display}' is not visible in RESOURCE '{}'",
+            owner.name
+        ))
+    })?;
+    parts.insert(0, AccessPart::Name(program));
+    Ok(AccessPath::Parts(parts))
+}
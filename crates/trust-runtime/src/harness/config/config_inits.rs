@@ -73,6 +73,7 @@ pub(super) fn apply_config_inits(
                 now,
                 debug: None,
                 call_depth: 0,
+                max_call_depth: crate::eval::DEFAULT_MAX_CALL_DEPTH,
                 functions: Some(&functions),
                 stdlib: Some(&stdlib),
                 function_blocks: Some(&function_blocks),
@@ -84,6 +85,7 @@ pub(super) fn apply_config_inits(
                 loop_depth: 0,
                 pause_requested: false,
                 execution_deadline: None,
+                fault_location: None,
             };
             let value = eval_expr(&mut ctx, expr)
                 .map_err(|err| CompileError::new(format!("VAR_CONFIG initializer error: {err}")))?;
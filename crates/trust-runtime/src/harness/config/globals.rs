@@ -17,6 +17,7 @@ pub(super) fn apply_globals(
             now,
             debug: None,
             call_depth: 0,
+            max_call_depth: crate::eval::DEFAULT_MAX_CALL_DEPTH,
             functions: Some(&functions),
             stdlib: Some(&stdlib),
             function_blocks: Some(&function_blocks),
@@ -28,6 +29,7 @@ pub(super) fn apply_globals(
             loop_depth: 0,
             pause_requested: false,
             execution_deadline: None,
+            fault_location: None,
         };
 
         for init in globals {
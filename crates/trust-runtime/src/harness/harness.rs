@@ -3,7 +3,7 @@
 #![allow(missing_docs)]
 
 use crate::error::RuntimeError;
-use crate::io::IoAddress;
+use crate::io::{IoAddress, IoLayout};
 use crate::memory::InstanceId;
 use crate::value::{Duration, Value};
 use crate::Runtime;
@@ -39,7 +39,12 @@ impl TestHarness {
 
     /// Creates a new test harness from source code.
     pub fn from_source(source: &str) -> Result<Self, CompileError> {
-        let runtime = CompileSession::from_source(source).build_runtime()?;
+        Self::from_session(&CompileSession::from_source(source))
+    }
+
+    /// Creates a new test harness from a configured compile session.
+    pub fn from_session(session: &CompileSession) -> Result<Self, CompileError> {
+        let runtime = session.build_runtime()?;
         Ok(Self {
             runtime,
             cycle_count: 0,
@@ -72,6 +77,29 @@ impl TestHarness {
         }
     }
 
+    /// Forces a variable to a value until [`TestHarness::release`].
+    pub fn force(&mut self, name: &str, value: impl Into<Value>) {
+        let value = value.into();
+        if self.runtime.storage().get_global(name).is_none() {
+            if let Some(instance_id) = self.find_program_var_instance(name) {
+                self.runtime.force_instance_var(instance_id, name, value);
+                return;
+            }
+        }
+        self.runtime.force_global(name, value);
+    }
+
+    /// Releases a forced variable.
+    pub fn release(&mut self, name: &str) {
+        if self.runtime.storage().get_global(name).is_none() {
+            if let Some(instance_id) = self.find_program_var_instance(name) {
+                self.runtime.release_instance_var(instance_id, name);
+                return;
+            }
+        }
+        self.runtime.release_global(name);
+    }
+
     /// Gets an output value.
     #[must_use]
     pub fn get_output(&self, name: &str) -> Option<Value> {
@@ -108,6 +136,12 @@ impl TestHarness {
         self.runtime.io().read(&addr)
     }
 
+    /// Returns the process-image layout of the `AT %...` bound variables.
+    #[must_use]
+    pub fn io_layout(&self) -> IoLayout {
+        self.runtime.io_layout()
+    }
+
     /// Binds a variable name to a direct address.
     pub fn bind_direct(&mut self, name: &str, address: &str) -> Result<(), RuntimeError> {
         let addr = IoAddress::parse(address)?;
@@ -4,6 +4,7 @@ fn collect_io_bindings(
     reference: crate::value::ValueRef,
     offset_bytes: u64,
     bit_offset: u8,
+    layout: crate::value::BoolArrayLayout,
     out: &mut Vec<IoLeafBinding>,
 ) -> Result<(), CompileError> {
     let ty = registry
@@ -11,22 +12,65 @@ fn collect_io_bindings(
         .ok_or_else(|| CompileError::new("unknown type for I/O binding"))?;
     match ty {
         Type::Alias { target, .. } => {
-            collect_io_bindings(registry, *target, reference, offset_bytes, bit_offset, out)
+            collect_io_bindings(
+                registry,
+                *target,
+                reference,
+                offset_bytes,
+                bit_offset,
+                layout,
+                out,
+            )
         }
         Type::Subrange { base, .. } => {
-            collect_io_bindings(registry, *base, reference, offset_bytes, bit_offset, out)
+            collect_io_bindings(
+                registry,
+                *base,
+                reference,
+                offset_bytes,
+                bit_offset,
+                layout,
+                out,
+            )
         }
         Type::Enum { base, .. } => {
-            collect_io_bindings(registry, *base, reference, offset_bytes, bit_offset, out)
+            collect_io_bindings(
+                registry,
+                *base,
+                reference,
+                offset_bytes,
+                bit_offset,
+                layout,
+                out,
+            )
         }
         Type::Array {
             element,
             dimensions,
         } => {
-            let element_size = type_size_bytes(*element, registry)?;
+            let dimensions: Vec<(i64, i64)> = dimensions
+                .iter()
+                .copied()
+                .collect::<Option<_>>()
+                .ok_or_else(|| CompileError::new("invalid array bounds for I/O binding"))?;
+            let dimensions = &dimensions;
+            if layout == crate::value::BoolArrayLayout::Packed
+                && crate::value::is_bool_type(*element, registry)
+            {
+                return collect_packed_bool_bindings(
+                    registry,
+                    *element,
+                    dimensions,
+                    &reference,
+                    offset_bytes,
+                    bit_offset,
+                    out,
+                );
+            }
+            let element_size = type_size_bytes(*element, registry, layout)?;
             let lengths: Vec<i64> = dimensions
                 .iter()
-                .map(|(lower, upper)| upper - lower + 1)
+                .map(|(lower, upper)| upper.saturating_sub(*lower).saturating_add(1))
                 .collect();
             if lengths.iter().any(|len| *len <= 0) {
                 return Err(CompileError::new("invalid array bounds for I/O binding"));
@@ -56,6 +100,7 @@ fn collect_io_bindings(
                 current_dim: usize,
                 indices: &mut Vec<i64>,
                 bit_offset: u8,
+                layout: crate::value::BoolArrayLayout,
                 out: &mut Vec<IoLeafBinding>,
             ) -> Result<(), CompileError> {
                 if current_dim == dimensions.len() {
@@ -69,6 +114,7 @@ fn collect_io_bindings(
                         ref_with_index,
                         offset_bytes,
                         bit_offset,
+                        layout,
                         out,
                     );
                 }
@@ -99,6 +145,7 @@ fn collect_io_bindings(
                         current_dim + 1,
                         indices,
                         bit_offset,
+                        layout,
                         out,
                     )?;
                     indices.pop();
@@ -118,6 +165,7 @@ fn collect_io_bindings(
                 0,
                 &mut indices,
                 bit_offset,
+                layout,
                 out,
             )
         }
@@ -156,9 +204,10 @@ fn collect_io_bindings(
                     field_ref,
                     field_offset,
                     field_bit_offset,
+                    layout,
                     out,
                 )?;
-                let field_size = type_size_bytes(field.type_id, registry)?;
+                let field_size = type_size_bytes(field.type_id, registry, layout)?;
                 let field_end = field_offset
                     .checked_add(field_size)
                     .ok_or_else(|| CompileError::new("struct offset overflow for I/O binding"))?;
@@ -203,6 +252,7 @@ fn collect_io_bindings(
                     variant_ref,
                     variant_offset,
                     variant_bit_offset,
+                    layout,
                     out,
                 )?;
             }
@@ -237,3 +287,54 @@ fn collect_io_bindings(
         }
     }
 }
+
+/// Binds each element of a packed `ARRAY OF BOOL` to consecutive bits, in
+/// row-major order, starting at the base bit.
+fn collect_packed_bool_bindings(
+    registry: &TypeRegistry,
+    element: TypeId,
+    dimensions: &[(i64, i64)],
+    reference: &crate::value::ValueRef,
+    offset_bytes: u64,
+    bit_offset: u8,
+    out: &mut Vec<IoLeafBinding>,
+) -> Result<(), CompileError> {
+    let mut tuples: Vec<Vec<i64>> = vec![Vec::new()];
+    for (lower, upper) in dimensions {
+        if upper < lower {
+            return Err(CompileError::new("invalid array bounds for I/O binding"));
+        }
+        tuples = tuples
+            .into_iter()
+            .flat_map(|prefix| {
+                (*lower..=*upper).map(move |index| {
+                    let mut next = prefix.clone();
+                    next.push(index);
+                    next
+                })
+            })
+            .collect();
+    }
+
+    let value_type = leaf_value_type(element, registry)?;
+    for (position, indices) in tuples.into_iter().enumerate() {
+        let position = u64::try_from(position)
+            .map_err(|_| CompileError::new("array offset overflow for I/O binding"))?;
+        let bit = u64::from(bit_offset) + position;
+        let element_offset = offset_bytes
+            .checked_add(bit / 8)
+            .ok_or_else(|| CompileError::new("array offset overflow for I/O binding"))?;
+        let mut element_ref = reference.clone();
+        element_ref
+            .path
+            .push(crate::value::RefSegment::Index(indices));
+        out.push(IoLeafBinding {
+            reference: element_ref,
+            offset_bytes: element_offset,
+            bit_offset: (bit % 8) as u8,
+            size: crate::io::IoSize::Bit,
+            value_type,
+        });
+    }
+    Ok(())
+}
@@ -1,5 +1,9 @@
-fn type_size_bytes(type_id: TypeId, registry: &TypeRegistry) -> Result<u64, CompileError> {
-    crate::value::size_of_type(type_id, registry)
+fn type_size_bytes(
+    type_id: TypeId,
+    registry: &TypeRegistry,
+    layout: crate::value::BoolArrayLayout,
+) -> Result<u64, CompileError> {
+    crate::value::size_of_type_with_layout(type_id, registry, layout)
         .map_err(|err| CompileError::new(format!("unsupported size for I/O binding: {err:?}")))
 }
 
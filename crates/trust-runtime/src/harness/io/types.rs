@@ -29,8 +29,15 @@ pub(super) fn bind_value_ref_to_address(
     address: &IoAddress,
     display_name: Option<SmolStr>,
 ) -> Result<(), CompileError> {
+    // BOOL arrays keep one byte per element unless packing was opted into, and
+    // packing only applies to bit addresses.
+    let layout = if matches!(address.size, crate::io::IoSize::Bit) {
+        io.bool_array_layout()
+    } else {
+        crate::value::BoolArrayLayout::Unpacked
+    };
     let mut bindings = Vec::new();
-    collect_io_bindings(registry, type_id, reference, 0, 0, &mut bindings)?;
+    collect_io_bindings(registry, type_id, reference, 0, 0, layout, &mut bindings)?;
     for binding in bindings {
         let target = offset_address(
             address,
@@ -41,6 +41,7 @@ pub(in crate::harness) fn const_int_from_node(
         now: Duration::ZERO,
         debug: None,
         call_depth: 0,
+        max_call_depth: crate::eval::DEFAULT_MAX_CALL_DEPTH,
         functions: None,
         stdlib: None,
         function_blocks: None,
@@ -52,6 +53,7 @@ pub(in crate::harness) fn const_int_from_node(
         loop_depth: 0,
         pause_requested: false,
         execution_deadline: None,
+        fault_location: None,
     };
     let value =
         eval_expr(&mut eval_ctx, &expr).map_err(|err| CompileError::new(err.to_string()))?;
@@ -90,6 +92,7 @@ pub(in crate::harness) fn const_duration_from_node(
         now: Duration::ZERO,
         debug: None,
         call_depth: 0,
+        max_call_depth: crate::eval::DEFAULT_MAX_CALL_DEPTH,
         functions: None,
         stdlib: None,
         function_blocks: None,
@@ -101,6 +104,7 @@ pub(in crate::harness) fn const_duration_from_node(
         loop_depth: 0,
         pause_requested: false,
         execution_deadline: None,
+        fault_location: None,
     };
     let value =
         eval_expr(&mut eval_ctx, &expr).map_err(|err| CompileError::new(err.to_string()))?;
@@ -112,6 +112,49 @@ fn lower_literal(node: &SyntaxNode, ctx: &LoweringContext<'_>) -> Result<Expr, C
     Ok(Expr::Literal(value))
 }
 
+/// Expands `__POUNAME` to the name of the enclosing POU and `__LINE` to the
+/// 1-based source line of the reference. Other names yield `None`.
+fn lower_compile_time_builtin(node: &SyntaxNode) -> Result<Option<Value>, CompileError> {
+    let name = node_text(node);
+    if name.eq_ignore_ascii_case("__POUNAME") {
+        let pou = node
+            .ancestors()
+            .find(|ancestor| {
+                matches!(
+                    ancestor.kind(),
+                    SyntaxKind::Program
+                        | SyntaxKind::Function
+                        | SyntaxKind::FunctionBlock
+                        | SyntaxKind::Method
+                        | SyntaxKind::Property
+                        | SyntaxKind::Action
+                )
+            })
+            .and_then(|pou| {
+                pou.children()
+                    .find(|child| child.kind() == SyntaxKind::Name)
+            })
+            .ok_or_else(|| CompileError::new("__POUNAME used outside of a POU"))?;
+        return Ok(Some(Value::String(SmolStr::new(node_text(&pou)))));
+    }
+    if name.eq_ignore_ascii_case("__LINE") {
+        let start = node
+            .descendants_with_tokens()
+            .find_map(|element| match element.into_token() {
+                Some(token) if !token.kind().is_trivia() => Some(token.text_range().start()),
+                _ => None,
+            })
+            .unwrap_or(node.text_range().start());
+        let root = node.ancestors().last().unwrap_or_else(|| node.clone());
+        let text = root.text().to_string();
+        let line = text[..usize::from(start)].matches('\n').count() + 1;
+        let line =
+            i16::try_from(line).map_err(|_| CompileError::new("__LINE out of range for INT"))?;
+        return Ok(Some(Value::Int(line)));
+    }
+    Ok(None)
+}
+
 fn parse_int_literal(text: &str) -> Result<i64, CompileError> {
     let cleaned: String = text.chars().filter(|c| *c != '_').collect();
     if let Some((base_str, digits)) = cleaned.split_once('#') {
@@ -147,8 +190,12 @@ fn parse_string_literal(text: &str, is_wide: bool) -> Result<String, CompileErro
     let end = bytes.len() - 1;
     while i < end {
         if bytes[i] != b'$' {
-            result.push(bytes[i] as char);
-            i += 1;
+            let ch = text[i..end]
+                .chars()
+                .next()
+                .ok_or_else(|| CompileError::new("invalid string literal"))?;
+            result.push(ch);
+            i += ch.len_utf8();
             continue;
         }
         if i + 1 >= end {
@@ -186,7 +233,11 @@ fn parse_string_literal(text: &str, is_wide: bool) -> Result<String, CompileErro
             }
             _ => {
                 let digits = if is_wide { 4 } else { 2 };
-                if i + 1 + digits > end {
+                if i + 1 + digits > end
+                    || !bytes[i + 1..i + 1 + digits]
+                        .iter()
+                        .all(|b| b.is_ascii_hexdigit())
+                {
                     return Err(CompileError::new("invalid escape sequence"));
                 }
                 let hex = &text[i + 1..i + 1 + digits];
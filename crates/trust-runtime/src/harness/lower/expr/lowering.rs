@@ -62,7 +62,10 @@ pub(in crate::harness) fn lower_expr(
 ) -> Result<Expr, CompileError> {
     match node.kind() {
         SyntaxKind::Literal => lower_literal(node, ctx),
-        SyntaxKind::NameRef => Ok(Expr::Name(node_text(node).into())),
+        SyntaxKind::NameRef => match lower_compile_time_builtin(node)? {
+            Some(value) => Ok(Expr::Literal(value)),
+            None => Ok(Expr::Name(node_text(node).into())),
+        },
         SyntaxKind::ThisExpr => Ok(Expr::This),
         SyntaxKind::SuperExpr => Ok(Expr::Super),
         SyntaxKind::UnaryExpr => {
@@ -122,6 +125,10 @@ pub(in crate::harness) fn lower_expr(
         SyntaxKind::DerefExpr => {
             let expr =
                 first_expr_child(node).ok_or_else(|| CompileError::new("missing deref target"))?;
+            // `THIS^` names the current instance, same as `THIS`.
+            if expr.kind() == SyntaxKind::ThisExpr {
+                return Ok(Expr::This);
+            }
             Ok(Expr::Deref(Box::new(lower_expr(&expr, ctx)?)))
         }
         SyntaxKind::AddrExpr => {
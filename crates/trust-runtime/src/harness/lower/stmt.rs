@@ -1,5 +1,5 @@
 use crate::debug::SourceLocation;
-use crate::eval::expr::Expr;
+use crate::eval::expr::{Expr, LValue};
 use crate::eval::stmt::{CaseLabel, Stmt};
 use crate::value::Value;
 use trust_syntax::syntax::{SyntaxKind, SyntaxNode};
@@ -66,6 +66,7 @@ fn lower_stmt(
         SyntaxKind::ForStmt => lower_for(node, ctx).map(Some),
         SyntaxKind::WhileStmt => lower_while(node, ctx).map(Some),
         SyntaxKind::RepeatStmt => lower_repeat(node, ctx).map(Some),
+        SyntaxKind::TryStmt => lower_try(node, ctx).map(Some),
         SyntaxKind::ReturnStmt => lower_return(node, ctx).map(Some),
         SyntaxKind::ExitStmt => Ok(Some(Stmt::Exit {
             location: stmt_location(node, ctx),
@@ -86,6 +87,9 @@ fn lower_assign(node: &SyntaxNode, ctx: &mut LoweringContext<'_>) -> Result<Stmt
         return Err(CompileError::new("invalid assignment"));
     }
     let target = lower_lvalue(&exprs[0], ctx)?;
+    if is_move_call(&exprs[1]) && !assignment_is_attempt(node) {
+        return lower_move_assign(node, target, &exprs[1], ctx);
+    }
     let value = lower_expr(&exprs[1], ctx)?;
     let location = stmt_location(node, ctx);
     if assignment_is_attempt(node) {
@@ -103,6 +107,78 @@ fn lower_assign(node: &SyntaxNode, ctx: &mut LoweringContext<'_>) -> Result<Stmt
     }
 }
 
+fn is_move_call(node: &SyntaxNode) -> bool {
+    node.kind() == SyntaxKind::CallExpr
+        && first_expr_child(node)
+            .is_some_and(|callee| node_text(&callee).eq_ignore_ascii_case("MOVE"))
+}
+
+/// Lowers `target := MOVE(...)` to a plain assignment of `IN`. With `EN` or
+/// `ENO` bound, the assignment only runs when `EN` is TRUE, so the target
+/// keeps its value otherwise, and `ENO` reports whether it ran.
+fn lower_move_assign(
+    node: &SyntaxNode,
+    target: LValue,
+    call: &SyntaxNode,
+    ctx: &mut LoweringContext<'_>,
+) -> Result<Stmt, CompileError> {
+    let mut input = None;
+    let mut enable = None;
+    let mut eno = None;
+    let args = call
+        .children()
+        .filter(|child| child.kind() == SyntaxKind::ArgList)
+        .flat_map(|arg_list| arg_list.children())
+        .filter(|child| child.kind() == SyntaxKind::Arg);
+    for arg in args {
+        let name = arg
+            .children()
+            .find(|child| child.kind() == SyntaxKind::Name)
+            .map(|name| node_text(&name).to_ascii_uppercase());
+        let expr =
+            first_expr_child(&arg).ok_or_else(|| CompileError::new("missing call argument"))?;
+        match name.as_deref() {
+            None | Some("IN") => input = Some(lower_expr(&expr, ctx)?),
+            Some("EN") => enable = Some(lower_expr(&expr, ctx)?),
+            Some("ENO") => eno = Some(lower_lvalue(&expr, ctx)?),
+            Some(other) => {
+                return Err(CompileError::new(format!(
+                    "unknown MOVE parameter '{other}'"
+                )))
+            }
+        }
+    }
+    let value = input.ok_or_else(|| CompileError::new("MOVE expects an IN argument"))?;
+    let location = stmt_location(node, ctx);
+    if enable.is_none() && eno.is_none() {
+        return Ok(Stmt::Assign {
+            target,
+            value,
+            location,
+        });
+    }
+
+    let set_eno = |value: bool| {
+        eno.clone().map(|target| Stmt::Assign {
+            target,
+            value: Expr::Literal(Value::Bool(value)),
+            location: None,
+        })
+    };
+    let assign = Stmt::Assign {
+        target,
+        value,
+        location: None,
+    };
+    Ok(Stmt::If {
+        condition: enable.unwrap_or(Expr::Literal(Value::Bool(true))),
+        then_block: std::iter::once(assign).chain(set_eno(true)).collect(),
+        else_if: Vec::new(),
+        else_block: set_eno(false).into_iter().collect(),
+        location,
+    })
+}
+
 fn assignment_is_attempt(node: &SyntaxNode) -> bool {
     node.children_with_tokens()
         .filter_map(|child| child.into_token())
@@ -338,6 +414,35 @@ fn lower_repeat(node: &SyntaxNode, ctx: &mut LoweringContext<'_>) -> Result<Stmt
     })
 }
 
+fn lower_try(node: &SyntaxNode, ctx: &mut LoweringContext<'_>) -> Result<Stmt, CompileError> {
+    let mut body = Vec::new();
+    let mut catch = None;
+    let mut finally_block = Vec::new();
+    for child in node.children() {
+        match child.kind() {
+            SyntaxKind::CatchClause => {
+                let target = first_expr_child(&child)
+                    .ok_or_else(|| CompileError::new("missing __CATCH target"))?;
+                let target = lower_lvalue(&target, ctx)?;
+                catch = Some((target, lower_else_block(&child, ctx)?));
+            }
+            SyntaxKind::FinallyClause => finally_block = lower_else_block(&child, ctx)?,
+            _ if is_statement_kind(child.kind()) => {
+                if let Some(stmt) = lower_stmt(&child, ctx)? {
+                    body.push(stmt);
+                }
+            }
+            _ => {}
+        }
+    }
+    Ok(Stmt::Try {
+        body,
+        catch,
+        finally_block,
+        location: stmt_location(node, ctx),
+    })
+}
+
 fn lower_label_stmt(
     node: &SyntaxNode,
     ctx: &mut LoweringContext<'_>,
@@ -34,6 +34,6 @@ use compiler::{
 };
 use compiler::{
     AccessDecl, AccessPart, AccessPath, ConfigInit, GlobalInit, ProgramInstanceConfig,
-    ResolvedAccess, WildcardRequirement,
+    ResolvedAccess, ResourceModel, WildcardRequirement,
 };
 use lower::lower_expr;
@@ -85,10 +85,30 @@ pub(super) fn builtin_type_name(kind: SyntaxKind) -> Option<&'static str> {
         SyntaxKind::KwWString => Some("WSTRING"),
         SyntaxKind::KwChar => Some("CHAR"),
         SyntaxKind::KwWChar => Some("WCHAR"),
+        SyntaxKind::KwAny => Some("ANY"),
+        SyntaxKind::KwAnyDerived => Some("ANY_DERIVED"),
+        SyntaxKind::KwAnyElementary => Some("ANY_ELEMENTARY"),
+        SyntaxKind::KwAnyMagnitude => Some("ANY_MAGNITUDE"),
+        SyntaxKind::KwAnyInt => Some("ANY_INT"),
+        SyntaxKind::KwAnyUnsigned => Some("ANY_UNSIGNED"),
+        SyntaxKind::KwAnySigned => Some("ANY_SIGNED"),
+        SyntaxKind::KwAnyReal => Some("ANY_REAL"),
+        SyntaxKind::KwAnyNum => Some("ANY_NUM"),
+        SyntaxKind::KwAnyDuration => Some("ANY_DURATION"),
+        SyntaxKind::KwAnyBit => Some("ANY_BIT"),
+        SyntaxKind::KwAnyChars => Some("ANY_CHARS"),
+        SyntaxKind::KwAnyString => Some("ANY_STRING"),
+        SyntaxKind::KwAnyChar => Some("ANY_CHAR"),
+        SyntaxKind::KwAnyDate => Some("ANY_DATE"),
         _ => None,
     }
 }
 
+/// Expressions plus the `[...]` array initializer a declaration may use.
+pub(super) fn is_initializer_kind(kind: SyntaxKind) -> bool {
+    kind == SyntaxKind::ArrayInitializer || is_expression_kind(kind)
+}
+
 pub(super) fn is_expression_kind(kind: SyntaxKind) -> bool {
     matches!(
         kind,
@@ -116,6 +136,7 @@ pub(super) fn is_statement_kind(kind: SyntaxKind) -> bool {
             | SyntaxKind::ForStmt
             | SyntaxKind::WhileStmt
             | SyntaxKind::RepeatStmt
+            | SyntaxKind::TryStmt
             | SyntaxKind::CaseStmt
             | SyntaxKind::ReturnStmt
             | SyntaxKind::ExprStmt
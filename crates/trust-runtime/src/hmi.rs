@@ -7,6 +7,7 @@ use std::fmt::Write as _;
 use std::path::Path;
 
 use indexmap::IndexMap;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use smol_str::SmolStr;
 use trust_hir::types::Type;
@@ -20,7 +21,7 @@ mod descriptor;
 mod scaffold;
 
 use descriptor::{apply_hmi_dir_descriptor, load_hmi_toml};
-pub use descriptor::{load_hmi_dir, write_hmi_dir_descriptor};
+pub use descriptor::{hmi_descriptor_json_schema, load_hmi_dir, write_hmi_dir_descriptor};
 use descriptor::{load_hmi_dir_impl, map_hmi_dir_page, render_hmi_dir_page_toml};
 #[cfg(test)]
 use scaffold::parse_hmi_annotation_payload;
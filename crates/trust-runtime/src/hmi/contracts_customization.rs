@@ -131,7 +131,8 @@ struct HmiTomlWrite {
     allow: Vec<String>,
 }
 
-#[derive(Debug, Default, Deserialize)]
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+#[schemars(rename = "config", deny_unknown_fields)]
 struct HmiDirConfigToml {
     version: Option<u32>,
     #[serde(default)]
@@ -144,7 +145,8 @@ struct HmiDirConfigToml {
     alarms: Vec<HmiDirAlarm>,
 }
 
-#[derive(Debug, Default, Deserialize)]
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+#[schemars(rename = "page", deny_unknown_fields)]
 struct HmiDirPageToml {
     title: Option<String>,
     icon: Option<String>,
@@ -162,7 +164,8 @@ struct HmiDirPageToml {
     bindings: Vec<HmiDirProcessBindingToml>,
 }
 
-#[derive(Debug, Default, Deserialize)]
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+#[schemars(rename = "section", deny_unknown_fields)]
 struct HmiDirSectionToml {
     title: Option<String>,
     span: Option<u32>,
@@ -171,7 +174,8 @@ struct HmiDirSectionToml {
     widgets: Vec<HmiDirWidgetToml>,
 }
 
-#[derive(Debug, Default, Deserialize)]
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+#[schemars(rename = "widget", deny_unknown_fields)]
 struct HmiDirWidgetToml {
     #[serde(rename = "type")]
     widget_type: Option<String>,
@@ -189,7 +193,8 @@ struct HmiDirWidgetToml {
     zones: Vec<HmiZoneSchema>,
 }
 
-#[derive(Debug, Default, Deserialize)]
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+#[schemars(rename = "processBinding", deny_unknown_fields)]
 struct HmiDirProcessBindingToml {
     selector: Option<String>,
     attribute: Option<String>,
@@ -200,7 +205,8 @@ struct HmiDirProcessBindingToml {
     scale: Option<HmiProcessScaleToml>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+#[schemars(rename = "processScale", deny_unknown_fields)]
 struct HmiProcessScaleToml {
     min: f64,
     max: f64,
@@ -17,27 +17,31 @@ pub struct HmiDirConfig {
     pub alarms: Vec<HmiDirAlarm>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[schemars(rename = "theme", deny_unknown_fields)]
 pub struct HmiDirTheme {
     pub style: Option<String>,
     pub accent: Option<String>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[schemars(rename = "layout", deny_unknown_fields)]
 pub struct HmiDirLayout {
     pub navigation: Option<String>,
     pub header: Option<bool>,
     pub header_title: Option<String>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[schemars(rename = "write", deny_unknown_fields)]
 pub struct HmiDirWrite {
     pub enabled: Option<bool>,
     #[serde(default)]
     pub allow: Vec<String>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[schemars(rename = "alarm", deny_unknown_fields)]
 pub struct HmiDirAlarm {
     pub bind: String,
     pub high: Option<f64>,
@@ -126,7 +126,8 @@ pub struct HmiProcessScaleSchema {
     pub output_max: f64,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, JsonSchema)]
+#[schemars(rename = "zone", deny_unknown_fields)]
 pub struct HmiZoneSchema {
     pub from: f64,
     pub to: f64,
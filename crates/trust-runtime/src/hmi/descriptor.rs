@@ -4,3 +4,4 @@ include!("descriptor_io.rs");
 include!("descriptor_render.rs");
 include!("descriptor_load_map.rs");
 include!("descriptor_apply.rs");
+include!("descriptor_schema.rs");
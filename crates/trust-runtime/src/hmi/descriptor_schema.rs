@@ -0,0 +1,18 @@
+/// JSON Schema (draft 2020-12) for the `hmi/` descriptor files.
+///
+/// `_config.toml` matches `#/$defs/config` and every page file matches
+/// `#/$defs/page`. The definitions are generated from the serde types the
+/// loader parses, so the schema accepts exactly the fields `load_hmi_dir` reads.
+pub fn hmi_descriptor_json_schema() -> serde_json::Value {
+    let mut generator = schemars::generate::SchemaSettings::draft2020_12().into_generator();
+    let config = generator.subschema_for::<HmiDirConfigToml>();
+    let page = generator.subschema_for::<HmiDirPageToml>();
+    let definitions = generator.take_definitions(true);
+    serde_json::json!({
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "title": "truST HMI descriptor",
+        "description": "Schema for hmi/_config.toml and hmi/<page>.toml descriptor files.",
+        "anyOf": [config, page],
+        "$defs": definitions,
+    })
+}
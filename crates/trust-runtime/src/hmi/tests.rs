@@ -1,3 +1,4 @@
 include!("tests/scaffold.rs");
 include!("tests/schema.rs");
 include!("tests/live_state.rs");
+include!("tests/descriptor_schema.rs");
@@ -0,0 +1,157 @@
+/// Validates `text` against one `$defs` entry of the descriptor schema and
+/// returns `instance path: message` for every violation.
+fn descriptor_errors(definition: &str, text: &str) -> Vec<String> {
+    let schema = hmi_descriptor_json_schema();
+    let target = serde_json::json!({
+        "$schema": schema["$schema"],
+        "$defs": schema["$defs"],
+        "$ref": format!("#/$defs/{definition}"),
+    });
+    let validator = jsonschema::validator_for(&target).expect("descriptor schema compiles");
+    let document: toml::Value = toml::from_str(text).expect("descriptor toml");
+    let instance = serde_json::to_value(document).expect("toml to json");
+    validator
+        .iter_errors(&instance)
+        .map(|error| format!("{}: {error}", error.instance_path()))
+        .collect()
+}
+
+fn matches_descriptor_schema(text: &str) -> bool {
+    let document: toml::Value = toml::from_str(text).expect("descriptor toml");
+    let instance = serde_json::to_value(document).expect("toml to json");
+    jsonschema::is_valid(&hmi_descriptor_json_schema(), &instance)
+}
+
+#[test]
+fn descriptor_schema_accepts_known_good_files() {
+    let config = r##"
+version = 1
+
+[theme]
+style = "industrial"
+accent = "#0ea5e9"
+
+[layout]
+navigation = "sidebar"
+header = true
+header_title = "Plant"
+
+[write]
+enabled = true
+allow = ["Main.setpoint"]
+
+[[alarm]]
+bind = "Main.flow"
+high = 110
+low = 5
+deadband = 1.5
+inferred = false
+label = "Flow"
+"##;
+    // `kind` is matched case-insensitively by the loader, so the schema must
+    // not restrict it to the lower-case spelling.
+    let page = r##"
+title = "Pumps"
+icon = "droplet"
+order = 20
+kind = "Process"
+duration_s = 600
+svg = "pumps.svg"
+hidden = false
+signals = ["Main.flow"]
+
+[[section]]
+title = "Station"
+span = 6
+tier = "primary"
+
+[[section.widget]]
+type = "gauge"
+bind = "Main.flow"
+label = "Flow"
+unit = "l/min"
+min = 0
+max = 120
+span = 4
+on_color = "#22c55e"
+off_color = "#64748b"
+inferred_interface = false
+detail_page = "trends"
+zones = [{ from = 0, to = 100, color = "#22c55e" }, { from = 100, to = 120, color = "#ef4444" }]
+
+[[bind]]
+selector = "#pump1"
+attribute = "Fill"
+source = "Main.running"
+format = "{}"
+map = { "true" = "#22c55e", "false" = "#64748b" }
+scale = { min = 0, max = 100, output_min = 0, output_max = 1 }
+"##;
+    assert_eq!(descriptor_errors("config", config), Vec::<String>::new());
+    assert_eq!(descriptor_errors("page", page), Vec::<String>::new());
+    assert!(matches_descriptor_schema(config));
+    assert!(matches_descriptor_schema(page));
+
+    // The loader must understand every field the schema advertises.
+    let parsed = toml::from_str::<HmiDirPageToml>(page).expect("page toml");
+    let page = map_hmi_dir_page("pumps".to_string(), 0, parsed);
+    assert_eq!(page.kind, "process");
+    assert_eq!(page.duration_ms, Some(600_000));
+    let widget = &page.sections[0].widgets[0];
+    assert_eq!(widget.widget_type.as_deref(), Some("gauge"));
+    assert_eq!(widget.detail_page.as_deref(), Some("trends"));
+    assert_eq!(widget.zones.len(), 2);
+    assert_eq!(page.bindings.len(), 1);
+    assert_eq!(page.bindings[0].attribute, "fill");
+    assert!(page.bindings[0].scale.is_some());
+}
+
+#[test]
+fn descriptor_schema_rejects_unknown_widget_field() {
+    let page = r##"
+title = "Overview"
+
+[[section]]
+title = "Main"
+
+[[section.widget]]
+bind = "Main.speed"
+colour = "#ff0000"
+"##;
+    let errors = descriptor_errors("page", page);
+    assert_eq!(errors.len(), 1, "unexpected errors: {errors:?}");
+    assert!(
+        errors[0].starts_with("/section/0/widget/0: ") && errors[0].contains("'colour'"),
+        "unexpected error: {}",
+        errors[0]
+    );
+    assert!(!matches_descriptor_schema(page));
+}
+
+#[test]
+fn descriptor_schema_requires_what_the_loader_requires() {
+    // Widgets without `bind` parse and are skipped by the loader.
+    let page = r#"
+[[section]]
+
+[[section.widget]]
+label = "Spare"
+"#;
+    assert_eq!(descriptor_errors("page", page), Vec::<String>::new());
+    let parsed = toml::from_str::<HmiDirPageToml>(page).expect("page toml");
+    assert!(map_hmi_dir_page("spare".to_string(), 0, parsed).sections[0]
+        .widgets
+        .is_empty());
+
+    // Alarms cannot be parsed without `bind`.
+    let config = r#"
+[[alarm]]
+high = 10
+"#;
+    let errors = descriptor_errors("config", config);
+    assert!(
+        errors.iter().any(|error| error.contains("\"bind\"")),
+        "unexpected errors: {errors:?}"
+    );
+    assert!(toml::from_str::<HmiDirConfigToml>(config).is_err());
+}
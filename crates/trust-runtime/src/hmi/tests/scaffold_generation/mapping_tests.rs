@@ -22,7 +22,7 @@ fn widget_mapping_covers_required_type_buckets() {
         widget_for_type(
             &Type::Array {
                 element: trust_hir::TypeId::INT,
-                dimensions: vec![(1, 4)],
+                dimensions: vec![Some((1, 4))],
             },
             false,
         ),
@@ -83,6 +83,7 @@ pub fn create_fb_instance(
         functions,
         stdlib,
         instance_id,
+        &fb.params,
         &fb.vars,
         &fb.using,
     )?;
@@ -112,6 +113,7 @@ pub fn create_program_instance(
         functions,
         stdlib,
         instance_id,
+        &[],
         &program.vars,
         &program.using,
     )?;
@@ -163,6 +165,7 @@ pub fn create_class_instance(
         functions,
         stdlib,
         instance_id,
+        &[],
         &class_def.vars,
         &class_def.using,
     )?;
@@ -194,6 +197,7 @@ fn init_var_defaults(
     functions: &IndexMap<SmolStr, FunctionDef>,
     stdlib: &StandardLibrary,
     instance_id: InstanceId,
+    params: &[Param],
     vars: &[VarDef],
     using: &[SmolStr],
 ) -> Result<(), RuntimeError> {
@@ -246,6 +250,7 @@ fn init_var_defaults(
         now: Duration::ZERO,
         debug: None,
         call_depth: 0,
+        max_call_depth: crate::eval::DEFAULT_MAX_CALL_DEPTH,
         functions: Some(functions),
         stdlib: Some(stdlib),
         function_blocks: Some(function_blocks),
@@ -257,7 +262,18 @@ fn init_var_defaults(
         loop_depth: 0,
         pause_requested: false,
         execution_deadline: None,
+        fault_location: None,
     };
+    for param in params {
+        let Some(expr) = &param.default else {
+            continue;
+        };
+        let value = eval_expr(&mut ctx, expr)?;
+        let value = crate::harness::coerce_value_to_type(value, param.type_id)
+            .map_err(|_| RuntimeError::TypeMismatch)?;
+        ctx.storage
+            .set_instance_var(instance_id, param.name.clone(), value);
+    }
     for var in vars {
         if function_block_type_name(var.type_id, registry).is_some() {
             if var.initializer.is_some() {
@@ -22,6 +22,7 @@ use crate::memory::IoArea;
 use crate::memory::VariableStorage;
 use crate::value::Value;
 use crate::value::ValueRef;
+use trust_hir::types::TypeRegistry;
 use trust_hir::TypeId;
 
 include!("io/driver.rs");
@@ -108,6 +108,37 @@ impl IoAddress {
     }
 }
 
+impl std::fmt::Display for IoAddress {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let area = match self.area {
+            IoArea::Input => 'I',
+            IoArea::Output => 'Q',
+            IoArea::Memory => 'M',
+        };
+        let size = match self.size {
+            IoSize::Bit => 'X',
+            IoSize::Byte => 'B',
+            IoSize::Word => 'W',
+            IoSize::DWord => 'D',
+            IoSize::LWord => 'L',
+        };
+        write!(f, "%{area}{size}")?;
+        if self.wildcard {
+            return write!(f, "*");
+        }
+        for (index, part) in self.path.iter().enumerate() {
+            if index > 0 {
+                write!(f, ".")?;
+            }
+            write!(f, "{part}")?;
+        }
+        if self.size == IoSize::Bit {
+            write!(f, ".{}", self.bit)?;
+        }
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum IoTarget {
     Name(SmolStr),
@@ -143,6 +174,22 @@ pub struct IoSnapshot {
     pub memory: Vec<IoSnapshotEntry>,
 }
 
+/// A variable bound into the process image.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IoLayoutEntry {
+    pub address: IoAddress,
+    pub name: Option<SmolStr>,
+    pub type_name: SmolStr,
+}
+
+/// Process-image layout derived from the `AT %...` bindings, sorted by address.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IoLayout {
+    pub inputs: Vec<IoLayoutEntry>,
+    pub outputs: Vec<IoLayoutEntry>,
+    pub memory: Vec<IoLayoutEntry>,
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct IoSafeState {
     pub outputs: Vec<(IoAddress, Value)>,
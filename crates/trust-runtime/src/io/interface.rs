@@ -5,6 +5,7 @@ pub struct IoInterface {
     memory: Vec<u8>,
     bindings: Vec<IoBinding>,
     hierarchical: std::collections::HashMap<IoAddressKey, Value>,
+    bool_array_layout: crate::value::BoolArrayLayout,
 }
 
 impl IoInterface {
@@ -18,6 +19,18 @@ impl IoInterface {
         &self.bindings
     }
 
+    /// Layout used when binding `ARRAY OF BOOL` to a bit address.
+    #[must_use]
+    pub fn bool_array_layout(&self) -> crate::value::BoolArrayLayout {
+        self.bool_array_layout
+    }
+
+    /// Opt in to (or out of) packing bit-addressed BOOL arrays one bit per
+    /// element. Only affects bindings made after the call.
+    pub fn set_bool_array_layout(&mut self, layout: crate::value::BoolArrayLayout) {
+        self.bool_array_layout = layout;
+    }
+
     /// Resize the process image buffers.
     pub fn resize(&mut self, inputs: usize, outputs: usize, memory: usize) {
         self.inputs.resize(inputs, 0);
@@ -91,6 +104,55 @@ impl IoInterface {
         snapshot
     }
 
+    /// Returns the process-image layout of all bindings, each area sorted by address.
+    ///
+    /// Wildcard (`%I*`) bindings appear with the address assigned in
+    /// `VAR_CONFIG`; bindings without a declared type report the elementary
+    /// type of their address size.
+    #[must_use]
+    pub fn layout(&self, registry: &TypeRegistry) -> IoLayout {
+        let mut layout = IoLayout::default();
+        for binding in &self.bindings {
+            let name = binding
+                .display_name
+                .clone()
+                .or_else(|| match &binding.target {
+                    IoTarget::Name(name) => Some(name.clone()),
+                    IoTarget::Reference(_) => None,
+                });
+            let type_name = binding
+                .value_type
+                .and_then(|type_id| registry.type_name(type_id))
+                .unwrap_or_else(|| {
+                    SmolStr::new_static(match binding.address.size {
+                        IoSize::Bit => "BOOL",
+                        IoSize::Byte => "BYTE",
+                        IoSize::Word => "WORD",
+                        IoSize::DWord => "DWORD",
+                        IoSize::LWord => "LWORD",
+                    })
+                });
+            let entry = IoLayoutEntry {
+                address: binding.address.clone(),
+                name,
+                type_name,
+            };
+            match binding.address.area {
+                IoArea::Input => layout.inputs.push(entry),
+                IoArea::Output => layout.outputs.push(entry),
+                IoArea::Memory => layout.memory.push(entry),
+            }
+        }
+        for entries in [&mut layout.inputs, &mut layout.outputs, &mut layout.memory] {
+            entries.sort_by(|a, b| {
+                (a.address.wildcard, &a.address.path, a.address.bit)
+                    .cmp(&(b.address.wildcard, &b.address.path, b.address.bit))
+                    .then_with(|| a.name.cmp(&b.name))
+            });
+        }
+        layout
+    }
+
     pub fn bind(&mut self, name: impl Into<SmolStr>, address: IoAddress) {
         let name = name.into();
         self.bindings.push(IoBinding {
@@ -55,6 +55,8 @@ pub mod registry;
 pub mod retain;
 /// Runtime cloud contract and UI projection helpers.
 pub mod runtime_cloud;
+/// SARIF export of project diagnostics for code-scanning tools.
+pub mod sarif;
 /// Resource scheduling helpers and clocks.
 pub mod scheduler;
 /// Security roles and authorization helpers.
@@ -81,4 +83,7 @@ pub mod web;
 mod runtime;
 
 pub(crate) use runtime::types::GlobalInitValue;
-pub use runtime::{RestartMode, RetainPolicy, RetainSnapshot, Runtime, RuntimeMetadata};
+pub use runtime::{
+    RestartMode, RetainPolicy, RetainSnapshot, Runtime, RuntimeMetadata, SubscriptionId,
+    VariableChange,
+};
@@ -3,7 +3,7 @@
 #![allow(missing_docs)]
 
 use indexmap::IndexMap;
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
 use smol_str::SmolStr;
 
 use crate::value::{PartialAccess, RefSegment, Value, ValueRef};
@@ -69,6 +69,8 @@ pub struct VariableStorage {
     retain: IndexMap<SmolStr, Value>,
     next_frame_id: u32,
     next_instance_id: u32,
+    /// Local slots not written since allocation; `None` when tracking is off.
+    uninitialized: Option<FxHashSet<(FrameId, usize)>>,
 }
 
 impl VariableStorage {
@@ -136,7 +138,11 @@ impl VariableStorage {
     }
 
     pub fn pop_frame(&mut self) -> Option<LocalFrame> {
-        self.frames.pop()
+        let frame = self.frames.pop()?;
+        if let Some(uninitialized) = &mut self.uninitialized {
+            uninitialized.retain(|(frame_id, _)| *frame_id != frame.id);
+        }
+        Some(frame)
     }
 
     #[must_use]
@@ -154,14 +160,52 @@ impl VariableStorage {
     }
 
     pub fn set_local(&mut self, name: impl Into<SmolStr>, value: Value) -> bool {
-        if let Some(frame) = self.current_frame_mut() {
-            frame.variables.insert(name.into(), value);
+        if let Some(frame) = self.frames.last_mut() {
+            let (offset, _) = frame.variables.insert_full(name.into(), value);
+            if let Some(uninitialized) = &mut self.uninitialized {
+                uninitialized.remove(&(frame.id, offset));
+            }
             true
         } else {
             false
         }
     }
 
+    /// Enables or disables tracking of locals that are read before being written.
+    pub fn set_track_uninitialized(&mut self, enabled: bool) {
+        self.uninitialized = enabled.then(FxHashSet::default);
+    }
+
+    #[must_use]
+    pub fn tracks_uninitialized(&self) -> bool {
+        self.uninitialized.is_some()
+    }
+
+    /// Marks a local of the current frame as not yet written (no-op unless tracking).
+    pub fn mark_local_uninitialized(&mut self, name: &str) {
+        let Some(slot) = self.local_slot(name) else {
+            return;
+        };
+        if let Some(uninitialized) = &mut self.uninitialized {
+            uninitialized.insert(slot);
+        }
+    }
+
+    /// Returns true the first time a local of the current frame is read before any write.
+    pub fn take_uninitialized_local(&mut self, name: &str) -> bool {
+        let Some(slot) = self.local_slot(name) else {
+            return false;
+        };
+        self.uninitialized
+            .as_mut()
+            .is_some_and(|uninitialized| uninitialized.remove(&slot))
+    }
+
+    fn local_slot(&self, name: &str) -> Option<(FrameId, usize)> {
+        let frame = self.current_frame()?;
+        Some((frame.id, frame.variables.get_index_of(name)?))
+    }
+
     #[must_use]
     pub fn get_local(&self, name: &str) -> Option<&Value> {
         self.current_frame()
@@ -177,6 +221,9 @@ impl VariableStorage {
     pub fn clear_frames(&mut self) {
         self.frames.clear();
         self.next_frame_id = 0;
+        if let Some(uninitialized) = &mut self.uninitialized {
+            uninitialized.clear();
+        }
     }
 
     /// Temporarily treat the provided frame as the current frame.
@@ -212,6 +259,11 @@ impl VariableStorage {
         id
     }
 
+    /// Drop an instance; values still referring to `id` become dangling.
+    pub fn remove_instance(&mut self, id: InstanceId) -> Option<InstanceData> {
+        self.instances.remove(&id)
+    }
+
     #[must_use]
     pub fn get_instance(&self, id: InstanceId) -> Option<&InstanceData> {
         self.instances.get(&id)
@@ -324,18 +376,22 @@ impl VariableStorage {
                 };
                 write_by_ref_path(slot, &value_ref.path, value)
             }
-            MemoryLocation::Local(frame_id) => self
-                .frames
-                .iter_mut()
-                .find(|frame| frame.id == frame_id)
-                .and_then(|frame| {
-                    frame
-                        .variables
-                        .get_index_mut(value_ref.offset)
-                        .map(|(_, v)| v)
-                })
-                .map(|slot| write_by_ref_path(slot, &value_ref.path, value))
-                .unwrap_or(false),
+            MemoryLocation::Local(frame_id) => {
+                if let Some(uninitialized) = &mut self.uninitialized {
+                    uninitialized.remove(&(frame_id, value_ref.offset));
+                }
+                self.frames
+                    .iter_mut()
+                    .find(|frame| frame.id == frame_id)
+                    .and_then(|frame| {
+                        frame
+                            .variables
+                            .get_index_mut(value_ref.offset)
+                            .map(|(_, v)| v)
+                    })
+                    .map(|slot| write_by_ref_path(slot, &value_ref.path, value))
+                    .unwrap_or(false)
+            }
             MemoryLocation::Instance(instance_id) => self
                 .instances
                 .get_mut(&instance_id)
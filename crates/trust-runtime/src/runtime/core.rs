@@ -23,6 +23,7 @@ use super::faults::FaultSubsystem;
 use super::io_subsystem::IoSubsystem;
 use super::metadata::{resolve_using_for_frame, RuntimeMetadata};
 use super::metrics_subsystem::MetricsSubsystem;
+use super::subscriptions::ChangeSubscriptions;
 use super::types::{GlobalInitValue, GlobalVarMeta, RetainPolicy};
 use super::watchdog_subsystem::WatchdogSubsystem;
 
@@ -33,6 +34,7 @@ pub struct Runtime {
     pub(super) registry: TypeRegistry,
     pub(super) io: IoSubsystem,
     pub(super) access: AccessMap,
+    pub(super) resource_access: IndexMap<SmolStr, AccessMap>,
     pub(super) stdlib: StandardLibrary,
     pub(super) debug: Option<DebugControl>,
     pub(super) statement_index: IndexMap<u32, Vec<crate::debug::SourceLocation>>,
@@ -53,7 +55,9 @@ pub struct Runtime {
     pub(super) metrics: MetricsSubsystem,
     pub(super) watchdog: WatchdogSubsystem,
     pub(super) faults: FaultSubsystem,
+    pub(super) subscriptions: ChangeSubscriptions,
     pub(super) execution_deadline: Option<std::time::Instant>,
+    pub(super) max_call_depth: u32,
 }
 
 impl std::fmt::Debug for Runtime {
@@ -64,6 +68,7 @@ impl std::fmt::Debug for Runtime {
             .field("registry", &self.registry)
             .field("io", &"<io>")
             .field("access", &self.access)
+            .field("resource_access", &self.resource_access)
             .field("stdlib", &self.stdlib)
             .field("debug", &self.debug.is_some())
             .field("statement_index", &self.statement_index)
@@ -107,6 +107,12 @@ impl Runtime {
         self.io.interface()
     }
 
+    /// Returns the input/output process-image layout of the bound variables.
+    #[must_use]
+    pub fn io_layout(&self) -> crate::io::IoLayout {
+        self.io().layout(&self.registry)
+    }
+
     /// Mutable access to the I/O interface.
     pub fn io_mut(&mut self) -> &mut IoInterface {
         self.io.interface_mut()
@@ -151,6 +157,20 @@ impl Runtime {
         &mut self.access
     }
 
+    /// Returns the access map used while executing a program instance.
+    ///
+    /// Programs configured inside a RESOURCE see that resource's globals and
+    /// VAR_ACCESS paths; other programs use the configuration-level map.
+    #[must_use]
+    pub fn program_access_map(&self, program: &str) -> &AccessMap {
+        self.resource_access.get(program).unwrap_or(&self.access)
+    }
+
+    /// Bind a resource-scoped access map to a program instance.
+    pub(crate) fn set_program_access_map(&mut self, program: SmolStr, access: AccessMap) {
+        self.resource_access.insert(program, access);
+    }
+
     /// Resolve USING directives for the given frame id.
     #[must_use]
     pub fn using_for_frame(&self, frame_id: FrameId) -> Option<Vec<SmolStr>> {
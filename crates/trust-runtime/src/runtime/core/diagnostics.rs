@@ -47,4 +47,52 @@ impl Runtime {
         Some((location, resolved_line, resolved_col))
     }
 
+    /// Force a global variable to `value` until [`Runtime::release_global`].
+    ///
+    /// The value takes effect immediately and is re-applied at the start of
+    /// every cycle, after each task and before outputs are published, so
+    /// program writes never reach observers or the I/O image. Enables debugging
+    /// if no control handle is set.
+    pub fn force_global(&mut self, name: impl Into<SmolStr>, value: Value) {
+        let name = name.into();
+        self.force_control().force_global(name.clone(), value.clone());
+        self.storage.set_global(name, value);
+    }
+
+    /// Force a variable of a program or function block instance.
+    ///
+    /// See [`Runtime::force_global`] for when the value is applied.
+    pub fn force_instance_var(
+        &mut self,
+        instance_id: InstanceId,
+        name: impl Into<SmolStr>,
+        value: Value,
+    ) {
+        let name = name.into();
+        self.force_control()
+            .force_instance(instance_id, name.clone(), value.clone());
+        self.storage.set_instance_var(instance_id, name, value);
+    }
+
+    /// Release a forced global variable; the program owns it again from the
+    /// next write on.
+    pub fn release_global(&mut self, name: &str) {
+        if let Some(debug) = &self.debug {
+            debug.release_global(name);
+        }
+    }
+
+    /// Release a forced instance variable.
+    pub fn release_instance_var(&mut self, instance_id: InstanceId, name: &str) {
+        if let Some(debug) = &self.debug {
+            debug.release_instance(instance_id, name);
+        }
+    }
+
+    fn force_control(&mut self) -> DebugControl {
+        match &self.debug {
+            Some(debug) => debug.clone(),
+            None => self.enable_debug(),
+        }
+    }
 }
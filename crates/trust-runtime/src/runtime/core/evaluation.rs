@@ -14,6 +14,7 @@ impl Runtime {
         let classes = &self.classes;
         let access = &self.access;
         let execution_deadline = self.execution_deadline;
+        let max_call_depth = self.max_call_depth;
         let eval = |storage: &mut VariableStorage, instance_id: Option<InstanceId>| {
             let mut ctx = EvalContext {
                 storage,
@@ -22,6 +23,7 @@ impl Runtime {
                 now,
                 debug: None,
                 call_depth: 0,
+                max_call_depth,
                 functions: Some(functions),
                 stdlib: Some(stdlib),
                 function_blocks: Some(function_blocks),
@@ -33,6 +35,7 @@ impl Runtime {
                 loop_depth: 0,
                 pause_requested: false,
                 execution_deadline,
+                fault_location: None,
             };
             eval::eval_expr(&mut ctx, expr)
         };
@@ -65,6 +68,7 @@ impl Runtime {
         let classes = &self.classes;
         let access = &self.access;
         let execution_deadline = self.execution_deadline;
+        let max_call_depth = self.max_call_depth;
         let eval = |storage: &mut VariableStorage, instance_id: Option<InstanceId>| {
             let mut ctx = EvalContext {
                 storage,
@@ -73,6 +77,7 @@ impl Runtime {
                 now,
                 debug: None,
                 call_depth: 0,
+                max_call_depth,
                 functions: Some(functions),
                 stdlib: Some(stdlib),
                 function_blocks: Some(function_blocks),
@@ -84,6 +89,7 @@ impl Runtime {
                 loop_depth: 0,
                 pause_requested: false,
                 execution_deadline,
+                fault_location: None,
             };
             f(&mut ctx)
         };
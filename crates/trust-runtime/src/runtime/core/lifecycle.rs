@@ -8,6 +8,7 @@ impl Runtime {
             registry: TypeRegistry::new(),
             io: IoSubsystem::new(),
             access: AccessMap::default(),
+            resource_access: IndexMap::new(),
             stdlib: StandardLibrary::new(),
             debug: None,
             statement_index: IndexMap::new(),
@@ -28,7 +29,9 @@ impl Runtime {
             metrics: MetricsSubsystem::new(),
             watchdog: WatchdogSubsystem::new(),
             faults: FaultSubsystem::new(),
+            subscriptions: ChangeSubscriptions::default(),
             execution_deadline: None,
+            max_call_depth: crate::eval::DEFAULT_MAX_CALL_DEPTH,
         };
         runtime.register_builtin_function_blocks();
         runtime
@@ -53,6 +56,15 @@ impl Runtime {
         self.debug = Some(control);
     }
 
+    /// Report reads of never-written locals through the debug hook.
+    ///
+    /// Locals declared without an initializer are tagged when their frame is
+    /// allocated; the first read before any write emits
+    /// [`RuntimeEvent::UninitializedRead`](crate::debug::RuntimeEvent::UninitializedRead).
+    pub fn set_uninitialized_read_checks(&mut self, enabled: bool) {
+        self.storage.set_track_uninitialized(enabled);
+    }
+
     /// Snapshot static metadata for external tooling.
     #[must_use]
     pub fn metadata_snapshot(&self) -> RuntimeMetadata {
@@ -130,6 +142,19 @@ impl Runtime {
         self.execution_deadline
     }
 
+    /// Set the maximum nesting of function, method and function block calls.
+    ///
+    /// A call beyond the limit fails with [`crate::error::RuntimeError::CallDepthExceeded`].
+    pub fn set_max_call_depth(&mut self, depth: u32) {
+        self.max_call_depth = depth;
+    }
+
+    /// Get the maximum call nesting depth.
+    #[must_use]
+    pub fn max_call_depth(&self) -> u32 {
+        self.max_call_depth
+    }
+
     /// Update configured safe-state outputs.
     pub fn set_io_safe_state(&mut self, safe_state: IoSafeState) {
         self.io.set_safe_state(safe_state);
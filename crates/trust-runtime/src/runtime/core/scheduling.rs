@@ -107,6 +107,12 @@ impl Runtime {
         self.faults.last_fault()
     }
 
+    /// Get the source location of the statement that raised the last fault, if known.
+    #[must_use]
+    pub fn last_fault_location(&self) -> Option<crate::debug::SourceLocation> {
+        self.faults.last_fault_location()
+    }
+
     /// Clear the faulted state (used by tests and tooling).
     pub fn clear_fault(&mut self) {
         self.faults.clear();
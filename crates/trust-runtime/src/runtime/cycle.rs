@@ -74,6 +74,11 @@ impl Runtime {
             if let Err(err) = self.execute_task(&task) {
                 return Err(self.record_fault(err));
             }
+            if let Some(debug) = self.debug.clone() {
+                if let Err(err) = self.apply_forced_values(&debug) {
+                    return Err(self.record_fault(err));
+                }
+            }
             if let Some(start) = task_timer {
                 self.metrics.record_task(&task.name, start.elapsed());
             }
@@ -99,6 +104,9 @@ impl Runtime {
                 time: self.current_time,
             });
         }
+        if !self.subscriptions.is_empty() {
+            self.subscriptions.collect(&self.storage);
+        }
         if let Some(start) = cycle_timer {
             self.metrics.record_cycle(start.elapsed());
         }
@@ -132,6 +140,7 @@ impl Runtime {
 
     /// Execute a program body in the runtime context.
     pub fn execute_program(&mut self, program: &ProgramDef) -> Result<(), error::RuntimeError> {
+        self.faults.set_pending_location(None);
         let mut debug = self.debug.take();
         let instance_id = match self.storage.get_global(program.name.as_ref()) {
             Some(Value::Instance(id)) => Some(*id),
@@ -146,17 +155,23 @@ impl Runtime {
                 .as_mut()
                 .map(|hook| hook as &mut dyn crate::debug::DebugHook),
             call_depth: 0,
+            max_call_depth: self.max_call_depth,
             functions: Some(&self.functions),
             stdlib: Some(&self.stdlib),
             function_blocks: Some(&self.function_blocks),
             classes: Some(&self.classes),
             using: Some(&program.using),
-            access: Some(&self.access),
+            access: Some(
+                self.resource_access
+                    .get(program.name.as_str())
+                    .unwrap_or(&self.access),
+            ),
             current_instance: instance_id,
             return_name: None,
             loop_depth: 0,
             pause_requested: false,
             execution_deadline: self.execution_deadline,
+            fault_location: None,
         };
         let mut has_frame = false;
         if instance_id.is_some() || !program.temps.is_empty() {
@@ -181,7 +196,9 @@ impl Runtime {
                 if has_frame {
                     ctx.storage.pop_frame();
                 }
+                let location = ctx.fault_location;
                 self.debug = debug;
+                self.faults.set_pending_location(location);
                 return Err(err);
             }
         };
@@ -346,6 +363,7 @@ impl Runtime {
                 .as_mut()
                 .map(|hook| hook as &mut dyn crate::debug::DebugHook),
             call_depth: 0,
+            max_call_depth: self.max_call_depth,
             functions: Some(&self.functions),
             stdlib: Some(&self.stdlib),
             function_blocks: Some(&self.function_blocks),
@@ -357,6 +375,7 @@ impl Runtime {
             loop_depth: 0,
             pause_requested: false,
             execution_deadline: self.execution_deadline,
+            fault_location: None,
         };
         ctx.storage
             .push_frame_with_instance(fb.name.clone(), instance_id);
@@ -387,7 +406,11 @@ impl Runtime {
         };
 
         ctx.storage.pop_frame();
+        let location = ctx.fault_location;
         self.debug = debug;
+        if result.is_err() {
+            self.faults.set_pending_location(location);
+        }
         if let Some(start) = timer {
             self.metrics
                 .record_profile_call("fb", &fb.name, start.elapsed());
@@ -416,9 +439,15 @@ impl Runtime {
     }
 
     fn write_cycle_outputs(&mut self) -> Result<(), error::RuntimeError> {
+        // Forced variables must reach the output image, and forced addresses
+        // must override it afterwards.
+        let debug = self.debug.clone();
+        if let Some(debug) = &debug {
+            self.apply_forced_values(debug)?;
+        }
         self.io.interface_mut().write_outputs(&self.storage)?;
-        if let Some(debug) = self.debug.clone() {
-            self.apply_forced_values(&debug)?;
+        if let Some(debug) = &debug {
+            self.apply_forced_values(debug)?;
         }
         #[cfg(feature = "debug")]
         self.emit_io_snapshot();
@@ -1,5 +1,6 @@
 //! Fault state management.
 
+use crate::debug::SourceLocation;
 use crate::error::RuntimeError;
 use crate::watchdog::{FaultDecision, FaultPolicy};
 
@@ -7,6 +8,8 @@ pub(super) struct FaultSubsystem {
     policy: FaultPolicy,
     faulted: bool,
     last_fault: Option<RuntimeError>,
+    last_fault_location: Option<SourceLocation>,
+    pending_location: Option<SourceLocation>,
 }
 
 impl FaultSubsystem {
@@ -15,6 +18,8 @@ impl FaultSubsystem {
             policy: FaultPolicy::Halt,
             faulted: false,
             last_fault: None,
+            last_fault_location: None,
+            pending_location: None,
         }
     }
 
@@ -30,14 +35,25 @@ impl FaultSubsystem {
         FaultDecision::from_fault_policy(self.policy)
     }
 
+    /// Remembers where the failing statement of the last program run was.
+    ///
+    /// Consumed by the next [`Self::record`] so faults raised outside statement
+    /// execution (I/O, retain) do not inherit a stale location.
+    pub(super) fn set_pending_location(&mut self, location: Option<SourceLocation>) {
+        self.pending_location = location;
+    }
+
     pub(super) fn record(&mut self, err: RuntimeError) {
         self.faulted = true;
         self.last_fault = Some(err);
+        self.last_fault_location = self.pending_location.take();
     }
 
     pub(super) fn clear(&mut self) {
         self.faulted = false;
         self.last_fault = None;
+        self.last_fault_location = None;
+        self.pending_location = None;
     }
 
     pub(super) fn is_faulted(&self) -> bool {
@@ -47,4 +63,8 @@ impl FaultSubsystem {
     pub(super) fn last_fault(&self) -> Option<&RuntimeError> {
         self.last_fault.as_ref()
     }
+
+    pub(super) fn last_fault_location(&self) -> Option<SourceLocation> {
+        self.last_fault_location
+    }
 }
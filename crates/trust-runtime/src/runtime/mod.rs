@@ -12,9 +12,11 @@ mod metadata;
 mod metrics_subsystem;
 mod restart;
 mod retain_store;
+mod subscriptions;
 pub(crate) mod types;
 mod watchdog_subsystem;
 
 pub use core::Runtime;
 pub use metadata::RuntimeMetadata;
+pub use subscriptions::{SubscriptionId, VariableChange};
 pub use types::{RestartMode, RetainPolicy, RetainSnapshot};
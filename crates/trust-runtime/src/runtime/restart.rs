@@ -6,6 +6,7 @@ use indexmap::IndexMap;
 use smol_str::SmolStr;
 
 use crate::error;
+use crate::memory::InstanceId;
 use crate::task::TaskState;
 use crate::value::{Duration, Value};
 
@@ -18,40 +19,36 @@ impl Runtime {
         let globals = self.globals.clone();
         let mut retained = IndexMap::new();
         let mut retained_program_vars = Vec::new();
-        if matches!(mode, RestartMode::Warm) {
-            for (name, meta) in &globals {
-                if retain_on_warm(meta.retain) {
-                    if let Some(value) = self.storage.get_global(name.as_ref()) {
-                        retained.insert(name.clone(), value.clone());
-                    }
+        for (name, meta) in &globals {
+            if retained_on(mode, meta.retain) {
+                if let Some(value) = self.storage.get_global(name.as_ref()) {
+                    retained.insert(name.clone(), value.clone());
                 }
             }
-            for program in self.programs.values() {
-                let Some(Value::Instance(id)) = self.storage.get_global(program.name.as_ref())
-                else {
+        }
+        for program in self.programs.values() {
+            let Some(Value::Instance(id)) = self.storage.get_global(program.name.as_ref()) else {
+                continue;
+            };
+            for var in &program.vars {
+                if !retained_on(mode, var.retain) {
+                    continue;
+                }
+                let Some(value) = self.storage.get_instance_var(*id, var.name.as_ref()) else {
                     continue;
                 };
-                for var in &program.vars {
-                    if !retain_on_warm(var.retain) {
-                        continue;
-                    }
-                    let Some(value) = self.storage.get_instance_var(*id, var.name.as_ref()) else {
-                        continue;
-                    };
-                    if value_is_retainable(value) {
-                        retained_program_vars.push((
-                            program.name.clone(),
-                            var.name.clone(),
-                            value.clone(),
-                        ));
-                    }
+                if value_is_retainable(value) {
+                    retained_program_vars.push((
+                        program.name.clone(),
+                        var.name.clone(),
+                        value.clone(),
+                    ));
                 }
             }
         }
 
         for (name, meta) in globals {
-            let keep = matches!(mode, RestartMode::Warm) && retain_on_warm(meta.retain);
-            if keep {
+            if retained_on(mode, meta.retain) {
                 if let Some(value) = retained.get(&name) {
                     self.storage.set_global(name.clone(), value.clone());
                     continue;
@@ -134,6 +131,167 @@ impl Runtime {
         Ok(())
     }
 
+    /// Reset a single FB or class instance to its initial values.
+    ///
+    /// `path` is dotted like a subscription path (`Main.counter`,
+    /// `GVL.motor.axis`). Members are re-initialized as on instance creation,
+    /// nested instances included; members retained under `mode` keep their
+    /// current values, as on a restart. The instance keeps its identity, so
+    /// references to it stay valid, and no other instance is touched.
+    pub fn reset_instance(
+        &mut self,
+        path: &str,
+        mode: RestartMode,
+    ) -> Result<(), error::RuntimeError> {
+        let target = self.instance_at_path(path)?;
+        let type_name = self
+            .storage
+            .get_instance(target)
+            .map(|instance| instance.type_name.clone())
+            .ok_or_else(|| error::RuntimeError::UndefinedVariable(path.into()))?;
+        let key = SmolStr::new(type_name.to_ascii_uppercase());
+        let fresh = if let Some(fb) = self.function_blocks.get(&key) {
+            crate::instance::create_fb_instance(
+                &mut self.storage,
+                &self.registry,
+                &self.profile,
+                &self.classes,
+                &self.function_blocks,
+                &self.functions,
+                &self.stdlib,
+                fb,
+            )?
+        } else if let Some(class_def) = self.classes.get(&key) {
+            crate::instance::create_class_instance(
+                &mut self.storage,
+                &self.registry,
+                &self.profile,
+                &self.classes,
+                &self.function_blocks,
+                &self.functions,
+                &self.stdlib,
+                class_def,
+            )?
+        } else {
+            return Err(error::RuntimeError::UndefinedFunctionBlock(type_name));
+        };
+        self.reset_instance_members(target, fresh, mode);
+        Ok(())
+    }
+
+    fn instance_at_path(&self, path: &str) -> Result<InstanceId, error::RuntimeError> {
+        let undefined = || error::RuntimeError::UndefinedVariable(path.into());
+        let mut segments = path.split('.');
+        let mut value = self
+            .storage
+            .get_global(segments.next().ok_or_else(undefined)?)
+            .ok_or_else(undefined)?;
+        for segment in segments {
+            let Value::Instance(id) = value else {
+                return Err(undefined());
+            };
+            value = self
+                .storage
+                .get_instance_var_recursive(*id, segment)
+                .ok_or_else(undefined)?;
+        }
+        match value {
+            Value::Instance(id) => Ok(*id),
+            _ => Err(error::RuntimeError::TypeMismatch),
+        }
+    }
+
+    /// Moves the initial values of `fresh` into `target` and drops `fresh`.
+    /// Nested and base instances are reset in place the same way; internal
+    /// state the fresh instance lacks (e.g. standard FB edge memory) is cleared.
+    fn reset_instance_members(&mut self, target: InstanceId, fresh: InstanceId, mode: RestartMode) {
+        let Some(fresh_data) = self.storage.remove_instance(fresh) else {
+            return;
+        };
+        let retained = self.retained_members(&fresh_data.type_name, mode);
+        let mut variables = IndexMap::new();
+        for (name, initial) in fresh_data.variables {
+            let current = self
+                .storage
+                .get_instance_var(target, name.as_ref())
+                .cloned();
+            let value = match (current, initial) {
+                (Some(current), initial)
+                    if retained
+                        .iter()
+                        .any(|member| member.eq_ignore_ascii_case(name.as_str())) =>
+                {
+                    self.discard_instances(initial);
+                    current
+                }
+                (Some(Value::Instance(current_id)), Value::Instance(fresh_id)) => {
+                    self.reset_instance_members(current_id, fresh_id, mode);
+                    Value::Instance(current_id)
+                }
+                // Generic members such as `CV` of `CTU` start unset and take
+                // their type from the first call; reset them to that type's zero.
+                (Some(current), Value::Null) => self.zero_like(&current),
+                (_, initial) => initial,
+            };
+            variables.insert(name, value);
+        }
+        let target_parent = self
+            .storage
+            .get_instance(target)
+            .and_then(|instance| instance.parent);
+        match (target_parent, fresh_data.parent) {
+            (Some(target_parent), Some(fresh_parent)) => {
+                self.reset_instance_members(target_parent, fresh_parent, mode);
+            }
+            (None, Some(fresh_parent)) => self.discard_instances(Value::Instance(fresh_parent)),
+            _ => {}
+        }
+        if let Some(instance) = self.storage.get_instance_mut(target) {
+            instance.variables = variables;
+        }
+    }
+
+    /// Default value of the elementary type `value` holds, or `Null` when it
+    /// has none.
+    fn zero_like(&self, value: &Value) -> Value {
+        crate::stdlib::conversions::value_type_id(value)
+            .and_then(|type_id| {
+                crate::value::default_value_for_type_id(type_id, &self.registry, &self.profile).ok()
+            })
+            .unwrap_or(Value::Null)
+    }
+
+    /// Drops the fresh instances `value` owns, bases and nested members included.
+    fn discard_instances(&mut self, value: Value) {
+        let Value::Instance(id) = value else {
+            return;
+        };
+        let Some(data) = self.storage.remove_instance(id) else {
+            return;
+        };
+        for member in data.variables.into_values() {
+            self.discard_instances(member);
+        }
+        if let Some(parent) = data.parent {
+            self.discard_instances(Value::Instance(parent));
+        }
+    }
+
+    fn retained_members(&self, type_name: &str, mode: RestartMode) -> Vec<SmolStr> {
+        let key = SmolStr::new(type_name.to_ascii_uppercase());
+        let vars = match self.function_blocks.get(&key) {
+            Some(fb) => &fb.vars,
+            None => match self.classes.get(&key) {
+                Some(class_def) => &class_def.vars,
+                None => return Vec::new(),
+            },
+        };
+        vars.iter()
+            .filter(|var| retained_on(mode, var.retain))
+            .map(|var| var.name.clone())
+            .collect()
+    }
+
     /// Capture retained global values that can be preserved across reloads.
     #[must_use]
     pub fn retain_snapshot(&self) -> RetainSnapshot {
@@ -169,6 +327,14 @@ fn retain_on_warm(policy: RetainPolicy) -> bool {
     matches!(policy, RetainPolicy::Retain | RetainPolicy::Persistent)
 }
 
+/// RETAIN survives a warm restart; PERSISTENT also survives a cold restart.
+fn retained_on(mode: RestartMode, policy: RetainPolicy) -> bool {
+    match mode {
+        RestartMode::Warm => retain_on_warm(policy),
+        RestartMode::Cold => matches!(policy, RetainPolicy::Persistent),
+    }
+}
+
 fn value_is_retainable(value: &Value) -> bool {
     match value {
         Value::Array(array) => array.elements.iter().all(value_is_retainable),
@@ -0,0 +1,150 @@
+//! Variable-change subscriptions for live monitoring clients.
+
+#![allow(missing_docs)]
+
+use indexmap::IndexMap;
+use smol_str::SmolStr;
+
+use crate::memory::VariableStorage;
+use crate::value::Value;
+
+use super::core::Runtime;
+
+/// Handle returned by [`Runtime::subscribe_changes`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SubscriptionId(pub u64);
+
+/// A single changed value reported to a subscriber.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VariableChange {
+    pub path: SmolStr,
+    pub value: Value,
+}
+
+#[derive(Debug, Default)]
+pub(super) struct ChangeSubscriptions {
+    next_id: u64,
+    entries: IndexMap<SubscriptionId, ChangeSubscription>,
+}
+
+#[derive(Debug)]
+struct ChangeSubscription {
+    /// Value each path had when it was last reported (or subscribed).
+    reported: IndexMap<SmolStr, Option<Value>>,
+    pending: IndexMap<SmolStr, Value>,
+}
+
+impl ChangeSubscriptions {
+    pub(super) fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    fn insert(&mut self, reported: IndexMap<SmolStr, Option<Value>>) -> SubscriptionId {
+        self.next_id = self.next_id.saturating_add(1);
+        let id = SubscriptionId(self.next_id);
+        self.entries.insert(
+            id,
+            ChangeSubscription {
+                reported,
+                pending: IndexMap::new(),
+            },
+        );
+        id
+    }
+
+    /// Compare every subscribed path against its last reported value and queue
+    /// the differences; a path that changed back since the last drain is dropped.
+    pub(super) fn collect(&mut self, storage: &VariableStorage) {
+        for subscription in self.entries.values_mut() {
+            for (path, reported) in &subscription.reported {
+                match resolve_path(storage, path) {
+                    Some(current) if Some(current) != reported.as_ref() => {
+                        subscription.pending.insert(path.clone(), current.clone());
+                    }
+                    _ => {
+                        subscription.pending.shift_remove(path);
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl Runtime {
+    /// Register for change notifications on a set of variable paths.
+    ///
+    /// Paths are dotted (`Main.count`, `GVL.motor.speed`) and, like ST
+    /// identifiers, match regardless of case. Current values are captured as
+    /// the baseline, so only subsequent changes are reported.
+    pub fn subscribe_changes<I, S>(&mut self, paths: I) -> SubscriptionId
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<SmolStr>,
+    {
+        let reported = paths
+            .into_iter()
+            .map(Into::into)
+            .map(|path| {
+                let value = resolve_path(&self.storage, &path).cloned();
+                (path, value)
+            })
+            .collect();
+        self.subscriptions.insert(reported)
+    }
+
+    /// Remove a change subscription. Returns `false` if the id is unknown.
+    pub fn unsubscribe_changes(&mut self, id: SubscriptionId) -> bool {
+        self.subscriptions.entries.shift_remove(&id).is_some()
+    }
+
+    /// Drain the values that changed since the last call, in subscription order.
+    ///
+    /// A path that changed several times between drains is reported once with
+    /// its latest value, and not at all if it ended up back at the value last
+    /// reported.
+    pub fn take_changes(&mut self, id: SubscriptionId) -> Vec<VariableChange> {
+        let Some(subscription) = self.subscriptions.entries.get_mut(&id) else {
+            return Vec::new();
+        };
+        let mut changes = std::mem::take(&mut subscription.pending)
+            .into_iter()
+            .map(|(path, value)| {
+                if let Some(reported) = subscription.reported.get_mut(&path) {
+                    *reported = Some(value.clone());
+                }
+                VariableChange { path, value }
+            })
+            .collect::<Vec<_>>();
+        let order = &subscription.reported;
+        changes.sort_by_key(|change| order.get_index_of(&change.path));
+        changes
+    }
+}
+
+fn resolve_path<'a>(storage: &'a VariableStorage, path: &str) -> Option<&'a Value> {
+    let mut segments = path.split('.');
+    let root = segments.next()?;
+    let mut value = if root.eq_ignore_ascii_case("retain") {
+        lookup(storage.retain(), segments.next()?)?
+    } else {
+        lookup(storage.globals(), root)?
+    };
+    for segment in segments {
+        value = match value {
+            Value::Instance(id) => lookup(&storage.get_instance(*id)?.variables, segment)?,
+            Value::Struct(value) => lookup(&value.fields, segment)?,
+            _ => return None,
+        };
+    }
+    Some(value)
+}
+
+/// Exact lookup first, then a case-insensitive scan.
+fn lookup<'a>(entries: &'a IndexMap<SmolStr, Value>, name: &str) -> Option<&'a Value> {
+    entries.get(name).or_else(|| {
+        entries
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value)
+    })
+}
@@ -30,7 +30,7 @@ pub enum RetainPolicy {
 /// Restart mode for a resource/configuration.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum RestartMode {
-    /// Cold restart: reinitialize all variables.
+    /// Cold restart: reinitialize all variables except PERSISTENT ones.
     Cold,
     /// Warm restart: retain RETAIN/PERSISTENT variables.
     Warm,
@@ -0,0 +1,142 @@
+//! SARIF 2.1.0 export of project diagnostics.
+//!
+//! The log carries one rule per [`DiagnosticCode`] and one result per
+//! diagnostic. Regions are 1-based with UTF-16 columns, the SARIF default
+//! `columnKind`, so code-scanning viewers place them without extra hints.
+
+use serde_json::{json, Value};
+use text_size::TextRange;
+use trust_hir::db::{SemanticDatabase, SourceDatabase};
+use trust_hir::diagnostics::DiagnosticCode;
+use trust_hir::{Diagnostic, DiagnosticSeverity, Project};
+
+/// SARIF schema the exported log declares.
+pub const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+/// SARIF version the exported log declares.
+pub const SARIF_VERSION: &str = "2.1.0";
+
+/// Converts the diagnostics of every source in `project` into a SARIF log.
+///
+/// Sources are emitted in display-name order so the output is stable.
+#[must_use]
+pub fn project_diagnostics_to_sarif(project: &Project) -> Value {
+    let mut sources: Vec<_> = project
+        .sources()
+        .iter()
+        .map(|(key, file_id)| (key.display(), file_id))
+        .collect();
+    sources.sort_by(|a, b| a.0.cmp(&b.0));
+
+    let db = project.database();
+    let mut results = Vec::new();
+    for (uri, file_id) in sources {
+        let text = db.source_text(file_id);
+        for diagnostic in db.diagnostics(file_id).iter() {
+            results.push(sarif_result(&uri, &text, diagnostic));
+        }
+    }
+
+    json!({
+        "$schema": SARIF_SCHEMA,
+        "version": SARIF_VERSION,
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": env!("CARGO_PKG_NAME"),
+                    "version": env!("CARGO_PKG_VERSION"),
+                    "rules": DiagnosticCode::ALL.iter().map(sarif_rule).collect::<Vec<_>>(),
+                }
+            },
+            "columnKind": "utf16CodeUnits",
+            "results": results,
+        }]
+    })
+}
+
+fn sarif_rule(code: &DiagnosticCode) -> Value {
+    json!({
+        "id": code.code(),
+        "shortDescription": { "text": code.description() },
+        "defaultConfiguration": { "level": sarif_level(code.severity()) },
+    })
+}
+
+fn sarif_result(uri: &str, text: &str, diagnostic: &Diagnostic) -> Value {
+    let mut result = json!({
+        "ruleId": diagnostic.code.code(),
+        "ruleIndex": rule_index(diagnostic.code),
+        "level": sarif_level(diagnostic.severity),
+        "message": { "text": diagnostic.message },
+        "locations": [sarif_location(uri, text, diagnostic.range, None)],
+    });
+    if !diagnostic.related.is_empty() {
+        result["relatedLocations"] = diagnostic
+            .related
+            .iter()
+            .map(|related| sarif_location(uri, text, related.range, Some(&related.message)))
+            .collect();
+    }
+    result
+}
+
+fn sarif_location(uri: &str, text: &str, range: TextRange, message: Option<&str>) -> Value {
+    let (start_line, start_column) = line_column(text, u32::from(range.start()));
+    let (end_line, end_column) = line_column(text, u32::from(range.end()));
+    let mut location = json!({
+        "physicalLocation": {
+            "artifactLocation": { "uri": uri },
+            "region": {
+                "startLine": start_line,
+                "startColumn": start_column,
+                "endLine": end_line,
+                "endColumn": end_column,
+            }
+        }
+    });
+    if let Some(message) = message {
+        location["message"] = json!({ "text": message });
+    }
+    location
+}
+
+fn rule_index(code: DiagnosticCode) -> usize {
+    DiagnosticCode::ALL
+        .iter()
+        .position(|candidate| *candidate == code)
+        .unwrap_or_default()
+}
+
+fn sarif_level(severity: DiagnosticSeverity) -> &'static str {
+    match severity {
+        DiagnosticSeverity::Error => "error",
+        DiagnosticSeverity::Warning => "warning",
+        DiagnosticSeverity::Info | DiagnosticSeverity::Hint => "note",
+    }
+}
+
+/// Converts a byte offset into a 1-based line and 1-based UTF-16 column.
+fn line_column(text: &str, offset: u32) -> (usize, usize) {
+    let offset = usize::try_from(offset)
+        .unwrap_or(usize::MAX)
+        .min(text.len());
+    let prefix = text.get(..offset).unwrap_or(text);
+    let line_start = prefix.rfind('\n').map_or(0, |idx| idx + 1);
+    let line = prefix.matches('\n').count() + 1;
+    let column = prefix[line_start..].encode_utf16().count() + 1;
+    (line, column)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn line_column_counts_utf16_units() {
+        let text = "A := 1;\nS := 'é€';\n";
+        assert_eq!(line_column(text, 0), (1, 1));
+        let semicolon = u32::try_from(text.rfind(';').unwrap()).unwrap();
+        assert_eq!(line_column(text, semicolon), (2, 10));
+        let end = u32::try_from(text.len()).unwrap();
+        assert_eq!(line_column(text, end), (3, 1));
+    }
+}
@@ -35,6 +35,11 @@ pub enum ResourceCommand {
     Snapshot {
         respond_to: std::sync::mpsc::Sender<crate::debug::DebugSnapshot>,
     },
+    ResetInstance {
+        path: SmolStr,
+        mode: crate::RestartMode,
+        respond_to: std::sync::mpsc::Sender<Result<(), RuntimeError>>,
+    },
 }
 
 /// Gate that blocks resource execution until opened.
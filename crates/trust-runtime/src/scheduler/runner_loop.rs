@@ -350,5 +350,12 @@ fn apply_resource_command(runtime: &mut Runtime, command: ResourceCommand) {
             };
             let _ = respond_to.send(snapshot);
         }
+        ResourceCommand::ResetInstance {
+            path,
+            mode,
+            respond_to,
+        } => {
+            let _ = respond_to.send(runtime.reset_instance(&path, mode));
+        }
     }
 }
@@ -55,6 +55,22 @@ pub(super) fn unsigned_to_bit_string(value: u64, dst: TypeId) -> Result<Value, R
     bit_string_from_u64(bits, dst)
 }
 
+/// Converts an integer or bit string to BOOL; any non-zero value is TRUE.
+pub(super) fn convert_to_bool(value: &Value) -> Result<Value, RuntimeError> {
+    let non_zero = match value {
+        Value::SInt(v) => *v != 0,
+        Value::Int(v) => *v != 0,
+        Value::DInt(v) => *v != 0,
+        Value::LInt(v) => *v != 0,
+        Value::USInt(v) => *v != 0,
+        Value::UInt(v) => *v != 0,
+        Value::UDInt(v) => *v != 0,
+        Value::ULInt(v) => *v != 0,
+        _ => bit_string_to_u64(value)? != 0,
+    };
+    Ok(Value::Bool(non_zero))
+}
+
 pub(super) fn bit_string_to_u64(value: &Value) -> Result<u64, RuntimeError> {
     match value {
         Value::Byte(v) => Ok(*v as u64),
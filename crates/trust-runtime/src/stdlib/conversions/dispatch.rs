@@ -1,15 +1,16 @@
 use crate::error::RuntimeError;
 use crate::stdlib::helpers::require_arity;
 use crate::value::Value;
+use trust_hir::types::conversions::is_conversion_allowed;
 use trust_hir::TypeId;
 
 use super::bcd::{from_bcd, to_bcd};
-use super::bitstring::convert_to_bit_string;
+use super::bitstring::{convert_to_bit_string, convert_to_bool};
 use super::numeric::{convert_to_int, convert_to_real};
 use super::spec::ConversionSpec;
 use super::string::{convert_to_char, convert_to_string};
 use super::time::{convert_to_date, convert_to_dt, convert_to_time, convert_to_tod};
-use super::util::{is_conversion_allowed, is_integer_type, value_type_id};
+use super::util::{is_integer_type, value_type_id};
 use super::ConversionMode;
 
 pub(super) fn apply_conversion(
@@ -88,7 +89,7 @@ fn convert_value(value: &Value, dst: TypeId, mode: ConversionMode) -> Result<Val
         TypeId::DT | TypeId::LDT => convert_to_dt(value, dst),
         TypeId::STRING | TypeId::WSTRING => convert_to_string(value, dst),
         TypeId::CHAR | TypeId::WCHAR => convert_to_char(value, dst),
-        TypeId::BOOL => Err(RuntimeError::TypeMismatch),
+        TypeId::BOOL => convert_to_bool(value),
         _ => Err(RuntimeError::TypeMismatch),
     }
 }
@@ -33,154 +33,7 @@ pub(super) fn is_bit_string_type(ty: TypeId) -> bool {
     )
 }
 
-pub(super) fn is_conversion_allowed(src: TypeId, dst: TypeId) -> bool {
-    if src == dst {
-        return true;
-    }
-
-    if is_numeric_type(src) && is_numeric_type(dst) {
-        return true;
-    }
-
-    if matches!(
-        src,
-        TypeId::BYTE | TypeId::WORD | TypeId::DWORD | TypeId::LWORD
-    ) && matches!(
-        dst,
-        TypeId::BYTE | TypeId::WORD | TypeId::DWORD | TypeId::LWORD
-    ) {
-        return true;
-    }
-
-    if matches!(
-        src,
-        TypeId::BOOL | TypeId::BYTE | TypeId::WORD | TypeId::DWORD | TypeId::LWORD
-    ) && matches!(
-        dst,
-        TypeId::SINT
-            | TypeId::INT
-            | TypeId::DINT
-            | TypeId::LINT
-            | TypeId::USINT
-            | TypeId::UINT
-            | TypeId::UDINT
-            | TypeId::ULINT
-    ) {
-        return true;
-    }
-
-    if src == TypeId::DWORD && dst == TypeId::REAL {
-        return true;
-    }
-    if src == TypeId::LWORD && dst == TypeId::LREAL {
-        return true;
-    }
-
-    if matches!(
-        dst,
-        TypeId::BYTE | TypeId::WORD | TypeId::DWORD | TypeId::LWORD
-    ) && matches!(
-        src,
-        TypeId::SINT
-            | TypeId::INT
-            | TypeId::DINT
-            | TypeId::LINT
-            | TypeId::USINT
-            | TypeId::UINT
-            | TypeId::UDINT
-            | TypeId::ULINT
-    ) {
-        return true;
-    }
-
-    if src == TypeId::REAL && dst == TypeId::DWORD {
-        return true;
-    }
-    if src == TypeId::LREAL && dst == TypeId::LWORD {
-        return true;
-    }
-
-    if src == TypeId::LTIME && dst == TypeId::TIME {
-        return true;
-    }
-    if src == TypeId::TIME && dst == TypeId::LTIME {
-        return true;
-    }
-    if src == TypeId::LDT && dst == TypeId::DT {
-        return true;
-    }
-    if src == TypeId::LDT && dst == TypeId::DATE {
-        return true;
-    }
-    if src == TypeId::LDT && dst == TypeId::LTOD {
-        return true;
-    }
-    if src == TypeId::LDT && dst == TypeId::TOD {
-        return true;
-    }
-    if src == TypeId::DT && dst == TypeId::LDT {
-        return true;
-    }
-    if src == TypeId::DT && dst == TypeId::DATE {
-        return true;
-    }
-    if src == TypeId::DT && dst == TypeId::LTOD {
-        return true;
-    }
-    if src == TypeId::DT && dst == TypeId::TOD {
-        return true;
-    }
-    if src == TypeId::LTOD && dst == TypeId::TOD {
-        return true;
-    }
-    if src == TypeId::TOD && dst == TypeId::LTOD {
-        return true;
-    }
-
-    let src = normalize_string_type_id(src);
-    let dst = normalize_string_type_id(dst);
-
-    if src == TypeId::WSTRING && matches!(dst, TypeId::STRING | TypeId::WCHAR) {
-        return true;
-    }
-    if src == TypeId::STRING && matches!(dst, TypeId::WSTRING | TypeId::CHAR) {
-        return true;
-    }
-    if src == TypeId::WCHAR && matches!(dst, TypeId::WSTRING | TypeId::CHAR) {
-        return true;
-    }
-    if src == TypeId::CHAR && matches!(dst, TypeId::STRING | TypeId::WCHAR) {
-        return true;
-    }
-
-    false
-}
-
-fn is_numeric_type(ty: TypeId) -> bool {
-    matches!(
-        ty,
-        TypeId::SINT
-            | TypeId::INT
-            | TypeId::DINT
-            | TypeId::LINT
-            | TypeId::USINT
-            | TypeId::UINT
-            | TypeId::UDINT
-            | TypeId::ULINT
-            | TypeId::REAL
-            | TypeId::LREAL
-    )
-}
-
-fn normalize_string_type_id(ty: TypeId) -> TypeId {
-    match ty {
-        TypeId::STRING => TypeId::STRING,
-        TypeId::WSTRING => TypeId::WSTRING,
-        _ => ty,
-    }
-}
-
-pub(super) fn value_type_id(value: &Value) -> Option<TypeId> {
+pub(crate) fn value_type_id(value: &Value) -> Option<TypeId> {
     match value {
         Value::Bool(_) => Some(TypeId::BOOL),
         Value::SInt(_) => Some(TypeId::SINT),
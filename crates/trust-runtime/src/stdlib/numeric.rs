@@ -64,15 +64,15 @@ fn abs(args: &[Value]) -> Result<Value, RuntimeError> {
 }
 
 fn sqrt(args: &[Value]) -> Result<Value, RuntimeError> {
-    unary_real(args, |v| v.sqrt())
+    unary_real_in_domain("SQRT", args, |v| v >= 0.0, |v| v.sqrt())
 }
 
 fn ln(args: &[Value]) -> Result<Value, RuntimeError> {
-    unary_real(args, |v| v.ln())
+    unary_real_in_domain("LN", args, |v| v > 0.0, |v| v.ln())
 }
 
 fn log10(args: &[Value]) -> Result<Value, RuntimeError> {
-    unary_real(args, |v| v.log10())
+    unary_real_in_domain("LOG", args, |v| v > 0.0, |v| v.log10())
 }
 
 fn exp(args: &[Value]) -> Result<Value, RuntimeError> {
@@ -92,11 +92,11 @@ fn tan(args: &[Value]) -> Result<Value, RuntimeError> {
 }
 
 fn asin(args: &[Value]) -> Result<Value, RuntimeError> {
-    unary_real(args, |v| v.asin())
+    unary_real_in_domain("ASIN", args, |v| (-1.0..=1.0).contains(&v), |v| v.asin())
 }
 
 fn acos(args: &[Value]) -> Result<Value, RuntimeError> {
-    unary_real(args, |v| v.acos())
+    unary_real_in_domain("ACOS", args, |v| (-1.0..=1.0).contains(&v), |v| v.acos())
 }
 
 fn atan(args: &[Value]) -> Result<Value, RuntimeError> {
@@ -114,6 +114,29 @@ fn atan2(args: &[Value]) -> Result<Value, RuntimeError> {
     }
 }
 
+/// Like [`unary_real`], but fails with [`RuntimeError::MathDomain`] when the
+/// argument is outside `domain` instead of producing NaN or an infinity.
+fn unary_real_in_domain(
+    name: &str,
+    args: &[Value],
+    domain: impl Fn(f64) -> bool,
+    f: impl Fn(f64) -> f64,
+) -> Result<Value, RuntimeError> {
+    require_arity(args, 1)?;
+    let value = match args[0] {
+        Value::Real(v) => v as f64,
+        Value::LReal(v) => v,
+        _ => return Err(RuntimeError::TypeMismatch),
+    };
+    if !domain(value) {
+        return Err(RuntimeError::MathDomain(name.into()));
+    }
+    unary_real(args, f)
+}
+
+/// Applies `f` to a REAL or LREAL argument, keeping its type.
+///
+/// Non-finite results fail with [`RuntimeError::Overflow`].
 fn unary_real(args: &[Value], f: impl Fn(f64) -> f64) -> Result<Value, RuntimeError> {
     require_arity(args, 1)?;
     match args[0] {
@@ -181,21 +204,19 @@ fn expt(args: &[Value]) -> Result<Value, RuntimeError> {
     let base = &args[0];
     let exp = &args[1];
     let exp = to_f64(exp)?;
-    match base {
-        Value::Real(v) => {
-            let result = (*v as f64).powf(exp);
-            if !result.is_finite() {
-                return Err(RuntimeError::Overflow);
-            }
-            Ok(Value::Real(result as f32))
+    let power = |base: f64| {
+        if base < 0.0 && exp.fract() != 0.0 {
+            return Err(RuntimeError::MathDomain("EXPT".into()));
         }
-        Value::LReal(v) => {
-            let result = v.powf(exp);
-            if !result.is_finite() {
-                return Err(RuntimeError::Overflow);
-            }
-            Ok(Value::LReal(result))
+        let result = base.powf(exp);
+        if !result.is_finite() {
+            return Err(RuntimeError::Overflow);
         }
+        Ok(result)
+    };
+    match base {
+        Value::Real(v) => power(*v as f64).map(|result| Value::Real(result as f32)),
+        Value::LReal(v) => power(*v).map(Value::LReal),
         _ => Err(RuntimeError::TypeMismatch),
     }
 }
@@ -20,6 +20,26 @@ pub fn register(lib: &mut StandardLibrary) {
     lib.register("FIND", &["IN1", "IN2"], find);
 }
 
+/// Truncates a STRING/WSTRING value to a declared maximum length.
+///
+/// STRING lengths count bytes; a multi-byte character that would be split is dropped.
+pub fn truncate_to_length(value: Value, max_len: u32) -> Value {
+    let max_len = max_len as usize;
+    match value {
+        Value::String(text) if text.len() > max_len => {
+            let mut end = max_len;
+            while !text.is_char_boundary(end) {
+                end -= 1;
+            }
+            Value::String(SmolStr::new(&text[..end]))
+        }
+        Value::WString(text) if text.chars().count() > max_len => {
+            Value::WString(text.chars().take(max_len).collect())
+        }
+        other => other,
+    }
+}
+
 fn len(args: &[Value]) -> Result<Value, RuntimeError> {
     require_arity(args, 1)?;
     let length = match &args[0] {
@@ -70,14 +70,19 @@ fn default_value_for_type(
             element,
             dimensions,
         } => {
-            let total = array_len(dimensions)?;
+            let dimensions: Vec<_> = dimensions
+                .iter()
+                .copied()
+                .collect::<Option<_>>()
+                .ok_or(DefaultValueError::InvalidArrayBounds)?;
+            let total = array_len(&dimensions)?;
             let mut elements = Vec::with_capacity(total);
             for _ in 0..total {
                 elements.push(default_value_for_type_id(*element, registry, profile)?);
             }
             Ok(Value::Array(ArrayValue {
                 elements,
-                dimensions: dimensions.clone(),
+                dimensions,
             }))
         }
         Type::Struct { name, fields } => {
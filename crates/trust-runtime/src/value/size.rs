@@ -10,24 +10,50 @@ pub enum SizeOfError {
     Overflow,
 }
 
+/// Storage layout for `ARRAY OF BOOL` in the size model.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BoolArrayLayout {
+    /// One byte per element.
+    #[default]
+    Unpacked,
+    /// One bit per element, least significant bit first (bit-addressed I/O images).
+    Packed,
+}
+
 pub fn size_of_type(type_id: TypeId, registry: &TypeRegistry) -> Result<u64, SizeOfError> {
+    size_of_type_with_layout(type_id, registry, BoolArrayLayout::Unpacked)
+}
+
+pub fn size_of_type_with_layout(
+    type_id: TypeId,
+    registry: &TypeRegistry,
+    layout: BoolArrayLayout,
+) -> Result<u64, SizeOfError> {
     let ty = registry.get(type_id).ok_or(SizeOfError::UnknownType)?;
     match ty {
-        Type::Alias { target, .. } => size_of_type(*target, registry),
-        Type::Subrange { base, .. } => size_of_type(*base, registry),
-        Type::Enum { base, .. } => size_of_type(*base, registry),
+        Type::Alias { target, .. } => size_of_type_with_layout(*target, registry, layout),
+        Type::Subrange { base, .. } => size_of_type_with_layout(*base, registry, layout),
+        Type::Enum { base, .. } => size_of_type_with_layout(*base, registry, layout),
         Type::Array {
             element,
             dimensions,
         } => {
-            let element_size = size_of_type(*element, registry)?;
-            let len = array_len_bits(dimensions).ok_or(SizeOfError::UnsupportedType)?;
+            let dimensions: Vec<_> = dimensions
+                .iter()
+                .copied()
+                .collect::<Option<_>>()
+                .ok_or(SizeOfError::UnsupportedType)?;
+            let len = array_len_bits(&dimensions).ok_or(SizeOfError::UnsupportedType)?;
+            if layout == BoolArrayLayout::Packed && is_bool_type(*element, registry) {
+                return Ok(len.div_ceil(8));
+            }
+            let element_size = size_of_type_with_layout(*element, registry, layout)?;
             element_size.checked_mul(len).ok_or(SizeOfError::Overflow)
         }
         Type::Struct { fields, .. } => {
             let mut total = 0u64;
             for field in fields {
-                let size = size_of_type(field.type_id, registry)?;
+                let size = size_of_type_with_layout(field.type_id, registry, layout)?;
                 total = total.checked_add(size).ok_or(SizeOfError::Overflow)?;
             }
             Ok(total)
@@ -35,7 +61,7 @@ pub fn size_of_type(type_id: TypeId, registry: &TypeRegistry) -> Result<u64, Siz
         Type::Union { variants, .. } => {
             let mut max = 0u64;
             for variant in variants {
-                let size = size_of_type(variant.type_id, registry)?;
+                let size = size_of_type_with_layout(variant.type_id, registry, layout)?;
                 max = max.max(size);
             }
             Ok(max)
@@ -99,6 +125,15 @@ pub fn size_of_value(registry: &TypeRegistry, value: &Value) -> Result<u64, Size
     Ok(size)
 }
 
+/// Returns true when `type_id` resolves (through aliases) to `BOOL`.
+pub(crate) fn is_bool_type(type_id: TypeId, registry: &TypeRegistry) -> bool {
+    match registry.get(type_id) {
+        Some(Type::Bool) => true,
+        Some(Type::Alias { target, .. }) => is_bool_type(*target, registry),
+        _ => false,
+    }
+}
+
 fn array_len_bits(dimensions: &[(i64, i64)]) -> Option<u64> {
     let mut total: i128 = 1;
     for (lower, upper) in dimensions {
@@ -14,6 +14,7 @@ pub fn make_context<'a>(
         now: Duration::ZERO,
         debug: None,
         call_depth: 0,
+        max_call_depth: trust_runtime::eval::DEFAULT_MAX_CALL_DEPTH,
         functions: None,
         stdlib: None,
         function_blocks: None,
@@ -25,5 +26,6 @@ pub fn make_context<'a>(
         loop_depth: 0,
         pause_requested: false,
         execution_deadline: None,
+        fault_location: None,
     }
 }
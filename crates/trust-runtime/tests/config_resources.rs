@@ -0,0 +1,223 @@
+use trust_runtime::harness::TestHarness;
+use trust_runtime::value::Value;
+
+#[test]
+fn resource_globals_are_isolated_and_configuration_globals_shared() {
+    let source = r#"
+PROGRAM Producer
+VAR_EXTERNAL
+    shared : INT;
+    count : INT;
+END_VAR
+VAR
+    seen_count : INT;
+END_VAR
+count := count + INT#1;
+shared := shared + INT#10;
+seen_count := count;
+END_PROGRAM
+
+PROGRAM Consumer
+VAR_EXTERNAL
+    shared : INT;
+    count : INT;
+END_VAR
+VAR
+    seen_shared : INT;
+    seen_own : INT;
+END_VAR
+count := count + INT#100;
+seen_shared := shared;
+seen_own := count;
+END_PROGRAM
+
+CONFIGURATION Plant
+VAR_GLOBAL
+    shared : INT;
+END_VAR
+VAR_ACCESS
+    R1_COUNT : R1.count : INT READ_ONLY;
+END_VAR
+RESOURCE R1 ON CPU
+VAR_GLOBAL
+    count : INT := 1;
+    r1_only : INT := 7;
+END_VAR
+PROGRAM P1 : Producer;
+END_RESOURCE
+RESOURCE R2 ON CPU
+VAR_GLOBAL
+    count : INT := 50;
+END_VAR
+VAR_ACCESS
+    OWN_COUNT : count : INT READ_ONLY;
+END_VAR
+PROGRAM P2 : Consumer;
+END_RESOURCE
+END_CONFIGURATION
+"#;
+
+    let mut harness = TestHarness::from_source(source).unwrap();
+    let result = harness.cycle();
+    assert!(result.errors.is_empty(), "{:?}", result.errors);
+
+    harness.assert_eq("seen_count", 2i16);
+    harness.assert_eq("seen_own", 150i16);
+    harness.assert_eq("seen_shared", 10i16);
+
+    let storage = harness.runtime().storage();
+    assert_eq!(storage.get_global("R1.count"), Some(&Value::Int(2)));
+    assert_eq!(storage.get_global("R2.count"), Some(&Value::Int(150)));
+    assert_eq!(storage.get_global("shared"), Some(&Value::Int(10)));
+    assert_eq!(storage.get_global("count"), None);
+    // Names declared once keep their plain storage name.
+    assert_eq!(storage.get_global("r1_only"), Some(&Value::Int(7)));
+    assert_eq!(storage.get_global("R1.r1_only"), None);
+}
+
+#[test]
+fn var_access_resolves_resource_globals_within_scope() {
+    let source = r#"
+PROGRAM Producer
+VAR_EXTERNAL
+    shared : INT;
+    count : INT;
+END_VAR
+VAR
+    seen_count : INT;
+END_VAR
+count := count + INT#1;
+shared := shared + INT#10;
+seen_count := count;
+END_PROGRAM
+
+PROGRAM Consumer
+VAR_EXTERNAL
+    shared : INT;
+    count : INT;
+END_VAR
+VAR
+    seen_shared : INT;
+    seen_own : INT;
+END_VAR
+count := count + INT#100;
+seen_shared := shared;
+seen_own := count;
+END_PROGRAM
+
+CONFIGURATION Plant
+VAR_GLOBAL
+    shared : INT;
+END_VAR
+VAR_ACCESS
+    R1_COUNT : R1.count : INT READ_ONLY;
+END_VAR
+RESOURCE R1 ON CPU
+VAR_GLOBAL
+    count : INT := 1;
+END_VAR
+PROGRAM P1 : Producer;
+END_RESOURCE
+RESOURCE R2 ON CPU
+VAR_GLOBAL
+    count : INT := 50;
+END_VAR
+VAR_ACCESS
+    OWN_COUNT : count : INT READ_ONLY;
+END_VAR
+PROGRAM P2 : Consumer;
+END_RESOURCE
+END_CONFIGURATION
+"#;
+
+    let mut harness = TestHarness::from_source(source).unwrap();
+    harness.cycle();
+
+    let runtime = harness.runtime();
+    let read = |map: &trust_runtime::memory::AccessMap, name: &str| {
+        let binding = map.get(name).expect("access binding");
+        runtime
+            .storage()
+            .read_by_ref(binding.reference.clone())
+            .cloned()
+    };
+
+    // Configuration-level paths reach a resource global through its RESOURCE prefix.
+    assert_eq!(read(runtime.access_map(), "R1_COUNT"), Some(Value::Int(2)));
+    // Resource-level paths resolve against the declaring resource only.
+    let r2 = runtime.program_access_map("P2");
+    assert_eq!(read(r2, "OWN_COUNT"), Some(Value::Int(150)));
+    assert!(runtime.access_map().get("OWN_COUNT").is_none());
+    assert!(runtime.program_access_map("P1").get("OWN_COUNT").is_none());
+}
+
+#[test]
+fn var_access_rejects_paths_into_another_resource() {
+    let source = r#"
+PROGRAM Left
+VAR
+    value : INT;
+END_VAR
+END_PROGRAM
+
+PROGRAM Right
+VAR
+    other : INT;
+END_VAR
+END_PROGRAM
+
+CONFIGURATION Plant
+RESOURCE R1 ON CPU
+PROGRAM P1 : Left;
+END_RESOURCE
+RESOURCE R2 ON CPU
+VAR_ACCESS
+    PEEK : P1.value : INT READ_ONLY;
+END_VAR
+PROGRAM P2 : Right;
+END_RESOURCE
+END_CONFIGURATION
+"#;
+
+    let Err(err) = TestHarness::from_source(source) else {
+        panic!("expected cross-resource VAR_ACCESS to be rejected");
+    };
+    assert!(
+        err.to_string().contains("crosses into RESOURCE 'R1'"),
+        "unexpected error: {err}"
+    );
+}
+
+#[test]
+fn var_access_rejects_another_resources_global() {
+    let source = r#"
+PROGRAM Idle
+END_PROGRAM
+
+PROGRAM Watcher
+END_PROGRAM
+
+CONFIGURATION Plant
+RESOURCE R1 ON CPU
+VAR_GLOBAL
+    level : INT;
+END_VAR
+PROGRAM P1 : Idle;
+END_RESOURCE
+RESOURCE R2 ON CPU
+VAR_ACCESS
+    PEEK : level : INT READ_ONLY;
+END_VAR
+PROGRAM P2 : Watcher;
+END_RESOURCE
+END_CONFIGURATION
+"#;
+
+    let Err(err) = TestHarness::from_source(source) else {
+        panic!("expected VAR_ACCESS to another resource's global to be rejected");
+    };
+    assert!(
+        err.to_string().contains("crosses into RESOURCE 'R1'"),
+        "unexpected error: {err}"
+    );
+}
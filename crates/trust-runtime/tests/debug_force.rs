@@ -0,0 +1,103 @@
+use trust_runtime::harness::TestHarness;
+use trust_runtime::value::{Duration, Value};
+
+#[test]
+fn forced_output_holds_value_despite_program_writes() {
+    let source = r#"
+PROGRAM Main
+VAR
+    count : INT;
+    out : INT;
+END_VAR
+count := count + INT#1;
+out := count * INT#10;
+END_PROGRAM
+"#;
+
+    let mut harness = TestHarness::from_source(source).unwrap();
+    harness.cycle();
+    harness.assert_eq("out", 10i16);
+
+    harness.force("out", 99i16);
+    harness.assert_eq("out", 99i16);
+    harness.run_cycles(3);
+    harness.assert_eq("count", 4i16);
+    harness.assert_eq("out", 99i16);
+
+    harness.release("out");
+    harness.cycle();
+    harness.assert_eq("out", 50i16);
+}
+
+#[test]
+fn forced_global_is_seen_by_later_tasks_in_the_same_cycle() {
+    let source = r#"
+PROGRAM Writer
+VAR_EXTERNAL
+    shared : INT;
+END_VAR
+shared := INT#1;
+END_PROGRAM
+
+PROGRAM Reader
+VAR_EXTERNAL
+    shared : INT;
+END_VAR
+VAR
+    seen : INT;
+END_VAR
+seen := shared;
+END_PROGRAM
+
+CONFIGURATION Plant
+VAR_GLOBAL
+    shared : INT;
+END_VAR
+RESOURCE Cpu ON PLC
+    TASK First (INTERVAL := T#10ms, PRIORITY := 1);
+    TASK Second (INTERVAL := T#10ms, PRIORITY := 2);
+    PROGRAM WriterInstance WITH First : Writer;
+    PROGRAM ReaderInstance WITH Second : Reader;
+END_RESOURCE
+END_CONFIGURATION
+"#;
+
+    let mut harness = TestHarness::from_source(source).unwrap();
+    harness.force("shared", 7i16);
+    harness.advance_time(Duration::from_millis(10));
+    harness.cycle();
+    harness.assert_eq("shared", 7i16);
+    harness.assert_eq("seen", 7i16);
+
+    harness.release("shared");
+    harness.advance_time(Duration::from_millis(10));
+    harness.cycle();
+    harness.assert_eq("seen", 1i16);
+}
+
+#[test]
+fn forced_variable_reaches_the_output_image() {
+    let source = r#"
+PROGRAM Main
+VAR
+    out AT %QX0.1 : BOOL;
+END_VAR
+out := TRUE;
+END_PROGRAM
+"#;
+
+    let mut harness = TestHarness::from_source(source).unwrap();
+    harness.force("out", false);
+    harness.cycle();
+    assert_eq!(
+        harness.get_direct_output("%QX0.1").unwrap(),
+        Value::Bool(false)
+    );
+
+    harness.release("out");
+    harness.cycle();
+    assert_eq!(
+        harness.get_direct_output("%QX0.1").unwrap(),
+        Value::Bool(true)
+    );
+}
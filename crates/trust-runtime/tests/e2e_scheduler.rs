@@ -138,8 +138,9 @@ END_PROGRAM
 PROGRAM FaultProg
 VAR
     x : INT := 0;
+    zero : INT := 0;
 END_VAR
-x := 1 / 0;
+x := 1 / zero;
 END_PROGRAM
 "#;
 
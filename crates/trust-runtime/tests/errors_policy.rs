@@ -7,8 +7,9 @@ fn error_policy() {
 PROGRAM Main
 VAR
     x : DINT := 0;
+    zero : DINT := 0;
 END_VAR
-x := 1 / 0;
+x := 1 / zero;
 END_PROGRAM
 "#;
 
@@ -16,3 +17,122 @@ END_PROGRAM
     let result = harness.cycle();
     assert!(result.errors.contains(&RuntimeError::DivisionByZero));
 }
+
+fn fault_statement(harness: &TestHarness, source: &str) -> String {
+    let location = harness
+        .runtime()
+        .last_fault_location()
+        .expect("fault location");
+    source[location.start as usize..location.end as usize]
+        .trim()
+        .to_string()
+}
+
+#[test]
+fn dynamic_division_by_zero_reports_statement_location() {
+    let source = r#"
+PROGRAM Main
+VAR
+    zero : INT := 0;
+    x : INT;
+END_VAR
+x := 1;
+x := 5 / zero;
+END_PROGRAM
+"#;
+
+    let mut harness = TestHarness::from_source(source).unwrap();
+    let result = harness.cycle();
+    assert_eq!(result.errors, vec![RuntimeError::DivisionByZero]);
+    assert_eq!(fault_statement(&harness, source), "x := 5 / zero;");
+}
+
+#[test]
+fn dynamic_modulo_by_zero_reports_statement_location() {
+    let source = r#"
+PROGRAM Main
+VAR
+    zero : INT := 0;
+    x : INT;
+END_VAR
+IF x = 0 THEN
+    x := 5 MOD zero;
+END_IF;
+END_PROGRAM
+"#;
+
+    let mut harness = TestHarness::from_source(source).unwrap();
+    let result = harness.cycle();
+    assert_eq!(result.errors, vec![RuntimeError::ModuloByZero]);
+    assert_eq!(fault_statement(&harness, source), "x := 5 MOD zero;");
+}
+
+#[test]
+fn constant_division_by_zero_is_rejected_at_compile_time() {
+    for expr in ["5 / 0", "5 MOD 0"] {
+        let source =
+            format!("PROGRAM Main\nVAR\n    x : INT;\nEND_VAR\nx := {expr};\nEND_PROGRAM\n");
+        assert!(
+            TestHarness::from_source(&source).is_err(),
+            "expected '{expr}' to fail compilation"
+        );
+    }
+}
+
+#[test]
+fn nested_calls_beyond_the_configured_depth_fail_cleanly() {
+    let source = r#"
+FUNCTION Level5 : INT
+Level5 := INT#5;
+END_FUNCTION
+
+FUNCTION Level4 : INT
+Level4 := Level5();
+END_FUNCTION
+
+FUNCTION Level3 : INT
+Level3 := Level4();
+END_FUNCTION
+
+FUNCTION Level2 : INT
+Level2 := Level3();
+END_FUNCTION
+
+FUNCTION Level1 : INT
+Level1 := Level2();
+END_FUNCTION
+
+PROGRAM Main
+VAR
+    x : INT;
+END_VAR
+x := Level1();
+END_PROGRAM
+"#;
+
+    let mut harness = TestHarness::from_source(source).unwrap();
+    harness.runtime_mut().set_max_call_depth(5);
+    let result = harness.cycle();
+    assert!(result.errors.is_empty(), "{:?}", result.errors);
+    harness.assert_eq("x", 5i16);
+
+    harness.runtime_mut().set_max_call_depth(4);
+    let result = harness.cycle();
+    let [RuntimeError::CallDepthExceeded { limit, chain }] = result.errors.as_slice() else {
+        panic!("expected call depth error, got {:?}", result.errors);
+    };
+    assert_eq!(*limit, 4);
+    assert!(
+        chain.ends_with(&[
+            "Level1".into(),
+            "Level2".into(),
+            "Level3".into(),
+            "Level4".into(),
+            "Level5".into(),
+        ]),
+        "unexpected call chain {chain:?}"
+    );
+    assert!(result.errors[0]
+        .to_string()
+        .ends_with("Level1 -> Level2 -> Level3 -> Level4 -> Level5"));
+}
@@ -48,6 +48,32 @@ fn function_call_named_args() {
     harness.assert_eq("res", 5i16);
 }
 
+#[test]
+fn function_call_generic_any_num_dispatches_on_argument_type() {
+    let source = r#"
+        FUNCTION Twice : ANY_NUM
+        VAR_INPUT
+            x : ANY_NUM;
+        END_VAR
+        Twice := x + x;
+        END_FUNCTION
+
+        PROGRAM Test
+        VAR
+            ri : INT := 0;
+            rr : REAL := 0.0;
+        END_VAR
+        ri := Twice(INT#3);
+        rr := Twice(REAL#1.5);
+        END_PROGRAM
+    "#;
+
+    let mut harness = TestHarness::from_source(source).unwrap();
+    harness.cycle();
+    harness.assert_eq("ri", 6i16);
+    harness.assert_eq("rr", trust_runtime::value::Value::Real(3.0));
+}
+
 #[test]
 fn function_call_output_positional() {
     let source = r#"
@@ -212,3 +238,240 @@ fn function_in_out_without_conversion_expression_baseline() {
     );
     harness.assert_eq("i", 104u8);
 }
+
+#[test]
+fn function_call_named_args_reverse_order_coerce_per_parameter() {
+    let source = r#"
+        FUNCTION Scale : REAL
+        VAR_INPUT
+            base : DINT;
+            factor : REAL;
+            offset : DINT;
+        END_VAR
+        Scale := DINT_TO_REAL(base + offset) * factor;
+        END_FUNCTION
+
+        PROGRAM Test
+        VAR
+            small : INT := 3;
+            res : REAL;
+        END_VAR
+        res := Scale(OFFSET := small, factor := 2.5, Base := 1);
+        END_PROGRAM
+    "#;
+
+    let mut harness = TestHarness::from_source(source).unwrap();
+    let cycle = harness.cycle();
+    assert!(cycle.errors.is_empty(), "{:?}", cycle.errors);
+    harness.assert_eq("res", trust_runtime::value::Value::Real(10.0));
+}
+
+#[test]
+fn function_call_leading_positional_then_named_args() {
+    let source = r#"
+        FUNCTION Sub3 : INT
+        VAR_INPUT
+            a : INT;
+            b : INT;
+            c : INT;
+        END_VAR
+        Sub3 := a - b - c;
+        END_FUNCTION
+
+        PROGRAM Test
+        VAR
+            res : INT := 0;
+        END_VAR
+        res := Sub3(INT#10, c := INT#1, b := INT#4);
+        END_PROGRAM
+    "#;
+
+    let mut harness = TestHarness::from_source(source).unwrap();
+    let cycle = harness.cycle();
+    assert!(cycle.errors.is_empty(), "{:?}", cycle.errors);
+    harness.assert_eq("res", 5i16);
+}
+
+#[test]
+fn function_call_named_args_missing_in_out_is_rejected() {
+    let source = r#"
+        FUNCTION Bump : INT
+        VAR_IN_OUT
+            counter : INT;
+        END_VAR
+        VAR_INPUT
+            amount : INT;
+        END_VAR
+        counter := counter + amount;
+        Bump := counter;
+        END_FUNCTION
+
+        PROGRAM Test
+        VAR
+            res : INT := 0;
+        END_VAR
+        res := Bump(amount := INT#1);
+        END_PROGRAM
+    "#;
+
+    let err = TestHarness::from_source(source)
+        .err()
+        .expect("missing in-out binding should fail to compile");
+    assert!(
+        err.to_string().contains("counter"),
+        "unexpected error: {err}"
+    );
+}
+
+#[test]
+fn function_call_omitted_input_uses_declared_default() {
+    let source = r#"
+        FUNCTION Scale : INT
+        VAR_INPUT
+            value : INT;
+            factor : INT := INT#3;
+        END_VAR
+        Scale := value * factor;
+        END_FUNCTION
+
+        PROGRAM Test
+        VAR
+            res : INT := 0;
+        END_VAR
+        res := Scale(value := INT#5);
+        END_PROGRAM
+    "#;
+
+    let mut harness = TestHarness::from_source(source).unwrap();
+    let cycle = harness.cycle();
+    assert!(cycle.errors.is_empty(), "{:?}", cycle.errors);
+    harness.assert_eq("res", 15i16);
+}
+
+#[test]
+fn function_call_omitted_input_without_default_is_rejected() {
+    let source = r#"
+        FUNCTION Scale : INT
+        VAR_INPUT
+            value : INT;
+            factor : INT;
+        END_VAR
+        Scale := value * factor;
+        END_FUNCTION
+
+        PROGRAM Test
+        VAR
+            res : INT := 0;
+        END_VAR
+        res := Scale(value := INT#5);
+        END_PROGRAM
+    "#;
+
+    let err = TestHarness::from_source(source)
+        .err()
+        .expect("missing input without default should fail to compile");
+    assert!(
+        err.to_string().contains("factor"),
+        "unexpected error: {err}"
+    );
+}
+
+#[test]
+fn function_block_omitted_input_keeps_last_value() {
+    let source = r#"
+        FUNCTION_BLOCK Scale
+        VAR_INPUT
+            value : INT;
+            factor : INT := INT#2;
+        END_VAR
+        VAR_OUTPUT
+            result : INT;
+        END_VAR
+        result := value * factor;
+        END_FUNCTION_BLOCK
+
+        PROGRAM Test
+        VAR
+            fb : Scale;
+            first : INT := 0;
+            second : INT := 0;
+            third : INT := 0;
+        END_VAR
+        fb(value := INT#4, result => first);
+        fb(factor := INT#5, result => second);
+        fb(value := INT#1, result => third);
+        END_PROGRAM
+    "#;
+
+    let mut harness = TestHarness::from_source(source).unwrap();
+    let cycle = harness.cycle();
+    assert!(cycle.errors.is_empty(), "{:?}", cycle.errors);
+    harness.assert_eq("first", 8i16);
+    harness.assert_eq("second", 20i16);
+    harness.assert_eq("third", 5i16);
+}
+
+#[test]
+fn sel_evaluates_only_selected_operand() {
+    let source = r#"
+        FUNCTION Mark : INT
+        VAR_IN_OUT
+            hits : INT;
+        END_VAR
+        VAR_INPUT
+            value : INT;
+        END_VAR
+        hits := hits + INT#1;
+        Mark := value;
+        END_FUNCTION
+
+        PROGRAM Test
+        VAR
+            f_hits : INT := 0;
+            g_hits : INT := 0;
+            out : INT := 0;
+        END_VAR
+        out := SEL(TRUE, Mark(f_hits, INT#1), Mark(g_hits, INT#2));
+        END_PROGRAM
+    "#;
+
+    let mut harness = TestHarness::from_source(source).unwrap();
+    harness.cycle();
+    harness.assert_eq("out", 2i16);
+    harness.assert_eq("f_hits", 0i16);
+    harness.assert_eq("g_hits", 1i16);
+}
+
+#[test]
+fn mux_evaluates_only_indexed_operand() {
+    let source = r#"
+        FUNCTION Mark : INT
+        VAR_IN_OUT
+            hits : INT;
+        END_VAR
+        VAR_INPUT
+            value : INT;
+        END_VAR
+        hits := hits + INT#1;
+        Mark := value;
+        END_FUNCTION
+
+        PROGRAM Test
+        VAR
+            hits0 : INT := 0;
+            hits1 : INT := 0;
+            hits2 : INT := 0;
+            out : INT := 0;
+        END_VAR
+        out := MUX(K := 1, IN0 := Mark(hits0, INT#10), IN1 := Mark(hits1, INT#20),
+            IN2 := Mark(hits2, INT#30));
+        END_PROGRAM
+    "#;
+
+    let mut harness = TestHarness::from_source(source).unwrap();
+    harness.cycle();
+    harness.assert_eq("out", 20i16);
+    harness.assert_eq("hits0", 0i16);
+    harness.assert_eq("hits1", 1i16);
+    harness.assert_eq("hits2", 0i16);
+}
@@ -78,3 +78,36 @@ fn super_uses_parent_instance() {
     let value = eval_expr(&mut ctx, &Expr::Super).unwrap();
     assert_eq!(value, Value::Instance(parent));
 }
+
+#[test]
+fn this_deref_reads_current_instance() {
+    let source = r#"
+        FUNCTION_BLOCK Counter
+        VAR
+            count : INT := INT#5;
+        END_VAR
+        VAR_OUTPUT
+            plain : INT;
+            deref : INT;
+        END_VAR
+        count := count + INT#1;
+        plain := THIS.count;
+        deref := THIS^.count;
+        END_FUNCTION_BLOCK
+
+        PROGRAM Test
+        VAR
+            fb : Counter;
+            plain : INT;
+            deref : INT;
+        END_VAR
+        fb(plain => plain, deref => deref);
+        END_PROGRAM
+    "#;
+
+    let mut harness = TestHarness::from_source(source).unwrap();
+    let result = harness.cycle();
+    assert!(result.errors.is_empty(), "{:?}", result.errors);
+    harness.assert_eq("plain", 6i16);
+    harness.assert_eq("deref", 6i16);
+}
@@ -2,6 +2,7 @@ mod common;
 
 use trust_hir::types::TypeRegistry;
 use trust_runtime::eval::{eval_expr, expr::Expr};
+use trust_runtime::harness::TestHarness;
 use trust_runtime::memory::VariableStorage;
 use trust_runtime::value::Value;
 
@@ -28,3 +29,77 @@ fn name_ref_eval() {
     let value = eval_expr(&mut ctx, &expr).unwrap();
     assert_eq!(value, Value::Int(7));
 }
+
+#[test]
+fn string_literal_escapes_decode_at_runtime() {
+    let source = r#"
+PROGRAM Main
+VAR
+    crlf : STRING := 'a$R$Lb';
+    quote : STRING;
+    wide : WSTRING;
+    mixed : STRING;
+    len_crlf : INT;
+END_VAR
+quote := '$27';
+wide := "$00C4$N";
+mixed := 'é$T$$';
+len_crlf := LEN(crlf);
+END_PROGRAM
+"#;
+    let mut harness = TestHarness::from_source(source).unwrap();
+    let result = harness.cycle();
+    assert!(result.errors.is_empty(), "{:?}", result.errors);
+    harness.assert_eq("crlf", Value::String("a\r\nb".into()));
+    harness.assert_eq("quote", Value::String("'".into()));
+    harness.assert_eq("wide", Value::WString("\u{00C4}\n".into()));
+    harness.assert_eq("mixed", Value::String("é\t$".into()));
+    harness.assert_eq("len_crlf", Value::Int(4));
+}
+
+#[test]
+fn compile_time_builtins_expand_to_pou_name_and_line() {
+    let source = r#"
+FUNCTION Describe : STRING
+Describe := __POUNAME;
+END_FUNCTION
+
+PROGRAM Main
+VAR
+    name : STRING;
+    top : INT;
+    nested : INT;
+    i : INT;
+END_VAR
+name := Describe();
+top := __LINE;
+IF TRUE THEN
+    FOR i := 1 TO 2 DO
+        nested :=
+            __line;
+    END_FOR;
+END_IF;
+END_PROGRAM
+"#;
+    let mut harness = TestHarness::from_source(source).unwrap();
+    let result = harness.cycle();
+    assert!(result.errors.is_empty(), "{:?}", result.errors);
+    harness.assert_eq("name", Value::String("Describe".into()));
+    harness.assert_eq("top", Value::Int(14));
+    harness.assert_eq("nested", Value::Int(18));
+}
+
+#[test]
+fn invalid_string_escape_fails_to_compile() {
+    let source = r#"
+PROGRAM Main
+VAR
+    s : STRING;
+END_VAR
+s := 'a$Qb';
+END_PROGRAM
+"#;
+    // The lexer does not accept `$Q` inside a string, so the source never
+    // reaches lowering.
+    assert!(TestHarness::from_source(source).is_err());
+}
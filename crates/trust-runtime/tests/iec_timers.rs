@@ -93,3 +93,41 @@ fn timing_diagrams() {
     harness.assert_eq("q_tp", Value::Bool(true));
     harness.assert_eq("et_tp", Value::Time(Duration::from_millis(5)));
 }
+
+#[test]
+fn output_read_after_call_sees_current_cycle_state() {
+    let source = r#"
+        PROGRAM Test
+        VAR
+            timer : TON;
+            start : BOOL;
+            q_before : BOOL;
+            q_after : BOOL;
+            et_after : TIME;
+            fired : BOOL;
+        END_VAR
+        q_before := timer.Q;
+        timer(IN := start, PT := T#10ms);
+        IF timer.Q THEN
+            fired := TRUE;
+        END_IF
+        q_after := timer.Q;
+        et_after := timer.ET;
+        END_PROGRAM
+    "#;
+
+    let mut harness = TestHarness::from_source(source).unwrap();
+    harness.set_input("start", true);
+    harness.cycle();
+    harness.assert_eq("q_after", Value::Bool(false));
+    harness.assert_eq("fired", Value::Bool(false));
+
+    // The call that reaches PT must be visible to reads later in the same cycle.
+    harness.advance_time(Duration::from_millis(10));
+    let result = harness.cycle();
+    assert!(result.errors.is_empty(), "{:?}", result.errors);
+    harness.assert_eq("q_before", Value::Bool(false));
+    harness.assert_eq("q_after", Value::Bool(true));
+    harness.assert_eq("et_after", Value::Time(Duration::from_millis(10)));
+    harness.assert_eq("fired", Value::Bool(true));
+}
@@ -0,0 +1,64 @@
+use trust_runtime::harness::TestHarness;
+
+fn listed(entries: &[trust_runtime::io::IoLayoutEntry]) -> Vec<(String, String, String)> {
+    entries
+        .iter()
+        .map(|entry| {
+            (
+                entry.address.to_string(),
+                entry.name.as_deref().unwrap_or_default().to_string(),
+                entry.type_name.to_string(),
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn io_layout_lists_bound_variables_sorted_by_address() {
+    let source = r#"
+PROGRAM Main
+VAR
+    level AT %IW2 : INT;
+    stop AT %IX0.1 : BOOL;
+    start AT %IX0.0 : BOOL;
+    speed AT %QD4 : DINT;
+    motor AT %QX0.0 : BOOL;
+    lamp AT %Q* : BOOL;
+END_VAR
+END_PROGRAM
+
+CONFIGURATION Conf
+PROGRAM P1 : Main;
+VAR_CONFIG
+    P1.lamp AT %QX0.3 : BOOL;
+END_VAR
+END_CONFIGURATION
+"#;
+
+    let harness = TestHarness::from_source(source).unwrap();
+    let layout = harness.io_layout();
+
+    let owned = |entries: &[(&str, &str, &str)]| {
+        entries
+            .iter()
+            .map(|(a, n, t)| (a.to_string(), n.to_string(), t.to_string()))
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(
+        listed(&layout.inputs),
+        owned(&[
+            ("%IX0.0", "P1.start", "BOOL"),
+            ("%IX0.1", "P1.stop", "BOOL"),
+            ("%IW2", "P1.level", "INT"),
+        ])
+    );
+    assert_eq!(
+        listed(&layout.outputs),
+        owned(&[
+            ("%QX0.0", "P1.motor", "BOOL"),
+            ("%QX0.3", "P1.lamp", "BOOL"),
+            ("%QD4", "P1.speed", "DINT"),
+        ])
+    );
+    assert!(layout.memory.is_empty());
+}
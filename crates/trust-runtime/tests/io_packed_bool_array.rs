@@ -0,0 +1,129 @@
+use trust_hir::types::TypeRegistry;
+use trust_hir::TypeId;
+use trust_runtime::harness::{CompileSession, TestHarness};
+use trust_runtime::value::{size_of_type, size_of_type_with_layout, BoolArrayLayout, Value};
+
+#[test]
+fn packed_bool_array_size_rounds_up_to_bytes() {
+    let mut registry = TypeRegistry::new();
+    let flags = registry.register_array(TypeId::BOOL, vec![(0, 9)]);
+    let words = registry.register_array(TypeId::INT, vec![(0, 9)]);
+
+    assert_eq!(
+        size_of_type_with_layout(flags, &registry, BoolArrayLayout::Packed),
+        Ok(2)
+    );
+    assert_eq!(size_of_type(flags, &registry), Ok(10));
+    assert_eq!(
+        size_of_type_with_layout(words, &registry, BoolArrayLayout::Packed),
+        Ok(20)
+    );
+}
+
+#[test]
+fn packed_bool_array_writes_set_bits() {
+    let source = r#"
+PROGRAM Main
+VAR
+    lamps AT %QX4.2 : ARRAY[0..9] OF BOOL;
+END_VAR
+lamps[0] := TRUE;
+lamps[5] := TRUE;
+lamps[9] := TRUE;
+END_PROGRAM
+"#;
+
+    let session = CompileSession::from_source(source).bool_array_layout(BoolArrayLayout::Packed);
+    let mut harness = TestHarness::from_session(&session).unwrap();
+    harness.cycle();
+
+    // Elements start at bit 2 of byte 4: 0 -> %QX4.2, 5 -> %QX4.7, 9 -> %QX5.3.
+    assert_eq!(
+        harness.get_direct_output("%QB4").unwrap(),
+        Value::Byte(0b1000_0100)
+    );
+    assert_eq!(
+        harness.get_direct_output("%QB5").unwrap(),
+        Value::Byte(0b0000_1000)
+    );
+    assert_eq!(
+        harness.get_direct_output("%QX5.3").unwrap(),
+        Value::Bool(true)
+    );
+}
+
+#[test]
+fn packed_bool_array_reads_bits() {
+    let source = r#"
+PROGRAM Main
+VAR
+    inputs AT %IX0.0 : ARRAY[0..15] OF BOOL;
+    b1 : BOOL;
+    b2 : BOOL;
+    b9 : BOOL;
+END_VAR
+b1 := inputs[1];
+b2 := inputs[2];
+b9 := inputs[9];
+END_PROGRAM
+"#;
+
+    let session = CompileSession::from_source(source).bool_array_layout(BoolArrayLayout::Packed);
+    let mut harness = TestHarness::from_session(&session).unwrap();
+    harness
+        .set_direct_input("%IB0", Value::Byte(0b0000_0010))
+        .unwrap();
+    harness
+        .set_direct_input("%IB1", Value::Byte(0b0000_0010))
+        .unwrap();
+    harness.cycle();
+
+    harness.assert_eq("b1", true);
+    harness.assert_eq("b2", false);
+    harness.assert_eq("b9", true);
+}
+
+#[test]
+fn bit_addressed_bool_array_is_unpacked_by_default() {
+    let source = r#"
+PROGRAM Main
+VAR
+    lamps AT %QX4.2 : ARRAY[0..9] OF BOOL;
+END_VAR
+lamps[0] := TRUE;
+lamps[5] := TRUE;
+END_PROGRAM
+"#;
+
+    let mut harness = TestHarness::from_source(source).unwrap();
+    harness.cycle();
+
+    // Without the opt-in each element keeps its own byte: 5 -> %QX9.2.
+    assert_eq!(
+        harness.get_direct_output("%QB4").unwrap(),
+        Value::Byte(0b0000_0100)
+    );
+    assert_eq!(
+        harness.get_direct_output("%QX9.2").unwrap(),
+        Value::Bool(true)
+    );
+}
+
+#[test]
+fn byte_addressed_bool_array_stays_unpacked() {
+    let source = r#"
+PROGRAM Main
+VAR
+    flags AT %QB0 : ARRAY[0..2] OF BOOL;
+END_VAR
+flags[1] := TRUE;
+END_PROGRAM
+"#;
+
+    let session = CompileSession::from_source(source).bool_array_layout(BoolArrayLayout::Packed);
+    let mut harness = TestHarness::from_session(&session).unwrap();
+    harness.cycle();
+
+    assert_eq!(harness.get_direct_output("%QB0").unwrap(), Value::Byte(0));
+    assert_eq!(harness.get_direct_output("%QB1").unwrap(), Value::Byte(1));
+}
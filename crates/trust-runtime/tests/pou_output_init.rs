@@ -0,0 +1,77 @@
+use trust_runtime::harness::TestHarness;
+
+#[test]
+fn fb_output_initializer_applies_before_first_call_and_persists() {
+    let source = r#"
+FUNCTION_BLOCK FB_Out
+VAR_INPUT
+    inc : BOOL;
+END_VAR
+VAR_OUTPUT
+    q : INT := 7;
+END_VAR
+IF inc THEN
+    q := q + INT#1;
+END_IF
+END_FUNCTION_BLOCK
+
+PROGRAM Main
+VAR
+    fb : FB_Out;
+    before : INT;
+    after_first : INT;
+    after_second : INT;
+END_VAR
+before := fb.q;
+fb(inc := TRUE);
+after_first := fb.q;
+fb(inc := FALSE);
+after_second := fb.q;
+END_PROGRAM
+"#;
+
+    let mut harness = TestHarness::from_source(source).unwrap();
+    harness.cycle();
+    harness.assert_eq("before", 7i16);
+    harness.assert_eq("after_first", 8i16);
+    harness.assert_eq("after_second", 8i16);
+
+    harness.cycle();
+    harness.assert_eq("before", 8i16);
+    harness.assert_eq("after_first", 9i16);
+}
+
+#[test]
+fn function_output_initializer_is_fresh_each_call() {
+    let source = r#"
+FUNCTION F_Out : INT
+VAR_INPUT
+    bump : BOOL;
+END_VAR
+VAR_OUTPUT
+    o : INT := 5;
+END_VAR
+IF bump THEN
+    o := o + INT#1;
+END_IF
+F_Out := o;
+END_FUNCTION
+
+PROGRAM Main
+VAR
+    r1 : INT;
+    r2 : INT;
+    r3 : INT;
+END_VAR
+F_Out(bump := TRUE, o => r1);
+F_Out(bump := TRUE, o => r2);
+F_Out(bump := FALSE, o => r3);
+END_PROGRAM
+"#;
+
+    let mut harness = TestHarness::from_source(source).unwrap();
+    harness.cycle();
+    harness.assert_eq("r1", 6i16);
+    harness.assert_eq("r2", 6i16);
+    harness.assert_eq("r3", 5i16);
+}
@@ -0,0 +1,85 @@
+use trust_runtime::harness::TestHarness;
+use trust_runtime::value::Value;
+use trust_runtime::RestartMode;
+
+fn global(harness: &TestHarness, name: &str) -> Option<Value> {
+    harness.runtime().storage().get_global(name).cloned()
+}
+
+#[test]
+fn retain_global_survives_warm_restart() {
+    let source = r#"
+PROGRAM Main
+VAR_EXTERNAL
+    kept : INT;
+    stored : INT;
+    plain : INT;
+END_VAR
+kept := kept + INT#1;
+stored := stored + INT#1;
+plain := plain + INT#1;
+END_PROGRAM
+
+CONFIGURATION Plant
+VAR_GLOBAL RETAIN
+    kept : INT := 10;
+END_VAR
+VAR_GLOBAL PERSISTENT
+    stored : INT := 20;
+END_VAR
+VAR_GLOBAL
+    plain : INT := 30;
+END_VAR
+RESOURCE R1 ON CPU
+PROGRAM P1 : Main;
+END_RESOURCE
+END_CONFIGURATION
+"#;
+    let mut harness = TestHarness::from_source(source).unwrap();
+    let result = harness.cycle();
+    assert!(result.errors.is_empty(), "{:?}", result.errors);
+
+    harness.restart(RestartMode::Warm).unwrap();
+    assert_eq!(global(&harness, "kept"), Some(Value::Int(11)));
+    assert_eq!(global(&harness, "stored"), Some(Value::Int(21)));
+    assert_eq!(global(&harness, "plain"), Some(Value::Int(30)));
+}
+
+#[test]
+fn persistent_global_survives_cold_restart() {
+    let source = r#"
+PROGRAM Main
+VAR_EXTERNAL
+    kept : INT;
+    stored : INT;
+    plain : INT;
+END_VAR
+kept := kept + INT#1;
+stored := stored + INT#1;
+plain := plain + INT#1;
+END_PROGRAM
+
+CONFIGURATION Plant
+VAR_GLOBAL RETAIN
+    kept : INT := 10;
+END_VAR
+VAR_GLOBAL PERSISTENT
+    stored : INT := 20;
+END_VAR
+VAR_GLOBAL
+    plain : INT := 30;
+END_VAR
+RESOURCE R1 ON CPU
+PROGRAM P1 : Main;
+END_RESOURCE
+END_CONFIGURATION
+"#;
+    let mut harness = TestHarness::from_source(source).unwrap();
+    let result = harness.cycle();
+    assert!(result.errors.is_empty(), "{:?}", result.errors);
+
+    harness.restart(RestartMode::Cold).unwrap();
+    assert_eq!(global(&harness, "kept"), Some(Value::Int(10)));
+    assert_eq!(global(&harness, "stored"), Some(Value::Int(21)));
+    assert_eq!(global(&harness, "plain"), Some(Value::Int(30)));
+}
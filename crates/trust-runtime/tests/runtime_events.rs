@@ -2,6 +2,7 @@ use trust_runtime::debug::RuntimeEvent;
 use trust_runtime::eval::expr::{Expr, LValue};
 use trust_runtime::eval::ops::BinaryOp;
 use trust_runtime::eval::stmt::Stmt;
+use trust_runtime::harness::TestHarness;
 use trust_runtime::task::{ProgramDef, TaskConfig};
 use trust_runtime::value::{Duration, Value};
 use trust_runtime::Runtime;
@@ -140,3 +141,73 @@ fn runtime_event_fault_emitted() {
         .any(|event| matches!(event, RuntimeEvent::Fault { .. })));
     assert!(runtime.faulted());
 }
+
+fn uninitialized_reads(events: Vec<RuntimeEvent>) -> Vec<String> {
+    events
+        .into_iter()
+        .filter_map(|event| match event {
+            RuntimeEvent::UninitializedRead { name, .. } => Some(name.to_ascii_uppercase()),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn uninitialized_local_read_emits_event_in_debug_mode() {
+    let source = r#"
+    FUNCTION Peek : INT
+    VAR
+        scratch : INT;
+        seeded : INT := INT#3;
+        written : INT;
+    END_VAR
+    written := INT#1;
+    Peek := scratch + seeded + written;
+    END_FUNCTION
+
+    PROGRAM Main
+    VAR
+        out : INT;
+    END_VAR
+    out := Peek();
+    END_PROGRAM
+"#;
+    let mut harness = TestHarness::from_source(source).unwrap();
+    harness.runtime_mut().set_uninitialized_read_checks(true);
+    let control = harness.runtime_mut().enable_debug();
+
+    let result = harness.cycle();
+    assert!(result.errors.is_empty(), "{:?}", result.errors);
+    harness.assert_eq("out", 4i16);
+    assert_eq!(
+        uninitialized_reads(control.drain_runtime_events()),
+        vec!["SCRATCH".to_string()]
+    );
+}
+
+#[test]
+fn uninitialized_local_reads_are_not_reported_by_default() {
+    let source = r#"
+    FUNCTION Peek : INT
+    VAR
+        scratch : INT;
+        seeded : INT := INT#3;
+        written : INT;
+    END_VAR
+    written := 1;
+    Peek := scratch + seeded + written;
+    END_FUNCTION
+
+    PROGRAM Main
+    VAR
+        out : INT;
+    END_VAR
+    out := Peek();
+    END_PROGRAM
+"#;
+    let mut harness = TestHarness::from_source(source).unwrap();
+    let control = harness.runtime_mut().enable_debug();
+
+    harness.cycle();
+    assert!(uninitialized_reads(control.drain_runtime_events()).is_empty());
+}
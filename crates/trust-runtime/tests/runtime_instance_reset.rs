@@ -0,0 +1,124 @@
+use trust_runtime::error::RuntimeError;
+use trust_runtime::harness::TestHarness;
+use trust_runtime::value::Value;
+use trust_runtime::RestartMode;
+
+fn instance_var(harness: &TestHarness, path: &str) -> Option<Value> {
+    let storage = harness.runtime().storage();
+    let mut segments = path.split('.');
+    let mut value = storage.get_global(segments.next()?)?.clone();
+    for segment in segments {
+        let Value::Instance(id) = value else {
+            return None;
+        };
+        value = storage.get_instance_var(id, segment)?.clone();
+    }
+    Some(value)
+}
+
+#[test]
+fn reset_counter_instance_leaves_other_instances_untouched() {
+    let source = r#"
+PROGRAM Main
+VAR
+    pulse : BOOL;
+    ctr : CTU;
+    other : CTU;
+END_VAR
+pulse := NOT pulse;
+ctr(CU := pulse, PV := INT#100);
+other(CU := pulse, PV := INT#100);
+END_PROGRAM
+"#;
+
+    let mut harness = TestHarness::from_source(source).unwrap();
+    harness.run_cycles(6);
+    assert_eq!(instance_var(&harness, "Main.ctr.CV"), Some(Value::Int(3)));
+    assert_eq!(instance_var(&harness, "Main.other.CV"), Some(Value::Int(3)));
+
+    harness
+        .runtime_mut()
+        .reset_instance("Main.ctr", RestartMode::Warm)
+        .expect("reset counter");
+    assert_eq!(instance_var(&harness, "Main.ctr.CV"), Some(Value::Int(0)));
+    assert_eq!(instance_var(&harness, "Main.ctr.PV"), Some(Value::Int(0)));
+    assert_eq!(instance_var(&harness, "Main.other.CV"), Some(Value::Int(3)));
+    assert_eq!(
+        instance_var(&harness, "Main.pulse"),
+        Some(Value::Bool(false))
+    );
+
+    harness.run_cycles(2);
+    assert_eq!(instance_var(&harness, "Main.ctr.CV"), Some(Value::Int(1)));
+    assert_eq!(instance_var(&harness, "Main.other.CV"), Some(Value::Int(4)));
+}
+
+#[test]
+fn reset_instance_keeps_retained_members_on_warm_reset() {
+    let source = r#"
+FUNCTION_BLOCK Tally
+VAR
+    cv : INT := INT#0;
+END_VAR
+VAR RETAIN
+    total : INT := INT#0;
+END_VAR
+cv := cv + INT#1;
+total := total + INT#1;
+END_FUNCTION_BLOCK
+
+PROGRAM Main
+VAR
+    tally : Tally;
+END_VAR
+tally();
+END_PROGRAM
+"#;
+
+    let mut harness = TestHarness::from_source(source).unwrap();
+    harness.run_cycles(4);
+
+    harness
+        .runtime_mut()
+        .reset_instance("Main.tally", RestartMode::Warm)
+        .expect("warm reset");
+    assert_eq!(instance_var(&harness, "Main.tally.cv"), Some(Value::Int(0)));
+    assert_eq!(
+        instance_var(&harness, "Main.tally.total"),
+        Some(Value::Int(4))
+    );
+
+    harness.cycle();
+    harness
+        .runtime_mut()
+        .reset_instance("Main.tally", RestartMode::Cold)
+        .expect("cold reset");
+    assert_eq!(instance_var(&harness, "Main.tally.cv"), Some(Value::Int(0)));
+    assert_eq!(
+        instance_var(&harness, "Main.tally.total"),
+        Some(Value::Int(0))
+    );
+}
+
+#[test]
+fn reset_instance_rejects_unknown_and_non_instance_paths() {
+    let source = r#"
+PROGRAM Main
+VAR
+    x : INT;
+    ctr : CTU;
+END_VAR
+END_PROGRAM
+"#;
+
+    let mut harness = TestHarness::from_source(source).unwrap();
+    let runtime = harness.runtime_mut();
+    assert!(matches!(
+        runtime.reset_instance("Main.missing", RestartMode::Warm),
+        Err(RuntimeError::UndefinedVariable(_))
+    ));
+    assert!(matches!(
+        runtime.reset_instance("Main.x", RestartMode::Warm),
+        Err(RuntimeError::TypeMismatch)
+    ));
+}
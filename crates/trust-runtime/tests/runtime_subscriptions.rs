@@ -0,0 +1,65 @@
+use trust_runtime::harness::TestHarness;
+use trust_runtime::value::Value;
+
+#[test]
+fn subscription_reports_only_changed_paths() {
+    let source = r#"
+PROGRAM Main
+VAR
+    counter : INT := 0;
+    setpoint : INT := 10;
+END_VAR
+counter := counter + INT#1;
+END_PROGRAM
+"#;
+
+    let mut harness = TestHarness::from_source(source).unwrap();
+    let id = harness
+        .runtime_mut()
+        .subscribe_changes(["Main.counter", "Main.setpoint"]);
+
+    harness.cycle();
+    let changes = harness.runtime_mut().take_changes(id);
+    assert_eq!(changes.len(), 1);
+    assert_eq!(changes[0].path.as_str(), "Main.counter");
+    assert_eq!(changes[0].value, Value::Int(1));
+
+    harness.run_cycles(2);
+    let changes = harness.runtime_mut().take_changes(id);
+    assert_eq!(changes.len(), 1);
+    assert_eq!(changes[0].path.as_str(), "Main.counter");
+    assert_eq!(changes[0].value, Value::Int(3));
+
+    assert!(harness.runtime_mut().take_changes(id).is_empty());
+    assert!(harness.runtime_mut().unsubscribe_changes(id));
+    harness.cycle();
+    assert!(harness.runtime_mut().take_changes(id).is_empty());
+}
+
+#[test]
+fn subscription_paths_ignore_case_and_skip_values_that_changed_back() {
+    let source = r#"
+PROGRAM Main
+VAR
+    flag : BOOL;
+END_VAR
+flag := NOT flag;
+END_PROGRAM
+"#;
+
+    let mut harness = TestHarness::from_source(source).unwrap();
+    let id = harness.runtime_mut().subscribe_changes(["main.FLAG"]);
+
+    // TRUE then back to FALSE before anyone drained: nothing to report.
+    harness.run_cycles(2);
+    assert!(harness.runtime_mut().take_changes(id).is_empty());
+
+    harness.cycle();
+    let changes = harness.runtime_mut().take_changes(id);
+    assert_eq!(changes.len(), 1);
+    assert_eq!(changes[0].path.as_str(), "main.FLAG");
+    assert_eq!(changes[0].value, Value::Bool(true));
+
+    harness.run_cycles(2);
+    assert!(harness.runtime_mut().take_changes(id).is_empty());
+}
@@ -0,0 +1,85 @@
+use trust_hir::db::SemanticDatabase;
+use trust_hir::{Project, SourceKey};
+use trust_runtime::sarif::{project_diagnostics_to_sarif, SARIF_VERSION};
+
+fn project(sources: &[(&str, &str)]) -> Project {
+    let mut project = Project::new();
+    for (name, text) in sources {
+        project.set_source_text(SourceKey::from_virtual(*name), (*text).to_string());
+    }
+    project
+}
+
+#[test]
+fn sarif_log_has_one_result_per_diagnostic() {
+    let project = project(&[
+        (
+            "main.st",
+            "PROGRAM Main\nVAR\n    x : INT;\nEND_VAR\n    x := missing;\nEND_PROGRAM\n",
+        ),
+        (
+            "other.st",
+            "FUNCTION Helper : INT\n    Helper := 'text';\nEND_FUNCTION\n",
+        ),
+    ]);
+    let expected: usize = project
+        .sources()
+        .iter()
+        .map(|(_, file_id)| project.database().diagnostics(file_id).len())
+        .sum();
+    assert!(expected >= 2);
+
+    let log = project_diagnostics_to_sarif(&project);
+    assert_eq!(log["version"], SARIF_VERSION);
+    assert!(log["$schema"].as_str().is_some());
+    let runs = log["runs"].as_array().expect("runs array");
+    assert_eq!(runs.len(), 1);
+    let run = &runs[0];
+    assert!(run["tool"]["driver"]["name"].as_str().is_some());
+
+    let rules = run["tool"]["driver"]["rules"]
+        .as_array()
+        .expect("rules array");
+    assert!(!rules.is_empty());
+    let results = run["results"].as_array().expect("results array");
+    assert_eq!(results.len(), expected);
+
+    for result in results {
+        let index = result["ruleIndex"].as_u64().expect("ruleIndex") as usize;
+        assert_eq!(rules[index]["id"], result["ruleId"]);
+        assert!(matches!(
+            result["level"].as_str(),
+            Some("error" | "warning" | "note")
+        ));
+        assert!(result["message"]["text"].as_str().is_some());
+        let location = &result["locations"][0]["physicalLocation"];
+        assert!(location["artifactLocation"]["uri"].as_str().is_some());
+        for field in ["startLine", "startColumn", "endLine", "endColumn"] {
+            assert!(location["region"][field].as_u64().unwrap_or(0) >= 1);
+        }
+    }
+}
+
+#[test]
+fn sarif_region_uses_one_based_lines_and_columns() {
+    let project = project(&[(
+        "main.st",
+        "PROGRAM Main\nVAR\n    x : INT;\nEND_VAR\n    x := missing;\nEND_PROGRAM\n",
+    )]);
+
+    let log = project_diagnostics_to_sarif(&project);
+    let result = log["runs"][0]["results"]
+        .as_array()
+        .expect("results array")
+        .iter()
+        .find(|result| result["ruleId"] == "E101")
+        .expect("undefined identifier result");
+    assert_eq!(result["level"], "error");
+    let location = &result["locations"][0]["physicalLocation"];
+    assert_eq!(location["artifactLocation"]["uri"], "main.st");
+    let region = &location["region"];
+    assert_eq!(region["startLine"], 5);
+    assert_eq!(region["startColumn"], 10);
+    assert_eq!(region["endLine"], 5);
+    assert_eq!(region["endColumn"], 17);
+}
@@ -11,8 +11,9 @@ fn fault_stops_resource() {
 PROGRAM Main
 VAR
     x : INT := 0;
+    zero : INT := 0;
 END_VAR
-x := 1 / 0;
+x := 1 / zero;
 END_PROGRAM
 "#;
 
@@ -0,0 +1,56 @@
+use trust_runtime::stdlib::StandardLibrary;
+use trust_runtime::value::Value;
+
+fn compare(lib: &StandardLibrary, name: &str, args: &[Value]) -> bool {
+    match lib.call(name, args).unwrap() {
+        Value::Bool(value) => value,
+        other => panic!("expected BOOL from {name}, got {other:?}"),
+    }
+}
+
+#[test]
+fn comparison_two_argument_base_cases() {
+    let lib = StandardLibrary::new();
+    let (one, two) = (Value::Int(1), Value::Int(2));
+
+    assert!(compare(&lib, "GT", &[two.clone(), one.clone()]));
+    assert!(!compare(&lib, "GT", &[one.clone(), one.clone()]));
+    assert!(compare(&lib, "GE", &[one.clone(), one.clone()]));
+    assert!(compare(&lib, "LT", &[one.clone(), two.clone()]));
+    assert!(compare(&lib, "LE", &[two.clone(), two.clone()]));
+    assert!(!compare(&lib, "LE", &[two.clone(), one.clone()]));
+    assert!(compare(&lib, "EQ", &[one.clone(), Value::DInt(1)]));
+    assert!(compare(&lib, "NE", &[one, two]));
+}
+
+#[test]
+fn comparison_chains_are_monotonic() {
+    let lib = StandardLibrary::new();
+    let ints = |values: &[i16]| values.iter().map(|v| Value::Int(*v)).collect::<Vec<_>>();
+
+    assert!(compare(&lib, "GT", &ints(&[3, 2, 1])));
+    assert!(!compare(&lib, "GT", &ints(&[3, 2, 2])));
+    assert!(compare(&lib, "GE", &ints(&[3, 2, 2])));
+    assert!(compare(&lib, "LT", &ints(&[1, 2, 3, 4])));
+    assert!(!compare(&lib, "LT", &ints(&[1, 3, 2])));
+    assert!(compare(&lib, "LE", &ints(&[1, 1, 2])));
+
+    let x = Value::Real(2.5);
+    assert!(compare(&lib, "EQ", &[x.clone(), x.clone(), x.clone()]));
+    assert!(!compare(&lib, "EQ", &[x.clone(), x, Value::Real(3.0)]));
+}
+
+#[test]
+fn comparison_rejects_incompatible_operands() {
+    let lib = StandardLibrary::new();
+    let args = [Value::Int(3), Value::String("2".into()), Value::Int(1)];
+    assert!(lib.call("GT", &args).is_err());
+}
+
+#[test]
+fn ne_is_not_extensible() {
+    let lib = StandardLibrary::new();
+    assert!(lib
+        .call("NE", &[Value::Int(1), Value::Int(2), Value::Int(3)])
+        .is_err());
+}
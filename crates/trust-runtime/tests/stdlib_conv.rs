@@ -29,4 +29,14 @@ fn conversion_functions() {
         lib.call("REAL_TO_INT", &[Value::Real(3.9)]).unwrap(),
         Value::Int(4)
     );
+
+    assert_eq!(
+        lib.call("INT_TO_BOOL", &[Value::Int(-3)]).unwrap(),
+        Value::Bool(true)
+    );
+
+    assert_eq!(
+        lib.call("WORD_TO_BOOL", &[Value::Word(0)]).unwrap(),
+        Value::Bool(false)
+    );
 }
@@ -0,0 +1,85 @@
+use trust_runtime::harness::TestHarness;
+
+#[test]
+fn move_scalar() {
+    let source = r#"
+        PROGRAM Test
+        VAR
+            x : INT := INT#7;
+            y : INT;
+            z : INT;
+        END_VAR
+        y := MOVE(x);
+        z := MOVE(IN := x + INT#1);
+        END_PROGRAM
+    "#;
+
+    let mut harness = TestHarness::from_source(source).unwrap();
+    harness.cycle();
+    harness.assert_eq("y", 7i16);
+    harness.assert_eq("z", 8i16);
+}
+
+#[test]
+fn move_struct_is_independent_copy() {
+    let source = r#"
+        TYPE Point : STRUCT
+            x : INT;
+            y : INT;
+        END_STRUCT
+        END_TYPE
+
+        PROGRAM Test
+        VAR
+            src : Point;
+            dst : Point;
+            arr_src : ARRAY[1..2] OF INT;
+            arr_dst : ARRAY[1..2] OF INT;
+            dst_x : INT;
+            dst_y : INT;
+            dst_arr : INT;
+        END_VAR
+        src.x := INT#1;
+        src.y := INT#2;
+        arr_src[1] := INT#10;
+        dst := MOVE(src);
+        arr_dst := MOVE(arr_src);
+        src.x := INT#100;
+        arr_src[1] := INT#100;
+        dst_x := dst.x;
+        dst_y := dst.y;
+        dst_arr := arr_dst[1];
+        END_PROGRAM
+    "#;
+
+    let mut harness = TestHarness::from_source(source).unwrap();
+    harness.cycle();
+    harness.assert_eq("dst_x", 1i16);
+    harness.assert_eq("dst_y", 2i16);
+    harness.assert_eq("dst_arr", 10i16);
+}
+
+#[test]
+fn move_with_en_false_leaves_output_unchanged() {
+    let source = r#"
+        PROGRAM Test
+        VAR
+            enable : BOOL := FALSE;
+            x : INT := INT#5;
+            y : INT := INT#3;
+            ok : BOOL := TRUE;
+        END_VAR
+        y := MOVE(EN := enable, IN := x, ENO => ok);
+        END_PROGRAM
+    "#;
+
+    let mut harness = TestHarness::from_source(source).unwrap();
+    harness.cycle();
+    harness.assert_eq("y", 3i16);
+    harness.assert_eq("ok", false);
+
+    harness.set_input("enable", true);
+    harness.cycle();
+    harness.assert_eq("y", 5i16);
+    harness.assert_eq("ok", true);
+}
@@ -1,3 +1,5 @@
+use trust_runtime::error::RuntimeError;
+use trust_runtime::harness::TestHarness;
 use trust_runtime::stdlib::StandardLibrary;
 use trust_runtime::value::Value;
 
@@ -19,3 +21,93 @@ fn numeric_functions() {
         _ => panic!("expected REAL result"),
     }
 }
+
+#[test]
+fn math_functions_reject_arguments_outside_their_domain() {
+    let lib = StandardLibrary::new();
+
+    assert_eq!(
+        lib.call("SQRT", &[Value::Real(-1.0)]),
+        Err(RuntimeError::MathDomain("SQRT".into()))
+    );
+    assert_eq!(
+        lib.call("LN", &[Value::LReal(0.0)]),
+        Err(RuntimeError::MathDomain("LN".into()))
+    );
+    assert_eq!(
+        lib.call("LOG", &[Value::Real(-10.0)]),
+        Err(RuntimeError::MathDomain("LOG".into()))
+    );
+    assert_eq!(
+        lib.call("ACOS", &[Value::Real(1.5)]),
+        Err(RuntimeError::MathDomain("ACOS".into()))
+    );
+    assert_eq!(
+        lib.call("EXPT", &[Value::Real(-8.0), Value::Real(0.5)]),
+        Err(RuntimeError::MathDomain("EXPT".into()))
+    );
+
+    assert_eq!(
+        lib.call("SQRT", &[Value::Real(0.0)]).unwrap(),
+        Value::Real(0.0)
+    );
+    assert_eq!(
+        lib.call("LN", &[Value::LReal(1.0)]).unwrap(),
+        Value::LReal(0.0)
+    );
+    assert_eq!(
+        lib.call("ASIN", &[Value::Real(0.0)]).unwrap(),
+        Value::Real(0.0)
+    );
+    assert_eq!(
+        lib.call("EXPT", &[Value::Real(-2.0), Value::Real(3.0)])
+            .unwrap(),
+        Value::Real(-8.0)
+    );
+    assert_eq!(
+        lib.call("EXP", &[Value::Real(1000.0)]),
+        Err(RuntimeError::Overflow)
+    );
+}
+
+#[test]
+fn abs_of_most_negative_integer_overflows() {
+    let lib = StandardLibrary::new();
+
+    assert_eq!(
+        lib.call("ABS", &[Value::SInt(i8::MIN)]),
+        Err(RuntimeError::Overflow)
+    );
+    assert_eq!(
+        lib.call("ABS", &[Value::SInt(i8::MIN + 1)]).unwrap(),
+        Value::SInt(i8::MAX)
+    );
+    assert_eq!(
+        lib.call("ABS", &[Value::LReal(-2.5)]).unwrap(),
+        Value::LReal(2.5)
+    );
+}
+
+#[test]
+fn math_domain_errors_fault_the_cycle() {
+    let source = r#"
+PROGRAM Main
+VAR
+    x : REAL := -1.0;
+    root : REAL;
+    ok : REAL;
+END_VAR
+ok := SQRT(REAL#16.0);
+root := SQRT(x);
+END_PROGRAM
+"#;
+
+    let mut harness = TestHarness::from_source(source).unwrap();
+    let result = harness.cycle();
+    assert_eq!(result.errors, vec![RuntimeError::MathDomain("SQRT".into())]);
+    harness.assert_eq("ok", Value::Real(4.0));
+    assert_eq!(
+        RuntimeError::MathDomain("SQRT".into()).exception_code(),
+        Some(0x154)
+    );
+}
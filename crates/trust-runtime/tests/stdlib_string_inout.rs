@@ -0,0 +1,71 @@
+use trust_runtime::harness::TestHarness;
+use trust_runtime::value::Value;
+
+#[test]
+fn insert_within_limit_updates_inout_exactly() {
+    let source = r#"
+FUNCTION InsertTag : BOOL
+VAR_IN_OUT
+    text : STRING[8];
+END_VAR
+VAR_INPUT
+    tag : STRING;
+END_VAR
+text := INSERT(IN1 := text, IN2 := tag, P := 2);
+InsertTag := TRUE;
+END_FUNCTION
+
+PROGRAM Main
+VAR
+    short_text : STRING[8] := 'ABCD';
+    ok : BOOL;
+END_VAR
+ok := InsertTag(text := short_text, tag := 'XY');
+END_PROGRAM
+"#;
+    let mut harness = TestHarness::from_source(source).unwrap();
+    let result = harness.cycle();
+    assert!(result.errors.is_empty(), "{:?}", result.errors);
+    harness.assert_eq("short_text", Value::String("ABXYCD".into()));
+}
+
+#[test]
+fn insert_beyond_declared_length_truncates_inout_target() {
+    let source = r#"
+FUNCTION InsertTag : BOOL
+VAR_IN_OUT
+    text : STRING[8];
+END_VAR
+VAR_INPUT
+    tag : STRING;
+END_VAR
+text := INSERT(IN1 := text, IN2 := tag, P := 2);
+InsertTag := TRUE;
+END_FUNCTION
+
+FUNCTION_BLOCK Patcher
+VAR_IN_OUT
+    text : STRING[8];
+END_VAR
+text := REPLACE(IN1 := text, IN2 := 'XYZXYZ', L := 1, P := 3);
+END_FUNCTION_BLOCK
+
+PROGRAM Main
+VAR
+    long_text : STRING[8] := 'ABCDEF';
+    patched : STRING[8] := 'ABCDEFGH';
+    patcher : Patcher;
+    ok : BOOL;
+END_VAR
+ok := InsertTag(text := long_text, tag := 'XYZ');
+patcher(text := patched);
+END_PROGRAM
+"#;
+    let mut harness = TestHarness::from_source(source).unwrap();
+    let result = harness.cycle();
+    assert!(result.errors.is_empty(), "{:?}", result.errors);
+    // 'AB' + 'XYZ' + 'CDEF' is 9 characters; STRING[8] keeps the first 8.
+    harness.assert_eq("long_text", Value::String("ABXYZCDE".into()));
+    // REPLACE grows 'ABCDEFGH' to 'ABXYZXYZDEFGH'; the FB in-out writes back 8 characters.
+    harness.assert_eq("patched", Value::String("ABXYZXYZ".into()));
+}
@@ -0,0 +1,91 @@
+use trust_runtime::harness::{bytecode_bytes_from_source, TestHarness};
+use trust_runtime::value::Value;
+
+#[test]
+fn divide_by_zero_in_try_routes_to_catch_and_continues() {
+    let source = r#"
+PROGRAM Main
+VAR
+    divisor : INT := 0;
+    result : INT := 7;
+    err : DINT;
+    handled : BOOL;
+    cleaned : BOOL;
+    after : INT;
+END_VAR
+__TRY
+    result := 10 / divisor;
+    result := 99;
+__CATCH(err)
+    handled := TRUE;
+__FINALLY
+    cleaned := TRUE;
+__ENDTRY
+after := after + INT#1;
+END_PROGRAM
+"#;
+
+    let mut harness = TestHarness::from_source(source).unwrap();
+    let cycle = harness.cycle();
+    assert!(
+        cycle.errors.is_empty(),
+        "unexpected errors: {:?}",
+        cycle.errors
+    );
+    assert_eq!(harness.get_output("result"), Some(Value::Int(7)));
+    assert_eq!(harness.get_output("err"), Some(Value::DInt(0x101)));
+    assert_eq!(harness.get_output("handled"), Some(Value::Bool(true)));
+    assert_eq!(harness.get_output("cleaned"), Some(Value::Bool(true)));
+    assert_eq!(harness.get_output("after"), Some(Value::Int(1)));
+
+    let cycle = harness.cycle();
+    assert!(cycle.errors.is_empty());
+    assert_eq!(harness.get_output("after"), Some(Value::Int(2)));
+}
+
+#[test]
+fn try_without_catch_still_faults_after_finally() {
+    let source = r#"
+PROGRAM Main
+VAR
+    divisor : INT := 0;
+    result : INT;
+    cleaned : BOOL;
+END_VAR
+__TRY
+    result := 10 / divisor;
+__FINALLY
+    cleaned := TRUE;
+__ENDTRY
+END_PROGRAM
+"#;
+
+    let mut harness = TestHarness::from_source(source).unwrap();
+    let cycle = harness.cycle();
+    assert!(!cycle.errors.is_empty());
+    assert_eq!(harness.get_output("cleaned"), Some(Value::Bool(true)));
+}
+
+#[test]
+fn try_blocks_encode_to_bytecode() {
+    let source = r#"
+PROGRAM Main
+VAR
+    divisor : INT := 0;
+    result : INT;
+    err : DINT;
+    cleaned : BOOL;
+END_VAR
+__TRY
+    result := 10 / divisor;
+__CATCH(err)
+    result := -1;
+__FINALLY
+    cleaned := TRUE;
+__ENDTRY
+END_PROGRAM
+"#;
+
+    let bytes = bytecode_bytes_from_source(source).expect("bytecode");
+    assert!(!bytes.is_empty());
+}
@@ -1,3 +1,4 @@
+use trust_runtime::error::RuntimeError;
 use trust_runtime::harness::TestHarness;
 
 #[test]
@@ -60,3 +61,61 @@ fn iec_table12() {
     harness.assert_eq("out_field", 12i16);
     harness.assert_eq("out_fb", 3i16);
 }
+
+#[test]
+fn reference_to_derived_instance_assigns_to_base_reference() {
+    let source = r#"
+        FUNCTION_BLOCK Base
+        VAR PUBLIC
+            v : INT := INT#3;
+        END_VAR
+        END_FUNCTION_BLOCK
+
+        FUNCTION_BLOCK Derived EXTENDS Base
+        END_FUNCTION_BLOCK
+
+        PROGRAM Test
+        VAR
+            d : Derived;
+            rd : REF_TO Derived;
+            rb : REF_TO Base;
+            out_v : INT := INT#0;
+            was_set : BOOL := FALSE;
+            is_null : BOOL := FALSE;
+        END_VAR
+        rd := REF(d);
+        rb := rd;
+        out_v := rb^.v;
+        was_set := rb <> NULL;
+        rb := NULL;
+        is_null := rb = NULL;
+        END_PROGRAM
+    "#;
+
+    let mut harness = TestHarness::from_source(source).unwrap();
+    let result = harness.cycle();
+    assert!(result.errors.is_empty(), "{:?}", result.errors);
+    harness.assert_eq("out_v", 3i16);
+    harness.assert_eq("was_set", true);
+    harness.assert_eq("is_null", true);
+}
+
+#[test]
+fn dereferencing_a_null_assigned_reference_faults() {
+    let source = r#"
+        PROGRAM Test
+        VAR
+            x : INT := INT#1;
+            r : REF_TO INT;
+            out_x : INT := INT#0;
+        END_VAR
+        r := REF(x);
+        r := NULL;
+        out_x := r^;
+        END_PROGRAM
+    "#;
+
+    let mut harness = TestHarness::from_source(source).unwrap();
+    let result = harness.cycle();
+    assert_eq!(result.errors, vec![RuntimeError::NullReference]);
+}
@@ -58,3 +58,37 @@ END_PROGRAM
     assert_eq!(array_value.elements[1], Value::Int(20));
     assert_eq!(array_value.elements[2], Value::Int(30));
 }
+
+#[test]
+fn enum_qualified_literal_evaluates_to_member() {
+    let source = r#"
+TYPE
+    Color : (Green, Red, Blue);
+END_TYPE
+
+PROGRAM Main
+VAR
+    c : Color;
+    is_red : BOOL;
+END_VAR
+c := Color#Red;
+is_red := c = Color#Red;
+END_PROGRAM
+"#;
+
+    let mut harness = TestHarness::from_source(source).unwrap();
+    harness.cycle();
+
+    match harness.get_output("c").unwrap() {
+        Value::Enum(EnumValue {
+            variant_name,
+            numeric_value,
+            ..
+        }) => {
+            assert_eq!(variant_name.as_str(), "Red");
+            assert_eq!(numeric_value, 1);
+        }
+        _ => panic!("expected enum value"),
+    }
+    assert_eq!(harness.get_output("is_red"), Some(Value::Bool(true)));
+}
@@ -20,3 +20,22 @@ END_PROGRAM
     assert_eq!(harness.get_output("b"), Some(Value::Int(4)));
     assert_eq!(harness.get_output("c"), Some(Value::Int(5)));
 }
+
+#[test]
+fn array_initializer_is_rejected_instead_of_dropped() {
+    let source = r#"
+PROGRAM Main
+VAR
+    values : ARRAY[1..3] OF INT := [1, 2, 3];
+END_VAR
+END_PROGRAM
+"#;
+
+    let Err(err) = TestHarness::from_source(source) else {
+        panic!("array initializer should not compile to zeroed elements");
+    };
+    assert!(
+        err.to_string().contains("not supported"),
+        "unexpected error: {err}"
+    );
+}
@@ -4,13 +4,35 @@
 //! of tokens with their positions in the source text.
 
 mod tokens;
+mod trivia;
 
 pub use tokens::TokenKind;
+pub use trivia::{lex_with_trivia, TokenGroup};
 
 use logos::Logos;
 use std::collections::VecDeque;
 use text_size::{TextRange, TextSize};
 
+/// How keyword spellings are matched.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum KeywordCase {
+    /// Only upper-case spellings are keywords; others lex as identifiers.
+    Strict,
+    /// Keywords are recognized in any case.
+    #[default]
+    Insensitive,
+    /// Keywords are recognized in any case, and spellings containing lower-case
+    /// letters set [`Token::lowercase_keyword`].
+    WarnOnLowercase,
+}
+
+/// Options controlling how source text is tokenized.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LexerOptions {
+    /// How keyword spellings are matched.
+    pub keyword_case: KeywordCase,
+}
+
 /// A token produced by the lexer.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Token {
@@ -18,13 +40,20 @@ pub struct Token {
     pub kind: TokenKind,
     /// The byte range of the token in the source text.
     pub range: TextRange,
+    /// Set for keywords spelled with lower-case letters when lexing with
+    /// [`KeywordCase::WarnOnLowercase`].
+    pub lowercase_keyword: bool,
 }
 
 impl Token {
     /// Creates a new token.
     #[must_use]
     pub fn new(kind: TokenKind, range: TextRange) -> Self {
-        Self { kind, range }
+        Self {
+            kind,
+            range,
+            lowercase_keyword: false,
+        }
     }
 
     /// Returns the length of the token in bytes.
@@ -48,16 +77,24 @@ pub struct Lexer<'src> {
     inner: logos::Lexer<'src, TokenKind>,
     source: &'src str,
     pending: VecDeque<Token>,
+    options: LexerOptions,
 }
 
 impl<'src> Lexer<'src> {
     /// Creates a new lexer for the given source text.
     #[must_use]
     pub fn new(source: &'src str) -> Self {
+        Self::new_with_options(source, LexerOptions::default())
+    }
+
+    /// Creates a new lexer for the given source text using `options`.
+    #[must_use]
+    pub fn new_with_options(source: &'src str, options: LexerOptions) -> Self {
         Self {
             inner: TokenKind::lexer(source),
             source,
             pending: VecDeque::new(),
+            options,
         }
     }
 
@@ -72,12 +109,25 @@ impl<'src> Lexer<'src> {
     pub fn slice(&self) -> &'src str {
         self.inner.slice()
     }
-}
 
-impl<'src> Iterator for Lexer<'src> {
-    type Item = Token;
+    /// Applies the keyword case mode to a token produced by the grammar.
+    fn apply_keyword_case(&self, mut token: Token) -> Token {
+        if self.options.keyword_case == KeywordCase::Insensitive || !token.kind.is_keyword() {
+            return token;
+        }
+        let text = &self.source[usize::from(token.range.start())..usize::from(token.range.end())];
+        if !text.bytes().any(|byte| byte.is_ascii_lowercase()) {
+            return token;
+        }
+        match self.options.keyword_case {
+            KeywordCase::Strict => token.kind = TokenKind::Ident,
+            KeywordCase::WarnOnLowercase => token.lowercase_keyword = true,
+            KeywordCase::Insensitive => {}
+        }
+        token
+    }
 
-    fn next(&mut self) -> Option<Self::Item> {
+    fn next_token(&mut self) -> Option<Token> {
         if let Some(token) = self.pending.pop_front() {
             return Some(token);
         }
@@ -142,13 +192,28 @@ impl<'src> Iterator for Lexer<'src> {
     }
 }
 
+impl<'src> Iterator for Lexer<'src> {
+    type Item = Token;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let token = self.next_token()?;
+        Some(self.apply_keyword_case(token))
+    }
+}
+
 /// Lex the entire source and return all tokens.
 ///
 /// This is a convenience function for testing and simple use cases.
 /// For the parser, use the `Lexer` iterator directly.
 #[must_use]
 pub fn lex(source: &str) -> Vec<Token> {
-    Lexer::new(source).collect()
+    lex_with_options(source, LexerOptions::default())
+}
+
+/// Lex the entire source with `options` and return all tokens.
+#[must_use]
+pub fn lex_with_options(source: &str, options: LexerOptions) -> Vec<Token> {
+    Lexer::new_with_options(source, options).collect()
 }
 
 /// Lex source and return tokens paired with their text.
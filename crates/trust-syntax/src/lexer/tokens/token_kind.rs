@@ -242,6 +242,14 @@ pub enum TokenKind {
     KwContinue,
     #[token("JMP", ignore(case))]
     KwJmp,
+    #[token("__TRY", ignore(case))]
+    KwTryDunder,
+    #[token("__CATCH", ignore(case))]
+    KwCatchDunder,
+    #[token("__FINALLY", ignore(case))]
+    KwFinallyDunder,
+    #[token("__ENDTRY", ignore(case))]
+    KwEndTryDunder,
     #[token("STEP", ignore(case))]
     KwStep,
     #[token("END_STEP", ignore(case))]
@@ -100,6 +100,10 @@ impl TokenKind {
                 | Self::KwExit
                 | Self::KwContinue
                 | Self::KwJmp
+                | Self::KwTryDunder
+                | Self::KwCatchDunder
+                | Self::KwFinallyDunder
+                | Self::KwEndTryDunder
                 | Self::KwStep
                 | Self::KwEndStep
                 | Self::KwInitialStep
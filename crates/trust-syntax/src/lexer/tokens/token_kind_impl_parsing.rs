@@ -78,6 +78,7 @@ impl TokenKind {
                 | Self::KwExit
                 | Self::KwContinue
                 | Self::KwJmp
+                | Self::KwTryDunder
                 | Self::Semicolon // Empty statement
         )
     }
@@ -0,0 +1,105 @@
+//! Grouping of trivia around significant tokens.
+//!
+//! [`lex_with_trivia`] attaches every whitespace, comment and pragma token to
+//! a neighbouring significant token:
+//!
+//! - Trailing trivia of a token extends to the end of its line, up to and
+//!   including the first line break. A whitespace token spanning that line
+//!   break is split right after it; a multi-line block comment that starts on
+//!   the token's line is trailing and ends the trailing trivia.
+//! - Everything else is leading trivia of the next significant token.
+//!
+//! Trivia at the start of the file leads the first token. Trivia after the
+//! trailing trivia of the last token leads a final empty
+//! [`TokenKind::Eof`] token, so the groups always cover the whole source.
+
+use text_size::{TextRange, TextSize};
+
+use super::{Lexer, Token, TokenKind};
+
+/// A significant token with the trivia attached to it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TokenGroup {
+    /// Trivia on the lines before the token and before it on its own line.
+    pub leading: Vec<Token>,
+    /// The significant token, or an empty [`TokenKind::Eof`] token closing
+    /// the last group.
+    pub token: Token,
+    /// Trivia after the token up to and including the end of its line.
+    pub trailing: Vec<Token>,
+}
+
+impl TokenGroup {
+    /// Returns the range covered by the token and all of its trivia.
+    #[must_use]
+    pub fn full_range(&self) -> TextRange {
+        let start = self
+            .leading
+            .first()
+            .map_or(self.token.range.start(), |token| token.range.start());
+        let end = self
+            .trailing
+            .last()
+            .map_or(self.token.range.end(), |token| token.range.end());
+        TextRange::new(start, end)
+    }
+}
+
+/// Lex the entire source and group trivia with the significant tokens.
+///
+/// The groups are in source order and concatenating their tokens reproduces
+/// the source. See the [module documentation](self) for the attachment rule.
+#[must_use]
+pub fn lex_with_trivia(source: &str) -> Vec<TokenGroup> {
+    let mut groups: Vec<TokenGroup> = Vec::new();
+    let mut leading = Vec::new();
+    // Set while the trivia after the last significant token is still on its line.
+    let mut trailing_open = false;
+
+    for token in Lexer::new(source) {
+        if !token.kind.is_trivia() {
+            groups.push(TokenGroup {
+                leading: std::mem::take(&mut leading),
+                token,
+                trailing: Vec::new(),
+            });
+            trailing_open = true;
+            continue;
+        }
+        if !trailing_open {
+            leading.push(token);
+            continue;
+        }
+        let text = &source[usize::from(token.range.start())..usize::from(token.range.end())];
+        let trailing = &mut groups.last_mut().expect("open trailing trivia").trailing;
+        match text.find('\n') {
+            None => trailing.push(token),
+            Some(idx) if token.kind == TokenKind::Whitespace => {
+                let split = token.range.start() + TextSize::from(idx as u32 + 1);
+                trailing.push(Token::new(
+                    token.kind,
+                    TextRange::new(token.range.start(), split),
+                ));
+                if split < token.range.end() {
+                    leading.push(Token::new(
+                        token.kind,
+                        TextRange::new(split, token.range.end()),
+                    ));
+                }
+                trailing_open = false;
+            }
+            Some(_) => {
+                trailing.push(token);
+                trailing_open = false;
+            }
+        }
+    }
+
+    let end = TextSize::of(source);
+    groups.push(TokenGroup {
+        leading,
+        token: Token::new(TokenKind::Eof, TextRange::empty(end)),
+        trailing: Vec::new(),
+    });
+    groups
+}
@@ -13,7 +13,7 @@
 //!
 //! - **Lossless**: All source text is preserved, including whitespace and comments
 //! - **Error-tolerant**: Parsing continues after errors, producing a partial tree
-//! - **Incremental**: Designed to support incremental re-parsing (future)
+//! - **Incremental**: Edits can be reparsed incrementally, sharing unchanged subtrees
 //!
 //! # Example
 //!
@@ -42,5 +42,5 @@ pub mod parser;
 pub mod syntax;
 mod token_kinds;
 
-pub use lexer::{lex, Lexer, Token, TokenKind};
+pub use lexer::{lex, lex_with_options, KeywordCase, Lexer, LexerOptions, Token, TokenKind};
 pub use syntax::{StLanguage, SyntaxKind, SyntaxNode, SyntaxToken};
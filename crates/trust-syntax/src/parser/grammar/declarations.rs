@@ -34,7 +34,7 @@ impl Parser<'_, '_> {
                 self.parse_type_def();
                 if self.at(TokenKind::Assign) {
                     self.bump();
-                    self.parse_expression();
+                    self.parse_initializer();
                 }
             } else {
                 self.error("expected ':' after type name");
@@ -366,7 +366,7 @@ impl Parser<'_, '_> {
         // Parse initializer
         if self.at(TokenKind::Assign) {
             self.bump();
-            self.parse_expression();
+            self.parse_initializer();
         }
 
         if self.at(TokenKind::Semicolon) {
@@ -376,6 +376,38 @@ impl Parser<'_, '_> {
         self.finish_node();
     }
 
+    /// Parse a declaration initializer (expression or array initializer).
+    pub(crate) fn parse_initializer(&mut self) {
+        if self.at(TokenKind::LBracket) {
+            self.parse_array_initializer();
+        } else {
+            self.parse_expression();
+        }
+    }
+
+    /// Parse an array initializer (e.g., `[1, 2, 3(0)]`).
+    fn parse_array_initializer(&mut self) {
+        self.start_node(SyntaxKind::ArrayInitializer);
+        self.bump(); // [
+
+        while !self.at(TokenKind::RBracket) && !self.at_end() {
+            self.parse_initializer();
+            if self.at(TokenKind::Comma) {
+                self.bump();
+            } else {
+                break;
+            }
+        }
+
+        if self.at(TokenKind::RBracket) {
+            self.bump();
+        } else {
+            self.error("expected ]");
+        }
+
+        self.finish_node();
+    }
+
     /// Parse a type reference.
     pub(crate) fn parse_type_ref(&mut self) {
         self.start_node(SyntaxKind::TypeRef);
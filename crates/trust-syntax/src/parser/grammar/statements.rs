@@ -8,9 +8,12 @@
 //! - FOR/TO/BY/DO/END_FOR
 //! - WHILE/DO/END_WHILE
 //! - REPEAT/UNTIL/END_REPEAT
+//! - __TRY/__CATCH/__FINALLY/__ENDTRY (CODESYS extension)
 //! - RETURN, EXIT, CONTINUE
 //! - Empty statement: `;`
 
+use text_size::TextRange;
+
 use crate::lexer::TokenKind;
 use crate::syntax::SyntaxKind;
 
@@ -29,6 +32,8 @@ impl Parser<'_, '_> {
             self.parse_while_stmt();
         } else if self.at(TokenKind::KwRepeat) {
             self.parse_repeat_stmt();
+        } else if self.at(TokenKind::KwTryDunder) {
+            self.parse_try_stmt();
         } else if self.at(TokenKind::KwReturn) {
             self.parse_return_stmt();
         } else if self.at(TokenKind::KwExit) {
@@ -67,8 +72,7 @@ impl Parser<'_, '_> {
 
     /// Parse IF statement.
     pub(crate) fn parse_if_stmt(&mut self) {
-        self.start_node(SyntaxKind::IfStmt);
-        self.bump(); // IF
+        let block = self.open_block(SyntaxKind::IfStmt, TokenKind::KwEndIf);
 
         self.parse_expression(); // condition
 
@@ -82,8 +86,7 @@ impl Parser<'_, '_> {
         while !self.at(TokenKind::KwElsif)
             && !self.at(TokenKind::KwElse)
             && !self.at(TokenKind::KwEndIf)
-            && !self.at_end()
-            && !self.at_stmt_list_end()
+            && !self.at_body_end(&block)
         {
             self.parse_statement();
         }
@@ -99,8 +102,7 @@ impl Parser<'_, '_> {
             while !self.at(TokenKind::KwElsif)
                 && !self.at(TokenKind::KwElse)
                 && !self.at(TokenKind::KwEndIf)
-                && !self.at_end()
-                && !self.at_stmt_list_end()
+                && !self.at_body_end(&block)
             {
                 self.parse_statement();
             }
@@ -111,17 +113,13 @@ impl Parser<'_, '_> {
         if self.at(TokenKind::KwElse) {
             self.start_node(SyntaxKind::ElseBranch);
             self.bump();
-            while !self.at(TokenKind::KwEndIf) && !self.at_end() && !self.at_stmt_list_end() {
+            while !self.at(TokenKind::KwEndIf) && !self.at_body_end(&block) {
                 self.parse_statement();
             }
             self.finish_node();
         }
 
-        if self.at(TokenKind::KwEndIf) {
-            self.bump();
-        } else {
-            self.error("expected END_IF");
-        }
+        self.expect_block_end(&block);
 
         self.finish_node();
     }
@@ -148,8 +146,7 @@ impl Parser<'_, '_> {
 
     /// Parse CASE statement.
     pub(crate) fn parse_case_stmt(&mut self) {
-        self.start_node(SyntaxKind::CaseStmt);
-        self.bump(); // CASE
+        let block = self.open_block(SyntaxKind::CaseStmt, TokenKind::KwEndCase);
 
         self.parse_expression();
 
@@ -160,8 +157,7 @@ impl Parser<'_, '_> {
         // Parse case branches
         while !self.at(TokenKind::KwElse)
             && !self.at(TokenKind::KwEndCase)
-            && !self.at_end()
-            && !self.at_stmt_list_end()
+            && !self.at_body_end(&block)
         {
             self.start_node(SyntaxKind::CaseBranch);
 
@@ -179,8 +175,7 @@ impl Parser<'_, '_> {
             // Parse statements
             while !self.at(TokenKind::KwElse)
                 && !self.at(TokenKind::KwEndCase)
-                && !self.at_end()
-                && !self.at_stmt_list_end()
+                && !self.at_body_end(&block)
             {
                 if self.current().can_start_expr() && self.source.has_case_label_ahead() {
                     break;
@@ -198,17 +193,13 @@ impl Parser<'_, '_> {
         if self.at(TokenKind::KwElse) {
             self.start_node(SyntaxKind::ElseBranch);
             self.bump();
-            while !self.at(TokenKind::KwEndCase) && !self.at_end() && !self.at_stmt_list_end() {
+            while !self.at(TokenKind::KwEndCase) && !self.at_body_end(&block) {
                 self.parse_statement();
             }
             self.finish_node();
         }
 
-        if self.at(TokenKind::KwEndCase) {
-            self.bump();
-        } else {
-            self.error("expected END_CASE");
-        }
+        self.expect_block_end(&block);
 
         self.finish_node();
     }
@@ -221,8 +212,7 @@ impl Parser<'_, '_> {
 
     /// Parse FOR statement.
     pub(crate) fn parse_for_stmt(&mut self) {
-        self.start_node(SyntaxKind::ForStmt);
-        self.bump(); // FOR
+        let block = self.open_block(SyntaxKind::ForStmt, TokenKind::KwEndFor);
 
         if self.at(TokenKind::Hash) {
             self.bump();
@@ -254,23 +244,18 @@ impl Parser<'_, '_> {
             self.bump();
         }
 
-        while !self.at(TokenKind::KwEndFor) && !self.at_end() && !self.at_stmt_list_end() {
+        while !self.at(TokenKind::KwEndFor) && !self.at_body_end(&block) {
             self.parse_statement();
         }
 
-        if self.at(TokenKind::KwEndFor) {
-            self.bump();
-        } else {
-            self.error("expected END_FOR");
-        }
+        self.expect_block_end(&block);
 
         self.finish_node();
     }
 
     /// Parse WHILE statement.
     pub(crate) fn parse_while_stmt(&mut self) {
-        self.start_node(SyntaxKind::WhileStmt);
-        self.bump(); // WHILE
+        let block = self.open_block(SyntaxKind::WhileStmt, TokenKind::KwEndWhile);
 
         self.parse_expression();
 
@@ -278,25 +263,20 @@ impl Parser<'_, '_> {
             self.bump();
         }
 
-        while !self.at(TokenKind::KwEndWhile) && !self.at_end() && !self.at_stmt_list_end() {
+        while !self.at(TokenKind::KwEndWhile) && !self.at_body_end(&block) {
             self.parse_statement();
         }
 
-        if self.at(TokenKind::KwEndWhile) {
-            self.bump();
-        } else {
-            self.error("expected END_WHILE");
-        }
+        self.expect_block_end(&block);
 
         self.finish_node();
     }
 
     /// Parse REPEAT statement.
     pub(crate) fn parse_repeat_stmt(&mut self) {
-        self.start_node(SyntaxKind::RepeatStmt);
-        self.bump(); // REPEAT
+        let block = self.open_block(SyntaxKind::RepeatStmt, TokenKind::KwEndRepeat);
 
-        while !self.at(TokenKind::KwUntil) && !self.at_end() && !self.at_stmt_list_end() {
+        while !self.at(TokenKind::KwUntil) && !self.at_body_end(&block) {
             self.parse_statement();
         }
 
@@ -305,10 +285,63 @@ impl Parser<'_, '_> {
             self.parse_expression();
         }
 
-        if self.at(TokenKind::KwEndRepeat) {
+        self.expect_block_end(&block);
+
+        self.finish_node();
+    }
+
+    /// Parse __TRY statement.
+    pub(crate) fn parse_try_stmt(&mut self) {
+        self.start_node(SyntaxKind::TryStmt);
+        self.bump(); // __TRY
+
+        while !self.at(TokenKind::KwCatchDunder)
+            && !self.at(TokenKind::KwFinallyDunder)
+            && !self.at(TokenKind::KwEndTryDunder)
+            && !self.at_end()
+            && !self.at_stmt_list_end()
+        {
+            self.parse_statement();
+        }
+
+        if self.at(TokenKind::KwCatchDunder) {
+            self.start_node(SyntaxKind::CatchClause);
+            self.bump();
+            if self.at(TokenKind::LParen) {
+                self.bump();
+                self.parse_expression();
+                if self.at(TokenKind::RParen) {
+                    self.bump();
+                } else {
+                    self.error("expected ')'");
+                }
+            } else {
+                self.error("expected '(' after __CATCH");
+            }
+            while !self.at(TokenKind::KwFinallyDunder)
+                && !self.at(TokenKind::KwEndTryDunder)
+                && !self.at_end()
+                && !self.at_stmt_list_end()
+            {
+                self.parse_statement();
+            }
+            self.finish_node();
+        }
+
+        if self.at(TokenKind::KwFinallyDunder) {
+            self.start_node(SyntaxKind::FinallyClause);
+            self.bump();
+            while !self.at(TokenKind::KwEndTryDunder) && !self.at_end() && !self.at_stmt_list_end()
+            {
+                self.parse_statement();
+            }
+            self.finish_node();
+        }
+
+        if self.at(TokenKind::KwEndTryDunder) {
             self.bump();
         } else {
-            self.error("expected END_REPEAT");
+            self.error("expected __ENDTRY");
         }
 
         self.finish_node();
@@ -352,4 +385,91 @@ impl Parser<'_, '_> {
 
         self.finish_node();
     }
+
+    /// Opens a control-flow block at the current keyword.
+    ///
+    /// When no matching `end` follows before the enclosing POU ends, the body
+    /// is bounded by indentation: it stops at the first statement that starts
+    /// a line indented no deeper than the opener's line.
+    fn open_block(&mut self, kind: SyntaxKind, end: TokenKind) -> OpenBlock {
+        self.start_node(kind);
+        let opener = self.current();
+        let range = self
+            .source
+            .current_token()
+            .map_or_else(TextRange::default, |token| token.range);
+        let indent = self.source.current_line_indent();
+        self.bump();
+        self.open_blocks.push(end);
+        let terminated = self.source.has_block_end_ahead(opener, end);
+        OpenBlock {
+            end,
+            opener: range,
+            body_indent: (!terminated).then_some(indent),
+        }
+    }
+
+    /// Returns true when the body of `block` cannot continue at the current token.
+    fn at_body_end(&self, block: &OpenBlock) -> bool {
+        self.at_end()
+            || self.at_stmt_list_end()
+            || block.body_indent.is_some_and(|indent| {
+                self.source
+                    .current_line_start_indent()
+                    .is_some_and(|current| current <= indent)
+            })
+    }
+
+    /// Close the innermost control-flow block.
+    ///
+    /// A different block terminator is reported by name. It is consumed when no
+    /// enclosing block is waiting for it, so the statement list keeps parsing.
+    /// A block without any matching terminator is reported once, at its opener,
+    /// unless it stops at a terminator no enclosing block is waiting for.
+    fn expect_block_end(&mut self, block: &OpenBlock) {
+        self.open_blocks.pop();
+        if self.at(block.end) {
+            self.bump();
+            return;
+        }
+
+        let expected = block_end_text(block.end).unwrap_or("block end");
+        let found = self.current();
+        let unterminated = block.body_indent.is_some();
+        match block_end_text(found) {
+            Some(_) if unterminated && self.open_blocks.contains(&found) => {
+                self.error_at(block.opener, &format!("missing {expected}"));
+            }
+            Some(found_text) => {
+                self.error(&format!("expected {expected}, found {found_text}"));
+                if !self.open_blocks.contains(&found) {
+                    self.bump();
+                }
+            }
+            None if unterminated => {
+                self.error_at(block.opener, &format!("missing {expected}"));
+            }
+            None => self.error(&format!("expected {expected}")),
+        }
+    }
+}
+
+/// A control-flow block whose terminator is still expected.
+struct OpenBlock {
+    end: TokenKind,
+    /// Range of the opening keyword.
+    opener: TextRange,
+    /// Indentation bounding the body, set when no matching terminator follows.
+    body_indent: Option<usize>,
+}
+
+fn block_end_text(kind: TokenKind) -> Option<&'static str> {
+    match kind {
+        TokenKind::KwEndIf => Some("END_IF"),
+        TokenKind::KwEndCase => Some("END_CASE"),
+        TokenKind::KwEndFor => Some("END_FOR"),
+        TokenKind::KwEndWhile => Some("END_WHILE"),
+        TokenKind::KwEndRepeat => Some("END_REPEAT"),
+        _ => None,
+    }
 }
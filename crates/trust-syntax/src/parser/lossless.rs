@@ -0,0 +1,158 @@
+//! Lossless round-trip verification.
+//!
+//! The tree must reproduce its source byte for byte. [`Parse::verify_lossless`]
+//! checks this in a single pass over the green tree's tokens, comparing each
+//! token against the source without building the tree text.
+
+use rowan::{Language, NodeOrToken};
+use text_size::TextSize;
+
+use super::Parse;
+use crate::syntax::{StLanguage, SyntaxKind};
+
+/// Bytes of context shown on each side of the divergence.
+const SNIPPET_CONTEXT: usize = 16;
+
+/// The tree text differs from the source it was parsed from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LosslessError {
+    /// Byte offset of the first difference.
+    pub offset: TextSize,
+    /// Kind of the tree token at the difference, `None` if the tree text ends
+    /// before it.
+    pub token: Option<SyntaxKind>,
+    /// Kind of the tree token before `token`.
+    pub prev_token: Option<SyntaxKind>,
+    /// Kind of the tree token after `token`.
+    pub next_token: Option<SyntaxKind>,
+    /// The source around the difference.
+    pub expected: String,
+    /// The tree text around the difference.
+    pub found: String,
+}
+
+impl std::fmt::Display for LosslessError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "tree text differs from source at byte {}",
+            u32::from(self.offset)
+        )?;
+        let kind =
+            |kind: Option<SyntaxKind>| kind.map_or_else(|| "-".to_string(), |k| format!("{k:?}"));
+        write!(
+            f,
+            " ({} | {} | {})",
+            kind(self.prev_token),
+            kind(self.token),
+            kind(self.next_token)
+        )?;
+        write!(f, ": expected {:?}, found {:?}", self.expected, self.found)
+    }
+}
+
+impl std::error::Error for LosslessError {}
+
+impl Parse {
+    /// Checks that the tree text equals `original` byte for byte.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first divergent offset and the surrounding token kinds when
+    /// the parser dropped, added or reordered any text.
+    pub fn verify_lossless(&self, original: &str) -> Result<(), LosslessError> {
+        let source = original.as_bytes();
+        let mut offset = 0usize;
+        let mut prev_token = None;
+        let mut tokens = green_tokens(&self.green_node);
+        while let Some((kind, text)) = tokens.next() {
+            let text = text.as_bytes();
+            let actual = &source[offset.min(source.len())..];
+            if let Some(diff) = first_difference(text, actual) {
+                let next_token = tokens.next().map(|(kind, _)| kind);
+                return Err(self.lossless_error(
+                    original,
+                    offset + diff,
+                    Some(kind),
+                    prev_token,
+                    next_token,
+                ));
+            }
+            offset += text.len();
+            prev_token = Some(kind);
+        }
+        if offset < source.len() {
+            return Err(self.lossless_error(original, offset, None, prev_token, None));
+        }
+        Ok(())
+    }
+
+    /// Panics if the tree text differs from `original`.
+    ///
+    /// The check only runs with debug assertions enabled, so downstream tools
+    /// can leave it in place and pay nothing in release builds.
+    pub fn debug_assert_lossless(&self, original: &str) {
+        if cfg!(debug_assertions) {
+            if let Err(err) = self.verify_lossless(original) {
+                panic!("{err}");
+            }
+        }
+    }
+
+    fn lossless_error(
+        &self,
+        original: &str,
+        offset: usize,
+        token: Option<SyntaxKind>,
+        prev_token: Option<SyntaxKind>,
+        next_token: Option<SyntaxKind>,
+    ) -> LosslessError {
+        let tree_text = self.syntax().to_string();
+        LosslessError {
+            offset: TextSize::try_from(offset).unwrap_or(TextSize::from(u32::MAX)),
+            token,
+            prev_token,
+            next_token,
+            expected: snippet(original, offset),
+            found: snippet(&tree_text, offset),
+        }
+    }
+}
+
+/// Iterates the tokens of a green tree in source order.
+fn green_tokens(root: &rowan::GreenNodeData) -> impl Iterator<Item = (SyntaxKind, &str)> {
+    let mut stack = vec![root.children()];
+    std::iter::from_fn(move || loop {
+        let children = stack.last_mut()?;
+        match children.next() {
+            Some(NodeOrToken::Node(node)) => stack.push(node.children()),
+            Some(NodeOrToken::Token(token)) => {
+                return Some((StLanguage::kind_from_raw(token.kind()), token.text()));
+            }
+            None => {
+                stack.pop();
+            }
+        }
+    })
+}
+
+/// Returns the index of the first byte of `expected` not matched by `actual`.
+fn first_difference(expected: &[u8], actual: &[u8]) -> Option<usize> {
+    let common = expected.len().min(actual.len());
+    match expected[..common]
+        .iter()
+        .zip(&actual[..common])
+        .position(|(a, b)| a != b)
+    {
+        Some(idx) => Some(idx),
+        None if common < expected.len() => Some(common),
+        None => None,
+    }
+}
+
+fn snippet(text: &str, offset: usize) -> String {
+    let bytes = text.as_bytes();
+    let start = offset.saturating_sub(SNIPPET_CONTEXT).min(bytes.len());
+    let end = offset.saturating_add(SNIPPET_CONTEXT).min(bytes.len());
+    String::from_utf8_lossy(&bytes[start..end]).into_owned()
+}
@@ -8,8 +8,9 @@
 //! The parser is designed for IDE use:
 //!
 //! - **Error-tolerant**: Continues parsing after errors
-//! - **Lossless**: Preserves all source text including whitespace and comments
-//! - **Incremental-ready**: Architecture supports future incremental reparsing
+//! - **Lossless**: Preserves all source text including whitespace and comments;
+//!   [`Parse::verify_lossless`] checks the round trip
+//! - **Incremental**: [`reparse`] rebuilds only the part of the tree an edit touches
 //!
 //! # Architecture
 //!
@@ -23,12 +24,16 @@
 
 pub mod event;
 pub mod grammar;
+mod lossless;
 mod parser;
+mod reparse;
 mod sink;
 mod source;
 
+pub use lossless::LosslessError;
 pub use parser::parse;
 pub(crate) use parser::{CompletedMarker, Parser};
+pub use reparse::{reparse, TextEdit};
 
 use crate::syntax::SyntaxNode;
 
@@ -33,6 +33,8 @@ pub(crate) struct Parser<'t, 'src> {
     pub(crate) events: Vec<Event>,
     errors: Vec<ParseError>,
     pub(crate) expr_depth: usize,
+    /// Terminators of the control-flow blocks currently being parsed, innermost last.
+    pub(crate) open_blocks: Vec<TokenKind>,
 }
 
 pub(crate) struct Marker {
@@ -110,6 +112,7 @@ impl<'t, 'src> Parser<'t, 'src> {
             events: Vec::new(),
             errors: Vec::new(),
             expr_depth: 0,
+            open_blocks: Vec::new(),
         }
     }
 
@@ -197,13 +200,17 @@ impl<'t, 'src> Parser<'t, 'src> {
         self.events.push(Event::Finish);
     }
 
+    /// Reports an error at the current token, or at the end of the source
+    /// when no tokens remain.
     pub(crate) fn error(&mut self, message: &str) {
-        let range = self
-            .source
-            .current_token()
-            .map(|t| t.range)
-            .unwrap_or_else(|| text_size::TextRange::empty(text_size::TextSize::from(0)));
+        let range = self.source.current_token().map_or_else(
+            || text_size::TextRange::empty(text_size::TextSize::of(self.source.source())),
+            |t| t.range,
+        );
+        self.error_at(range, message);
+    }
 
+    pub(crate) fn error_at(&mut self, range: text_size::TextRange, message: &str) {
         self.errors.push(ParseError {
             message: message.to_string(),
             range,
@@ -234,6 +241,9 @@ impl<'t, 'src> Parser<'t, 'src> {
             | TokenKind::KwEndFor
             | TokenKind::KwEndWhile
             | TokenKind::KwEndRepeat
+            | TokenKind::KwCatchDunder
+            | TokenKind::KwFinallyDunder
+            | TokenKind::KwEndTryDunder
             | TokenKind::KwEndCase
             // End of blocks
             | TokenKind::KwEndVar
@@ -443,7 +453,7 @@ END_PROGRAM
             parse
                 .errors()
                 .iter()
-                .any(|error| error.message == "expected END_CASE"),
+                .any(|error| error.message == "missing END_CASE"),
             "errors: {:?}",
             parse.errors()
         );
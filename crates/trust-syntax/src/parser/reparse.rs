@@ -0,0 +1,355 @@
+//! Incremental reparsing.
+//!
+//! [`reparse`] applies a [`TextEdit`] to a previous [`Parse`] and rebuilds only
+//! the part of the tree the edit touches; every other green node is shared
+//! with the old tree. Two strategies are tried, cheapest first:
+//!
+//! 1. **Token relex**: an edit inside a single identifier, literal, comment,
+//!    pragma or whitespace token that still lexes to one token of the same
+//!    kind only swaps that token.
+//! 2. **Item reparse**: otherwise the top-level item (POU, type declaration,
+//!    namespace member, ...) enclosing the edit is parsed on its own and
+//!    spliced back in place of the old item.
+//!
+//! Everything else falls back to a full parse. This includes edits that touch
+//! a keyword, since those may open or close a block (e.g. deleting `END_IF`),
+//! and items whose reparse would run past their own end.
+
+use text_size::{TextRange, TextSize};
+
+use crate::lexer::lex;
+use crate::parser::{parse, Parse, ParseError};
+use crate::syntax::{SyntaxKind, SyntaxNode, SyntaxToken};
+
+/// A single text replacement: `delete` is replaced by `insert`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextEdit {
+    /// The range of the old text that is removed.
+    pub delete: TextRange,
+    /// The text inserted at `delete.start()`.
+    pub insert: String,
+}
+
+impl TextEdit {
+    /// Creates an edit that replaces `range` with `text`.
+    #[must_use]
+    pub fn replace(range: TextRange, text: impl Into<String>) -> Self {
+        Self {
+            delete: range,
+            insert: text.into(),
+        }
+    }
+
+    /// Creates an edit that inserts `text` at `offset`.
+    #[must_use]
+    pub fn insert(offset: TextSize, text: impl Into<String>) -> Self {
+        Self::replace(TextRange::empty(offset), text)
+    }
+
+    /// Creates an edit that deletes `range`.
+    #[must_use]
+    pub fn delete(range: TextRange) -> Self {
+        Self::replace(range, String::new())
+    }
+
+    /// Applies the edit to `text` in place.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `delete` is out of bounds or not on a char boundary.
+    pub fn apply(&self, text: &mut String) {
+        text.replace_range(std::ops::Range::<usize>::from(self.delete), &self.insert);
+    }
+
+    /// Maps an offset at or after the end of the deleted range into the new text.
+    fn shift(&self, offset: TextSize) -> TextSize {
+        offset - self.delete.len() + TextSize::of(self.insert.as_str())
+    }
+
+    fn shift_range(&self, range: TextRange) -> TextRange {
+        TextRange::new(self.shift(range.start()), self.shift(range.end()))
+    }
+}
+
+/// Reparses `old` after applying `edit`, reusing unaffected parts of the tree.
+///
+/// The result is equivalent to calling [`parse`] on the edited text, including
+/// the errors for the whole file.
+///
+/// # Panics
+///
+/// Panics if the edit range is out of bounds for the old text.
+#[must_use]
+pub fn reparse(old: &Parse, edit: &TextEdit) -> Parse {
+    let root = old.syntax();
+    reparse_token(old, &root, edit)
+        .or_else(|| reparse_item(old, &root, edit))
+        .unwrap_or_else(|| {
+            let mut text = root.text().to_string();
+            edit.apply(&mut text);
+            parse(&text)
+        })
+}
+
+/// Relexes the single token containing the edit.
+fn reparse_token(old: &Parse, root: &SyntaxNode, edit: &TextEdit) -> Option<Parse> {
+    let candidates: Vec<SyntaxToken> = if edit.delete.is_empty() {
+        root.token_at_offset(edit.delete.start()).collect()
+    } else {
+        root.covering_element(edit.delete)
+            .into_token()
+            .into_iter()
+            .collect()
+    };
+    let (token, text) = candidates
+        .into_iter()
+        .find_map(|token| relex_token(&token, edit).map(|text| (token, text)))?;
+
+    let green = rowan::GreenToken::new(token.kind().into(), &text);
+    let token_end = token.text_range().end();
+    let map = |offset: TextSize| {
+        if offset >= token_end {
+            edit.shift(offset)
+        } else {
+            offset
+        }
+    };
+    let errors = old
+        .errors
+        .iter()
+        .map(|error| ParseError {
+            message: error.message.clone(),
+            range: TextRange::new(map(error.range.start()), map(error.range.end())),
+        })
+        .collect();
+    Some(Parse {
+        green_node: token.replace_with(green),
+        errors,
+    })
+}
+
+/// Returns the new text of `token` if the edit keeps it a single token of the same kind.
+fn relex_token(token: &SyntaxToken, edit: &TextEdit) -> Option<String> {
+    let kind = token.kind();
+    if !is_relexable(kind) {
+        return None;
+    }
+    let range = token.text_range();
+    if !range.contains_range(edit.delete) {
+        return None;
+    }
+    let mut text = token.text().to_string();
+    text.replace_range(
+        std::ops::Range::<usize>::from(edit.delete - range.start()),
+        &edit.insert,
+    );
+    if text.is_empty() || !parses_same(kind, token.text(), &text) {
+        return None;
+    }
+
+    // Lex together with the neighbouring tokens so a token that would merge
+    // with either of them (e.g. an identifier growing into `INT#`) is rejected.
+    let prev = token.prev_token();
+    let next = token.next_token();
+    let prev_text = prev.as_ref().map_or("", |prev| prev.text());
+    let next_text = next.as_ref().map_or("", |next| next.text());
+    let context = format!("{prev_text}{text}{next_text}");
+    let expected: Vec<SyntaxKind> = prev
+        .iter()
+        .map(SyntaxToken::kind)
+        .chain(std::iter::once(kind))
+        .chain(next.iter().map(SyntaxToken::kind))
+        .collect();
+    let lexed = lex(&context);
+    let middle = lexed.get(usize::from(prev.is_some()))?;
+    let same_tokens = lexed.len() == expected.len()
+        && lexed
+            .iter()
+            .zip(&expected)
+            .all(|(token, kind)| SyntaxKind::from(token.kind) == *kind);
+    let same_range =
+        middle.range == TextRange::at(TextSize::of(prev_text), TextSize::of(text.as_str()));
+    (same_tokens && same_range).then_some(text)
+}
+
+fn is_relexable(kind: SyntaxKind) -> bool {
+    matches!(
+        kind,
+        SyntaxKind::Ident
+            | SyntaxKind::IntLiteral
+            | SyntaxKind::RealLiteral
+            | SyntaxKind::TimeLiteral
+            | SyntaxKind::DateLiteral
+            | SyntaxKind::TimeOfDayLiteral
+            | SyntaxKind::DateAndTimeLiteral
+            | SyntaxKind::StringLiteral
+            | SyntaxKind::WideStringLiteral
+            | SyntaxKind::Whitespace
+            | SyntaxKind::LineComment
+            | SyntaxKind::BlockComment
+            | SyntaxKind::Pragma
+    )
+}
+
+/// Returns true if the parser treats both texts of a `kind` token the same way.
+///
+/// The grammar inspects token text in a few places: access declarations accept
+/// `READ_WRITE`/`READ_ONLY` identifiers, and signed typed literals reject based
+/// integers. Unterminated blocks are closed at the first dedented statement, so
+/// whitespace must keep the indentation of the line it ends on.
+fn parses_same(kind: SyntaxKind, old: &str, new: &str) -> bool {
+    match kind {
+        SyntaxKind::Whitespace => {
+            let indent = |text: &str| text.rfind('\n').map(|idx| text.len() - idx);
+            indent(old) == indent(new)
+        }
+        SyntaxKind::Ident => {
+            let is_access = |text: &str| {
+                text.eq_ignore_ascii_case("READ_WRITE") || text.eq_ignore_ascii_case("READ_ONLY")
+            };
+            !is_access(old) && !is_access(new)
+        }
+        SyntaxKind::IntLiteral => old.contains('#') == new.contains('#'),
+        _ => true,
+    }
+}
+
+/// Reparses the top-level item enclosing the edit and splices it into the tree.
+fn reparse_item(old: &Parse, root: &SyntaxNode, edit: &TextEdit) -> Option<Parse> {
+    let start_node = if edit.delete.is_empty() {
+        root.token_at_offset(edit.delete.start())
+            .left_biased()?
+            .parent()?
+    } else {
+        match root.covering_element(edit.delete) {
+            rowan::NodeOrToken::Node(node) => node,
+            rowan::NodeOrToken::Token(token) => token.parent()?,
+        }
+    };
+    let item = start_node.ancestors().find(is_reparsable_item)?;
+    let range = item.text_range();
+    if edit.delete.start() <= range.start() || edit.delete.end() > range.end() {
+        return None;
+    }
+
+    let old_text = item.text().to_string();
+    let local_delete = edit.delete - range.start();
+    if touches_keyword(&old_text, local_delete) {
+        return None;
+    }
+    let mut text = old_text;
+    text.replace_range(std::ops::Range::<usize>::from(local_delete), &edit.insert);
+    let local_insert = TextRange::at(local_delete.start(), TextSize::of(edit.insert.as_str()));
+    if touches_keyword(&text, local_insert) {
+        return None;
+    }
+
+    let fragment = parse(&text);
+    let fragment_root = fragment.syntax();
+    let mut children = fragment_root.children_with_tokens();
+    let new_item = match (children.next(), children.next()) {
+        (Some(rowan::NodeOrToken::Node(node)), None) if node.kind() == item.kind() => node,
+        _ => return None,
+    };
+    // An item that is not closed inside the fragment reports errors at the end
+    // of input or ends in something other than its END keyword, such as an
+    // unterminated comment; in the full file it would keep consuming the
+    // following items.
+    let at_end = TextRange::empty(TextSize::of(text.as_str()));
+    if fragment.errors.iter().any(|error| error.range == at_end)
+        || last_significant_kind(&new_item) != last_significant_kind(&item)
+        || old
+            .errors
+            .iter()
+            .any(|error| error.range.start() == range.end())
+    {
+        return None;
+    }
+
+    let before = old
+        .errors
+        .iter()
+        .filter(|error| error.range.end() <= range.start())
+        .cloned();
+    let inside = fragment.errors.iter().map(|error| ParseError {
+        message: error.message.clone(),
+        range: error.range + range.start(),
+    });
+    let after = old
+        .errors
+        .iter()
+        .filter(|error| error.range.start() >= range.end())
+        .map(|error| ParseError {
+            message: error.message.clone(),
+            range: edit.shift_range(error.range),
+        });
+    let errors = before.chain(inside).chain(after).collect();
+    Some(Parse {
+        green_node: item.replace_with(new_item.green().into_owned()),
+        errors,
+    })
+}
+
+fn is_reparsable_item(node: &SyntaxNode) -> bool {
+    matches!(
+        node.kind(),
+        SyntaxKind::Program
+            | SyntaxKind::Function
+            | SyntaxKind::FunctionBlock
+            | SyntaxKind::Class
+            | SyntaxKind::Interface
+            | SyntaxKind::TypeDecl
+            | SyntaxKind::Configuration
+            | SyntaxKind::Namespace
+            | SyntaxKind::UsingDirective
+    ) && node.parent().is_some_and(|parent| {
+        matches!(
+            parent.kind(),
+            SyntaxKind::SourceFile | SyntaxKind::Namespace
+        )
+    })
+}
+
+/// Kind of the last non-trivia token under `node`.
+fn last_significant_kind(node: &SyntaxNode) -> Option<SyntaxKind> {
+    node.descendants_with_tokens()
+        .filter_map(|element| element.into_token())
+        .filter(|token| !token.kind().is_trivia())
+        .last()
+        .map(|token| token.kind())
+}
+
+/// Returns true if a keyword token of `text` overlaps or touches `range`.
+fn touches_keyword(text: &str, range: TextRange) -> bool {
+    lex(text).iter().any(|token| {
+        token.kind.is_keyword()
+            && token.range.start() <= range.end()
+            && range.start() <= token.range.end()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shift_range_after_edit() {
+        let edit = TextEdit::replace(TextRange::new(2.into(), 5.into()), "x");
+        assert_eq!(
+            edit.shift_range(TextRange::new(7.into(), 9.into())),
+            TextRange::new(5.into(), 7.into())
+        );
+    }
+
+    #[test]
+    fn test_keyword_touching_edit() {
+        assert!(touches_keyword(
+            "x := 1; END_IF",
+            TextRange::empty(8.into())
+        ));
+        assert!(!touches_keyword(
+            "x := 1; END_IF",
+            TextRange::empty(1.into())
+        ));
+    }
+}
@@ -3,13 +3,13 @@
 //! This module provides the `Source` struct that wraps a token stream
 //! and provides lookahead and consumption operations.
 
+use text_size::TextSize;
+
 use crate::lexer::{Token, TokenKind};
 
 /// A token source that provides tokens to the parser.
 pub struct Source<'t, 'src> {
     tokens: &'t [Token],
-    // Reserved for error reporting and debug helpers.
-    #[allow(dead_code)]
     source: &'src str,
     cursor: usize,
 }
@@ -101,8 +101,6 @@ impl<'t, 'src> Source<'t, 'src> {
     }
 
     /// Returns the source text.
-    // Currently unused; kept for diagnostics and tests.
-    #[allow(dead_code)]
     pub fn source(&self) -> &'src str {
         self.source
     }
@@ -141,6 +139,9 @@ impl<'t, 'src> Source<'t, 'src> {
                 | TokenKind::KwEndFor
                 | TokenKind::KwEndWhile
                 | TokenKind::KwEndRepeat
+                | TokenKind::KwCatchDunder
+                | TokenKind::KwFinallyDunder
+                | TokenKind::KwEndTryDunder
                 | TokenKind::KwEndFunctionBlock
                 | TokenKind::KwEndTestFunctionBlock
                 | TokenKind::KwEndFunction
@@ -160,6 +161,50 @@ impl<'t, 'src> Source<'t, 'src> {
         false
     }
 
+    /// Returns the indentation of the line containing the current token.
+    pub fn current_line_indent(&self) -> usize {
+        let prefix = self.current_line_prefix();
+        prefix.len() - prefix.trim_start().len()
+    }
+
+    /// Returns the indentation of the current token if it is the first token
+    /// on its line.
+    pub fn current_line_start_indent(&self) -> Option<usize> {
+        let prefix = self.current_line_prefix();
+        prefix.trim().is_empty().then_some(prefix.len())
+    }
+
+    /// Returns the text between the start of the current token's line and the token.
+    fn current_line_prefix(&self) -> &'src str {
+        let start = self
+            .current_token()
+            .map_or(TextSize::of(self.source), |token| token.range.start());
+        let before = &self.source[..usize::from(start)];
+        let line_start = before.rfind('\n').map_or(0, |idx| idx + 1);
+        &before[line_start..]
+    }
+
+    /// Returns true if a matching `end` follows before the enclosing POU ends.
+    ///
+    /// Nested blocks opened by `opener` consume their own `end`.
+    pub fn has_block_end_ahead(&self, opener: TokenKind, end: TokenKind) -> bool {
+        let mut depth = 0u32;
+        for token in &self.tokens[self.cursor..] {
+            match token.kind {
+                kind if kind == opener => depth += 1,
+                kind if kind == end => {
+                    if depth == 0 {
+                        return true;
+                    }
+                    depth -= 1;
+                }
+                kind if is_pou_boundary(kind) => return false,
+                _ => {}
+            }
+        }
+        false
+    }
+
     /// Returns true if there is a top-level colon before statement end.
     pub fn has_case_label_ahead(&self) -> bool {
         let mut cursor = self.cursor;
@@ -190,6 +235,9 @@ impl<'t, 'src> Source<'t, 'src> {
                 | TokenKind::KwEndFor
                 | TokenKind::KwEndWhile
                 | TokenKind::KwEndRepeat
+                | TokenKind::KwCatchDunder
+                | TokenKind::KwFinallyDunder
+                | TokenKind::KwEndTryDunder
                 | TokenKind::KwEndFunctionBlock
                 | TokenKind::KwEndTestFunctionBlock
                 | TokenKind::KwEndFunction
@@ -209,3 +257,43 @@ impl<'t, 'src> Source<'t, 'src> {
         false
     }
 }
+
+/// Returns true for tokens that end a POU body or start a new declaration.
+fn is_pou_boundary(kind: TokenKind) -> bool {
+    matches!(
+        kind,
+        TokenKind::KwEndProgram
+            | TokenKind::KwEndTestProgram
+            | TokenKind::KwEndFunction
+            | TokenKind::KwEndFunctionBlock
+            | TokenKind::KwEndTestFunctionBlock
+            | TokenKind::KwEndClass
+            | TokenKind::KwEndMethod
+            | TokenKind::KwEndProperty
+            | TokenKind::KwEndInterface
+            | TokenKind::KwEndNamespace
+            | TokenKind::KwEndAction
+            | TokenKind::KwEndGet
+            | TokenKind::KwEndSet
+            | TokenKind::KwProgram
+            | TokenKind::KwTestProgram
+            | TokenKind::KwFunction
+            | TokenKind::KwFunctionBlock
+            | TokenKind::KwTestFunctionBlock
+            | TokenKind::KwClass
+            | TokenKind::KwMethod
+            | TokenKind::KwProperty
+            | TokenKind::KwInterface
+            | TokenKind::KwNamespace
+            | TokenKind::KwConfiguration
+            | TokenKind::KwType
+            | TokenKind::KwAction
+            | TokenKind::KwVar
+            | TokenKind::KwVarInput
+            | TokenKind::KwVarOutput
+            | TokenKind::KwVarInOut
+            | TokenKind::KwVarTemp
+            | TokenKind::KwVarGlobal
+            | TokenKind::KwVarExternal
+    )
+}
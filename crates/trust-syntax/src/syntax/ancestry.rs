@@ -0,0 +1,144 @@
+//! Root-to-leaf node stacks for position-based queries.
+//!
+//! Hover, completion and selection range all start by resolving the nodes
+//! that cover a cursor offset. [`NodeAncestry`] computes that stack once, from
+//! the root down to the innermost covering node, and [`AncestryCache`] reuses
+//! the shared prefix of the previous stack when the next offset is nearby.
+//!
+//! A node covers an offset if the offset lies inside it; when the offset sits
+//! on a boundary between two siblings, the one starting there wins, and the
+//! one ending there is used only if nothing starts at the offset (e.g. the
+//! cursor at the end of an identifier or of the file).
+
+use text_size::TextSize;
+
+use super::{SyntaxKind, SyntaxNode};
+
+/// The nodes covering an offset, ordered from the root to the innermost node.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NodeAncestry {
+    offset: TextSize,
+    nodes: Vec<SyntaxNode>,
+}
+
+impl NodeAncestry {
+    /// Computes the node stack covering `offset`, starting at `root`.
+    #[must_use]
+    pub fn at_offset(root: &SyntaxNode, offset: TextSize) -> Self {
+        let mut ancestry = Self {
+            offset,
+            nodes: vec![root.clone()],
+        };
+        ancestry.descend();
+        ancestry
+    }
+
+    /// The offset this stack was computed for.
+    #[must_use]
+    pub fn offset(&self) -> TextSize {
+        self.offset
+    }
+
+    /// The nodes from the root down to the innermost covering node.
+    #[must_use]
+    pub fn nodes(&self) -> &[SyntaxNode] {
+        &self.nodes
+    }
+
+    /// The root node.
+    #[must_use]
+    pub fn root(&self) -> &SyntaxNode {
+        &self.nodes[0]
+    }
+
+    /// The innermost node covering the offset.
+    #[must_use]
+    pub fn innermost(&self) -> &SyntaxNode {
+        &self.nodes[self.nodes.len() - 1]
+    }
+
+    /// The node kinds from the root down.
+    pub fn kinds(&self) -> impl Iterator<Item = SyntaxKind> + '_ {
+        self.nodes.iter().map(SyntaxNode::kind)
+    }
+
+    /// Returns the innermost node of `kind`, if any.
+    #[must_use]
+    pub fn innermost_of_kind(&self, kind: SyntaxKind) -> Option<&SyntaxNode> {
+        self.find_innermost(|node| node.kind() == kind)
+    }
+
+    /// Returns the innermost node matching `predicate`, if any.
+    pub fn find_innermost(&self, predicate: impl Fn(&SyntaxNode) -> bool) -> Option<&SyntaxNode> {
+        self.nodes.iter().rev().find(|node| predicate(node))
+    }
+
+    fn descend(&mut self) {
+        while let Some(child) = child_covering(self.innermost(), self.offset) {
+            self.nodes.push(child);
+        }
+    }
+}
+
+/// Caches the last [`NodeAncestry`] of a tree.
+///
+/// A lookup keeps every node of the previous stack that still strictly
+/// contains the new offset and only descends from the deepest of them, so
+/// successive queries in the same region skip the walk from the root.
+#[derive(Debug, Clone)]
+pub struct AncestryCache {
+    root: SyntaxNode,
+    last: Option<NodeAncestry>,
+}
+
+impl AncestryCache {
+    /// Creates an empty cache for the tree rooted at `root`.
+    #[must_use]
+    pub fn new(root: SyntaxNode) -> Self {
+        Self { root, last: None }
+    }
+
+    /// Returns the node stack covering `offset`.
+    pub fn ancestry_at(&mut self, offset: TextSize) -> &NodeAncestry {
+        let ancestry = match self.last.take() {
+            Some(last) if last.offset == offset => last,
+            Some(mut last) => {
+                // Below the root, a node that strictly contains the offset is
+                // the only sibling `child_covering` can pick.
+                let keep = last.nodes[1..]
+                    .iter()
+                    .position(|node| !strictly_contains(node, offset))
+                    .map_or(last.nodes.len(), |idx| idx + 1);
+                last.nodes.truncate(keep);
+                last.offset = offset;
+                last.descend();
+                last
+            }
+            None => NodeAncestry::at_offset(&self.root, offset),
+        };
+        self.last.insert(ancestry)
+    }
+}
+
+fn strictly_contains(node: &SyntaxNode, offset: TextSize) -> bool {
+    let range = node.text_range();
+    range.start() <= offset && offset < range.end()
+}
+
+/// Returns the child node of `node` covering `offset`.
+fn child_covering(node: &SyntaxNode, offset: TextSize) -> Option<SyntaxNode> {
+    let mut touching = None;
+    for child in node.children() {
+        let range = child.text_range();
+        if range.start() > offset {
+            break;
+        }
+        if offset < range.end() {
+            return Some(child);
+        }
+        if range.end() == offset && !range.is_empty() {
+            touching = Some(child);
+        }
+    }
+    touching
+}
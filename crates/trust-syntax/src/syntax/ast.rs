@@ -0,0 +1,164 @@
+//! Typed views over type-definition nodes.
+//!
+//! Each wrapper checks the node kind once in `cast` and exposes the node's
+//! parts as typed accessors, so consumers don't walk `children()` by kind.
+//! Accessors return `None` (or an empty iterator) for parts missing from
+//! erroneous code.
+
+use super::{SyntaxKind, SyntaxNode};
+
+macro_rules! ast_node {
+    ($(#[$meta:meta])* $name:ident, $kind:ident) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        pub struct $name(SyntaxNode);
+
+        impl $name {
+            #[doc = concat!("Wraps `node` if it is a [`SyntaxKind::", stringify!($kind), "`].")]
+            #[must_use]
+            pub fn cast(node: SyntaxNode) -> Option<Self> {
+                (node.kind() == SyntaxKind::$kind).then_some(Self(node))
+            }
+
+            /// The underlying syntax node.
+            #[must_use]
+            pub fn syntax(&self) -> &SyntaxNode {
+                &self.0
+            }
+        }
+    };
+}
+
+ast_node!(
+    /// A string type with optional length: `STRING[80]`, `WSTRING`.
+    StringTypeNode,
+    StringType
+);
+ast_node!(
+    /// An array type: `ARRAY[1..10, 0..*] OF INT`.
+    ArrayTypeNode,
+    ArrayType
+);
+ast_node!(
+    /// A subrange: `1..10`, `*`, or a single bound.
+    SubrangeNode,
+    Subrange
+);
+ast_node!(
+    /// An enum definition: `(Red, Green := 5) INT` or `INT (Red, Green)`.
+    EnumDefNode,
+    EnumDef
+);
+ast_node!(
+    /// A member of an enum definition: `Red` or `Green := 5`.
+    EnumValueNode,
+    EnumValue
+);
+
+impl StringTypeNode {
+    /// Returns true for `WSTRING`.
+    #[must_use]
+    pub fn is_wide(&self) -> bool {
+        self.0
+            .children_with_tokens()
+            .any(|element| element.kind() == SyntaxKind::KwWString)
+    }
+
+    /// The length expression between the brackets.
+    #[must_use]
+    pub fn length_expr(&self) -> Option<SyntaxNode> {
+        expressions(&self.0).next()
+    }
+}
+
+impl ArrayTypeNode {
+    /// The dimensions in declaration order.
+    pub fn dimensions(&self) -> impl Iterator<Item = SubrangeNode> {
+        self.0.children().filter_map(SubrangeNode::cast)
+    }
+
+    /// The element type after `OF`.
+    #[must_use]
+    pub fn element_type(&self) -> Option<SyntaxNode> {
+        child_of_kind(&self.0, SyntaxKind::TypeRef)
+    }
+}
+
+impl SubrangeNode {
+    /// The lower bound, or the only bound of a single-expression subrange.
+    #[must_use]
+    pub fn lower(&self) -> Option<SyntaxNode> {
+        expressions(&self.0).next()
+    }
+
+    /// The upper bound after `..`.
+    #[must_use]
+    pub fn upper(&self) -> Option<SyntaxNode> {
+        expressions(&self.0).nth(1)
+    }
+
+    /// Returns true for a variable-length bound written as `*`.
+    #[must_use]
+    pub fn is_variable_length(&self) -> bool {
+        expressions(&self.0).any(|expr| {
+            expr.kind() == SyntaxKind::Literal
+                && expr
+                    .children_with_tokens()
+                    .any(|element| element.kind() == SyntaxKind::Star)
+        })
+    }
+}
+
+impl EnumDefNode {
+    /// The members in declaration order.
+    pub fn values(&self) -> impl Iterator<Item = EnumValueNode> {
+        self.0.children().filter_map(EnumValueNode::cast)
+    }
+
+    /// The base type, written before or after the member list.
+    #[must_use]
+    pub fn base_type(&self) -> Option<SyntaxNode> {
+        child_of_kind(&self.0, SyntaxKind::TypeRef)
+    }
+}
+
+impl EnumValueNode {
+    /// The member name.
+    #[must_use]
+    pub fn name(&self) -> Option<SyntaxNode> {
+        child_of_kind(&self.0, SyntaxKind::Name)
+    }
+
+    /// The value expression after `:=`.
+    #[must_use]
+    pub fn value_expr(&self) -> Option<SyntaxNode> {
+        expressions(&self.0).next()
+    }
+}
+
+fn child_of_kind(node: &SyntaxNode, kind: SyntaxKind) -> Option<SyntaxNode> {
+    node.children().find(|child| child.kind() == kind)
+}
+
+fn expressions(node: &SyntaxNode) -> impl Iterator<Item = SyntaxNode> {
+    node.children().filter(|child| is_expression(child.kind()))
+}
+
+fn is_expression(kind: SyntaxKind) -> bool {
+    matches!(
+        kind,
+        SyntaxKind::BinaryExpr
+            | SyntaxKind::UnaryExpr
+            | SyntaxKind::ParenExpr
+            | SyntaxKind::CallExpr
+            | SyntaxKind::IndexExpr
+            | SyntaxKind::FieldExpr
+            | SyntaxKind::DerefExpr
+            | SyntaxKind::AddrExpr
+            | SyntaxKind::SizeOfExpr
+            | SyntaxKind::NameRef
+            | SyntaxKind::Literal
+            | SyntaxKind::ThisExpr
+            | SyntaxKind::SuperExpr
+    )
+}
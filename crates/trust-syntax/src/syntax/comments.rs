@@ -0,0 +1,170 @@
+//! Comment trivia attachment queries.
+//!
+//! The sink attaches trivia to whichever node is open when it is encountered,
+//! so a comment that documents a declaration frequently lives inside the
+//! previous sibling's subtree. These helpers walk the token stream instead of
+//! the tree, which makes attachment independent of where the trivia landed.
+//!
+//! Attachment rules:
+//!
+//! - **Trailing** comments start after the node's last non-trivia token and
+//!   run up to the first newline.
+//! - **Leading** comments sit between the previous non-trivia token and the
+//!   node's first non-trivia token, excluding the previous token's trailing
+//!   comments.
+//! - The **doc comment** is the part of the leading comments below the last
+//!   blank line, joined into plain text.
+
+use super::{SyntaxKind, SyntaxNode, SyntaxToken};
+
+/// Returns the comment tokens attached in front of `node`, in source order.
+#[must_use]
+pub fn leading_comments(node: &SyntaxNode) -> Vec<SyntaxToken> {
+    leading_trivia(node)
+        .into_iter()
+        .filter(|token| token.kind().is_comment())
+        .collect()
+}
+
+/// Returns the documentation comment directly above `node`, markers stripped.
+///
+/// Only the comments after the last blank line (or pragma) in front of the
+/// node count. Line comments contribute one line each; block comments keep
+/// their relative indentation. Returns `None` when no comment precedes the node.
+#[must_use]
+pub fn leading_doc_comment(node: &SyntaxNode) -> Option<String> {
+    let trivia = leading_trivia(node);
+    let start = trivia
+        .iter()
+        .rposition(|token| token.kind() == SyntaxKind::Pragma || is_blank_line(token))
+        .map_or(0, |idx| idx + 1);
+    let lines: Vec<String> = trivia[start..]
+        .iter()
+        .filter(|token| token.kind().is_comment())
+        .flat_map(comment_lines)
+        .collect();
+    (!lines.is_empty()).then(|| lines.join("\n"))
+}
+
+/// Returns the trivia between the previous token's trailing comments and the
+/// node's first non-trivia token, in source order.
+pub(super) fn leading_trivia(node: &SyntaxNode) -> Vec<SyntaxToken> {
+    let Some(first) = first_significant_token(node) else {
+        return Vec::new();
+    };
+
+    let mut between = Vec::new();
+    let mut cursor = first.prev_token();
+    let mut has_previous = false;
+    while let Some(token) = cursor {
+        if !token.kind().is_trivia() {
+            has_previous = true;
+            break;
+        }
+        cursor = token.prev_token();
+        between.push(token);
+    }
+    between.reverse();
+
+    // Skip the previous token's trailing comments (everything up to the
+    // first line break) so they are not attached twice.
+    if has_previous {
+        let start = between
+            .iter()
+            .position(is_line_break)
+            .map_or(between.len(), |idx| idx + 1);
+        between.drain(..start);
+    }
+    between
+}
+
+/// Splits a comment into text lines with the comment markers removed.
+fn comment_lines(token: &SyntaxToken) -> Vec<String> {
+    let text = token.text();
+    if token.kind() == SyntaxKind::LineComment {
+        let body = text.strip_prefix("//").unwrap_or(text);
+        let body = body.strip_prefix(' ').unwrap_or(body);
+        return vec![body.trim_end().to_string()];
+    }
+
+    let body = text
+        .strip_prefix("(*")
+        .and_then(|body| body.strip_suffix("*)"))
+        .or_else(|| {
+            text.strip_prefix("/*")
+                .and_then(|body| body.strip_suffix("*/"))
+        })
+        .unwrap_or(text);
+    let mut lines: Vec<&str> = body.lines().map(str::trim_end).collect();
+    while lines.last().is_some_and(|line| line.is_empty()) {
+        lines.pop();
+    }
+    let Some((first, rest)) = lines.split_first() else {
+        return Vec::new();
+    };
+    // The first line follows the opening marker, so only the remaining lines
+    // carry meaningful indentation; strip their common prefix.
+    let indent = rest
+        .iter()
+        .filter(|line| !line.is_empty())
+        .map(|line| line.len() - line.trim_start().len())
+        .min()
+        .unwrap_or(0);
+    let first = first.trim_start();
+    let mut out = Vec::with_capacity(lines.len());
+    if !first.is_empty() {
+        out.push(first.to_string());
+    }
+    out.extend(
+        rest.iter()
+            .map(|line| line.get(indent..).unwrap_or("").to_string()),
+    );
+    out
+}
+
+/// Returns the comment tokens attached after `node` on the same line, in
+/// source order.
+#[must_use]
+pub fn trailing_comments(node: &SyntaxNode) -> Vec<SyntaxToken> {
+    let Some(last) = last_significant_token(node) else {
+        return Vec::new();
+    };
+
+    let mut comments = Vec::new();
+    let mut cursor = last.next_token();
+    while let Some(token) = cursor {
+        if !token.kind().is_trivia() || is_line_break(&token) {
+            break;
+        }
+        if token.kind().is_comment() {
+            comments.push(token.clone());
+        }
+        cursor = token.next_token();
+    }
+    comments
+}
+
+fn first_significant_token(node: &SyntaxNode) -> Option<SyntaxToken> {
+    node.descendants_with_tokens()
+        .filter_map(rowan::NodeOrToken::into_token)
+        .find(|token| !token.kind().is_trivia())
+}
+
+fn last_significant_token(node: &SyntaxNode) -> Option<SyntaxToken> {
+    let mut token = node.last_token()?;
+    while token.kind().is_trivia() {
+        token = token.prev_token()?;
+        if token.text_range().end() <= node.text_range().start() {
+            return None;
+        }
+    }
+    Some(token)
+}
+
+fn is_line_break(token: &SyntaxToken) -> bool {
+    token.kind() == SyntaxKind::Whitespace && token.text().contains('\n')
+}
+
+fn is_blank_line(token: &SyntaxToken) -> bool {
+    token.kind() == SyntaxKind::Whitespace && token.text().matches('\n').count() >= 2
+}
@@ -3,6 +3,16 @@
 //! This module provides the `rowan`-based syntax tree implementation,
 //! including the `SyntaxKind` enum that covers both tokens and composite nodes.
 
+mod ancestry;
+mod ast;
+mod comments;
+mod pragma;
+
+pub use ancestry::{AncestryCache, NodeAncestry};
+pub use ast::{ArrayTypeNode, EnumDefNode, EnumValueNode, StringTypeNode, SubrangeNode};
+pub use comments::{leading_comments, leading_doc_comment, trailing_comments};
+pub use pragma::{leading_pragmas, parse_pragma, Pragma, PragmaArg};
+
 use crate::lexer::TokenKind;
 use crate::token_kinds::for_each_token_kind;
 
@@ -198,6 +208,15 @@ macro_rules! define_syntax_kind {
             /// Repeat statement: `REPEAT ... UNTIL cond END_REPEAT`
             RepeatStmt,
 
+            /// Try statement: `__TRY ... __CATCH(code) ... __FINALLY ... __ENDTRY`
+            TryStmt,
+
+            /// Catch clause: `__CATCH(code) statements`
+            CatchClause,
+
+            /// Finally clause: `__FINALLY statements`
+            FinallyClause,
+
             /// Return statement: `RETURN;` or `RETURN expr;`
             ReturnStmt,
 
@@ -283,6 +302,12 @@ impl SyntaxKind {
         )
     }
 
+    /// Returns `true` if this is a line or block comment.
+    #[must_use]
+    pub fn is_comment(self) -> bool {
+        matches!(self, Self::LineComment | Self::BlockComment)
+    }
+
     /// Returns `true` if this is a token kind (not a composite node).
     #[must_use]
     pub fn is_token(self) -> bool {
@@ -382,6 +407,9 @@ macro_rules! define_syntax_kinds {
             SyntaxKind::ForStmt,
             SyntaxKind::WhileStmt,
             SyntaxKind::RepeatStmt,
+            SyntaxKind::TryStmt,
+            SyntaxKind::CatchClause,
+            SyntaxKind::FinallyClause,
             SyntaxKind::ReturnStmt,
             SyntaxKind::ExitStmt,
             SyntaxKind::ContinueStmt,
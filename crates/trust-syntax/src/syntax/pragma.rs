@@ -0,0 +1,182 @@
+//! Structured view of pragma trivia.
+//!
+//! The lexer keeps each `{...}` pragma as a single trivia token so layout and
+//! parsing are unaffected. [`parse_pragma`] splits such a token into a
+//! directive name and its arguments:
+//!
+//! ```text
+//! {attribute 'hide'}               name = attribute, args = ['hide']
+//! {warning disable W016, W005}     name = warning,   args = [disable, W016, W005]
+//! {name arg1 := val1, arg2}        name = name,      args = [arg1 := val1, arg2]
+//! ```
+//!
+//! Arguments are separated by whitespace or commas. Quoted arguments and
+//! values (`'...'` or `"..."`) are unquoted; an argument followed by `:=`
+//! without a value keeps `value: None`.
+
+use smol_str::SmolStr;
+use text_size::TextRange;
+
+use super::comments::leading_trivia;
+use super::{SyntaxKind, SyntaxNode, SyntaxToken};
+
+/// A pragma split into its directive name and arguments.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pragma {
+    /// The directive name, e.g. `attribute` or `warning`.
+    pub name: SmolStr,
+    /// The arguments in source order.
+    pub args: Vec<PragmaArg>,
+    /// The range of the whole pragma token.
+    pub range: TextRange,
+}
+
+/// A single pragma argument: `key` or `key := value`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PragmaArg {
+    /// The argument text, unquoted.
+    pub key: SmolStr,
+    /// The assigned value, unquoted, if the argument has `:= value`.
+    pub value: Option<SmolStr>,
+}
+
+impl Pragma {
+    /// Returns true if the directive name matches `name`, ignoring case.
+    #[must_use]
+    pub fn is(&self, name: &str) -> bool {
+        self.name.eq_ignore_ascii_case(name)
+    }
+
+    /// Returns the first argument whose key matches `key`, ignoring case.
+    #[must_use]
+    pub fn arg(&self, key: &str) -> Option<&PragmaArg> {
+        self.args
+            .iter()
+            .find(|arg| arg.key.eq_ignore_ascii_case(key))
+    }
+}
+
+/// Parses a `Pragma` token into its directive name and arguments.
+///
+/// Returns `None` for other tokens and for pragmas without a bare directive
+/// name (e.g. `{}` or `{'text'}`).
+#[must_use]
+pub fn parse_pragma(token: &SyntaxToken) -> Option<Pragma> {
+    if token.kind() != SyntaxKind::Pragma {
+        return None;
+    }
+    let text = token.text();
+    let inner = text.strip_prefix('{')?;
+    let inner = inner.strip_suffix('}').unwrap_or(inner);
+
+    let mut parts = split_parts(inner).into_iter();
+    let name = match parts.next()? {
+        Part::Word(name) => name,
+        Part::Quoted(_) | Part::Assign | Part::Comma => return None,
+    };
+
+    let mut args: Vec<PragmaArg> = Vec::new();
+    let mut pending_assign = false;
+    for part in parts {
+        match part {
+            Part::Comma => pending_assign = false,
+            Part::Assign => {
+                // `:=` without a key keeps the value under an empty key.
+                if pending_assign || args.last().is_none_or(|arg| arg.value.is_some()) {
+                    args.push(PragmaArg {
+                        key: SmolStr::default(),
+                        value: None,
+                    });
+                }
+                pending_assign = true;
+            }
+            Part::Word(text) | Part::Quoted(text) => match args.last_mut() {
+                Some(last) if pending_assign => {
+                    last.value = Some(text);
+                    pending_assign = false;
+                }
+                _ => args.push(PragmaArg {
+                    key: text,
+                    value: None,
+                }),
+            },
+        }
+    }
+
+    Some(Pragma {
+        name,
+        args,
+        range: token.text_range(),
+    })
+}
+
+/// Returns the pragmas directly in front of `node`, in source order.
+///
+/// Uses the same attachment as [`super::leading_comments`], so a pragma on the
+/// line above a declaration belongs to that declaration.
+#[must_use]
+pub fn leading_pragmas(node: &SyntaxNode) -> Vec<Pragma> {
+    leading_trivia(node)
+        .iter()
+        .filter_map(parse_pragma)
+        .collect()
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Part {
+    Word(SmolStr),
+    Quoted(SmolStr),
+    Assign,
+    Comma,
+}
+
+fn split_parts(text: &str) -> Vec<Part> {
+    let mut parts = Vec::new();
+    let mut chars = text.char_indices().peekable();
+    while let Some(&(start, ch)) = chars.peek() {
+        if ch.is_whitespace() {
+            chars.next();
+        } else if ch == ',' {
+            chars.next();
+            parts.push(Part::Comma);
+        } else if text[start..].starts_with(":=") {
+            chars.next();
+            chars.next();
+            parts.push(Part::Assign);
+        } else if ch == '\'' || ch == '"' {
+            chars.next();
+            let mut value = String::new();
+            while let Some((_, next)) = chars.next() {
+                if next == ch {
+                    break;
+                }
+                // `$'`, `$"` and `$$` escape the following character.
+                if next == '$' {
+                    if let Some(&(_, escaped)) = chars.peek() {
+                        if escaped == ch || escaped == '$' {
+                            chars.next();
+                            value.push(escaped);
+                            continue;
+                        }
+                    }
+                }
+                value.push(next);
+            }
+            parts.push(Part::Quoted(SmolStr::new(value)));
+        } else {
+            let mut end = text.len();
+            while let Some(&(idx, next)) = chars.peek() {
+                if next.is_whitespace()
+                    || matches!(next, ',' | '\'' | '"')
+                    || text[idx..].starts_with(":=")
+                {
+                    end = idx;
+                    break;
+                }
+                chars.next();
+            }
+            parts.push(Part::Word(SmolStr::new(&text[start..end])));
+        }
+    }
+    parts
+}
@@ -120,6 +120,10 @@ macro_rules! for_each_token_kind {
             KwExit,
             KwContinue,
             KwJmp,
+            KwTryDunder,
+            KwCatchDunder,
+            KwFinallyDunder,
+            KwEndTryDunder,
             KwStep,
             KwEndStep,
             KwInitialStep,
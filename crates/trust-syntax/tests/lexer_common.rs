@@ -1,4 +1,6 @@
-use trust_syntax::lexer::{lex_with_text, TokenKind};
+use trust_syntax::lexer::{
+    lex, lex_with_options, lex_with_text, KeywordCase, LexerOptions, TokenKind,
+};
 
 fn non_trivia_kinds(source: &str) -> Vec<TokenKind> {
     lex_with_text(source)
@@ -40,3 +42,45 @@ fn iec_6_1() {
         .iter()
         .any(|(token, _)| token.kind == TokenKind::Error));
 }
+
+fn kinds_with_options(source: &str, keyword_case: KeywordCase) -> Vec<(TokenKind, bool)> {
+    lex_with_options(source, LexerOptions { keyword_case })
+        .into_iter()
+        .filter(|token| !token.kind.is_trivia())
+        .map(|token| (token.kind, token.lowercase_keyword))
+        .collect()
+}
+
+#[test]
+fn keyword_case_modes() {
+    let source = "function_block FB END_FUNCTION_BLOCK";
+
+    assert_eq!(
+        kinds_with_options(source, KeywordCase::Insensitive),
+        vec![
+            (TokenKind::KwFunctionBlock, false),
+            (TokenKind::Ident, false),
+            (TokenKind::KwEndFunctionBlock, false),
+        ]
+    );
+    assert_eq!(
+        kinds_with_options(source, KeywordCase::Strict),
+        vec![
+            (TokenKind::Ident, false),
+            (TokenKind::Ident, false),
+            (TokenKind::KwEndFunctionBlock, false),
+        ]
+    );
+    assert_eq!(
+        kinds_with_options(source, KeywordCase::WarnOnLowercase),
+        vec![
+            (TokenKind::KwFunctionBlock, true),
+            (TokenKind::Ident, false),
+            (TokenKind::KwEndFunctionBlock, false),
+        ]
+    );
+    assert_eq!(
+        lex(source),
+        lex_with_options(source, LexerOptions::default())
+    );
+}
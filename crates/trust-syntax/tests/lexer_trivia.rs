@@ -0,0 +1,85 @@
+use trust_syntax::lexer::{lex_with_trivia, Token, TokenGroup, TokenKind};
+
+fn texts<'a>(source: &'a str, tokens: &[Token]) -> Vec<&'a str> {
+    tokens
+        .iter()
+        .map(|token| &source[usize::from(token.range.start())..usize::from(token.range.end())])
+        .collect()
+}
+
+fn group_text<'a>(source: &'a str, group: &TokenGroup) -> &'a str {
+    let range = group.full_range();
+    &source[usize::from(range.start())..usize::from(range.end())]
+}
+
+#[test]
+fn trailing_trivia_ends_at_line_break() {
+    let source = "(* header *)\nx := 1; // set x\n  // about y\n  y := 2;";
+    let groups = lex_with_trivia(source);
+
+    let semicolon = &groups[3];
+    assert_eq!(semicolon.token.kind, TokenKind::Semicolon);
+    assert_eq!(
+        texts(source, &semicolon.trailing),
+        vec![" ", "// set x", "\n"]
+    );
+
+    let y = &groups[4];
+    assert_eq!(y.token.kind, TokenKind::Ident);
+    assert_eq!(texts(source, &y.leading), vec!["  ", "// about y", "\n  "]);
+
+    let x = &groups[0];
+    assert_eq!(texts(source, &x.leading), vec!["(* header *)", "\n"]);
+    assert_eq!(texts(source, &x.trailing), vec![" "]);
+}
+
+#[test]
+fn multi_line_block_comment_on_token_line_is_trailing() {
+    let source = "a; (* one\ntwo *) b;";
+    let groups = lex_with_trivia(source);
+
+    assert_eq!(
+        texts(source, &groups[1].trailing),
+        vec![" ", "(* one\ntwo *)"]
+    );
+    assert_eq!(texts(source, &groups[2].leading), vec![" "]);
+}
+
+#[test]
+fn trivia_after_last_token_leads_eof() {
+    let source = "END_PROGRAM // done\n\n// tail\n";
+    let groups = lex_with_trivia(source);
+
+    assert_eq!(groups.len(), 2);
+    assert_eq!(
+        texts(source, &groups[0].trailing),
+        vec![" ", "// done", "\n"]
+    );
+    let eof = &groups[1];
+    assert_eq!(eof.token.kind, TokenKind::Eof);
+    assert!(eof.token.is_empty());
+    assert_eq!(texts(source, &eof.leading), vec!["\n", "// tail", "\n"]);
+}
+
+#[test]
+fn groups_reproduce_source() {
+    let source = "PROGRAM Main\r\n    VAR x : INT; END_VAR {attribute 'hide'}\r\n\r\n    x := x + 1; (* inc *)\r\nEND_PROGRAM\r\n";
+    let groups = lex_with_trivia(source);
+
+    let text: String = groups
+        .iter()
+        .map(|group| group_text(source, group))
+        .collect();
+    assert_eq!(text, source);
+    assert!(groups
+        .windows(2)
+        .all(|pair| pair[0].full_range().end() == pair[1].full_range().start()));
+}
+
+#[test]
+fn empty_source_has_only_eof() {
+    let groups = lex_with_trivia("");
+    assert_eq!(groups.len(), 1);
+    assert_eq!(groups[0].token.kind, TokenKind::Eof);
+    assert!(groups[0].leading.is_empty());
+}
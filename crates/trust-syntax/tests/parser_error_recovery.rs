@@ -40,6 +40,37 @@ END_PROGRAM"#
     ));
 }
 
+#[test]
+fn test_mismatched_end_for() {
+    insta::assert_snapshot!(snapshot_parse(
+        r#"PROGRAM Test
+FOR i := 1 TO 3 DO
+    x := i;
+END_WHILE
+END_PROGRAM"#
+    ));
+}
+
+#[test]
+fn test_matching_block_terminators_parse_cleanly() {
+    let parsed = parse(
+        r#"PROGRAM Test
+WHILE x < 10 DO
+    FOR i := 1 TO 3 DO
+        IF i = 2 THEN
+            x := x + i;
+        END_IF;
+    END_FOR;
+END_WHILE;
+REPEAT
+    x := x - 1;
+UNTIL x = 0
+END_REPEAT;
+END_PROGRAM"#,
+    );
+    assert!(parsed.ok(), "{:?}", parsed.errors());
+}
+
 #[test]
 fn test_missing_then() {
     insta::assert_snapshot!(snapshot_parse(
@@ -104,3 +135,149 @@ fn test_deep_parenthesized_expression_is_bounded() {
         .message
         .contains("expression nesting exceeds parser limit")));
 }
+
+fn count_nodes(parsed: &trust_syntax::parser::Parse, kind: SyntaxKind) -> usize {
+    parsed
+        .syntax()
+        .descendants()
+        .filter(|node| node.kind() == kind)
+        .count()
+}
+
+/// Returns the kind of the node owning the assignment to `target`.
+fn assignment_parent(parsed: &trust_syntax::parser::Parse, target: &str) -> Option<SyntaxKind> {
+    parsed
+        .syntax()
+        .descendants()
+        .filter(|node| node.kind() == SyntaxKind::AssignStmt)
+        .find(|node| node.text().to_string().trim_start().starts_with(target))?
+        .ancestors()
+        .skip(1)
+        .map(|node| node.kind())
+        .find(|kind| *kind != SyntaxKind::StmtList)
+}
+
+fn error_messages(parsed: &trust_syntax::parser::Parse) -> Vec<&str> {
+    parsed
+        .errors()
+        .iter()
+        .map(|err| err.message.as_str())
+        .collect()
+}
+
+#[test]
+fn test_unterminated_if_keeps_following_statement() {
+    let source = "PROGRAM Test
+    IF x THEN
+        y := 1;
+    z := 2;
+END_PROGRAM
+";
+    let parsed = parse(source);
+    assert_eq!(count_nodes(&parsed, SyntaxKind::IfStmt), 1);
+    assert_eq!(count_nodes(&parsed, SyntaxKind::AssignStmt), 2);
+    assert_eq!(assignment_parent(&parsed, "y"), Some(SyntaxKind::IfStmt));
+    assert_eq!(assignment_parent(&parsed, "z"), Some(SyntaxKind::Program));
+
+    let errors = parsed.errors();
+    assert_eq!(errors.len(), 1, "errors: {errors:?}");
+    assert_eq!(errors[0].message, "missing END_IF");
+    let if_start = source.find("IF").unwrap();
+    assert_eq!(
+        std::ops::Range::<usize>::from(errors[0].range),
+        if_start..if_start + 2
+    );
+}
+
+#[test]
+fn test_unterminated_for_inside_if() {
+    let source = "PROGRAM Test
+    IF a THEN
+        FOR i := 1 TO 3 DO
+            x := i;
+    END_IF
+    y := 2;
+END_PROGRAM
+";
+    let parsed = parse(source);
+    assert_eq!(count_nodes(&parsed, SyntaxKind::IfStmt), 1);
+    assert_eq!(count_nodes(&parsed, SyntaxKind::ForStmt), 1);
+    assert_eq!(assignment_parent(&parsed, "x"), Some(SyntaxKind::ForStmt));
+    assert_eq!(assignment_parent(&parsed, "y"), Some(SyntaxKind::Program));
+
+    assert_eq!(error_messages(&parsed), vec!["missing END_FOR"]);
+}
+
+#[test]
+fn test_nested_unterminated_blocks() {
+    let source = "PROGRAM Test
+    IF a THEN
+        FOR i := 1 TO 3 DO
+            x := i;
+        b := FALSE;
+    y := 2;
+END_PROGRAM
+";
+    let parsed = parse(source);
+    assert_eq!(count_nodes(&parsed, SyntaxKind::AssignStmt), 3);
+    assert_eq!(assignment_parent(&parsed, "x"), Some(SyntaxKind::ForStmt));
+    assert_eq!(assignment_parent(&parsed, "b"), Some(SyntaxKind::IfStmt));
+    assert_eq!(assignment_parent(&parsed, "y"), Some(SyntaxKind::Program));
+
+    assert_eq!(
+        error_messages(&parsed),
+        vec!["missing END_FOR", "missing END_IF"]
+    );
+}
+
+#[test]
+fn test_unterminated_while_around_terminated_case() {
+    let source = "PROGRAM Test
+    WHILE run DO
+        CASE mode OF
+            1: x := 1;
+        END_CASE
+    y := 2;
+END_PROGRAM
+";
+    let parsed = parse(source);
+    assert_eq!(count_nodes(&parsed, SyntaxKind::WhileStmt), 1);
+    assert_eq!(count_nodes(&parsed, SyntaxKind::CaseStmt), 1);
+    assert_eq!(assignment_parent(&parsed, "y"), Some(SyntaxKind::Program));
+
+    assert_eq!(error_messages(&parsed), vec!["missing END_WHILE"]);
+}
+
+#[test]
+fn test_unterminated_blocks_at_eof() {
+    let source = "PROGRAM Test
+    IF a THEN
+        WHILE run DO
+            x := 1;
+";
+    let parsed = parse(source);
+    assert_eq!(count_nodes(&parsed, SyntaxKind::IfStmt), 1);
+    assert_eq!(count_nodes(&parsed, SyntaxKind::WhileStmt), 1);
+    assert_eq!(assignment_parent(&parsed, "x"), Some(SyntaxKind::WhileStmt));
+
+    assert_eq!(
+        error_messages(&parsed),
+        vec![
+            "missing END_WHILE",
+            "missing END_IF",
+            "expected END_PROGRAM"
+        ]
+    );
+}
+
+#[test]
+fn test_missing_pou_end_reported_at_eof() {
+    let source = "PROGRAM Test\n    x := 1;";
+    let parsed = parse(source);
+    let errors = parsed.errors();
+    assert_eq!(error_messages(&parsed), vec!["expected END_PROGRAM"]);
+    assert_eq!(
+        std::ops::Range::<usize>::from(errors[0].range),
+        source.len()..source.len()
+    );
+}
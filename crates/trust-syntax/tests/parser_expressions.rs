@@ -123,6 +123,17 @@ END_FUNCTION_BLOCK"#
     ));
 }
 
+#[test]
+fn test_this_deref() {
+    insta::assert_snapshot!(snapshot_parse(
+        r#"FUNCTION_BLOCK FB_Test
+    METHOD DoWork
+        THIS^.value := 1;
+    END_METHOD
+END_FUNCTION_BLOCK"#
+    ));
+}
+
 #[test]
 fn test_siemens_hash_prefixed_locals() {
     insta::assert_snapshot!(snapshot_parse(
@@ -132,3 +143,12 @@ fn test_siemens_hash_prefixed_locals() {
 END_PROGRAM"#
     ));
 }
+
+#[test]
+fn test_enum_qualified_literal() {
+    insta::assert_snapshot!(snapshot_parse(
+        r#"PROGRAM Test
+    c := Color#Red;
+END_PROGRAM"#
+    ));
+}
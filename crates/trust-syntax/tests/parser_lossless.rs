@@ -0,0 +1,64 @@
+use text_size::TextSize;
+use trust_syntax::parser::{parse, reparse, TextEdit};
+use trust_syntax::syntax::SyntaxKind;
+
+#[test]
+fn verify_lossless_accepts_parsed_source() {
+    let source = "(* header *)
+PROGRAM Main {attribute 'hide'}
+VAR
+    x : INT := 16#FF; // counter
+END_VAR
+    IF x > 0 THEN
+        x := x - 1;
+    (* unterminated
+END_PROGRAM
+";
+    let parsed = parse(source);
+    assert_eq!(parsed.verify_lossless(source), Ok(()));
+    parsed.debug_assert_lossless(source);
+
+    let offset = TextSize::from(source.find("x - 1").expect("needle") as u32);
+    let edited = reparse(&parsed, &TextEdit::insert(offset, "y + "));
+    let mut text = source.to_string();
+    TextEdit::insert(offset, "y + ").apply(&mut text);
+    assert_eq!(edited.verify_lossless(&text), Ok(()));
+}
+
+#[test]
+fn verify_lossless_reports_first_divergence() {
+    let source = "PROGRAM Main
+VAR
+    x : INT := 1;
+END_VAR
+END_PROGRAM
+";
+    let parsed = parse(source);
+    let changed = source.replacen("x : INT", "x :  INT", 1);
+    let err = parsed.verify_lossless(&changed).expect_err("text differs");
+    let offset = source.find("INT := ").expect("needle");
+    assert_eq!(usize::from(err.offset), offset);
+    assert_eq!(err.prev_token, Some(SyntaxKind::Whitespace));
+    assert!(err.token.is_some());
+    assert!(err.to_string().contains(&format!("at byte {offset}")));
+    assert!(err.expected.contains(":  INT"));
+    assert!(err.found.contains(": INT"));
+}
+
+#[test]
+fn verify_lossless_reports_missing_and_extra_text() {
+    let source = "PROGRAM Main\nEND_PROGRAM\n";
+    let parsed = parse(source);
+
+    let longer = format!("{source}x");
+    let err = parsed
+        .verify_lossless(&longer)
+        .expect_err("tree is shorter");
+    assert_eq!(usize::from(err.offset), source.len());
+    assert_eq!(err.token, None);
+
+    let shorter = &source[..source.len() - 1];
+    let err = parsed.verify_lossless(shorter).expect_err("tree is longer");
+    assert_eq!(usize::from(err.offset), shorter.len());
+    assert!(err.token.is_some());
+}
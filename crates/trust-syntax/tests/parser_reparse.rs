@@ -0,0 +1,247 @@
+use std::time::{Duration, Instant};
+
+use text_size::{TextRange, TextSize};
+use trust_syntax::parser::{parse, reparse, Parse, TextEdit};
+
+/// Applies `edit` both incrementally and from scratch and checks the results match.
+fn assert_reparse_matches(source: &str, edit: TextEdit) -> Parse {
+    let old = parse(source);
+    let incremental = reparse(&old, &edit);
+
+    let mut text = source.to_string();
+    edit.apply(&mut text);
+    let full = parse(&text);
+
+    assert_eq!(incremental.syntax().to_string(), text);
+    assert_eq!(
+        format!("{:#?}", incremental.syntax()),
+        format!("{:#?}", full.syntax())
+    );
+    assert_eq!(incremental.errors(), full.errors());
+    incremental
+}
+
+fn offset_of(source: &str, needle: &str) -> TextSize {
+    TextSize::from(source.find(needle).expect("needle") as u32)
+}
+
+#[test]
+fn test_reparse_identifier_edit_shares_other_items() {
+    let source = "FUNCTION_BLOCK FB_Counter
+VAR
+    count : INT;
+END_VAR
+IF count < 10 THEN
+    count := count + 1;
+END_IF
+END_FUNCTION_BLOCK
+
+PROGRAM Main
+VAR
+    counter : FB_Counter;
+    total : INT;
+END_VAR
+counter();
+total := total + 1;
+END_PROGRAM
+";
+    let old = parse(source);
+    let offset = offset_of(source, "total : INT") + TextSize::from(5);
+    let edit = TextEdit::insert(offset, "s");
+    assert_reparse_matches(source, edit.clone());
+    let new = reparse(&old, &edit);
+
+    let old_fb = old.syntax().first_child().unwrap();
+    let new_fb = new.syntax().first_child().unwrap();
+    assert!(std::ptr::eq(&*old_fb.green(), &*new_fb.green()));
+}
+
+#[test]
+fn test_reparse_statement_edit_in_item() {
+    let source = "PROGRAM Main
+VAR
+    total : INT;
+END_VAR
+total := total + 1;
+END_PROGRAM
+";
+    let offset = offset_of(source, "total + 1;") + TextSize::from(9);
+    assert_reparse_matches(source, TextEdit::insert(offset, " * 2"));
+}
+
+#[test]
+fn test_reparse_edit_introducing_error_keeps_other_errors() {
+    let source = "PROGRAM A
+x := ;
+END_PROGRAM
+
+PROGRAM B
+y := 1;
+END_PROGRAM
+
+PROGRAM C
+z := ;
+END_PROGRAM
+";
+    let start = offset_of(source, "1;");
+    let new = assert_reparse_matches(
+        source,
+        TextEdit::delete(TextRange::at(start, TextSize::from(1))),
+    );
+    let first_error = new.errors().first().expect("errors").range;
+    assert!(first_error.end() <= offset_of(source, "PROGRAM B"));
+}
+
+#[test]
+fn test_reparse_deleting_end_if_falls_back_to_full_parse() {
+    let source = "FUNCTION_BLOCK FB_Counter
+VAR
+    count : INT;
+END_VAR
+IF count < 10 THEN
+    count := count + 1;
+END_IF
+END_FUNCTION_BLOCK
+
+PROGRAM Main
+END_PROGRAM
+";
+    let start = offset_of(source, "END_IF");
+    let new = assert_reparse_matches(
+        source,
+        TextEdit::delete(TextRange::at(start, TextSize::of("END_IF"))),
+    );
+    assert!(!new.ok());
+}
+
+#[test]
+fn test_reparse_unterminated_item_falls_back_to_full_parse() {
+    let source = "FUNCTION_BLOCK FB_Counter
+VAR
+    count : INT;
+END_VAR
+count := count + 1;
+END_FUNCTION_BLOCK
+
+PROGRAM Main
+END_PROGRAM
+";
+    let start = offset_of(source, "END_FUNCTION_BLOCK");
+    assert_reparse_matches(
+        source,
+        TextEdit::replace(
+            TextRange::at(start, TextSize::of("END_FUNCTION_BLOCK")),
+            "(* removed *)",
+        ),
+    );
+}
+
+#[test]
+fn test_reparse_indentation_edit_after_unterminated_block() {
+    let source = "PROGRAM Main\n    IF x THEN\n        y := 1;\n        z := 2;\nEND_PROGRAM\n";
+    let start = offset_of(source, "        z :=");
+    let new = assert_reparse_matches(
+        source,
+        TextEdit::delete(TextRange::at(start, TextSize::from(4))),
+    );
+    assert_eq!(new.errors().len(), 1);
+}
+
+#[test]
+fn test_reparse_token_merge_is_rejected() {
+    let source = "PROGRAM Main\nx := IN + 1;\nEND_PROGRAM\n";
+    let offset = offset_of(source, "IN +") + TextSize::from(2);
+    assert_reparse_matches(source, TextEdit::insert(offset, "T#5"));
+}
+
+#[test]
+fn test_reparse_comment_edit() {
+    let source = "PROGRAM Main\n(* counter *)\nx := 1;\nEND_PROGRAM\n";
+    let offset = offset_of(source, "counter");
+    assert_reparse_matches(source, TextEdit::insert(offset, "main "));
+    assert_reparse_matches(source, TextEdit::insert(offset, "*) y := 2; (*"));
+}
+
+#[test]
+fn test_reparse_unterminated_comment_falls_back_to_full_parse() {
+    let source = "PROGRAM A\nx := 1;\nEND_PROGRAM\n\nPROGRAM B\ny := 2;\nEND_PROGRAM\n";
+    let offset = offset_of(source, "x := 1");
+    let new = assert_reparse_matches(source, TextEdit::insert(offset, "(* "));
+    assert_eq!(new.syntax().children().count(), 1);
+}
+
+#[test]
+fn test_reparse_unterminated_string_falls_back_to_full_parse() {
+    let source = "PROGRAM A\nx := 1;\nEND_PROGRAM\n\nPROGRAM B\ny := 2;\nEND_PROGRAM\n";
+    let offset = offset_of(source, "x := 1");
+    assert_reparse_matches(source, TextEdit::insert(offset, "'"));
+}
+
+fn large_source(pous: usize) -> String {
+    let mut source = String::new();
+    for index in 0..pous {
+        source.push_str(&format!(
+            "FUNCTION_BLOCK FB_{index}
+VAR_INPUT
+    enable : BOOL;
+    setpoint : REAL;
+END_VAR
+VAR
+    value : REAL;
+    steps : INT;
+END_VAR
+IF enable THEN
+    value := value + (setpoint - value) * 0.1;
+    steps := steps + 1;
+ELSE
+    value := 0.0;
+END_IF
+FOR steps := 0 TO 10 DO
+    value := value * 2.0;
+END_FOR
+END_FUNCTION_BLOCK
+
+"
+        ));
+    }
+    source
+}
+
+#[test]
+#[ignore]
+fn perf_reparse_single_char_edit_budget() {
+    let source = large_source(100);
+    assert!(source.lines().count() >= 2000);
+    let old = parse(&source);
+    let offset = offset_of(&source, "FB_50") + TextSize::from(4);
+    let edits = [
+        TextEdit::insert(offset, "1"),
+        TextEdit::insert(offset_of(&source, "steps + 1") + TextSize::from(5), "-"),
+    ];
+
+    let iterations = std::env::var("ST_SYNTAX_PERF_ITERATIONS")
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(200u32);
+    let budget_us = std::env::var("ST_SYNTAX_PERF_REPARSE_US")
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(1000u64);
+    for edit in &edits {
+        let mut total = Duration::ZERO;
+        for _ in 0..iterations {
+            let started = Instant::now();
+            let new = reparse(&old, edit);
+            total += started.elapsed();
+            std::hint::black_box(new);
+        }
+        let avg = total / iterations;
+        assert!(
+            avg.as_micros() <= u128::from(budget_us),
+            "reparse avg {:?} exceeded budget {}us for {:?}",
+            avg,
+            budget_us,
+            edit
+        );
+    }
+}
@@ -160,3 +160,18 @@ fn test_siemens_hash_prefixed_statement_forms() {
 END_PROGRAM"#
     ));
 }
+
+#[test]
+fn test_try_catch_finally() {
+    insta::assert_snapshot!(snapshot_parse(
+        r#"PROGRAM Test
+__TRY
+    x := 1 / y;
+__CATCH(err)
+    x := 0;
+__FINALLY
+    y := 1;
+__ENDTRY
+END_PROGRAM"#
+    ));
+}
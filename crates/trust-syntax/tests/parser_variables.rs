@@ -81,3 +81,15 @@ END_VAR
 END_PROGRAM"#
     ));
 }
+
+#[test]
+// IEC 61131-3 Ed.3 Table 14 (array initialization)
+fn test_var_array_initializer() {
+    insta::assert_snapshot!(snapshot_parse(
+        r#"PROGRAM Test
+VAR
+    a : ARRAY[1..2] OF INT := [1, 2];
+END_VAR
+END_PROGRAM"#
+    ));
+}
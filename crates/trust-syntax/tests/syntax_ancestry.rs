@@ -0,0 +1,62 @@
+use text_size::TextSize;
+use trust_syntax::parser::parse;
+use trust_syntax::syntax::{AncestryCache, NodeAncestry, SyntaxKind};
+
+fn offset_of(source: &str, needle: &str) -> TextSize {
+    TextSize::from(source.find(needle).expect("needle") as u32)
+}
+
+#[test]
+fn ancestry_inside_nested_expression_reaches_literal() {
+    let source = "PROGRAM Test
+    y := (1 + 2) * 3;
+END_PROGRAM
+";
+    let root = parse(source).syntax();
+    let offset = offset_of(source, "2) * 3");
+    let ancestry = NodeAncestry::at_offset(&root, offset);
+
+    assert_eq!(
+        ancestry.kinds().collect::<Vec<_>>(),
+        vec![
+            SyntaxKind::SourceFile,
+            SyntaxKind::Program,
+            SyntaxKind::StmtList,
+            SyntaxKind::AssignStmt,
+            SyntaxKind::BinaryExpr,
+            SyntaxKind::ParenExpr,
+            SyntaxKind::BinaryExpr,
+            SyntaxKind::Literal,
+        ]
+    );
+    assert_eq!(ancestry.root(), &root);
+    assert_eq!(ancestry.innermost().text().to_string().trim(), "2");
+    assert!(ancestry
+        .innermost_of_kind(SyntaxKind::AssignStmt)
+        .is_some_and(|stmt| stmt.text().to_string().starts_with("y :=")));
+    assert!(ancestry.innermost_of_kind(SyntaxKind::CallExpr).is_none());
+}
+
+#[test]
+fn ancestry_cache_matches_fresh_lookup() {
+    let source = "PROGRAM Test
+    x := 1 + 2 * 3;
+    y := (1 + 2) * 3;
+END_PROGRAM
+";
+    let root = parse(source).syntax();
+    let mut cache = AncestryCache::new(root.clone());
+    let offsets = [
+        offset_of(source, "2) * 3"),
+        offset_of(source, "1 + 2)"),
+        offset_of(source, "* 3;\nEND"),
+        offset_of(source, "x :="),
+        offset_of(source, "2 * 3;"),
+        offset_of(source, "END_PROGRAM"),
+        TextSize::of(source),
+    ];
+    for offset in offsets {
+        let fresh = NodeAncestry::at_offset(&root, offset);
+        assert_eq!(cache.ancestry_at(offset), &fresh, "offset {offset:?}");
+    }
+}
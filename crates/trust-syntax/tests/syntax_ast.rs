@@ -0,0 +1,100 @@
+use trust_syntax::parser::parse;
+use trust_syntax::syntax::{
+    ArrayTypeNode, EnumDefNode, StringTypeNode, SubrangeNode, SyntaxKind, SyntaxNode,
+};
+
+fn nodes<T>(source: &str, cast: fn(SyntaxNode) -> Option<T>) -> Vec<T> {
+    parse(source)
+        .syntax()
+        .descendants()
+        .filter_map(cast)
+        .collect()
+}
+
+fn text(node: Option<SyntaxNode>) -> Option<String> {
+    node.map(|node| node.text().to_string().trim().to_string())
+}
+
+#[test]
+fn string_type_exposes_length_expression() {
+    let source = "PROGRAM Test
+VAR
+    name : STRING[N + 1];
+    wide : WSTRING;
+END_VAR
+END_PROGRAM
+";
+    let strings = nodes(source, StringTypeNode::cast);
+    assert_eq!(strings.len(), 2);
+
+    let length = strings[0].length_expr().expect("length");
+    assert_eq!(length.kind(), SyntaxKind::BinaryExpr);
+    assert_eq!(length.text().to_string(), "N + 1");
+    assert!(!strings[0].is_wide());
+
+    assert!(strings[1].length_expr().is_none());
+    assert!(strings[1].is_wide());
+}
+
+#[test]
+fn array_type_exposes_dimensions() {
+    let source = "PROGRAM Test
+VAR
+    grid : ARRAY[1..10, 0..*] OF INT;
+    flat : ARRAY[2 * 3] OF BOOL;
+END_VAR
+END_PROGRAM
+";
+    let arrays = nodes(source, ArrayTypeNode::cast);
+    assert_eq!(arrays.len(), 2);
+
+    let dims: Vec<SubrangeNode> = arrays[0].dimensions().collect();
+    assert_eq!(dims.len(), 2);
+    assert_eq!(text(dims[0].lower()).as_deref(), Some("1"));
+    assert_eq!(text(dims[0].upper()).as_deref(), Some("10"));
+    assert!(!dims[0].is_variable_length());
+    assert!(dims[1].is_variable_length());
+    assert_eq!(text(arrays[0].element_type()).as_deref(), Some("INT"));
+
+    let dims: Vec<SubrangeNode> = arrays[1].dimensions().collect();
+    assert_eq!(dims.len(), 1);
+    assert_eq!(text(dims[0].lower()).as_deref(), Some("2 * 3"));
+    assert!(dims[0].upper().is_none());
+    assert!(!dims[0].is_variable_length());
+}
+
+#[test]
+fn enum_def_exposes_members_and_base_type() {
+    let source = "TYPE
+    Color : (Red, Green := 5, Blue) INT;
+    Mode : DINT (Off, Auto := 2 + 1);
+END_TYPE
+";
+    let enums = nodes(source, EnumDefNode::cast);
+    assert_eq!(enums.len(), 2);
+
+    let members: Vec<_> = enums[0]
+        .values()
+        .map(|value| (text(value.name()), text(value.value_expr())))
+        .collect();
+    assert_eq!(
+        members,
+        vec![
+            (Some("Red".into()), None),
+            (Some("Green".into()), Some("5".into())),
+            (Some("Blue".into()), None),
+        ]
+    );
+    assert_eq!(text(enums[0].base_type()).as_deref(), Some("INT"));
+
+    assert_eq!(text(enums[1].base_type()).as_deref(), Some("DINT"));
+    let auto = enums[1].values().nth(1).expect("Auto");
+    assert_eq!(text(auto.value_expr()).as_deref(), Some("2 + 1"));
+}
+
+#[test]
+fn cast_rejects_other_kinds() {
+    let root = parse("PROGRAM Test\nVAR\n    name : STRING;\nEND_VAR\nEND_PROGRAM\n").syntax();
+    assert!(StringTypeNode::cast(root.clone()).is_none());
+    assert!(ArrayTypeNode::cast(root).is_none());
+}
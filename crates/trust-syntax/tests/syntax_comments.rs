@@ -0,0 +1,118 @@
+use trust_syntax::parser::parse;
+use trust_syntax::syntax::{
+    leading_comments, leading_doc_comment, trailing_comments, SyntaxKind, SyntaxNode, SyntaxToken,
+};
+
+fn var_decl(root: &SyntaxNode, name: &str) -> SyntaxNode {
+    root.descendants_with_tokens()
+        .filter_map(rowan::NodeOrToken::into_token)
+        .find(|token| token.kind() == SyntaxKind::Ident && token.text() == name)
+        .and_then(|token| {
+            token
+                .parent_ancestors()
+                .find(|n| n.kind() == SyntaxKind::VarDecl)
+        })
+        .expect("var decl")
+}
+
+fn texts(tokens: &[SyntaxToken]) -> Vec<String> {
+    tokens
+        .iter()
+        .map(|token| token.text().to_string())
+        .collect()
+}
+
+#[test]
+fn leading_comments_attach_to_following_declaration() {
+    let source = r#"(* Program header *)
+PROGRAM Main
+VAR
+    (* Speed setpoint *)
+    speed : INT; // rpm
+    // Motor enable
+    (* second line *)
+    enable : BOOL;
+    count : DINT; (* pieces *)
+END_VAR
+END_PROGRAM
+"#;
+    let root = parse(source).syntax();
+
+    assert_eq!(
+        texts(&leading_comments(&var_decl(&root, "speed"))),
+        vec!["(* Speed setpoint *)"]
+    );
+    assert_eq!(
+        texts(&leading_comments(&var_decl(&root, "enable"))),
+        vec!["// Motor enable", "(* second line *)"]
+    );
+    assert!(leading_comments(&var_decl(&root, "count")).is_empty());
+
+    let program = root
+        .descendants()
+        .find(|node| node.kind() == SyntaxKind::Program)
+        .expect("program");
+    assert_eq!(
+        texts(&leading_comments(&program)),
+        vec!["(* Program header *)"]
+    );
+}
+
+#[test]
+fn trailing_comments_stop_at_line_end() {
+    let source = r#"PROGRAM Main
+VAR
+    speed : INT; // rpm
+    // Motor enable
+    enable : BOOL;
+    count : DINT; (* pieces *) // total
+END_VAR
+END_PROGRAM
+"#;
+    let root = parse(source).syntax();
+
+    assert_eq!(
+        texts(&trailing_comments(&var_decl(&root, "speed"))),
+        vec!["// rpm"]
+    );
+    assert!(trailing_comments(&var_decl(&root, "enable")).is_empty());
+    assert_eq!(
+        texts(&trailing_comments(&var_decl(&root, "count"))),
+        vec!["(* pieces *)", "// total"]
+    );
+}
+
+#[test]
+fn leading_doc_comment_strips_markers_and_stops_at_blank_line() {
+    let source = r#"PROGRAM Main
+VAR
+    (* Not part of the doc *)
+
+    // Speed setpoint
+    // in rpm
+    speed : INT; // trailing, not a doc
+    (*
+        Enables the motor.
+          - requires speed > 0
+    *)
+    enable : BOOL;
+    (* Detached *)
+
+    count : DINT;
+    total : DINT;
+END_VAR
+END_PROGRAM
+"#;
+    let root = parse(source).syntax();
+
+    assert_eq!(
+        leading_doc_comment(&var_decl(&root, "speed")).as_deref(),
+        Some("Speed setpoint\nin rpm")
+    );
+    assert_eq!(
+        leading_doc_comment(&var_decl(&root, "enable")).as_deref(),
+        Some("Enables the motor.\n  - requires speed > 0")
+    );
+    assert_eq!(leading_doc_comment(&var_decl(&root, "count")), None);
+    assert_eq!(leading_doc_comment(&var_decl(&root, "total")), None);
+}
@@ -0,0 +1,88 @@
+use trust_syntax::parser::parse;
+use trust_syntax::syntax::{leading_pragmas, parse_pragma, Pragma, SyntaxKind, SyntaxNode};
+
+fn pragmas(source: &str) -> Vec<Option<Pragma>> {
+    parse(source)
+        .syntax()
+        .descendants_with_tokens()
+        .filter_map(rowan::NodeOrToken::into_token)
+        .filter(|token| token.kind() == SyntaxKind::Pragma)
+        .map(|token| parse_pragma(&token))
+        .collect()
+}
+
+fn args(pragma: &Pragma) -> Vec<(&str, Option<&str>)> {
+    pragma
+        .args
+        .iter()
+        .map(|arg| (arg.key.as_str(), arg.value.as_deref()))
+        .collect()
+}
+
+#[test]
+fn parse_pragma_splits_name_and_arguments() {
+    let source = r#"PROGRAM Main
+{attribute 'hide'}
+{warning disable W016, W005}
+{name arg1 := val1, arg2}
+{attribute 'monitoring' := 'call'}
+{flags first :=, second := "it$"s"}
+{}
+END_PROGRAM
+"#;
+    let parsed = pragmas(source);
+    assert_eq!(parsed.len(), 6);
+
+    let hide = parsed[0].as_ref().expect("attribute");
+    assert!(hide.is("ATTRIBUTE"));
+    assert_eq!(args(hide), vec![("hide", None)]);
+
+    let warning = parsed[1].as_ref().expect("warning");
+    assert_eq!(warning.name, "warning");
+    assert_eq!(
+        args(warning),
+        vec![("disable", None), ("W016", None), ("W005", None)]
+    );
+
+    let named = parsed[2].as_ref().expect("name");
+    assert_eq!(args(named), vec![("arg1", Some("val1")), ("arg2", None)]);
+    assert_eq!(
+        named.arg("ARG1").and_then(|arg| arg.value.as_deref()),
+        Some("val1")
+    );
+
+    let monitoring = parsed[3].as_ref().expect("monitoring");
+    assert_eq!(args(monitoring), vec![("monitoring", Some("call"))]);
+
+    let flags = parsed[4].as_ref().expect("flags");
+    assert_eq!(
+        args(flags),
+        vec![("first", None), ("second", Some("it\"s"))]
+    );
+
+    assert!(parsed[5].is_none());
+}
+
+#[test]
+fn leading_pragmas_attach_to_following_declaration() {
+    let source = r#"PROGRAM Main
+VAR
+    {attribute 'hide'}
+    hidden : INT;
+    visible : INT; {attribute 'trailing'}
+END_VAR
+END_PROGRAM
+"#;
+    let root = parse(source).syntax();
+    let decls: Vec<SyntaxNode> = root
+        .descendants()
+        .filter(|node| node.kind() == SyntaxKind::VarDecl)
+        .collect();
+    assert_eq!(decls.len(), 2);
+
+    let hidden = leading_pragmas(&decls[0]);
+    assert_eq!(hidden.len(), 1);
+    assert!(hidden[0].is("attribute"));
+    assert_eq!(hidden[0].args[0].key, "hide");
+    assert!(leading_pragmas(&decls[1]).is_empty());
+}
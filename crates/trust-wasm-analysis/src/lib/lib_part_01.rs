@@ -2,7 +2,7 @@ use std::collections::{BTreeMap, BTreeSet};
 
 use serde::{Deserialize, Serialize};
 use text_size::{TextRange, TextSize};
-use trust_hir::db::FileId;
+use trust_hir::db::{Database, FileId, SalsaEventSnapshot, SemanticDatabase};
 use trust_hir::project::{Project, SourceKey};
 use trust_hir::DiagnosticSeverity;
 use trust_ide::StdlibFilter;
@@ -171,6 +171,10 @@ pub struct RenameEdit {
     pub new_text: String,
 }
 
+/// Most diagnostics reported for one document; the editor cannot usefully
+/// show more, and huge generated files can produce tens of thousands.
+const MAX_DIAGNOSTICS: usize = 500;
+
 #[derive(Debug, Default)]
 pub struct BrowserAnalysisEngine {
     project: Project,
@@ -3,6 +3,14 @@ impl BrowserAnalysisEngine {
         Self::default()
     }
 
+    /// Creates an engine whose database always counts query events.
+    pub fn new_with_query_metrics() -> Self {
+        Self {
+            project: Project::from_database(Database::new_with_salsa_observability()),
+            documents: BTreeMap::new(),
+        }
+    }
+
     pub fn replace_documents(
         &mut self,
         documents: Vec<DocumentInput>,
@@ -48,37 +56,62 @@ impl BrowserAnalysisEngine {
         Ok(ApplyDocumentsResult { documents: loaded })
     }
 
+    /// Replaces the text of one loaded document; the other documents keep
+    /// their cached parse and symbol table.
+    pub fn update_document(&mut self, document: DocumentInput) -> EngineResult<LoadedDocument> {
+        if !self.documents.contains_key(&document.uri) {
+            return Err(EngineError::new(format!(
+                "document '{}' is not loaded",
+                document.uri
+            )));
+        }
+        let file_id = self
+            .project
+            .set_source_text(source_key(&document.uri), document.text.clone());
+        self.documents.insert(document.uri.clone(), document.text);
+        Ok(LoadedDocument {
+            uri: document.uri,
+            file_id: file_id.0,
+        })
+    }
+
+    /// Returns up to [`MAX_DIAGNOSTICS`] diagnostics of a document, most
+    /// severe first when the cap applies, sorted by range.
     pub fn diagnostics(&self, uri: &str) -> EngineResult<Vec<DiagnosticItem>> {
         let file_id = self.file_id_for_uri(uri)?;
         let source = self.source_for_uri(uri)?;
-        let diagnostics = self
-            .project
-            .with_database(|db| trust_ide::diagnostics::collect_diagnostics(db, file_id));
-        let mut items: Vec<DiagnosticItem> = diagnostics
-            .into_iter()
-            .map(|diagnostic| {
-                let mut related = diagnostic
-                    .related
-                    .into_iter()
-                    .map(|related| RelatedInfoItem {
-                        range: lsp_range(source, related.range),
-                        message: related.message,
-                    })
-                    .collect::<Vec<_>>();
-                related.sort_by(|left, right| {
-                    left.range
-                        .cmp(&right.range)
-                        .then_with(|| left.message.cmp(&right.message))
-                });
-                DiagnosticItem {
-                    code: diagnostic.code.code().to_string(),
-                    severity: severity_label(diagnostic.severity).to_string(),
-                    message: diagnostic.message,
-                    range: lsp_range(source, diagnostic.range),
-                    related,
-                }
-            })
-            .collect();
+        let mut items: Vec<DiagnosticItem> = self.project.with_database(|db| {
+            let diagnostics = db.diagnostics(file_id);
+            let mut kept: Vec<_> = diagnostics.iter().collect();
+            // Cap after ranking so errors late in a file are never dropped
+            // in favour of earlier warnings.
+            kept.sort_by_key(|diagnostic| (diagnostic.severity, diagnostic.range.start()));
+            kept.truncate(MAX_DIAGNOSTICS);
+            kept.into_iter()
+                .map(|diagnostic| {
+                    let mut related = diagnostic
+                        .related
+                        .iter()
+                        .map(|related| RelatedInfoItem {
+                            range: lsp_range(source, related.range),
+                            message: related.message.clone(),
+                        })
+                        .collect::<Vec<_>>();
+                    related.sort_by(|left, right| {
+                        left.range
+                            .cmp(&right.range)
+                            .then_with(|| left.message.cmp(&right.message))
+                    });
+                    DiagnosticItem {
+                        code: diagnostic.code.code().to_string(),
+                        severity: severity_label(diagnostic.severity).to_string(),
+                        message: diagnostic.message.clone(),
+                        range: lsp_range(source, diagnostic.range),
+                        related,
+                    }
+                })
+                .collect()
+        });
         items.sort_by(|left, right| {
             left.range
                 .cmp(&right.range)
@@ -296,6 +329,14 @@ impl BrowserAnalysisEngine {
         }
     }
 
+    pub fn query_event_snapshot(&self) -> SalsaEventSnapshot {
+        self.project.database().salsa_event_snapshot()
+    }
+
+    pub fn reset_query_event_counters(&self) {
+        self.project.database().reset_salsa_event_counters();
+    }
+
     fn source_for_uri(&self, uri: &str) -> EngineResult<&str> {
         self.documents
             .get(uri)
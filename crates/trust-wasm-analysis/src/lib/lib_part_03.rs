@@ -26,6 +26,17 @@ impl WasmAnalysisEngine {
         json_string(&result)
     }
 
+    #[cfg_attr(
+        all(target_arch = "wasm32", feature = "wasm"),
+        wasm_bindgen(js_name = updateDocumentJson)
+    )]
+    pub fn update_document_json(&mut self, document_json: &str) -> Result<String, String> {
+        let document: DocumentInput = serde_json::from_str(document_json)
+            .map_err(|err| format!("invalid document json: {err}"))?;
+        let result = self.inner.update_document(document)?;
+        json_string(&result)
+    }
+
     #[cfg_attr(
         all(target_arch = "wasm32", feature = "wasm"),
         wasm_bindgen(js_name = diagnosticsJson)
@@ -64,6 +64,8 @@ fn completion_kind_label(kind: trust_ide::CompletionKind) -> &'static str {
         trust_ide::CompletionKind::Constant => "constant",
         trust_ide::CompletionKind::Type => "type",
         trust_ide::CompletionKind::EnumValue => "enum_value",
+        trust_ide::CompletionKind::Task => "task",
+        trust_ide::CompletionKind::Resource => "resource",
         trust_ide::CompletionKind::Snippet => "snippet",
     }
 }
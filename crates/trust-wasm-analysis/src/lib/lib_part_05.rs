@@ -1,6 +1,21 @@
 #[cfg(test)]
 mod tests {
-    use super::{offset_to_position, position_to_offset, Position};
+    use std::sync::Arc;
+
+    use trust_hir::db::SemanticDatabase;
+
+    use super::{
+        offset_to_position, position_to_offset, BrowserAnalysisEngine, DocumentInput, Position,
+        MAX_DIAGNOSTICS,
+    };
+
+    fn library_uri(idx: usize) -> String {
+        format!("memory:///lib{idx}.st")
+    }
+
+    fn library_source(idx: usize, value: i32) -> String {
+        format!("FUNCTION Lib{idx} : INT\nLib{idx} := {value};\nEND_FUNCTION\n")
+    }
 
     #[test]
     fn line_character_offset_roundtrip_ascii() {
@@ -39,4 +54,108 @@ mod tests {
         .expect("offset");
         assert_eq!(offset, 0);
     }
+
+    #[test]
+    fn update_document_recomputes_only_the_edited_symbol_table() {
+        let mut engine = BrowserAnalysisEngine::new_with_query_metrics();
+        let documents = (0..3)
+            .map(|idx| DocumentInput {
+                uri: library_uri(idx),
+                text: library_source(idx, 0),
+            })
+            .collect();
+        let loaded = engine.replace_documents(documents).expect("load");
+        let file_ids: Vec<_> = loaded
+            .documents
+            .iter()
+            .map(|document| trust_hir::db::FileId(document.file_id))
+            .collect();
+        for idx in 0..3 {
+            engine.diagnostics(&library_uri(idx)).expect("diagnostics");
+        }
+        let before = engine
+            .project
+            .with_database(|db| db.file_symbols(file_ids[1]));
+
+        engine
+            .update_document(DocumentInput {
+                uri: library_uri(0),
+                text: library_source(0, 42),
+            })
+            .expect("update");
+
+        engine.reset_query_event_counters();
+        let after = engine.project.with_database(|db| {
+            let _ = db.file_symbols(file_ids[2]);
+            db.file_symbols(file_ids[1])
+        });
+        assert_eq!(engine.query_event_snapshot().recomputes, 0);
+        assert!(Arc::ptr_eq(&before, &after));
+
+        engine.reset_query_event_counters();
+        engine
+            .project
+            .with_database(|db| db.file_symbols(file_ids[0]));
+        assert!(engine.query_event_snapshot().recomputes > 0);
+
+        engine.diagnostics(&library_uri(0)).expect("diagnostics");
+        engine.reset_query_event_counters();
+        engine.project.with_database(|db| {
+            let _ = db.file_symbols(file_ids[1]);
+            let _ = db.file_symbols(file_ids[2]);
+        });
+        assert_eq!(engine.query_event_snapshot().recomputes, 0);
+    }
+
+    #[test]
+    fn update_document_rejects_unknown_uri() {
+        let mut engine = BrowserAnalysisEngine::new();
+        let err = engine
+            .update_document(DocumentInput {
+                uri: library_uri(0),
+                text: library_source(0, 1),
+            })
+            .expect_err("unknown document");
+        assert!(err.to_string().contains("is not loaded"));
+    }
+
+    #[test]
+    fn diagnostics_are_capped_at_the_display_limit() {
+        let body: String = (0..MAX_DIAGNOSTICS + 20)
+            .map(|idx| format!("x := missing{idx};\n"))
+            .collect();
+        let source = format!("PROGRAM Main\nVAR\n    x : INT;\nEND_VAR\n{body}END_PROGRAM\n");
+        let mut engine = BrowserAnalysisEngine::new();
+        engine
+            .replace_documents(vec![DocumentInput {
+                uri: library_uri(0),
+                text: source,
+            }])
+            .expect("load");
+
+        let items = engine.diagnostics(&library_uri(0)).expect("diagnostics");
+        assert_eq!(items.len(), MAX_DIAGNOSTICS);
+        assert!(items.windows(2).all(|pair| pair[0].range <= pair[1].range));
+    }
+
+    #[test]
+    fn diagnostics_cap_keeps_errors_over_earlier_warnings() {
+        let locals: String = (0..MAX_DIAGNOSTICS + 20)
+            .map(|idx| format!("    unused{idx} : INT;\n"))
+            .collect();
+        let source = format!(
+            "PROGRAM Main\nVAR\n    x : INT;\n{locals}END_VAR\nx := missing;\nEND_PROGRAM\n"
+        );
+        let mut engine = BrowserAnalysisEngine::new();
+        engine
+            .replace_documents(vec![DocumentInput {
+                uri: library_uri(0),
+                text: source,
+            }])
+            .expect("load");
+
+        let items = engine.diagnostics(&library_uri(0)).expect("diagnostics");
+        assert_eq!(items.len(), MAX_DIAGNOSTICS);
+        assert!(items.iter().any(|item| item.severity == "error"));
+    }
 }
@@ -106,6 +106,8 @@ pub(crate) fn completion_kind_label(kind: trust_ide::CompletionKind) -> &'static
         trust_ide::CompletionKind::Constant => "constant",
         trust_ide::CompletionKind::Type => "type",
         trust_ide::CompletionKind::EnumValue => "enum_value",
+        trust_ide::CompletionKind::Task => "task",
+        trust_ide::CompletionKind::Resource => "resource",
         trust_ide::CompletionKind::Snippet => "snippet",
     }
 }